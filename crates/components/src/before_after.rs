@@ -0,0 +1,283 @@
+use dioxus::prelude::*;
+use freya_core::platform::CursorIcon;
+use freya_elements::{
+    self as dioxus_elements,
+    events::{
+        keyboard::Key,
+        KeyboardEvent,
+        MouseEvent,
+    },
+};
+use freya_hooks::{
+    use_applied_theme,
+    use_focus,
+    use_node_signal,
+    use_platform,
+    BeforeAfterThemeWith,
+};
+
+/// Width in pixels of the draggable divider.
+const DIVIDER_WIDTH: f32 = 4.;
+
+/// Size in pixels of the grip in the middle of the divider.
+const HANDLE_SIZE: f32 = 24.;
+
+/// Percentage moved with every arrow key press.
+const KEYBOARD_STEP: f64 = 4.;
+
+/// Compare two images, or any other two elements, by overlaying them with a draggable divider.
+/// The `before` element is revealed at the left of the divider and the `after` element at its right.
+///
+/// Both elements should have the same size as the component, e.g. with `width: "fill"` and `height: "fill"`.
+///
+/// # Example
+///
+/// ```no_run
+/// # use freya::prelude::*;
+/// fn app() -> Element {
+///     let original = static_bytes(include_bytes!("../../../examples/rust_logo.png"));
+///     let edited = static_bytes(include_bytes!("../../../examples/rust_logo.png"));
+///
+///     rsx!(BeforeAfter {
+///         width: "400",
+///         height: "300",
+///         before: rsx!(image {
+///             width: "fill",
+///             height: "fill",
+///             image_data: original,
+///         }),
+///         after: rsx!(image {
+///             width: "fill",
+///             height: "fill",
+///             image_data: edited,
+///         }),
+///         onmoved: |position| println!("Divider at {position}%"),
+///     })
+/// }
+/// ```
+#[component]
+pub fn BeforeAfter(
+    /// Element revealed at the left of the divider.
+    before: Element,
+    /// Element revealed at the right of the divider.
+    after: Element,
+    /// Width of the BeforeAfter. Default `fill`.
+    #[props(default = "fill".to_string())]
+    width: String,
+    /// Height of the BeforeAfter. Default `fill`.
+    #[props(default = "fill".to_string())]
+    height: String,
+    /// Initial position of the divider as a percentage from `0.0` to `100.0`. Default `50.0`.
+    #[props(default = 50.)]
+    initial_position: f64,
+    /// Handler called with the new position of the divider, as a percentage, every time it's moved.
+    onmoved: Option<EventHandler<f64>>,
+    /// Theme override.
+    theme: Option<BeforeAfterThemeWith>,
+) -> Element {
+    let theme = use_applied_theme!(&theme, before_after);
+    let mut focus = use_focus();
+    let (node_reference, layout) = use_node_signal();
+    let mut position = use_signal(|| initial_position.clamp(0., 100.));
+    let mut dragging = use_signal(|| false);
+    let mut hovering = use_signal(|| false);
+    let platform = use_platform();
+
+    use_drop(move || {
+        if *hovering.peek() || *dragging.peek() {
+            platform.set_cursor(CursorIcon::default());
+        }
+    });
+
+    let mut move_to = move |new_position: f64| {
+        let new_position = new_position.clamp(0., 100.);
+        if *position.peek() != new_position {
+            position.set(new_position);
+            if let Some(onmoved) = &onmoved {
+                onmoved.call(new_position);
+            }
+        }
+    };
+
+    let mut move_to_cursor = move |e: &MouseEvent| {
+        let area = layout.peek().area;
+        if area.width() > 0. {
+            let x = e.get_screen_coordinates().x - area.min_x() as f64;
+            move_to(x / area.width() as f64 * 100.);
+        }
+    };
+
+    let onmousedown = move |e: MouseEvent| {
+        e.stop_propagation();
+        focus.request_focus();
+        dragging.set(true);
+        move_to_cursor(&e);
+    };
+
+    let onglobalmousemove = move |e: MouseEvent| {
+        if *dragging.peek() {
+            move_to_cursor(&e);
+        }
+    };
+
+    let onglobalclick = move |_: MouseEvent| {
+        if *dragging.peek() {
+            dragging.set(false);
+            if !*hovering.peek() {
+                platform.set_cursor(CursorIcon::default());
+            }
+        }
+    };
+
+    let onmouseenter = move |_: MouseEvent| {
+        hovering.set(true);
+        platform.set_cursor(CursorIcon::ColResize);
+    };
+
+    let onmouseleave = move |_: MouseEvent| {
+        hovering.set(false);
+        if !*dragging.peek() {
+            platform.set_cursor(CursorIcon::default());
+        }
+    };
+
+    let onkeydown = move |e: KeyboardEvent| match e.key {
+        Key::ArrowLeft => {
+            e.stop_propagation();
+            move_to(*position.peek() - KEYBOARD_STEP);
+        }
+        Key::ArrowRight => {
+            e.stop_propagation();
+            move_to(*position.peek() + KEYBOARD_STEP);
+        }
+        _ => {}
+    };
+
+    let content_width = layout.read().area.width();
+    let divider_x = content_width * (position() / 100.) as f32;
+    let divider_left = divider_x - DIVIDER_WIDTH / 2.;
+    let handle_offset = (DIVIDER_WIDTH - HANDLE_SIZE) / 2.;
+    let border = if focus.is_focused_with_keyboard() {
+        format!("2 inner {}", theme.border_fill)
+    } else {
+        "none".to_string()
+    };
+
+    rsx!(
+        rect {
+            reference: node_reference,
+            width: "{width}",
+            height: "{height}",
+            overflow: "clip",
+            a11y_id: focus.attribute(),
+            onmousedown,
+            onglobalmousemove,
+            onglobalclick,
+            onkeydown,
+            rect {
+                width: "fill",
+                height: "fill",
+                {after}
+            }
+            rect {
+                position: "absolute",
+                position_top: "0",
+                position_left: "0",
+                width: "{divider_x}",
+                height: "fill",
+                overflow: "clip",
+                rect {
+                    width: "{content_width}",
+                    height: "fill",
+                    {before}
+                }
+            }
+            rect {
+                position: "absolute",
+                position_top: "0",
+                position_left: "{divider_left}",
+                width: "{DIVIDER_WIDTH}",
+                height: "fill",
+                main_align: "center",
+                background: "{theme.divider_background}",
+                onmouseenter,
+                onmouseleave,
+                rect {
+                    offset_x: "{handle_offset}",
+                    width: "{HANDLE_SIZE}",
+                    height: "{HANDLE_SIZE}",
+                    corner_radius: "50",
+                    background: "{theme.handle_background}",
+                    border: "2 inner {theme.divider_background}",
+                }
+            }
+            rect {
+                position: "absolute",
+                position_top: "0",
+                position_left: "0",
+                width: "fill",
+                height: "fill",
+                border: "{border}",
+            }
+        }
+    )
+}
+
+#[cfg(test)]
+mod test {
+    use freya::prelude::*;
+    use freya_testing::prelude::*;
+
+    #[tokio::test]
+    pub async fn before_after() {
+        fn before_after_app() -> Element {
+            let mut position = use_signal(|| 50.);
+
+            rsx!(
+                BeforeAfter {
+                    width: "200",
+                    height: "100",
+                    before: rsx!(rect { width: "fill", height: "fill", background: "red" }),
+                    after: rsx!(rect { width: "fill", height: "fill", background: "blue" }),
+                    onmoved: move |p| position.set(p),
+                }
+                label {
+                    "{position}"
+                }
+            )
+        }
+
+        let mut utils = launch_test(before_after_app);
+        utils.wait_for_update().await;
+        utils.wait_for_update().await;
+
+        let container = utils.root().get(0);
+        let before = container.get(1);
+        assert_eq!(before.layout().unwrap().area.width(), 100.);
+
+        // Drag the divider
+        utils.push_event(TestEvent::Mouse {
+            name: EventName::MouseDown,
+            cursor: (100., 50.).into(),
+            button: Some(MouseButton::Left),
+        });
+        utils.wait_for_update().await;
+        utils.move_cursor((50., 50.)).await;
+        utils.push_event(TestEvent::Mouse {
+            name: EventName::MouseUp,
+            cursor: (50., 50.).into(),
+            button: Some(MouseButton::Left),
+        });
+        utils.wait_for_update().await;
+        utils.wait_for_update().await;
+
+        assert_eq!(utils.root().get(1).get(0).text(), Some("25"));
+        assert_eq!(before.layout().unwrap().area.width(), 50.);
+        // The before element keeps its size, it's only clipped
+        assert_eq!(before.get(0).layout().unwrap().area.width(), 200.);
+
+        // Moving the cursor after releasing doesn't move the divider
+        utils.move_cursor((150., 50.)).await;
+        assert_eq!(utils.root().get(1).get(0).text(), Some("25"));
+    }
+}
@@ -0,0 +1,629 @@
+use std::{
+    fmt,
+    time::{
+        SystemTime,
+        UNIX_EPOCH,
+    },
+};
+
+use dioxus::prelude::*;
+use freya_elements::{
+    self as dioxus_elements,
+    events::{
+        keyboard::Key,
+        KeyboardEvent,
+        MouseEvent,
+    },
+};
+use freya_hooks::{
+    use_applied_theme,
+    use_focus,
+    CalendarTheme,
+    CalendarThemeWith,
+};
+
+use crate::ArrowIcon;
+
+/// Size of the days of a [`Calendar`].
+const DAY_SIZE: f32 = 36.;
+
+/// Date of the Gregorian calendar, picked with a [`Calendar`] or [`DatePicker`](crate::DatePicker).
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct CalendarDate {
+    year: i32,
+    month: u32,
+    day: u32,
+}
+
+impl CalendarDate {
+    /// Create a date, `None` if the month (`1` to `12`) or the day don't exist.
+    pub fn new(year: i32, month: u32, day: u32) -> Option<Self> {
+        if (1..=12).contains(&month) && (1..=Self::days_in_month(year, month)).contains(&day) {
+            Some(Self { year, month, day })
+        } else {
+            None
+        }
+    }
+
+    /// Current date in UTC.
+    pub fn today() -> Self {
+        let seconds = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .map(|duration| duration.as_secs())
+            .unwrap_or_default();
+        Self::from_days((seconds / 86_400) as i64)
+    }
+
+    pub fn year(&self) -> i32 {
+        self.year
+    }
+
+    /// Month of the year, from `1` to `12`.
+    pub fn month(&self) -> u32 {
+        self.month
+    }
+
+    /// Day of the month, from `1`.
+    pub fn day(&self) -> u32 {
+        self.day
+    }
+
+    /// Day of the week, from `0` for Monday to `6` for Sunday.
+    pub fn weekday(&self) -> usize {
+        // 1970-01-01 was a Thursday
+        (self.to_days() + 3).rem_euclid(7) as usize
+    }
+
+    /// Amount of days of the given month.
+    pub fn days_in_month(year: i32, month: u32) -> u32 {
+        match month {
+            2 if year % 4 == 0 && (year % 100 != 0 || year % 400 == 0) => 29,
+            2 => 28,
+            4 | 6 | 9 | 11 => 30,
+            _ => 31,
+        }
+    }
+
+    /// Move the date by the given amount of days, backwards if negative.
+    pub fn add_days(self, days: i64) -> Self {
+        Self::from_days(self.to_days() + days)
+    }
+
+    /// Move the date by the given amount of months, backwards if negative.
+    /// The day is limited to the last one of the resulting month.
+    pub fn add_months(self, months: i32) -> Self {
+        let month = self.year * 12 + self.month as i32 - 1 + months;
+        let year = month.div_euclid(12);
+        let month = month.rem_euclid(12) as u32 + 1;
+        Self {
+            year,
+            month,
+            day: self.day.min(Self::days_in_month(year, month)),
+        }
+    }
+
+    /// First day of the month of the date.
+    pub fn first_of_month(self) -> Self {
+        Self { day: 1, ..self }
+    }
+
+    /// Position of the date in its week, from `0` for the given first day of the week.
+    fn day_of_week(&self, first_weekday: usize) -> usize {
+        (self.weekday() + 7 - first_weekday) % 7
+    }
+
+    /// Days since 1970-01-01.
+    fn to_days(self) -> i64 {
+        let year = self.year as i64 - (self.month <= 2) as i64;
+        let era = year.div_euclid(400);
+        let year_of_era = year - era * 400;
+        let month_from_march = (self.month as i64 + 9) % 12;
+        let day_of_year = (153 * month_from_march + 2) / 5 + self.day as i64 - 1;
+        let day_of_era = year_of_era * 365 + year_of_era / 4 - year_of_era / 100 + day_of_year;
+        era * 146_097 + day_of_era - 719_468
+    }
+
+    fn from_days(days: i64) -> Self {
+        let days = days + 719_468;
+        let era = days.div_euclid(146_097);
+        let day_of_era = days - era * 146_097;
+        let year_of_era =
+            (day_of_era - day_of_era / 1460 + day_of_era / 36_524 - day_of_era / 146_096) / 365;
+        let day_of_year = day_of_era - (365 * year_of_era + year_of_era / 4 - year_of_era / 100);
+        let month_from_march = (5 * day_of_year + 2) / 153;
+        let day = day_of_year - (153 * month_from_march + 2) / 5 + 1;
+        let month = if month_from_march < 10 {
+            month_from_march + 3
+        } else {
+            month_from_march - 9
+        };
+        Self {
+            year: (year_of_era + era * 400 + (month <= 2) as i64) as i32,
+            month: month as u32,
+            day: day as u32,
+        }
+    }
+}
+
+impl fmt::Display for CalendarDate {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{:04}-{:02}-{:02}", self.year, self.month, self.day)
+    }
+}
+
+/// Names of the months and days of the week shown by a [`Calendar`], English by default.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct CalendarLocale {
+    /// Names of the months, from January.
+    pub months: [String; 12],
+    /// Short names of the days of the week, from Monday.
+    pub weekdays: [String; 7],
+    /// First day of the week, from `0` for Monday to `6` for Sunday.
+    pub first_weekday: usize,
+}
+
+impl CalendarLocale {
+    pub fn new(months: [&str; 12], weekdays: [&str; 7]) -> Self {
+        Self {
+            months: months.map(String::from),
+            weekdays: weekdays.map(String::from),
+            first_weekday: 0,
+        }
+    }
+
+    /// Set the first day of the week, from `0` for Monday to `6` for Sunday.
+    pub fn with_first_weekday(mut self, first_weekday: usize) -> Self {
+        self.first_weekday = first_weekday % 7;
+        self
+    }
+
+    /// Name of the month of the date.
+    pub fn month_name(&self, date: &CalendarDate) -> &str {
+        &self.months[date.month as usize - 1]
+    }
+}
+
+impl Default for CalendarLocale {
+    fn default() -> Self {
+        Self::new(
+            [
+                "January",
+                "February",
+                "March",
+                "April",
+                "May",
+                "June",
+                "July",
+                "August",
+                "September",
+                "October",
+                "November",
+                "December",
+            ],
+            ["Mo", "Tu", "We", "Th", "Fr", "Sa", "Su"],
+        )
+    }
+}
+
+/// Dates picked with a [`Calendar`].
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum DateSelection {
+    #[default]
+    None,
+    /// A date, or the start of a range whose end is still to be picked.
+    Single(CalendarDate),
+    /// Range of dates, both included. The start is never after the end.
+    Range(CalendarDate, CalendarDate),
+}
+
+impl DateSelection {
+    /// Check whether the date is part of the selection.
+    pub fn contains(&self, date: &CalendarDate) -> bool {
+        match self {
+            Self::None => false,
+            Self::Single(selected) => selected == date,
+            Self::Range(start, end) => (start..=end).contains(&date),
+        }
+    }
+
+    /// Check whether the date is the picked one, or the start or end of the range.
+    fn is_edge(&self, date: &CalendarDate) -> bool {
+        match self {
+            Self::None => false,
+            Self::Single(selected) => selected == date,
+            Self::Range(start, end) => start == date || end == date,
+        }
+    }
+}
+
+/// Month view to pick a date or a range of dates.
+///
+/// Pick a day by clicking it or, once focused, by moving with the arrow keys and pressing
+/// `Enter` or `Space`. `PageUp` and `PageDown` go to the previous and next months,
+/// `Home` and `End` to the start and end of the week.
+/// With `range` enabled, the first picked date is the start of the range and the second its end.
+///
+/// # Styling
+/// Inherits the [`CalendarTheme`](freya_hooks::CalendarTheme) theme.
+///
+/// # Example
+///
+/// ```no_run
+/// # use freya::prelude::*;
+/// fn app() -> Element {
+///     let mut value = use_signal(DateSelection::default);
+///
+///     rsx!(Calendar {
+///         value: value(),
+///         onchange: move |selection| value.set(selection),
+///         min: CalendarDate::today(),
+///     })
+/// }
+/// ```
+#[allow(non_snake_case)]
+#[component]
+pub fn Calendar(
+    /// Picked dates.
+    value: DateSelection,
+    /// Handler for when dates are picked.
+    onchange: EventHandler<DateSelection>,
+    /// Pick a range of dates instead of a single one. Default `false`.
+    #[props(default)]
+    range: bool,
+    /// Earliest date that can be picked.
+    #[props(into)]
+    min: Option<CalendarDate>,
+    /// Latest date that can be picked.
+    #[props(into)]
+    max: Option<CalendarDate>,
+    /// Names of the months and days of the week.
+    #[props(default)]
+    locale: CalendarLocale,
+    /// Focus the Calendar once it's mounted. Default `false`.
+    #[props(default)]
+    auto_focus: bool,
+    /// Theme override.
+    theme: Option<CalendarThemeWith>,
+) -> Element {
+    let theme = use_applied_theme!(&theme, calendar);
+    let CalendarTheme {
+        background,
+        border_fill,
+        focus_border_fill,
+        weekday_color,
+        arrow_fill,
+        font_theme,
+        ..
+    } = &theme;
+    let focus = use_focus();
+    let first_weekday = locale.first_weekday;
+    let mut hovered = use_signal::<Option<CalendarDate>>(|| None);
+
+    let clamp = move |date: CalendarDate| {
+        let date = min.map_or(date, |min| date.max(min));
+        max.map_or(date, |max| date.min(max))
+    };
+    let is_enabled = move |date: CalendarDate| clamp(date) == date;
+
+    // Date to move from with the keyboard, its month is the one shown
+    let mut cursor = use_signal(|| {
+        clamp(match value {
+            DateSelection::Single(date) | DateSelection::Range(date, _) => date,
+            DateSelection::None => CalendarDate::today(),
+        })
+    });
+
+    let select = move |date: CalendarDate| {
+        if !is_enabled(date) {
+            return;
+        }
+        cursor.set(date);
+        let selection = match value {
+            DateSelection::Single(start) if range => {
+                DateSelection::Range(start.min(date), start.max(date))
+            }
+            _ => DateSelection::Single(date),
+        };
+        onchange.call(selection);
+    };
+
+    let onkeydown = move |e: KeyboardEvent| {
+        let date = *cursor.peek();
+        let day_of_week = date.day_of_week(first_weekday) as i64;
+        let next = match e.key {
+            Key::ArrowLeft => date.add_days(-1),
+            Key::ArrowRight => date.add_days(1),
+            Key::ArrowUp => date.add_days(-7),
+            Key::ArrowDown => date.add_days(7),
+            Key::PageUp => date.add_months(-1),
+            Key::PageDown => date.add_months(1),
+            Key::Home => date.add_days(-day_of_week),
+            Key::End => date.add_days(6 - day_of_week),
+            Key::Enter => {
+                select(date);
+                return;
+            }
+            Key::Character(ref ch) if ch == " " => {
+                select(date);
+                return;
+            }
+            _ => return,
+        };
+        e.stop_propagation();
+        cursor.set(clamp(next));
+    };
+
+    let month = cursor().first_of_month();
+    let previous_month = month.add_days(-1);
+    let next_month = month.add_months(1);
+    let has_previous = min.is_none_or(|min| previous_month >= min);
+    let has_next = max.is_none_or(|max| next_month <= max);
+
+    // While picking the end of a range, the hovered date previews it
+    let preview = match (value, hovered()) {
+        (DateSelection::Single(start), Some(hovered)) if range => {
+            DateSelection::Range(start.min(hovered), start.max(hovered))
+        }
+        _ => value,
+    };
+
+    let offset = month.day_of_week(first_weekday);
+    let days = CalendarDate::days_in_month(month.year, month.month) as usize;
+    let weeks = (offset + days).div_ceil(7);
+    let first_day = month.add_days(-(offset as i64));
+    let month_name = locale.month_name(&month);
+    let weekdays = (0..7).map(|i| &locale.weekdays[(first_weekday + i) % 7]);
+    let is_focused_with_keyboard = focus.is_focused_with_keyboard();
+    let border = if is_focused_with_keyboard {
+        format!("2 inner {focus_border_fill}")
+    } else {
+        format!("1 inner {border_fill}")
+    };
+
+    let navigation_button = move |delta: i32, enabled: bool, rotate: &str| {
+        rsx!(
+            rect {
+                a11y_role: "button",
+                width: "{DAY_SIZE}",
+                height: "{DAY_SIZE}",
+                main_align: "center",
+                cross_align: "center",
+                corner_radius: "{DAY_SIZE / 2.}",
+                opacity: if enabled { "1" } else { "0.4" },
+                onclick: move |_: MouseEvent| {
+                    if enabled {
+                        cursor.set(clamp(cursor.peek().add_months(delta)));
+                    }
+                },
+                ArrowIcon {
+                    rotate: "{rotate}",
+                    fill: "{arrow_fill}",
+                }
+            }
+        )
+    };
+
+    rsx!(
+        rect {
+            a11y_id: focus.attribute(),
+            a11y_role: "grid",
+            a11y_auto_focus: "{auto_focus}",
+            onkeydown,
+            onmouseleave: move |_| hovered.set(None),
+            padding: "12",
+            spacing: "8",
+            corner_radius: "8",
+            background: "{background}",
+            color: "{font_theme.color}",
+            border,
+            rect {
+                direction: "horizontal",
+                cross_align: "center",
+                {navigation_button(-1, has_previous, "90")}
+                label {
+                    width: "{DAY_SIZE * 5.}",
+                    text_align: "center",
+                    font_weight: "bold",
+                    "{month_name} {month.year}"
+                }
+                {navigation_button(1, has_next, "-90")}
+            }
+            rect {
+                direction: "horizontal",
+                for (i, weekday) in weekdays.enumerate() {
+                    label {
+                        key: "{i}",
+                        width: "{DAY_SIZE}",
+                        text_align: "center",
+                        font_size: "12",
+                        color: "{weekday_color}",
+                        "{weekday}"
+                    }
+                }
+            }
+            for week in 0..weeks {
+                rect {
+                    key: "{week}",
+                    direction: "horizontal",
+                    for day in 0..7 {
+                        CalendarDay {
+                            key: "{day}",
+                            date: first_day.add_days((week * 7 + day) as i64),
+                            month: month.month,
+                            selection: preview,
+                            min,
+                            max,
+                            cursor: is_focused_with_keyboard.then(|| cursor()),
+                            hovered,
+                            theme: theme.clone(),
+                            onselect: move |date| select(date),
+                        }
+                    }
+                }
+            }
+        }
+    )
+}
+
+#[allow(non_snake_case)]
+#[component]
+fn CalendarDay(
+    date: CalendarDate,
+    /// Month being shown, days of other months are left empty.
+    month: u32,
+    selection: DateSelection,
+    min: Option<CalendarDate>,
+    max: Option<CalendarDate>,
+    /// Date where the keyboard moves from, while the Calendar is focused with it.
+    cursor: Option<CalendarDate>,
+    hovered: Signal<Option<CalendarDate>>,
+    theme: CalendarTheme,
+    onselect: EventHandler<CalendarDate>,
+) -> Element {
+    let CalendarTheme {
+        focus_border_fill,
+        hover_background,
+        selected_background,
+        selected_color,
+        range_background,
+        today_border_fill,
+        disabled_color,
+        font_theme,
+        ..
+    } = theme;
+
+    if date.month != month {
+        return rsx!(rect {
+            width: "{DAY_SIZE}",
+            height: "{DAY_SIZE}",
+        });
+    }
+
+    let enabled = min.is_none_or(|min| date >= min) && max.is_none_or(|max| date <= max);
+    let (background, color) = if selection.is_edge(&date) {
+        (selected_background, selected_color)
+    } else if selection.contains(&date) {
+        (range_background, font_theme.color)
+    } else if hovered() == Some(date) && enabled {
+        (hover_background, font_theme.color)
+    } else if enabled {
+        ("transparent".into(), font_theme.color)
+    } else {
+        ("transparent".into(), disabled_color)
+    };
+    let border = if cursor == Some(date) {
+        format!("2 inner {focus_border_fill}")
+    } else if date == CalendarDate::today() {
+        format!("1 inner {today_border_fill}")
+    } else {
+        "none".to_string()
+    };
+
+    rsx!(
+        rect {
+            width: "{DAY_SIZE}",
+            height: "{DAY_SIZE}",
+            main_align: "center",
+            cross_align: "center",
+            corner_radius: "{DAY_SIZE / 2.}",
+            background: "{background}",
+            color: "{color}",
+            border,
+            onmouseenter: move |_| hovered.set(Some(date)),
+            onclick: move |_: MouseEvent| onselect.call(date),
+            label {
+                "{date.day}"
+            }
+        }
+    )
+}
+
+#[cfg(test)]
+mod test {
+    use freya::prelude::*;
+    use freya_testing::prelude::*;
+
+    #[test]
+    pub fn calendar_date() {
+        let date = CalendarDate::new(2024, 2, 29).unwrap();
+        assert_eq!(date.weekday(), 3);
+        assert_eq!(date.add_days(1), CalendarDate::new(2024, 3, 1).unwrap());
+        assert_eq!(date.add_days(-365), CalendarDate::new(2023, 3, 1).unwrap());
+        assert_eq!(date.add_months(12), CalendarDate::new(2025, 2, 28).unwrap());
+        assert_eq!(
+            date.add_months(-3),
+            CalendarDate::new(2023, 11, 29).unwrap()
+        );
+        assert_eq!(CalendarDate::new(2023, 2, 29), None);
+        assert_eq!(date.to_string(), "2024-02-29");
+    }
+
+    #[tokio::test]
+    pub async fn calendar() {
+        fn calendar_app() -> Element {
+            let mut value =
+                use_signal(|| DateSelection::Single(CalendarDate::new(2024, 5, 10).unwrap()));
+
+            rsx!(
+                Calendar {
+                    value: value(),
+                    onchange: move |selection| value.set(selection),
+                    range: true,
+                    max: CalendarDate::new(2024, 5, 20),
+                }
+                label {
+                    "{value():?}"
+                }
+            )
+        }
+
+        let mut utils = launch_test(calendar_app);
+        utils.wait_for_update().await;
+        let root = utils.root();
+        let calendar = root.get(0);
+        let value = root.get(1).get(0);
+
+        // May 2024 starts on a Wednesday
+        assert_eq!(calendar.get(0).get(1).get(0).text(), Some("May 2024"));
+        let first_week = calendar.get(2);
+        assert_eq!(first_week.get(0).children_ids().len(), 0);
+        assert_eq!(first_week.get(2).get(0).get(0).text(), Some("1"));
+
+        // Pick the end of the range
+        let day = calendar.get(4).get(0);
+        assert_eq!(day.get(0).get(0).text(), Some("13"));
+        let area = day.layout().unwrap().area;
+        utils
+            .click_cursor((area.center().x as f64, area.center().y as f64))
+            .await;
+        utils.wait_for_update().await;
+        let range = DateSelection::Range(
+            CalendarDate::new(2024, 5, 10).unwrap(),
+            CalendarDate::new(2024, 5, 13).unwrap(),
+        );
+        assert_eq!(value.text(), Some(format!("{range:?}").as_str()));
+
+        // Dates after the max can't be picked, and the next month can't be shown
+        let day = calendar.get(6).get(0);
+        assert_eq!(day.get(0).get(0).text(), Some("27"));
+        let area = day.layout().unwrap().area;
+        utils
+            .click_cursor((area.center().x as f64, area.center().y as f64))
+            .await;
+        let area = calendar.get(0).get(2).layout().unwrap().area;
+        utils
+            .click_cursor((area.center().x as f64, area.center().y as f64))
+            .await;
+        utils.wait_for_update().await;
+        assert_eq!(value.text(), Some(format!("{range:?}").as_str()));
+        assert_eq!(calendar.get(0).get(1).get(0).text(), Some("May 2024"));
+
+        // Go to the previous month
+        let area = calendar.get(0).get(0).layout().unwrap().area;
+        utils
+            .click_cursor((area.center().x as f64, area.center().y as f64))
+            .await;
+        utils.wait_for_update().await;
+        assert_eq!(calendar.get(0).get(1).get(0).text(), Some("April 2024"));
+    }
+}
@@ -0,0 +1,85 @@
+use dioxus::prelude::*;
+use freya_elements as dioxus_elements;
+use freya_hooks::UseCamera;
+
+/// Properties for the [`CameraView`] component.
+#[derive(Props, Clone, PartialEq)]
+pub struct CameraViewProps {
+    /// Camera to display, created with `use_camera`.
+    pub camera: UseCamera,
+    /// Width of the CameraView. Default `fill`.
+    #[props(default = "fill".into())]
+    pub width: String,
+    /// Height of the CameraView. Default `fill`.
+    #[props(default = "fill".into())]
+    pub height: String,
+    /// Aspect ratio of the frames, see the `aspect_ratio` attribute. Default `min`.
+    #[props(default = "min".into())]
+    pub aspect_ratio: String,
+    /// Element displayed instead of the frames if the camera fails.
+    pub fallback: Option<Element>,
+}
+
+/// Live preview of a camera, whose frames are captured in a separate thread by `use_camera`.
+///
+/// Use [`UseCamera::snapshot`] to get the last frame, for example to capture an avatar,
+/// and `available_cameras` to let the user pick the camera.
+///
+/// Requires the `use_camera` feature.
+///
+/// # Example
+///
+/// ```no_run
+/// # use freya::prelude::*;
+/// fn app() -> Element {
+///     let camera = use_camera(CameraSettings::default().with_resolution(Resolution::new(1280, 720)));
+///     let mut avatar = use_signal(|| None);
+///
+///     rsx!(
+///         CameraView {
+///             camera: camera.clone(),
+///             height: "80%",
+///         }
+///         Button {
+///             onpress: move |_| avatar.set(camera.snapshot()),
+///             label { "Take photo" }
+///         }
+///     )
+/// }
+/// ```
+#[allow(non_snake_case)]
+pub fn CameraView(
+    CameraViewProps {
+        camera,
+        width,
+        height,
+        aspect_ratio,
+        fallback,
+    }: CameraViewProps,
+) -> Element {
+    if let Some(err) = &*camera.error().read() {
+        return if let Some(fallback) = fallback {
+            fallback
+        } else {
+            rsx!(
+                rect {
+                    width: "{width}",
+                    height: "{height}",
+                    main_align: "center",
+                    cross_align: "center",
+                    label {
+                        "{err}"
+                    }
+                }
+            )
+        };
+    }
+
+    rsx!(image {
+        width: "{width}",
+        height: "{height}",
+        aspect_ratio: "{aspect_ratio}",
+        reference: camera.attribute(),
+        image_reference: camera.image_attribute(),
+    })
+}
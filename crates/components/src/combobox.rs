@@ -0,0 +1,409 @@
+use dioxus::prelude::*;
+use freya_elements::{
+    self as dioxus_elements,
+    events::{
+        keyboard::Key,
+        KeyboardEvent,
+        MouseEvent,
+    },
+};
+use freya_hooks::{
+    fuzzy_filter,
+    theme_with,
+    use_applied_theme,
+    use_node_signal,
+    use_platform_information,
+    DropdownItemTheme,
+    DropdownItemThemeWith,
+    DropdownTheme,
+    DropdownThemeWith,
+    IconThemeWith,
+};
+
+use crate::{
+    icons::ArrowIcon,
+    table::scroll_to_row,
+    use_scroll_controller,
+    Input,
+    ScrollConfig,
+    ScrollView,
+};
+
+/// Whether the options should open above the field, because they don't fit below it
+/// and there is more room above.
+fn opens_above(
+    field_top: f32,
+    field_bottom: f32,
+    options_height: f32,
+    viewport_height: f32,
+) -> bool {
+    field_bottom + options_height > viewport_height && field_top > viewport_height - field_bottom
+}
+
+/// Properties for the [`ComboBox`] component.
+#[derive(Props, Clone, PartialEq)]
+pub struct ComboBoxProps {
+    /// Theme override.
+    pub theme: Option<DropdownThemeWith>,
+    /// Theme override for the options.
+    pub item_theme: Option<DropdownItemThemeWith>,
+    /// Options to choose from.
+    pub options: ReadOnlySignal<Vec<String>>,
+    /// Selected option.
+    pub value: ReadOnlySignal<Option<String>>,
+    /// Handler for when an option is chosen.
+    pub onchange: EventHandler<String>,
+    /// Handler for when the typed text changes, e.g. to load the options asynchronously.
+    /// The options are not filtered by the ComboBox when set.
+    pub onsearch: Option<EventHandler<String>>,
+    /// Show a loading indicator instead of the options. Default `false`.
+    #[props(default = false)]
+    pub loading: bool,
+    /// Text to show when nothing has been typed or selected.
+    #[props(into)]
+    pub placeholder: Option<String>,
+    /// Width of the ComboBox. Default `200`.
+    #[props(default = "200".to_string())]
+    pub width: String,
+    /// Height of every option. Default `32`.
+    #[props(default = 32.)]
+    pub item_height: f32,
+    /// Maximum height of the options, they are scrollable past it. Default `200`.
+    #[props(default = 200.)]
+    pub max_height: f32,
+}
+
+/// Text field with a list of options that are filtered as you type.
+///
+/// The options are navigated with the arrow keys, chosen with `Enter` or a click,
+/// and closed with `Escape`.
+/// They open below the field, or above it when there is no room left in the window.
+///
+/// Use `onsearch` and `loading` to load the options asynchronously instead.
+///
+/// # Styling
+/// Inherits the [`DropdownTheme`](freya_hooks::DropdownTheme) and
+/// [`DropdownItemTheme`](freya_hooks::DropdownItemTheme) themes.
+///
+/// # Example
+///
+/// ```no_run
+/// # use freya::prelude::*;
+/// fn app() -> Element {
+///     let options = use_signal(|| {
+///         vec![
+///             "Apple".to_string(),
+///             "Banana".to_string(),
+///             "Cherry".to_string(),
+///         ]
+///     });
+///     let mut selected = use_signal(|| None);
+///
+///     rsx!(ComboBox {
+///         options,
+///         value: selected(),
+///         placeholder: "Pick a fruit",
+///         onchange: move |value| selected.set(Some(value)),
+///     })
+/// }
+/// ```
+#[allow(non_snake_case)]
+pub fn ComboBox(
+    ComboBoxProps {
+        theme,
+        item_theme,
+        options,
+        value,
+        onchange,
+        onsearch,
+        loading,
+        placeholder,
+        width,
+        item_height,
+        max_height,
+    }: ComboBoxProps,
+) -> Element {
+    let DropdownTheme {
+        dropdown_background,
+        border_fill,
+        arrow_fill,
+        font_theme,
+        ..
+    } = use_applied_theme!(&theme, dropdown);
+    let DropdownItemTheme {
+        background,
+        select_background,
+        hover_background,
+        font_theme: item_font_theme,
+        ..
+    } = use_applied_theme!(&item_theme, dropdown_item);
+    let mut query = use_signal(String::new);
+    let mut opened = use_signal(|| false);
+    let mut highlighted = use_signal(|| 0);
+    let mut scroll_controller = use_scroll_controller(ScrollConfig::default);
+    let (reference, layout) = use_node_signal();
+    let (options_reference, options_layout) = use_node_signal();
+    let platform_information = use_platform_information();
+
+    let filtered = use_memo(move || {
+        let options = options.read();
+        let query = query.read();
+        if query.is_empty() || onsearch.is_some() {
+            options.clone()
+        } else {
+            fuzzy_filter(&query, options.iter(), |option| option.as_str())
+                .into_iter()
+                .map(|(option, _)| option.clone())
+                .collect()
+        }
+    });
+
+    let mut close = move || {
+        opened.set(false);
+        query.set(String::new());
+    };
+
+    let mut select = move |option: String| {
+        onchange.call(option);
+        close();
+    };
+
+    let oninput = move |text: String| {
+        if let Some(onsearch) = &onsearch {
+            onsearch.call(text.clone());
+        }
+        query.set(text);
+        highlighted.set(0);
+        opened.set(true);
+    };
+
+    let mut highlight = move |index: usize| {
+        highlighted.set(index);
+        let scroll = -*scroll_controller.y().peek() as f32;
+        let viewport = scroll_controller.layout().peek().area.height();
+        if let Some(to) = scroll_to_row(index, item_height, scroll, viewport) {
+            scroll_controller.scroll_to_y(-to as i32);
+        }
+    };
+
+    let onkeydown = move |e: KeyboardEvent| {
+        let len = filtered.read().len();
+        match e.key {
+            Key::ArrowDown if !*opened.peek() => opened.set(true),
+            Key::ArrowDown if len > 0 => highlight((*highlighted.peek() + 1) % len),
+            Key::ArrowUp if len > 0 => highlight((*highlighted.peek() + len - 1) % len),
+            Key::Enter if *opened.peek() => {
+                let option = filtered.read().get(*highlighted.peek()).cloned();
+                if let Some(option) = option {
+                    select(option);
+                }
+            }
+            Key::Escape => close(),
+            _ => {}
+        }
+    };
+
+    let onclick = move |e: MouseEvent| {
+        // Don't let the options close right after being opened
+        e.prevent_default();
+        opened.set(true);
+    };
+
+    let onglobalclick = move |_: MouseEvent| close();
+
+    let is_opened = *opened.read();
+    let text = if is_opened {
+        query()
+    } else {
+        value().unwrap_or_default()
+    };
+
+    let field = layout.read().area;
+    let options_height = options_layout.read().area.height();
+    let viewport_height = platform_information.read().viewport_size.height;
+    let (arrow_rotation, options_top) = if opens_above(
+        field.min_y(),
+        field.max_y(),
+        options_height,
+        viewport_height,
+    ) {
+        (180, field.min_y() - options_height - 4.)
+    } else {
+        (0, field.max_y() + 4.)
+    };
+
+    rsx!(
+        rect {
+            width: "{width}",
+            rect {
+                reference,
+                width: "fill",
+                direction: "horizontal",
+                cross_align: "center",
+                color: "{font_theme.color}",
+                onclick,
+                onkeydown,
+                Input {
+                    value: text,
+                    placeholder,
+                    width: "calc(100% - 18)",
+                    onchange: oninput,
+                }
+                ArrowIcon {
+                    rotate: "{arrow_rotation}",
+                    fill: "{arrow_fill}",
+                    theme: theme_with!(IconTheme {
+                        margin : "0 0 0 8".into(),
+                    })
+                }
+            }
+            if is_opened {
+                rect {
+                    reference: options_reference,
+                    onglobalclick,
+                    position: "global",
+                    position_left: "{field.min_x()}",
+                    position_top: "{options_top}",
+                    layer: "-1000",
+                    width: "{field.width()}",
+                    border: "1 inner {border_fill}",
+                    overflow: "clip",
+                    corner_radius: "8",
+                    background: "{dropdown_background}",
+                    shadow: "0 2 4 0 rgb(0, 0, 0, 0.15)",
+                    padding: "4",
+                    color: "{item_font_theme.color}",
+                    if loading {
+                        rect {
+                            height: "{item_height}",
+                            padding: "0 10",
+                            main_align: "center",
+                            label { "Loading…" }
+                        }
+                    } else if filtered.read().is_empty() {
+                        rect {
+                            height: "{item_height}",
+                            padding: "0 10",
+                            main_align: "center",
+                            label { "No results" }
+                        }
+                    } else {
+                        ScrollView {
+                            height: "auto",
+                            max_height: max_height,
+                            scroll_controller,
+                            scroll_with_arrows: false,
+                            for (i, option) in filtered.read().iter().cloned().enumerate() {
+                                rect {
+                                    key: "{option}",
+                                    width: "fill",
+                                    height: "{item_height}",
+                                    padding: "0 10",
+                                    corner_radius: "6",
+                                    main_align: "center",
+                                    a11y_role: "list-item",
+                                    a11y_selected: "{value.read().as_ref() == Some(&option)}",
+                                    background: if value.read().as_ref() == Some(&option) {
+                                        "{select_background}"
+                                    } else if *highlighted.read() == i {
+                                        "{hover_background}"
+                                    } else {
+                                        "{background}"
+                                    },
+                                    onmouseenter: move |_| highlighted.set(i),
+                                    onclick: {
+                                        let option = option.clone();
+                                        move |_| select(option.clone())
+                                    },
+                                    label {
+                                        max_lines: "1",
+                                        text_overflow: "ellipsis",
+                                        "{option}"
+                                    }
+                                }
+                            }
+                        }
+                    }
+                }
+            }
+        }
+    )
+}
+
+#[cfg(test)]
+mod test {
+    use freya::prelude::*;
+    use freya_testing::prelude::*;
+
+    use super::opens_above;
+
+    #[test]
+    pub fn flip() {
+        // Enough room below
+        assert!(!opens_above(10., 40., 100., 500.));
+        // Not enough room below, but enough above
+        assert!(opens_above(420., 450., 100., 500.));
+        // Not enough room anywhere, open where there is more of it
+        assert!(!opens_above(100., 130., 450., 500.));
+    }
+
+    #[tokio::test]
+    pub async fn combobox() {
+        fn combobox_app() -> Element {
+            let options = use_signal(|| {
+                vec![
+                    "Apple".to_string(),
+                    "Banana".to_string(),
+                    "Cherry".to_string(),
+                ]
+            });
+            let mut selected = use_signal(|| None);
+
+            rsx!(
+                ComboBox {
+                    options,
+                    value: selected(),
+                    onchange: move |value| selected.set(Some(value)),
+                }
+                label {
+                    "{selected:?}"
+                }
+            )
+        }
+
+        let mut utils = launch_test(combobox_app);
+        utils.wait_for_update().await;
+        let root = utils.root();
+        let start_size = utils.sdom().get().layout().size();
+
+        // Open the options
+        utils.click_cursor((15., 15.)).await;
+        utils.wait_for_update().await;
+        assert!(utils.sdom().get().layout().size() > start_size);
+
+        // Filter them by typing
+        for (key, code) in [("c", Code::KeyC), ("h", Code::KeyH)] {
+            utils.push_event(TestEvent::Keyboard {
+                name: EventName::KeyDown,
+                key: Key::Character(key.to_string()),
+                code,
+                modifiers: Modifiers::default(),
+            });
+            utils.wait_for_update().await;
+        }
+        utils.wait_for_update().await;
+
+        // Choose the only match with the keyboard
+        utils.push_event(TestEvent::Keyboard {
+            name: EventName::KeyDown,
+            key: Key::Enter,
+            code: Code::Enter,
+            modifiers: Modifiers::default(),
+        });
+        utils.wait_for_update().await;
+        utils.wait_for_update().await;
+
+        assert_eq!(root.get(1).get(0).text(), Some("Some(\"Cherry\")"));
+        assert_eq!(utils.sdom().get().layout().size(), start_size);
+    }
+}
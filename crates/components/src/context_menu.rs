@@ -0,0 +1,347 @@
+use dioxus::prelude::*;
+use freya_elements::{
+    self as dioxus_elements,
+    events::{
+        Key,
+        KeyboardEvent,
+        MouseEvent,
+    },
+};
+use freya_hooks::{
+    use_applied_theme,
+    use_node_signal,
+    use_platform_information,
+    MenuBarThemeWith,
+};
+use torin::prelude::{
+    Area,
+    Point2D,
+    Size2D,
+};
+
+use crate::{
+    menu_bar::{
+        find_mnemonic,
+        menu_items,
+        next_selectable,
+        MenuBarMenu,
+        MenuBarState,
+    },
+    MenuBarItem,
+};
+
+/// Origin of an overlay of the given size, placed at the top right corner of `anchor`.
+///
+/// The overlay is flipped to the other side of the anchor along the axes where it would
+/// overflow the viewport, and then clamped so it stays inside of it.
+pub(crate) fn fit_overlay(anchor: Area, size: Size2D, viewport: Size2D) -> Point2D {
+    let fit = |start: f32, end: f32, size: f32, viewport: f32| {
+        let position = if end + size > viewport {
+            start - size
+        } else {
+            end
+        };
+        position.min(viewport - size).max(0.)
+    };
+    Point2D::new(
+        fit(anchor.min_x(), anchor.max_x(), size.width, viewport.width),
+        fit(anchor.max_y(), anchor.min_y(), size.height, viewport.height),
+    )
+}
+
+/// Properties for the [`ContextMenu`] component.
+#[derive(Props, Clone, PartialEq)]
+pub struct ContextMenuProps {
+    /// Items of the menu, created like the ones of a [`crate::MenuBar`].
+    pub items: Vec<MenuBarItem>,
+    /// Handler called with the `id` of the activated item.
+    pub onselect: Option<EventHandler<String>>,
+    /// Theme override.
+    pub theme: Option<MenuBarThemeWith>,
+    /// Width of the ContextMenu area. Default `auto`.
+    #[props(default = "auto".to_string())]
+    pub width: String,
+    /// Height of the ContextMenu area. Default `auto`.
+    #[props(default = "auto".to_string())]
+    pub height: String,
+    /// Content that opens the menu when right clicked.
+    pub children: Element,
+}
+
+/// Menu opened by right clicking its children, at the position of the cursor.
+///
+/// It's displayed over the rest of the app and moved or flipped so it always fits in the window,
+/// and so do its submenus. Items can be checkable, disabled or separators, same as in the
+/// [`crate::MenuBar`]. Arrows move between the items and submenus, `Enter` activates the
+/// highlighted item and `Escape` goes back.
+///
+/// # Styling
+/// Inherits the [`MenuBarTheme`](freya_hooks::MenuBarTheme) theme.
+///
+/// # Example
+///
+/// ```no_run
+/// # use freya::prelude::*;
+/// fn app() -> Element {
+///     rsx!(ContextMenu {
+///         width: "fill",
+///         height: "fill",
+///         items: vec![
+///             MenuBarItem::new("cut", "Cu&t").with_shortcut("Ctrl+X"),
+///             MenuBarItem::new("copy", "&Copy").with_shortcut("Ctrl+C"),
+///             MenuBarItem::new("paste", "&Paste").with_disabled(true),
+///             MenuBarItem::separator(),
+///             MenuBarItem::submenu(
+///                 "&Sort by",
+///                 vec![MenuBarItem::new("name", "&Name"), MenuBarItem::new("date", "&Date")]
+///             ),
+///         ],
+///         onselect: move |id: String| println!("{id}"),
+///         label { "Right click me" }
+///     })
+/// }
+/// ```
+#[allow(non_snake_case)]
+pub fn ContextMenu(
+    ContextMenuProps {
+        items,
+        onselect,
+        theme,
+        width,
+        height,
+        children,
+    }: ContextMenuProps,
+) -> Element {
+    let theme = use_applied_theme!(&theme, menu_bar);
+    let mut state = use_context_provider(|| MenuBarState {
+        open: Signal::new(Vec::new()),
+        highlighted: Signal::new(None),
+        keyboard: Signal::new(false),
+    });
+    let mut cursor = use_signal(Point2D::zero);
+    let (reference, layout) = use_node_signal();
+    let platform_information = use_platform_information();
+
+    // The menu is the only submenu of an invisible bar
+    let menus = vec![MenuBarItem::submenu("", items.clone())];
+
+    let oncontextmenu = {
+        let menus = menus.clone();
+        move |e: MouseEvent| {
+            if menus[0].items.is_empty() {
+                return;
+            }
+            let coordinates = e.get_screen_coordinates();
+            cursor.set(Point2D::new(coordinates.x as f32, coordinates.y as f32));
+            state.close();
+            state.activate(&menus[0], vec![0], onselect);
+        }
+    };
+
+    let onglobalkeydown = move |e: KeyboardEvent| {
+        let mut open = state.open.peek().clone();
+        if open.is_empty() {
+            return;
+        }
+        state.keyboard.set(true);
+        let highlighted = *state.highlighted.peek();
+        let items = menu_items(&menus, &open);
+        let is_space = e.key == Key::Character(" ".to_string());
+
+        match &e.key {
+            Key::Escape | Key::ArrowLeft if open.len() > 1 => {
+                let menu = open.pop();
+                state.open.set(open);
+                state.highlighted.set(menu);
+            }
+            Key::Escape | Key::Tab => state.close(),
+            Key::ArrowUp | Key::ArrowDown => {
+                let forward = e.key == Key::ArrowDown;
+                let next = next_selectable(items, highlighted, forward);
+                state.highlighted.set(next);
+            }
+            Key::Enter | Key::ArrowRight
+                if highlighted.is_some_and(|item| !items[item].items.is_empty()) =>
+            {
+                let item = highlighted.unwrap();
+                open.push(item);
+                state.activate(&items[item], open, onselect);
+            }
+            Key::Enter => {
+                if let Some(item) = highlighted {
+                    state.activate(&items[item], Vec::new(), onselect);
+                }
+            }
+            _ if is_space => {
+                if let Some(item) = highlighted {
+                    state.activate(&items[item], Vec::new(), onselect);
+                }
+            }
+            Key::Character(character) => {
+                if let Some(index) = find_mnemonic(items, character) {
+                    open.push(index);
+                    state.activate(&items[index], open, onselect);
+                }
+            }
+            _ => {}
+        }
+    };
+
+    // Close the menu if clicked anywhere else
+    let onglobalclick = move |_: MouseEvent| {
+        if state.is_active() {
+            state.close();
+        }
+    };
+
+    let is_open = !state.open.read().is_empty();
+    let position = fit_overlay(
+        Area::new(*cursor.read(), Size2D::zero()),
+        layout.read().area.size,
+        platform_information.read().viewport_size,
+    );
+
+    rsx!(
+        rect {
+            width: "{width}",
+            height: "{height}",
+            oncontextmenu,
+            onglobalkeydown,
+            onglobalclick,
+            {children}
+            if is_open {
+                rect {
+                    reference,
+                    position: "global",
+                    position_left: "{position.x}",
+                    position_top: "{position.y}",
+                    layer: "-1000",
+                    color: "{theme.font_theme.color}",
+                    MenuBarMenu {
+                        items,
+                        path: vec![0],
+                        onselect,
+                        theme: theme.clone(),
+                    }
+                }
+            }
+        }
+    )
+}
+
+#[cfg(test)]
+mod test {
+    use freya::prelude::*;
+    use freya_testing::prelude::*;
+    use torin::prelude::{
+        Area,
+        Point2D,
+        Size2D,
+    };
+
+    use super::fit_overlay;
+
+    #[test]
+    pub fn fit() {
+        let viewport = Size2D::new(500., 500.);
+        let size = Size2D::new(100., 200.);
+        let at = |x: f32, y: f32| Area::new(Point2D::new(x, y), Size2D::zero());
+
+        // Fits at the cursor
+        assert_eq!(
+            fit_overlay(at(10., 10.), size, viewport),
+            Point2D::new(10., 10.)
+        );
+        // Flipped horizontally and vertically
+        assert_eq!(
+            fit_overlay(at(450., 400.), size, viewport),
+            Point2D::new(350., 200.)
+        );
+        // Too tall to fit below or above, clamped
+        let tall = Size2D::new(100., 450.);
+        assert_eq!(
+            fit_overlay(at(10., 400.), tall, viewport),
+            Point2D::new(10., 0.)
+        );
+
+        // Submenus open at the right of their item, or at its left
+        let item = Area::new(Point2D::new(300., 50.), Size2D::new(150., 30.));
+        assert_eq!(fit_overlay(item, size, viewport), Point2D::new(200., 50.));
+    }
+
+    #[tokio::test]
+    pub async fn context_menu() {
+        fn context_menu_app() -> Element {
+            let mut selected = use_signal(String::new);
+
+            rsx!(
+                ContextMenu {
+                    width: "fill",
+                    height: "200",
+                    items: vec![
+                        MenuBarItem::new("copy", "&Copy"),
+                        MenuBarItem::new("paste", "&Paste").with_disabled(true),
+                        MenuBarItem::separator(),
+                        MenuBarItem::submenu(
+                            "&Sort by",
+                            vec![MenuBarItem::new("name", "&Name")]
+                        ),
+                    ],
+                    onselect: move |id| selected.set(id),
+                }
+                label { "{selected}" }
+            )
+        }
+
+        fn key(utils: &mut TestingHandler<()>, key: Key) {
+            utils.push_event(TestEvent::Keyboard {
+                name: EventName::KeyDown,
+                key,
+                code: Code::Unidentified,
+                modifiers: Modifiers::default(),
+            });
+        }
+
+        let mut utils = launch_test(context_menu_app);
+        utils.wait_for_update().await;
+
+        let is_open = |utils: &TestingHandler<()>| utils.root().get(0).children_ids().len() == 1;
+        let selected =
+            |utils: &TestingHandler<()>| utils.root().get(1).get(0).text().map(str::to_string);
+
+        // Open it near the bottom right corner, it's moved to fit in the window
+        utils.push_event(TestEvent::Mouse {
+            name: EventName::RightClick,
+            cursor: (490., 190.).into(),
+            button: Some(MouseButton::Right),
+        });
+        utils.wait_for_update().await;
+        utils.wait_for_update().await;
+        utils.wait_for_update().await;
+        assert!(is_open(&utils));
+        let area = utils.root().get(0).get(0).layout().unwrap().area;
+        assert!(area.max_x() <= 490.);
+
+        // Skip the disabled item and the separator, then open the submenu
+        key(&mut utils, Key::ArrowDown);
+        key(&mut utils, Key::ArrowDown);
+        key(&mut utils, Key::ArrowRight);
+        utils.wait_for_update().await;
+        key(&mut utils, Key::Enter);
+        utils.wait_for_update().await;
+        assert_eq!(selected(&utils).as_deref(), Some("name"));
+        assert!(!is_open(&utils));
+
+        // Open it again and activate an item with its mnemonic
+        utils.push_event(TestEvent::Mouse {
+            name: EventName::RightClick,
+            cursor: (10., 10.).into(),
+            button: Some(MouseButton::Right),
+        });
+        utils.wait_for_update().await;
+        key(&mut utils, Key::Character("c".to_string()));
+        utils.wait_for_update().await;
+        assert_eq!(selected(&utils).as_deref(), Some("copy"));
+        assert!(!is_open(&utils));
+    }
+}
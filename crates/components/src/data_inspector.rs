@@ -0,0 +1,623 @@
+use std::collections::HashSet;
+
+use dioxus::prelude::*;
+use freya_elements::{
+    self as dioxus_elements,
+    events::MouseEvent,
+};
+use freya_hooks::{
+    use_applied_theme,
+    use_clipboard,
+    DataInspectorTheme,
+    DataInspectorThemeWith,
+};
+
+use crate::{
+    Input,
+    VirtualScrollView,
+};
+
+/// Data displayed by a [`DataInspector`], with the same shape as JSON.
+///
+/// Enable the `json` feature to convert a `serde_json::Value` into it.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub enum DataValue {
+    #[default]
+    Null,
+    Bool(bool),
+    /// A number, kept as text so it is displayed with its original precision.
+    Number(String),
+    String(String),
+    Array(Vec<DataValue>),
+    /// Entries of an object, in the order they are displayed.
+    Object(Vec<(String, DataValue)>),
+}
+
+impl DataValue {
+    /// Inner values of an array or an object, with their index or key.
+    pub fn children(&self) -> Vec<(String, &DataValue)> {
+        match self {
+            Self::Array(items) => items
+                .iter()
+                .enumerate()
+                .map(|(i, item)| (i.to_string(), item))
+                .collect(),
+            Self::Object(entries) => entries
+                .iter()
+                .map(|(key, value)| (key.clone(), value))
+                .collect(),
+            _ => Vec::new(),
+        }
+    }
+
+    /// Get the inner value at the given path of child positions.
+    pub fn get(&self, path: &[usize]) -> Option<&DataValue> {
+        let Some((first, rest)) = path.split_first() else {
+            return Some(self);
+        };
+        let child = match self {
+            Self::Array(items) => items.get(*first)?,
+            Self::Object(entries) => &entries.get(*first)?.1,
+            _ => return None,
+        };
+        child.get(rest)
+    }
+
+    /// Whether it's an array or an object.
+    pub fn is_container(&self) -> bool {
+        matches!(self, Self::Array(_) | Self::Object(_))
+    }
+
+    /// Serialize the value as indented JSON.
+    pub fn to_json_string(&self) -> String {
+        let mut json = String::new();
+        self.write_json(&mut json, 0);
+        json
+    }
+
+    fn write_json(&self, json: &mut String, indent: usize) {
+        let write_entries = |json: &mut String, entries: Vec<(Option<&str>, &DataValue)>| {
+            for (i, (key, value)) in entries.iter().enumerate() {
+                json.push('\n');
+                json.push_str(&"  ".repeat(indent + 1));
+                if let Some(key) = key {
+                    json.push_str(&escape_json(key));
+                    json.push_str(": ");
+                }
+                value.write_json(json, indent + 1);
+                if i + 1 < entries.len() {
+                    json.push(',');
+                }
+            }
+            json.push('\n');
+            json.push_str(&"  ".repeat(indent));
+        };
+
+        match self {
+            Self::Null => json.push_str("null"),
+            Self::Bool(value) => json.push_str(&value.to_string()),
+            Self::Number(value) => json.push_str(value),
+            Self::String(value) => json.push_str(&escape_json(value)),
+            Self::Array(items) if items.is_empty() => json.push_str("[]"),
+            Self::Object(entries) if entries.is_empty() => json.push_str("{}"),
+            Self::Array(items) => {
+                json.push('[');
+                write_entries(json, items.iter().map(|item| (None, item)).collect());
+                json.push(']');
+            }
+            Self::Object(entries) => {
+                json.push('{');
+                write_entries(
+                    json,
+                    entries
+                        .iter()
+                        .map(|(key, value)| (Some(key.as_str()), value))
+                        .collect(),
+                );
+                json.push('}');
+            }
+        }
+    }
+}
+
+fn escape_json(text: &str) -> String {
+    let mut escaped = String::with_capacity(text.len() + 2);
+    escaped.push('"');
+    for char in text.chars() {
+        match char {
+            '"' => escaped.push_str("\\\""),
+            '\\' => escaped.push_str("\\\\"),
+            '\n' => escaped.push_str("\\n"),
+            '\r' => escaped.push_str("\\r"),
+            '\t' => escaped.push_str("\\t"),
+            char if char.is_control() => escaped.push_str(&format!("\\u{:04x}", char as u32)),
+            char => escaped.push(char),
+        }
+    }
+    escaped.push('"');
+    escaped
+}
+
+impl From<bool> for DataValue {
+    fn from(value: bool) -> Self {
+        Self::Bool(value)
+    }
+}
+
+impl From<i32> for DataValue {
+    fn from(value: i32) -> Self {
+        Self::Number(value.to_string())
+    }
+}
+
+impl From<i64> for DataValue {
+    fn from(value: i64) -> Self {
+        Self::Number(value.to_string())
+    }
+}
+
+impl From<u64> for DataValue {
+    fn from(value: u64) -> Self {
+        Self::Number(value.to_string())
+    }
+}
+
+impl From<f64> for DataValue {
+    fn from(value: f64) -> Self {
+        Self::Number(value.to_string())
+    }
+}
+
+impl From<&str> for DataValue {
+    fn from(value: &str) -> Self {
+        Self::String(value.to_string())
+    }
+}
+
+impl From<String> for DataValue {
+    fn from(value: String) -> Self {
+        Self::String(value)
+    }
+}
+
+impl<T: Into<DataValue>> From<Vec<T>> for DataValue {
+    fn from(items: Vec<T>) -> Self {
+        Self::Array(items.into_iter().map(Into::into).collect())
+    }
+}
+
+#[cfg(feature = "json")]
+impl From<serde_json::Value> for DataValue {
+    fn from(value: serde_json::Value) -> Self {
+        match value {
+            serde_json::Value::Null => Self::Null,
+            serde_json::Value::Bool(value) => Self::Bool(value),
+            serde_json::Value::Number(value) => Self::Number(value.to_string()),
+            serde_json::Value::String(value) => Self::String(value),
+            serde_json::Value::Array(items) => {
+                Self::Array(items.into_iter().map(Self::from).collect())
+            }
+            serde_json::Value::Object(entries) => Self::Object(
+                entries
+                    .into_iter()
+                    .map(|(key, value)| (key, Self::from(value)))
+                    .collect(),
+            ),
+        }
+    }
+}
+
+/// Row rendered by a [`DataInspector`].
+#[derive(Clone, Debug, PartialEq)]
+struct InspectorRow {
+    /// Positions of the children from the root to this value.
+    path: Vec<usize>,
+    /// Key or index of the value in its parent.
+    label: Option<String>,
+    value: RowValue,
+}
+
+#[derive(Clone, Debug, PartialEq)]
+enum RowValue {
+    Leaf(DataValue),
+    Container {
+        is_open: bool,
+        is_array: bool,
+        len: usize,
+    },
+}
+
+/// Whether the key or the value of a node contain the query.
+fn matches_query(label: Option<&str>, value: &DataValue, query: &str) -> bool {
+    let contains = |text: &str| text.to_lowercase().contains(query);
+    label.is_some_and(contains)
+        || match value {
+            DataValue::Null => contains("null"),
+            DataValue::Bool(value) => contains(&value.to_string()),
+            DataValue::Number(value) | DataValue::String(value) => contains(value),
+            _ => false,
+        }
+}
+
+/// Whether a node or any of its inner nodes contain the query.
+fn subtree_matches_query(label: Option<&str>, value: &DataValue, query: &str) -> bool {
+    matches_query(label, value, query)
+        || value
+            .children()
+            .iter()
+            .any(|(label, value)| subtree_matches_query(Some(label), value, query))
+}
+
+/// Flatten the open nodes into rows, keeping the ones containing the query and their ancestors.
+fn inspector_rows(
+    value: &DataValue,
+    expanded: &HashSet<Vec<usize>>,
+    query: &str,
+) -> Vec<InspectorRow> {
+    fn push_rows(
+        rows: &mut Vec<InspectorRow>,
+        label: Option<String>,
+        value: &DataValue,
+        path: Vec<usize>,
+        expanded: &HashSet<Vec<usize>>,
+        query: &str,
+    ) {
+        let children = value.children();
+        let is_searching = !query.is_empty();
+        let has_matching_children = is_searching
+            && children
+                .iter()
+                .any(|(label, value)| subtree_matches_query(Some(label), value, query));
+
+        if is_searching && !has_matching_children && !matches_query(label.as_deref(), value, query)
+        {
+            return;
+        }
+
+        if !value.is_container() {
+            rows.push(InspectorRow {
+                path,
+                label,
+                value: RowValue::Leaf(value.clone()),
+            });
+            return;
+        }
+
+        let is_open = expanded.contains(&path) || has_matching_children;
+        rows.push(InspectorRow {
+            path: path.clone(),
+            label,
+            value: RowValue::Container {
+                is_open,
+                is_array: matches!(value, DataValue::Array(_)),
+                len: children.len(),
+            },
+        });
+
+        if is_open {
+            for (i, (label, child)) in children.into_iter().enumerate() {
+                let mut child_path = path.clone();
+                child_path.push(i);
+                push_rows(rows, Some(label), child, child_path, expanded, query);
+            }
+        }
+    }
+
+    let mut rows = Vec::new();
+    push_rows(
+        &mut rows,
+        None,
+        value,
+        Vec::new(),
+        expanded,
+        &query.to_lowercase(),
+    );
+    rows
+}
+
+/// Arrow, text and color of a row.
+fn row_text<'a>(
+    row_value: &RowValue,
+    theme: &'a DataInspectorTheme,
+) -> (&'static str, String, &'a str) {
+    match row_value {
+        RowValue::Container {
+            is_open,
+            is_array,
+            len,
+        } => {
+            let arrow = if *is_open { "▾" } else { "▸" };
+            let text = match (is_array, len) {
+                (true, 1) => "[…] 1 item".to_string(),
+                (true, len) => format!("[…] {len} items"),
+                (false, 1) => "{…} 1 key".to_string(),
+                (false, len) => format!("{{…}} {len} keys"),
+            };
+            (arrow, text, &*theme.null_color)
+        }
+        RowValue::Leaf(leaf) => {
+            let (text, color) = match leaf {
+                DataValue::String(text) => (escape_json(text), &theme.string_color),
+                DataValue::Number(number) => (number.clone(), &theme.number_color),
+                DataValue::Bool(value) => (value.to_string(), &theme.boolean_color),
+                _ => ("null".to_string(), &theme.null_color),
+            };
+            ("", text, &**color)
+        }
+    }
+}
+
+/// Properties for the [`DataInspector`] component.
+#[derive(Props, Clone, PartialEq)]
+pub struct DataInspectorProps {
+    /// The data to inspect.
+    #[props(into)]
+    pub value: DataValue,
+    /// Show a search input to filter the nodes by their key or value. Default `true`.
+    #[props(default = true)]
+    pub searchable: bool,
+    /// Width of the DataInspector. Default `fill`.
+    #[props(default = "fill".into())]
+    pub width: String,
+    /// Height of the DataInspector. Default `fill`.
+    #[props(default = "fill".into())]
+    pub height: String,
+    /// Height of every node. Default `24`.
+    #[props(default = 24.0)]
+    pub line_height: f32,
+    /// Font size of the text. Default `14`.
+    #[props(default = "14".into())]
+    pub font_size: String,
+    /// Handler called with the JSON of a node after being copied to the clipboard.
+    pub oncopy: Option<EventHandler<String>>,
+    /// Theme override.
+    pub theme: Option<DataInspectorThemeWith>,
+}
+
+/// Expandable and searchable tree of JSON-like data, for debug panels, API tools and similar.
+///
+/// Click an array or an object to expand it, and right-click any node to copy its JSON.
+/// Only the visible nodes are rendered so big documents stay cheap.
+///
+/// # Example
+///
+/// ```no_run
+/// # use freya::prelude::*;
+/// fn app() -> Element {
+///     let value = DataValue::Object(vec![
+///         ("name".to_string(), "Freya".into()),
+///         ("stars".to_string(), DataValue::from(1500)),
+///         ("tags".to_string(), vec!["gui", "rust"].into()),
+///     ]);
+///
+///     rsx!(DataInspector { value })
+/// }
+/// ```
+#[allow(non_snake_case)]
+pub fn DataInspector(
+    DataInspectorProps {
+        value,
+        searchable,
+        width,
+        height,
+        line_height,
+        font_size,
+        oncopy,
+        theme,
+    }: DataInspectorProps,
+) -> Element {
+    let theme = use_applied_theme!(&theme, data_inspector);
+    let clipboard = use_clipboard();
+    // The root is open by default
+    let mut expanded = use_signal(|| HashSet::from([Vec::<usize>::new()]));
+    let mut query = use_signal(String::new);
+    let value = use_memo(use_reactive!(|value| value));
+    let rows = use_memo(move || inspector_rows(&value.read(), &expanded.read(), &query.read()));
+
+    let length = rows.read().len();
+    let background = theme.background.clone();
+    let list_height = if searchable {
+        "calc(100% - 40)"
+    } else {
+        "fill"
+    };
+
+    rsx!(
+        rect {
+            width: "{width}",
+            height: "{height}",
+            background: "{background}",
+            if searchable {
+                rect {
+                    width: "fill",
+                    padding: "4",
+                    Input {
+                        width: "fill",
+                        placeholder: "Search",
+                        value: query,
+                        onchange: move |new_query| query.set(new_query),
+                    }
+                }
+            }
+            VirtualScrollView {
+                height: "{list_height}",
+                length,
+                item_size: line_height,
+                cache_elements: false,
+                builder_args: (line_height, font_size, theme),
+                builder: move |i, args: &Option<(f32, String, DataInspectorTheme)>| {
+                    let (line_height, font_size, theme) = args.as_ref().unwrap();
+                    let Some(row) = rows.read().get(i).cloned() else {
+                        return VNode::empty();
+                    };
+                    let InspectorRow { path, label: key_label, value: row_value } = row;
+                    let padding_left = path.len() * 16;
+
+                    let (arrow, text, color) = row_text(&row_value, theme);
+                    let is_container = matches!(row_value, RowValue::Container { .. });
+                    let key = format!("{path:?}");
+
+                    let onclick = {
+                        let path = path.clone();
+                        move |_: MouseEvent| {
+                            if is_container {
+                                let mut expanded = expanded.write();
+                                if !expanded.remove(&path) {
+                                    expanded.insert(path.clone());
+                                }
+                            }
+                        }
+                    };
+
+                    let oncontextmenu = move |_: MouseEvent| {
+                        let json = value.read().get(&path).map(DataValue::to_json_string);
+                        let Some(json) = json else {
+                            return;
+                        };
+                        spawn(async move {
+                            if clipboard.set_text(json.clone()).await.is_ok() {
+                                if let Some(oncopy) = oncopy {
+                                    oncopy.call(json);
+                                }
+                            }
+                        });
+                    };
+
+                    rsx!(
+                        rect {
+                            key: "{key}",
+                            width: "fill",
+                            height: "{line_height}",
+                            direction: "horizontal",
+                            cross_align: "center",
+                            padding: "0 0 0 {padding_left}",
+                            onclick,
+                            oncontextmenu,
+                            label {
+                                width: "16",
+                                font_size: "{font_size}",
+                                color: "{theme.font_theme.color}",
+                                "{arrow}"
+                            }
+                            if let Some(key_label) = key_label {
+                                label {
+                                    font_size: "{font_size}",
+                                    font_family: "monospace",
+                                    color: "{theme.key_color}",
+                                    max_lines: "1",
+                                    "{key_label}: "
+                                }
+                            }
+                            label {
+                                font_size: "{font_size}",
+                                font_family: "monospace",
+                                color: "{color}",
+                                max_lines: "1",
+                                text_overflow: "ellipsis",
+                                "{text}"
+                            }
+                        }
+                    )
+                }
+            }
+        }
+    )
+}
+
+#[cfg(test)]
+mod test {
+    use std::collections::HashSet;
+
+    use freya::prelude::*;
+    use freya_testing::prelude::*;
+
+    use super::inspector_rows;
+
+    fn data() -> DataValue {
+        DataValue::Object(vec![
+            ("name".to_string(), "Freya".into()),
+            ("stars".to_string(), DataValue::from(1500)),
+            (
+                "authors".to_string(),
+                DataValue::Array(vec![DataValue::Object(vec![(
+                    "name".to_string(),
+                    "Marc".into(),
+                )])]),
+            ),
+            ("archived".to_string(), false.into()),
+        ])
+    }
+
+    #[test]
+    fn data_value_json() {
+        assert_eq!(
+            data().to_json_string(),
+            "{\n  \"name\": \"Freya\",\n  \"stars\": 1500,\n  \"authors\": [\n    {\n      \"name\": \"Marc\"\n    }\n  ],\n  \"archived\": false\n}"
+        );
+        assert_eq!(
+            DataValue::from("a \"quote\"\n").to_json_string(),
+            "\"a \\\"quote\\\"\\n\""
+        );
+        assert_eq!(data().get(&[2, 0, 0]), Some(&DataValue::from("Marc")));
+    }
+
+    #[test]
+    fn inspector_rows_search() {
+        let data = data();
+        let expanded = HashSet::from([vec![]]);
+
+        let rows = inspector_rows(&data, &expanded, "");
+        assert_eq!(rows.len(), 5);
+
+        // The ancestors of the matching nodes are opened
+        let rows = inspector_rows(&data, &expanded, "MARC");
+        let paths = rows.iter().map(|row| row.path.clone()).collect::<Vec<_>>();
+        assert_eq!(paths, vec![vec![], vec![2], vec![2, 0], vec![2, 0, 0]]);
+    }
+
+    #[tokio::test]
+    pub async fn data_inspector() {
+        fn data_inspector_app() -> Element {
+            let mut copied = use_signal(String::new);
+
+            rsx!(
+                DataInspector {
+                    height: "200",
+                    searchable: false,
+                    value: data(),
+                    oncopy: move |json| copied.set(json),
+                }
+                label {
+                    "{copied}"
+                }
+            )
+        }
+
+        let mut utils = launch_test(data_inspector_app);
+        utils.wait_for_update().await;
+
+        let content = utils.root().get(0).get(0).get(0).get(0);
+        assert_eq!(content.children_ids().len(), 5);
+        assert_eq!(content.get(1).get(1).get(0).text(), Some("name: "));
+        assert_eq!(content.get(1).get(2).get(0).text(), Some("\"Freya\""));
+        assert_eq!(content.get(3).get(2).get(0).text(), Some("[…] 1 item"));
+
+        // Expand the authors
+        utils.click_cursor((50., 84.)).await;
+        utils.wait_for_update().await;
+        let content = utils.root().get(0).get(0).get(0).get(0);
+        assert_eq!(content.children_ids().len(), 6);
+        assert_eq!(content.get(3).get(0).get(0).text(), Some("▾"));
+
+        // Copy a node
+        utils.push_event(TestEvent::Mouse {
+            name: EventName::RightClick,
+            cursor: (50., 36.).into(),
+            button: Some(MouseButton::Right),
+        });
+        utils.wait_for_update().await;
+        utils.wait_for_update().await;
+        assert_eq!(utils.clipboard_text(), Some("\"Freya\""));
+        assert_eq!(utils.root().get(1).get(0).text(), Some("\"Freya\""));
+    }
+}
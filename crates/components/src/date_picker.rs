@@ -0,0 +1,235 @@
+use dioxus::prelude::*;
+use freya_elements::{
+    self as dioxus_elements,
+    events::{
+        keyboard::Key,
+        KeyboardEvent,
+        MouseEvent,
+    },
+};
+use freya_hooks::{
+    use_applied_theme,
+    use_focus,
+    use_node_signal,
+    CalendarTheme,
+    CalendarThemeWith,
+};
+use torin::prelude::Point2D;
+
+use crate::{
+    ArrowIcon,
+    Calendar,
+    CalendarDate,
+    CalendarLocale,
+    DateSelection,
+};
+
+/// Field showing the picked dates, which opens a [`Calendar`] below it to pick them.
+///
+/// The Calendar is closed once the date, or both dates of the range, are picked.
+/// Clicking anywhere else or pressing `Escape` also closes it.
+///
+/// # Styling
+/// Inherits the [`CalendarTheme`](freya_hooks::CalendarTheme) theme.
+///
+/// # Example
+///
+/// ```no_run
+/// # use freya::prelude::*;
+/// fn app() -> Element {
+///     let mut value = use_signal(DateSelection::default);
+///
+///     rsx!(DatePicker {
+///         value: value(),
+///         onchange: move |selection| value.set(selection),
+///         range: true,
+///         placeholder: "Pick the dates of the trip",
+///     })
+/// }
+/// ```
+#[allow(non_snake_case)]
+#[component]
+pub fn DatePicker(
+    /// Picked dates.
+    value: DateSelection,
+    /// Handler for when dates are picked.
+    onchange: EventHandler<DateSelection>,
+    /// Pick a range of dates instead of a single one. Default `false`.
+    #[props(default)]
+    range: bool,
+    /// Earliest date that can be picked.
+    #[props(into)]
+    min: Option<CalendarDate>,
+    /// Latest date that can be picked.
+    #[props(into)]
+    max: Option<CalendarDate>,
+    /// Names of the months and days of the week.
+    #[props(default)]
+    locale: CalendarLocale,
+    /// Text shown while no date is picked. Default `"Pick a date"`.
+    #[props(default = "Pick a date".to_string(), into)]
+    placeholder: String,
+    /// Theme override.
+    theme: Option<CalendarThemeWith>,
+) -> Element {
+    let CalendarTheme {
+        background,
+        border_fill,
+        focus_border_fill,
+        disabled_color,
+        arrow_fill,
+        font_theme,
+        ..
+    } = use_applied_theme!(&theme, calendar);
+    let mut focus = use_focus();
+    let mut opened = use_signal(|| false);
+    let (field_reference, field_layout) = use_node_signal();
+    let (calendar_reference, calendar_layout) = use_node_signal();
+
+    let onpick = move |selection: DateSelection| {
+        let is_complete = !range || matches!(selection, DateSelection::Range(..));
+        if is_complete {
+            opened.set(false);
+            focus.request_focus();
+        }
+        onchange.call(selection);
+    };
+
+    let onclick = move |_: MouseEvent| {
+        focus.request_focus();
+        opened.toggle();
+    };
+
+    let onkeydown = move |e: KeyboardEvent| {
+        if matches!(e.key, Key::Enter | Key::ArrowDown) {
+            opened.set(true);
+        }
+    };
+
+    // Close it if clicked anywhere else
+    let onglobalclick = move |e: MouseEvent| {
+        if !*opened.peek() {
+            return;
+        }
+        let coordinates = e.get_screen_coordinates();
+        let cursor = Point2D::new(coordinates.x as f32, coordinates.y as f32);
+        let is_inside = field_layout.peek().area.contains(cursor)
+            || calendar_layout.peek().area.contains(cursor);
+        if !is_inside {
+            opened.set(false);
+        }
+    };
+
+    let onglobalkeydown = move |e: KeyboardEvent| {
+        if e.key == Key::Escape && *opened.peek() {
+            opened.set(false);
+            focus.request_focus();
+        }
+    };
+
+    let (text, color) = match value {
+        DateSelection::None => (placeholder, disabled_color),
+        DateSelection::Single(date) if range => (format!("{date} – …"), font_theme.color),
+        DateSelection::Single(date) => (date.to_string(), font_theme.color),
+        DateSelection::Range(start, end) => (format!("{start} – {end}"), font_theme.color),
+    };
+    let border = if focus.is_focused_with_keyboard() {
+        format!("2 inner {focus_border_fill}")
+    } else {
+        format!("1 inner {border_fill}")
+    };
+
+    rsx!(
+        rect {
+            onglobalclick,
+            onglobalkeydown,
+            rect {
+                reference: field_reference,
+                a11y_id: focus.attribute(),
+                a11y_role: "button",
+                onclick,
+                onkeydown,
+                direction: "horizontal",
+                cross_align: "center",
+                spacing: "8",
+                padding: "6 12",
+                corner_radius: "8",
+                background: "{background}",
+                border,
+                label {
+                    color: "{color}",
+                    "{text}"
+                }
+                ArrowIcon {
+                    rotate: "0",
+                    fill: "{arrow_fill}",
+                }
+            }
+            if opened() {
+                rect {
+                    height: "0",
+                    width: "0",
+                    rect {
+                        reference: calendar_reference,
+                        layer: "-1000",
+                        margin: "4 0 0 0",
+                        shadow: "0 2 4 0 rgb(0, 0, 0, 0.15)",
+                        corner_radius: "8",
+                        Calendar {
+                            value,
+                            onchange: onpick,
+                            range,
+                            min,
+                            max,
+                            locale,
+                            auto_focus: true,
+                            theme,
+                        }
+                    }
+                }
+            }
+        }
+    )
+}
+
+#[cfg(test)]
+mod test {
+    use freya::prelude::*;
+    use freya_testing::prelude::*;
+
+    #[tokio::test]
+    pub async fn date_picker() {
+        fn date_picker_app() -> Element {
+            let mut value =
+                use_signal(|| DateSelection::Single(CalendarDate::new(2024, 5, 10).unwrap()));
+
+            rsx!(DatePicker {
+                value: value(),
+                onchange: move |selection| value.set(selection),
+            })
+        }
+
+        let mut utils = launch_test(date_picker_app);
+        utils.wait_for_update().await;
+        let picker = utils.root().get(0);
+        let field = picker.get(0);
+        assert_eq!(field.get(0).get(0).text(), Some("2024-05-10"));
+
+        // Open it
+        utils.click_cursor((5., 5.)).await;
+        utils.wait_for_update().await;
+        assert_eq!(picker.children_ids().len(), 2);
+
+        // Pick a day, which closes it
+        let calendar = picker.get(1).get(0).get(0);
+        let day = calendar.get(4).get(0);
+        assert_eq!(day.get(0).get(0).text(), Some("13"));
+        let area = day.layout().unwrap().area;
+        utils
+            .click_cursor((area.center().x as f64, area.center().y as f64))
+            .await;
+        utils.wait_for_update().await;
+        assert_eq!(field.get(0).get(0).text(), Some("2024-05-13"));
+        assert_eq!(picker.children_ids().len(), 1);
+    }
+}
@@ -0,0 +1,457 @@
+use std::{
+    collections::HashSet,
+    ops::Range,
+};
+
+use dioxus::prelude::*;
+use dioxus_core::AttributeValue;
+use freya_core::custom_attributes::CustomAttributeValues;
+use freya_elements as dioxus_elements;
+use freya_hooks::{
+    diff_lines,
+    use_applied_theme,
+    DiffLine,
+    DiffLineKind,
+    DiffViewTheme,
+    DiffViewThemeWith,
+};
+
+use crate::VirtualScrollView;
+
+/// How a [`DiffView`] displays the changes.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum DiffMode {
+    /// Removed and added lines one after the other, in a single column.
+    #[default]
+    Unified,
+    /// Old text on the left and new text on the right.
+    SideBySide,
+}
+
+/// Row rendered by a [`DiffView`].
+#[derive(Clone, Debug, PartialEq)]
+enum DiffRow {
+    /// Line of the diff in [`DiffMode::Unified`].
+    Line(usize),
+    /// Old and new lines of the diff in [`DiffMode::SideBySide`].
+    Pair(Option<usize>, Option<usize>),
+    /// Hidden unchanged lines, starting at the given line of the diff.
+    Collapsed { start: usize, len: usize },
+}
+
+/// Rows of the diff, collapsing the unchanged lines further than `context` lines from any change.
+fn diff_rows(
+    lines: &[DiffLine],
+    mode: DiffMode,
+    context: usize,
+    expanded: &HashSet<usize>,
+) -> Vec<DiffRow> {
+    let mut rows = Vec::new();
+    let mut changes = Vec::new();
+
+    let push_line = |rows: &mut Vec<DiffRow>, changes: &mut Vec<usize>, i: usize| {
+        let line = &lines[i];
+        match mode {
+            DiffMode::Unified => rows.push(DiffRow::Line(i)),
+            DiffMode::SideBySide if line.kind != DiffLineKind::Unchanged => changes.push(i),
+            DiffMode::SideBySide => {
+                flush_pairs(lines, rows, changes);
+                rows.push(DiffRow::Pair(Some(i), Some(i)));
+            }
+        }
+    };
+
+    let mut i = 0;
+    while i < lines.len() {
+        if lines[i].kind != DiffLineKind::Unchanged {
+            push_line(&mut rows, &mut changes, i);
+            i += 1;
+            continue;
+        }
+
+        let start = i;
+        while i < lines.len() && lines[i].kind == DiffLineKind::Unchanged {
+            i += 1;
+        }
+
+        // Keep the context around the changes
+        let visible_start = if start == 0 { 0 } else { context };
+        let visible_end = if i == lines.len() { 0 } else { context };
+        let hidden = (i - start).saturating_sub(visible_start + visible_end);
+
+        if hidden == 0 || expanded.contains(&start) {
+            for line in start..i {
+                push_line(&mut rows, &mut changes, line);
+            }
+        } else {
+            for line in start..start + visible_start {
+                push_line(&mut rows, &mut changes, line);
+            }
+            if mode == DiffMode::SideBySide {
+                flush_pairs(lines, &mut rows, &mut changes);
+            }
+            rows.push(DiffRow::Collapsed { start, len: hidden });
+            for line in i - visible_end..i {
+                push_line(&mut rows, &mut changes, line);
+            }
+        }
+    }
+    flush_pairs(lines, &mut rows, &mut changes);
+
+    rows
+}
+
+/// Place the removed lines of a block of changes next to its added lines.
+fn flush_pairs(lines: &[DiffLine], rows: &mut Vec<DiffRow>, changes: &mut Vec<usize>) {
+    let (removed, added): (Vec<usize>, Vec<usize>) = changes
+        .drain(..)
+        .partition(|i| lines[*i].kind == DiffLineKind::Removed);
+    for row in 0..removed.len().max(added.len()) {
+        rows.push(DiffRow::Pair(
+            removed.get(row).copied(),
+            added.get(row).copied(),
+        ));
+    }
+}
+
+/// Convert ranges of chars into the ranges of UTF-16 code units used by the `highlights` attribute.
+fn to_utf16_highlights(text: &str, ranges: &[Range<usize>]) -> Vec<(usize, usize)> {
+    let mut offsets = Vec::with_capacity(text.len() + 1);
+    let mut offset = 0;
+    offsets.push(offset);
+    for char in text.chars() {
+        offset += char.len_utf16();
+        offsets.push(offset);
+    }
+    ranges
+        .iter()
+        .filter_map(|range| Some((*offsets.get(range.start)?, *offsets.get(range.end)?)))
+        .collect()
+}
+
+fn diff_line_text(line: &DiffLine, theme: &DiffViewTheme, font_size: &str, width: &str) -> Element {
+    let highlight_color = match line.kind {
+        DiffLineKind::Added => &theme.added_highlight,
+        _ => &theme.removed_highlight,
+    };
+    let highlights = AttributeValue::any_value(CustomAttributeValues::TextHighlights(
+        to_utf16_highlights(&line.text, &line.changes),
+    ));
+    let text = &line.text;
+
+    rsx!(
+        paragraph {
+            width: "{width}",
+            font_size: "{font_size}",
+            font_family: "monospace",
+            color: "{theme.font_theme.color}",
+            max_lines: "1",
+            highlights,
+            highlight_color: "{highlight_color}",
+            text {
+                "{text}"
+            }
+        }
+    )
+}
+
+fn line_number(number: Option<usize>) -> String {
+    number.map(|number| number.to_string()).unwrap_or_default()
+}
+
+fn line_background(line: Option<&DiffLine>, theme: &DiffViewTheme) -> String {
+    match line.map(|line| line.kind) {
+        Some(DiffLineKind::Added) => theme.added_background.to_string(),
+        Some(DiffLineKind::Removed) => theme.removed_background.to_string(),
+        _ => "transparent".to_string(),
+    }
+}
+
+/// Properties for the [`DiffView`] component.
+#[derive(Props, Clone, PartialEq)]
+pub struct DiffViewProps {
+    /// Old version of the text, either a [`String`] or a rope.
+    #[props(into)]
+    pub old: String,
+    /// New version of the text, either a [`String`] or a rope.
+    #[props(into)]
+    pub new: String,
+    /// How the changes are displayed. Default to [`DiffMode::Unified`].
+    #[props(default)]
+    pub mode: DiffMode,
+    /// Amount of unchanged lines shown around every change, the rest are collapsed. Default `3`.
+    #[props(default = 3)]
+    pub context: usize,
+    /// Width of the DiffView. Default `fill`.
+    #[props(default = "fill".into())]
+    pub width: String,
+    /// Height of the DiffView. Default `fill`.
+    #[props(default = "fill".into())]
+    pub height: String,
+    /// Height of every line. Default `20`.
+    #[props(default = 20.0)]
+    pub line_height: f32,
+    /// Font size of the text. Default `14`.
+    #[props(default = "14".into())]
+    pub font_size: String,
+    /// Theme override.
+    pub theme: Option<DiffViewThemeWith>,
+}
+
+/// Display the differences between two texts, with line numbers and the changed
+/// parts of the edited lines highlighted.
+///
+/// Unchanged lines far from any change are collapsed and can be expanded by clicking them,
+/// and only the visible lines are rendered so big files stay cheap.
+///
+/// # Example
+///
+/// ```no_run
+/// # use freya::prelude::*;
+/// fn app() -> Element {
+///     rsx!(DiffView {
+///         old: "fn main() {\n    println!(\"Hello\");\n}",
+///         new: "fn main() {\n    println!(\"Hello, World!\");\n}",
+///         mode: DiffMode::SideBySide,
+///     })
+/// }
+/// ```
+#[allow(non_snake_case)]
+pub fn DiffView(
+    DiffViewProps {
+        old,
+        new,
+        mode,
+        context,
+        width,
+        height,
+        line_height,
+        font_size,
+        theme,
+    }: DiffViewProps,
+) -> Element {
+    let theme = use_applied_theme!(&theme, diff_view);
+    let mut expanded = use_signal(HashSet::<usize>::new);
+    let lines = use_memo(use_reactive!(|old, new| {
+        expanded.write().clear();
+        diff_lines(&old, &new)
+    }));
+    let rows = use_memo(use_reactive!(|mode, context| {
+        diff_rows(&lines.read(), mode, context, &expanded.read())
+    }));
+
+    let length = rows.read().len();
+    let background = theme.background.clone();
+
+    rsx!(
+        rect {
+            width: "{width}",
+            height: "{height}",
+            background: "{background}",
+            VirtualScrollView {
+                length,
+                item_size: line_height,
+                cache_elements: false,
+                builder_args: (line_height, font_size, theme),
+                builder: move |i, args: &Option<(f32, String, DiffViewTheme)>| {
+                    let (line_height, font_size, theme) = args.as_ref().unwrap();
+                    let lines = lines.read();
+                    let Some(row) = rows.read().get(i).cloned() else {
+                        return VNode::empty();
+                    };
+                    let line_number_color = &theme.line_number_color;
+
+                    match row {
+                        DiffRow::Collapsed { start, len } => rsx!(
+                            rect {
+                                key: "collapsed-{start}",
+                                width: "fill",
+                                height: "{line_height}",
+                                main_align: "center",
+                                padding: "0 12",
+                                background: "{theme.collapsed_background}",
+                                onclick: move |_| {
+                                    expanded.write().insert(start);
+                                },
+                                label {
+                                    font_size: "{font_size}",
+                                    color: "{line_number_color}",
+                                    max_lines: "1",
+                                    "⋯ {len} unchanged lines"
+                                }
+                            }
+                        ),
+                        DiffRow::Line(index) => {
+                            let line = &lines[index];
+                            let old_number = line_number(line.old_number);
+                            let new_number = line_number(line.new_number);
+                            let prefix = match line.kind {
+                                DiffLineKind::Unchanged => " ",
+                                DiffLineKind::Added => "+",
+                                DiffLineKind::Removed => "-",
+                            };
+                            let background = line_background(Some(line), theme);
+                            rsx!(
+                                rect {
+                                    key: "{index}",
+                                    width: "fill",
+                                    height: "{line_height}",
+                                    direction: "horizontal",
+                                    cross_align: "center",
+                                    background: "{background}",
+                                    label {
+                                        width: "48",
+                                        text_align: "right",
+                                        font_size: "{font_size}",
+                                        color: "{line_number_color}",
+                                        "{old_number}"
+                                    }
+                                    label {
+                                        width: "48",
+                                        text_align: "right",
+                                        font_size: "{font_size}",
+                                        color: "{line_number_color}",
+                                        "{new_number}"
+                                    }
+                                    label {
+                                        width: "24",
+                                        text_align: "center",
+                                        font_size: "{font_size}",
+                                        font_family: "monospace",
+                                        color: "{theme.font_theme.color}",
+                                        "{prefix}"
+                                    }
+                                    {diff_line_text(line, theme, font_size, "calc(100% - 120)")}
+                                }
+                            )
+                        }
+                        DiffRow::Pair(old_index, new_index) => {
+                            let sides = [(old_index, true), (new_index, false)];
+                            let [old_side, new_side] = sides.map(|(index, is_old)| {
+                                let line = index.map(|index| &lines[index]);
+                                let number = line_number(line.and_then(|line| {
+                                    if is_old {
+                                        line.old_number
+                                    } else {
+                                        line.new_number
+                                    }
+                                }));
+                                let background = line_background(line, theme);
+                                let text = line.map(|line| {
+                                    diff_line_text(line, theme, font_size, "calc(100% - 60)")
+                                });
+                                rsx!(
+                                    rect {
+                                        width: "50%",
+                                        height: "fill",
+                                        direction: "horizontal",
+                                        cross_align: "center",
+                                        background: "{background}",
+                                        label {
+                                            width: "48",
+                                            text_align: "right",
+                                            font_size: "{font_size}",
+                                            color: "{line_number_color}",
+                                            "{number}"
+                                        }
+                                        rect {
+                                            width: "12",
+                                        }
+                                        {text}
+                                    }
+                                )
+                            });
+                            rsx!(
+                                rect {
+                                    key: "{old_index:?}-{new_index:?}",
+                                    width: "fill",
+                                    height: "{line_height}",
+                                    direction: "horizontal",
+                                    {old_side}
+                                    {new_side}
+                                }
+                            )
+                        }
+                    }
+                }
+            }
+        }
+    )
+}
+
+#[cfg(test)]
+mod test {
+    use std::collections::HashSet;
+
+    use freya::prelude::*;
+    use freya_hooks::diff_lines;
+    use freya_testing::prelude::*;
+
+    use super::{
+        diff_rows,
+        to_utf16_highlights,
+        DiffRow,
+    };
+
+    #[test]
+    fn diff_rows_collapse_unchanged_lines() {
+        let old = (0..20)
+            .map(|i| i.to_string())
+            .collect::<Vec<_>>()
+            .join("\n");
+        let new = old.replace("10", "ten");
+        let lines = diff_lines(&old, &new);
+
+        let rows = diff_rows(&lines, DiffMode::Unified, 2, &HashSet::new());
+        assert_eq!(rows[0], DiffRow::Collapsed { start: 0, len: 8 });
+        assert_eq!(rows[1], DiffRow::Line(8));
+        assert_eq!(rows[7], DiffRow::Collapsed { start: 12, len: 7 });
+        assert_eq!(rows.len(), 8);
+
+        // Side by side, the removed and added lines share a row
+        let rows = diff_rows(&lines, DiffMode::SideBySide, 2, &HashSet::from([0]));
+        assert_eq!(rows[10], DiffRow::Pair(Some(10), Some(11)));
+        assert_eq!(rows.len(), 14);
+    }
+
+    #[test]
+    fn utf16_highlights() {
+        assert_eq!(to_utf16_highlights("a😀b", &[1..3]), vec![(1, 4)]);
+    }
+
+    #[tokio::test]
+    pub async fn diff_view() {
+        fn diff_view_app() -> Element {
+            let old = (0..20)
+                .map(|i| i.to_string())
+                .collect::<Vec<_>>()
+                .join("\n");
+            let new = old.replace("10", "ten");
+
+            rsx!(DiffView {
+                old,
+                new,
+                context: 1,
+            })
+        }
+
+        let mut utils = launch_test(diff_view_app);
+        utils.wait_for_update().await;
+
+        let content = utils.root().get(0).get(0).get(0).get(0);
+        assert_eq!(
+            content.get(0).get(0).get(0).text(),
+            Some("⋯ 9 unchanged lines")
+        );
+        let removed = content.get(2);
+        assert_eq!(removed.get(0).get(0).text(), Some("11"));
+        assert_eq!(removed.get(2).get(0).text(), Some("-"));
+        assert_eq!(removed.get(3).get(0).get(0).text(), Some("10"));
+
+        // Expand the collapsed lines
+        utils.click_cursor((100., 10.)).await;
+        utils.wait_for_update().await;
+        let content = utils.root().get(0).get(0).get(0).get(0);
+        assert_eq!(content.get(0).get(1).get(0).text(), Some("1"));
+    }
+}
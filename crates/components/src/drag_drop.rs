@@ -21,6 +21,13 @@ pub fn DragProvider<T: 'static>(DragProviderProps { children }: DragProviderProp
     rsx!({ children })
 }
 
+/// Data being dragged from a [`DragZone`] of the closest [`DragProvider`], if any.
+///
+/// Useful to highlight the places where it can be dropped.
+pub fn use_drag<T: 'static>() -> ReadOnlySignal<Option<T>> {
+    use_context::<Signal<Option<T>>>().into()
+}
+
 /// Properties for the [`DragZone`] component.
 #[derive(Props, Clone, PartialEq)]
 pub struct DragZoneProps<T: Clone + 'static + PartialEq> {
@@ -47,20 +54,15 @@ pub fn DragZone<T: 'static + Clone + PartialEq>(
 ) -> Element {
     let mut drags = use_context::<Signal<Option<T>>>();
     let mut dragging = use_signal(|| false);
+    // Position of the cursor in the window
     let mut pos = use_signal(CursorPoint::default);
+    // Position of the cursor inside the DragZone when the drag started
+    let mut grab = use_signal(CursorPoint::default);
     let (node_reference, size) = use_node_signal();
 
     let onglobalmousemove = move |e: MouseEvent| {
         if *dragging.read() {
-            let size = size.read();
-            let coord = e.get_screen_coordinates();
-            pos.set(
-                (
-                    coord.x - size.area.min_x() as f64,
-                    coord.y - size.area.min_y() as f64,
-                )
-                    .into(),
-            );
+            pos.set(e.get_screen_coordinates());
         }
     };
 
@@ -70,13 +72,14 @@ pub fn DragZone<T: 'static + Clone + PartialEq>(
         }
         let size = size.read();
         let coord = e.get_screen_coordinates();
-        pos.set(
+        grab.set(
             (
                 coord.x - size.area.min_x() as f64,
                 coord.y - size.area.min_y() as f64,
             )
                 .into(),
         );
+        pos.set(coord);
         dragging.set(true);
         *drags.write() = Some(data.clone());
     };
@@ -84,11 +87,13 @@ pub fn DragZone<T: 'static + Clone + PartialEq>(
     let onglobalclick = move |_: MouseEvent| {
         if *dragging.read() {
             dragging.set(false);
-            pos.set((0.0, 0.0).into());
             *drags.write() = None;
         }
     };
 
+    let preview_top = pos.read().y - grab.read().y;
+    let preview_left = pos.read().x - grab.read().x;
+
     rsx!(
         rect {
             reference: node_reference,
@@ -96,12 +101,14 @@ pub fn DragZone<T: 'static + Clone + PartialEq>(
             onglobalmousemove,
             onmousedown,
             if *dragging.read() {
+                // Rendered above everything and not clipped by the ancestors
                 rect {
-                    position: "absolute",
+                    position: "global",
+                    position_top: "{preview_top}",
+                    position_left: "{preview_left}",
+                    layer: "-9999",
                     width: "0",
                     height: "0",
-                    offset_x: "{pos.read().x}",
-                    offset_y: "{pos.read().y}",
                     {drag_element}
                 }
             }
@@ -119,6 +126,10 @@ pub struct DropZoneProps<T: 'static + PartialEq + Clone> {
     children: Element,
     /// Handler for the `ondrop` event.
     ondrop: EventHandler<T>,
+    /// Handler called when the cursor enters the DropZone while dragging.
+    ondragenter: Option<EventHandler<T>>,
+    /// Handler called when the cursor leaves the DropZone while dragging.
+    ondragleave: Option<EventHandler<()>>,
     /// Width of the [DropZone].
     #[props(default = "auto".to_string())]
     width: String,
@@ -131,9 +142,29 @@ pub struct DropZoneProps<T: 'static + PartialEq + Clone> {
 #[allow(non_snake_case)]
 pub fn DropZone<T: 'static + Clone + PartialEq>(props: DropZoneProps<T>) -> Element {
     let mut drags = use_context::<Signal<Option<T>>>();
+    let mut is_over = use_signal(|| false);
+
+    let onmouseenter = move |_| {
+        if let Some(current_drags) = &*drags.read() {
+            is_over.set(true);
+            if let Some(ondragenter) = &props.ondragenter {
+                ondragenter.call(current_drags.clone());
+            }
+        }
+    };
+
+    let onmouseleave = move |_| {
+        if *is_over.read() {
+            is_over.set(false);
+            if let Some(ondragleave) = &props.ondragleave {
+                ondragleave.call(());
+            }
+        }
+    };
 
     let onmouseup = move |e: MouseEvent| {
         e.stop_propagation();
+        is_over.set(false);
         if let Some(current_drags) = &*drags.read() {
             props.ondrop.call(current_drags.clone());
         }
@@ -144,6 +175,8 @@ pub fn DropZone<T: 'static + Clone + PartialEq>(props: DropZoneProps<T>) -> Elem
 
     rsx!(
         rect {
+            onmouseenter,
+            onmouseleave,
             onmouseup,
             width: props.width,
             height: props.height,
@@ -307,4 +340,74 @@ mod test {
             Some("Enabled: true")
         );
     }
+
+    #[tokio::test]
+    pub async fn drag_enter_leave() {
+        #[allow(non_snake_case)]
+        fn DropApp() -> Element {
+            let mut over = use_signal(|| false);
+            let dragging = use_drag::<bool>();
+
+            rsx!(
+                rect {
+                    height: "50%",
+                    width: "100%",
+                    DragZone {
+                        data: true,
+                        drag_element: rsx!(
+                            label {
+                                "Moving"
+                            }
+                        ),
+                        label {
+                            "Move"
+                        }
+                    }
+                }
+                DropZone {
+                    ondrop: move |_: bool| {},
+                    ondragenter: move |_| over.set(true),
+                    ondragleave: move |_| over.set(false),
+                    rect {
+                        height: "50%",
+                        width: "100%",
+                        label {
+                            "{over} {dragging.read().is_some()}"
+                        }
+                    }
+                }
+            )
+        }
+
+        fn drop_app() -> Element {
+            rsx!(
+                DragProvider::<bool> {
+                    DropApp {}
+                }
+            )
+        }
+
+        let mut utils = launch_test(drop_app);
+        let root = utils.root();
+        utils.wait_for_update().await;
+
+        let label = root.get(1).get(0).get(0);
+        assert_eq!(label.get(0).text(), Some("false false"));
+
+        utils.push_event(TestEvent::Mouse {
+            name: EventName::MouseDown,
+            cursor: (5.0, 5.0).into(),
+            button: Some(MouseButton::Left),
+        });
+        utils.wait_for_update().await;
+        assert_eq!(label.get(0).text(), Some("false true"));
+
+        // Enter the DropZone
+        utils.move_cursor((5., 300.)).await;
+        assert_eq!(label.get(0).text(), Some("true true"));
+
+        // Leave the DropZone
+        utils.move_cursor((5., 5.)).await;
+        assert_eq!(label.get(0).text(), Some("false true"));
+    }
 }
@@ -0,0 +1,493 @@
+use std::fmt::Write;
+
+use dioxus::prelude::*;
+use freya_core::{
+    custom_attributes::CanvasRunnerContext,
+    parsing::Parse,
+};
+use freya_elements::{
+    self as dioxus_elements,
+    events::{
+        PointerEvent,
+        PointerType,
+    },
+};
+use freya_engine::prelude::*;
+use freya_hooks::{
+    use_applied_theme,
+    use_canvas,
+    use_node_signal,
+    use_platform,
+    DrawingPadThemeWith,
+};
+
+/// Points closer than this to the previous point of a stroke are ignored.
+const MIN_POINT_DISTANCE: f32 = 1.;
+
+/// Point of a [`Stroke`], relative to the [`DrawingPad`].
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct DrawingPoint {
+    pub x: f32,
+    pub y: f32,
+    /// Pressure from `0.0` to `1.0`, always `1.0` for devices that don't report it like mice.
+    pub pressure: f32,
+}
+
+impl DrawingPoint {
+    fn distance_to(&self, other: &Self) -> f32 {
+        (self.x - other.x).hypot(self.y - other.y)
+    }
+
+    fn midpoint(&self, other: &Self) -> Self {
+        Self {
+            x: (self.x + other.x) / 2.,
+            y: (self.y + other.y) / 2.,
+            pressure: (self.pressure + other.pressure) / 2.,
+        }
+    }
+}
+
+/// Stroke drawn in a [`DrawingPad`].
+#[derive(Debug, Clone, PartialEq)]
+pub struct Stroke {
+    pub points: Vec<DrawingPoint>,
+    pub color: String,
+    pub width: f32,
+}
+
+/// Piece of a smoothed [`Stroke`], a quadratic curve when it has a `control` point or a line otherwise.
+#[derive(Debug, Clone, Copy, PartialEq)]
+struct StrokeSegment {
+    from: DrawingPoint,
+    control: Option<DrawingPoint>,
+    to: DrawingPoint,
+}
+
+impl Stroke {
+    /// Smooth the stroke with quadratic curves between the midpoints of its points.
+    fn segments(&self) -> Vec<StrokeSegment> {
+        let points = &self.points;
+        let (Some(first), Some(last)) = (points.first(), points.last()) else {
+            return Vec::new();
+        };
+        if points.len() < 3 {
+            return vec![StrokeSegment {
+                from: *first,
+                control: None,
+                to: *last,
+            }];
+        }
+
+        let mut segments = Vec::with_capacity(points.len());
+        let mut from = *first;
+        for window in points.windows(3) {
+            let to = window[1].midpoint(&window[2]);
+            segments.push(StrokeSegment {
+                from,
+                control: Some(window[1]),
+                to,
+            });
+            from = to;
+        }
+        segments.push(StrokeSegment {
+            from,
+            control: None,
+            to: *last,
+        });
+        segments
+    }
+
+    /// Width of the stroke at the given pressure.
+    fn width_at(&self, pressure: f32) -> f32 {
+        self.width * pressure.clamp(0.1, 1.)
+    }
+
+    /// Average width of the stroke.
+    fn average_width(&self) -> f32 {
+        let pressure = self.points.iter().map(|point| point.pressure).sum::<f32>()
+            / self.points.len().max(1) as f32;
+        self.width_at(pressure)
+    }
+
+    fn draw(&self, canvas: &Canvas, origin: (f32, f32), scale: f32) {
+        let Ok(color) = Color::parse(&self.color) else {
+            return;
+        };
+        let point = |point: DrawingPoint| (origin.0 + point.x * scale, origin.1 + point.y * scale);
+
+        let mut paint = Paint::default();
+        paint.set_anti_alias(true);
+        paint.set_color(color);
+
+        if let [dot] = self.points.as_slice() {
+            paint.set_style(PaintStyle::Fill);
+            canvas.draw_circle(
+                point(*dot),
+                self.width_at(dot.pressure) * scale / 2.,
+                &paint,
+            );
+            return;
+        }
+
+        paint.set_style(PaintStyle::Stroke);
+        paint.set_stroke_cap(PaintCap::Round);
+        // Every segment is drawn separately so its width follows the pressure
+        for segment in self.segments() {
+            let pressure = segment.control.unwrap_or(segment.to).pressure;
+            paint.set_stroke_width(self.width_at(pressure) * scale);
+
+            let mut path = Path::new();
+            path.move_to(point(segment.from));
+            if let Some(control) = segment.control {
+                path.quad_to(point(control), point(segment.to));
+            } else {
+                path.line_to(point(segment.to));
+            }
+            canvas.draw_path(&path, &paint);
+        }
+    }
+
+    /// SVG `path` element of this stroke, drawn with its average width.
+    fn to_svg_path(&self) -> String {
+        let color = Color::parse(&self.color).unwrap_or(Color::BLACK);
+        let mut data = String::new();
+        if let [dot] = self.points.as_slice() {
+            write!(data, "M{} {} L{} {}", dot.x, dot.y, dot.x, dot.y).ok();
+        } else {
+            for (i, segment) in self.segments().iter().enumerate() {
+                if i == 0 {
+                    write!(data, "M{} {}", segment.from.x, segment.from.y).ok();
+                }
+                match segment.control {
+                    Some(control) => write!(
+                        data,
+                        " Q{} {} {} {}",
+                        control.x, control.y, segment.to.x, segment.to.y
+                    ),
+                    None => write!(data, " L{} {}", segment.to.x, segment.to.y),
+                }
+                .ok();
+            }
+        }
+
+        format!(
+            r##"<path d="{data}" fill="none" stroke="#{:02x}{:02x}{:02x}" stroke-opacity="{}" stroke-width="{}" stroke-linecap="round" stroke-linejoin="round"/>"##,
+            color.r(),
+            color.g(),
+            color.b(),
+            color.a() as f32 / 255.,
+            self.average_width()
+        )
+    }
+}
+
+/// Controller of a [`DrawingPad`], created with [`use_drawing_pad`].
+#[derive(Clone, Copy, PartialEq)]
+pub struct DrawingPadController {
+    strokes: Signal<Vec<Stroke>>,
+}
+
+impl DrawingPadController {
+    /// Finished strokes, in the order they were drawn.
+    pub fn strokes(&self) -> ReadOnlySignal<Vec<Stroke>> {
+        self.strokes.into()
+    }
+
+    /// Remove the last stroke.
+    pub fn undo(&mut self) -> Option<Stroke> {
+        self.strokes.write().pop()
+    }
+
+    /// Remove all the strokes.
+    pub fn clear(&mut self) {
+        self.strokes.write().clear();
+    }
+
+    /// Export the strokes as an SVG document of the given size.
+    pub fn to_svg(&self, width: f32, height: f32) -> String {
+        let mut svg = format!(
+            r#"<svg xmlns="http://www.w3.org/2000/svg" width="{width}" height="{height}" viewBox="0 0 {width} {height}">"#
+        );
+        for stroke in self.strokes.read().iter() {
+            svg.push_str(&stroke.to_svg_path());
+        }
+        svg.push_str("</svg>");
+        svg
+    }
+
+    /// Export the strokes as a PNG image of the given size, with a transparent background.
+    pub fn to_png(&self, width: i32, height: i32) -> Option<Vec<u8>> {
+        let mut surface = raster_n32_premul((width, height))?;
+        surface.canvas().clear(Color::TRANSPARENT);
+        for stroke in self.strokes.read().iter() {
+            stroke.draw(surface.canvas(), (0., 0.), 1.);
+        }
+        let image = surface.image_snapshot();
+        let mut context = surface.direct_context();
+        let data = image.encode(context.as_mut(), EncodedImageFormat::PNG, None)?;
+        Some(data.as_bytes().to_vec())
+    }
+}
+
+/// Create a [`DrawingPadController`] to undo, clear or export the strokes of a [`DrawingPad`].
+pub fn use_drawing_pad() -> DrawingPadController {
+    let strokes = use_signal(Vec::new);
+    DrawingPadController { strokes }
+}
+
+/// Properties for the [`DrawingPad`] component.
+#[derive(Props, Clone, PartialEq)]
+pub struct DrawingPadProps {
+    /// Controller of the strokes, use it to undo, clear or export them.
+    pub controller: Option<DrawingPadController>,
+    /// Color of the new strokes. Defaults to the `stroke_color` of the theme.
+    pub stroke_color: Option<String>,
+    /// Width of the new strokes at full pressure. Default `3`.
+    #[props(default = 3.)]
+    pub stroke_width: f32,
+    /// Width of the DrawingPad. Default `fill`.
+    #[props(default = "fill".into())]
+    pub width: String,
+    /// Height of the DrawingPad. Default `fill`.
+    #[props(default = "fill".into())]
+    pub height: String,
+    /// Handler called with every finished stroke.
+    pub onstroke: Option<EventHandler<Stroke>>,
+    /// Theme override.
+    pub theme: Option<DrawingPadThemeWith>,
+}
+
+/// Freehand drawing surface, e.g. for signatures. Strokes are captured from the mouse, touch and pen,
+/// using the pressure when the device reports it, and rendered as smoothed paths.
+///
+/// # Example
+///
+/// ```no_run
+/// # use freya::prelude::*;
+/// fn app() -> Element {
+///     let mut controller = use_drawing_pad();
+///
+///     rsx!(
+///         DrawingPad {
+///             controller,
+///             height: "300",
+///             stroke_color: "rgb(20, 20, 120)",
+///             onstroke: |stroke: Stroke| println!("Stroke of {} points", stroke.points.len()),
+///         }
+///         Button {
+///             onpress: move |_| {
+///                 controller.undo();
+///             },
+///             label { "Undo" }
+///         }
+///         Button {
+///             onpress: move |_| {
+///                 let svg = controller.to_svg(400., 300.);
+///                 std::fs::write("./signature.svg", svg).ok();
+///             },
+///             label { "Export" }
+///         }
+///     )
+/// }
+/// ```
+#[allow(non_snake_case)]
+pub fn DrawingPad(
+    DrawingPadProps {
+        controller,
+        stroke_color,
+        stroke_width,
+        width,
+        height,
+        onstroke,
+        theme,
+    }: DrawingPadProps,
+) -> Element {
+    let theme = use_applied_theme!(&theme, drawing_pad);
+    let mut controller = controller.unwrap_or_else(use_drawing_pad);
+    let mut current = use_signal::<Option<Stroke>>(|| None);
+    let (reference, layout) = use_node_signal();
+    let platform = use_platform();
+
+    let stroke_color = stroke_color.unwrap_or_else(|| theme.stroke_color.to_string());
+
+    let canvas = use_canvas(move || {
+        let mut strokes = controller.strokes.read().clone();
+        strokes.extend(current.read().clone());
+        platform.invalidate_drawing_area(layout.peek().area);
+        platform.request_animation_frame();
+        move |ctx: &mut CanvasRunnerContext| {
+            ctx.canvas.save();
+            ctx.canvas.clip_rect(
+                Rect::new(
+                    ctx.area.min_x(),
+                    ctx.area.min_y(),
+                    ctx.area.max_x(),
+                    ctx.area.max_y(),
+                ),
+                ClipOp::Intersect,
+                true,
+            );
+            for stroke in &strokes {
+                stroke.draw(
+                    ctx.canvas,
+                    (ctx.area.min_x(), ctx.area.min_y()),
+                    ctx.scale_factor,
+                );
+            }
+            ctx.canvas.restore();
+        }
+    });
+
+    let point = |e: &PointerEvent| {
+        let coordinates = e.get_element_coordinates();
+        let pressure = match e.get_pointer_type() {
+            PointerType::Touch {
+                force: Some(force), ..
+            } => force.normalized() as f32,
+            _ => 1.,
+        };
+        DrawingPoint {
+            x: coordinates.x as f32,
+            y: coordinates.y as f32,
+            pressure,
+        }
+    };
+
+    let onpointerdown = move |e: PointerEvent| {
+        e.stop_propagation();
+        current.set(Some(Stroke {
+            points: vec![point(&e)],
+            color: stroke_color.clone(),
+            width: stroke_width,
+        }));
+    };
+
+    let onpointerover = move |e: PointerEvent| {
+        let point = point(&e);
+        let is_far_enough = current.peek().as_ref().is_some_and(|stroke| {
+            stroke
+                .points
+                .last()
+                .is_none_or(|last| last.distance_to(&point) >= MIN_POINT_DISTANCE)
+        });
+        if is_far_enough {
+            if let Some(stroke) = current.write().as_mut() {
+                stroke.points.push(point);
+            }
+        }
+    };
+
+    let onglobalpointerup = move |_: PointerEvent| {
+        if current.peek().is_none() {
+            return;
+        }
+        let Some(stroke) = current.write().take() else {
+            return;
+        };
+        controller.strokes.write().push(stroke.clone());
+        if let Some(onstroke) = &onstroke {
+            onstroke.call(stroke);
+        }
+    };
+
+    rsx!(rect {
+        reference,
+        canvas_reference: canvas.attribute(),
+        width: "{width}",
+        height: "{height}",
+        background: "{theme.background}",
+        onpointerdown,
+        onpointerover,
+        onglobalpointerup,
+    })
+}
+
+#[cfg(test)]
+mod test {
+    use freya::prelude::*;
+    use freya_testing::prelude::*;
+
+    fn stroke(points: &[(f32, f32)]) -> Stroke {
+        Stroke {
+            points: points
+                .iter()
+                .map(|(x, y)| DrawingPoint {
+                    x: *x,
+                    y: *y,
+                    pressure: 1.,
+                })
+                .collect(),
+            color: "red".to_string(),
+            width: 2.,
+        }
+    }
+
+    #[test]
+    fn stroke_svg_path() {
+        assert_eq!(
+            stroke(&[(0., 0.)]).to_svg_path(),
+            r##"<path d="M0 0 L0 0" fill="none" stroke="#ff0000" stroke-opacity="1" stroke-width="2" stroke-linecap="round" stroke-linejoin="round"/>"##
+        );
+
+        // Curves between the midpoints of the points
+        let stroke = stroke(&[(0., 0.), (10., 0.), (10., 10.), (20., 10.)]);
+        assert_eq!(stroke.segments().len(), 3);
+        assert!(stroke
+            .to_svg_path()
+            .starts_with(r#"<path d="M0 0 Q10 0 10 5 Q10 10 15 10 L20 10""#));
+    }
+
+    #[tokio::test]
+    pub async fn drawing_pad() {
+        fn drawing_pad_app() -> Element {
+            let mut controller = use_drawing_pad();
+            let mut last_stroke = use_signal(|| 0);
+
+            rsx!(
+                DrawingPad {
+                    controller,
+                    height: "200",
+                    onstroke: move |stroke: Stroke| last_stroke.set(stroke.points.len()),
+                }
+                rect {
+                    width: "100",
+                    height: "50",
+                    onclick: move |_| {
+                        controller.undo();
+                    },
+                    label {
+                        "{controller.strokes().read().len()} {last_stroke}"
+                    }
+                }
+            )
+        }
+
+        let mut utils = launch_test(drawing_pad_app);
+        utils.wait_for_update().await;
+        let label = utils.root().get(1).get(0);
+        assert_eq!(label.get(0).text(), Some("0 0"));
+
+        utils.push_event(TestEvent::Mouse {
+            name: EventName::MouseDown,
+            cursor: (10., 10.).into(),
+            button: Some(MouseButton::Left),
+        });
+        utils.wait_for_update().await;
+        utils.move_cursor((50., 10.)).await;
+        utils.move_cursor((50.5, 10.)).await;
+        utils.move_cursor((50., 50.)).await;
+        utils.push_event(TestEvent::Mouse {
+            name: EventName::MouseUp,
+            cursor: (50., 50.).into(),
+            button: Some(MouseButton::Left),
+        });
+        utils.wait_for_update().await;
+
+        // Points too close to the previous one are skipped
+        assert_eq!(label.get(0).text(), Some("1 3"));
+
+        // Undo
+        utils.click_cursor((10., 210.)).await;
+        assert_eq!(label.get(0).text(), Some("0 3"));
+    }
+}
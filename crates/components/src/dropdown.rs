@@ -1,4 +1,12 @@
-use std::fmt::Display;
+use std::{
+    cell::RefCell,
+    fmt::Display,
+    rc::Rc,
+    time::{
+        Duration,
+        Instant,
+    },
+};
 
 use dioxus::prelude::*;
 use freya_core::{
@@ -14,6 +22,7 @@ use freya_elements::{
     },
 };
 use freya_hooks::{
+    fuzzy_filter,
     theme_with,
     use_applied_theme,
     use_focus,
@@ -63,7 +72,7 @@ pub fn DropdownItem<T>(
     }: DropdownItemProps<T>,
 ) -> Element
 where
-    T: Clone + PartialEq + 'static,
+    T: Clone + PartialEq + Display + 'static,
 {
     let selected = use_context::<Signal<T>>();
     let theme = use_applied_theme!(&theme, dropdown_item);
@@ -74,6 +83,9 @@ where
 
     let a11y_id = focus.attribute();
     let a11y_member_of = UseFocus::attribute_for_id(dropdown_group.group_id);
+
+    // Keep the label up to date for the type-ahead of the Dropdown
+    dropdown_group.set_item(focus.id(), value.to_string());
     let is_selected = *selected.read() == value;
 
     let DropdownItemTheme {
@@ -96,9 +108,13 @@ where
         format!("1 inner {border_fill}")
     };
 
-    use_drop(move || {
-        if *status.peek() == DropdownItemStatus::Hovering {
-            platform.set_cursor(CursorIcon::default());
+    use_drop({
+        let dropdown_group = dropdown_group.clone();
+        move || {
+            dropdown_group.remove_item(focus.id());
+            if *status.peek() == DropdownItemStatus::Hovering {
+                platform.set_cursor(CursorIcon::default());
+            }
         }
     });
 
@@ -171,13 +187,43 @@ pub enum DropdownStatus {
     Hovering,
 }
 
+/// How long the typed characters are kept for the type-ahead.
+const TYPE_AHEAD_TIMEOUT: Duration = Duration::from_secs(1);
+
 #[derive(Clone)]
 struct DropdownGroup {
     group_id: AccessibilityId,
+    /// Labels of the items, used to focus them by typing.
+    items: Rc<RefCell<Vec<(AccessibilityId, String)>>>,
+}
+
+impl DropdownGroup {
+    fn set_item(&self, id: AccessibilityId, label: String) {
+        let mut items = self.items.borrow_mut();
+        match items.iter_mut().find(|(item_id, _)| *item_id == id) {
+            Some(item) => item.1 = label,
+            None => items.push((id, label)),
+        }
+    }
+
+    fn remove_item(&self, id: AccessibilityId) {
+        self.items
+            .borrow_mut()
+            .retain(|(item_id, _)| *item_id != id);
+    }
+
+    /// The item that best matches the typed text.
+    fn find_item(&self, typed: &str) -> Option<AccessibilityId> {
+        let items = self.items.borrow();
+        let matches = fuzzy_filter(typed, items.iter(), |(_, label)| label.as_str());
+        matches.first().map(|((id, _), _)| *id)
+    }
 }
 
 /// Select from multiple options, use alongside [`DropdownItem`].
 ///
+/// While opened, typing focuses the item that best matches the typed text.
+///
 /// # Styling
 /// Inherits the [`DropdownTheme`](freya_hooks::DropdownTheme) theme.
 ///
@@ -231,9 +277,11 @@ where
     let mut opened = use_signal(|| false);
     let platform = use_platform();
 
-    use_context_provider(|| DropdownGroup {
+    let dropdown_group = use_context_provider(|| DropdownGroup {
         group_id: focus.id(),
+        items: Rc::default(),
     });
+    let mut typed = use_signal(|| (String::new(), Instant::now()));
 
     let is_opened = *opened.read();
     let is_focused = focus.is_focused();
@@ -283,6 +331,22 @@ where
         }
     };
 
+    // Focus the item that best matches the typed text
+    let ontypeahead = move |e: KeyboardEvent| {
+        let Key::Character(ch) = &e.key else {
+            return;
+        };
+        let mut typed = typed.write();
+        if typed.1.elapsed() > TYPE_AHEAD_TIMEOUT {
+            typed.0.clear();
+        }
+        typed.0.push_str(ch);
+        typed.1 = Instant::now();
+        if let Some(id) = dropdown_group.find_item(&typed.0) {
+            UseFocus::focus_id(id);
+        }
+    };
+
     let onmouseenter = move |_| {
         platform.set_cursor(CursorIcon::Pointer);
         status.set(DropdownStatus::Hovering);
@@ -357,7 +421,7 @@ where
                         width: "100v",
                         rect {
                             onglobalclick,
-                            onglobalkeydown,
+                            onglobalkeydown: ontypeahead,
                             layer: "-1000",
                             margin: "{margin}",
                             border: "1 inner {border_fill}",
@@ -574,4 +638,66 @@ mod test {
         // The second option was selected
         assert_eq!(label.get(0).text(), Some("Value B"));
     }
+
+    #[tokio::test]
+    pub async fn dropdown_type_ahead() {
+        fn dropdown_type_ahead_app() -> Element {
+            let values = use_hook(|| {
+                vec![
+                    "Apple".to_string(),
+                    "Banana".to_string(),
+                    "Blueberry".to_string(),
+                ]
+            });
+            let mut selected_dropdown = use_signal(|| "Apple".to_string());
+
+            rsx!(
+                Dropdown {
+                    value: selected_dropdown.read().clone(),
+                    for ch in values {
+                        DropdownItem {
+                            value: ch.clone(),
+                            onpress: {
+                                to_owned![ch];
+                                move |_| selected_dropdown.set(ch.clone())
+                            },
+                            label { "{ch}" }
+                        }
+                    }
+                }
+            )
+        }
+
+        let mut utils = launch_test(dropdown_type_ahead_app);
+        let root = utils.root();
+        let label = root.get(0).get(0).get(0);
+        utils.wait_for_update().await;
+
+        // Open the dropdown
+        utils.click_cursor((15., 15.)).await;
+        utils.wait_for_update().await;
+
+        // Type to focus the best matching item
+        for ch in ["b", "l"] {
+            utils.push_event(TestEvent::Keyboard {
+                name: EventName::KeyDown,
+                key: Key::Character(ch.to_string()),
+                code: Code::Unidentified,
+                modifiers: Modifiers::default(),
+            });
+            utils.wait_for_update().await;
+            utils.wait_for_update().await;
+        }
+
+        utils.push_event(TestEvent::Keyboard {
+            name: EventName::KeyDown,
+            key: Key::Enter,
+            code: Code::Enter,
+            modifiers: Modifiers::default(),
+        });
+        utils.wait_for_update().await;
+        utils.wait_for_update().await;
+
+        assert_eq!(label.get(0).text(), Some("Blueberry"));
+    }
 }
@@ -0,0 +1,595 @@
+use dioxus::prelude::*;
+use freya_elements::{
+    self as dioxus_elements,
+    events::{
+        keyboard::Key,
+        KeyboardEvent,
+        MouseEvent,
+        WheelEvent,
+    },
+};
+use freya_hooks::{
+    use_applied_theme,
+    use_node_signal,
+    GanttThemeWith,
+};
+
+use crate::get_render_range;
+
+/// How much the zoom changes for every pixel scrolled with the wheel.
+const WHEEL_ZOOM_SPEED: f32 = 0.002;
+/// Least distance in pixels between two ticks of the time axis.
+const MIN_TICK_DISTANCE: f32 = 60.;
+/// Width of the handles to resize the bars.
+const HANDLE_WIDTH: f32 = 6.;
+/// Height of the time axis.
+const AXIS_HEIGHT: f32 = 30.;
+
+/// Task displayed as a bar in a [`Gantt`] chart.
+///
+/// Times are in any unit, e.g. days since some date, as long as it's the same for the whole chart.
+#[derive(Debug, Clone, PartialEq)]
+pub struct GanttTask {
+    pub name: String,
+    pub start: f64,
+    pub end: f64,
+    /// Color of the bar, the theme one if `None`.
+    pub color: Option<String>,
+}
+
+impl GanttTask {
+    pub fn new(name: impl Into<String>, start: f64, end: f64) -> Self {
+        Self {
+            name: name.into(),
+            start,
+            end,
+            color: None,
+        }
+    }
+
+    pub fn with_color(mut self, color: impl Into<String>) -> Self {
+        self.color = Some(color.into());
+        self
+    }
+}
+
+/// New times of a [`GanttTask`] moved or resized by the user.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct GanttTaskChange {
+    /// Index of the task.
+    pub index: usize,
+    pub start: f64,
+    pub end: f64,
+}
+
+/// What is being done with the bar being dragged.
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum DragMode {
+    Move,
+    ResizeStart,
+    ResizeEnd,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+struct Drag {
+    index: usize,
+    mode: DragMode,
+    /// Cursor position, in pixels, where the drag started.
+    origin: f64,
+    start: f64,
+    end: f64,
+}
+
+/// Round to the closest multiple of `snap`, if any.
+fn snap_time(time: f64, snap: f64) -> f64 {
+    if snap > 0. {
+        (time / snap).round() * snap
+    } else {
+        time
+    }
+}
+
+/// Times of a task after being dragged by `delta` units.
+/// Resizing never makes it shorter than `snap`, or a hundredth of a unit if there is no snap.
+fn apply_drag(mode: DragMode, start: f64, end: f64, delta: f64, snap: f64) -> (f64, f64) {
+    let min_duration = if snap > 0. { snap } else { 0.01 };
+    match mode {
+        DragMode::Move => {
+            let start_moved = snap_time(start + delta, snap);
+            (start_moved, start_moved + end - start)
+        }
+        DragMode::ResizeStart => (snap_time(start + delta, snap).min(end - min_duration), end),
+        DragMode::ResizeEnd => (
+            start,
+            snap_time(end + delta, snap).max(start + min_duration),
+        ),
+    }
+}
+
+/// Distance between ticks of the time axis: the smallest multiple of `tick` of 1, 2 or 5
+/// times a power of ten so the labels don't overlap.
+fn tick_step(tick: f64, unit_width: f32) -> f64 {
+    let min_step = MIN_TICK_DISTANCE as f64 / unit_width as f64;
+    let mut magnitude = tick;
+    loop {
+        for multiplier in [1., 2., 5.] {
+            let step = magnitude * multiplier;
+            if step >= min_step {
+                return step;
+            }
+        }
+        magnitude *= 10.;
+    }
+}
+
+/// Properties for the [`Gantt`] component.
+#[derive(Props, Clone, PartialEq)]
+pub struct GanttProps {
+    /// Tasks to display, one per row.
+    pub tasks: ReadOnlySignal<Vec<GanttTask>>,
+    /// Handler called when a task is moved or resized by dragging its bar or its edges.
+    pub onchange: Option<EventHandler<GanttTaskChange>>,
+    /// Time of the today marker.
+    pub today: Option<f64>,
+    /// Time displayed at the start when mounted. Default `0`.
+    #[props(default = 0.)]
+    pub initial_time: f64,
+    /// Width in pixels of a time unit when mounted, changed by zooming. Default `40`.
+    #[props(default = 40.)]
+    pub unit_width: f32,
+    /// Smallest distance between two ticks of the time axis. Default `1`.
+    #[props(default = 1.)]
+    pub tick: f64,
+    /// Round the times of dragged tasks to multiples of this. Default `1`, `0` to disable.
+    #[props(default = 1.)]
+    pub snap: f64,
+    /// Format the labels of the time axis.
+    pub format_time: Option<Callback<f64, String>>,
+    /// Height of every row. Default `32`.
+    #[props(default = 32.)]
+    pub row_height: f32,
+    /// Width of the column with the names of the tasks. Default `150`.
+    #[props(default = 150.)]
+    pub names_width: f32,
+    /// Width of the Gantt. Default `fill`.
+    #[props(default = "fill".into())]
+    pub width: String,
+    /// Height of the Gantt. Default `fill`.
+    #[props(default = "fill".into())]
+    pub height: String,
+    /// Theme override.
+    pub theme: Option<GanttThemeWith>,
+}
+
+/// Timeline of tasks where they can be moved and resized by dragging their bars.
+///
+/// Scroll the rows with the wheel, move in time with the horizontal wheel or Shift,
+/// and zoom around the cursor while holding Control. Only the visible rows are rendered.
+///
+/// # Example
+///
+/// ```no_run
+/// # use freya::prelude::*;
+/// fn app() -> Element {
+///     let mut tasks = use_signal(|| {
+///         vec![
+///             GanttTask::new("Design", 0., 5.),
+///             GanttTask::new("Build", 4., 14.).with_color("rgb(90, 170, 90)"),
+///             GanttTask::new("Release", 14., 15.),
+///         ]
+///     });
+///
+///     rsx!(Gantt {
+///         tasks,
+///         today: 3.,
+///         format_time: |day| format!("Day {day}"),
+///         onchange: move |change: GanttTaskChange| {
+///             let task = &mut tasks.write()[change.index];
+///             task.start = change.start;
+///             task.end = change.end;
+///         }
+///     })
+/// }
+/// ```
+#[allow(non_snake_case)]
+pub fn Gantt(
+    GanttProps {
+        tasks,
+        onchange,
+        today,
+        initial_time,
+        unit_width,
+        tick,
+        snap,
+        format_time,
+        row_height,
+        names_width,
+        width,
+        height,
+        theme,
+    }: GanttProps,
+) -> Element {
+    let theme = use_applied_theme!(&theme, gantt);
+    let (reference, layout) = use_node_signal();
+    // Time at the start of the chart
+    let mut time = use_signal(|| initial_time);
+    let mut unit_width = use_signal(|| unit_width.max(0.01));
+    let mut scroll_y = use_signal(|| 0f32);
+    let mut cursor_x = use_signal(|| 0.);
+    let mut clicking_control = use_signal(|| false);
+    let mut clicking_shift = use_signal(|| false);
+    let mut drag = use_signal::<Option<Drag>>(|| None);
+    // Times of the task being dragged
+    let mut preview = use_signal::<Option<(usize, f64, f64)>>(|| None);
+
+    let tasks_length = tasks.read().len();
+    let viewport = layout.read().area;
+    let content_height = row_height * tasks_length as f32;
+
+    let onwheel = move |e: WheelEvent| {
+        e.stop_propagation();
+        let (delta_x, delta_y) = if *clicking_shift.peek() {
+            (e.get_delta_y(), e.get_delta_x())
+        } else {
+            (e.get_delta_x(), e.get_delta_y())
+        };
+
+        if *clicking_control.peek() {
+            // Zoom keeping the time under the cursor in place
+            let cursor_time = *time.peek() + *cursor_x.peek() / *unit_width.peek() as f64;
+            let new_unit_width = (*unit_width.peek()
+                * 2f32.powf(delta_y as f32 * WHEEL_ZOOM_SPEED))
+            .clamp(0.01, 10_000.);
+            unit_width.set(new_unit_width);
+            time.set(cursor_time - *cursor_x.peek() / new_unit_width as f64);
+        } else {
+            *time.write() -= delta_x / *unit_width.peek() as f64;
+            let max_scroll = (content_height - layout.peek().area.height()).max(0.);
+            scroll_y.set((*scroll_y.peek() - delta_y as f32).clamp(0., max_scroll));
+        }
+    };
+
+    let onmousemove = move |e: MouseEvent| {
+        cursor_x.set(e.get_element_coordinates().x);
+    };
+
+    let onglobalmousemove = move |e: MouseEvent| {
+        let Some(drag) = *drag.peek() else {
+            return;
+        };
+        let delta = (e.get_screen_coordinates().x - drag.origin) / *unit_width.peek() as f64;
+        let (start, end) = apply_drag(drag.mode, drag.start, drag.end, delta, snap);
+        preview.set(Some((drag.index, start, end)));
+    };
+
+    let onglobalclick = move |_: MouseEvent| {
+        if drag.peek().is_none() {
+            return;
+        }
+        drag.set(None);
+        let changed = *preview.peek();
+        preview.set(None);
+        if let Some((index, start, end)) = changed {
+            if let Some(onchange) = onchange {
+                onchange.call(GanttTaskChange { index, start, end });
+            }
+        }
+    };
+
+    let onglobalkeydown = move |e: KeyboardEvent| match e.key {
+        Key::Control => clicking_control.set(true),
+        Key::Shift => clicking_shift.set(true),
+        _ => {}
+    };
+
+    let onglobalkeyup = move |e: KeyboardEvent| match e.key {
+        Key::Control => clicking_control.set(false),
+        Key::Shift => clicking_shift.set(false),
+        _ => {}
+    };
+
+    let start_drag = move |index: usize, mode: DragMode| {
+        move |e: MouseEvent| {
+            e.stop_propagation();
+            let tasks = tasks.peek();
+            let Some(task) = tasks.get(index) else {
+                return;
+            };
+            drag.set(Some(Drag {
+                index,
+                mode,
+                origin: e.get_screen_coordinates().x,
+                start: task.start,
+                end: task.end,
+            }));
+        }
+    };
+
+    let (time, unit_width, scroll_y) = (time(), unit_width(), scroll_y());
+    let x_at = move |at: f64| ((at - time) * unit_width as f64) as f32;
+
+    // Ticks of the time axis
+    let step = tick_step(tick.max(f64::EPSILON), unit_width);
+    let visible_duration = viewport.width() as f64 / unit_width as f64;
+    let ticks = ((time / step).floor() as i64..=((time + visible_duration) / step).ceil() as i64)
+        .map(|i| {
+            let at = i as f64 * step;
+            let label = if let Some(format_time) = format_time {
+                format_time.call(at)
+            } else {
+                format!("{at}")
+            };
+            (i, x_at(at), label)
+        })
+        .collect::<Vec<_>>();
+
+    let rows = get_render_range(
+        viewport.height(),
+        -scroll_y,
+        row_height,
+        tasks_length as f32,
+    );
+    let rows_offset = rows.start as f32 * row_height - scroll_y;
+    let preview = preview();
+    let tasks = tasks.read();
+    let visible_tasks = rows.clone().filter_map(|index| {
+        let task = tasks.get(index)?;
+        let (start, end) = match preview {
+            Some((preview_index, start, end)) if preview_index == index => (start, end),
+            _ => (task.start, task.end),
+        };
+        let background = task
+            .color
+            .clone()
+            .unwrap_or_else(|| theme.bar_background.to_string());
+        let row_background = if index % 2 == 0 {
+            theme.background.to_string()
+        } else {
+            theme.row_background.to_string()
+        };
+        Some((
+            index,
+            &task.name,
+            x_at(start),
+            x_at(end) - x_at(start),
+            background,
+            row_background,
+        ))
+    });
+    let visible_tasks = visible_tasks.collect::<Vec<_>>();
+    let bar_height = row_height * 0.6;
+    let bar_top = (row_height - bar_height) / 2.;
+    let today_x = today.map(x_at);
+
+    rsx!(
+        rect {
+            width: "{width}",
+            height: "{height}",
+            background: "{theme.background}",
+            color: "{theme.font_theme.color}",
+            onglobalmousemove,
+            onglobalclick,
+            onglobalkeydown,
+            onglobalkeyup,
+            rect {
+                direction: "horizontal",
+                width: "fill",
+                height: "{AXIS_HEIGHT}",
+                border: "0 0 1 0 inner {theme.grid_color}",
+                rect {
+                    width: "{names_width}",
+                    height: "fill",
+                }
+                rect {
+                    width: "fill",
+                    height: "fill",
+                    overflow: "clip",
+                    for (i, x, label) in ticks.iter() {
+                        rect {
+                            key: "{i}",
+                            position: "absolute",
+                            position_top: "0",
+                            position_left: "{x}",
+                            width: "1",
+                            height: "fill",
+                            background: "{theme.grid_color}",
+                        }
+                        label {
+                            key: "{i}-label",
+                            position: "absolute",
+                            position_top: "8",
+                            position_left: "{x + 4.}",
+                            font_size: "12",
+                            max_lines: "1",
+                            "{label}"
+                        }
+                    }
+                }
+            }
+            rect {
+                direction: "horizontal",
+                width: "fill",
+                height: "fill",
+                onwheel,
+                rect {
+                    width: "{names_width}",
+                    height: "fill",
+                    overflow: "clip",
+                    offset_y: "{rows_offset}",
+                    for (index, name, _, _, _, row_background) in visible_tasks.iter() {
+                        rect {
+                            key: "{index}",
+                            width: "fill",
+                            height: "{row_height}",
+                            padding: "0 8",
+                            main_align: "center",
+                            background: "{row_background}",
+                            label {
+                                max_lines: "1",
+                                text_overflow: "ellipsis",
+                                "{name}"
+                            }
+                        }
+                    }
+                }
+                rect {
+                    reference,
+                    width: "fill",
+                    height: "fill",
+                    overflow: "clip",
+                    onmousemove,
+                    rect {
+                        width: "fill",
+                        height: "fill",
+                        offset_y: "{rows_offset}",
+                        for (index, _, left, bar_width, bar_bg, row_bg) in visible_tasks.iter() {
+                            rect {
+                                key: "{index}",
+                                width: "fill",
+                                height: "{row_height}",
+                                background: "{row_bg}",
+                                rect {
+                                    position: "absolute",
+                                    position_top: "{bar_top}",
+                                    position_left: "{left}",
+                                    width: "{bar_width.max(HANDLE_WIDTH * 2.)}",
+                                    height: "{bar_height}",
+                                    corner_radius: "4",
+                                    background: "{bar_bg}",
+                                    direction: "horizontal",
+                                    cursor: "grab",
+                                    onmousedown: start_drag(*index, DragMode::Move),
+                                    rect {
+                                        width: "{HANDLE_WIDTH}",
+                                        height: "fill",
+                                        cursor: "ew-resize",
+                                        onmousedown: start_drag(*index, DragMode::ResizeStart),
+                                    }
+                                    rect {
+                                        width: "fill",
+                                        height: "fill",
+                                    }
+                                    rect {
+                                        width: "{HANDLE_WIDTH}",
+                                        height: "fill",
+                                        cursor: "ew-resize",
+                                        onmousedown: start_drag(*index, DragMode::ResizeEnd),
+                                    }
+                                }
+                            }
+                        }
+                    }
+                    if let Some(today_x) = today_x {
+                        rect {
+                            position: "absolute",
+                            position_top: "0",
+                            position_left: "{today_x - 1.}",
+                            width: "2",
+                            height: "fill",
+                            background: "{theme.today_color}",
+                        }
+                    }
+                }
+            }
+        }
+    )
+}
+
+#[cfg(test)]
+mod test {
+    use freya::prelude::*;
+    use freya_testing::prelude::*;
+
+    use super::{
+        apply_drag,
+        tick_step,
+        DragMode,
+    };
+
+    #[test]
+    fn drag() {
+        assert_eq!(apply_drag(DragMode::Move, 2., 5., 1.4, 1.), (3., 6.));
+        assert_eq!(apply_drag(DragMode::Move, 2., 5., 1.4, 0.), (3.4, 6.4));
+        assert_eq!(apply_drag(DragMode::ResizeEnd, 2., 5., 2.6, 1.), (2., 8.));
+        // Never shorter than the snap
+        assert_eq!(apply_drag(DragMode::ResizeStart, 2., 5., 10., 1.), (4., 5.));
+        assert_eq!(
+            apply_drag(DragMode::ResizeEnd, 2., 5., -10., 0.5),
+            (2., 2.5)
+        );
+    }
+
+    #[test]
+    fn ticks() {
+        assert_eq!(tick_step(1., 60.), 1.);
+        assert_eq!(tick_step(1., 40.), 2.);
+        assert_eq!(tick_step(1., 15.), 5.);
+        assert_eq!(tick_step(1., 1.), 100.);
+        assert_eq!(tick_step(7., 5.), 14.);
+    }
+
+    #[tokio::test]
+    pub async fn gantt() {
+        fn gantt_app() -> Element {
+            let mut tasks = use_signal(|| {
+                (0..100)
+                    .map(|i| GanttTask::new(format!("Task {i}"), i as f64, i as f64 + 2.))
+                    .collect::<Vec<_>>()
+            });
+
+            rsx!(Gantt {
+                tasks,
+                names_width: 100.,
+                onchange: move |change: GanttTaskChange| {
+                    let task = &mut tasks.write()[change.index];
+                    task.start = change.start;
+                    task.end = change.end;
+                }
+            })
+        }
+
+        let mut utils = launch_test(gantt_app);
+        utils.wait_for_update().await;
+        utils.wait_for_update().await;
+
+        // (500 - 30) / 32 + 1 rows are rendered
+        let body = utils.root().get(0).get(1);
+        let names = body.get(0);
+        assert_eq!(names.children_ids().len(), 15);
+        assert_eq!(names.get(0).get(0).get(0).text(), Some("Task 0"));
+
+        // Move the first task 2 units to the right
+        let bar = body.get(1).get(0).get(0).get(0);
+        assert_eq!(bar.layout().unwrap().area.min_x(), 100.);
+        assert_eq!(bar.layout().unwrap().area.width(), 80.);
+        utils.push_event(TestEvent::Mouse {
+            name: EventName::MouseDown,
+            cursor: (140., 46.).into(),
+            button: Some(MouseButton::Left),
+        });
+        utils.wait_for_update().await;
+        utils.move_cursor((220., 46.)).await;
+        utils.push_event(TestEvent::Mouse {
+            name: EventName::MouseUp,
+            cursor: (220., 46.).into(),
+            button: Some(MouseButton::Left),
+        });
+        utils.wait_for_update().await;
+        utils.wait_for_update().await;
+
+        let bar = utils.root().get(0).get(1).get(1).get(0).get(0).get(0);
+        assert_eq!(bar.layout().unwrap().area.min_x(), 180.);
+        assert_eq!(bar.layout().unwrap().area.width(), 80.);
+
+        // Scroll the rows
+        utils.push_event(TestEvent::Wheel {
+            name: EventName::Wheel,
+            scroll: (0., -320.).into(),
+            cursor: (50., 100.).into(),
+        });
+        utils.wait_for_update().await;
+
+        let names = utils.root().get(0).get(1).get(0);
+        assert_eq!(names.get(0).get(0).get(0).text(), Some("Task 10"));
+    }
+}
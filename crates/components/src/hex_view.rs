@@ -0,0 +1,311 @@
+use std::ops::Range;
+
+use dioxus::prelude::*;
+use freya_elements::{
+    self as dioxus_elements,
+    events::MouseEvent,
+};
+use freya_hooks::{
+    use_applied_theme,
+    HexViewTheme,
+    HexViewThemeWith,
+};
+
+use crate::{
+    use_scroll_controller,
+    ScrollConfig,
+    VirtualScrollView,
+};
+
+/// Char displayed in the ASCII column for a byte.
+fn ascii_char(byte: u8) -> char {
+    if byte.is_ascii_graphic() || byte == b' ' {
+        byte as char
+    } else {
+        '.'
+    }
+}
+
+/// Byte rendered by a [`HexView`].
+struct HexCell {
+    offset: usize,
+    hex: String,
+    char: char,
+    background: String,
+    color: String,
+}
+
+/// Sorted range of bytes between the two ends of a selection.
+fn selection_range((anchor, focus): (usize, usize)) -> Range<usize> {
+    anchor.min(focus)..anchor.max(focus) + 1
+}
+
+/// Properties for the [`HexView`] component.
+#[derive(Props, Clone, PartialEq)]
+pub struct HexViewProps {
+    /// Bytes to display.
+    pub data: ReadOnlySignal<Vec<u8>>,
+    /// Previous version of the bytes, the ones that changed since then are highlighted.
+    pub original: Option<ReadOnlySignal<Vec<u8>>>,
+    /// Scroll to the row of this offset and select its byte when it changes.
+    pub goto: Option<usize>,
+    /// Amount of bytes of every row. Default `16`.
+    #[props(default = 16)]
+    pub bytes_per_row: usize,
+    /// Width of the HexView. Default `fill`.
+    #[props(default = "fill".into())]
+    pub width: String,
+    /// Height of the HexView. Default `fill`.
+    #[props(default = "fill".into())]
+    pub height: String,
+    /// Height of every row. Default `20`.
+    #[props(default = 20.0)]
+    pub line_height: f32,
+    /// Font size of the text. Default `14`.
+    #[props(default = "14".into())]
+    pub font_size: String,
+    /// Handler called with the range of selected bytes every time the selection changes.
+    pub onselect: Option<EventHandler<Range<usize>>>,
+    /// Theme override.
+    pub theme: Option<HexViewThemeWith>,
+}
+
+/// Virtualized view of binary data, with offset, hexadecimal and ASCII columns.
+///
+/// Bytes are selected by clicking or dragging over them in either column.
+///
+/// # Example
+///
+/// ```no_run
+/// # use freya::prelude::*;
+/// fn app() -> Element {
+///     let data = use_signal(|| std::fs::read("Cargo.toml").unwrap_or_default());
+///
+///     rsx!(HexView {
+///         data,
+///         onselect: |range| println!("Selected {range:?}"),
+///     })
+/// }
+/// ```
+#[allow(non_snake_case)]
+pub fn HexView(
+    HexViewProps {
+        data,
+        original,
+        goto,
+        bytes_per_row,
+        width,
+        height,
+        line_height,
+        font_size,
+        onselect,
+        theme,
+    }: HexViewProps,
+) -> Element {
+    let theme = use_applied_theme!(&theme, hex_view);
+    let mut scroll_controller = use_scroll_controller(ScrollConfig::default);
+    let mut selection = use_signal::<Option<(usize, usize)>>(|| None);
+    let mut selecting = use_signal(|| false);
+    let bytes_per_row = bytes_per_row.max(1);
+
+    use_effect(use_reactive!(|goto| {
+        if let Some(offset) = goto {
+            let row = offset / bytes_per_row;
+            scroll_controller.scroll_to_y(-(row as f32 * line_height) as i32);
+            selection.set(Some((offset, offset)));
+        }
+    }));
+
+    let length = data.read().len().div_ceil(bytes_per_row);
+    let background = theme.background.clone();
+
+    let onglobalclick = move |_: MouseEvent| {
+        if *selecting.peek() {
+            selecting.set(false);
+            if let (Some(selection), Some(onselect)) = (*selection.peek(), onselect) {
+                onselect.call(selection_range(selection));
+            }
+        }
+    };
+
+    rsx!(
+        rect {
+            width: "{width}",
+            height: "{height}",
+            background: "{background}",
+            onglobalclick,
+            VirtualScrollView {
+                scroll_controller,
+                length,
+                item_size: line_height,
+                cache_elements: false,
+                builder_args: (line_height, font_size, theme, bytes_per_row),
+                builder: move |row: usize, args: &Option<(f32, String, HexViewTheme, usize)>| {
+                    let (line_height, font_size, theme, bytes_per_row) = args.as_ref().unwrap();
+                    let start = row * bytes_per_row;
+                    let selected = selection.read().map(selection_range);
+                    let original = original.as_ref().map(|original| original.read());
+                    let data = data.read();
+                    let end = (start + bytes_per_row).min(data.len());
+                    let bytes = data.get(start..end).unwrap_or_default();
+
+                    let cells = bytes.iter().enumerate().map(|(i, byte)| {
+                        let offset = start + i;
+                        let is_selected = selected
+                            .as_ref()
+                            .is_some_and(|range| range.contains(&offset));
+                        let is_changed = original
+                            .as_ref()
+                            .is_some_and(|original| original.get(offset) != Some(byte));
+                        HexCell {
+                            offset,
+                            hex: format!("{byte:02X}"),
+                            char: ascii_char(*byte),
+                            background: if is_selected {
+                                theme.selection_background.to_string()
+                            } else {
+                                "transparent".to_string()
+                            },
+                            color: if is_changed {
+                                theme.changed_color.to_string()
+                            } else {
+                                theme.font_theme.color.to_string()
+                            },
+                        }
+                    }).collect::<Vec<_>>();
+
+                    let onmousedown = move |offset: usize| {
+                        move |_: MouseEvent| {
+                            selection.set(Some((offset, offset)));
+                            selecting.set(true);
+                        }
+                    };
+                    let onmouseenter = move |offset: usize| {
+                        move |_: MouseEvent| {
+                            if *selecting.peek() {
+                                if let Some((anchor, _)) = *selection.peek() {
+                                    selection.set(Some((anchor, offset)));
+                                }
+                            }
+                        }
+                    };
+
+                    rsx!(
+                        rect {
+                            key: "{row}",
+                            width: "fill",
+                            height: "{line_height}",
+                            direction: "horizontal",
+                            cross_align: "center",
+                            spacing: "16",
+                            font_size: "{font_size}",
+                            font_family: "monospace",
+                            label {
+                                color: "{theme.offset_color}",
+                                "{start:08X}"
+                            }
+                            rect {
+                                direction: "horizontal",
+                                spacing: "4",
+                                for cell in cells.iter() {
+                                    rect {
+                                        key: "{cell.offset}",
+                                        background: "{cell.background}",
+                                        onmousedown: onmousedown(cell.offset),
+                                        onmouseenter: onmouseenter(cell.offset),
+                                        label {
+                                            color: "{cell.color}",
+                                            "{cell.hex}"
+                                        }
+                                    }
+                                }
+                            }
+                            rect {
+                                direction: "horizontal",
+                                for cell in cells.iter() {
+                                    rect {
+                                        key: "{cell.offset}",
+                                        background: "{cell.background}",
+                                        onmousedown: onmousedown(cell.offset),
+                                        onmouseenter: onmouseenter(cell.offset),
+                                        label {
+                                            color: "{cell.color}",
+                                            "{cell.char}"
+                                        }
+                                    }
+                                }
+                            }
+                        }
+                    )
+                }
+            }
+        }
+    )
+}
+
+#[cfg(test)]
+mod test {
+    use freya::prelude::*;
+    use freya_testing::prelude::*;
+
+    #[tokio::test]
+    pub async fn hex_view() {
+        fn hex_view_app() -> Element {
+            let data = use_signal(|| (0..40).map(|i| b'A' + i % 26).collect::<Vec<u8>>());
+            let original = use_signal(|| {
+                let mut original = data.peek().clone();
+                original[1] = 0;
+                original
+            });
+            let mut selected = use_signal(|| None);
+
+            rsx!(
+                HexView {
+                    height: "100",
+                    data,
+                    original: ReadOnlySignal::from(original),
+                    onselect: move |range| selected.set(Some(range)),
+                }
+                label {
+                    "{selected:?}"
+                }
+            )
+        }
+
+        let mut utils = launch_test(hex_view_app);
+        utils.wait_for_update().await;
+
+        let content = utils.root().get(0).get(0).get(0).get(0);
+        assert_eq!(content.children_ids().len(), 3);
+        let second_row = content.get(1);
+        assert_eq!(second_row.get(0).get(0).text(), Some("00000010"));
+        assert_eq!(second_row.get(1).get(0).get(0).get(0).text(), Some("51"));
+        assert_eq!(second_row.get(2).get(0).get(0).get(0).text(), Some("Q"));
+
+        let first_row = content.get(0);
+
+        // Select bytes by dragging over them
+        let from = first_row.get(1).get(2).layout().unwrap().area.center();
+        let to = second_row.get(2).get(1).layout().unwrap().area.center();
+        utils.push_event(TestEvent::Mouse {
+            name: EventName::MouseDown,
+            cursor: (from.x as f64, from.y as f64).into(),
+            button: Some(MouseButton::Left),
+        });
+        utils.wait_for_update().await;
+        utils.move_cursor((to.x as f64, to.y as f64)).await;
+        utils.push_event(TestEvent::Mouse {
+            name: EventName::MouseUp,
+            cursor: (to.x as f64, to.y as f64).into(),
+            button: Some(MouseButton::Left),
+        });
+        utils.wait_for_update().await;
+        assert_eq!(utils.root().get(1).get(0).text(), Some("Some(2..18)"));
+        let content = utils.root().get(0).get(0).get(0).get(0);
+        let first_row = content.get(0);
+        assert_ne!(
+            first_row.get(1).get(1).style().background,
+            first_row.get(1).get(2).style().background
+        );
+    }
+}
@@ -1,3 +1,5 @@
 mod use_form;
+mod use_window_manager;
 
 pub use use_form::*;
+pub use use_window_manager::*;
@@ -0,0 +1,98 @@
+// This hook is in freya-components instead of freya-hooks because it wraps the windows with the NativeContainer component.
+
+use dioxus::prelude::*;
+use freya_core::{
+    event_loop_messages::{
+        NewWindow,
+        WindowSettings,
+    },
+    platform::WindowId,
+};
+use freya_hooks::{
+    use_platform,
+    UsePlatform,
+};
+
+use crate::NativeContainer;
+
+type WindowComponent = fn() -> Element;
+
+#[derive(Props, Clone, PartialEq)]
+struct WindowRootProps {
+    app: WindowComponent,
+}
+
+#[allow(non_snake_case)]
+fn WindowRoot(props: WindowRootProps) -> Element {
+    #[allow(non_snake_case)]
+    let App = props.app;
+
+    rsx!(NativeContainer {
+        App {}
+    })
+}
+
+/// Manage the windows of the app, see [`use_window_manager`].
+#[derive(Clone, Copy, PartialEq)]
+pub struct UseWindowManager {
+    platform: UsePlatform,
+}
+
+impl UseWindowManager {
+    /// Open a new window running the given component in its own VirtualDOM.
+    ///
+    /// Every window has its own surface but they all share the same event loop and fonts.
+    pub fn open_window(&self, app: WindowComponent, settings: WindowSettings) {
+        self.platform.open_window(NewWindow {
+            vdom: Box::new(move || VirtualDom::new_with_props(WindowRoot, WindowRootProps { app })),
+            settings,
+        });
+    }
+
+    /// Close the window in which this component runs, closing the main window exits the app.
+    pub fn close_current_window(&self) {
+        self.platform.close_window();
+    }
+
+    /// ID of the window in which this component runs.
+    pub fn current_window(&self) -> Option<WindowId> {
+        self.platform.window_id()
+    }
+}
+
+/// Open and close windows, each one running its own VirtualDOM.
+///
+/// ```rust,no_run
+/// # use freya::prelude::*;
+/// fn app() -> Element {
+///     let window_manager = use_window_manager();
+///
+///     rsx!(
+///         Button {
+///             onpress: move |_| {
+///                 window_manager.open_window(
+///                     settings_window,
+///                     WindowSettings::default().with_title("Settings"),
+///                 )
+///             },
+///             label { "Open settings" }
+///         }
+///     )
+/// }
+///
+/// fn settings_window() -> Element {
+///     let window_manager = use_window_manager();
+///
+///     rsx!(
+///         Button {
+///             onpress: move |_| window_manager.close_current_window(),
+///             label { "Close" }
+///         }
+///     )
+/// }
+/// ```
+pub fn use_window_manager() -> UseWindowManager {
+    UseWindowManager {
+        platform: use_platform(),
+    }
+}
@@ -8,7 +8,10 @@ use std::{
 };
 
 use dioxus::prelude::*;
-use freya_core::platform::CursorIcon;
+use freya_core::{
+    platform::CursorIcon,
+    values::InputHint,
+};
 use freya_elements::{
     self as dioxus_elements,
     events::{
@@ -25,6 +28,7 @@ use freya_hooks::{
     EditableConfig,
     EditableEvent,
     EditableMode,
+    EditorDirection,
     InputTheme,
     InputThemeWith,
     TextEditor,
@@ -38,7 +42,9 @@ pub enum InputMode {
     /// The input text is shown
     #[default]
     Shown,
-    /// The input text is obfuscated with a character
+    /// The input text is obfuscated with a character.
+    ///
+    /// Hidden inputs are sensitive, their text can't be copied and is excluded from screen captures where supported.
     Hidden(char),
 }
 
@@ -111,6 +117,13 @@ pub struct InputProps {
     pub width: String,
     /// Handler for the `onfocuschange` function.
     pub onfocuschange: Option<EventHandler<bool>>,
+    /// Base direction of the text. Use [`EditorDirection::Rtl`] for Arabic or Hebrew text,
+    /// or [`EditorDirection::Auto`] to resolve it from the content. Default `EditorDirection::Ltr`.
+    #[props(default)]
+    pub text_direction: EditorDirection,
+    /// Kind of text expected, so the platform can show the right soft keyboard.
+    /// Defaults to [`InputHint::Password`] in [`InputMode::Hidden`] and [`InputHint::Text`] otherwise.
+    pub input_hint: Option<InputHint>,
 }
 
 /// Small box to edit text.
@@ -166,12 +179,24 @@ pub fn Input(
         onvalidate,
         width,
         onfocuschange,
+        text_direction,
+        input_hint,
     }: InputProps,
 ) -> Element {
     let platform = use_platform();
     let mut status = use_signal(InputStatus::default);
+    let sensitive = matches!(mode, InputMode::Hidden(_));
+    let input_hint = input_hint.unwrap_or(if sensitive {
+        InputHint::Password
+    } else {
+        InputHint::Text
+    });
     let mut editable = use_editable(
-        || EditableConfig::new(value.to_string()),
+        || {
+            EditableConfig::new(value.to_string())
+                .with_text_direction(text_direction)
+                .with_sensitive(sensitive)
+        },
         EditableMode::MultipleLinesSingleEditor,
     );
     let InputTheme {
@@ -203,6 +228,9 @@ pub fn Input(
         }
     });
 
+    // The mode can change after the editable was created, e.g. to show a password
+    use_effect(use_reactive!(|sensitive| editable.set_sensitive(sensitive)));
+
     use_effect(move || {
         if !focus.is_focused() {
             editable.editor_mut().write().clear_selection();
@@ -342,6 +370,11 @@ pub fn Input(
         (InputMode::Shown, _) => Cow::Borrowed(value.as_str()),
     };
 
+    let text_direction = match text_direction.resolve(&text) {
+        EditorDirection::Rtl => "rtl",
+        _ => "ltr",
+    };
+
     rsx!(
         rect {
             width,
@@ -357,6 +390,8 @@ pub fn Input(
             a11y_role: "text-input",
             a11y_auto_focus: "{auto_focus}",
             a11y_value: "{text}",
+            sensitive: "{sensitive}",
+            input_hint: "{input_hint}",
             onkeydown,
             onkeyup,
             overflow: "clip",
@@ -379,6 +414,8 @@ pub fn Input(
                     cursor_mode: "editable",
                     cursor_color: "{color}",
                     max_lines: "1",
+                    direction: text_direction,
+                    sensitive: "{sensitive}",
                     highlights,
                     text {
                         "{text}"
@@ -494,4 +531,63 @@ mod test {
         // Check that only "BC" was been written to the input.
         assert_eq!(text.get(0).text(), Some("ABC"));
     }
+
+    #[tokio::test]
+    pub async fn toggle_sensitive() {
+        fn input_app() -> Element {
+            let mut hidden = use_signal(|| true);
+            let mut value = use_signal(|| "hunter2".to_string());
+
+            rsx!(
+                rect {
+                    width: "100",
+                    height: "50",
+                    onclick: move |_| hidden.toggle(),
+                }
+                Input {
+                    value,
+                    mode: if hidden() { InputMode::new_password() } else { InputMode::Shown },
+                    onchange: move |new_value| {
+                        value.set(new_value);
+                    }
+                }
+            )
+        }
+
+        #[cfg(target_os = "macos")]
+        let modifiers = Modifiers::META;
+
+        #[cfg(not(target_os = "macos"))]
+        let modifiers = Modifiers::CONTROL;
+
+        let mut utils = launch_test(input_app);
+        utils.wait_for_update().await;
+
+        for (hidden, copied_text) in [(true, None), (false, Some("hunter2"))] {
+            if !hidden {
+                utils.click_cursor((5., 5.)).await;
+            }
+
+            // Focus the input, select all the text and copy it
+            utils.push_event(TestEvent::Mouse {
+                name: EventName::MouseDown,
+                cursor: (115., 75.).into(),
+                button: Some(MouseButton::Left),
+            });
+            utils.wait_for_update().await;
+            utils.wait_for_update().await;
+            for (key, code) in [("a", Code::KeyA), ("c", Code::KeyC)] {
+                utils.push_event(TestEvent::Keyboard {
+                    name: EventName::KeyDown,
+                    key: Key::Character(key.to_string()),
+                    code,
+                    modifiers,
+                });
+                utils.wait_for_update().await;
+            }
+
+            // Hidden passwords can't be copied, but they can once they are shown
+            assert_eq!(utils.clipboard_text(), copied_text);
+        }
+    }
 }
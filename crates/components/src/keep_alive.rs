@@ -0,0 +1,123 @@
+use dioxus::prelude::*;
+use freya_elements as dioxus_elements;
+
+/// Hide its children without unmounting them, so they keep their state while hidden
+/// and can be shown again cheaply.
+///
+/// Hidden children are not laid out, painted or reached by events, but their hooks,
+/// scroll positions and editor contents are preserved. Useful for tab panels that are expensive to mount.
+///
+/// # Example
+///
+/// ```no_run
+/// # use freya::prelude::*;
+/// fn app() -> Element {
+///     let mut selected = use_signal(|| 0);
+///
+///     rsx!(
+///         Button {
+///             onpress: move |_| selected.set((selected() + 1) % 2),
+///             label { "Switch tab" }
+///         }
+///         KeepAlive {
+///             active: selected() == 0,
+///             label { "First tab" }
+///         }
+///         KeepAlive {
+///             active: selected() == 1,
+///             label { "Second tab" }
+///         }
+///     )
+/// }
+/// ```
+#[allow(non_snake_case)]
+#[component]
+pub fn KeepAlive(
+    /// Whether the children are shown.
+    active: bool,
+    /// Width of the KeepAlive. Default `auto`.
+    #[props(default = "auto".to_string())]
+    width: String,
+    /// Height of the KeepAlive. Default `auto`.
+    #[props(default = "auto".to_string())]
+    height: String,
+    /// The inner children for the KeepAlive.
+    children: Element,
+) -> Element {
+    rsx!(
+        rect {
+            width,
+            height,
+            offscreen: "{!active}",
+            {children}
+        }
+    )
+}
+
+#[cfg(test)]
+mod test {
+    use freya::prelude::*;
+    use freya_testing::prelude::*;
+
+    #[tokio::test]
+    pub async fn keep_alive() {
+        #[allow(non_snake_case)]
+        fn Counter() -> Element {
+            let mut count = use_signal(|| 0);
+
+            rsx!(rect {
+                width: "100%",
+                height: "100",
+                onclick: move |_| count += 1,
+                label {
+                    "{count}"
+                }
+            })
+        }
+
+        fn keep_alive_app() -> Element {
+            let mut active = use_signal(|| true);
+
+            rsx!(
+                rect {
+                    width: "100%",
+                    height: "100",
+                    onclick: move |_| active.toggle(),
+                }
+                KeepAlive {
+                    width: "100%",
+                    active: active(),
+                    Counter {}
+                }
+                rect {
+                    width: "100%",
+                    height: "100",
+                }
+            )
+        }
+
+        let mut utils = launch_test(keep_alive_app);
+        utils.wait_for_update().await;
+
+        let root = utils.root();
+        let keep_alive = root.get(1);
+        let counter = keep_alive.get(0);
+
+        utils.click_cursor((5., 150.)).await;
+        assert_eq!(counter.get(0).get(0).text(), Some("1"));
+
+        // Hide the counter, the next sibling takes its place
+        utils.click_cursor((5., 5.)).await;
+        assert_eq!(root.get(2).layout().unwrap().area.min_y(), 100.);
+
+        // Hidden elements don't receive events
+        utils.click_cursor((5., 150.)).await;
+        assert_eq!(counter.get(0).get(0).text(), Some("1"));
+
+        // Show it again, the state was preserved
+        utils.click_cursor((5., 5.)).await;
+        assert_eq!(root.get(2).layout().unwrap().area.min_y(), 200.);
+        utils.click_cursor((5., 150.)).await;
+        assert_eq!(counter.get(0).get(0).text(), Some("2"));
+    }
+}
@@ -42,34 +42,61 @@ mod accordion;
 mod activable_route;
 mod animated_position;
 mod animated_router;
+mod before_after;
 mod body;
 mod button;
+mod calendar;
+#[cfg(feature = "use_camera")]
+mod camera_view;
 mod checkbox;
+mod combobox;
+mod context_menu;
 mod cursor_area;
+mod data_inspector;
+mod date_picker;
+mod diff_view;
 mod drag_drop;
+mod drawing_pad;
 mod dropdown;
+mod gantt;
 mod gesture_area;
 mod global_animated_position;
 mod graph;
+mod hex_view;
 mod hooks;
 mod icons;
 mod image;
 mod input;
+mod keep_alive;
 mod link;
 mod loader;
+mod log_view;
+#[cfg(feature = "network-image")]
+mod map_view;
 mod menu;
+mod menu_bar;
+mod modal;
 mod native_container;
 mod native_router;
 #[cfg(feature = "network-image")]
 mod network_image;
 mod overflowed_content;
+#[cfg(feature = "pdf")]
+mod pdf_view;
+mod popover;
 mod popup;
 mod progress_bar;
+#[cfg(feature = "qr-code")]
+mod qr_code;
 mod radio;
 mod resizable_container;
+mod resize_handle;
+#[cfg(feature = "use_screen_capture")]
+mod screen_capture_view;
 mod scroll_views;
 mod selectable_text;
 mod sidebar;
+mod skeleton;
 mod slider;
 mod snackbar;
 mod svg;
@@ -78,41 +105,72 @@ mod table;
 mod tabs;
 mod theme;
 mod tile;
+mod toast;
 mod tooltip;
 mod tree;
+mod tree_view;
+mod waveform;
 mod window_drag_area;
+mod window_resize_borders;
 
 pub use accordion::*;
 pub use activable_route::*;
 pub use animated_position::*;
 pub use animated_router::*;
+pub use before_after::*;
 pub use body::*;
 pub use button::*;
+pub use calendar::*;
+#[cfg(feature = "use_camera")]
+pub use camera_view::*;
 pub use checkbox::*;
+pub use combobox::*;
+pub use context_menu::*;
 pub use cursor_area::*;
+pub use data_inspector::*;
+pub use date_picker::*;
+pub use diff_view::*;
 pub use drag_drop::*;
+pub use drawing_pad::*;
 pub use dropdown::*;
+pub use gantt::*;
 pub use gesture_area::*;
 pub use global_animated_position::*;
 pub use graph::*;
+pub use hex_view::*;
 pub use hooks::*;
 pub use icons::*;
 pub use input::*;
+pub use keep_alive::*;
 pub use link::*;
 pub use loader::*;
+pub use log_view::*;
+#[cfg(feature = "network-image")]
+pub use map_view::*;
 pub use menu::*;
+pub use menu_bar::*;
+pub use modal::*;
 pub use native_container::*;
 pub use native_router::*;
 #[cfg(feature = "network-image")]
 pub use network_image::*;
 pub use overflowed_content::*;
+#[cfg(feature = "pdf")]
+pub use pdf_view::*;
+pub use popover::*;
 pub use popup::*;
 pub use progress_bar::*;
+#[cfg(feature = "qr-code")]
+pub use qr_code::*;
 pub use radio::*;
 pub use resizable_container::*;
+pub use resize_handle::*;
+#[cfg(feature = "use_screen_capture")]
+pub use screen_capture_view::*;
 pub use scroll_views::*;
 pub use selectable_text::*;
 pub use sidebar::*;
+pub use skeleton::*;
 pub use slider::*;
 pub use snackbar::*;
 pub use switch::*;
@@ -120,6 +178,10 @@ pub use table::*;
 pub use tabs::*;
 pub use theme::*;
 pub use tile::*;
+pub use toast::*;
 pub use tooltip::*;
 pub use tree::*;
+pub use tree_view::*;
+pub use waveform::*;
 pub use window_drag_area::*;
+pub use window_resize_borders::*;
@@ -0,0 +1,255 @@
+use std::collections::VecDeque;
+
+use dioxus::prelude::*;
+use freya_elements as dioxus_elements;
+use freya_hooks::use_node_signal;
+
+use crate::{
+    use_scroll_controller,
+    ScrollConfig,
+    ScrollDirection,
+    ScrollPosition,
+    VirtualScrollView,
+};
+
+/// Append-only list of text lines, created with [`use_text_log`] and displayed with [`LogView`].
+#[derive(Clone, Copy, PartialEq)]
+pub struct UseTextLog {
+    lines: Signal<VecDeque<String>>,
+    removed: Signal<usize>,
+    capacity: Option<usize>,
+}
+
+impl UseTextLog {
+    /// Append a line, removing the oldest one if the capacity is exceeded.
+    pub fn push_line(&mut self, line: impl Into<String>) {
+        self.extend_lines([line.into()]);
+    }
+
+    /// Append the lines of a text.
+    pub fn push_text(&mut self, text: &str) {
+        self.extend_lines(text.lines().map(str::to_string));
+    }
+
+    /// Append multiple lines at once, removing the oldest ones if the capacity is exceeded.
+    pub fn extend_lines(&mut self, new_lines: impl IntoIterator<Item = String>) {
+        let mut lines = self.lines.write();
+        lines.extend(new_lines);
+        if let Some(capacity) = self.capacity {
+            let overflow = lines.len().saturating_sub(capacity);
+            if overflow > 0 {
+                lines.drain(..overflow);
+                *self.removed.write() += overflow;
+            }
+        }
+    }
+
+    /// Remove all the lines.
+    pub fn clear(&mut self) {
+        let mut lines = self.lines.write();
+        *self.removed.write() += lines.len();
+        lines.clear();
+    }
+
+    /// Get a line given its index.
+    pub fn line(&self, index: usize) -> Option<String> {
+        self.lines.read().get(index).cloned()
+    }
+
+    /// Amount of lines.
+    pub fn len(&self) -> usize {
+        self.lines.read().len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+}
+
+/// Create an append-only text log, optionally keeping only the last `capacity` lines.
+///
+/// Every line is shaped only once, so appending to long logs stays cheap.
+pub fn use_text_log(capacity: Option<usize>) -> UseTextLog {
+    use_hook(|| UseTextLog {
+        lines: Signal::new(VecDeque::new()),
+        removed: Signal::new(0),
+        capacity,
+    })
+}
+
+/// Properties for the [`LogView`] component.
+#[derive(Props, Clone, PartialEq)]
+pub struct LogViewProps {
+    /// The text log to display.
+    pub log: UseTextLog,
+    /// Width of the LogView. Default `fill`.
+    #[props(default = "fill".into())]
+    pub width: String,
+    /// Height of the LogView. Default `fill`.
+    #[props(default = "fill".into())]
+    pub height: String,
+    /// Height of every line. Default `20`.
+    #[props(default = 20.0)]
+    pub line_height: f32,
+    /// Font size of the text. Default `14`.
+    #[props(default = "14".into())]
+    pub font_size: String,
+    /// Keep showing the latest lines as they are appended, unless the user scrolls up. Default `true`.
+    #[props(default = true)]
+    pub follow: bool,
+}
+
+/// Virtualized view of a [`UseTextLog`], for log viewers, chat transcripts and similar.
+///
+/// Only the visible lines are rendered and every line is laid out as a single line of text.
+/// When `follow` is enabled it sticks to the bottom as new lines are appended, until the user scrolls up,
+/// and it starts following again once scrolled back to the bottom.
+///
+/// # Example
+///
+/// ```no_run
+/// # use freya::prelude::*;
+/// fn app() -> Element {
+///     let mut log = use_text_log(Some(10_000));
+///
+///     use_hook(move || {
+///         spawn(async move {
+///             loop {
+///                 tokio::time::sleep(std::time::Duration::from_millis(100)).await;
+///                 log.push_line("New event");
+///             }
+///         })
+///     });
+///
+///     rsx!(LogView { log })
+/// }
+/// ```
+#[allow(non_snake_case)]
+pub fn LogView(
+    LogViewProps {
+        log,
+        width,
+        height,
+        line_height,
+        font_size,
+        follow,
+    }: LogViewProps,
+) -> Element {
+    let mut scroll_controller = use_scroll_controller(|| ScrollConfig {
+        default_vertical_position: ScrollPosition::End,
+        ..Default::default()
+    });
+    let (reference, size) = use_node_signal();
+    let mut following = use_signal(|| true);
+    let length = log.len();
+    let removed = *log.removed.read();
+
+    // Stop following when scrolled away from the bottom and follow again when back to it
+    use_effect(move || {
+        let scrolled_y = *scroll_controller.y().read();
+        let viewport_height = size.peek().area.height();
+        let inner_height = log.lines.peek().len() as f32 * line_height;
+        let is_at_bottom = -scrolled_y as f32 + viewport_height >= inner_height - line_height;
+        if *following.peek() != is_at_bottom {
+            following.set(is_at_bottom);
+        }
+    });
+
+    // Scroll to the latest lines as they are appended
+    use_effect(use_reactive!(|length, follow| {
+        if follow && length > 0 && *following.peek() {
+            scroll_controller.scroll_to(ScrollPosition::End, ScrollDirection::Vertical);
+        }
+    }));
+
+    rsx!(
+        rect {
+            reference,
+            width: "{width}",
+            height: "{height}",
+            VirtualScrollView {
+                scroll_controller,
+                length,
+                item_size: line_height,
+                builder_args: (line_height, font_size, removed),
+                builder: move |i, args: &Option<(f32, String, usize)>| {
+                    let (line_height, font_size, removed) = args.clone().unwrap();
+                    let line = log.line(i).unwrap_or_default();
+                    rsx!(
+                        label {
+                            key: "{removed + i}",
+                            width: "fill",
+                            height: "{line_height}",
+                            font_size: "{font_size}",
+                            main_align: "center",
+                            max_lines: "1",
+                            "{line}"
+                        }
+                    )
+                }
+            }
+        }
+    )
+}
+
+#[cfg(test)]
+mod test {
+    use freya::prelude::*;
+    use freya_testing::prelude::*;
+
+    #[tokio::test]
+    pub async fn log_view_follows_tail() {
+        fn log_view_app() -> Element {
+            let mut log = use_text_log(Some(100));
+
+            rsx!(
+                Button {
+                    onpress: move |_| log.push_text("first\nsecond\nthird"),
+                    label { "Append" }
+                }
+                LogView {
+                    height: "100",
+                    line_height: 20.0,
+                    log
+                }
+            )
+        }
+
+        let mut utils = launch_test(log_view_app);
+        utils.wait_for_update().await;
+
+        for _ in 0..4 {
+            utils.click_cursor((15., 15.)).await;
+            utils.wait_for_update().await;
+        }
+        utils.wait_for_update().await;
+
+        // The last appended line is visible
+        let content = utils.root().get(1).get(0).get(0).get(0);
+        let last_line = content.get(content.children_ids().len() - 1);
+        assert_eq!(last_line.get(0).text(), Some("third"));
+        assert!(last_line.is_visible());
+    }
+
+    #[tokio::test]
+    pub async fn text_log_capacity() {
+        fn text_log_app() -> Element {
+            let mut log = use_text_log(Some(2));
+
+            rsx!(
+                Button {
+                    onpress: move |_| log.push_text("a\nb\nc"),
+                    label { "{log.len()} {log.line(0).unwrap_or_default()}" }
+                }
+            )
+        }
+
+        let mut utils = launch_test(text_log_app);
+        utils.wait_for_update().await;
+        utils.click_cursor((15., 15.)).await;
+        utils.wait_for_update().await;
+
+        let label = utils.root().get(0).get(0);
+        assert_eq!(label.get(0).text(), Some("2 b"));
+    }
+}
@@ -0,0 +1,539 @@
+use std::f64::consts::PI;
+
+use dioxus::prelude::*;
+use freya_core::{
+    custom_attributes::CanvasRunnerContext,
+    parsing::Parse,
+};
+use freya_elements::{
+    self as dioxus_elements,
+    events::{
+        MouseEvent,
+        TouchEvent,
+        TouchPhase,
+        WheelEvent,
+    },
+};
+use freya_engine::prelude::*;
+use freya_hooks::{
+    use_applied_theme,
+    use_canvas_with_deps,
+    use_gestures,
+    use_node_signal,
+    use_platform,
+    MapViewThemeWith,
+    TouchGesture,
+};
+use reqwest::Url;
+
+use crate::NetworkImage;
+
+/// Size in pixels of the map tiles.
+const TILE_SIZE: f64 = 256.;
+
+/// Max latitude that can be displayed with the Web Mercator projection.
+const MAX_LATITUDE: f64 = 85.051_128_78;
+
+/// Zoom levels changed by every pixel scrolled with the wheel.
+const WHEEL_ZOOM_SPEED: f64 = 1. / 250.;
+
+/// Geographic coordinate in degrees.
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub struct LatLon {
+    pub lat: f64,
+    pub lon: f64,
+}
+
+impl LatLon {
+    pub fn new(lat: f64, lon: f64) -> Self {
+        Self { lat, lon }
+    }
+}
+
+/// Project a coordinate to pixels of the whole world map at the given zoom, using the Web Mercator projection.
+fn project(coordinate: LatLon, zoom: f64) -> (f64, f64) {
+    let world_size = TILE_SIZE * 2f64.powf(zoom);
+    let lat = coordinate
+        .lat
+        .clamp(-MAX_LATITUDE, MAX_LATITUDE)
+        .to_radians();
+    let x = (coordinate.lon + 180.) / 360. * world_size;
+    let y = (1. - (lat.tan() + 1. / lat.cos()).ln() / PI) / 2. * world_size;
+    (x, y)
+}
+
+/// Inverse of [`project`].
+fn unproject((x, y): (f64, f64), zoom: f64) -> LatLon {
+    let world_size = TILE_SIZE * 2f64.powf(zoom);
+    let lon = x / world_size * 360. - 180.;
+    let lat = (PI * (1. - 2. * y / world_size)).sinh().atan().to_degrees();
+    LatLon::new(lat.clamp(-MAX_LATITUDE, MAX_LATITUDE), lon)
+}
+
+/// Tile of the map visible in the [`MapView`].
+#[derive(Debug, Clone, PartialEq)]
+struct MapTile {
+    x: i64,
+    y: i64,
+    z: u8,
+    /// Times the map was repeated horizontally until this tile.
+    wrap: i64,
+    /// Position of the tile relative to the [`MapView`].
+    left: f64,
+    top: f64,
+    size: f64,
+}
+
+impl MapTile {
+    /// URL of the tile given a template with the `{x}`, `{y}` and `{z}` placeholders.
+    fn url(&self, template: &str) -> String {
+        template
+            .replace("{x}", &self.x.to_string())
+            .replace("{y}", &self.y.to_string())
+            .replace("{z}", &self.z.to_string())
+    }
+}
+
+/// Tiles covering a view of the given size, centered in the given coordinate.
+/// Tiles are loaded from the closest integer zoom and scaled to fractional zooms.
+fn visible_tiles(center: LatLon, zoom: f64, (width, height): (f64, f64)) -> Vec<MapTile> {
+    let z = zoom.floor().max(0.);
+    let tiles_count = 2i64.pow(z as u32);
+    let size = TILE_SIZE * 2f64.powf(zoom - z);
+    let (center_x, center_y) = project(center, zoom);
+    let (left, top) = (center_x - width / 2., center_y - height / 2.);
+
+    let mut tiles = Vec::new();
+    let (first_x, last_x) = (
+        (left / size).floor() as i64,
+        ((left + width) / size).floor() as i64,
+    );
+    let (first_y, last_y) = (
+        (top / size).floor().max(0.) as i64,
+        ((top + height) / size).floor().min(tiles_count as f64 - 1.) as i64,
+    );
+    for y in first_y..=last_y {
+        for x in first_x..=last_x {
+            tiles.push(MapTile {
+                // The map repeats horizontally
+                x: x.rem_euclid(tiles_count),
+                y,
+                z: z as u8,
+                wrap: x.div_euclid(tiles_count),
+                left: x as f64 * size - left,
+                top: y as f64 * size - top,
+                size,
+            });
+        }
+    }
+    tiles
+}
+
+/// Marker displayed in a [`MapView`].
+#[derive(Debug, Clone, PartialEq)]
+pub struct MapMarker {
+    pub position: LatLon,
+    pub color: String,
+    /// Radius in pixels. Default `8`.
+    pub radius: f32,
+}
+
+impl MapMarker {
+    pub fn new(position: LatLon, color: impl Into<String>) -> Self {
+        Self {
+            position,
+            color: color.into(),
+            radius: 8.,
+        }
+    }
+}
+
+/// Line between coordinates displayed in a [`MapView`], e.g. a route.
+#[derive(Debug, Clone, PartialEq)]
+pub struct MapPolyline {
+    pub points: Vec<LatLon>,
+    pub color: String,
+    /// Width in pixels. Default `3`.
+    pub width: f32,
+}
+
+impl MapPolyline {
+    pub fn new(points: Vec<LatLon>, color: impl Into<String>) -> Self {
+        Self {
+            points,
+            color: color.into(),
+            width: 3.,
+        }
+    }
+}
+
+/// Markers and polylines in pixels relative to the [`MapView`], drawn in its canvas.
+#[derive(Clone, PartialEq, Default)]
+struct Overlay {
+    markers: Vec<((f32, f32), String, f32)>,
+    polylines: Vec<(Vec<(f32, f32)>, String, f32)>,
+}
+
+/// Properties for the [`MapView`] component.
+#[derive(Props, Clone, PartialEq)]
+pub struct MapViewProps {
+    /// URL template of the XYZ raster tiles, e.g. `https://tile.openstreetmap.org/{z}/{x}/{y}.png`.
+    pub tile_url: String,
+    /// Coordinate initially displayed in the center of the map.
+    #[props(default)]
+    pub initial_center: LatLon,
+    /// Initial zoom level. Default `2`.
+    #[props(default = 2.)]
+    pub initial_zoom: f64,
+    /// Minimum zoom level. Default `0`.
+    #[props(default = 0.)]
+    pub min_zoom: f64,
+    /// Maximum zoom level. Default `19`.
+    #[props(default = 19.)]
+    pub max_zoom: f64,
+    /// Markers displayed over the map.
+    #[props(default)]
+    pub markers: Vec<MapMarker>,
+    /// Lines displayed over the map.
+    #[props(default)]
+    pub polylines: Vec<MapPolyline>,
+    /// Width of the MapView. Default `fill`.
+    #[props(default = "fill".into())]
+    pub width: String,
+    /// Height of the MapView. Default `fill`.
+    #[props(default = "fill".into())]
+    pub height: String,
+    /// Theme override.
+    pub theme: Option<MapViewThemeWith>,
+}
+
+/// Interactive map made of raster tiles from any XYZ ("slippy map") tile server.
+///
+/// Tiles are fetched asynchronously and cached like a [`NetworkImage`]. Drag with the mouse or
+/// two fingers to pan, and use the wheel or pinch to zoom.
+///
+/// Requires the `network-image` feature.
+///
+/// # Example
+///
+/// ```no_run
+/// # use freya::prelude::*;
+/// fn app() -> Element {
+///     let barcelona = LatLon::new(41.3874, 2.1686);
+///     let madrid = LatLon::new(40.4168, -3.7038);
+///
+///     rsx!(MapView {
+///         tile_url: "https://tile.openstreetmap.org/{z}/{x}/{y}.png",
+///         initial_center: barcelona,
+///         initial_zoom: 6.,
+///         markers: vec![
+///             MapMarker::new(barcelona, "rgb(220, 50, 50)"),
+///             MapMarker::new(madrid, "rgb(50, 50, 220)"),
+///         ],
+///         polylines: vec![MapPolyline::new(vec![barcelona, madrid], "black")],
+///     })
+/// }
+/// ```
+#[allow(non_snake_case)]
+pub fn MapView(
+    MapViewProps {
+        tile_url,
+        initial_center,
+        initial_zoom,
+        min_zoom,
+        max_zoom,
+        markers,
+        polylines,
+        width,
+        height,
+        theme,
+    }: MapViewProps,
+) -> Element {
+    let theme = use_applied_theme!(&theme, map_view);
+    let platform = use_platform();
+    let (reference, layout) = use_node_signal();
+    let mut center = use_signal(|| initial_center);
+    let mut zoom = use_signal(|| initial_zoom.clamp(min_zoom, max_zoom));
+    let mut dragging = use_signal::<Option<(f64, f64)>>(|| None);
+    let mut cursor = use_signal::<Option<(f64, f64)>>(|| None);
+    let mut pinch_zoom = use_signal(|| zoom());
+
+    // Move the map by the given amount of pixels
+    let mut pan = move |dx: f64, dy: f64| {
+        let zoom = *zoom.peek();
+        let (x, y) = project(*center.peek(), zoom);
+        center.set(unproject((x - dx, y - dy), zoom));
+    };
+
+    // Zoom keeping the coordinate under the given point of the view in the same place
+    let mut zoom_at = move |new_zoom: f64, (x, y): (f64, f64)| {
+        let old_zoom = *zoom.peek();
+        let new_zoom = new_zoom.clamp(min_zoom, max_zoom);
+        if old_zoom == new_zoom {
+            return;
+        }
+        let area = layout.peek().area;
+        let offset = (x - area.width() as f64 / 2., y - area.height() as f64 / 2.);
+        let (center_x, center_y) = project(*center.peek(), old_zoom);
+        let target = unproject((center_x + offset.0, center_y + offset.1), old_zoom);
+        let (target_x, target_y) = project(target, new_zoom);
+        center.set(unproject(
+            (target_x - offset.0, target_y - offset.1),
+            new_zoom,
+        ));
+        zoom.set(new_zoom);
+    };
+
+    let mut gestures = use_gestures(move |gesture| match gesture {
+        TouchGesture::Pinch { scale, center } => {
+            let area = layout.peek().area;
+            let position = (
+                center.x - area.min_x() as f64,
+                center.y - area.min_y() as f64,
+            );
+            zoom_at(*pinch_zoom.peek() + scale.log2(), position);
+        }
+        TouchGesture::Pan { delta } => pan(delta.x, delta.y),
+        TouchGesture::LongPress { .. } => {}
+    });
+
+    let ontouch = move |e: TouchEvent| {
+        if e.get_touch_phase() == TouchPhase::Started {
+            pinch_zoom.set(*zoom.peek());
+        }
+        gestures.process_touch(&e.data);
+    };
+
+    let onmousedown = move |e: MouseEvent| {
+        let coordinates = e.get_screen_coordinates();
+        dragging.set(Some((coordinates.x, coordinates.y)));
+    };
+
+    let onglobalmousemove = move |e: MouseEvent| {
+        let Some((last_x, last_y)) = *dragging.peek() else {
+            return;
+        };
+        let coordinates = e.get_screen_coordinates();
+        pan(coordinates.x - last_x, coordinates.y - last_y);
+        dragging.set(Some((coordinates.x, coordinates.y)));
+    };
+
+    let onglobalclick = move |_: MouseEvent| {
+        if dragging.peek().is_some() {
+            dragging.set(None);
+        }
+    };
+
+    let onmousemove = move |e: MouseEvent| {
+        let coordinates = e.get_element_coordinates();
+        cursor.set(Some((coordinates.x, coordinates.y)));
+    };
+
+    let onwheel = move |e: WheelEvent| {
+        e.stop_propagation();
+        let delta = (e.get_delta_y() * WHEEL_ZOOM_SPEED).clamp(-1., 1.);
+        // Zoom around the cursor, or the center if it's unknown
+        let area = layout.peek().area;
+        let position = cursor
+            .peek()
+            .unwrap_or((area.width() as f64 / 2., area.height() as f64 / 2.));
+        zoom_at(*zoom.peek() + delta, position);
+    };
+
+    let area = layout.read().area;
+    let view_size = (area.width() as f64, area.height() as f64);
+    let (center, zoom) = (center(), zoom());
+    let tiles = visible_tiles(center, zoom, view_size);
+
+    let (center_x, center_y) = project(center, zoom);
+    let to_view = |coordinate: LatLon| {
+        let (x, y) = project(coordinate, zoom);
+        (
+            (x - center_x + view_size.0 / 2.) as f32,
+            (y - center_y + view_size.1 / 2.) as f32,
+        )
+    };
+    let overlay = Overlay {
+        markers: markers
+            .iter()
+            .map(|marker| {
+                (
+                    to_view(marker.position),
+                    marker.color.clone(),
+                    marker.radius,
+                )
+            })
+            .collect(),
+        polylines: polylines
+            .iter()
+            .map(|polyline| {
+                let points = polyline.points.iter().copied().map(to_view).collect();
+                (points, polyline.color.clone(), polyline.width)
+            })
+            .collect(),
+    };
+
+    let canvas = use_canvas_with_deps(&overlay, move |overlay| {
+        platform.invalidate_drawing_area(layout.peek().area);
+        platform.request_animation_frame();
+        move |ctx: &mut CanvasRunnerContext| {
+            let point = |(x, y): (f32, f32)| {
+                (
+                    ctx.area.min_x() + x * ctx.scale_factor,
+                    ctx.area.min_y() + y * ctx.scale_factor,
+                )
+            };
+
+            ctx.canvas.save();
+            ctx.canvas.clip_rect(
+                Rect::new(
+                    ctx.area.min_x(),
+                    ctx.area.min_y(),
+                    ctx.area.max_x(),
+                    ctx.area.max_y(),
+                ),
+                ClipOp::Intersect,
+                true,
+            );
+
+            let mut paint = Paint::default();
+            paint.set_anti_alias(true);
+
+            paint.set_style(PaintStyle::Stroke);
+            paint.set_stroke_cap(PaintCap::Round);
+            for (points, color, width) in &overlay.polylines {
+                let (Ok(color), Some(first)) = (Color::parse(color), points.first()) else {
+                    continue;
+                };
+                paint.set_color(color);
+                paint.set_stroke_width(width * ctx.scale_factor);
+                let mut path = Path::new();
+                path.move_to(point(*first));
+                for position in &points[1..] {
+                    path.line_to(point(*position));
+                }
+                ctx.canvas.draw_path(&path, &paint);
+            }
+
+            paint.set_style(PaintStyle::Fill);
+            for (position, color, radius) in &overlay.markers {
+                let Ok(color) = Color::parse(color) else {
+                    continue;
+                };
+                paint.set_color(color);
+                ctx.canvas
+                    .draw_circle(point(*position), radius * ctx.scale_factor, &paint);
+            }
+
+            ctx.canvas.restore();
+        }
+    });
+
+    rsx!(
+        rect {
+            reference,
+            width: "{width}",
+            height: "{height}",
+            overflow: "clip",
+            background: "{theme.background}",
+            onmousedown,
+            onmousemove,
+            onglobalmousemove,
+            onglobalclick,
+            onwheel,
+            ontouchstart: ontouch,
+            ontouchmove: ontouch,
+            ontouchend: ontouch,
+            ontouchcancel: ontouch,
+            for tile in tiles {
+                if let Ok(url) = Url::parse(&tile.url(&tile_url)) {
+                    rect {
+                        key: "{tile.z}-{tile.x}-{tile.y}-{tile.wrap}",
+                        position: "absolute",
+                        position_left: "{tile.left}",
+                        position_top: "{tile.top}",
+                        width: "{tile.size}",
+                        height: "{tile.size}",
+                        NetworkImage {
+                            url,
+                            width: "fill",
+                            height: "fill",
+                            loading: rsx!(rect {
+                                width: "fill",
+                                height: "fill",
+                                background: "{theme.tile_background}",
+                            }),
+                            fallback: rsx!(rect {
+                                width: "fill",
+                                height: "fill",
+                                background: "{theme.tile_background}",
+                            }),
+                        }
+                    }
+                }
+            }
+            rect {
+                position: "absolute",
+                position_left: "0",
+                position_top: "0",
+                width: "fill",
+                height: "fill",
+                canvas_reference: canvas.attribute(),
+            }
+        }
+    )
+}
+
+#[cfg(test)]
+mod test {
+    use super::{
+        project,
+        unproject,
+        visible_tiles,
+        LatLon,
+    };
+
+    #[test]
+    fn projection() {
+        assert_eq!(project(LatLon::new(0., 0.), 0.), (128., 128.));
+        assert_eq!(project(LatLon::new(0., 180.), 1.), (512., 256.));
+
+        let barcelona = LatLon::new(41.3874, 2.1686);
+        let unprojected = unproject(project(barcelona, 10.), 10.);
+        assert!((unprojected.lat - barcelona.lat).abs() < 1e-9);
+        assert!((unprojected.lon - barcelona.lon).abs() < 1e-9);
+    }
+
+    #[test]
+    fn tiles() {
+        // The whole world fits in a single tile
+        let tiles = visible_tiles(LatLon::default(), 0., (256., 256.));
+        assert_eq!(tiles.len(), 1);
+        assert_eq!((tiles[0].left, tiles[0].top), (0., 0.));
+        assert_eq!(
+            tiles[0].url("https://tiles.example/{z}/{x}/{y}.png"),
+            "https://tiles.example/0/0/0.png"
+        );
+
+        // Four tiles meet in the center
+        let tiles = visible_tiles(LatLon::default(), 1., (200., 200.));
+        let positions = tiles
+            .iter()
+            .map(|tile| (tile.x, tile.y, tile.left, tile.top))
+            .collect::<Vec<_>>();
+        assert_eq!(
+            positions,
+            vec![
+                (0, 0, -156., -156.),
+                (1, 0, 100., -156.),
+                (0, 1, -156., 100.),
+                (1, 1, 100., 100.),
+            ]
+        );
+
+        // Tiles are scaled between zoom levels and repeat horizontally
+        let tiles = visible_tiles(LatLon::new(0., 180.), 0.5, (100., 100.));
+        assert!(tiles.iter().all(|tile| tile.z == 0 && tile.x == 0));
+        assert!((tiles[0].size - 256. * 2f64.sqrt()).abs() < 1e-9);
+    }
+}
@@ -0,0 +1,912 @@
+use dioxus::prelude::*;
+use freya_elements::{
+    self as dioxus_elements,
+    events::{
+        Key,
+        KeyboardEvent,
+        Modifiers,
+        MouseEvent,
+    },
+};
+use freya_hooks::{
+    use_applied_theme,
+    use_node_signal,
+    use_platform_information,
+    MenuBarTheme,
+    MenuBarThemeWith,
+    MenuItemTheme,
+};
+
+use crate::{
+    context_menu::fit_overlay,
+    MenuContainer,
+};
+
+/// Item of a [`MenuBar`]: an action, a submenu or a separator.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct MenuBarItem {
+    /// Passed to `onselect` when the item is activated.
+    pub id: String,
+    /// Text of the item. The character after a `&` is its mnemonic, e.g. `&File`, use `&&` for a literal `&`.
+    pub label: String,
+    /// Shortcut displayed at the right, e.g. `Ctrl+S`. It's only a hint, handle it yourself.
+    pub shortcut: Option<String>,
+    /// Whether the item is checked, `None` if it isn't checkable.
+    pub checked: Option<bool>,
+    pub disabled: bool,
+    /// Items of the submenu opened by this item.
+    pub items: Vec<MenuBarItem>,
+    separator: bool,
+}
+
+impl MenuBarItem {
+    pub fn new(id: impl Into<String>, label: impl Into<String>) -> Self {
+        Self {
+            id: id.into(),
+            label: label.into(),
+            ..Default::default()
+        }
+    }
+
+    /// Item that opens a submenu with the given items, or a menu of the bar.
+    pub fn submenu(label: impl Into<String>, items: Vec<MenuBarItem>) -> Self {
+        let label = label.into();
+        Self {
+            id: label.clone(),
+            label,
+            items,
+            ..Default::default()
+        }
+    }
+
+    pub fn separator() -> Self {
+        Self {
+            separator: true,
+            ..Default::default()
+        }
+    }
+
+    pub fn with_shortcut(mut self, shortcut: impl Into<String>) -> Self {
+        self.shortcut = Some(shortcut.into());
+        self
+    }
+
+    pub fn with_checked(mut self, checked: bool) -> Self {
+        self.checked = Some(checked);
+        self
+    }
+
+    pub fn with_disabled(mut self, disabled: bool) -> Self {
+        self.disabled = disabled;
+        self
+    }
+
+    pub fn is_separator(&self) -> bool {
+        self.separator
+    }
+
+    fn is_selectable(&self) -> bool {
+        !self.separator && !self.disabled
+    }
+}
+
+/// Text of a label without the `&` markers, and the index of the character of its mnemonic.
+fn parse_mnemonic(label: &str) -> (String, Option<usize>) {
+    let mut text = String::new();
+    let mut mnemonic = None;
+    let mut chars = label.chars();
+    while let Some(ch) = chars.next() {
+        if ch != '&' {
+            text.push(ch);
+            continue;
+        }
+        match chars.next() {
+            Some('&') => text.push('&'),
+            Some(ch) => {
+                mnemonic = mnemonic.or(Some(text.chars().count()));
+                text.push(ch);
+            }
+            None => {}
+        }
+    }
+    (text, mnemonic)
+}
+
+/// Index of the selectable item whose mnemonic is the given character, ignoring the case.
+pub(crate) fn find_mnemonic(items: &[MenuBarItem], character: &str) -> Option<usize> {
+    items.iter().position(|item| {
+        let (text, mnemonic) = parse_mnemonic(&item.label);
+        let mnemonic = mnemonic.and_then(|index| text.chars().nth(index));
+        item.is_selectable()
+            && mnemonic.is_some_and(|ch| ch.to_lowercase().eq(character.to_lowercase().chars()))
+    })
+}
+
+/// Items of the menu opened by following `path` from the menus of the bar.
+pub(crate) fn menu_items<'a>(menus: &'a [MenuBarItem], path: &[usize]) -> &'a [MenuBarItem] {
+    path.iter().fold(menus, |items, index| {
+        items
+            .get(*index)
+            .map(|item| item.items.as_slice())
+            .unwrap_or_default()
+    })
+}
+
+/// Next selectable item after `from`, or before it if not `forward`, wrapping around.
+pub(crate) fn next_selectable(
+    items: &[MenuBarItem],
+    from: Option<usize>,
+    forward: bool,
+) -> Option<usize> {
+    let len = items.len();
+    (1..=len)
+        .map(|step| match from {
+            Some(from) if forward => (from + step) % len,
+            Some(from) => (from + len - step % len) % len,
+            None if forward => step - 1,
+            None => len - step,
+        })
+        .find(|index| items[*index].is_selectable())
+}
+
+/// Opened menus and highlighted item, shared by the whole [`MenuBar`].
+#[derive(Clone, Copy)]
+pub(crate) struct MenuBarState {
+    /// Path of the opened menus, starting by the index of the menu of the bar.
+    pub(crate) open: Signal<Vec<usize>>,
+    /// Item highlighted in the last opened menu, or in the bar if none is opened.
+    pub(crate) highlighted: Signal<Option<usize>>,
+    /// Whether the bar is being used with the keyboard, to underline the mnemonics.
+    pub(crate) keyboard: Signal<bool>,
+}
+
+impl MenuBarState {
+    pub(crate) fn is_active(&self) -> bool {
+        self.highlighted.peek().is_some() || !self.open.peek().is_empty()
+    }
+
+    pub(crate) fn close(&mut self) {
+        self.open.set(Vec::new());
+        self.highlighted.set(None);
+        self.keyboard.set(false);
+    }
+
+    /// Open the submenu of the item at `path`, or select it if it has none.
+    pub(crate) fn activate(
+        &mut self,
+        item: &MenuBarItem,
+        path: Vec<usize>,
+        onselect: Option<EventHandler<String>>,
+    ) {
+        if !item.is_selectable() {
+            return;
+        }
+        if item.items.is_empty() {
+            self.close();
+            if let Some(onselect) = onselect {
+                onselect.call(item.id.clone());
+            }
+        } else {
+            let highlighted = if *self.keyboard.peek() {
+                next_selectable(&item.items, None, true)
+            } else {
+                None
+            };
+            self.open.set(path);
+            self.highlighted.set(highlighted);
+        }
+    }
+}
+
+/// Properties for the [`MenuBar`] component.
+#[derive(Props, Clone, PartialEq)]
+pub struct MenuBarProps {
+    /// Menus of the bar, created with [`MenuBarItem::submenu`].
+    pub menus: Vec<MenuBarItem>,
+    /// Handler called with the `id` of the activated item.
+    pub onselect: Option<EventHandler<String>>,
+    /// Theme override.
+    pub theme: Option<MenuBarThemeWith>,
+}
+
+/// In-app menu bar, e.g. File, Edit and View menus, that looks and behaves the same in every platform.
+///
+/// - Press and release `Alt` to use it with the keyboard, or `Alt` plus the mnemonic of a menu to open it.
+/// - Arrows move between the items and menus, `Enter` activates the highlighted item and `Escape` goes back.
+/// - Items can be checkable, disabled or open nested submenus, which are displayed over the rest of the app.
+///
+/// # Styling
+/// Inherits the [`MenuBarTheme`](freya_hooks::MenuBarTheme) theme.
+///
+/// # Example
+///
+/// ```no_run
+/// # use freya::prelude::*;
+/// fn app() -> Element {
+///     let mut word_wrap = use_signal(|| true);
+///
+///     rsx!(MenuBar {
+///         menus: vec![
+///             MenuBarItem::submenu(
+///                 "&File",
+///                 vec![
+///                     MenuBarItem::new("open", "&Open").with_shortcut("Ctrl+O"),
+///                     MenuBarItem::new("save", "&Save").with_shortcut("Ctrl+S"),
+///                     MenuBarItem::separator(),
+///                     MenuBarItem::new("exit", "E&xit"),
+///                 ]
+///             ),
+///             MenuBarItem::submenu(
+///                 "&View",
+///                 vec![MenuBarItem::new("wrap", "&Word Wrap").with_checked(word_wrap())]
+///             ),
+///         ],
+///         onselect: move |id: String| {
+///             if id == "wrap" {
+///                 word_wrap.toggle();
+///             }
+///         }
+///     })
+/// }
+/// ```
+#[allow(non_snake_case)]
+pub fn MenuBar(
+    MenuBarProps {
+        menus,
+        onselect,
+        theme,
+    }: MenuBarProps,
+) -> Element {
+    let theme = use_applied_theme!(&theme, menu_bar);
+    let mut state = use_context_provider(|| MenuBarState {
+        open: Signal::new(Vec::new()),
+        highlighted: Signal::new(None),
+        keyboard: Signal::new(false),
+    });
+    // Whether `Alt` was pressed without any other key
+    let mut alt_alone = use_signal(|| false);
+
+    let onglobalkeydown = {
+        let menus = menus.clone();
+        move |e: KeyboardEvent| {
+            if e.key == Key::Alt {
+                alt_alone.set(true);
+                return;
+            }
+            alt_alone.set(false);
+
+            let mut open = state.open.peek().clone();
+            let highlighted = *state.highlighted.peek();
+            let items = menu_items(&menus, &open);
+            let is_space = e.key == Key::Character(" ".to_string());
+
+            if let Key::Character(character) = &e.key {
+                // Mnemonics work with `Alt` or once the bar is active
+                if !is_space && (e.modifiers.contains(Modifiers::ALT) || state.is_active()) {
+                    if let Some(index) = find_mnemonic(items, character) {
+                        state.keyboard.set(true);
+                        let mut path = open.clone();
+                        path.push(index);
+                        state.activate(&items[index], path, onselect);
+                    }
+                    return;
+                }
+            }
+
+            if !state.is_active() {
+                return;
+            }
+
+            let switch_menu = |forward: bool| {
+                let menu = next_selectable(&menus, open.first().copied(), forward);
+                menu.map(|menu| (&menus[menu], vec![menu]))
+            };
+
+            match e.key {
+                Key::Escape if open.is_empty() => state.close(),
+                Key::Tab => state.close(),
+                Key::Escape => {
+                    let menu = open.pop();
+                    state.open.set(open);
+                    state.highlighted.set(menu);
+                }
+                // Move in the bar
+                Key::ArrowLeft | Key::ArrowRight if open.is_empty() => {
+                    let forward = e.key == Key::ArrowRight;
+                    let next = next_selectable(&menus, highlighted, forward);
+                    state.highlighted.set(next);
+                }
+                Key::ArrowDown | Key::Enter if open.is_empty() => {
+                    if let Some(menu) = highlighted {
+                        state.activate(&menus[menu], vec![menu], onselect);
+                    }
+                }
+                _ if is_space && open.is_empty() => {
+                    if let Some(menu) = highlighted {
+                        state.activate(&menus[menu], vec![menu], onselect);
+                    }
+                }
+                // Move in the opened menu
+                Key::ArrowUp | Key::ArrowDown => {
+                    let forward = e.key == Key::ArrowDown;
+                    let next = next_selectable(items, highlighted, forward);
+                    state.highlighted.set(next);
+                }
+                Key::Enter | Key::ArrowRight
+                    if highlighted.is_some_and(|item| !items[item].items.is_empty()) =>
+                {
+                    let item = highlighted.unwrap();
+                    let mut path = open.clone();
+                    path.push(item);
+                    state.activate(&items[item], path, onselect);
+                }
+                Key::Enter => {
+                    if let Some(item) = highlighted {
+                        state.activate(&items[item], Vec::new(), onselect);
+                    }
+                }
+                _ if is_space => {
+                    if let Some(item) = highlighted {
+                        state.activate(&items[item], Vec::new(), onselect);
+                    }
+                }
+                Key::ArrowLeft if open.len() > 1 => {
+                    let menu = open.pop();
+                    state.open.set(open);
+                    state.highlighted.set(menu);
+                }
+                // Move to the next or previous menu of the bar
+                Key::ArrowLeft | Key::ArrowRight => {
+                    if let Some((menu, path)) = switch_menu(e.key == Key::ArrowRight) {
+                        state.activate(menu, path, onselect);
+                    }
+                }
+                _ => {}
+            }
+        }
+    };
+
+    let onglobalkeyup = {
+        let menus = menus.clone();
+        move |e: KeyboardEvent| {
+            if e.key != Key::Alt || !*alt_alone.peek() {
+                return;
+            }
+            alt_alone.set(false);
+            if state.is_active() {
+                state.close();
+            } else {
+                state.keyboard.set(true);
+                state.highlighted.set(next_selectable(&menus, None, true));
+            }
+        }
+    };
+
+    // Close the menus if clicked anywhere else
+    let onglobalclick = move |_: MouseEvent| {
+        if state.is_active() {
+            state.close();
+        }
+    };
+
+    rsx!(
+        rect {
+            a11y_role: "menu-bar",
+            width: "fill",
+            direction: "horizontal",
+            cross_align: "center",
+            padding: "{theme.padding}",
+            background: "{theme.background}",
+            color: "{theme.font_theme.color}",
+            onglobalkeydown,
+            onglobalkeyup,
+            onglobalclick,
+            for (index, menu) in menus.into_iter().enumerate() {
+                MenuBarTitle {
+                    key: "{index}",
+                    index,
+                    menu,
+                    onselect,
+                    theme: theme.clone(),
+                }
+            }
+        }
+    )
+}
+
+/// Label whose mnemonic is underlined while the [`MenuBar`] is used with the keyboard.
+#[allow(non_snake_case)]
+#[component]
+fn MnemonicLabel(label: String) -> Element {
+    let state = use_context::<MenuBarState>();
+    let (text, mnemonic) = parse_mnemonic(&label);
+    let mnemonic = mnemonic.filter(|_| *state.keyboard.read());
+
+    let Some(mnemonic) = mnemonic else {
+        return rsx!(
+            label {
+                max_lines: "1",
+                "{text}"
+            }
+        );
+    };
+
+    let before = text.chars().take(mnemonic).collect::<String>();
+    let character = text.chars().skip(mnemonic).take(1).collect::<String>();
+    let after = text.chars().skip(mnemonic + 1).collect::<String>();
+
+    rsx!(
+        paragraph {
+            max_lines: "1",
+            text {
+                "{before}"
+            }
+            text {
+                decoration: "underline",
+                "{character}"
+            }
+            text {
+                "{after}"
+            }
+        }
+    )
+}
+
+/// Menu of the [`MenuBar`] itself.
+#[allow(non_snake_case)]
+#[component]
+fn MenuBarTitle(
+    index: usize,
+    menu: MenuBarItem,
+    onselect: Option<EventHandler<String>>,
+    theme: MenuBarTheme,
+) -> Element {
+    let mut state = use_context::<MenuBarState>();
+    let mut hovering = use_signal(|| false);
+
+    let is_open = state.open.read().first() == Some(&index);
+    let is_highlighted = is_open
+        || (state.open.read().is_empty() && *state.highlighted.read() == Some(index))
+        || (hovering() && !menu.disabled);
+    let background = if is_highlighted {
+        &*theme.hover_background
+    } else {
+        "transparent"
+    };
+    let color = if menu.disabled {
+        &*theme.disabled_color
+    } else {
+        "inherit"
+    };
+
+    let onclick = {
+        let menu = menu.clone();
+        move |e: MouseEvent| {
+            e.prevent_default();
+            if is_open {
+                state.close();
+            } else {
+                state.keyboard.set(false);
+                state.activate(&menu, vec![index], onselect);
+            }
+        }
+    };
+
+    let onmouseenter = {
+        let menu = menu.clone();
+        move |_| {
+            hovering.set(true);
+            // Switch menus by hovering them once one is opened
+            if !state.open.peek().is_empty() && !is_open {
+                state.activate(&menu, vec![index], onselect);
+            }
+        }
+    };
+
+    rsx!(
+        rect {
+            rect {
+                a11y_role: "menu-item",
+                a11y_has_popup: "menu",
+                a11y_expanded: "{is_open}",
+                a11y_disabled: "{menu.disabled}",
+                padding: "4 10",
+                corner_radius: "{theme.corner_radius}",
+                background: "{background}",
+                color: "{color}",
+                cursor: "pointer",
+                onclick,
+                onmouseenter,
+                onmouseleave: move |_| hovering.set(false),
+                MnemonicLabel {
+                    label: menu.label.clone()
+                }
+            }
+            if is_open {
+                rect {
+                    height: "0",
+                    width: "0",
+                    rect {
+                        width: "100v",
+                        layer: "-1000",
+                        MenuBarMenu {
+                            items: menu.items.clone(),
+                            path: vec![index],
+                            onselect,
+                            theme: theme.clone(),
+                        }
+                    }
+                }
+            }
+        }
+    )
+}
+
+/// Dropdown menu opened from the [`MenuBar`] or a submenu.
+#[allow(non_snake_case)]
+#[component]
+pub(crate) fn MenuBarMenu(
+    items: Vec<MenuBarItem>,
+    /// Path of this menu.
+    path: Vec<usize>,
+    onselect: Option<EventHandler<String>>,
+    theme: MenuBarTheme,
+) -> Element {
+    rsx!(
+        MenuContainer {
+            rect {
+                a11y_role: "menu",
+                for (index, item) in items.into_iter().enumerate() {
+                    if item.is_separator() {
+                        rect {
+                            key: "{index}",
+                            width: "fill",
+                            height: "1",
+                            margin: "4 0",
+                            background: "{theme.separator_fill}",
+                        }
+                    } else {
+                        MenuBarMenuItem {
+                            key: "{index}",
+                            item,
+                            path: path.iter().copied().chain([index]).collect::<Vec<_>>(),
+                            onselect,
+                            theme: theme.clone(),
+                        }
+                    }
+                }
+            }
+        }
+    )
+}
+
+/// Item of a [`MenuBarMenu`].
+#[allow(non_snake_case)]
+#[component]
+fn MenuBarMenuItem(
+    item: MenuBarItem,
+    /// Path of this item.
+    path: Vec<usize>,
+    onselect: Option<EventHandler<String>>,
+    theme: MenuBarTheme,
+) -> Element {
+    let mut state = use_context::<MenuBarState>();
+    let MenuItemTheme {
+        hover_background,
+        corner_radius,
+        ..
+    } = use_applied_theme!(None, menu_item);
+    let (item_reference, item_layout) = use_node_signal();
+    let (submenu_reference, submenu_layout) = use_node_signal();
+    let platform_information = use_platform_information();
+    let (index, menu_path) = path.split_last().unwrap();
+    let index = *index;
+
+    let is_open = state.open.read().starts_with(&path);
+    let is_highlighted =
+        is_open || (*state.open.read() == menu_path && *state.highlighted.read() == Some(index));
+    let has_submenu = !item.items.is_empty();
+    let background = if is_highlighted && !item.disabled {
+        &*hover_background
+    } else {
+        "transparent"
+    };
+    let color = if item.disabled {
+        &*theme.disabled_color
+    } else {
+        "inherit"
+    };
+    let role = if item.checked.is_some() {
+        "menu-item-check-box"
+    } else {
+        "menu-item"
+    };
+
+    let onmouseenter = {
+        let path = path.clone();
+        let menu_path = menu_path.to_vec();
+        move |_| {
+            if has_submenu && !item.disabled {
+                state.open.set(path.clone());
+                state.highlighted.set(None);
+            } else {
+                state.open.set(menu_path.clone());
+                state.highlighted.set(Some(index));
+            }
+        }
+    };
+
+    let onclick = {
+        let item = item.clone();
+        let path = path.clone();
+        move |e: MouseEvent| {
+            e.prevent_default();
+            state.keyboard.set(false);
+            state.activate(&item, path.clone(), onselect);
+        }
+    };
+
+    // Overlap the borders of the menus
+    let submenu_position = fit_overlay(
+        item_layout.read().area.inflate(12., 6.),
+        submenu_layout.read().area.size,
+        platform_information.read().viewport_size,
+    );
+
+    rsx!(
+        rect {
+            reference: item_reference,
+            a11y_role: "{role}",
+            a11y_toggled: item.checked.map(|checked| checked.to_string()),
+            a11y_disabled: "{item.disabled}",
+            a11y_keyboard_shortcut: item.shortcut.clone(),
+            a11y_has_popup: if has_submenu { Some("menu") } else { None },
+            min_width: "160",
+            width: "fill-min",
+            padding: "6 12 6 4",
+            margin: "2",
+            corner_radius: "{corner_radius}",
+            background: "{background}",
+            color: "{color}",
+            direction: "horizontal",
+            main_align: "space-between",
+            cross_align: "center",
+            onmouseenter,
+            onclick,
+            rect {
+                direction: "horizontal",
+                cross_align: "center",
+                rect {
+                    width: "22",
+                    main_align: "center",
+                    cross_align: "center",
+                    if item.checked == Some(true) {
+                        label {
+                            "✓"
+                        }
+                    }
+                }
+                MnemonicLabel {
+                    label: item.label.clone()
+                }
+            }
+            if let Some(shortcut) = &item.shortcut {
+                label {
+                    margin: "0 0 0 24",
+                    color: "{theme.shortcut_color}",
+                    "{shortcut}"
+                }
+            } else if has_submenu {
+                label {
+                    margin: "0 0 0 24",
+                    "›"
+                }
+            }
+            if is_open && has_submenu {
+                rect {
+                    reference: submenu_reference,
+                    position: "global",
+                    position_left: "{submenu_position.x}",
+                    position_top: "{submenu_position.y}",
+                    layer: "-1000",
+                    MenuBarMenu {
+                        items: item.items.clone(),
+                        path: path.clone(),
+                        onselect,
+                        theme: theme.clone(),
+                    }
+                }
+            }
+        }
+    )
+}
+
+#[cfg(test)]
+mod test {
+    use freya::prelude::*;
+    use freya_testing::prelude::*;
+
+    use super::{
+        find_mnemonic,
+        menu_items,
+        next_selectable,
+        parse_mnemonic,
+    };
+
+    #[test]
+    fn mnemonics() {
+        assert_eq!(parse_mnemonic("&File"), ("File".to_string(), Some(0)));
+        assert_eq!(parse_mnemonic("E&xit"), ("Exit".to_string(), Some(1)));
+        assert_eq!(
+            parse_mnemonic("Save && &Quit"),
+            ("Save & Quit".to_string(), Some(7))
+        );
+        assert_eq!(parse_mnemonic("Plain"), ("Plain".to_string(), None));
+
+        let items = vec![
+            MenuBarItem::new("open", "&Open"),
+            MenuBarItem::new("exit", "E&xit").with_disabled(true),
+            MenuBarItem::new("export", "E&xport"),
+        ];
+        assert_eq!(find_mnemonic(&items, "O"), Some(0));
+        assert_eq!(find_mnemonic(&items, "x"), Some(2));
+        assert_eq!(find_mnemonic(&items, "z"), None);
+    }
+
+    #[test]
+    fn navigation() {
+        let items = vec![
+            MenuBarItem::new("a", "A"),
+            MenuBarItem::separator(),
+            MenuBarItem::new("b", "B").with_disabled(true),
+            MenuBarItem::submenu("C", vec![MenuBarItem::new("d", "D")]),
+        ];
+        assert_eq!(next_selectable(&items, None, true), Some(0));
+        assert_eq!(next_selectable(&items, None, false), Some(3));
+        assert_eq!(next_selectable(&items, Some(0), true), Some(3));
+        assert_eq!(next_selectable(&items, Some(3), true), Some(0));
+        assert_eq!(next_selectable(&items, Some(0), false), Some(3));
+        assert_eq!(next_selectable(&[], None, true), None);
+
+        assert_eq!(menu_items(&items, &[3])[0].id, "d");
+        assert!(menu_items(&items, &[5]).is_empty());
+    }
+
+    #[tokio::test]
+    pub async fn menu_bar() {
+        fn menu_bar_app() -> Element {
+            let mut selected = use_signal(String::new);
+
+            rsx!(
+                MenuBar {
+                    menus: vec![
+                        MenuBarItem::submenu(
+                            "&File",
+                            vec![
+                                MenuBarItem::new("open", "&Open").with_shortcut("Ctrl+O"),
+                                MenuBarItem::separator(),
+                                MenuBarItem::new("save", "&Save").with_disabled(true),
+                                MenuBarItem::submenu(
+                                    "&Recent",
+                                    vec![MenuBarItem::new("project", "&Project")]
+                                ),
+                            ]
+                        ),
+                        MenuBarItem::submenu("&Edit", vec![MenuBarItem::new("undo", "&Undo")]),
+                    ],
+                    onselect: move |id| selected.set(id),
+                }
+                label { "{selected}" }
+            )
+        }
+
+        fn key(utils: &mut TestingHandler<()>, name: EventName, key: Key, modifiers: Modifiers) {
+            utils.push_event(TestEvent::Keyboard {
+                name,
+                key,
+                code: Code::Unidentified,
+                modifiers,
+            });
+        }
+
+        let mut utils = launch_test(menu_bar_app);
+        utils.wait_for_update().await;
+
+        let is_open = |utils: &TestingHandler<()>, menu: usize| {
+            utils.root().get(0).get(menu).children_ids().len() == 2
+        };
+        let selected =
+            |utils: &TestingHandler<()>| utils.root().get(1).get(0).text().map(str::to_string);
+
+        // Open the File menu with its mnemonic
+        let f = Key::Character("f".to_string());
+        key(&mut utils, EventName::KeyDown, f, Modifiers::ALT);
+        utils.wait_for_update().await;
+        assert!(is_open(&utils, 0));
+        assert!(!is_open(&utils, 1));
+
+        // Skip the separator and the disabled item, then open the submenu
+        key(
+            &mut utils,
+            EventName::KeyDown,
+            Key::ArrowDown,
+            Modifiers::default(),
+        );
+        key(
+            &mut utils,
+            EventName::KeyDown,
+            Key::ArrowRight,
+            Modifiers::default(),
+        );
+        utils.wait_for_update().await;
+        key(
+            &mut utils,
+            EventName::KeyDown,
+            Key::Enter,
+            Modifiers::default(),
+        );
+        utils.wait_for_update().await;
+        assert_eq!(selected(&utils).as_deref(), Some("project"));
+        assert!(!is_open(&utils, 0));
+
+        // Activate the bar with `Alt` and move to the Edit menu
+        key(&mut utils, EventName::KeyDown, Key::Alt, Modifiers::ALT);
+        key(&mut utils, EventName::KeyUp, Key::Alt, Modifiers::default());
+        key(
+            &mut utils,
+            EventName::KeyDown,
+            Key::ArrowRight,
+            Modifiers::default(),
+        );
+        key(
+            &mut utils,
+            EventName::KeyDown,
+            Key::ArrowDown,
+            Modifiers::default(),
+        );
+        utils.wait_for_update().await;
+        assert!(is_open(&utils, 1));
+        key(
+            &mut utils,
+            EventName::KeyDown,
+            Key::Enter,
+            Modifiers::default(),
+        );
+        utils.wait_for_update().await;
+        assert_eq!(selected(&utils).as_deref(), Some("undo"));
+
+        // Escape goes back to the bar and then deactivates it
+        key(&mut utils, EventName::KeyDown, Key::Alt, Modifiers::ALT);
+        key(&mut utils, EventName::KeyUp, Key::Alt, Modifiers::default());
+        key(
+            &mut utils,
+            EventName::KeyDown,
+            Key::Enter,
+            Modifiers::default(),
+        );
+        utils.wait_for_update().await;
+        assert!(is_open(&utils, 0));
+        key(
+            &mut utils,
+            EventName::KeyDown,
+            Key::Escape,
+            Modifiers::default(),
+        );
+        utils.wait_for_update().await;
+        assert!(!is_open(&utils, 0));
+        key(
+            &mut utils,
+            EventName::KeyDown,
+            Key::Escape,
+            Modifiers::default(),
+        );
+        key(
+            &mut utils,
+            EventName::KeyDown,
+            Key::ArrowDown,
+            Modifiers::default(),
+        );
+        utils.wait_for_update().await;
+        assert!(!is_open(&utils, 0));
+    }
+}
@@ -0,0 +1,290 @@
+use dioxus::prelude::*;
+use freya_core::accessibility::AccessibilityFocusStrategy;
+use freya_elements::{
+    self as dioxus_elements,
+    events::{
+        Key,
+        KeyboardEvent,
+        Modifiers,
+    },
+};
+use freya_hooks::{
+    use_applied_theme,
+    use_focus,
+    use_platform,
+    PopupTheme,
+    PopupThemeWith,
+};
+
+use crate::PopupBackground;
+
+/// Open state of a [`Modal`], created with [`use_modal`].
+#[derive(Clone, Copy, PartialEq)]
+pub struct UseModal {
+    open: Signal<bool>,
+}
+
+impl UseModal {
+    /// Open the Modal.
+    pub fn open(&mut self) {
+        self.open.set(true);
+    }
+
+    /// Close the Modal.
+    pub fn close(&mut self) {
+        self.open.set(false);
+    }
+
+    /// Open the Modal if it's closed, or close it otherwise.
+    pub fn toggle(&mut self) {
+        self.open.toggle();
+    }
+
+    /// Check whether the Modal is open.
+    pub fn is_open(&self) -> bool {
+        *self.open.read()
+    }
+}
+
+/// Create the open state of a [`Modal`], closed by default.
+pub fn use_modal() -> UseModal {
+    UseModal {
+        open: use_signal(|| false),
+    }
+}
+
+/// Dialog displayed over the rest of the app, which is dimmed and can't be interacted with
+/// until it's closed.
+///
+/// While open, the keyboard focus is trapped inside of it, and it's given back to the previously
+/// focused element once closed. Pressing `Escape` or clicking the backdrop closes it.
+///
+/// # Styling
+/// Inherits the [`PopupTheme`](freya_hooks::PopupTheme) theme.
+///
+/// # Example
+///
+/// ```no_run
+/// # use freya::prelude::*;
+/// fn app() -> Element {
+///     let mut modal = use_modal();
+///
+///     rsx!(
+///         Button {
+///             onpress: move |_| modal.open(),
+///             label { "Delete" }
+///         }
+///         Modal {
+///             modal,
+///             PopupTitle {
+///                 text: "Delete the file?"
+///             }
+///             PopupButtons {
+///                 PopupButton {
+///                     onpress: move |_| modal.close(),
+///                     label { "Cancel" }
+///                 }
+///             }
+///         }
+///     )
+/// }
+/// ```
+#[allow(non_snake_case)]
+#[component]
+pub fn Modal(
+    /// Open state of the Modal, created with [`use_modal`].
+    modal: UseModal,
+    /// Theme override.
+    theme: Option<PopupThemeWith>,
+    /// Handler for when the Modal is closed by pressing `Escape` or clicking the backdrop.
+    onclose: Option<EventHandler>,
+    /// Whether pressing `Escape` closes the Modal. Default `true`.
+    #[props(default = true)]
+    close_on_escape_key: bool,
+    /// Whether clicking the backdrop closes the Modal. Default `true`.
+    #[props(default = true)]
+    close_on_backdrop_click: bool,
+    /// Content of the Modal.
+    children: Element,
+) -> Element {
+    rsx!(if modal.is_open() {
+        ModalDialog {
+            modal,
+            theme,
+            onclose,
+            close_on_escape_key,
+            close_on_backdrop_click,
+            {children}
+        }
+    })
+}
+
+/// Content of an open [`Modal`], mounted only while it's open so it takes and restores the focus.
+#[allow(non_snake_case)]
+#[component]
+fn ModalDialog(
+    modal: UseModal,
+    theme: Option<PopupThemeWith>,
+    onclose: Option<EventHandler>,
+    close_on_escape_key: bool,
+    close_on_backdrop_click: bool,
+    children: Element,
+) -> Element {
+    let mut focus = use_focus();
+    let platform = use_platform();
+    let PopupTheme {
+        background,
+        color,
+        width,
+        height,
+    } = use_applied_theme!(&theme, popup);
+
+    use_hook(move || focus.request_focus());
+    use_drop(move || focus.focus_previous());
+
+    let mut close = move || {
+        let mut modal = modal;
+        modal.close();
+        if let Some(onclose) = &onclose {
+            onclose.call(());
+        }
+    };
+
+    let onglobalkeydown = move |e: KeyboardEvent| {
+        if close_on_escape_key && e.key == Key::Escape {
+            close();
+        }
+    };
+
+    // Move the focus between the elements of the Modal only
+    let onkeydown = move |e: KeyboardEvent| {
+        if e.key != Key::Tab {
+            return;
+        }
+        focus.prevent_navigation();
+        if e.modifiers.contains(Modifiers::SHIFT) {
+            platform.focus(AccessibilityFocusStrategy::BackwardWithin(focus.id()));
+        } else {
+            platform.focus(AccessibilityFocusStrategy::ForwardWithin(focus.id()));
+        }
+    };
+
+    rsx!(
+        PopupBackground {
+            onclick: move |_| {
+                if close_on_backdrop_click {
+                    close();
+                }
+            },
+            rect {
+                a11y_id: focus.attribute(),
+                a11y_role: "dialog",
+                a11y_modal: "true",
+                corner_radius: "12",
+                background: "{background}",
+                color: "{color}",
+                shadow: "0 4 5 0 rgb(0, 0, 0, 30)",
+                width: "{width}",
+                height: "{height}",
+                overflow: "clip",
+                spacing: "20",
+                onkeydown,
+                onglobalkeydown,
+                {children}
+            }
+        }
+    )
+}
+
+#[cfg(test)]
+mod test {
+    use freya::prelude::*;
+    use freya_testing::prelude::*;
+
+    #[tokio::test]
+    pub async fn modal() {
+        #[allow(non_snake_case)]
+        #[component]
+        fn Field(name: String) -> Element {
+            let focus = use_focus();
+
+            rsx!(rect {
+                a11y_id: focus.attribute(),
+                width: "100",
+                height: "20",
+                label {
+                    "{name} {focus.is_focused()}"
+                }
+            })
+        }
+
+        fn modal_app() -> Element {
+            let mut modal = use_modal();
+
+            rsx!(
+                rect {
+                    width: "100",
+                    height: "50",
+                    onclick: move |_| modal.open(),
+                    Field { name: "outside" }
+                }
+                Modal {
+                    modal,
+                    Field { name: "first" }
+                    Field { name: "second" }
+                }
+            )
+        }
+
+        fn tab(utils: &mut TestingHandler<()>) {
+            utils.push_event(TestEvent::Keyboard {
+                name: EventName::KeyDown,
+                key: Key::Tab,
+                code: Code::Tab,
+                modifiers: Modifiers::default(),
+            });
+        }
+
+        let mut utils = launch_test(modal_app);
+        utils.wait_for_update().await;
+        let root = utils.root();
+        let outside = root.get(0).get(0).get(0);
+        let size = utils.sdom().get().layout().size();
+
+        // Open it, the outside is dimmed
+        utils.click_cursor((5., 5.)).await;
+        utils.wait_for_update().await;
+        assert!(utils.sdom().get().layout().size() > size);
+        let dialog = root.get(1).get(1).get(0);
+        let first = dialog.get(0).get(0);
+        let second = dialog.get(1).get(0);
+
+        // The focus never leaves the Modal
+        for expected in [(true, false), (false, true), (false, false), (true, false)] {
+            tab(&mut utils);
+            utils.wait_for_update().await;
+            utils.wait_for_update().await;
+            assert_eq!(first.get(0).text() == Some("first true"), expected.0);
+            assert_eq!(second.get(0).text() == Some("second true"), expected.1);
+            assert_eq!(outside.get(0).text(), Some("outside false"));
+        }
+
+        // Close it with Escape
+        utils.push_event(TestEvent::Keyboard {
+            name: EventName::KeyDown,
+            key: Key::Escape,
+            code: Code::Escape,
+            modifiers: Modifiers::default(),
+        });
+        utils.wait_for_update().await;
+        assert_eq!(utils.sdom().get().layout().size(), size);
+
+        // Open it again and close it by clicking the backdrop
+        utils.click_cursor((5., 5.)).await;
+        utils.wait_for_update().await;
+        assert!(utils.sdom().get().layout().size() > size);
+        utils.click_cursor((5., 5.)).await;
+        utils.wait_for_update().await;
+        assert_eq!(utils.sdom().get().layout().size(), size);
+    }
+}
@@ -21,7 +21,7 @@ pub fn NativeContainer(children: Element) -> Element {
 
     let onglobalkeydown = move |e: KeyboardEvent| {
         let allowed_to_navigate = native_platform.navigation_mark.peek().allowed();
-        if e.key == Key::Tab && allowed_to_navigate {
+        if e.key == Key::Tab && allowed_to_navigate && e.default_action_enabled() {
             if e.modifiers.contains(Modifiers::SHIFT) {
                 platform.focus(AccessibilityFocusStrategy::Backward);
             } else {
@@ -0,0 +1,609 @@
+use std::{
+    fmt,
+    io::Cursor,
+    sync::{
+        mpsc,
+        Arc,
+    },
+    thread,
+};
+
+use bytes::Bytes;
+use dioxus::prelude::*;
+use freya_core::custom_attributes::dynamic_bytes;
+use freya_elements::{
+    self as dioxus_elements,
+    events::MouseEvent,
+};
+use freya_hooks::{
+    use_applied_theme,
+    PdfViewTheme,
+    PdfViewThemeWith,
+};
+use pdfium_render::prelude::{
+    PdfDocument as PdfiumDocument,
+    PdfPage as PdfiumPage,
+    PdfRenderConfig,
+    Pdfium,
+    PdfiumError,
+};
+use tokio::sync::oneshot;
+use torin::prelude::{
+    Area,
+    CursorPoint,
+};
+
+use crate::{
+    use_scroll_controller,
+    Loader,
+    ScrollConfig,
+    ScrollController,
+    VirtualScrollView,
+};
+
+/// Resolution of the rendered pages relative to their size, so they stay sharp on high density displays.
+const RENDER_SCALE: f32 = 2.;
+
+/// Space between the pages.
+const PAGE_SPACING: f32 = 12.;
+
+/// Error loading a [`PdfDocument`].
+#[derive(Debug)]
+pub enum PdfError {
+    /// Pdfium could not be loaded or failed to open the document.
+    Pdfium(PdfiumError),
+    /// The thread rendering the document stopped unexpectedly.
+    Stopped,
+}
+
+impl fmt::Display for PdfError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Pdfium(err) => write!(f, "{err}"),
+            Self::Stopped => f.write_str("The PDF renderer stopped"),
+        }
+    }
+}
+
+/// Size and text of a page of a [`PdfDocument`].
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct PdfPageInfo {
+    /// Width in points.
+    pub width: f32,
+    /// Height in points.
+    pub height: f32,
+    /// Characters of the page with their bounds relative to the page size, from `0` to `1`.
+    chars: Vec<(char, Area)>,
+}
+
+impl PdfPageInfo {
+    fn new(page: &PdfiumPage) -> Self {
+        let width = page.width().value;
+        let height = page.height().value;
+        let chars = page
+            .text()
+            .map(|text| {
+                text.chars()
+                    .iter()
+                    .filter_map(|char| {
+                        let bounds = char.loose_bounds().ok()?;
+                        // PDF coordinates start at the bottom
+                        let area = Area::new(
+                            (
+                                bounds.left().value / width,
+                                1. - bounds.top().value / height,
+                            )
+                                .into(),
+                            (bounds.width().value / width, bounds.height().value / height).into(),
+                        );
+                        Some((char.unicode_char()?, area))
+                    })
+                    .collect()
+            })
+            .unwrap_or_default();
+
+        Self {
+            width,
+            height,
+            chars,
+        }
+    }
+
+    /// Text of the page.
+    pub fn text(&self) -> String {
+        self.chars.iter().map(|(char, _)| char).collect()
+    }
+
+    /// Index of the character under the given point, or the closest one.
+    /// The point is relative to the page size, from `0` to `1`.
+    fn char_at(&self, point: CursorPoint) -> Option<usize> {
+        let point = point.to_f32();
+        self.chars
+            .iter()
+            .position(|(_, area)| area.contains(point))
+            .or_else(|| {
+                self.chars
+                    .iter()
+                    .enumerate()
+                    .min_by(|(_, (_, a)), (_, (_, b))| {
+                        let a = (a.center() - point).length();
+                        let b = (b.center() - point).length();
+                        a.total_cmp(&b)
+                    })
+                    .map(|(i, _)| i)
+            })
+    }
+}
+
+struct PdfRenderRequest {
+    page: usize,
+    width: u32,
+    reply: oneshot::Sender<Option<Bytes>>,
+}
+
+/// PDF document loaded with [Pdfium](https://pdfium.googlesource.com/pdfium/).
+///
+/// Pdfium is not thread-safe, so the document lives in its own thread where the pages get rendered.
+/// The thread stops once all the clones of the document are dropped.
+#[derive(Clone)]
+pub struct PdfDocument {
+    pages: Arc<Vec<PdfPageInfo>>,
+    sender: mpsc::Sender<PdfRenderRequest>,
+}
+
+impl PartialEq for PdfDocument {
+    fn eq(&self, other: &Self) -> bool {
+        Arc::ptr_eq(&self.pages, &other.pages)
+    }
+}
+
+impl PdfDocument {
+    /// Load a document from its bytes, using the Pdfium library installed in the system.
+    pub async fn load(bytes: Vec<u8>, password: Option<String>) -> Result<Self, PdfError> {
+        let (sender, receiver) = mpsc::channel::<PdfRenderRequest>();
+        let (loaded_sender, loaded_receiver) = oneshot::channel();
+
+        thread::spawn(move || {
+            let pdfium = match Pdfium::bind_to_system_library().map(Pdfium::new) {
+                Ok(pdfium) => pdfium,
+                Err(err) => {
+                    loaded_sender.send(Err(err)).ok();
+                    return;
+                }
+            };
+            let document = match pdfium.load_pdf_from_byte_vec(bytes, password.as_deref()) {
+                Ok(document) => document,
+                Err(err) => {
+                    loaded_sender.send(Err(err)).ok();
+                    return;
+                }
+            };
+
+            let pages = document
+                .pages()
+                .iter()
+                .map(|page| PdfPageInfo::new(&page))
+                .collect();
+            loaded_sender.send(Ok(pages)).ok();
+
+            while let Ok(PdfRenderRequest { page, width, reply }) = receiver.recv() {
+                reply.send(render_page(&document, page, width)).ok();
+            }
+        });
+
+        let pages = loaded_receiver
+            .await
+            .map_err(|_| PdfError::Stopped)?
+            .map_err(PdfError::Pdfium)?;
+
+        Ok(Self {
+            pages: Arc::new(pages),
+            sender,
+        })
+    }
+
+    /// Information about the pages of the document.
+    pub fn pages(&self) -> &[PdfPageInfo] {
+        &self.pages
+    }
+
+    /// Render a page with the given width in pixels, encoded as PNG.
+    pub async fn render_page(&self, page: usize, width: u32) -> Option<Bytes> {
+        let (reply, receiver) = oneshot::channel();
+        self.sender
+            .send(PdfRenderRequest { page, width, reply })
+            .ok()?;
+        receiver.await.ok().flatten()
+    }
+}
+
+fn render_page(document: &PdfiumDocument, page: usize, width: u32) -> Option<Bytes> {
+    let page = document.pages().get(page.try_into().ok()?).ok()?;
+    let config = PdfRenderConfig::new().set_target_width(width as i32);
+    let bitmap = page.render_with_config(&config).ok()?;
+    let mut bytes = Vec::new();
+    bitmap
+        .as_image()
+        .write_to(&mut Cursor::new(&mut bytes), image::ImageFormat::Png)
+        .ok()?;
+    Some(bytes.into())
+}
+
+/// Navigate and zoom the pages of a [`PdfView`], created with [`use_pdf_view`].
+#[derive(Clone, Copy, PartialEq)]
+pub struct PdfViewController {
+    scroll_controller: ScrollController,
+    zoom: Signal<f32>,
+    page_height: Signal<f32>,
+    page_count: Signal<usize>,
+}
+
+impl PdfViewController {
+    /// Height of every page slot in the scroll, pages are centered in it.
+    fn item_size(&self, zoom: f32) -> f32 {
+        *self.page_height.read() * zoom + PAGE_SPACING
+    }
+
+    /// Amount of pages of the document, `0` while it loads.
+    pub fn page_count(&self) -> usize {
+        *self.page_count.read()
+    }
+
+    /// Index of the page that occupies most of the top of the view.
+    pub fn current_page(&self) -> usize {
+        let (_, y) = self.scroll_controller.offset();
+        let item_size = self.item_size(*self.zoom.read());
+        if item_size <= PAGE_SPACING {
+            return 0;
+        }
+        let page = ((-y as f32 + item_size / 2.) / item_size).floor().max(0.) as usize;
+        page.min(self.page_count().saturating_sub(1))
+    }
+
+    /// Scroll to the start of the given page.
+    pub fn go_to_page(&mut self, page: usize) {
+        let page = page.min(self.page_count.peek().saturating_sub(1));
+        let item_size = self.item_size(*self.zoom.peek());
+        self.scroll_controller
+            .scroll_to_y(-(page as f32 * item_size) as i32);
+    }
+
+    /// Scroll to the next page.
+    pub fn next_page(&mut self) {
+        self.go_to_page(self.current_page() + 1);
+    }
+
+    /// Scroll to the previous page.
+    pub fn previous_page(&mut self) {
+        self.go_to_page(self.current_page().saturating_sub(1));
+    }
+
+    /// Current zoom, `1.0` displays the pages at their size in points.
+    pub fn zoom(&self) -> f32 {
+        *self.zoom.read()
+    }
+
+    /// Change the zoom, limited between `0.25` and `4.0`, keeping the scroll position.
+    pub fn set_zoom(&mut self, zoom: f32) {
+        let old_zoom = *self.zoom.peek();
+        let zoom = zoom.clamp(0.25, 4.);
+        let (_, y) = self.scroll_controller.offset();
+        let ratio = self.item_size(zoom) / self.item_size(old_zoom);
+        self.zoom.set(zoom);
+        self.scroll_controller
+            .scroll_to_y((y as f32 * ratio) as i32);
+    }
+
+    /// Zoom in by 25%.
+    pub fn zoom_in(&mut self) {
+        self.set_zoom(*self.zoom.peek() * 1.25);
+    }
+
+    /// Zoom out by 25%.
+    pub fn zoom_out(&mut self) {
+        self.set_zoom(*self.zoom.peek() / 1.25);
+    }
+}
+
+/// Create a [`PdfViewController`] to control a [`PdfView`].
+pub fn use_pdf_view() -> PdfViewController {
+    let scroll_controller = use_scroll_controller(ScrollConfig::default);
+    use_hook(|| PdfViewController {
+        scroll_controller,
+        zoom: Signal::new(1.),
+        page_height: Signal::new(0.),
+        page_count: Signal::new(0),
+    })
+}
+
+/// Properties for the [`PdfView`] component.
+#[derive(Props, Clone, PartialEq)]
+pub struct PdfViewProps {
+    /// Bytes of the PDF file.
+    pub data: ReadOnlySignal<Vec<u8>>,
+    /// Password of the document, if it's encrypted.
+    pub password: Option<String>,
+    /// Controller to navigate and zoom the document.
+    pub controller: Option<PdfViewController>,
+    /// Width of the PdfView. Default `fill`.
+    #[props(default = "fill".into())]
+    pub width: String,
+    /// Height of the PdfView. Default `fill`.
+    #[props(default = "fill".into())]
+    pub height: String,
+    /// Handler called with the selected text of a page every time the selection changes.
+    pub onselect: Option<EventHandler<String>>,
+    /// Theme override.
+    pub theme: Option<PdfViewThemeWith>,
+}
+
+/// Viewer of PDF documents rendered with [Pdfium](https://pdfium.googlesource.com/pdfium/),
+/// which must be installed in the system.
+///
+/// Only the visible pages are rendered. Text can be selected by dragging over a page when the document has it.
+/// Use a [`PdfViewController`] to navigate the pages and zoom.
+///
+/// Requires the `pdf` feature.
+///
+/// # Example
+///
+/// ```no_run
+/// # use freya::prelude::*;
+/// fn app() -> Element {
+///     let data = use_signal(|| std::fs::read("document.pdf").unwrap_or_default());
+///     let mut controller = use_pdf_view();
+///
+///     rsx!(
+///         rect {
+///             direction: "horizontal",
+///             Button {
+///                 onpress: move |_| controller.previous_page(),
+///                 label { "Previous" }
+///             }
+///             label { "{controller.current_page() + 1} / {controller.page_count()}" }
+///             Button {
+///                 onpress: move |_| controller.next_page(),
+///                 label { "Next" }
+///             }
+///             Button {
+///                 onpress: move |_| controller.zoom_in(),
+///                 label { "Zoom in" }
+///             }
+///         }
+///         PdfView {
+///             data,
+///             controller,
+///             onselect: |text| println!("Selected {text}"),
+///         }
+///     )
+/// }
+/// ```
+#[allow(non_snake_case)]
+pub fn PdfView(
+    PdfViewProps {
+        data,
+        password,
+        controller,
+        width,
+        height,
+        onselect,
+        theme,
+    }: PdfViewProps,
+) -> Element {
+    let theme = use_applied_theme!(&theme, pdf_view);
+    let mut controller = controller.unwrap_or_else(use_pdf_view);
+
+    let document = use_resource(use_reactive!(|password| async move {
+        PdfDocument::load(data(), password).await
+    }));
+
+    use_effect(move || {
+        let document = document.read();
+        let pages = match &*document {
+            Some(Ok(document)) => document.pages(),
+            _ => &[],
+        };
+        let page_height = pages.iter().map(|page| page.height).fold(0., f32::max);
+        controller.page_height.set(page_height);
+        controller.page_count.set(pages.len());
+    });
+
+    let document = document.read();
+    let content = match &*document {
+        None => rsx!(Loader {}),
+        Some(Err(err)) => rsx!(label {
+            color: "{theme.font_theme.color}",
+            "{err}"
+        }),
+        Some(Ok(document)) => {
+            let zoom = controller.zoom();
+            rsx!(VirtualScrollView {
+                scroll_controller: controller.scroll_controller,
+                length: document.pages().len(),
+                item_size: controller.item_size(zoom),
+                builder_args: (document.clone(), zoom, theme.clone(), onselect),
+                builder: move |index: usize, args: &Option<PdfPageArgs>| {
+                    let (document, zoom, theme, onselect) = args.clone().unwrap();
+                    rsx!(PdfPage {
+                        key: "{index}",
+                        document,
+                        index,
+                        zoom,
+                        theme,
+                        onselect,
+                    })
+                }
+            })
+        }
+    };
+
+    rsx!(
+        rect {
+            width: "{width}",
+            height: "{height}",
+            background: "{theme.background}",
+            main_align: "center",
+            cross_align: "center",
+            {content}
+        }
+    )
+}
+
+type PdfPageArgs = (PdfDocument, f32, PdfViewTheme, Option<EventHandler<String>>);
+
+#[component]
+fn PdfPage(
+    document: PdfDocument,
+    index: usize,
+    zoom: f32,
+    theme: PdfViewTheme,
+    onselect: Option<EventHandler<String>>,
+) -> Element {
+    let mut selection = use_signal::<Option<(usize, usize)>>(|| None);
+    let mut selecting = use_signal(|| false);
+    let info = document.pages()[index].clone();
+    let (width, height) = (info.width * zoom, info.height * zoom);
+
+    let image = use_resource(use_reactive!(|(document, index, zoom)| async move {
+        let width = (document.pages()[index].width * zoom * RENDER_SCALE) as u32;
+        document.render_page(index, width).await
+    }));
+
+    let char_at = {
+        let info = info.clone();
+        move |e: &MouseEvent| {
+            let coordinates = e.get_element_coordinates();
+            info.char_at(CursorPoint::new(
+                coordinates.x / width as f64,
+                coordinates.y / height as f64,
+            ))
+        }
+    };
+
+    let onmousedown = {
+        let char_at = char_at.clone();
+        move |e: MouseEvent| {
+            if let Some(index) = char_at(&e) {
+                selection.set(Some((index, index)));
+                selecting.set(true);
+            }
+        }
+    };
+
+    let onmousemove = move |e: MouseEvent| {
+        if !*selecting.peek() {
+            return;
+        }
+        if let (Some(index), Some((anchor, _))) = (char_at(&e), *selection.peek()) {
+            selection.set(Some((anchor, index)));
+        }
+    };
+
+    let onglobalmousedown = move |_: MouseEvent| {
+        // Selecting in another page or anywhere else clears this selection
+        if !*selecting.peek() && selection.peek().is_some() {
+            selection.set(None);
+        }
+    };
+
+    let onglobalclick = {
+        let info = info.clone();
+        move |_: MouseEvent| {
+            if !*selecting.peek() {
+                return;
+            }
+            selecting.set(false);
+            if let (Some((anchor, focus)), Some(onselect)) = (*selection.peek(), onselect) {
+                let text = info.chars[anchor.min(focus)..=anchor.max(focus)]
+                    .iter()
+                    .map(|(char, _)| char)
+                    .collect();
+                onselect.call(text);
+            }
+        }
+    };
+
+    let highlights = selection()
+        .map(|(anchor, focus)| {
+            info.chars[anchor.min(focus)..=anchor.max(focus)]
+                .iter()
+                .map(|(_, area)| {
+                    (
+                        area.min_x() * width,
+                        area.min_y() * height,
+                        area.width() * width,
+                        area.height() * height,
+                    )
+                })
+                .collect::<Vec<_>>()
+        })
+        .unwrap_or_default();
+
+    rsx!(
+        rect {
+            width: "fill",
+            height: "fill",
+            main_align: "center",
+            cross_align: "center",
+            rect {
+                width: "{width}",
+                height: "{height}",
+                background: "{theme.page_background}",
+                shadow: "0 2 8 0 rgb(0, 0, 0, 0.2)",
+                onmousedown,
+                onmousemove,
+                onglobalmousedown,
+                onglobalclick,
+                if let Some(Some(bytes)) = &*image.read() {
+                    image {
+                        width: "fill",
+                        height: "fill",
+                        image_data: dynamic_bytes(bytes.clone()),
+                    }
+                }
+                for (left, top, width, height) in highlights {
+                    rect {
+                        position: "absolute",
+                        position_left: "{left}",
+                        position_top: "{top}",
+                        width: "{width}",
+                        height: "{height}",
+                        background: "{theme.selection_background}",
+                    }
+                }
+            }
+        }
+    )
+}
+
+#[cfg(test)]
+mod test {
+    use torin::prelude::{
+        Area,
+        CursorPoint,
+    };
+
+    use super::PdfPageInfo;
+
+    #[test]
+    fn char_at() {
+        let page = PdfPageInfo {
+            width: 100.,
+            height: 100.,
+            chars: vec![
+                ('H', Area::new((0.1, 0.1).into(), (0.1, 0.1).into())),
+                ('i', Area::new((0.2, 0.1).into(), (0.1, 0.1).into())),
+                ('!', Area::new((0.1, 0.5).into(), (0.1, 0.1).into())),
+            ],
+        };
+
+        assert_eq!(page.text(), "Hi!");
+        assert_eq!(page.char_at(CursorPoint::new(0.25, 0.15)), Some(1));
+        // Closest char
+        assert_eq!(page.char_at(CursorPoint::new(0.15, 0.9)), Some(2));
+        assert_eq!(
+            PdfPageInfo::default().char_at(CursorPoint::new(0.5, 0.5)),
+            None
+        );
+    }
+}
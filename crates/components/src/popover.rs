@@ -0,0 +1,432 @@
+use std::time::Duration;
+
+use dioxus::prelude::*;
+use freya_elements::{
+    self as dioxus_elements,
+    events::{
+        Key,
+        KeyboardEvent,
+        MouseEvent,
+    },
+};
+use freya_hooks::{
+    use_applied_theme,
+    use_focus,
+    use_node_signal,
+    use_platform_information,
+    TooltipTheme,
+    TooltipThemeWith,
+};
+use torin::prelude::{
+    Area,
+    Point2D,
+    Size2D,
+};
+
+/// Size of the arrow of the [`Popover`], before it's rotated.
+const ARROW_SIZE: f32 = 10.;
+
+/// Side of the anchor where a [`Popover`] is placed.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum PopoverPlacement {
+    Top,
+    #[default]
+    Bottom,
+    Left,
+    Right,
+}
+
+impl PopoverPlacement {
+    fn opposite(self) -> Self {
+        match self {
+            Self::Top => Self::Bottom,
+            Self::Bottom => Self::Top,
+            Self::Left => Self::Right,
+            Self::Right => Self::Left,
+        }
+    }
+}
+
+/// Interaction that opens a [`Popover`].
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum PopoverTrigger {
+    /// Open while the anchor or the Popover are hovered.
+    #[default]
+    Hover,
+    /// Open while the anchor is focused.
+    Focus,
+    /// Toggle by clicking the anchor, closed by clicking anywhere else or pressing `Escape`.
+    Click,
+}
+
+/// Origin of a popover of the given size, placed at the `placement` side of `anchor`
+/// and centered along it.
+///
+/// The popover is flipped to the opposite side if it overflows the viewport and fits there,
+/// and then clamped along the other axis so it stays inside of it. Returns the side where
+/// it was finally placed.
+pub(crate) fn place_popover(
+    anchor: Area,
+    size: Size2D,
+    viewport: Size2D,
+    placement: PopoverPlacement,
+    gap: f32,
+) -> (PopoverPlacement, Point2D) {
+    let position = |placement: PopoverPlacement| match placement {
+        PopoverPlacement::Top => Point2D::new(
+            anchor.center().x - size.width / 2.,
+            anchor.min_y() - gap - size.height,
+        ),
+        PopoverPlacement::Bottom => {
+            Point2D::new(anchor.center().x - size.width / 2., anchor.max_y() + gap)
+        }
+        PopoverPlacement::Left => Point2D::new(
+            anchor.min_x() - gap - size.width,
+            anchor.center().y - size.height / 2.,
+        ),
+        PopoverPlacement::Right => {
+            Point2D::new(anchor.max_x() + gap, anchor.center().y - size.height / 2.)
+        }
+    };
+    let fits = |placement: PopoverPlacement, position: Point2D| match placement {
+        PopoverPlacement::Top => position.y >= 0.,
+        PopoverPlacement::Bottom => position.y + size.height <= viewport.height,
+        PopoverPlacement::Left => position.x >= 0.,
+        PopoverPlacement::Right => position.x + size.width <= viewport.width,
+    };
+
+    let mut placement = placement;
+    let mut origin = position(placement);
+    if !fits(placement, origin) {
+        let flipped = position(placement.opposite());
+        if fits(placement.opposite(), flipped) {
+            placement = placement.opposite();
+            origin = flipped;
+        }
+    }
+
+    let clamp = |position: f32, size: f32, viewport: f32| position.min(viewport - size).max(0.);
+    match placement {
+        PopoverPlacement::Top | PopoverPlacement::Bottom => {
+            origin.x = clamp(origin.x, size.width, viewport.width);
+        }
+        PopoverPlacement::Left | PopoverPlacement::Right => {
+            origin.y = clamp(origin.y, size.height, viewport.height);
+        }
+    }
+
+    (placement, origin)
+}
+
+/// Properties for the [`Popover`] component.
+#[derive(Props, Clone, PartialEq)]
+pub struct PopoverProps {
+    /// Theme override.
+    pub theme: Option<TooltipThemeWith>,
+    /// Content of the Popover.
+    pub content: Element,
+    /// Side of the anchor where the Popover is placed, if it fits.
+    /// Default [`PopoverPlacement::Bottom`].
+    #[props(default)]
+    pub placement: PopoverPlacement,
+    /// Interaction that opens the Popover. Default [`PopoverTrigger::Hover`].
+    #[props(default)]
+    pub trigger: PopoverTrigger,
+    /// Distance between the anchor and the Popover. Default `8`.
+    #[props(default = 8.)]
+    pub gap: f32,
+    /// Whether to show an arrow pointing to the anchor. Default `true`.
+    #[props(default = true)]
+    pub arrow: bool,
+    /// Anchor of the Popover.
+    pub children: Element,
+}
+
+/// Floating content anchored to its children, displayed over the rest of the app.
+///
+/// It's placed at the chosen side of the anchor and flipped to the opposite side if it doesn't
+/// fit in the window. It can be opened by hovering, focusing or clicking the anchor.
+/// For simple texts see the [`crate::Tooltip`].
+///
+/// # Styling
+/// Inherits the [`TooltipTheme`](freya_hooks::TooltipTheme) theme.
+///
+/// # Example
+///
+/// ```no_run
+/// # use freya::prelude::*;
+/// fn app() -> Element {
+///     rsx!(Popover {
+///         placement: PopoverPlacement::Right,
+///         trigger: PopoverTrigger::Click,
+///         content: rsx!(
+///             label { "Saved 2 minutes ago" }
+///         ),
+///         Button {
+///             label { "Details" }
+///         }
+///     })
+/// }
+/// ```
+#[allow(non_snake_case)]
+pub fn Popover(
+    PopoverProps {
+        theme,
+        content,
+        placement,
+        trigger,
+        gap,
+        arrow,
+        children,
+    }: PopoverProps,
+) -> Element {
+    let TooltipTheme {
+        background,
+        color,
+        border_fill,
+    } = use_applied_theme!(&theme, tooltip);
+    let focus = use_focus();
+    let mut hovered = use_signal(|| false);
+    let mut clicked = use_signal(|| false);
+    let mut leave_task = use_signal::<Option<Task>>(|| None);
+    let (anchor_reference, anchor_layout) = use_node_signal();
+    let (popover_reference, popover_layout) = use_node_signal();
+    let platform_information = use_platform_information();
+
+    let is_open = match trigger {
+        PopoverTrigger::Hover => hovered(),
+        PopoverTrigger::Focus => focus.is_focused(),
+        PopoverTrigger::Click => clicked(),
+    };
+
+    let onmouseenter = move |_: MouseEvent| {
+        if let Some(task) = leave_task.write().take() {
+            task.cancel();
+        }
+        hovered.set(true);
+    };
+
+    // Give some time to move the cursor between the anchor and the Popover
+    let onmouseleave = move |_: MouseEvent| {
+        let task = spawn(async move {
+            tokio::time::sleep(Duration::from_millis(100)).await;
+            hovered.set(false);
+        });
+        if let Some(task) = leave_task.write().replace(task) {
+            task.cancel();
+        }
+    };
+
+    let onclick = move |_: MouseEvent| {
+        if trigger == PopoverTrigger::Click {
+            clicked.toggle();
+        }
+    };
+
+    // Close it if clicked anywhere else
+    let onglobalclick = move |e: MouseEvent| {
+        if !*clicked.peek() {
+            return;
+        }
+        let coordinates = e.get_screen_coordinates();
+        let cursor = Point2D::new(coordinates.x as f32, coordinates.y as f32);
+        let is_inside = anchor_layout.peek().area.contains(cursor)
+            || popover_layout.peek().area.contains(cursor);
+        if !is_inside {
+            clicked.set(false);
+        }
+    };
+
+    let onglobalkeydown = move |e: KeyboardEvent| {
+        if e.key == Key::Escape && *clicked.peek() {
+            clicked.set(false);
+        }
+    };
+
+    let anchor = anchor_layout.read().area;
+    let size = popover_layout.read().area.size;
+    let (placement, position) = place_popover(
+        anchor,
+        size,
+        platform_information.read().viewport_size,
+        placement,
+        gap,
+    );
+
+    // The arrow points to the center of the anchor, as long as it's within the Popover
+    let arrow_offset = ARROW_SIZE / 2.;
+    let (arrow_left, arrow_top) = match placement {
+        PopoverPlacement::Top | PopoverPlacement::Bottom => (
+            (anchor.center().x - position.x - arrow_offset).clamp(
+                arrow_offset,
+                (size.width - ARROW_SIZE * 1.5).max(arrow_offset),
+            ),
+            if placement == PopoverPlacement::Top {
+                size.height - arrow_offset
+            } else {
+                -arrow_offset
+            },
+        ),
+        PopoverPlacement::Left | PopoverPlacement::Right => (
+            if placement == PopoverPlacement::Left {
+                size.width - arrow_offset
+            } else {
+                -arrow_offset
+            },
+            (anchor.center().y - position.y - arrow_offset).clamp(
+                arrow_offset,
+                (size.height - ARROW_SIZE * 1.5).max(arrow_offset),
+            ),
+        ),
+    };
+    let a11y_role = match trigger {
+        PopoverTrigger::Click => "dialog",
+        PopoverTrigger::Hover | PopoverTrigger::Focus => "tooltip",
+    };
+
+    rsx!(
+        rect {
+            reference: anchor_reference,
+            a11y_id: focus.attribute(),
+            a11y_focusable: "{trigger == PopoverTrigger::Focus}",
+            onmouseenter,
+            onmouseleave,
+            onclick,
+            onglobalclick,
+            onglobalkeydown,
+            {children}
+        }
+        if is_open {
+            rect {
+                reference: popover_reference,
+                a11y_role,
+                position: "global",
+                position_left: "{position.x}",
+                position_top: "{position.y}",
+                layer: "-1000",
+                onmouseenter,
+                onmouseleave,
+                if arrow {
+                    rect {
+                        position: "absolute",
+                        position_left: "{arrow_left}",
+                        position_top: "{arrow_top}",
+                        width: "{ARROW_SIZE}",
+                        height: "{ARROW_SIZE}",
+                        rotate: "45deg",
+                        background: "{background}",
+                        border: "1 inner {border_fill}",
+                    }
+                }
+                rect {
+                    padding: "8 12",
+                    shadow: "0 1 2 1 rgb(0, 0, 0, 0.05)",
+                    border: "1 inner {border_fill}",
+                    corner_radius: "8",
+                    background: "{background}",
+                    color: "{color}",
+                    {content}
+                }
+            }
+        }
+    )
+}
+
+#[cfg(test)]
+mod test {
+    use freya::prelude::*;
+    use freya_testing::prelude::*;
+    use torin::prelude::{
+        Area,
+        Point2D,
+        Size2D,
+    };
+
+    use super::place_popover;
+
+    #[test]
+    pub fn placement() {
+        let viewport = Size2D::new(500., 500.);
+        let size = Size2D::new(100., 50.);
+        let anchor = |x: f32, y: f32| Area::new(Point2D::new(x, y), Size2D::new(40., 20.));
+
+        // Fits below, centered
+        assert_eq!(
+            place_popover(
+                anchor(200., 200.),
+                size,
+                viewport,
+                PopoverPlacement::Bottom,
+                8.
+            ),
+            (PopoverPlacement::Bottom, Point2D::new(170., 228.))
+        );
+        // Flipped above
+        assert_eq!(
+            place_popover(
+                anchor(200., 460.),
+                size,
+                viewport,
+                PopoverPlacement::Bottom,
+                8.
+            ),
+            (PopoverPlacement::Top, Point2D::new(170., 402.))
+        );
+        // Flipped to the right and clamped vertically
+        assert_eq!(
+            place_popover(anchor(10., 0.), size, viewport, PopoverPlacement::Left, 8.),
+            (PopoverPlacement::Right, Point2D::new(58., 0.))
+        );
+        // Clamped horizontally
+        assert_eq!(
+            place_popover(
+                anchor(480., 200.),
+                size,
+                viewport,
+                PopoverPlacement::Top,
+                8.
+            ),
+            (PopoverPlacement::Top, Point2D::new(400., 142.))
+        );
+    }
+
+    #[tokio::test]
+    pub async fn popover() {
+        fn popover_app() -> Element {
+            rsx!(
+                Popover {
+                    trigger: PopoverTrigger::Click,
+                    content: rsx!(label { "Content" }),
+                    rect {
+                        width: "100",
+                        height: "50",
+                    }
+                }
+            )
+        }
+
+        let mut utils = launch_test(popover_app);
+        utils.wait_for_update().await;
+        let root = utils.root();
+
+        // Open it by clicking the anchor, it's placed below
+        utils.click_cursor((5., 5.)).await;
+        utils.wait_for_update().await;
+        utils.wait_for_update().await;
+        assert_eq!(root.children_ids().len(), 2);
+        let popover = root.get(1).layout().unwrap().area;
+        assert_eq!(popover.min_y(), 58.);
+
+        // Clicking the Popover keeps it open
+        utils
+            .click_cursor((popover.center().x as f64, popover.center().y as f64))
+            .await;
+        utils.wait_for_update().await;
+        assert_eq!(root.children_ids().len(), 2);
+
+        // Clicking anywhere else closes it
+        utils.click_cursor((300., 300.)).await;
+        utils.wait_for_update().await;
+        assert_eq!(root.children_ids().len(), 1);
+    }
+}
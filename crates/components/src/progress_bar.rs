@@ -1,11 +1,19 @@
 use dioxus::prelude::*;
 use freya_elements as dioxus_elements;
 use freya_hooks::{
+    use_animation,
     use_applied_theme,
+    AnimNum,
+    Ease,
+    Function,
+    OnFinish,
     ProgressBarTheme,
     ProgressBarThemeWith,
 };
 
+/// Width, in percentage, of the bar that goes back and forth when the progress is unknown.
+const INDETERMINATE_WIDTH: f32 = 30.;
+
 /// Properties for the [`ProgressBar`] component.
 #[derive(Props, Clone, PartialEq)]
 pub struct ProgressBarProps {
@@ -17,12 +25,15 @@ pub struct ProgressBarProps {
     /// Show a label with the current progress. Default to false.
     #[props(default = false)]
     pub show_progress: bool,
-    /// Percentage of the progress bar.
-    pub progress: f32,
+    /// Percentage of the progress bar, `None` when it's unknown.
+    pub progress: Option<f32>,
 }
 
 /// Display the progress of something visually. For example: downloading files, fetching data, etc.
 ///
+/// Without a `progress` a bar moves along it repeatedly,
+/// for when it's unknown how long it will take.
+///
 /// # Styling
 /// Inherits the [`ProgressBarTheme`](freya_hooks::ProgressBarTheme) theme.
 ///
@@ -70,8 +81,25 @@ pub fn ProgressBar(
         progress_background,
         height,
     } = use_applied_theme!(&theme, progress_bar);
+    let animation = use_animation(|conf| {
+        conf.on_finish(OnFinish::Restart);
+        AnimNum::new(-INDETERMINATE_WIDTH, 100.)
+            .time(1100)
+            .ease(Ease::InOut)
+            .function(Function::Sine)
+    });
+
+    let is_indeterminate = progress.is_none();
+    use_effect(use_reactive!(|is_indeterminate| {
+        if is_indeterminate {
+            animation.start();
+        } else {
+            animation.reset();
+        }
+    }));
 
-    let progress = progress.clamp(0., 100.);
+    // Where the bar starts when the progress is unknown, it's clipped once it goes past the end
+    let start = animation.get().read().read();
 
     rsx!(
         rect {
@@ -86,24 +114,37 @@ pub fn ProgressBar(
                 font_size: "13",
                 direction: "horizontal",
                 border: "1 outer {background}",
-                rect {
-                    corner_radius: "999",
-                    width: "{progress}%",
-                    height: "100%",
-                    background: "{progress_background}",
-                    main_align: "center",
-                    cross_align: "center",
-                    overflow: "clip",
-                    if show_progress {
-                        label {
-                            text_align: "center",
-                            width: "100%",
-                            color: "{color}",
-                            max_lines: "1",
-                            text_height: "disable-least-ascent",
-                            "{progress.floor()}%"
+                overflow: "clip",
+                if let Some(progress) = progress.map(|progress| progress.clamp(0., 100.)) {
+                    rect {
+                        corner_radius: "999",
+                        width: "{progress}%",
+                        height: "100%",
+                        background: "{progress_background}",
+                        main_align: "center",
+                        cross_align: "center",
+                        overflow: "clip",
+                        if show_progress {
+                            label {
+                                text_align: "center",
+                                width: "100%",
+                                color: "{color}",
+                                max_lines: "1",
+                                text_height: "disable-least-ascent",
+                                "{progress.floor()}%"
+                            }
                         }
                     }
+                } else {
+                    rect {
+                        width: "{start.max(0.)}%",
+                    }
+                    rect {
+                        corner_radius: "999",
+                        width: "{INDETERMINATE_WIDTH + start.min(0.)}%",
+                        height: "100%",
+                        background: "{progress_background}",
+                    }
                 }
             }
         }
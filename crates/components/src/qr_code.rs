@@ -0,0 +1,275 @@
+use dioxus::prelude::*;
+use freya_core::{
+    custom_attributes::CanvasRunnerContext,
+    parsing::Parse,
+};
+use freya_elements as dioxus_elements;
+use freya_engine::prelude::*;
+use freya_hooks::{
+    use_applied_theme,
+    use_canvas_with_deps,
+    use_node_signal,
+    use_platform,
+    QrCodeThemeWith,
+};
+use qrcodegen::QrCodeEcc;
+
+/// How much of a [`QrCode`] can be damaged or covered while still being readable.
+/// Higher levels make the code denser.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum QrErrorCorrection {
+    /// About 7% can be recovered.
+    Low,
+    /// About 15% can be recovered.
+    #[default]
+    Medium,
+    /// About 25% can be recovered.
+    Quartile,
+    /// About 30% can be recovered, recommended when embedding a logo.
+    High,
+}
+
+impl From<QrErrorCorrection> for QrCodeEcc {
+    fn from(value: QrErrorCorrection) -> Self {
+        match value {
+            QrErrorCorrection::Low => QrCodeEcc::Low,
+            QrErrorCorrection::Medium => QrCodeEcc::Medium,
+            QrErrorCorrection::Quartile => QrCodeEcc::Quartile,
+            QrErrorCorrection::High => QrCodeEcc::High,
+        }
+    }
+}
+
+/// Dark modules of an encoded QR code, grouped in horizontal runs so they are painted without seams.
+#[derive(Debug, PartialEq)]
+struct QrModules {
+    /// Amount of modules of every side.
+    size: i32,
+    /// Row, first column and length of every run.
+    runs: Vec<(i32, i32, i32)>,
+}
+
+impl QrModules {
+    /// Encode the given text, leaving empty the centered square that covers `hole` of every side.
+    fn encode(value: &str, error_correction: QrErrorCorrection, hole: f32) -> Option<Self> {
+        let qr = match qrcodegen::QrCode::encode_text(value, error_correction.into()) {
+            Ok(qr) => qr,
+            Err(err) => {
+                tracing::warn!("Failed to encode QR code: {err}");
+                return None;
+            }
+        };
+        let size = qr.size();
+        let hole_size = (size as f32 * hole.clamp(0., 1.)).ceil() as i32;
+        let hole = (size - hole_size) / 2..(size - hole_size) / 2 + hole_size;
+        let is_dark =
+            |x: i32, y: i32| qr.get_module(x, y) && !(hole.contains(&x) && hole.contains(&y));
+
+        let mut runs = Vec::new();
+        for y in 0..size {
+            let mut x = 0;
+            while x < size {
+                if !is_dark(x, y) {
+                    x += 1;
+                    continue;
+                }
+                let start = x;
+                while x < size && is_dark(x, y) {
+                    x += 1;
+                }
+                runs.push((y, start, x - start));
+            }
+        }
+
+        Some(Self { size, runs })
+    }
+}
+
+/// Options that require painting the [`QrCode`] again when changed.
+#[derive(Clone, PartialEq)]
+struct QrCodeOptions {
+    value: String,
+    error_correction: QrErrorCorrection,
+    quiet_zone: u8,
+    color: String,
+    hole: f32,
+}
+
+/// Properties for the [`QrCode`] component.
+#[derive(Props, Clone, PartialEq)]
+pub struct QrCodeProps {
+    /// Text to encode.
+    pub value: String,
+    /// Width and height of the QR code. Default `200`.
+    #[props(default = 200.)]
+    pub size: f32,
+    /// Error correction level. Default [`QrErrorCorrection::Medium`].
+    #[props(default)]
+    pub error_correction: QrErrorCorrection,
+    /// Empty modules around the code, scanners need at least `4` to find it. Default `4`.
+    #[props(default = 4)]
+    pub quiet_zone: u8,
+    /// Element displayed in the center of the code, e.g. an image.
+    pub logo: Option<Element>,
+    /// Size of the logo relative to the code. Default `0.2`.
+    #[props(default = 0.2)]
+    pub logo_size: f32,
+    /// Theme override.
+    pub theme: Option<QrCodeThemeWith>,
+}
+
+/// Encode some text, like a URL, as a QR code painted with vector paths so it's crisp at any size.
+///
+/// The modules covered by the `logo` are left empty, so prefer [`QrErrorCorrection::High`]
+/// and a small `logo_size` to keep the code readable.
+///
+/// Requires the `qr-code` feature.
+///
+/// # Example
+///
+/// ```no_run
+/// # use freya::prelude::*;
+/// fn app() -> Element {
+///     rsx!(QrCode {
+///         value: "https://freyaui.dev",
+///         size: 250.,
+///         error_correction: QrErrorCorrection::High,
+///         logo: rsx!(label { "Freya" }),
+///     })
+/// }
+/// ```
+#[allow(non_snake_case)]
+pub fn QrCode(
+    QrCodeProps {
+        value,
+        size,
+        error_correction,
+        quiet_zone,
+        logo,
+        logo_size,
+        theme,
+    }: QrCodeProps,
+) -> Element {
+    let theme = use_applied_theme!(&theme, qr_code);
+    let platform = use_platform();
+    let (reference, layout) = use_node_signal();
+
+    let logo_size = logo_size.clamp(0., 1.);
+    let options = QrCodeOptions {
+        value,
+        error_correction,
+        quiet_zone,
+        color: theme.color.to_string(),
+        hole: if logo.is_some() { logo_size } else { 0. },
+    };
+
+    let canvas = use_canvas_with_deps(&options, move |options| {
+        platform.invalidate_drawing_area(layout.peek().area);
+        platform.request_animation_frame();
+        let modules = QrModules::encode(&options.value, options.error_correction, options.hole);
+        let color = Color::parse(&options.color).unwrap_or(Color::BLACK);
+        move |ctx: &mut CanvasRunnerContext| {
+            let Some(modules) = &modules else {
+                return;
+            };
+            let quiet_zone = options.quiet_zone as f32;
+            let module_size =
+                ctx.area.width().min(ctx.area.height()) / (modules.size as f32 + quiet_zone * 2.);
+            let origin = (
+                ctx.area.min_x() + quiet_zone * module_size,
+                ctx.area.min_y() + quiet_zone * module_size,
+            );
+
+            let mut path = Path::new();
+            for (y, x, length) in &modules.runs {
+                path.add_rect(
+                    Rect::from_xywh(
+                        origin.0 + *x as f32 * module_size,
+                        origin.1 + *y as f32 * module_size,
+                        *length as f32 * module_size,
+                        module_size,
+                    ),
+                    None,
+                );
+            }
+
+            // Anti-aliasing would leave visible seams between the rows
+            let mut paint = Paint::default();
+            paint.set_anti_alias(false);
+            paint.set_style(PaintStyle::Fill);
+            paint.set_color(color);
+            ctx.canvas.draw_path(&path, &paint);
+        }
+    });
+
+    // The hole is measured in modules, so the logo background also hides the modules partially under it
+    let logo_size = size * logo_size;
+
+    rsx!(
+        rect {
+            reference,
+            width: "{size}",
+            height: "{size}",
+            background: "{theme.background}",
+            main_align: "center",
+            cross_align: "center",
+            canvas_reference: canvas.attribute(),
+            if let Some(logo) = logo {
+                rect {
+                    width: "{logo_size}",
+                    height: "{logo_size}",
+                    main_align: "center",
+                    cross_align: "center",
+                    overflow: "clip",
+                    background: "{theme.background}",
+                    {logo}
+                }
+            }
+        }
+    )
+}
+
+#[cfg(test)]
+mod test {
+    use super::{
+        QrErrorCorrection,
+        QrModules,
+    };
+
+    #[test]
+    fn encode() {
+        let modules = QrModules::encode("freya", QrErrorCorrection::Low, 0.).unwrap();
+        // Version 1
+        assert_eq!(modules.size, 21);
+        // Top finder patterns
+        let first_row = modules
+            .runs
+            .iter()
+            .filter(|(y, ..)| *y == 0)
+            .collect::<Vec<_>>();
+        assert_eq!(first_row.first(), Some(&&(0, 0, 7)));
+        assert_eq!(first_row.last(), Some(&&(0, 14, 7)));
+
+        // Denser with higher error correction
+        let long_text = "https://freyaui.dev/";
+        let low = QrModules::encode(long_text, QrErrorCorrection::Low, 0.).unwrap();
+        let high = QrModules::encode(long_text, QrErrorCorrection::High, 0.).unwrap();
+        assert!(high.size > low.size);
+
+        assert_eq!(
+            QrModules::encode(&"a".repeat(8000), QrErrorCorrection::Low, 0.),
+            None
+        );
+    }
+
+    #[test]
+    fn logo_hole() {
+        let modules = QrModules::encode("freya", QrErrorCorrection::High, 0.3).unwrap();
+        // The hole covers 7 modules of every side, from 7 to 13
+        for (y, x, length) in modules.runs {
+            if (7..14).contains(&y) {
+                assert!(x + length <= 7 || x >= 14);
+            }
+        }
+    }
+}
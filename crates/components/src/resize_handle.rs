@@ -0,0 +1,377 @@
+use dioxus::prelude::*;
+use freya_core::platform::CursorIcon;
+use freya_elements::{
+    self as dioxus_elements,
+    events::MouseEvent,
+};
+use freya_hooks::{
+    use_applied_theme,
+    use_node_signal,
+    use_platform,
+    ResizableHandleTheme,
+    ResizableHandleThemeWith,
+};
+
+/// When the panel of a [ResizeHandle()] is resized.
+#[derive(Debug, Default, PartialEq, Clone, Copy)]
+pub enum ResizeMode {
+    /// Resize the panel while dragging.
+    #[default]
+    Live,
+    /// Show a preview of the new size while dragging and only resize the panel when released.
+    Deferred,
+}
+
+#[derive(Clone, Copy, PartialEq)]
+struct Drag {
+    /// Cursor position along the resize axis when the drag started.
+    start_cursor: f32,
+    /// Size of the panel when the drag started.
+    start_size: f32,
+    /// Position of the handle along the resize axis when the drag started.
+    start_handle: f32,
+}
+
+/// Handle to resize a sibling panel by dragging it, lower-level than [ResizableContainer()](crate::ResizableContainer)
+/// for custom layouts like docks. The size of the panel is in pixels and is owned by you.
+///
+/// Double-click the handle to reset the panel to its `default_size`.
+///
+/// Example:
+///
+/// ```no_run
+/// # use freya::prelude::*;
+/// fn app() -> Element {
+///     let size = use_signal(|| 250.);
+///
+///     rsx!(
+///         rect {
+///             direction: "horizontal",
+///             width: "fill",
+///             height: "fill",
+///             rect {
+///                 width: "fill",
+///                 height: "fill",
+///                 label { "Content" }
+///             }
+///             ResizeHandle {
+///                 size,
+///                 reverse: true,
+///                 min_size: 100.,
+///                 max_size: 500.,
+///                 default_size: 250.,
+///             }
+///             rect {
+///                 width: "{size}",
+///                 height: "fill",
+///                 label { "Right dock" }
+///             }
+///         }
+///     )
+/// }
+/// ```
+#[component]
+pub fn ResizeHandle(
+    /// Size in pixels of the panel resized by this handle.
+    size: Signal<f32>,
+    /// Direction in which the panel is resized, `horizontal` resizes its width and `vertical` its height.
+    /// Default to `horizontal`.
+    #[props(default = "horizontal".to_string())]
+    direction: String,
+    /// Whether the panel is placed after the handle, e.g. a right dock, so dragging towards the start grows it.
+    #[props(default = false)]
+    reverse: bool,
+    /// Minimum size in pixels of the panel. Default to `0`.
+    #[props(default = 0.)]
+    min_size: f32,
+    /// Maximum size in pixels of the panel. Default to unlimited.
+    #[props(default = f32::MAX)]
+    max_size: f32,
+    /// Size in pixels the panel is reset to with a double-click.
+    default_size: Option<f32>,
+    /// When the panel is resized. Default to [ResizeMode::Live].
+    #[props(default)]
+    mode: ResizeMode,
+    /// Handler called with the new size of the panel every time it's resized.
+    onresize: Option<EventHandler<f32>>,
+    /// Theme override.
+    theme: Option<ResizableHandleThemeWith>,
+) -> Element {
+    let ResizableHandleTheme {
+        background,
+        hover_background,
+    } = use_applied_theme!(&theme, resizable_handle);
+    let (node_reference, layout) = use_node_signal();
+    let mut drag = use_signal::<Option<Drag>>(|| None);
+    let mut preview = use_signal::<Option<f32>>(|| None);
+    let mut hovering = use_signal(|| false);
+    let platform = use_platform();
+
+    use_drop(move || {
+        if *hovering.peek() || drag.peek().is_some() {
+            platform.set_cursor(CursorIcon::default());
+        }
+    });
+
+    let is_horizontal = direction != "vertical";
+    let cursor = if is_horizontal {
+        CursorIcon::ColResize
+    } else {
+        CursorIcon::RowResize
+    };
+    let axis = move |e: &MouseEvent| {
+        let coordinates = e.get_screen_coordinates();
+        if is_horizontal {
+            coordinates.x as f32
+        } else {
+            coordinates.y as f32
+        }
+    };
+
+    let mut resize = move |new_size: f32| {
+        if *size.peek() != new_size {
+            size.set(new_size);
+            if let Some(onresize) = &onresize {
+                onresize.call(new_size);
+            }
+        }
+    };
+
+    let onmouseenter = move |_: MouseEvent| {
+        hovering.set(true);
+        platform.set_cursor(cursor);
+    };
+
+    let onmouseleave = move |_: MouseEvent| {
+        hovering.set(false);
+        if drag.peek().is_none() {
+            platform.set_cursor(CursorIcon::default());
+        }
+    };
+
+    let onmousedown = move |e: MouseEvent| {
+        e.stop_propagation();
+
+        let area = layout.peek().area;
+        drag.set(Some(Drag {
+            start_cursor: axis(&e),
+            start_size: *size.peek(),
+            start_handle: if is_horizontal {
+                area.min_x()
+            } else {
+                area.min_y()
+            },
+        }));
+    };
+
+    let onmousemove = move |e: MouseEvent| {
+        let Some(Drag {
+            start_cursor,
+            start_size,
+            ..
+        }) = *drag.peek()
+        else {
+            return;
+        };
+
+        let mut displacement = axis(&e) - start_cursor;
+        if reverse {
+            displacement = -displacement;
+        }
+        let new_size = (start_size + displacement).clamp(min_size, max_size);
+
+        match mode {
+            ResizeMode::Live => resize(new_size),
+            ResizeMode::Deferred => preview.set(Some(new_size)),
+        }
+    };
+
+    let ondoubleclick = move |_: MouseEvent| {
+        if let Some(default_size) = default_size {
+            resize(default_size.clamp(min_size, max_size));
+        }
+    };
+
+    let onclick = move |_: MouseEvent| {
+        if drag.peek().is_none() {
+            return;
+        }
+        drag.set(None);
+        if let Some(new_size) = preview.write().take() {
+            resize(new_size);
+        }
+        if !*hovering.peek() {
+            platform.set_cursor(CursorIcon::default());
+        }
+    };
+
+    let (width, height) = if is_horizontal {
+        ("4", "fill")
+    } else {
+        ("fill", "4")
+    };
+
+    let background = if hovering() || drag.read().is_some() {
+        hover_background
+    } else {
+        background
+    };
+
+    // Line shown where the handle will be once the panel is resized
+    let preview_line = drag().zip(preview()).map(|(drag, preview)| {
+        let mut offset = preview - drag.start_size;
+        if reverse {
+            offset = -offset;
+        }
+        let position = drag.start_handle + offset;
+        let area = layout.read().area;
+        if is_horizontal {
+            (position, area.min_y(), 4., area.height())
+        } else {
+            (area.min_x(), position, area.width(), 4.)
+        }
+    });
+
+    rsx!(
+        rect {
+            reference: node_reference,
+            width: "{width}",
+            height: "{height}",
+            background: "{background}",
+            onmousedown,
+            ondoubleclick,
+            onglobalclick: onclick,
+            onmouseenter,
+            onglobalmousemove: onmousemove,
+            onmouseleave,
+            if let Some((left, top, width, height)) = preview_line {
+                rect {
+                    position: "global",
+                    position_left: "{left}",
+                    position_top: "{top}",
+                    width: "{width}",
+                    height: "{height}",
+                    layer: "-9999",
+                    background: "{hover_background}",
+                }
+            }
+        }
+    )
+}
+
+#[cfg(test)]
+mod test {
+    use freya::prelude::*;
+    use freya_testing::prelude::*;
+
+    fn drag(utils: &mut TestingHandler<()>, from: (f64, f64), to: (f64, f64)) {
+        utils.push_event(TestEvent::Mouse {
+            name: EventName::MouseDown,
+            cursor: from.into(),
+            button: Some(MouseButton::Left),
+        });
+        utils.push_event(TestEvent::Mouse {
+            name: EventName::MouseMove,
+            cursor: to.into(),
+            button: Some(MouseButton::Left),
+        });
+    }
+
+    fn release(utils: &mut TestingHandler<()>) {
+        utils.push_event(TestEvent::Mouse {
+            name: EventName::MouseUp,
+            cursor: (0.0, 0.0).into(),
+            button: Some(MouseButton::Left),
+        });
+    }
+
+    #[tokio::test]
+    pub async fn resize_handle() {
+        fn resize_handle_app() -> Element {
+            let size = use_signal(|| 200.);
+
+            rsx!(
+                rect {
+                    direction: "horizontal",
+                    width: "fill",
+                    height: "fill",
+                    rect {
+                        width: "fill",
+                        height: "fill",
+                    }
+                    ResizeHandle {
+                        size,
+                        reverse: true,
+                        min_size: 100.,
+                        max_size: 300.,
+                        default_size: 150.,
+                    }
+                    rect {
+                        width: "{size}",
+                        height: "fill",
+                    }
+                }
+            )
+        }
+
+        let mut utils = launch_test(resize_handle_app);
+        utils.wait_for_update().await;
+        let dock = utils.root().get(0).get(2);
+        assert_eq!(dock.layout().unwrap().area.width(), 200.);
+
+        // The handle is placed at 500 - 200 - 4, dragging to the left grows the dock
+        drag(&mut utils, (297., 100.), (247., 100.));
+        release(&mut utils);
+        utils.wait_for_update().await;
+        assert_eq!(dock.layout().unwrap().area.width(), 250.);
+
+        // The size is limited
+        drag(&mut utils, (247., 100.), (47., 100.));
+        release(&mut utils);
+        utils.wait_for_update().await;
+        assert_eq!(dock.layout().unwrap().area.width(), 300.);
+
+        // Double-click resets the size
+        utils.click_cursor((197., 100.)).await;
+        utils.click_cursor((197., 100.)).await;
+        assert_eq!(dock.layout().unwrap().area.width(), 150.);
+    }
+
+    #[tokio::test]
+    pub async fn resize_handle_deferred() {
+        fn resize_handle_app() -> Element {
+            let size = use_signal(|| 200.);
+
+            rsx!(
+                rect {
+                    width: "fill",
+                    height: "fill",
+                    rect {
+                        width: "fill",
+                        height: "{size}",
+                    }
+                    ResizeHandle {
+                        size,
+                        direction: "vertical",
+                        mode: ResizeMode::Deferred,
+                    }
+                }
+            )
+        }
+
+        let mut utils = launch_test(resize_handle_app);
+        utils.wait_for_update().await;
+        let panel = utils.root().get(0).get(0);
+
+        // Only the preview moves while dragging
+        drag(&mut utils, (100., 201.), (100., 251.));
+        utils.wait_for_update().await;
+        assert_eq!(panel.layout().unwrap().area.height(), 200.);
+        let preview = utils.root().get(0).get(1).get(0);
+        assert_eq!(preview.layout().unwrap().area.min_y(), 250.);
+
+        release(&mut utils);
+        utils.wait_for_update().await;
+        assert_eq!(panel.layout().unwrap().area.height(), 250.);
+    }
+}
@@ -0,0 +1,93 @@
+use dioxus::prelude::*;
+use freya_elements as dioxus_elements;
+use freya_hooks::UseScreenCapture;
+
+/// Properties for the [`ScreenCaptureView`] component.
+#[derive(Props, Clone, PartialEq)]
+pub struct ScreenCaptureViewProps {
+    /// Capture to display, created with `use_screen_capture`.
+    pub capture: UseScreenCapture,
+    /// Width of the ScreenCaptureView. Default `fill`.
+    #[props(default = "fill".into())]
+    pub width: String,
+    /// Height of the ScreenCaptureView. Default `fill`.
+    #[props(default = "fill".into())]
+    pub height: String,
+    /// Aspect ratio of the frames, see the `aspect_ratio` attribute. Default `min`.
+    #[props(default = "min".into())]
+    pub aspect_ratio: String,
+    /// Element displayed instead of the frames if the capture fails.
+    pub fallback: Option<Element>,
+    /// Elements displayed over the frames, e.g. annotations.
+    pub children: Element,
+}
+
+/// Live preview of a monitor or window, whose frames are captured in a separate thread by `use_screen_capture`.
+///
+/// Use `capture_sources` to let the user pick what to capture.
+///
+/// Requires the `use_screen_capture` feature.
+///
+/// # Example
+///
+/// ```no_run
+/// # use freya::prelude::*;
+/// fn app() -> Element {
+///     let sources = use_hook(|| capture_sources().unwrap_or_default());
+///     let capture =
+///         use_screen_capture(ScreenCaptureSettings::new(sources[0].source).with_frame_rate(30));
+///
+///     rsx!(ScreenCaptureView { capture })
+/// }
+/// ```
+#[allow(non_snake_case)]
+pub fn ScreenCaptureView(
+    ScreenCaptureViewProps {
+        capture,
+        width,
+        height,
+        aspect_ratio,
+        fallback,
+        children,
+    }: ScreenCaptureViewProps,
+) -> Element {
+    if let Some(err) = &*capture.error().read() {
+        return if let Some(fallback) = fallback {
+            fallback
+        } else {
+            rsx!(
+                rect {
+                    width: "{width}",
+                    height: "{height}",
+                    main_align: "center",
+                    cross_align: "center",
+                    label {
+                        "{err}"
+                    }
+                }
+            )
+        };
+    }
+
+    rsx!(
+        rect {
+            width: "{width}",
+            height: "{height}",
+            image {
+                width: "fill",
+                height: "fill",
+                aspect_ratio: "{aspect_ratio}",
+                reference: capture.attribute(),
+                image_reference: capture.image_attribute(),
+            }
+            rect {
+                position: "absolute",
+                position_top: "0",
+                position_left: "0",
+                width: "fill",
+                height: "fill",
+                {children}
+            }
+        }
+    )
+}
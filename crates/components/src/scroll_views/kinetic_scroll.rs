@@ -0,0 +1,373 @@
+use std::{
+    collections::VecDeque,
+    time::Duration,
+};
+
+use dioxus::prelude::*;
+use freya_hooks::UsePlatform;
+use tokio::time::{
+    sleep,
+    Instant,
+};
+
+use crate::ScrollController;
+
+/// Visual feedback of a [`ScrollView`](crate::ScrollView) with kinetic scroll when scrolled past its edges.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum OverscrollEffect {
+    /// Stop at the edges.
+    #[default]
+    None,
+    /// Let the content go past the edges with some resistance and spring back, like in iOS.
+    Bounce,
+    /// Show a glow in the edge that was hit, like in Android.
+    Glow,
+}
+
+/// Movements older than this are not taken into account for the velocity of a fling.
+const VELOCITY_WINDOW: Duration = Duration::from_millis(100);
+/// Velocity, in pixels per millisecond, under which the momentum stops.
+const MIN_VELOCITY: f32 = 0.02;
+/// Intensity of the glow, in pixels, at which it saturates.
+pub(crate) const MAX_GLOW: f32 = 60.;
+/// Fraction of the velocity kept every frame while overscrolled.
+const OVERSCROLL_DAMPING: f32 = 0.6;
+/// Fraction of the overscroll kept every frame while springing back.
+const OVERSCROLL_RELAXATION: f32 = 0.8;
+/// Inactivity after which a wheel gesture from a device that doesn't report phases is considered released.
+const WHEEL_RELEASE_DELAY: Duration = Duration::from_millis(80);
+
+/// Velocity, in pixels per millisecond, of the movements done in the last [`VELOCITY_WINDOW`].
+fn fling_velocity(samples: &VecDeque<(Instant, f32, f32)>, now: Instant) -> (f32, f32) {
+    let recent = samples
+        .iter()
+        .filter(|(instant, ..)| now.duration_since(*instant) <= VELOCITY_WINDOW);
+    let Some((first, ..)) = recent.clone().next() else {
+        return (0., 0.);
+    };
+    // Movements reported at once still need some time to have been done
+    let elapsed = (now.duration_since(*first).as_micros() as f32 / 1000.).max(16.);
+    let (x, y) = recent.fold((0., 0.), |(x, y), (_, dx, dy)| (x + dx, y + dy));
+    (x / elapsed, y / elapsed)
+}
+
+/// Move an axis by `delta`, returning its new offset and overscroll.
+///
+/// `min_offset` is the offset at which the end of the content is visible, negative or `0`.
+fn apply_delta(
+    offset: f32,
+    overscroll: f32,
+    delta: f32,
+    min_offset: f32,
+    viewport: f32,
+    effect: OverscrollEffect,
+) -> (f32, f32) {
+    let (mut delta, mut overscroll) = (delta, overscroll);
+
+    // Moving back first reduces the overscroll
+    if overscroll != 0. && overscroll.signum() != delta.signum() {
+        let reduced = overscroll + delta;
+        if reduced.signum() == overscroll.signum() {
+            return (offset, reduced);
+        }
+        delta = reduced;
+        overscroll = 0.;
+    }
+
+    let target = offset + delta;
+    let offset = target.clamp(min_offset, 0.);
+    let excess = target - offset;
+
+    // Content that fits in the viewport can't be overscrolled
+    let overscroll = match effect {
+        _ if min_offset == 0. => 0.,
+        OverscrollEffect::None => 0.,
+        OverscrollEffect::Bounce => {
+            // Harder the further it goes, up to half the viewport
+            let resistance = (1. - overscroll.abs() / (viewport / 2.)).max(0.) * 0.5;
+            overscroll + excess * resistance
+        }
+        OverscrollEffect::Glow => (overscroll + excess).clamp(-MAX_GLOW, MAX_GLOW),
+    };
+
+    (offset, overscroll)
+}
+
+/// Momentum scrolling of a [`ScrollController`], started when the user lifts the fingers
+/// off the trackpad or touchscreen.
+#[derive(Clone, Copy, PartialEq)]
+pub(crate) struct KineticScroll {
+    controller: ScrollController,
+    samples: Signal<VecDeque<(Instant, f32, f32)>>,
+    overscroll: Signal<(f32, f32)>,
+    gesture: Signal<bool>,
+    task: Signal<Option<Task>>,
+    effect: OverscrollEffect,
+    deceleration: f32,
+}
+
+impl KineticScroll {
+    /// Offset past the edges of both axes. Positive past the start and negative past the end.
+    pub fn overscroll(&self) -> (f32, f32) {
+        *self.overscroll.read()
+    }
+
+    /// The fingers were placed on the device, any momentum is stopped.
+    pub fn start(&mut self) {
+        self.stop();
+        self.samples.write().clear();
+        self.gesture.set(true);
+    }
+
+    /// Scroll both axes by the given deltas, returning whether anything moved.
+    pub fn scroll_by(&mut self, delta_x: f32, delta_y: f32) -> bool {
+        self.stop();
+
+        let layout = *self.controller.layout().peek();
+        let (mut x, mut y) = self.controller.into();
+        let (overscroll_x, overscroll_y) = *self.overscroll.peek();
+
+        let (new_x, new_overscroll_x) = apply_delta(
+            *x.peek() as f32,
+            overscroll_x,
+            delta_x,
+            (layout.area.width() - layout.inner.width).min(0.),
+            layout.area.width(),
+            self.effect,
+        );
+        let (new_y, new_overscroll_y) = apply_delta(
+            *y.peek() as f32,
+            overscroll_y,
+            delta_y,
+            (layout.area.height() - layout.inner.height).min(0.),
+            layout.area.height(),
+            self.effect,
+        );
+
+        let moved = *x.peek() != new_x as i32
+            || *y.peek() != new_y as i32
+            || (overscroll_x, overscroll_y) != (new_overscroll_x, new_overscroll_y);
+        if moved {
+            x.set(new_x as i32);
+            y.set(new_y as i32);
+            self.overscroll.set((new_overscroll_x, new_overscroll_y));
+        }
+
+        let mut samples = self.samples.write();
+        let now = Instant::now();
+        samples.retain(|(instant, ..)| now.duration_since(*instant) <= VELOCITY_WINDOW);
+        samples.push_back((now, delta_x, delta_y));
+        drop(samples);
+
+        // Not all devices report when the fingers are lifted
+        if !*self.gesture.peek() {
+            let mut kinetic = *self;
+            let platform = UsePlatform::current();
+            self.task.set(Some(spawn(async move {
+                sleep(WHEEL_RELEASE_DELAY).await;
+                kinetic.momentum(platform).await;
+            })));
+        }
+
+        moved
+    }
+
+    /// The fingers were lifted from the device, the content keeps moving with the last velocity.
+    pub fn release(&mut self) {
+        self.stop();
+        self.gesture.set(false);
+        let mut kinetic = *self;
+        let platform = UsePlatform::current();
+        self.task.set(Some(spawn(async move {
+            kinetic.momentum(platform).await;
+        })));
+    }
+
+    /// Stop the momentum, if any.
+    fn stop(&mut self) {
+        if let Some(task) = self.task.write().take() {
+            task.cancel();
+        }
+    }
+
+    /// Decelerate the content until it stops and spring back any overscroll.
+    async fn momentum(&mut self, platform: UsePlatform) {
+        let (mut x, mut y) = self.controller.into();
+        let mut velocity = fling_velocity(&self.samples.peek(), Instant::now());
+        self.samples.write().clear();
+
+        let mut ticker = platform.new_ticker();
+        let mut position = (*x.peek() as f32, *y.peek() as f32);
+        let mut last_tick = Instant::now();
+
+        loop {
+            platform.request_animation_frame();
+            ticker.tick().await;
+
+            // Something else, like the scrollbar, moved the content
+            if (*x.peek(), *y.peek()) != (position.0 as i32, position.1 as i32) {
+                break;
+            }
+
+            let elapsed = last_tick.elapsed().as_secs_f32() * 1000.;
+            let frames = elapsed / 16.;
+            last_tick = Instant::now();
+
+            let layout = *self.controller.layout().peek();
+            let (mut overscroll_x, mut overscroll_y) = *self.overscroll.peek();
+
+            let mut advance = |offset: &mut f32, overscroll: &mut f32, velocity: &mut f32, axis| {
+                *velocity *= self.deceleration.powf(elapsed);
+                if *overscroll != 0. {
+                    *velocity *= OVERSCROLL_DAMPING.powf(frames);
+                }
+                if velocity.abs() < MIN_VELOCITY {
+                    *velocity = 0.;
+                }
+
+                if *velocity != 0. {
+                    let (min_offset, viewport) = if axis == 0 {
+                        (
+                            layout.area.width() - layout.inner.width,
+                            layout.area.width(),
+                        )
+                    } else {
+                        (
+                            layout.area.height() - layout.inner.height,
+                            layout.area.height(),
+                        )
+                    };
+                    (*offset, *overscroll) = apply_delta(
+                        *offset,
+                        *overscroll,
+                        *velocity * elapsed,
+                        min_offset.min(0.),
+                        viewport,
+                        self.effect,
+                    );
+                } else {
+                    *overscroll *= OVERSCROLL_RELAXATION.powf(frames);
+                    if overscroll.abs() < 0.5 {
+                        *overscroll = 0.;
+                    }
+                }
+            };
+            advance(&mut position.0, &mut overscroll_x, &mut velocity.0, 0);
+            advance(&mut position.1, &mut overscroll_y, &mut velocity.1, 1);
+
+            x.set(position.0 as i32);
+            y.set(position.1 as i32);
+            self.overscroll.set((overscroll_x, overscroll_y));
+
+            if velocity == (0., 0.) && (overscroll_x, overscroll_y) == (0., 0.) {
+                break;
+            }
+        }
+
+        self.task.set(None);
+    }
+}
+
+/// Create a [`KineticScroll`] for the given [`ScrollController`].
+///
+/// `deceleration` is the fraction of the velocity kept every millisecond.
+pub(crate) fn use_kinetic_scroll(
+    controller: ScrollController,
+    effect: OverscrollEffect,
+    deceleration: f32,
+) -> KineticScroll {
+    let (samples, overscroll, gesture, task) = use_hook(|| {
+        (
+            Signal::new(VecDeque::new()),
+            Signal::new((0., 0.)),
+            Signal::new(false),
+            Signal::new(None),
+        )
+    });
+
+    KineticScroll {
+        controller,
+        samples,
+        overscroll,
+        gesture,
+        task,
+        effect,
+        deceleration: deceleration.clamp(0., 1.),
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use std::{
+        collections::VecDeque,
+        time::Duration,
+    };
+
+    use tokio::time::Instant;
+
+    use super::{
+        apply_delta,
+        fling_velocity,
+        OverscrollEffect,
+    };
+
+    #[test]
+    fn velocity() {
+        let now = Instant::now();
+        let samples = VecDeque::from([
+            (now - Duration::from_millis(300), 500., 0.),
+            (now - Duration::from_millis(50), 0., -20.),
+            (now, 0., -30.),
+        ]);
+        assert_eq!(fling_velocity(&samples, now), (0., -1.));
+
+        // At least a frame has passed
+        let samples = VecDeque::from([(now, 0., 32.)]);
+        assert_eq!(fling_velocity(&samples, now), (0., 2.));
+
+        assert_eq!(fling_velocity(&VecDeque::new(), now), (0., 0.));
+    }
+
+    #[test]
+    fn overscroll() {
+        // Stops at the edges
+        assert_eq!(
+            apply_delta(-10., 0., 50., -300., 500., OverscrollEffect::None),
+            (0., 0.)
+        );
+        assert_eq!(
+            apply_delta(-290., 0., -50., -300., 500., OverscrollEffect::None),
+            (-300., 0.)
+        );
+
+        // Bounces with resistance
+        let (offset, overscroll) =
+            apply_delta(-10., 0., 50., -300., 500., OverscrollEffect::Bounce);
+        assert_eq!(offset, 0.);
+        assert_eq!(overscroll, 20.);
+        let (_, further) = apply_delta(0., overscroll, 40., -300., 500., OverscrollEffect::Bounce);
+        assert!(further - overscroll < 20.);
+
+        // Moving back first reduces the overscroll
+        assert_eq!(
+            apply_delta(0., 20., -10., -300., 500., OverscrollEffect::Bounce),
+            (0., 10.)
+        );
+        assert_eq!(
+            apply_delta(0., 20., -30., -300., 500., OverscrollEffect::Bounce),
+            (-10., 0.)
+        );
+
+        // The glow saturates
+        assert_eq!(
+            apply_delta(-300., 0., -200., -300., 500., OverscrollEffect::Glow),
+            (-300., -60.)
+        );
+
+        // Content that fits can't be overscrolled
+        assert_eq!(
+            apply_delta(0., 0., 50., 0., 500., OverscrollEffect::Bounce),
+            (0., 0.)
+        );
+    }
+}
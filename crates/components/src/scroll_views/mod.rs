@@ -1,17 +1,23 @@
+mod kinetic_scroll;
 mod scroll_bar;
+mod scroll_restoration;
 mod scroll_thumb;
 mod scroll_view;
 mod use_scroll_controller;
+mod virtual_grid_view;
 mod virtual_scroll_view;
 
 use freya_elements::events::{
     keyboard::Key,
     KeyboardEvent,
 };
+pub use kinetic_scroll::*;
 pub use scroll_bar::*;
+pub use scroll_restoration::*;
 pub use scroll_thumb::*;
 pub use scroll_view::*;
 pub use use_scroll_controller::*;
+pub use virtual_grid_view::*;
 pub use virtual_scroll_view::*;
 
 // Holding alt while scrolling makes it 5x faster (VSCode behavior).
@@ -0,0 +1,280 @@
+use std::{
+    cell::RefCell,
+    collections::HashMap,
+    rc::Rc,
+};
+
+use dioxus::prelude::{
+    provide_root_context,
+    try_consume_context,
+    use_drop,
+    use_effect,
+    use_hook,
+};
+use dioxus_router::prelude::RouterContext;
+
+use crate::ScrollController;
+
+/// Scroll positions of the views of a visited route.
+struct HistoryEntry {
+    id: usize,
+    route: String,
+    positions: HashMap<String, (i32, i32)>,
+    /// Amount of mounted views without a `restoration_key`.
+    unnamed_views: usize,
+}
+
+#[derive(Default)]
+struct ScrollHistory {
+    entries: Vec<HistoryEntry>,
+    current: usize,
+    next_id: usize,
+}
+
+impl ScrollHistory {
+    /// Track the navigation to the given route, returns whether it was a back or forward
+    /// navigation along with its entry.
+    ///
+    /// Returning to the previous or next route of the history counts as going back or forward.
+    fn visit(&mut self, route: String) -> (bool, &mut HistoryEntry) {
+        let is_current = |entries: &[HistoryEntry], index: usize| {
+            entries.get(index).is_some_and(|entry| entry.route == route)
+        };
+
+        let is_traversal = if is_current(&self.entries, self.current) {
+            true
+        } else if self.current > 0 && is_current(&self.entries, self.current - 1) {
+            self.current -= 1;
+            true
+        } else if is_current(&self.entries, self.current + 1) {
+            self.current += 1;
+            true
+        } else {
+            // Navigating somewhere new forgets the routes that could be gone forward to
+            self.entries.truncate(self.current + 1);
+            self.entries.push(HistoryEntry {
+                id: self.next_id,
+                route,
+                positions: HashMap::new(),
+                unnamed_views: 0,
+            });
+            self.next_id += 1;
+            self.current = self.entries.len() - 1;
+            false
+        };
+
+        (is_traversal, &mut self.entries[self.current])
+    }
+}
+
+/// Scroll positions remembered for each visited route, see [`use_scroll_restoration`].
+#[derive(Clone, Default)]
+pub struct ScrollRestoration(Rc<RefCell<ScrollHistory>>);
+
+impl ScrollRestoration {
+    /// Forget the remembered positions of the views with the given `restoration_key`,
+    /// so they start from the beginning the next time.
+    pub fn reset(&self, key: &str) {
+        for entry in self.0.borrow_mut().entries.iter_mut() {
+            entry.positions.remove(key);
+        }
+    }
+
+    /// Forget all the remembered positions.
+    pub fn reset_all(&self) {
+        for entry in self.0.borrow_mut().entries.iter_mut() {
+            entry.positions.clear();
+        }
+    }
+
+    fn entry(&self, entry_id: usize, f: impl FnOnce(&mut HistoryEntry)) {
+        let mut history = self.0.borrow_mut();
+        if let Some(entry) = history
+            .entries
+            .iter_mut()
+            .find(|entry| entry.id == entry_id)
+        {
+            f(entry);
+        }
+    }
+}
+
+/// Get the scroll positions remembered for each visited route of the app.
+///
+/// The [`ScrollView`](crate::ScrollView), [`VirtualScrollView`](crate::VirtualScrollView) and
+/// [`VirtualGridView`](crate::VirtualGridView) inside of a router remember their scroll position,
+/// and restore it when going back or forward to their route. This can be disabled with their
+/// `restore_scroll` prop, or reset for the views with a certain `restoration_key`.
+///
+/// ```rust,no_run
+/// # use freya::prelude::*;
+/// #[component]
+/// fn Feed() -> Element {
+///     let scroll_restoration = use_scroll_restoration();
+///
+///     rsx!(
+///         Button {
+///             onpress: move |_| scroll_restoration.reset("posts"),
+///             label { "Refresh" }
+///         }
+///         ScrollView {
+///             restoration_key: "posts",
+///             label { "Posts" }
+///         }
+///     )
+/// }
+/// ```
+pub fn use_scroll_restoration() -> ScrollRestoration {
+    use_hook(|| {
+        try_consume_context::<ScrollRestoration>()
+            .unwrap_or_else(|| provide_root_context(ScrollRestoration::default()))
+    })
+}
+
+/// Restore the scroll position of a view when going back or forward to its route,
+/// and remember it while it's scrolled. Nothing is done outside of a router.
+pub(crate) fn use_restored_scroll(
+    mut scroll_controller: ScrollController,
+    restore_scroll: bool,
+    restoration_key: Option<String>,
+) {
+    let scroll_restoration = use_scroll_restoration();
+
+    let view = use_hook(|| {
+        if !restore_scroll {
+            return None;
+        }
+        let route = try_consume_context::<RouterContext>()?.full_route_string();
+        let mut history = scroll_restoration.0.borrow_mut();
+        let (is_traversal, entry) = history.visit(route);
+
+        // Views without a key are told apart by the order in which they are mounted
+        let key = restoration_key.clone().unwrap_or_else(|| {
+            entry.unnamed_views += 1;
+            format!("#{}", entry.unnamed_views)
+        });
+
+        if is_traversal {
+            if let Some((x, y)) = entry.positions.get(&key) {
+                scroll_controller.restore(*x, *y);
+            }
+        }
+
+        Some((entry.id, key, restoration_key.is_none()))
+    });
+
+    let dropped_view = view.clone();
+    let restoration = scroll_restoration.clone();
+    use_effect(move || {
+        let offset = scroll_controller.offset();
+        if let Some((entry_id, key, _)) = &view {
+            restoration.entry(*entry_id, |entry| {
+                entry.positions.insert(key.clone(), offset);
+            });
+        }
+    });
+
+    use_drop(move || {
+        if let Some((entry_id, _, true)) = dropped_view {
+            scroll_restoration.entry(entry_id, |entry| entry.unnamed_views -= 1);
+        }
+    });
+}
+
+#[cfg(test)]
+mod test {
+    use dioxus_router::prelude::{
+        Outlet,
+        Routable,
+        Router,
+    };
+    use freya::prelude::*;
+    use freya_core::events::EventName;
+    use freya_testing::prelude::*;
+
+    #[tokio::test]
+    pub async fn scroll_restoration() {
+        #[derive(Routable, Clone, PartialEq)]
+        #[rustfmt::skip]
+        pub enum Route {
+            #[layout(App)]
+                #[route("/")]
+                A,
+                #[route("/B")]
+                B,
+        }
+
+        #[allow(non_snake_case)]
+        #[component]
+        fn A() -> Element {
+            let scroll_controller = use_scroll_controller(ScrollConfig::default);
+
+            rsx!(
+                label {
+                    "{scroll_controller.y()}"
+                }
+                ScrollView {
+                    scroll_controller,
+                    for i in 0..10 {
+                        rect {
+                            key: "{i}",
+                            height: "100",
+                            width: "100",
+                        }
+                    }
+                }
+            )
+        }
+
+        #[allow(non_snake_case)]
+        #[component]
+        fn B() -> Element {
+            rsx!(
+                label {
+                    "B"
+                }
+            )
+        }
+
+        #[allow(non_snake_case)]
+        fn App() -> Element {
+            rsx!(
+                NativeRouter {
+                    Link {
+                        to: Route::B,
+                        label {
+                            "Go to B"
+                        }
+                    }
+                    Outlet::<Route> {  }
+                }
+            )
+        }
+
+        let mut utils = launch_test(|| -> Element { rsx!(Router::<Route> {}) });
+        utils.wait_for_update().await;
+        let offset = |utils: &TestingHandler<()>| {
+            utils.root().get(0).get(1).get(0).text().map(str::to_string)
+        };
+
+        utils.push_event(TestEvent::Wheel {
+            name: EventName::Wheel,
+            scroll: (0., -300.).into(),
+            cursor: (5., 100.).into(),
+        });
+        utils.wait_for_update().await;
+        assert_eq!(offset(&utils).as_deref(), Some("-300"));
+
+        // Go to B and back to A, the scroll position is restored
+        utils.click_cursor((5., 5.)).await;
+        assert_eq!(utils.root().get(0).get(1).get(0).text(), Some("B"));
+        utils.push_event(TestEvent::Mouse {
+            name: EventName::MouseUp,
+            cursor: (5.0, 5.0).into(),
+            button: Some(MouseButton::Back),
+        });
+        utils.wait_for_update().await;
+        utils.wait_for_update().await;
+        assert_eq!(offset(&utils).as_deref(), Some("-300"));
+    }
+}
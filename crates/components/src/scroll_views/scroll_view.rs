@@ -5,7 +5,10 @@ use freya_elements::{
         keyboard::Key,
         KeyboardEvent,
         MouseEvent,
+        TouchEvent,
+        WheelDeltaMode,
         WheelEvent,
+        WheelPhase,
     },
 };
 use freya_hooks::{
@@ -14,8 +17,17 @@ use freya_hooks::{
     use_node_from_signal,
     ScrollBarThemeWith,
 };
+use torin::prelude::CursorPoint;
 
-use super::use_scroll_controller::ScrollController;
+use super::{
+    kinetic_scroll::{
+        use_kinetic_scroll,
+        OverscrollEffect,
+        MAX_GLOW,
+    },
+    scroll_restoration::use_restored_scroll,
+    use_scroll_controller::ScrollController,
+};
 use crate::{
     get_container_sizes,
     get_corrected_scroll_position,
@@ -76,6 +88,22 @@ pub struct ScrollViewProps {
     /// If `true`, wheel scroll with no shift will scroll horizontally.
     #[props(default = false)]
     pub invert_scroll_wheel: bool,
+    /// Keep scrolling with deceleration after a trackpad or touch fling, and scroll by dragging with touch.
+    /// Mouse wheels scroll as usual.
+    #[props(default = false)]
+    pub kinetic_scroll: bool,
+    /// Effect when the content is scrolled past its edges with kinetic scroll. Default [`OverscrollEffect::None`].
+    #[props(default)]
+    pub overscroll_effect: OverscrollEffect,
+    /// Fraction of the velocity kept every millisecond with kinetic scroll. Default `0.998`.
+    #[props(default = 0.998)]
+    pub scroll_deceleration: f32,
+    /// Restore the scroll position when going back or forward to its route. Default `true`.
+    #[props(default = true)]
+    pub restore_scroll: bool,
+    /// Key of its remembered scroll position, see [`use_scroll_restoration`](crate::use_scroll_restoration).
+    #[props(into)]
+    pub restoration_key: Option<String>,
 }
 
 /// Scrollable area with bidirectional support and scrollbars.
@@ -149,6 +177,26 @@ pub struct ScrollViewProps {
 /// # }, (250., 250.).into(), "./images/gallery_scroll_view.png");
 /// ```
 ///
+/// # Kinetic scroll
+///
+/// ```no_run
+/// # use freya::prelude::*;
+/// fn app() -> Element {
+///     rsx!(
+///         ScrollView {
+///             kinetic_scroll: true,
+///             overscroll_effect: OverscrollEffect::Bounce,
+///             for i in 0..100 {
+///                 label {
+///                     key: "{i}",
+///                     "Item {i}"
+///                 }
+///             }
+///         }
+///     )
+/// }
+/// ```
+///
 /// # Preview
 /// ![ScrollView Preview][scroll_view]
 #[cfg_attr(feature = "docs",
@@ -172,6 +220,11 @@ pub fn ScrollView(
         scroll_with_arrows,
         scroll_controller,
         invert_scroll_wheel,
+        kinetic_scroll,
+        overscroll_effect,
+        scroll_deceleration,
+        restore_scroll,
+        restoration_key,
     }: ScrollViewProps,
 ) -> Element {
     let mut clicking_scrollbar = use_signal::<Option<(Axis, f64)>>(|| None);
@@ -181,11 +234,14 @@ pub fn ScrollView(
         scroll_controller.unwrap_or_else(|| use_scroll_controller(ScrollConfig::default));
     let (mut scrolled_x, mut scrolled_y) = scroll_controller.into();
     let (node_ref, size) = use_node_from_signal(|| scroll_controller.layout());
+    let mut kinetic = use_kinetic_scroll(scroll_controller, overscroll_effect, scroll_deceleration);
+    let mut touch = use_signal::<Option<(u64, CursorPoint)>>(|| None);
 
     let mut focus = use_focus();
     let applied_scrollbar_theme = use_applied_theme!(&scrollbar_theme, scroll_bar);
 
     scroll_controller.use_apply(size.inner.width, size.inner.height);
+    use_restored_scroll(scroll_controller, restore_scroll, restoration_key);
 
     let vertical_scrollbar_is_visible = is_scrollbar_visible(
         show_scrollbar,
@@ -219,6 +275,11 @@ pub fn ScrollView(
 
     // Moves the axis when the user scrolls in the container
     let onwheel = move |e: WheelEvent| {
+        // A nested element took care of this wheel event
+        if !e.default_action_enabled() {
+            return;
+        }
+
         let speed_multiplier = if *clicking_alt.peek() {
             SCROLL_SPEED_MULTIPLIER
         } else {
@@ -240,6 +301,28 @@ pub fn ScrollView(
             )
         };
 
+        if kinetic_scroll && e.get_delta_mode() == WheelDeltaMode::Pixel {
+            // The momentum is emulated instead
+            if e.is_momentum() {
+                e.stop_propagation();
+                return;
+            }
+
+            match e.get_phase() {
+                WheelPhase::Started => kinetic.start(),
+                WheelPhase::Ended => {
+                    kinetic.release();
+                    return;
+                }
+                WheelPhase::Moved => {}
+            }
+
+            if kinetic.scroll_by(x_movement, y_movement) {
+                e.stop_propagation();
+            }
+            return;
+        }
+
         let scroll_position_y = get_scroll_position_from_wheel(
             y_movement,
             size.inner.height,
@@ -267,6 +350,42 @@ pub fn ScrollView(
         }
     };
 
+    // Drag the content with a finger
+    let ontouchstart = move |e: TouchEvent| {
+        if kinetic_scroll && touch.peek().is_none() {
+            touch.set(Some((e.get_finger_id(), e.get_screen_coordinates())));
+            kinetic.start();
+        }
+    };
+
+    let ontouchmove = move |e: TouchEvent| {
+        let Some((finger_id, previous)) = *touch.peek() else {
+            return;
+        };
+        if finger_id != e.get_finger_id() {
+            return;
+        }
+
+        let location = e.get_screen_coordinates();
+        touch.set(Some((finger_id, location)));
+        if kinetic.scroll_by(
+            (location.x - previous.x) as f32,
+            (location.y - previous.y) as f32,
+        ) {
+            e.stop_propagation();
+        }
+    };
+
+    let ontouchend = move |e: TouchEvent| {
+        if touch
+            .peek()
+            .is_some_and(|(finger_id, _)| finger_id == e.get_finger_id())
+        {
+            touch.set(None);
+            kinetic.release();
+        }
+    };
+
     // Drag the scrollbars
     let onmousemove = move |e: MouseEvent| {
         let clicking_scrollbar = clicking_scrollbar.peek();
@@ -309,7 +428,7 @@ pub fn ScrollView(
                 clicking_alt.set(true);
             }
             k => {
-                if !focus.is_focused() {
+                if !focus.is_focused() || !e.default_action_enabled() {
                     return;
                 }
                 if !scroll_with_arrows
@@ -383,6 +502,29 @@ pub fn ScrollView(
 
     let a11y_id = focus.attribute();
 
+    let (overscroll_x, overscroll_y) = kinetic.overscroll();
+    let (bounce_x, bounce_y) = if overscroll_effect == OverscrollEffect::Bounce {
+        (overscroll_x, overscroll_y)
+    } else {
+        (0., 0.)
+    };
+    let (glow_x, glow_y) = if overscroll_effect == OverscrollEffect::Glow {
+        (overscroll_x, overscroll_y)
+    } else {
+        (0., 0.)
+    };
+    let glow_background = &applied_scrollbar_theme.overscroll_glow;
+    let (glow_top, glow_vertical_radius) = if glow_y > 0. {
+        (0., "0 0 999 999")
+    } else {
+        (size.area.height() + glow_y, "999 999 0 0")
+    };
+    let (glow_left, glow_horizontal_radius) = if glow_x > 0. {
+        (0., "0 999 999 0")
+    } else {
+        (size.area.width() + glow_x, "999 0 0 999")
+    };
+
     rsx!(
         rect {
             a11y_role:"scroll-view",
@@ -415,12 +557,38 @@ pub fn ScrollView(
                     max_width: max_width.map(|x| x.to_string()),
                     max_height: max_height.map(|x| x.to_string()),
                     direction: direction,
-                    offset_y: "{corrected_scrolled_y}",
-                    offset_x: "{corrected_scrolled_x}",
+                    offset_y: "{corrected_scrolled_y + bounce_y}",
+                    offset_x: "{corrected_scrolled_x + bounce_x}",
                     reference: node_ref,
                     onwheel,
+                    ontouchstart,
+                    ontouchmove,
+                    ontouchend,
+                    ontouchcancel: ontouchend,
                     {children}
                 }
+                if glow_y != 0. {
+                    rect {
+                        position: "absolute",
+                        position_top: "{glow_top}",
+                        width: "100%",
+                        height: "{glow_y.abs()}",
+                        corner_radius: glow_vertical_radius,
+                        background: "{glow_background}",
+                        opacity: "{glow_y.abs() / MAX_GLOW * 0.5}",
+                    }
+                }
+                if glow_x != 0. {
+                    rect {
+                        position: "absolute",
+                        position_left: "{glow_left}",
+                        width: "{glow_x.abs()}",
+                        height: "{size.area.height()}",
+                        corner_radius: glow_horizontal_radius,
+                        background: "{glow_background}",
+                        opacity: "{glow_x.abs() / MAX_GLOW * 0.5}",
+                    }
+                }
                 if show_scrollbar && horizontal_scrollbar_is_visible {
                     ScrollBar {
                         size: &applied_scrollbar_theme.size,
@@ -459,8 +627,11 @@ pub fn ScrollView(
 
 #[cfg(test)]
 mod test {
+    use std::time::Duration;
+
     use freya::prelude::*;
     use freya_testing::prelude::*;
+    use tokio::time::sleep;
 
     #[tokio::test]
     pub async fn scroll_view_wheel() {
@@ -515,6 +686,58 @@ mod test {
         assert!(content.get(3).is_visible()); // 4. 600 -> 800, 800 > 300
     }
 
+    #[tokio::test]
+    pub async fn scroll_view_wheel_prevented() {
+        fn scroll_view_wheel_prevented_app() -> Element {
+            rsx!(
+                ScrollView {
+                    rect {
+                        height: "200",
+                        width: "200",
+                        onwheel: move |e: WheelEvent| e.prevent_default(),
+                    }
+                    rect {
+                        height: "200",
+                        width: "200",
+                    }
+                    rect {
+                        height: "200",
+                        width: "200",
+                    }
+                    rect {
+                        height: "200",
+                        width: "200",
+                    }
+                }
+            )
+        }
+
+        let mut utils = launch_test(scroll_view_wheel_prevented_app);
+        let root = utils.root();
+        let content = root.get(0).get(0).get(0);
+        utils.wait_for_update().await;
+
+        // The first item handles the wheel events itself
+        utils.push_event(TestEvent::Wheel {
+            name: EventName::Wheel,
+            scroll: (0., -300.).into(),
+            cursor: (5., 5.).into(),
+        });
+        utils.wait_for_update().await;
+        assert!(content.get(0).is_visible());
+        assert!(!content.get(3).is_visible());
+
+        // The rest of the content scrolls as usual
+        utils.push_event(TestEvent::Wheel {
+            name: EventName::Wheel,
+            scroll: (0., -300.).into(),
+            cursor: (5., 305.).into(),
+        });
+        utils.wait_for_update().await;
+        assert!(!content.get(0).is_visible());
+        assert!(content.get(3).is_visible());
+    }
+
     #[tokio::test]
     pub async fn scroll_view_scrollbar() {
         fn scroll_view_scrollbar_app() -> Element {
@@ -613,4 +836,53 @@ mod test {
         assert!(content.get(2).is_visible());
         assert!(content.get(3).is_visible());
     }
+
+    #[tokio::test]
+    pub async fn scroll_view_kinetic() {
+        fn scroll_view_kinetic_app() -> Element {
+            rsx!(
+                ScrollView {
+                    kinetic_scroll: true,
+                    for i in 0..10 {
+                        rect {
+                            key: "{i}",
+                            height: "200",
+                            width: "200",
+                        }
+                    }
+                }
+            )
+        }
+
+        let mut utils = launch_test(scroll_view_kinetic_app);
+        let root = utils.root();
+        let content = root.get(0).get(0).get(0);
+        utils.wait_for_update().await;
+
+        // Fling upwards with a finger
+        for (name, y, phase) in [
+            (EventName::TouchStart, 400., TouchPhase::Started),
+            (EventName::TouchMove, 300., TouchPhase::Moved),
+            (EventName::TouchEnd, 300., TouchPhase::Ended),
+        ] {
+            utils.push_event(TestEvent::Touch {
+                name,
+                location: (100., y).into(),
+                finger_id: 0,
+                phase,
+                force: None,
+            });
+            utils.wait_for_update().await;
+        }
+        assert!(content.get(0).is_visible());
+        assert!(!content.get(3).is_visible());
+
+        // The content keeps moving after the finger was lifted
+        for _ in 0..10 {
+            sleep(Duration::from_millis(20)).await;
+            utils.wait_for_update().await;
+        }
+        assert!(!content.get(0).is_visible());
+        assert!(content.get(3).is_visible());
+    }
 }
@@ -3,15 +3,24 @@ use std::collections::HashSet;
 use dioxus::prelude::{
     current_scope_id,
     schedule_update_any,
+    spawn,
     use_drop,
     use_hook,
     Readable,
     ScopeId,
     Signal,
+    Task,
     Writable,
     WritableVecExt,
 };
 use freya_core::custom_attributes::NodeReferenceLayout;
+use freya_hooks::{
+    Transition,
+    UsePlatform,
+};
+use tokio::time::Instant;
+
+use crate::get_corrected_scroll_position;
 
 #[derive(Default, PartialEq, Eq)]
 pub enum ScrollPosition {
@@ -59,6 +68,7 @@ pub struct ScrollController {
     x: Signal<i32>,
     y: Signal<i32>,
     layout: Signal<NodeReferenceLayout>,
+    animations: Signal<[Option<Task>; 2]>,
 }
 
 impl From<ScrollController> for (Signal<i32>, Signal<i32>) {
@@ -75,6 +85,7 @@ impl ScrollController {
             requests_subscribers: Signal::new(HashSet::new()),
             requests: Signal::new(initial_requests),
             layout: Signal::default(),
+            animations: Signal::default(),
         }
     }
 
@@ -90,6 +101,11 @@ impl ScrollController {
         self.layout
     }
 
+    /// Current scroll offset of both axes, negative as the content moves towards the start.
+    pub fn offset(&self) -> (i32, i32) {
+        (*self.x.read(), *self.y.read())
+    }
+
     pub fn use_apply(&mut self, width: f32, height: f32) {
         let scope_id = current_scope_id().unwrap();
 
@@ -152,6 +168,13 @@ impl ScrollController {
         });
     }
 
+    /// Scroll to a remembered offset, instead of the initial position of the view.
+    pub(crate) fn restore(&mut self, x: i32, y: i32) {
+        self.requests.write().retain(|request| !request.init);
+        self.x.set(x);
+        self.y.set(y);
+    }
+
     pub fn scroll_to_x(&mut self, to: i32) {
         self.x.set(to);
     }
@@ -160,6 +183,121 @@ impl ScrollController {
         self.y.set(to);
     }
 
+    /// Animate the horizontal scroll to the given offset.
+    pub fn animate_to_x(&mut self, to: i32, transition: Transition) {
+        self.animate(0, to, transition);
+    }
+
+    /// Animate the vertical scroll to the given offset.
+    pub fn animate_to_y(&mut self, to: i32, transition: Transition) {
+        self.animate(1, to, transition);
+    }
+
+    /// Scroll so the given node of a [`ScrollView`](crate::ScrollView) is placed at the start of the viewport,
+    /// or as close as possible. Animated if a [`Transition`] is passed.
+    ///
+    /// The node layout can be obtained with `use_node_signal`.
+    pub fn scroll_to_node(&mut self, node: &NodeReferenceLayout, transition: Option<Transition>) {
+        let viewport = self.layout.peek().area;
+        self.scroll_by(
+            node.area.min_x() - viewport.min_x(),
+            node.area.min_y() - viewport.min_y(),
+            transition,
+        );
+    }
+
+    /// Scroll the least possible so the given node of a [`ScrollView`](crate::ScrollView) is fully visible,
+    /// nothing is done if it already is. Animated if a [`Transition`] is passed.
+    ///
+    /// The node layout can be obtained with `use_node_signal`.
+    pub fn scroll_into_view(&mut self, node: &NodeReferenceLayout, transition: Option<Transition>) {
+        let viewport = self.layout.peek().area;
+        let distance = |start: f32, end: f32, viewport_start: f32, viewport_end: f32| {
+            if start < viewport_start || end - start > viewport_end - viewport_start {
+                start - viewport_start
+            } else if end > viewport_end {
+                end - viewport_end
+            } else {
+                0.
+            }
+        };
+        self.scroll_by(
+            distance(
+                node.area.min_x(),
+                node.area.max_x(),
+                viewport.min_x(),
+                viewport.max_x(),
+            ),
+            distance(
+                node.area.min_y(),
+                node.area.max_y(),
+                viewport.min_y(),
+                viewport.max_y(),
+            ),
+            transition,
+        );
+    }
+
+    /// Move the content by the given distance, limited to the scrollable area.
+    fn scroll_by(&mut self, distance_x: f32, distance_y: f32, transition: Option<Transition>) {
+        let layout = *self.layout.peek();
+        let correct_x =
+            |x: f32| get_corrected_scroll_position(layout.inner.width, layout.area.width(), x);
+        let correct_y =
+            |y: f32| get_corrected_scroll_position(layout.inner.height, layout.area.height(), y);
+        let to_x = correct_x(correct_x(*self.x.peek() as f32) - distance_x) as i32;
+        let to_y = correct_y(correct_y(*self.y.peek() as f32) - distance_y) as i32;
+
+        if let Some(transition) = transition {
+            self.animate_to_x(to_x, transition);
+            self.animate_to_y(to_y, transition);
+        } else {
+            self.scroll_to_x(to_x);
+            self.scroll_to_y(to_y);
+        }
+    }
+
+    /// Animate the offset of an axis, `0` for horizontal and `1` for vertical.
+    /// The animation stops if the offset is changed by something else in the meantime, like the user scrolling.
+    fn animate(&mut self, axis: usize, to: i32, transition: Transition) {
+        let mut offset = if axis == 0 { self.x } else { self.y };
+        let mut animations = self.animations;
+
+        if let Some(task) = animations.write()[axis].take() {
+            task.cancel();
+        }
+
+        let from = *offset.peek();
+        if from == to {
+            return;
+        }
+
+        let platform = UsePlatform::current();
+        let mut ticker = platform.new_ticker();
+        let task = spawn(async move {
+            let started = Instant::now();
+            let mut last = from;
+            loop {
+                platform.request_animation_frame();
+                ticker.tick().await;
+
+                if *offset.peek() != last {
+                    break;
+                }
+
+                let elapsed = started.elapsed();
+                last = transition.value_at(from as f32, to as f32, elapsed).round() as i32;
+                offset.set(last);
+
+                if transition.is_finished(elapsed) {
+                    break;
+                }
+            }
+            animations.write()[axis] = None;
+        });
+        animations.write()[axis] = Some(task);
+    }
+
     pub fn scroll_to(
         &mut self,
         scroll_position: ScrollPosition,
@@ -200,8 +338,11 @@ pub fn use_scroll_controller(init: impl FnOnce() -> ScrollConfig) -> ScrollContr
 
 #[cfg(test)]
 mod test {
+    use std::time::Duration;
+
     use freya::prelude::*;
     use freya_testing::prelude::*;
+    use tokio::time::sleep;
 
     #[tokio::test]
     pub async fn controlled_scroll_view() {
@@ -279,4 +420,70 @@ mod test {
         assert!(content.get(3).is_visible());
         assert!(content.get(4).is_visible());
     }
+
+    #[tokio::test]
+    pub async fn animated_scroll_controller() {
+        fn scroll_view_app() -> Element {
+            let mut scroll_controller = use_scroll_controller(ScrollConfig::default);
+            let (reference, layout) = use_node_signal();
+
+            rsx!(
+                rect {
+                    height: "50",
+                    width: "fill",
+                    direction: "horizontal",
+                    rect {
+                        width: "50%",
+                        height: "fill",
+                        onclick: move |_| scroll_controller.animate_to_y(-400, Transition::new(50)),
+                    }
+                    rect {
+                        width: "50%",
+                        height: "fill",
+                        onclick: move |_| scroll_controller.scroll_into_view(&layout.read(), None),
+                    }
+                }
+                ScrollView {
+                    height: "400",
+                    scroll_controller,
+                    for i in 0..4 {
+                        rect {
+                            key: "{i}",
+                            height: "200",
+                            width: "200",
+                        }
+                    }
+                    rect {
+                        reference,
+                        height: "200",
+                        width: "200",
+                    }
+                    rect {
+                        height: "200",
+                        width: "200",
+                    }
+                }
+                label {
+                    "{scroll_controller.offset():?}"
+                }
+            )
+        }
+
+        let mut utils = launch_test(scroll_view_app);
+        utils.wait_for_update().await;
+        let label = utils.root().get(2);
+        assert_eq!(label.get(0).text(), Some("(0, 0)"));
+
+        // Animate the scroll
+        utils.click_cursor((15., 25.)).await;
+        for _ in 0..5 {
+            sleep(Duration::from_millis(20)).await;
+            utils.wait_for_update().await;
+        }
+        assert_eq!(label.get(0).text(), Some("(0, -400)"));
+
+        // Scroll the least to make the fifth item visible
+        utils.click_cursor((300., 25.)).await;
+        assert_eq!(label.get(0).text(), Some("(0, -600)"));
+    }
 }
@@ -0,0 +1,496 @@
+#![allow(clippy::type_complexity)]
+
+use dioxus::prelude::*;
+use freya_elements::{
+    self as dioxus_elements,
+    events::{
+        keyboard::Key,
+        KeyboardEvent,
+        MouseEvent,
+        WheelEvent,
+    },
+};
+use freya_hooks::{
+    use_applied_theme,
+    use_focus,
+    use_node,
+    ScrollBarThemeWith,
+};
+
+use super::{
+    scroll_restoration::use_restored_scroll,
+    virtual_scroll_view::get_render_range,
+};
+use crate::{
+    get_container_sizes,
+    get_corrected_scroll_position,
+    get_scroll_position_from_cursor,
+    get_scroll_position_from_wheel,
+    get_scrollbar_pos_and_size,
+    is_scrollbar_visible,
+    manage_key_event,
+    scroll_views::use_scroll_controller,
+    Axis,
+    ScrollBar,
+    ScrollConfig,
+    ScrollController,
+    ScrollThumb,
+    SCROLL_SPEED_MULTIPLIER,
+};
+
+/// Properties for the [`VirtualGridView`] component.
+#[derive(Props, Clone)]
+pub struct VirtualGridViewProps<
+    Builder: 'static + Clone + Fn(usize, usize, &Option<BuilderArgs>) -> Element,
+    BuilderArgs: Clone + 'static + PartialEq = (),
+> {
+    /// Width of the VirtualGridView container. Default to `fill`.
+    #[props(default = "fill".into())]
+    pub width: String,
+    /// Height of the VirtualGridView container. Default to `fill`.
+    #[props(default = "fill".into())]
+    pub height: String,
+    /// Theme override for the scrollbars.
+    pub scrollbar_theme: Option<ScrollBarThemeWith>,
+    /// Quantity of rows.
+    pub rows: usize,
+    /// Quantity of columns.
+    pub columns: usize,
+    /// Height of every row.
+    pub row_height: f32,
+    /// Width of every column.
+    pub column_width: f32,
+    /// The cell builder function, called with the row and column of the cell.
+    pub builder: Builder,
+    /// The values for the cell builder function.
+    #[props(into)]
+    pub builder_args: Option<BuilderArgs>,
+    /// Show the scrollbars, visible by default.
+    #[props(default = true, into)]
+    pub show_scrollbar: bool,
+    /// Enable scrolling with arrow keys.
+    #[props(default = true, into)]
+    pub scroll_with_arrows: bool,
+    /// Custom Scroll Controller for the VirtualGridView.
+    pub scroll_controller: Option<ScrollController>,
+    /// If `false` (default), wheel scroll with no shift will scroll vertically.
+    /// If `true`, wheel scroll with no shift will scroll horizontally.
+    #[props(default = false)]
+    pub invert_scroll_wheel: bool,
+    /// Restore the scroll position when going back or forward to its route. Default `true`.
+    #[props(default = true)]
+    pub restore_scroll: bool,
+    /// Key of its remembered scroll position, see [`use_scroll_restoration`](crate::use_scroll_restoration).
+    #[props(into)]
+    pub restoration_key: Option<String>,
+}
+
+impl<
+        BuilderArgs: Clone + PartialEq,
+        Builder: Clone + Fn(usize, usize, &Option<BuilderArgs>) -> Element,
+    > PartialEq for VirtualGridViewProps<Builder, BuilderArgs>
+{
+    fn eq(&self, other: &Self) -> bool {
+        self.width == other.width
+            && self.height == other.height
+            && self.rows == other.rows
+            && self.columns == other.columns
+            && self.row_height == other.row_height
+            && self.column_width == other.column_width
+            && self.show_scrollbar == other.show_scrollbar
+            && self.scroll_with_arrows == other.scroll_with_arrows
+            && self.builder_args == other.builder_args
+            && self.scroll_controller == other.scroll_controller
+            && self.invert_scroll_wheel == other.invert_scroll_wheel
+            && self.restore_scroll == other.restore_scroll
+            && self.restoration_key == other.restoration_key
+    }
+}
+
+/// Two-directions scrollable area that only builds and renders the cells of the rows and columns
+/// visible in the viewport, this is intended for big grids of data.
+///
+/// Use cases: spreadsheets, data tables with many columns, etc.
+///
+/// # Example
+///
+/// ```no_run
+/// # use freya::prelude::*;
+/// fn app() -> Element {
+///     rsx!(VirtualGridView {
+///         rows: 10000,
+///         columns: 500,
+///         row_height: 25.0,
+///         column_width: 100.0,
+///         builder: move |row, column, _other_args: &Option<()>| {
+///             rsx! {
+///                 label {
+///                     key: "{column}",
+///                     width: "100",
+///                     height: "25",
+///                     "{row}:{column}"
+///                 }
+///             }
+///         }
+///     })
+/// }
+/// ```
+#[allow(non_snake_case)]
+pub fn VirtualGridView<
+    Builder: Clone + Fn(usize, usize, &Option<BuilderArgs>) -> Element,
+    BuilderArgs: Clone + PartialEq,
+>(
+    VirtualGridViewProps {
+        width,
+        height,
+        scrollbar_theme,
+        rows,
+        columns,
+        row_height,
+        column_width,
+        builder,
+        builder_args,
+        show_scrollbar,
+        scroll_with_arrows,
+        scroll_controller,
+        invert_scroll_wheel,
+        restore_scroll,
+        restoration_key,
+    }: VirtualGridViewProps<Builder, BuilderArgs>,
+) -> Element {
+    let mut clicking_scrollbar = use_signal::<Option<(Axis, f64)>>(|| None);
+    let mut clicking_shift = use_signal(|| false);
+    let mut clicking_alt = use_signal(|| false);
+    let mut scroll_controller =
+        scroll_controller.unwrap_or_else(|| use_scroll_controller(ScrollConfig::default));
+    let (mut scrolled_x, mut scrolled_y) = scroll_controller.into();
+    let (node_ref, size) = use_node();
+    let mut focus = use_focus();
+    let applied_scrollbar_theme = use_applied_theme!(&scrollbar_theme, scroll_bar);
+
+    let inner_width = column_width * columns as f32;
+    let inner_height = row_height * rows as f32;
+
+    scroll_controller.use_apply(inner_width, inner_height);
+    use_restored_scroll(scroll_controller, restore_scroll, restoration_key);
+
+    let vertical_scrollbar_is_visible =
+        is_scrollbar_visible(show_scrollbar, inner_height, size.area.height());
+    let horizontal_scrollbar_is_visible =
+        is_scrollbar_visible(show_scrollbar, inner_width, size.area.width());
+
+    let (container_width, content_width) = get_container_sizes(&width);
+    let (container_height, content_height) = get_container_sizes(&height);
+
+    let corrected_scrolled_y =
+        get_corrected_scroll_position(inner_height, size.area.height(), *scrolled_y.read() as f32);
+    let corrected_scrolled_x =
+        get_corrected_scroll_position(inner_width, size.area.width(), *scrolled_x.read() as f32);
+
+    let (scrollbar_y, scrollbar_height) =
+        get_scrollbar_pos_and_size(inner_height, size.area.height(), corrected_scrolled_y);
+    let (scrollbar_x, scrollbar_width) =
+        get_scrollbar_pos_and_size(inner_width, size.area.width(), corrected_scrolled_x);
+
+    // Moves the axes when the user scrolls in the container
+    let onwheel = move |e: WheelEvent| {
+        // A nested element took care of this wheel event
+        if !e.default_action_enabled() {
+            return;
+        }
+
+        let speed_multiplier = if *clicking_alt.peek() {
+            SCROLL_SPEED_MULTIPLIER
+        } else {
+            1.0
+        };
+
+        let invert_direction = (clicking_shift() || invert_scroll_wheel)
+            && (!clicking_shift() || !invert_scroll_wheel);
+
+        let (x_movement, y_movement) = if invert_direction {
+            (
+                e.get_delta_y() as f32 * speed_multiplier,
+                e.get_delta_x() as f32 * speed_multiplier,
+            )
+        } else {
+            (
+                e.get_delta_x() as f32 * speed_multiplier,
+                e.get_delta_y() as f32 * speed_multiplier,
+            )
+        };
+
+        let scroll_position_y = get_scroll_position_from_wheel(
+            y_movement,
+            inner_height,
+            size.area.height(),
+            corrected_scrolled_y,
+        );
+
+        // Only scroll when there is still area to scroll
+        if *scrolled_y.peek() != scroll_position_y {
+            e.stop_propagation();
+            *scrolled_y.write() = scroll_position_y;
+        }
+
+        let scroll_position_x = get_scroll_position_from_wheel(
+            x_movement,
+            inner_width,
+            size.area.width(),
+            corrected_scrolled_x,
+        );
+
+        // Only scroll when there is still area to scroll
+        if *scrolled_x.peek() != scroll_position_x {
+            e.stop_propagation();
+            *scrolled_x.write() = scroll_position_x;
+        }
+    };
+
+    // Drag the scrollbars
+    let onmousemove = move |e: MouseEvent| {
+        let clicking_scrollbar = clicking_scrollbar.peek();
+
+        if let Some((Axis::Y, y)) = *clicking_scrollbar {
+            let coordinates = e.get_element_coordinates();
+            let cursor_y = coordinates.y - y - size.area.min_y() as f64;
+
+            let scroll_position =
+                get_scroll_position_from_cursor(cursor_y as f32, inner_height, size.area.height());
+
+            *scrolled_y.write() = scroll_position;
+        } else if let Some((Axis::X, x)) = *clicking_scrollbar {
+            let coordinates = e.get_element_coordinates();
+            let cursor_x = coordinates.x - x - size.area.min_x() as f64;
+
+            let scroll_position =
+                get_scroll_position_from_cursor(cursor_x as f32, inner_width, size.area.width());
+
+            *scrolled_x.write() = scroll_position;
+        }
+
+        if clicking_scrollbar.is_some() {
+            focus.request_focus();
+        }
+    };
+
+    let onglobalkeydown = move |e: KeyboardEvent| {
+        match &e.key {
+            Key::Shift => {
+                clicking_shift.set(true);
+            }
+            Key::Alt => {
+                clicking_alt.set(true);
+            }
+            k => {
+                if !focus.is_focused() || !e.default_action_enabled() {
+                    return;
+                }
+
+                if !scroll_with_arrows
+                    && (k == &Key::ArrowUp
+                        || k == &Key::ArrowRight
+                        || k == &Key::ArrowDown
+                        || k == &Key::ArrowLeft)
+                {
+                    return;
+                }
+
+                let (x, y) = manage_key_event(
+                    e,
+                    (corrected_scrolled_x, corrected_scrolled_y),
+                    inner_height,
+                    inner_width,
+                    size.area.height(),
+                    size.area.width(),
+                );
+
+                scrolled_x.set(x as i32);
+                scrolled_y.set(y as i32);
+            }
+        };
+    };
+
+    let onglobalkeyup = move |e: KeyboardEvent| {
+        if e.key == Key::Shift {
+            clicking_shift.set(false);
+        } else if e.key == Key::Alt {
+            clicking_alt.set(false);
+        }
+    };
+
+    // Mark the Y axis scrollbar as the one being dragged
+    let onmousedown_y = move |e: MouseEvent| {
+        let coordinates = e.get_element_coordinates();
+        *clicking_scrollbar.write() = Some((Axis::Y, coordinates.y));
+    };
+
+    // Mark the X axis scrollbar as the one being dragged
+    let onmousedown_x = move |e: MouseEvent| {
+        let coordinates = e.get_element_coordinates();
+        *clicking_scrollbar.write() = Some((Axis::X, coordinates.x));
+    };
+
+    // Unmark any scrollbar
+    let onclick = move |_: MouseEvent| {
+        if clicking_scrollbar.peek().is_some() {
+            *clicking_scrollbar.write() = None;
+        }
+    };
+
+    // Calculate what rows and columns must be rendered
+    let rows_range = get_render_range(
+        size.area.height(),
+        corrected_scrolled_y,
+        row_height,
+        rows as f32,
+    );
+    let columns_range = get_render_range(
+        size.area.width(),
+        corrected_scrolled_x,
+        column_width,
+        columns as f32,
+    );
+    let row_width = column_width * columns_range.len() as f32;
+
+    let is_scrolling_x = clicking_scrollbar
+        .read()
+        .as_ref()
+        .map(|f| f.0 == Axis::X)
+        .unwrap_or_default();
+    let is_scrolling_y = clicking_scrollbar
+        .read()
+        .as_ref()
+        .map(|f| f.0 == Axis::Y)
+        .unwrap_or_default();
+
+    // Only the part of the first visible row and column that is scrolled out is offset
+    let offset_y = corrected_scrolled_y % row_height;
+    let offset_x = corrected_scrolled_x % column_width;
+    let a11y_id = focus.attribute();
+
+    rsx!(
+        rect {
+            a11y_role: "scroll-view",
+            overflow: "clip",
+            direction: "horizontal",
+            width: "{width}",
+            height: "{height}",
+            onglobalclick: onclick,
+            onglobalmousemove: onmousemove,
+            onglobalkeydown,
+            onglobalkeyup,
+            a11y_id,
+            rect {
+                direction: "vertical",
+                width: "{container_width}",
+                height: "{container_height}",
+                rect {
+                    overflow: "clip",
+                    height: "{content_height}",
+                    width: "{content_width}",
+                    offset_x: "{offset_x}",
+                    offset_y: "{offset_y}",
+                    reference: node_ref,
+                    onwheel: onwheel,
+                    for row in rows_range {
+                        rect {
+                            key: "{row}",
+                            direction: "horizontal",
+                            width: "{row_width}",
+                            height: "{row_height}",
+                            for column in columns_range.clone() {
+                                {(builder)(row, column, &builder_args)}
+                            }
+                        }
+                    }
+                }
+                if show_scrollbar && horizontal_scrollbar_is_visible {
+                    ScrollBar {
+                        size: &applied_scrollbar_theme.size,
+                        offset_x: scrollbar_x,
+                        clicking_scrollbar: is_scrolling_x,
+                        theme: scrollbar_theme.clone(),
+                        ScrollThumb {
+                            clicking_scrollbar: is_scrolling_x,
+                            onmousedown: onmousedown_x,
+                            width: "{scrollbar_width}",
+                            height: "100%",
+                            theme: scrollbar_theme.clone(),
+                        }
+                    }
+                }
+            }
+            if show_scrollbar && vertical_scrollbar_is_visible {
+                ScrollBar {
+                    is_vertical: true,
+                    size: &applied_scrollbar_theme.size,
+                    offset_y: scrollbar_y,
+                    clicking_scrollbar: is_scrolling_y,
+                    theme: scrollbar_theme.clone(),
+                    ScrollThumb {
+                        clicking_scrollbar: is_scrolling_y,
+                        onmousedown: onmousedown_y,
+                        width: "100%",
+                        height: "{scrollbar_height}",
+                        theme: scrollbar_theme,
+                    }
+                }
+            }
+        }
+    )
+}
+
+#[cfg(test)]
+mod test {
+    use freya::prelude::*;
+    use freya_testing::prelude::*;
+
+    #[tokio::test]
+    pub async fn virtual_grid_view() {
+        fn virtual_grid_view_app() -> Element {
+            rsx!(VirtualGridView {
+                rows: 1000,
+                columns: 1000,
+                row_height: 50.0,
+                column_width: 100.0,
+                builder: move |row, column, _: &Option<()>| {
+                    rsx! {
+                        label {
+                            key: "{column}",
+                            width: "100",
+                            height: "50",
+                            "{row}:{column}"
+                        }
+                    }
+                }
+            })
+        }
+
+        let mut utils = launch_test(virtual_grid_view_app);
+        let root = utils.root();
+        utils.wait_for_update().await;
+        utils.wait_for_update().await;
+
+        // 500 / 50 + 1 rows and 500 / 100 + 1 columns
+        let content = root.get(0).get(0).get(0);
+        assert_eq!(content.children_ids().len(), 11);
+        assert_eq!(content.get(0).children_ids().len(), 6);
+        assert_eq!(content.get(0).get(0).get(0).text(), Some("0:0"));
+
+        // Scroll in both directions
+        utils.push_event(TestEvent::Wheel {
+            name: EventName::Wheel,
+            scroll: (-250., -300.).into(),
+            cursor: (5., 5.).into(),
+        });
+        utils.wait_for_update().await;
+        utils.wait_for_update().await;
+
+        let content = root.get(0).get(0).get(0);
+        assert_eq!(content.children_ids().len(), 11);
+        assert_eq!(content.get(0).children_ids().len(), 6);
+        assert_eq!(content.get(0).get(0).get(0).text(), Some("6:2"));
+        assert_eq!(content.get(10).get(5).get(0).text(), Some("16:7"));
+    }
+}
@@ -16,9 +16,11 @@ use freya_hooks::{
     use_applied_theme,
     use_focus,
     use_node,
+    use_node_signal,
     ScrollBarThemeWith,
 };
 
+use super::scroll_restoration::use_restored_scroll;
 use crate::{
     get_container_sizes,
     get_corrected_scroll_position,
@@ -56,6 +58,7 @@ pub struct VirtualScrollViewProps<
     /// Quantity of items in the VirtualScrollView.
     pub length: usize,
     /// Size of the items, height for vertical direction and width for horizontal.
+    /// Used as the estimated size of the items not measured yet if `measure_items` is enabled.
     pub item_size: f32,
     /// The item builder function.
     pub builder: Builder,
@@ -81,6 +84,16 @@ pub struct VirtualScrollViewProps<
     /// If `true`, wheel scroll with no shift will scroll horizontally.
     #[props(default = false)]
     pub invert_scroll_wheel: bool,
+    /// Measure the items once rendered so they can have different sizes, e.g. messages of a chat.
+    /// The measured sizes are cached by index. Default is `false`.
+    #[props(default = false)]
+    pub measure_items: bool,
+    /// Restore the scroll position when going back or forward to its route. Default `true`.
+    #[props(default = true)]
+    pub restore_scroll: bool,
+    /// Key of its remembered scroll position, see [`use_scroll_restoration`](crate::use_scroll_restoration).
+    #[props(into)]
+    pub restoration_key: Option<String>,
 }
 
 impl<
@@ -100,10 +113,13 @@ impl<
             && self.builder_args == other.builder_args
             && self.scroll_controller == other.scroll_controller
             && self.invert_scroll_wheel == other.invert_scroll_wheel
+            && self.measure_items == other.measure_items
+            && self.restore_scroll == other.restore_scroll
+            && self.restoration_key == other.restoration_key
     }
 }
 
-fn get_render_range(
+pub(crate) fn get_render_range(
     viewport_size: f32,
     scroll_position: f32,
     item_size: f32,
@@ -122,11 +138,91 @@ fn get_render_range(
     render_index_start as usize..(render_index_end as usize)
 }
 
+/// Start of every item plus the end of the last one, using the measured sizes or the estimated one.
+fn get_item_offsets(sizes: &[Option<f32>], length: usize, estimated_size: f32) -> Vec<f32> {
+    let mut offsets = Vec::with_capacity(length + 1);
+    let mut offset = 0.;
+    offsets.push(offset);
+    for i in 0..length {
+        offset += sizes.get(i).copied().flatten().unwrap_or(estimated_size);
+        offsets.push(offset);
+    }
+    offsets
+}
+
+/// Items that are visible given their offsets, see [`get_item_offsets`].
+fn get_measured_render_range(
+    offsets: &[f32],
+    viewport_size: f32,
+    scroll_position: f32,
+) -> Range<usize> {
+    let length = offsets.len().saturating_sub(1);
+    let start = offsets
+        .partition_point(|offset| *offset <= -scroll_position)
+        .saturating_sub(1)
+        .min(length);
+    let end = offsets
+        .partition_point(|offset| *offset < -scroll_position + viewport_size)
+        .min(length);
+    start..end.max(start)
+}
+
+#[derive(Props, Clone, PartialEq)]
+struct MeasuredItemProps {
+    index: usize,
+    vertical: bool,
+    onmeasure: EventHandler<(usize, f32)>,
+    children: Element,
+}
+
+/// Report the size of an item of a [`VirtualScrollView`] every time it changes.
+#[allow(non_snake_case)]
+fn MeasuredItem(
+    MeasuredItemProps {
+        index,
+        vertical,
+        onmeasure,
+        children,
+    }: MeasuredItemProps,
+) -> Element {
+    let (reference, layout) = use_node_signal();
+
+    use_effect(move || {
+        let area = layout.read().area;
+        let size = if vertical {
+            area.height()
+        } else {
+            area.width()
+        };
+        if size > 0. {
+            onmeasure.call((index, size));
+        }
+    });
+
+    let (width, height) = if vertical {
+        ("fill", "auto")
+    } else {
+        ("auto", "fill")
+    };
+
+    rsx!(
+        rect {
+            reference,
+            width,
+            height,
+            {children}
+        }
+    )
+}
+
 /// One-direction scrollable area that dynamically builds and renders items based in their size and current available size,
 /// this is intended for apps using large sets of data that need good performance.
 ///
 /// Use cases: text editors, chats, etc.
 ///
+/// Items can have different sizes with `measure_items`, they are measured once rendered
+/// and the scroll position is kept when the items before the visible ones change their size.
+///
 /// # Example
 ///
 /// ```rust
@@ -210,6 +306,9 @@ pub fn VirtualScrollView<
         cache_elements,
         scroll_controller,
         invert_scroll_wheel,
+        measure_items,
+        restore_scroll,
+        restoration_key,
     }: VirtualScrollViewProps<Builder, BuilderArgs>,
 ) -> Element {
     let mut clicking_scrollbar = use_signal::<Option<(Axis, f64)>>(|| None);
@@ -221,13 +320,22 @@ pub fn VirtualScrollView<
     let (node_ref, size) = use_node();
     let mut focus = use_focus();
     let applied_scrollbar_theme = use_applied_theme!(&scrollbar_theme, scroll_bar);
+    let mut item_sizes = use_signal::<Vec<Option<f32>>>(Vec::new);
+
+    let offsets = if measure_items {
+        get_item_offsets(&item_sizes.read(), length, item_size)
+    } else {
+        Vec::new()
+    };
+    let content_size = offsets.last().copied().unwrap_or(item_size * length as f32);
 
     let (inner_width, inner_height) = match direction.as_str() {
-        "vertical" => (size.inner.width, item_size * length as f32),
-        _ => (item_size * length as f32, size.inner.height),
+        "vertical" => (size.inner.width, content_size),
+        _ => (content_size, size.inner.height),
     };
 
     scroll_controller.use_apply(inner_width, inner_height);
+    use_restored_scroll(scroll_controller, restore_scroll, restoration_key);
 
     let vertical_scrollbar_is_visible =
         is_scrollbar_visible(show_scrollbar, inner_height, size.area.height());
@@ -249,6 +357,11 @@ pub fn VirtualScrollView<
 
     // Moves the Y axis when the user scrolls in the container
     let onwheel = move |e: WheelEvent| {
+        // A nested element took care of this wheel event
+        if !e.default_action_enabled() {
+            return;
+        }
+
         let speed_multiplier = if *clicking_alt.peek() {
             SCROLL_SPEED_MULTIPLIER
         } else {
@@ -333,7 +446,7 @@ pub fn VirtualScrollView<
                 clicking_alt.set(true);
             }
             k => {
-                if !focus.is_focused() {
+                if !focus.is_focused() || !e.default_action_enabled() {
                     return;
                 }
 
@@ -400,11 +513,42 @@ pub fn VirtualScrollView<
     };
 
     // Calculate from what to what items must be rendered
-    let render_range = get_render_range(viewport_size, scroll_position, item_size, length as f32);
+    let render_range = if measure_items {
+        get_measured_render_range(&offsets, viewport_size, scroll_position)
+    } else {
+        get_render_range(viewport_size, scroll_position, item_size, length as f32)
+    };
+
+    // Cache the new size of an item
+    let vertical = direction == "vertical";
+    let onmeasure = move |(index, size): (usize, f32)| {
+        let mut sizes = item_sizes.write();
+        if sizes.len() <= index {
+            sizes.resize(index + 1, None);
+        }
+        let previous = sizes[index].unwrap_or(item_size);
+        if sizes[index] == Some(size) {
+            return;
+        }
+        sizes[index] = Some(size);
+
+        // Keep the visible items still when the ones before them change
+        let end = sizes[..=index]
+            .iter()
+            .map(|size| size.unwrap_or(item_size))
+            .sum::<f32>()
+            - size
+            + previous;
+        let mut scrolled = if vertical { scrolled_y } else { scrolled_x };
+        let scroll_position = *scrolled.peek();
+        if end <= -scroll_position as f32 {
+            scrolled.set(scroll_position - (size - previous).round() as i32);
+        }
+    };
 
     let children = if cache_elements {
         let children = use_memo(use_reactive(
-            &(render_range, builder_args),
+            &(render_range.clone(), builder_args),
             move |(render_range, builder_args)| {
                 render_range
                     .clone()
@@ -412,10 +556,28 @@ pub fn VirtualScrollView<
                     .collect::<Vec<Element>>()
             },
         ));
-        rsx!({ children.read().iter() })
+        let children = children.read().clone();
+        children
+    } else {
+        render_range
+            .clone()
+            .map(|i| (builder)(i, &builder_args))
+            .collect::<Vec<Element>>()
+    };
+    let children = if measure_items {
+        rsx!(
+            for (index, child) in render_range.clone().zip(children) {
+                MeasuredItem {
+                    key: "{index}",
+                    index,
+                    vertical,
+                    onmeasure,
+                    {child}
+                }
+            }
+        )
     } else {
-        let children = render_range.map(|i| (builder)(i, &builder_args));
-        rsx!({ children })
+        rsx!({ children.into_iter() })
     };
 
     let is_scrolling_x = clicking_scrollbar
@@ -430,6 +592,15 @@ pub fn VirtualScrollView<
         .unwrap_or_default();
 
     let (offset_x, offset_y) = match direction.as_str() {
+        // Only the part of the first rendered item that is scrolled out is offset
+        _ if measure_items => {
+            let first_offset = offsets[render_range.start];
+            if vertical {
+                (corrected_scrolled_x, corrected_scrolled_y + first_offset)
+            } else {
+                (corrected_scrolled_x + first_offset, corrected_scrolled_y)
+            }
+        }
         "vertical" => {
             let offset_y_min = (-corrected_scrolled_y / item_size).floor() * item_size;
             let offset_y = -(-corrected_scrolled_y - offset_y_min);
@@ -699,4 +870,82 @@ mod test {
             );
         }
     }
+
+    #[tokio::test]
+    pub async fn virtual_scroll_view_measured_items() {
+        fn virtual_scroll_view_measured_items_app() -> Element {
+            rsx!(VirtualScrollView {
+                length: 30,
+                item_size: 50.0,
+                measure_items: true,
+                builder: move |index, _: &Option<()>| {
+                    let height = if index % 2 == 0 { 100 } else { 50 };
+                    rsx! {
+                        label {
+                            key: "{index}",
+                            height: "{height}",
+                            "{index}"
+                        }
+                    }
+                }
+            })
+        }
+
+        let mut utils = launch_test(virtual_scroll_view_measured_items_app);
+        let root = utils.root();
+
+        utils.wait_for_update().await;
+        utils.wait_for_update().await;
+        utils.wait_for_update().await;
+
+        // 100 + 50 + 100 + 50 + 100 + 50 + 100 > 500
+        let content = root.get(0).get(0).get(0);
+        assert_eq!(content.children_ids().len(), 7);
+        assert_eq!(content.get(6).get(0).get(0).text(), Some("6"));
+
+        utils.push_event(TestEvent::Wheel {
+            name: EventName::Wheel,
+            scroll: (0., -300.).into(),
+            cursor: (5., 5.).into(),
+        });
+        utils.wait_for_update().await;
+        utils.wait_for_update().await;
+
+        // The fifth item starts at 300
+        let content = root.get(0).get(0).get(0);
+        assert_eq!(content.get(0).get(0).get(0).text(), Some("4"));
+        assert_eq!(content.get(0).layout().unwrap().area.min_y(), 0.);
+
+        // Scroll to the end, measuring the rest of items on the way
+        for _ in 0..10 {
+            utils.push_event(TestEvent::Wheel {
+                name: EventName::Wheel,
+                scroll: (0., -500.).into(),
+                cursor: (5., 5.).into(),
+            });
+            utils.wait_for_update().await;
+        }
+        utils.wait_for_update().await;
+
+        let content = root.get(0).get(0).get(0);
+        let last = content.get(content.children_ids().len() - 1);
+        assert_eq!(last.get(0).get(0).text(), Some("29"));
+        assert_eq!(last.layout().unwrap().area.max_y(), 500.);
+    }
+
+    #[test]
+    pub fn measured_render_range() {
+        use super::{
+            get_item_offsets,
+            get_measured_render_range,
+        };
+
+        let offsets = get_item_offsets(&[Some(100.), None, Some(20.)], 5, 50.);
+        assert_eq!(offsets, vec![0., 100., 150., 170., 220., 270.]);
+
+        assert_eq!(get_measured_render_range(&offsets, 120., 0.), 0..2);
+        assert_eq!(get_measured_render_range(&offsets, 50., -100.), 1..2);
+        assert_eq!(get_measured_render_range(&offsets, 100., -160.), 2..5);
+        assert_eq!(get_measured_render_range(&offsets, 100., -300.), 5..5);
+    }
 }
@@ -0,0 +1,117 @@
+use dioxus::prelude::*;
+use freya_elements as dioxus_elements;
+use freya_hooks::{
+    use_animation,
+    use_applied_theme,
+    AnimNum,
+    OnFinish,
+    SkeletonTheme,
+    SkeletonThemeWith,
+};
+
+/// Width, in percentage, of the highlight that sweeps across a [`Skeleton`].
+const HIGHLIGHT_WIDTH: f32 = 30.;
+
+/// Placeholder with a shimmer for content that is still loading, shaped like the content to come.
+///
+/// # Styling
+/// Inherits the [`SkeletonTheme`](freya_hooks::SkeletonTheme) theme.
+///
+/// # Example
+///
+/// ```no_run
+/// # use freya::prelude::*;
+/// fn app() -> Element {
+///     rsx!(
+///         rect {
+///             direction: "horizontal",
+///             spacing: "8",
+///             Skeleton {
+///                 width: "48",
+///                 height: "48",
+///                 corner_radius: "24",
+///             }
+///             rect {
+///                 width: "flex(1)",
+///                 spacing: "6",
+///                 Skeleton {
+///                     width: "40%",
+///                 }
+///                 Skeleton { }
+///             }
+///         }
+///     )
+/// }
+/// ```
+#[allow(non_snake_case)]
+#[component]
+pub fn Skeleton(
+    /// Width of the Skeleton. Default `fill`.
+    #[props(default = "fill".to_string(), into)]
+    width: String,
+    /// Height of the Skeleton. Default `16`, about a line of text.
+    #[props(default = "16".to_string(), into)]
+    height: String,
+    /// Corner radius of the Skeleton. Default `6`.
+    #[props(default = "6".to_string(), into)]
+    corner_radius: String,
+    /// Theme override.
+    theme: Option<SkeletonThemeWith>,
+) -> Element {
+    let SkeletonTheme {
+        background,
+        highlight_background,
+    } = use_applied_theme!(&theme, skeleton);
+    let animation = use_animation(|conf| {
+        conf.auto_start(true);
+        conf.on_finish(OnFinish::Restart);
+        AnimNum::new(-HIGHLIGHT_WIDTH, 100. + HIGHLIGHT_WIDTH).time(1400)
+    });
+
+    // Stops of the gradient can't go past its edges
+    let center = animation.get().read().read();
+    let start = (center - HIGHLIGHT_WIDTH).clamp(0., 100.);
+    let end = (center + HIGHLIGHT_WIDTH).clamp(0., 100.);
+    let center = center.clamp(0., 100.);
+    let gradient = format!(
+        "linear-gradient(90deg, {background} 0%, {background} {start}%, \
+        {highlight_background} {center}%, {background} {end}%, {background} 100%)"
+    );
+
+    rsx!(rect {
+        width: "{width}",
+        height: "{height}",
+        corner_radius: "{corner_radius}",
+        background: gradient,
+    })
+}
+
+#[cfg(test)]
+mod test {
+    use freya::prelude::*;
+    use freya_testing::prelude::*;
+
+    #[tokio::test]
+    pub async fn skeleton() {
+        fn skeleton_app() -> Element {
+            rsx!(
+                Skeleton {
+                    width: "200",
+                }
+                Skeleton {
+                    width: "50%",
+                    height: "40",
+                }
+            )
+        }
+
+        let mut utils = launch_test(skeleton_app);
+        utils.wait_for_update().await;
+        let root = utils.root();
+
+        let line = root.get(0).layout().unwrap().area;
+        assert_eq!((line.width(), line.height()), (200., 16.));
+        let block = root.get(1).layout().unwrap().area;
+        assert_eq!((block.width(), block.height()), (250., 40.));
+    }
+}
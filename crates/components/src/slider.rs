@@ -7,11 +7,13 @@ use freya_elements::{
         KeyboardEvent,
         MouseEvent,
         WheelEvent,
+        WheelPhase,
     },
 };
 use freya_hooks::{
     use_applied_theme,
     use_focus,
+    use_layout_direction,
     use_node,
     use_platform,
     SliderThemeWith,
@@ -120,8 +122,11 @@ pub fn Slider(
     let mut clicking = use_signal(|| false);
     let platform = use_platform();
     let (node_reference, node_size) = use_node();
+    let layout_direction = use_layout_direction();
 
     let direction_is_vertical = direction == "vertical";
+    // The horizontal slider is mirrored in right-to-left layouts
+    let is_rtl = layout_direction().is_rtl();
     let value = ensure_correct_slider_range(value);
     let a11y_id = focus.attribute();
 
@@ -134,12 +139,14 @@ pub fn Slider(
     let onkeydown = move |e: KeyboardEvent| match e.key {
         Key::ArrowLeft if !direction_is_vertical => {
             e.stop_propagation();
-            let percentage = (value - 4.).clamp(0.0, 100.0);
+            let delta = if is_rtl { 4. } else { -4. };
+            let percentage = (value + delta).clamp(0.0, 100.0);
             onmoved.call(percentage);
         }
         Key::ArrowRight if !direction_is_vertical => {
             e.stop_propagation();
-            let percentage = (value + 4.).clamp(0.0, 100.0);
+            let delta = if is_rtl { -4. } else { 4. };
+            let percentage = (value + delta).clamp(0.0, 100.0);
             onmoved.call(percentage);
         }
         Key::ArrowUp if direction_is_vertical => {
@@ -177,7 +184,11 @@ pub fn Slider(
                     let y = coordinates.y - node_size.area.min_y() as f64 - 6.0;
                     100. - (y / (node_size.area.height() as f64 - 15.0) * 100.0)
                 } else {
-                    let x = coordinates.x - node_size.area.min_x() as f64 - 6.0;
+                    let x = if is_rtl {
+                        node_size.area.max_x() as f64 - coordinates.x - 6.0
+                    } else {
+                        coordinates.x - node_size.area.min_x() as f64 - 6.0
+                    };
                     x / (node_size.area.width() as f64 - 15.0) * 100.0
                 };
                 let percentage = percentage.clamp(0.0, 100.0);
@@ -198,7 +209,11 @@ pub fn Slider(
                 let y = coordinates.y - 6.0;
                 100. - (y / (node_size.area.height() as f64 - 15.0) * 100.0)
             } else {
-                let x = coordinates.x - 6.0;
+                let x = if is_rtl {
+                    node_size.area.width() as f64 - coordinates.x - 6.0
+                } else {
+                    coordinates.x - 6.0
+                };
                 x / (node_size.area.width() as f64 - 15.0) * 100.0
             };
             let percentage = percentage.clamp(0.0, 100.0);
@@ -213,6 +228,10 @@ pub fn Slider(
 
     let onwheel = move |e: WheelEvent| {
         e.stop_propagation();
+        // The start and end of trackpad gestures don't move it
+        if e.get_phase() != WheelPhase::Moved {
+            return;
+        }
         let wheel_y = e.get_delta_y().clamp(-1.0, 1.0);
         let percentage = value + (wheel_y * 2.0);
         let percentage = percentage.clamp(0.0, 100.0);
@@ -1,16 +1,29 @@
+use std::cmp::Ordering;
+
 use dioxus::prelude::*;
 use freya_elements::{
     self as dioxus_elements,
-    events::MouseEvent,
+    events::{
+        KeyboardEvent,
+        MouseEvent,
+    },
 };
 use freya_hooks::{
     use_applied_theme,
+    use_focus,
+    use_selection_with_onchange,
     FontTheme,
+    SelectionMode,
     TableTheme,
     TableThemeWith,
 };
 
-use crate::icons::ArrowIcon;
+use crate::{
+    icons::ArrowIcon,
+    use_scroll_controller,
+    ScrollConfig,
+    VirtualScrollView,
+};
 
 #[allow(non_snake_case)]
 #[component]
@@ -35,8 +48,12 @@ pub struct TableHeadProps {
 }
 
 /// The head of a [`Table`]. Use [`TableRow`] inside.
+///
+/// The cells inside are exposed as column headers to assistive technologies.
 #[allow(non_snake_case)]
 pub fn TableHead(TableHeadProps { children }: TableHeadProps) -> Element {
+    provide_context(TableHeadConfig);
+
     rsx!(
         rect { width: "100%", {children} }
     )
@@ -70,6 +87,13 @@ pub struct TableRowProps {
     pub theme: Option<TableThemeWith>,
     /// The content of this row.
     children: Element,
+    /// Index of this row in the whole table, reported to assistive technologies.
+    pub index: Option<usize>,
+    /// Whether this row is selected, see [`use_selection`](freya_hooks::use_selection).
+    #[props(default = false)]
+    pub selected: bool,
+    /// Handler for the `onpress` event, e.g. to select the row.
+    pub onpress: Option<EventHandler<MouseEvent>>,
 }
 
 /// Table row for [`Table`]. Use [`TableCell`] inside.
@@ -77,16 +101,27 @@ pub struct TableRowProps {
 /// # Styling
 /// Inherits the [`TableTheme`](freya_hooks::TableTheme) theme.
 #[allow(non_snake_case)]
-pub fn TableRow(TableRowProps { theme, children }: TableRowProps) -> Element {
+pub fn TableRow(
+    TableRowProps {
+        theme,
+        children,
+        index,
+        selected,
+        onpress,
+    }: TableRowProps,
+) -> Element {
     let theme = use_applied_theme!(&theme, table);
     let mut state = use_signal(|| TableRowState::Idle);
     let TableTheme {
         divider_fill,
         hover_row_background,
+        selected_row_background,
         row_background,
         ..
     } = theme;
-    let background = if state() == TableRowState::Hovering {
+    let background = if selected {
+        selected_row_background
+    } else if state() == TableRowState::Hovering {
         hover_row_background
     } else {
         row_background
@@ -94,8 +129,16 @@ pub fn TableRow(TableRowProps { theme, children }: TableRowProps) -> Element {
 
     rsx!(
         rect {
+            a11y_role: "row",
+            a11y_row_index: index.map(|index| index.to_string()),
+            a11y_selected: "{selected}",
             onmouseenter: move |_| state.set(TableRowState::Hovering),
             onmouseleave: move |_| state.set(TableRowState::Idle),
+            onclick: move |e| {
+                if let Some(onpress) = &onpress {
+                    onpress.call(e);
+                }
+            },
             direction: "horizontal",
             width: "fill",
             background: "{background}",
@@ -124,6 +167,8 @@ pub enum OrderDirection {
 pub struct TableCellProps {
     /// The content of this cell.
     pub children: Element,
+    /// Index of the column of this cell, reported to assistive technologies.
+    pub column_index: Option<usize>,
     /// Handler for the `onpress` event.
     pub onpress: Option<EventHandler<MouseEvent>>,
     /// The direction in which this TableCell's column will be ordered.
@@ -143,17 +188,29 @@ pub struct TableCellProps {
 #[allow(non_snake_case)]
 pub fn TableCell(props: TableCellProps) -> Element {
     let config = consume_context::<TableConfig>();
+    let is_header = try_consume_context::<TableHeadConfig>().is_some();
     let width = 100.0 / config.columns as f32;
     let TableCellProps {
         children,
+        column_index,
         order_direction,
         padding,
         height,
         ..
     } = &props;
+    let role = if is_header { "column-header" } else { "cell" };
+    let sort_direction = order_direction
+        .flatten()
+        .map(|order_direction| match order_direction {
+            OrderDirection::Down => "ascending",
+            OrderDirection::Up => "descending",
+        });
 
     rsx!(
         rect {
+            a11y_role: "{role}",
+            a11y_column_index: column_index.map(|index| index.to_string()),
+            a11y_sort_direction: sort_direction,
             overflow: "clip",
             padding: "{padding}",
             width: "{width}%",
@@ -193,6 +250,9 @@ pub struct TableProps {
     pub theme: Option<TableThemeWith>,
     /// Number of columns used in the table.
     pub columns: usize,
+    /// Total number of rows of the table, including the ones not rendered.
+    /// Reported to assistive technologies.
+    pub rows: Option<usize>,
     /// The content of the table.
     pub children: Element,
 }
@@ -207,6 +267,7 @@ pub fn Table(
         height,
         theme,
         columns,
+        rows,
         children,
     }: TableProps,
 ) -> Element {
@@ -220,6 +281,9 @@ pub fn Table(
     provide_context(TableConfig { columns });
 
     rsx!(rect {
+        a11y_role: "table",
+        a11y_column_count: "{columns}",
+        a11y_row_count: rows.map(|rows| rows.to_string()),
         overflow: "clip",
         color: "{color}",
         background: "{background}",
@@ -230,8 +294,583 @@ pub fn Table(
     })
 }
 
+/// Column of a [`DataTable`].
+#[derive(Debug, Clone, PartialEq)]
+pub struct TableColumn {
+    pub title: String,
+    /// Initial width, it can then be resized by the user.
+    pub width: f32,
+    /// Least width it can be resized to.
+    pub min_width: f32,
+    /// Whether the rows can be sorted by this column by clicking its header.
+    pub sortable: bool,
+}
+
+impl TableColumn {
+    pub fn new(title: impl Into<String>, width: f32) -> Self {
+        Self {
+            title: title.into(),
+            width,
+            min_width: 30.,
+            sortable: true,
+        }
+    }
+
+    pub fn with_min_width(mut self, min_width: f32) -> Self {
+        self.min_width = min_width;
+        self
+    }
+
+    pub fn with_sortable(mut self, sortable: bool) -> Self {
+        self.sortable = sortable;
+        self
+    }
+}
+
+/// Compare two cells, as numbers if both are.
+fn compare_cells(a: &str, b: &str) -> Ordering {
+    match (a.trim().parse::<f64>(), b.trim().parse::<f64>()) {
+        (Ok(a), Ok(b)) => a.total_cmp(&b),
+        _ => a.to_lowercase().cmp(&b.to_lowercase()),
+    }
+}
+
+/// Indexes of the rows in the order they are displayed.
+fn sort_rows(rows: &[Vec<String>], sort: Option<(usize, OrderDirection)>) -> Vec<usize> {
+    let mut order = (0..rows.len()).collect::<Vec<_>>();
+    if let Some((column, direction)) = sort {
+        let cell = |row: usize| {
+            rows[row]
+                .get(column)
+                .map(String::as_str)
+                .unwrap_or_default()
+        };
+        order.sort_by(|a, b| compare_cells(cell(*a), cell(*b)));
+        if direction == OrderDirection::Up {
+            order.reverse();
+        }
+    }
+    order
+}
+
+/// Scroll offset that makes the row at `position` fully visible, if it isn't already.
+pub(crate) fn scroll_to_row(
+    position: usize,
+    row_height: f32,
+    scroll: f32,
+    viewport: f32,
+) -> Option<f32> {
+    let top = position as f32 * row_height;
+    let bottom = top + row_height;
+    if top < scroll {
+        Some(top)
+    } else if bottom > scroll + viewport {
+        Some(bottom - viewport)
+    } else {
+        None
+    }
+}
+
+/// Column being resized.
+#[derive(Clone, Copy, PartialEq)]
+struct ColumnResize {
+    column: usize,
+    /// Cursor position where the resize started.
+    origin: f64,
+    width: f32,
+}
+
+/// Properties for the [`DataTable`] component.
+#[derive(Props, Clone, PartialEq)]
+pub struct DataTableProps {
+    /// Columns of the table.
+    pub columns: Vec<TableColumn>,
+    /// Cells of every row, in the order of the columns.
+    pub rows: ReadOnlySignal<Vec<Vec<String>>>,
+    /// How many rows can be selected. Default [`SelectionMode::Multiple`].
+    #[props(default)]
+    pub selection_mode: SelectionMode,
+    /// Handler called with the indexes of the selected rows when the selection changes.
+    pub onselect: Option<EventHandler<Vec<usize>>>,
+    /// Height of the rows. Default `35`.
+    #[props(default = 35.)]
+    pub row_height: f32,
+    /// Width of the table. Default `fill`.
+    #[props(default = "fill".into())]
+    pub width: String,
+    /// Height of the table. Default `fill`.
+    #[props(default = "fill".into())]
+    pub height: String,
+    /// Theme override.
+    pub theme: Option<TableThemeWith>,
+}
+
+/// Table built from column definitions and rows of text, for when [`Table`] would need too much boilerplate.
+///
+/// - Click a header to sort the rows by that column, again to reverse it.
+/// - Drag the edge of a header to resize the column.
+/// - Select rows by clicking them or with the keyboard, see [`use_selection`](freya_hooks::use_selection).
+///
+/// Only the visible rows are rendered, so it can have thousands of them.
+///
+/// # Styling
+/// Inherits the [`TableTheme`](freya_hooks::TableTheme) theme.
+///
+/// # Example
+///
+/// ```no_run
+/// # use freya::prelude::*;
+/// fn app() -> Element {
+///     let rows = use_signal(|| {
+///         vec![
+///             vec!["Zeus".to_string(), "Sky".to_string(), "1".to_string()],
+///             vec!["Poseidon".to_string(), "Sea".to_string(), "3".to_string()],
+///             vec!["Ares".to_string(), "War".to_string(), "8".to_string()],
+///         ]
+///     });
+///
+///     rsx!(DataTable {
+///         columns: vec![
+///             TableColumn::new("Name", 200.),
+///             TableColumn::new("Type", 150.),
+///             TableColumn::new("Rank", 80.),
+///         ],
+///         rows,
+///         onselect: |selected: Vec<usize>| println!("Selected {selected:?}")
+///     })
+/// }
+/// ```
+#[allow(non_snake_case)]
+pub fn DataTable(
+    DataTableProps {
+        columns,
+        rows,
+        selection_mode,
+        onselect,
+        row_height,
+        width,
+        height,
+        theme,
+    }: DataTableProps,
+) -> Element {
+    let TableTheme {
+        background,
+        arrow_fill,
+        corner_radius,
+        divider_fill,
+        font_theme: FontTheme { color },
+        ..
+    } = use_applied_theme!(&theme, table);
+    let mut focus = use_focus();
+    let mut scroll_controller = use_scroll_controller(ScrollConfig::default);
+    let mut selection = use_selection_with_onchange(selection_mode, move |selected: &[usize]| {
+        if let Some(onselect) = onselect {
+            onselect.call(selected.to_vec());
+        }
+    });
+    let mut sort = use_signal::<Option<(usize, OrderDirection)>>(|| None);
+    let mut widths = use_signal(|| {
+        columns
+            .iter()
+            .map(|column| column.width)
+            .collect::<Vec<_>>()
+    });
+    let mut resizing = use_signal::<Option<ColumnResize>>(|| None);
+    let order = use_memo(move || sort_rows(&rows.read(), sort()));
+
+    let column_widths = columns
+        .iter()
+        .enumerate()
+        .map(|(i, column)| widths.read().get(i).copied().unwrap_or(column.width))
+        .collect::<Vec<_>>();
+
+    let onkeydown = move |e: KeyboardEvent| {
+        let order = order.read();
+        if !selection.keydown(&e.data, &order) {
+            return;
+        }
+        let Some(active) = selection.active() else {
+            return;
+        };
+        let Some(position) = order.iter().position(|row| *row == active) else {
+            return;
+        };
+        let scroll = -*scroll_controller.y().peek() as f32;
+        let viewport = scroll_controller.layout().peek().area.height();
+        if let Some(to) = scroll_to_row(position, row_height + 1., scroll, viewport) {
+            scroll_controller.scroll_to_y(-to as i32);
+        }
+    };
+
+    let min_widths = columns
+        .iter()
+        .map(|column| column.min_width)
+        .collect::<Vec<_>>();
+    let onglobalmousemove = move |e: MouseEvent| {
+        let Some(resize) = *resizing.peek() else {
+            return;
+        };
+        let min_width = min_widths[resize.column];
+        let width = resize.width + (e.get_screen_coordinates().x - resize.origin) as f32;
+        widths.write()[resize.column] = width.max(min_width);
+    };
+
+    let onglobalclick = move |_: MouseEvent| {
+        if resizing.peek().is_some() {
+            resizing.set(None);
+        }
+    };
+
+    let mut on_header_press = move |column: usize| {
+        let direction = match *sort.peek() {
+            Some((sorted, OrderDirection::Down)) if sorted == column => OrderDirection::Up,
+            _ => OrderDirection::Down,
+        };
+        sort.set(Some((column, direction)));
+    };
+
+    let border = if focus.is_focused_with_keyboard() {
+        format!("2 inner {arrow_fill}")
+    } else {
+        format!("1 outer {divider_fill}")
+    };
+    // Sorting direction of every column
+    let directions = (0..columns.len()).map(|i| match sort() {
+        Some((sorted, order_direction)) if sorted == i => Some(order_direction),
+        _ => None,
+    });
+    let columns_count = columns.len();
+    let rows_count = rows.read().len();
+
+    rsx!(
+        rect {
+            a11y_id: focus.attribute(),
+            a11y_role: "table",
+            a11y_column_count: "{columns_count}",
+            a11y_row_count: "{rows_count + 1}",
+            onkeydown,
+            onglobalmousemove,
+            onglobalclick,
+            overflow: "clip",
+            color: "{color}",
+            background: "{background}",
+            corner_radius: "{corner_radius}",
+            width: "{width}",
+            height: "{height}",
+            border,
+            rect {
+                a11y_role: "row",
+                a11y_row_index: "0",
+                direction: "horizontal",
+                for (i, (column, order_direction)) in columns.iter().zip(directions).enumerate() {
+                    rect {
+                        key: "{i}",
+                        a11y_role: "column-header",
+                        a11y_column_index: "{i}",
+                        a11y_sort_direction: order_direction.map(|direction| match direction {
+                            OrderDirection::Down => "ascending",
+                            OrderDirection::Up => "descending",
+                        }),
+                        width: "{column_widths[i]}",
+                        height: "{row_height}",
+                        direction: "horizontal",
+                        cross_align: "center",
+                        onclick: {
+                            let sortable = column.sortable;
+                            move |_| {
+                                if sortable {
+                                    on_header_press(i)
+                                }
+                            }
+                        },
+                        rect {
+                            width: "calc(100% - 5)",
+                            padding: "0 10",
+                            direction: "horizontal",
+                            cross_align: "center",
+                            label {
+                                max_lines: "1",
+                                text_overflow: "ellipsis",
+                                font_weight: "bold",
+                                "{column.title}"
+                            }
+                            if let Some(order_direction) = order_direction {
+                                rect {
+                                    margin: "0 0 0 8",
+                                    width: "10",
+                                    height: "10",
+                                    TableArrow {
+                                        order_direction
+                                    }
+                                }
+                            }
+                        }
+                        rect {
+                            width: "5",
+                            height: "fill",
+                            cursor: "col-resize",
+                            onmousedown: {
+                                let column_widths = column_widths.clone();
+                                move |e: MouseEvent| {
+                                    e.stop_propagation();
+                                    widths.set(column_widths.clone());
+                                    resizing.set(Some(ColumnResize {
+                                        column: i,
+                                        origin: e.get_screen_coordinates().x,
+                                        width: column_widths[i],
+                                    }));
+                                }
+                            },
+                            onclick: move |e: MouseEvent| e.stop_propagation(),
+                            rect {
+                                width: "1",
+                                height: "fill",
+                                margin: "0 0 0 4",
+                                background: "{divider_fill}",
+                            }
+                        }
+                    }
+                }
+            }
+            rect {
+                height: "1",
+                width: "fill",
+                background: "{divider_fill}"
+            }
+            VirtualScrollView {
+                length: rows_count,
+                item_size: row_height + 1.,
+                scroll_controller,
+                scroll_with_arrows: false,
+                cache_elements: false,
+                builder_args: (theme.clone(), column_widths.clone()),
+                builder: move |i, args: &Option<(Option<TableThemeWith>, Vec<f32>)>| {
+                    let (theme, widths) = args.clone().unwrap();
+                    let row = order.read()[i];
+                    let rows = rows.read();
+                    let cells = &rows[row];
+                    rsx!(
+                        TableRow {
+                            key: "{row}",
+                            theme,
+                            index: i + 1,
+                            selected: selection.is_selected(&row),
+                            onpress: move |_| {
+                                focus.request_focus();
+                                selection.click(row, &order.read());
+                            },
+                            for (n, cell) in cells.iter().enumerate().take(columns_count) {
+                                rect {
+                                    key: "{n}",
+                                    a11y_role: "cell",
+                                    a11y_column_index: "{n}",
+                                    width: "{widths[n]}",
+                                    height: "{row_height}",
+                                    padding: "0 10",
+                                    main_align: "center",
+                                    label {
+                                        max_lines: "1",
+                                        text_overflow: "ellipsis",
+                                        "{cell}"
+                                    }
+                                }
+                            }
+                        }
+                    )
+                }
+            }
+        }
+    )
+}
+
 #[doc(hidden)]
 #[derive(Clone)]
 pub struct TableConfig {
     columns: usize,
 }
+
+#[derive(Clone)]
+struct TableHeadConfig;
+
+#[cfg(test)]
+mod test {
+    use freya::prelude::*;
+    use freya_testing::prelude::*;
+
+    use super::{
+        scroll_to_row,
+        sort_rows,
+    };
+
+    #[tokio::test]
+    pub async fn table_accessibility() {
+        fn table_app() -> Element {
+            rsx!(
+                Table {
+                    columns: 2,
+                    rows: 2,
+                    TableHead {
+                        TableRow {
+                            index: 0,
+                            TableCell {
+                                column_index: 0,
+                                order_direction: Some(OrderDirection::Up),
+                                label { "Name" }
+                            }
+                            TableCell {
+                                column_index: 1,
+                                order_direction: None::<OrderDirection>,
+                                label { "Age" }
+                            }
+                        }
+                    }
+                    TableBody {
+                        TableRow {
+                            index: 1,
+                            selected: true,
+                            TableCell {
+                                column_index: 0,
+                                label { "Marc" }
+                            }
+                            TableCell {
+                                column_index: 1,
+                                label { "26" }
+                            }
+                        }
+                    }
+                }
+            )
+        }
+
+        let mut utils = launch_test(table_app);
+        utils.wait_for_update().await;
+
+        let table = utils.root().get(0);
+        let head_row = table.get(0).get(0);
+        let body_row = table.get(1).get(0);
+
+        let accessibility = |node: &TestNode| node.state().accessibility.builder.clone().unwrap();
+
+        let table_node = accessibility(&table);
+        assert_eq!(format!("{:?}", table_node.role()), "Table");
+        assert_eq!(table_node.column_count(), Some(2));
+        assert_eq!(table_node.row_count(), Some(2));
+
+        let head_row_node = accessibility(&head_row);
+        assert_eq!(format!("{:?}", head_row_node.role()), "Row");
+        assert_eq!(head_row_node.row_index(), Some(0));
+
+        let header = accessibility(&head_row.get(0));
+        assert_eq!(format!("{:?}", header.role()), "ColumnHeader");
+        assert_eq!(header.column_index(), Some(0));
+        assert_eq!(format!("{:?}", header.sort_direction()), "Some(Descending)");
+
+        // Unsorted columns don't report a sorting direction
+        let unsorted_header = accessibility(&head_row.get(1));
+        assert_eq!(unsorted_header.column_index(), Some(1));
+        assert_eq!(unsorted_header.sort_direction(), None);
+
+        let body_row_node = accessibility(&body_row);
+        assert_eq!(body_row_node.row_index(), Some(1));
+        assert_eq!(body_row_node.is_selected(), Some(true));
+
+        let cell = accessibility(&body_row.get(1));
+        assert_eq!(format!("{:?}", cell.role()), "Cell");
+        assert_eq!(cell.column_index(), Some(1));
+    }
+
+    #[test]
+    fn sort_cells() {
+        let rows = ["b", "10", "A", "9"]
+            .iter()
+            .map(|cell| vec![cell.to_string()])
+            .collect::<Vec<_>>();
+        assert_eq!(sort_rows(&rows, None), vec![0, 1, 2, 3]);
+        assert_eq!(
+            sort_rows(&rows, Some((0, OrderDirection::Down))),
+            vec![3, 1, 2, 0]
+        );
+        assert_eq!(
+            sort_rows(&rows, Some((0, OrderDirection::Up))),
+            vec![0, 2, 1, 3]
+        );
+        assert_eq!(scroll_to_row(2, 10., 0., 50.), None);
+        assert_eq!(scroll_to_row(7, 10., 0., 50.), Some(30.));
+        assert_eq!(scroll_to_row(1, 10., 20., 50.), Some(10.));
+    }
+
+    #[tokio::test]
+    pub async fn data_table() {
+        fn data_table_app() -> Element {
+            let rows = use_signal(|| {
+                (0..100)
+                    .map(|i| vec![format!("Row {i}"), format!("{}", i % 7)])
+                    .collect::<Vec<_>>()
+            });
+            let mut selected = use_signal(Vec::new);
+
+            rsx!(
+                DataTable {
+                    columns: vec![TableColumn::new("Name", 200.), TableColumn::new("Group", 100.)],
+                    rows,
+                    onselect: move |rows| selected.set(rows),
+                }
+                label { "{selected:?}" }
+            )
+        }
+
+        let mut utils = launch_test(data_table_app);
+        utils.wait_for_update().await;
+        utils.wait_for_update().await;
+
+        let cell = |utils: &TestingHandler<()>, row: usize| {
+            let content = utils.root().get(0).get(2).get(0).get(0);
+            content
+                .get(row * 2)
+                .get(0)
+                .get(0)
+                .get(0)
+                .text()
+                .map(str::to_string)
+        };
+        let selected =
+            |utils: &TestingHandler<()>| utils.root().get(1).get(0).text().map(str::to_string);
+        assert_eq!(cell(&utils, 0).as_deref(), Some("Row 0"));
+
+        // Sort by group, then reverse it
+        utils.click_cursor((250., 17.)).await;
+        assert_eq!(cell(&utils, 1).as_deref(), Some("Row 7"));
+        utils.click_cursor((250., 17.)).await;
+        assert_eq!(cell(&utils, 0).as_deref(), Some("Row 97"));
+
+        // Select a row and move the selection with the keyboard
+        utils.click_cursor((100., 54.)).await;
+        assert_eq!(selected(&utils).as_deref(), Some("[97]"));
+        utils.push_event(TestEvent::Keyboard {
+            name: EventName::KeyDown,
+            key: Key::ArrowDown,
+            code: Code::ArrowDown,
+            modifiers: Modifiers::default(),
+        });
+        utils.wait_for_update().await;
+        assert_eq!(selected(&utils).as_deref(), Some("[90]"));
+
+        // Resize the first column
+        utils.push_event(TestEvent::Mouse {
+            name: EventName::MouseDown,
+            cursor: (197., 17.).into(),
+            button: Some(MouseButton::Left),
+        });
+        utils.wait_for_update().await;
+        utils.move_cursor((247., 17.)).await;
+        utils.push_event(TestEvent::Mouse {
+            name: EventName::MouseUp,
+            cursor: (247., 17.).into(),
+            button: Some(MouseButton::Left),
+        });
+        utils.wait_for_update().await;
+
+        let header = utils.root().get(0).get(0).get(0);
+        assert_eq!(header.layout().unwrap().area.width(), 250.);
+    }
+}
@@ -1,18 +1,42 @@
+use std::{
+    cell::{
+        Cell,
+        RefCell,
+    },
+    rc::Rc,
+};
+
 use dioxus::prelude::*;
-use freya_core::platform::CursorIcon;
+use freya_core::{
+    custom_attributes::NodeReferenceLayout,
+    platform::CursorIcon,
+    types::AccessibilityId,
+};
 use freya_elements::{
     self as dioxus_elements,
+    events::{
+        keyboard::Key,
+        MouseEvent,
+    },
     KeyboardEvent,
+    MouseButton,
 };
 use freya_hooks::{
     use_activable_route,
     use_applied_theme,
     use_focus,
+    use_node_signal,
     use_platform,
     BottomTabTheme,
     BottomTabThemeWith,
     TabTheme,
     TabThemeWith,
+    UseFocus,
+};
+
+use crate::{
+    CrossIcon,
+    KeepAlive,
 };
 
 /// Horizontal container for Tabs. Use in combination with [`Tab`]
@@ -22,7 +46,297 @@ pub fn Tabsbar(children: Element) -> Element {
     rsx!(
         rect {
             direction: "horizontal",
+            a11y_role: "tab-list",
+            {children}
+        }
+    )
+}
+
+/// How far the cursor needs to move before a pressed Tab starts being dragged.
+const DRAG_THRESHOLD: f64 = 5.;
+
+#[derive(Clone)]
+struct TabEntry {
+    id: AccessibilityId,
+    value: String,
+    layout: ReadOnlySignal<NodeReferenceLayout>,
+}
+
+#[derive(Clone, Copy)]
+struct TabDrag {
+    id: AccessibilityId,
+    origin: f64,
+    cursor: f64,
+    moved: bool,
+}
+
+#[derive(Clone)]
+struct TabsContext {
+    selected: ReadOnlySignal<String>,
+    onchange: EventHandler<String>,
+    onclose: Option<EventHandler<String>>,
+    onreorder: Option<EventHandler<(usize, usize)>>,
+    /// Tabs registered in this [`Tabs`], in no particular order.
+    tabs: Rc<RefCell<Vec<TabEntry>>>,
+    drag: Signal<Option<TabDrag>>,
+}
+
+impl TabsContext {
+    fn set_tab(
+        &self,
+        id: AccessibilityId,
+        value: &str,
+        layout: ReadOnlySignal<NodeReferenceLayout>,
+    ) {
+        let mut tabs = self.tabs.borrow_mut();
+        match tabs.iter_mut().find(|tab| tab.id == id) {
+            Some(tab) if tab.value != value => tab.value = value.to_string(),
+            Some(_) => {}
+            None => tabs.push(TabEntry {
+                id,
+                value: value.to_string(),
+                layout,
+            }),
+        }
+    }
+
+    fn remove_tab(&self, id: AccessibilityId) {
+        self.tabs.borrow_mut().retain(|tab| tab.id != id);
+    }
+
+    /// Registered tabs sorted by their position on screen.
+    fn ordered_tabs(&self) -> Vec<TabEntry> {
+        let mut tabs = self.tabs.borrow().clone();
+        tabs.sort_by(|a, b| {
+            let a = a.layout.peek().area.min_x();
+            let b = b.layout.peek().area.min_x();
+            a.total_cmp(&b)
+        });
+        tabs
+    }
+
+    /// Select and focus the tab `offset` positions away from the given one, wrapping around.
+    fn select_relative(&self, id: AccessibilityId, offset: isize) {
+        let tabs = self.ordered_tabs();
+        if let Some(index) = tabs.iter().position(|tab| tab.id == id) {
+            let len = tabs.len() as isize;
+            let index = (index as isize + offset).rem_euclid(len) as usize;
+            self.select(&tabs[index]);
+        }
+    }
+
+    fn select(&self, tab: &TabEntry) {
+        self.onchange.call(tab.value.clone());
+        UseFocus::focus_id(tab.id);
+    }
+
+    /// Source and target positions of the current drag, if the Tab has been moved enough.
+    fn drop_target(&self) -> Option<(usize, usize)> {
+        let drag = (*self.drag.peek())?;
+        if !drag.moved {
+            return None;
+        }
+        let tabs = self.ordered_tabs();
+        let from = tabs.iter().position(|tab| tab.id == drag.id)?;
+        let centers = tabs
+            .iter()
+            .map(|tab| tab.layout.peek().area.center().x as f64)
+            .collect::<Vec<_>>();
+        Some((from, reorder_target(&centers, from, drag.cursor)))
+    }
+}
+
+/// Position where the tab at `from` ends up when dropped at `cursor`,
+/// counted among the rest of tabs.
+fn reorder_target(centers: &[f64], from: usize, cursor: f64) -> usize {
+    centers
+        .iter()
+        .enumerate()
+        .filter(|(i, center)| *i != from && **center < cursor)
+        .count()
+}
+
+/// Container for a set of [`Tab`]s and [`TabPanel`]s that share the selected value.
+///
+/// Tabs with a `value` inside of it can be selected by clicking them or, once one is focused,
+/// with the arrow keys, `Home` and `End`. Closable tabs are closed with their close button
+/// or the `Delete` key, and tabs can be dragged to reorder them when `onreorder` is set.
+///
+/// # Styling
+/// Inherits the [`TabTheme`](freya_hooks::TabTheme) theme, used for the drop indicator.
+///
+/// # Example
+///
+/// ```no_run
+/// # use freya::prelude::*;
+/// fn app() -> Element {
+///     let mut tabs = use_signal(|| vec!["Home".to_string(), "Settings".to_string()]);
+///     let mut selected = use_signal(|| "Home".to_string());
+///
+///     rsx!(
+///         Tabs {
+///             value: selected(),
+///             onchange: move |value| selected.set(value),
+///             onclose: move |value| tabs.retain(|tab| *tab != value),
+///             onreorder: move |(from, to)| {
+///                 let tab = tabs.remove(from);
+///                 tabs.insert(to, tab);
+///             },
+///             Tabsbar {
+///                 for tab in tabs() {
+///                     Tab {
+///                         key: "{tab}",
+///                         value: tab.clone(),
+///                         closable: true,
+///                         label { "{tab}" }
+///                     }
+///                 }
+///             }
+///             for tab in tabs() {
+///                 TabPanel {
+///                     key: "{tab}",
+///                     value: tab.clone(),
+///                     label { "Content of {tab}" }
+///                 }
+///             }
+///         }
+///     )
+/// }
+/// ```
+#[allow(non_snake_case)]
+#[component]
+pub fn Tabs(
+    /// Value of the selected [`Tab`].
+    value: ReadOnlySignal<String>,
+    /// Handler for when a [`Tab`] is selected, with its value.
+    onchange: EventHandler<String>,
+    /// Handler for when a closable [`Tab`] is closed, with its value.
+    onclose: Option<EventHandler<String>>,
+    /// Handler for when a [`Tab`] is dragged to a new position, with its previous and new position.
+    /// Dragging is disabled if not set.
+    onreorder: Option<EventHandler<(usize, usize)>>,
+    /// Theme override.
+    theme: Option<TabThemeWith>,
+    /// Width of the Tabs. Default `fill`.
+    #[props(default = "fill".to_string())]
+    width: String,
+    /// Height of the Tabs. Default `auto`.
+    #[props(default = "auto".to_string())]
+    height: String,
+    /// The [`Tabsbar`] and [`TabPanel`]s.
+    children: Element,
+) -> Element {
+    let TabTheme {
+        focus_border_fill, ..
+    } = use_applied_theme!(&theme, tab);
+    let ctx = use_context_provider(|| TabsContext {
+        selected: value,
+        onchange,
+        onclose,
+        onreorder,
+        tabs: Rc::default(),
+        drag: Signal::new(None),
+    });
+    let mut drag = ctx.drag;
+
+    let onglobalmousemove = move |e: MouseEvent| {
+        if let Some(drag) = &mut *drag.write() {
+            drag.cursor = e.get_screen_coordinates().x;
+            drag.moved |= (drag.cursor - drag.origin).abs() > DRAG_THRESHOLD;
+        }
+    };
+
+    let onglobalclick = {
+        let ctx = ctx.clone();
+        move |_: MouseEvent| {
+            if drag.peek().is_none() {
+                return;
+            }
+            if let (Some((from, to)), Some(onreorder)) = (ctx.drop_target(), onreorder) {
+                if from != to {
+                    onreorder.call((from, to));
+                }
+            }
+            drag.set(None);
+        }
+    };
+
+    // Subscribe to the drag so the indicator follows the cursor
+    let indicator = (*drag.read()).and_then(|_| {
+        let (from, to) = ctx.drop_target().filter(|(from, to)| from != to)?;
+        let mut others = ctx.ordered_tabs();
+        others.remove(from);
+        let area = if to < others.len() {
+            let area = others[to].layout.peek().area;
+            (area.min_x(), area.min_y(), area.height())
+        } else {
+            let area = others.last()?.layout.peek().area;
+            (area.max_x() - 2., area.min_y(), area.height())
+        };
+        Some(area)
+    });
+
+    rsx!(
+        rect {
+            width: "{width}",
+            height: "{height}",
+            onglobalmousemove,
+            onglobalclick,
             {children}
+            if let Some((x, y, height)) = indicator {
+                rect {
+                    position: "global",
+                    position_left: "{x}",
+                    position_top: "{y}",
+                    layer: "-1000",
+                    width: "2",
+                    height: "{height}",
+                    background: "{focus_border_fill}",
+                }
+            }
+        }
+    )
+}
+
+/// Content of the [`Tab`] with the same `value` inside a [`Tabs`].
+///
+/// Panels are not mounted until their Tab is selected for the first time,
+/// and from then on they are only hidden while inactive so they keep their state.
+#[allow(non_snake_case)]
+#[component]
+pub fn TabPanel(
+    /// Value of the [`Tab`] this panel belongs to.
+    value: String,
+    /// Width of the TabPanel. Default `fill`.
+    #[props(default = "fill".to_string())]
+    width: String,
+    /// Height of the TabPanel. Default `auto`.
+    #[props(default = "auto".to_string())]
+    height: String,
+    /// The content of the TabPanel.
+    children: Element,
+) -> Element {
+    let ctx = use_context::<TabsContext>();
+    let mounted = use_hook(|| Rc::new(Cell::new(false)));
+    let active = *ctx.selected.read() == value;
+
+    if active {
+        mounted.set(true);
+    } else if !mounted.get() {
+        return VNode::empty();
+    }
+
+    rsx!(
+        KeepAlive {
+            active,
+            width,
+            height,
+            rect {
+                width: "fill",
+                height: "fill",
+                a11y_role: "tab-panel",
+                {children}
+            }
         }
     )
 }
@@ -39,6 +353,8 @@ pub enum TabStatus {
 
 ///  Clickable Tab. Usually used in combination with [`Tabsbar`], [`crate::Link`] and [`crate::ActivableRoute`].
 ///
+/// Give it a `value` to use it inside of [`Tabs`] instead.
+///
 /// # Styling
 /// Inherits the [`TabTheme`](freya_hooks::TabTheme) theme.
 ///
@@ -119,11 +435,33 @@ pub fn Tab(
     theme: Option<TabThemeWith>,
     /// Optionally handle the `onclick` event in the SidebarItem.
     onpress: Option<EventHandler<()>>,
+    /// Value of this Tab when used inside of [`Tabs`].
+    value: Option<String>,
+    /// Show a button to close this Tab, handled by the `onclose` of the [`Tabs`]. Default `false`.
+    #[props(default = false)]
+    closable: bool,
 ) -> Element {
-    let focus = use_focus();
+    let mut focus = use_focus();
     let mut status = use_signal(TabStatus::default);
     let platform = use_platform();
-    let is_active = use_activable_route();
+    let is_route_active = use_activable_route();
+    let tabs = try_use_context::<TabsContext>();
+    let (reference, layout) = use_node_signal();
+
+    // Only Tabs with a value are managed by the parent Tabs
+    let tabs = tabs.zip(value);
+    if let Some((tabs, value)) = &tabs {
+        tabs.set_tab(focus.id(), value, layout);
+    }
+
+    use_drop({
+        let tabs = tabs.clone();
+        move || {
+            if let Some((tabs, _)) = tabs {
+                tabs.remove_tab(focus.id());
+            }
+        }
+    });
 
     let a11y_id = focus.attribute();
 
@@ -144,12 +482,97 @@ pub fn Tab(
         }
     });
 
+    let is_active = match &tabs {
+        Some((tabs, value)) => *tabs.selected.read() == *value,
+        None => is_route_active,
+    };
+    let is_dragging = tabs.as_ref().is_some_and(|(tabs, _)| {
+        tabs.drag
+            .read()
+            .is_some_and(|drag| drag.id == focus.id() && drag.moved)
+    });
+    let can_close = closable
+        && tabs
+            .as_ref()
+            .is_some_and(|(tabs, _)| tabs.onclose.is_some());
+
     let onclick = move |_| {
         if let Some(onpress) = &onpress {
             onpress.call(());
         }
     };
 
+    let onmousedown = {
+        let tabs = tabs.clone();
+        move |e: MouseEvent| {
+            if e.data.trigger_button != Some(MouseButton::Left) {
+                return;
+            }
+            if let Some((mut tabs, value)) = tabs.clone() {
+                focus.request_focus();
+                tabs.onchange.call(value);
+                if tabs.onreorder.is_some() {
+                    let origin = e.get_screen_coordinates().x;
+                    tabs.drag.set(Some(TabDrag {
+                        id: focus.id(),
+                        origin,
+                        cursor: origin,
+                        moved: false,
+                    }));
+                }
+            }
+        }
+    };
+
+    let onkeydown = {
+        let tabs = tabs.clone();
+        move |e: KeyboardEvent| {
+            if focus.validate_keydown(&e) {
+                if let Some(onpress) = &onpress {
+                    onpress.call(());
+                }
+            }
+            let Some((tabs, value)) = &tabs else {
+                return;
+            };
+            if !focus.is_focused() {
+                return;
+            }
+            match &e.key {
+                Key::ArrowRight => tabs.select_relative(focus.id(), 1),
+                Key::ArrowLeft => tabs.select_relative(focus.id(), -1),
+                Key::Home => {
+                    if let Some(tab) = tabs.ordered_tabs().first() {
+                        tabs.select(tab);
+                    }
+                }
+                Key::End => {
+                    if let Some(tab) = tabs.ordered_tabs().last() {
+                        tabs.select(tab);
+                    }
+                }
+                Key::Delete if can_close => {
+                    if let Some(onclose) = &tabs.onclose {
+                        onclose.call(value.clone());
+                    }
+                }
+                _ => {}
+            }
+        }
+    };
+
+    let onclose = {
+        let tabs = tabs.clone();
+        move |e: MouseEvent| {
+            e.stop_propagation();
+            if let Some((tabs, value)) = &tabs {
+                if let Some(onclose) = &tabs.onclose {
+                    onclose.call(value.clone());
+                }
+            }
+        }
+    };
+
     let onmouseenter = move |_| {
         platform.set_cursor(CursorIcon::Pointer);
         status.set(TabStatus::Hovering);
@@ -169,10 +592,14 @@ pub fn Tab(
     } else {
         border_fill
     };
+    let opacity = if is_dragging { 0.5 } else { 1. };
 
     rsx!(
         rect {
+            reference,
             onclick,
+            onmousedown,
+            onkeydown,
             onmouseenter,
             onmouseleave,
             a11y_id,
@@ -180,14 +607,30 @@ pub fn Tab(
             height: "{height}",
             overflow: "clip",
             a11y_role:"tab",
+            a11y_selected: "{is_active}",
             color: "{font_theme.color}",
             background: "{background}",
+            opacity: "{opacity}",
             content: "fit",
             rect {
                 padding: "{padding}",
                 main_align: "center",
                 cross_align: "center",
+                direction: "horizontal",
+                spacing: "8",
                 {children}
+                if can_close {
+                    rect {
+                        a11y_role: "button",
+                        a11y_name: "Close",
+                        padding: "2",
+                        onmousedown: move |e: MouseEvent| e.stop_propagation(),
+                        onclick: onclose,
+                        CrossIcon {
+                            fill: font_theme.color.to_string(),
+                        }
+                    }
+                }
             }
             rect {
                 height: "2",
@@ -356,3 +799,95 @@ pub fn BottomTab(
         }
     )
 }
+
+#[cfg(test)]
+mod test {
+    use freya::prelude::*;
+    use freya_testing::prelude::*;
+
+    use super::reorder_target;
+
+    #[test]
+    pub fn reorder() {
+        let centers = [25., 75., 125.];
+        // Barely moved
+        assert_eq!(reorder_target(&centers, 0, 40.), 0);
+        // Past the center of the second tab
+        assert_eq!(reorder_target(&centers, 0, 80.), 1);
+        // Past the end
+        assert_eq!(reorder_target(&centers, 0, 200.), 2);
+        assert_eq!(reorder_target(&centers, 2, 10.), 0);
+    }
+
+    #[tokio::test]
+    pub async fn tabs() {
+        fn tabs_app() -> Element {
+            let mut tabs = use_signal(|| vec!["a".to_string(), "b".to_string(), "c".to_string()]);
+            let mut selected = use_signal(|| "a".to_string());
+
+            rsx!(
+                Tabs {
+                    value: selected(),
+                    onchange: move |value| selected.set(value),
+                    onclose: move |value| tabs.retain(|tab| *tab != value),
+                    Tabsbar {
+                        for tab in tabs() {
+                            Tab {
+                                key: "{tab}",
+                                value: tab.clone(),
+                                closable: true,
+                                label { "{tab}" }
+                            }
+                        }
+                    }
+                    for tab in tabs() {
+                        TabPanel {
+                            key: "{tab}",
+                            value: tab.clone(),
+                            label { "Panel {tab}" }
+                        }
+                    }
+                }
+            )
+        }
+
+        let mut utils = launch_test(tabs_app);
+        utils.wait_for_update().await;
+        let tabs = utils.root().get(0);
+        let tabsbar = tabs.get(0);
+
+        // Only the selected panel is mounted
+        assert_eq!(tabs.children_ids().len(), 2);
+        assert_eq!(tabs.get(1).get(0).get(0).get(0).text(), Some("Panel a"));
+
+        // Select the second tab with the mouse
+        let area = tabsbar.get(1).layout().unwrap().area;
+        utils
+            .click_cursor((area.min_x() as f64 + 5., area.center().y as f64))
+            .await;
+        assert_eq!(tabs.children_ids().len(), 3);
+        assert_eq!(tabs.get(2).get(0).get(0).get(0).text(), Some("Panel b"));
+
+        // Select the last tab with the keyboard
+        utils.push_event(TestEvent::Keyboard {
+            name: EventName::KeyDown,
+            key: Key::ArrowRight,
+            code: Code::ArrowRight,
+            modifiers: Modifiers::default(),
+        });
+        utils.wait_for_update().await;
+        utils.wait_for_update().await;
+        assert_eq!(tabs.children_ids().len(), 4);
+        assert_eq!(tabs.get(3).get(0).get(0).get(0).text(), Some("Panel c"));
+
+        // Close it with the Delete key
+        utils.push_event(TestEvent::Keyboard {
+            name: EventName::KeyDown,
+            key: Key::Delete,
+            code: Code::Delete,
+            modifiers: Modifiers::default(),
+        });
+        utils.wait_for_update().await;
+        assert_eq!(tabsbar.children_ids().len(), 2);
+    }
+}
@@ -0,0 +1,423 @@
+use std::time::Duration;
+
+use dioxus::prelude::*;
+use freya_elements::{
+    self as dioxus_elements,
+    events::MouseEvent,
+};
+use freya_hooks::{
+    use_animation,
+    use_applied_theme,
+    AnimNum,
+    Ease,
+    Function,
+    SnackBarTheme,
+    SnackBarThemeWith,
+};
+
+use crate::{
+    Button,
+    CrossIcon,
+};
+
+/// Duration of the enter and exit animations of the toasts.
+const TOAST_ANIMATION_DURATION: u64 = 200;
+
+/// Identifier of a toast, returned by [`UseNotifications::notify`].
+pub type ToastId = usize;
+
+/// Button of a [`Toast`], pressing it also dismisses the toast.
+#[derive(Clone, PartialEq)]
+pub struct ToastAction {
+    pub label: String,
+    pub onpress: EventHandler,
+}
+
+/// Notification shown by a [`ToastProvider`].
+#[derive(Clone, PartialEq)]
+pub struct Toast {
+    pub title: Option<String>,
+    pub message: String,
+    /// How long until it's dismissed, `None` keeps it until it's closed. Default 5 seconds.
+    pub duration: Option<Duration>,
+    pub actions: Vec<ToastAction>,
+}
+
+impl Toast {
+    pub fn new(message: impl Into<String>) -> Self {
+        Self {
+            title: None,
+            message: message.into(),
+            duration: Some(Duration::from_secs(5)),
+            actions: Vec::new(),
+        }
+    }
+
+    pub fn with_title(mut self, title: impl Into<String>) -> Self {
+        self.title = Some(title.into());
+        self
+    }
+
+    /// Set how long until it's dismissed, `None` keeps it until it's closed.
+    pub fn with_duration(mut self, duration: Option<Duration>) -> Self {
+        self.duration = duration;
+        self
+    }
+
+    /// Add a button, e.g. to undo what was notified.
+    pub fn with_action(
+        mut self,
+        label: impl Into<String>,
+        mut onpress: impl FnMut() + 'static,
+    ) -> Self {
+        self.actions.push(ToastAction {
+            label: label.into(),
+            onpress: EventHandler::new(move |_| onpress()),
+        });
+        self
+    }
+}
+
+/// Corner of the window where the toasts of a [`ToastProvider`] are stacked.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum ToastPosition {
+    TopLeft,
+    TopRight,
+    BottomLeft,
+    #[default]
+    BottomRight,
+}
+
+#[derive(Clone, PartialEq)]
+struct ToastEntry {
+    id: ToastId,
+    toast: Toast,
+    /// Whether it's being dismissed, it's removed once its exit animation finishes.
+    closing: bool,
+}
+
+/// Toasts of the closest [`ToastProvider`], created with [`use_notifications`].
+#[derive(Clone, Copy, PartialEq)]
+pub struct UseNotifications {
+    toasts: Signal<Vec<ToastEntry>>,
+    next_id: Signal<ToastId>,
+}
+
+impl UseNotifications {
+    /// Show a toast, returns its ID so it can be dismissed.
+    pub fn notify(&mut self, toast: Toast) -> ToastId {
+        let id = *self.next_id.peek();
+        self.next_id += 1;
+        self.toasts.write().push(ToastEntry {
+            id,
+            toast,
+            closing: false,
+        });
+        id
+    }
+
+    /// Dismiss a toast.
+    pub fn dismiss(&mut self, id: ToastId) {
+        let mut toasts = self.toasts.write();
+        if let Some(entry) = toasts.iter_mut().find(|entry| entry.id == id) {
+            entry.closing = true;
+        }
+    }
+
+    /// Dismiss all the toasts.
+    pub fn dismiss_all(&mut self) {
+        for entry in self.toasts.write().iter_mut() {
+            entry.closing = true;
+        }
+    }
+
+    /// How many toasts are shown.
+    pub fn len(&self) -> usize {
+        self.toasts.read().len()
+    }
+
+    /// Check whether there are no toasts shown.
+    pub fn is_empty(&self) -> bool {
+        self.toasts.read().is_empty()
+    }
+}
+
+/// Get the toasts of the closest [`ToastProvider`].
+pub fn use_notifications() -> UseNotifications {
+    use_context()
+}
+
+/// Show toasts stacked in a corner of the window, over the rest of the app.
+///
+/// Use [`use_notifications`] in any of its children to show them.
+/// Toasts are dismissed after some time, by closing them or by pressing one of their actions.
+///
+/// # Styling
+/// Inherits the [`SnackBarTheme`](freya_hooks::SnackBarTheme) theme.
+///
+/// # Example
+///
+/// ```no_run
+/// # use freya::prelude::*;
+/// fn app() -> Element {
+///     rsx!(ToastProvider {
+///         Save {}
+///     })
+/// }
+///
+/// #[component]
+/// fn Save() -> Element {
+///     let mut notifications = use_notifications();
+///
+///     rsx!(Button {
+///         onpress: move |_| {
+///             notifications.notify(
+///                 Toast::new("The file was saved")
+///                     .with_title("Saved")
+///                     .with_action("Undo", || println!("Undo")),
+///             );
+///         },
+///         label { "Save" }
+///     })
+/// }
+/// ```
+#[allow(non_snake_case)]
+#[component]
+pub fn ToastProvider(
+    /// Corner where the toasts are shown. Default [`ToastPosition::BottomRight`].
+    #[props(default)]
+    position: ToastPosition,
+    /// Width of the toasts. Default `300`.
+    #[props(default = "300".to_string())]
+    width: String,
+    /// Theme override.
+    theme: Option<SnackBarThemeWith>,
+    /// Content of the app.
+    children: Element,
+) -> Element {
+    let notifications = use_context_provider(|| UseNotifications {
+        toasts: Signal::new(Vec::new()),
+        next_id: Signal::new(0),
+    });
+
+    let (top, bottom) = match position {
+        ToastPosition::TopLeft | ToastPosition::TopRight => (Some("16"), None),
+        ToastPosition::BottomLeft | ToastPosition::BottomRight => (None, Some("16")),
+    };
+    let (left, right, cross_align) = match position {
+        ToastPosition::TopLeft | ToastPosition::BottomLeft => (Some("16"), None, "start"),
+        ToastPosition::TopRight | ToastPosition::BottomRight => (None, Some("16"), "end"),
+    };
+
+    rsx!(
+        {children}
+        rect {
+            position: "global",
+            position_top: top,
+            position_bottom: bottom,
+            position_left: left,
+            position_right: right,
+            layer: "-1000",
+            spacing: "8",
+            cross_align,
+            for entry in notifications.toasts.read().iter().cloned() {
+                ToastItem {
+                    key: "{entry.id}",
+                    entry,
+                    notifications,
+                    width: width.clone(),
+                    theme: theme.clone(),
+                }
+            }
+        }
+    )
+}
+
+#[allow(non_snake_case)]
+#[component]
+fn ToastItem(
+    entry: ToastEntry,
+    notifications: UseNotifications,
+    width: String,
+    theme: Option<SnackBarThemeWith>,
+) -> Element {
+    let SnackBarTheme { background, color } = use_applied_theme!(&theme, snackbar);
+    let animations = use_animation(|conf| {
+        conf.auto_start(true);
+        (
+            AnimNum::new(0., 1.)
+                .time(TOAST_ANIMATION_DURATION)
+                .ease(Ease::Out)
+                .function(Function::Expo),
+            AnimNum::new(24., 0.)
+                .time(TOAST_ANIMATION_DURATION)
+                .ease(Ease::Out)
+                .function(Function::Expo),
+        )
+    });
+    let id = entry.id;
+    let duration = entry.toast.duration;
+
+    // Dismiss it once its time is up
+    use_hook(move || {
+        if let Some(duration) = duration {
+            spawn(async move {
+                tokio::time::sleep(duration).await;
+                let mut notifications = notifications;
+                notifications.dismiss(id);
+            });
+        }
+    });
+
+    // Remove it once its exit animation finishes
+    let closing = entry.closing;
+    use_effect(use_reactive!(|closing| {
+        if closing {
+            animations.reverse();
+            spawn(async move {
+                tokio::time::sleep(Duration::from_millis(TOAST_ANIMATION_DURATION)).await;
+                let mut toasts = notifications.toasts;
+                toasts.write().retain(|entry| entry.id != id);
+            });
+        }
+    }));
+
+    let dismiss = move || {
+        let mut notifications = notifications;
+        notifications.dismiss(id);
+    };
+
+    let values = animations.get();
+    let (opacity, offset_y) = &*values.read();
+    let Toast {
+        title,
+        message,
+        actions,
+        ..
+    } = entry.toast;
+
+    rsx!(
+        rect {
+            a11y_role: "alert",
+            width: "{width}",
+            padding: "12",
+            corner_radius: "8",
+            background: "{background}",
+            color: "{color}",
+            shadow: "0 4 8 0 rgb(0, 0, 0, 0.2)",
+            opacity: "{opacity.read()}",
+            offset_y: "{offset_y.read()}",
+            direction: "horizontal",
+            spacing: "8",
+            rect {
+                width: "flex(1)",
+                spacing: "4",
+                if let Some(title) = title {
+                    label {
+                        font_weight: "bold",
+                        "{title}"
+                    }
+                }
+                paragraph {
+                    width: "fill",
+                    text { "{message}" }
+                }
+                if !actions.is_empty() {
+                    rect {
+                        direction: "horizontal",
+                        spacing: "8",
+                        margin: "4 0 0 0",
+                        for (i, action) in actions.into_iter().enumerate() {
+                            Button {
+                                key: "{i}",
+                                onpress: move |_| {
+                                    action.onpress.call(());
+                                    dismiss();
+                                },
+                                label { "{action.label}" }
+                            }
+                        }
+                    }
+                }
+            }
+            rect {
+                padding: "2",
+                onclick: move |_: MouseEvent| dismiss(),
+                CrossIcon {
+                    fill: color,
+                }
+            }
+        }
+    )
+}
+
+#[cfg(test)]
+mod test {
+    use std::time::Duration;
+
+    use freya::prelude::*;
+    use freya_testing::prelude::*;
+    use tokio::time::sleep;
+
+    #[tokio::test]
+    pub async fn toasts() {
+        #[allow(non_snake_case)]
+        #[component]
+        fn Notify() -> Element {
+            let mut notifications = use_notifications();
+            let mut undone = use_signal(|| false);
+
+            rsx!(
+                rect {
+                    width: "100",
+                    height: "50",
+                    onclick: move |_| {
+                        notifications.notify(
+                            Toast::new("Saved").with_duration(Some(Duration::from_millis(300))),
+                        );
+                        notifications.notify(
+                            Toast::new("Deleted")
+                                .with_duration(None)
+                                .with_action("Undo", move || undone.set(true)),
+                        );
+                    },
+                    label { "{undone}" }
+                }
+            )
+        }
+
+        fn toasts_app() -> Element {
+            rsx!(ToastProvider {
+                Notify {}
+            })
+        }
+
+        let mut utils = launch_test(toasts_app);
+        utils.wait_for_update().await;
+        let root = utils.root();
+        let toasts = |utils: &TestingHandler<()>| utils.root().get(1).children_ids().len();
+
+        utils.click_cursor((5., 5.)).await;
+        utils.wait_for_update().await;
+        assert_eq!(toasts(&utils), 2);
+
+        // The first one is dismissed after its duration and the exit animation
+        sleep(Duration::from_millis(600)).await;
+        utils.wait_for_update().await;
+        utils.wait_for_update().await;
+        assert_eq!(toasts(&utils), 1);
+
+        // Pressing the action of the second one calls it and dismisses it
+        let action = root.get(1).get(0).get(0).get(1).get(0);
+        let area = action.layout().unwrap().area;
+        utils
+            .click_cursor((area.center().x as f64, area.center().y as f64))
+            .await;
+        utils.wait_for_update().await;
+        assert_eq!(root.get(0).get(0).get(0).text(), Some("true"));
+        sleep(Duration::from_millis(300)).await;
+        utils.wait_for_update().await;
+        utils.wait_for_update().await;
+        assert_eq!(toasts(&utils), 0);
+    }
+}
@@ -90,6 +90,7 @@ pub enum TooltipPosition {
 /// `TooltipContainer` component.
 ///
 /// Provides a hoverable area where to show a [Tooltip].
+/// See the [`crate::Popover`] for other placements, click or focus triggers and richer content.
 ///
 /// # Example
 #[component]
@@ -49,6 +49,14 @@ where
         }
     }
 
+    /// Get the value of the item.
+    pub fn value(&self) -> &V {
+        match self {
+            Self::Expandable { value, .. } => value,
+            Self::Standalone { value, .. } => value,
+        }
+    }
+
     /// Update the state of the given Expandable Item, e.g to open with more items or to simply close.
     pub fn set_state(&mut self, item_id: &I, item_state: &ExpandableItemState<I, V>) {
         if let TreeItem::Expandable { id, state, .. } = self {
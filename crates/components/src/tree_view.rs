@@ -0,0 +1,576 @@
+#![allow(clippy::type_complexity)]
+
+use std::{
+    fmt::Display,
+    future::Future,
+};
+
+use dioxus::prelude::*;
+use freya_elements::{
+    self as dioxus_elements,
+    events::{
+        Key,
+        KeyboardEvent,
+        MouseEvent,
+    },
+};
+use freya_hooks::{
+    use_applied_theme,
+    use_focus,
+    use_selection_with_onchange,
+    SelectionMode,
+    TreeViewTheme,
+    TreeViewThemeWith,
+};
+
+use crate::{
+    icons::ArrowIcon,
+    table::scroll_to_row,
+    use_scroll_controller,
+    ExpandableItemState,
+    ItemPath,
+    ScrollConfig,
+    TreeItem,
+    VirtualScrollView,
+};
+
+/// Item of the tree as displayed in a row of the [`TreeView`].
+#[derive(Debug, Clone, PartialEq)]
+struct TreeRow<I, V> {
+    id: I,
+    value: V,
+    depth: usize,
+    /// ID of the root item this item belongs to.
+    root_id: I,
+    is_expandable: bool,
+    is_open: bool,
+}
+
+/// Turn the visible items of the tree into a list of rows.
+fn flatten_items<I, V>(items: &[TreeItem<I, V>]) -> Vec<TreeRow<I, V>>
+where
+    I: ItemPath + Clone,
+    V: Clone + PartialEq,
+{
+    fn flatten_item<I, V>(
+        item: &TreeItem<I, V>,
+        depth: usize,
+        root_id: &I,
+        rows: &mut Vec<TreeRow<I, V>>,
+    ) where
+        I: ItemPath + Clone,
+        V: Clone + PartialEq,
+    {
+        let (is_expandable, children) = match item {
+            TreeItem::Expandable {
+                state: ExpandableItemState::Open(children),
+                ..
+            } => (true, Some(children)),
+            TreeItem::Expandable { .. } => (true, None),
+            TreeItem::Standalone { .. } => (false, None),
+        };
+        rows.push(TreeRow {
+            id: item.id().clone(),
+            value: item.value().clone(),
+            depth,
+            root_id: root_id.clone(),
+            is_expandable,
+            is_open: children.is_some(),
+        });
+        for child in children.into_iter().flatten() {
+            flatten_item(child, depth + 1, root_id, rows);
+        }
+    }
+
+    let mut rows = Vec::new();
+    for item in items {
+        flatten_item(item, 0, item.id(), &mut rows);
+    }
+    rows
+}
+
+/// Index of the row of the parent of the given row.
+fn parent_row<I, V>(rows: &[TreeRow<I, V>], index: usize) -> Option<usize> {
+    let depth = rows[index].depth.checked_sub(1)?;
+    rows[..index].iter().rposition(|row| row.depth == depth)
+}
+
+/// Properties for the [`TreeView`] component.
+#[derive(Props, Clone)]
+pub struct TreeViewProps<I, V, Loader>
+where
+    I: ItemPath + Clone + 'static,
+    V: Clone + PartialEq + Display + 'static,
+    Loader: Clone + 'static,
+{
+    /// Root items of the tree, updated when the items are expanded or collapsed.
+    pub items: Signal<Vec<TreeItem<I, V>>>,
+    /// Async function that loads the children of an expandable item when it's expanded.
+    pub load: Loader,
+    /// Handler called with the IDs of the selected items when the selection changes.
+    pub onselect: Option<EventHandler<Vec<I>>>,
+    /// How many items can be selected. Default [`SelectionMode::Multiple`].
+    #[props(default)]
+    pub selection_mode: SelectionMode,
+    /// Height of the rows. Default `26`.
+    #[props(default = 26.)]
+    pub item_height: f32,
+    /// Indentation of every level of the tree. Default `16`.
+    #[props(default = 16.)]
+    pub indent: f32,
+    /// Width of the TreeView. Default `fill`.
+    #[props(default = "fill".into())]
+    pub width: String,
+    /// Height of the TreeView. Default `fill`.
+    #[props(default = "fill".into())]
+    pub height: String,
+    /// Theme override.
+    pub theme: Option<TreeViewThemeWith>,
+}
+
+impl<I, V, Loader> PartialEq for TreeViewProps<I, V, Loader>
+where
+    I: ItemPath + Clone,
+    V: Clone + PartialEq + Display,
+    Loader: Clone,
+{
+    fn eq(&self, other: &Self) -> bool {
+        self.items == other.items
+            && self.onselect == other.onselect
+            && self.selection_mode == other.selection_mode
+            && self.item_height == other.item_height
+            && self.indent == other.indent
+            && self.width == other.width
+            && self.height == other.height
+            && self.theme == other.theme
+    }
+}
+
+/// Tree of [`TreeItem`]s, e.g. the files of a folder, whose children are loaded when expanded.
+///
+/// - Click the arrow of an item, double click it or press `Enter` to expand or collapse it.
+/// - `ArrowRight` expands the active item or moves to its first child, `ArrowLeft` collapses it or moves to its parent.
+/// - Select items by clicking them or with the keyboard, see [`use_selection`](freya_hooks::use_selection).
+///
+/// The values of the items are displayed as labels, and only the visible rows are rendered.
+///
+/// # Styling
+/// Inherits the [`TreeViewTheme`](freya_hooks::TreeViewTheme) theme.
+///
+/// # Example
+///
+/// ```no_run
+/// # use std::path::PathBuf;
+/// # use freya::prelude::*;
+/// async fn read_folder(path: PathBuf) -> Vec<TreeItem<PathBuf, String>> {
+///     // Read the folder...
+///     # vec![]
+/// }
+///
+/// fn app() -> Element {
+///     let items = use_signal(|| {
+///         vec![TreeItem::Expandable {
+///             id: PathBuf::from("/"),
+///             value: "/".to_string(),
+///             state: ExpandableItemState::Closed,
+///         }]
+///     });
+///
+///     rsx!(TreeView {
+///         items,
+///         load: read_folder,
+///         onselect: |selected: Vec<PathBuf>| println!("Selected {selected:?}")
+///     })
+/// }
+/// ```
+#[allow(non_snake_case)]
+pub fn TreeView<I, V, Loader, Fut>(
+    TreeViewProps {
+        mut items,
+        load,
+        onselect,
+        selection_mode,
+        item_height,
+        indent,
+        width,
+        height,
+        theme,
+    }: TreeViewProps<I, V, Loader>,
+) -> Element
+where
+    I: ItemPath + Clone + 'static,
+    V: Clone + PartialEq + Display + 'static,
+    Loader: Clone + Fn(I) -> Fut + 'static,
+    Fut: Future<Output = Vec<TreeItem<I, V>>> + 'static,
+{
+    let theme = use_applied_theme!(&theme, tree_view);
+    let mut focus = use_focus();
+    let mut scroll_controller = use_scroll_controller(ScrollConfig::default);
+    let mut selection = use_selection_with_onchange(selection_mode, move |selected: &[I]| {
+        if let Some(onselect) = onselect {
+            onselect.call(selected.to_vec());
+        }
+    });
+    // Items whose children are being loaded
+    let mut loading = use_signal(Vec::<I>::new);
+    let rows = use_memo(move || flatten_items(&items.read()));
+
+    let mut set_state = move |root_id: &I, id: &I, state: ExpandableItemState<I, V>| {
+        let mut items = items.write();
+        if let Some(root) = items.iter_mut().find(|root| root.id() == root_id) {
+            root.set_state(id, &state);
+        }
+    };
+
+    // Collapse the given item, or load its children and expand it
+    let toggle = move |row: &TreeRow<I, V>| {
+        if row.is_open {
+            set_state(&row.root_id, &row.id, ExpandableItemState::Closed);
+        } else if row.is_expandable && !loading.peek().contains(&row.id) {
+            loading.write().push(row.id.clone());
+            let children = load(row.id.clone());
+            let (root_id, id) = (row.root_id.clone(), row.id.clone());
+            spawn(async move {
+                let children = children.await;
+                set_state(&root_id, &id, ExpandableItemState::Open(children));
+                loading.write().retain(|loading| *loading != id);
+            });
+        }
+    };
+
+    let onkeydown = {
+        let mut toggle = toggle.clone();
+        move |e: KeyboardEvent| {
+            let rows = rows.read().clone();
+            let ids = rows.iter().map(|row| row.id.clone()).collect::<Vec<_>>();
+            let active = selection
+                .active()
+                .and_then(|active| ids.iter().position(|id| *id == active));
+
+            match (&e.key, active) {
+                (Key::ArrowRight, Some(index)) if rows[index].is_open => {
+                    let child = rows
+                        .get(index + 1)
+                        .filter(|row| row.depth > rows[index].depth);
+                    if let Some(child) = child {
+                        selection.select(child.id.clone());
+                    }
+                }
+                (Key::ArrowRight, Some(index)) => toggle(&rows[index]),
+                (Key::ArrowLeft, Some(index)) if rows[index].is_open => toggle(&rows[index]),
+                (Key::ArrowLeft, Some(index)) => {
+                    if let Some(parent) = parent_row(&rows, index) {
+                        selection.select(ids[parent].clone());
+                    }
+                }
+                (Key::Enter, Some(index)) => toggle(&rows[index]),
+                _ => {
+                    selection.keydown(&e.data, &ids);
+                }
+            }
+
+            // Keep the active item visible
+            let Some(position) = selection
+                .active()
+                .and_then(|active| ids.iter().position(|id| *id == active))
+            else {
+                return;
+            };
+            let scroll = -*scroll_controller.y().peek() as f32;
+            let viewport = scroll_controller.layout().peek().area.height();
+            if let Some(to) = scroll_to_row(position, item_height, scroll, viewport) {
+                scroll_controller.scroll_to_y(-to as i32);
+            }
+        }
+    };
+
+    let border = if focus.is_focused_with_keyboard() {
+        format!("2 inner {}", theme.arrow_fill)
+    } else {
+        "none".to_string()
+    };
+    let rows_count = rows.read().len();
+
+    rsx!(
+        rect {
+            a11y_id: focus.attribute(),
+            a11y_role: "tree",
+            a11y_multiselectable: "{selection_mode == SelectionMode::Multiple}",
+            width: "{width}",
+            height: "{height}",
+            background: "{theme.background}",
+            color: "{theme.font_theme.color}",
+            border,
+            onkeydown,
+            VirtualScrollView {
+                length: rows_count,
+                item_size: item_height,
+                scroll_controller,
+                scroll_with_arrows: false,
+                cache_elements: false,
+                builder_args: theme.clone(),
+                builder: move |index, theme: &Option<TreeViewTheme>| {
+                    let theme = theme.clone().unwrap();
+                    let row = rows.read()[index].clone();
+                    let is_loading = loading.read().contains(&row.id);
+                    let selected = selection.is_selected(&row.id);
+                    let mut toggle = toggle.clone();
+                    rsx!(
+                        TreeViewItem {
+                            key: "{index}",
+                            row: row.clone(),
+                            selected,
+                            is_loading,
+                            item_height,
+                            indent,
+                            theme,
+                            onpress: {
+                                let id = row.id.clone();
+                                move |_| {
+                                    focus.request_focus();
+                                    let rows = rows.read();
+                                    let ids = rows.iter().map(|row| row.id.clone());
+                                    selection.click(id.clone(), &ids.collect::<Vec<_>>());
+                                }
+                            },
+                            ontoggle: move |_| toggle(&row),
+                        }
+                    )
+                }
+            }
+        }
+    )
+}
+
+#[derive(Props, Clone, PartialEq)]
+struct TreeViewItemProps<I: Clone + PartialEq + 'static, V: Clone + PartialEq + Display + 'static> {
+    row: TreeRow<I, V>,
+    selected: bool,
+    is_loading: bool,
+    item_height: f32,
+    indent: f32,
+    theme: TreeViewTheme,
+    onpress: EventHandler<MouseEvent>,
+    ontoggle: EventHandler<()>,
+}
+
+/// Row of a [`TreeView`].
+#[allow(non_snake_case)]
+fn TreeViewItem<I: Clone + PartialEq + 'static, V: Clone + PartialEq + Display + 'static>(
+    TreeViewItemProps {
+        row,
+        selected,
+        is_loading,
+        item_height,
+        indent,
+        theme,
+        onpress,
+        ontoggle,
+    }: TreeViewItemProps<I, V>,
+) -> Element {
+    let mut hovering = use_signal(|| false);
+
+    let background = if selected {
+        &*theme.selected_background
+    } else if hovering() {
+        &*theme.hover_background
+    } else {
+        "transparent"
+    };
+    let rotate = if row.is_open { "0" } else { "-90" };
+
+    rsx!(
+        rect {
+            a11y_role: "tree-item",
+            a11y_level: "{row.depth + 1}",
+            a11y_selected: "{selected}",
+            a11y_expanded: if row.is_expandable { Some(row.is_open.to_string()) } else { None },
+            a11y_busy: "{is_loading}",
+            width: "fill",
+            height: "{item_height}",
+            padding: "0 0 0 {row.depth as f32 * indent}",
+            direction: "horizontal",
+            cross_align: "center",
+            background: "{background}",
+            onmouseenter: move |_| hovering.set(true),
+            onmouseleave: move |_| hovering.set(false),
+            onclick: move |e| onpress.call(e),
+            ondoubleclick: move |_| ontoggle.call(()),
+            // Indentation guides
+            for depth in 0..row.depth {
+                rect {
+                    key: "{depth}",
+                    position: "absolute",
+                    position_top: "0",
+                    position_left: "{depth as f32 * indent + indent / 2.}",
+                    width: "1",
+                    height: "fill",
+                    background: "{theme.guide_color}",
+                }
+            }
+            rect {
+                width: "{indent}",
+                height: "fill",
+                main_align: "center",
+                cross_align: "center",
+                onclick: move |e: MouseEvent| {
+                    e.stop_propagation();
+                    ontoggle.call(());
+                },
+                if row.is_expandable {
+                    ArrowIcon {
+                        rotate,
+                        fill: "{theme.arrow_fill}",
+                    }
+                }
+            }
+            label {
+                max_lines: "1",
+                text_overflow: "ellipsis",
+                "{row.value}"
+            }
+            if is_loading {
+                label {
+                    margin: "0 0 0 8",
+                    color: "{theme.loading_color}",
+                    "Loading…"
+                }
+            }
+        }
+    )
+}
+
+#[cfg(test)]
+mod test {
+    use std::path::PathBuf;
+
+    use freya::prelude::*;
+    use freya_testing::prelude::*;
+
+    use super::{
+        flatten_items,
+        parent_row,
+    };
+
+    #[test]
+    fn rows() {
+        let items = vec![
+            TreeItem::Expandable {
+                id: PathBuf::from("/a"),
+                value: "a",
+                state: ExpandableItemState::Open(vec![
+                    TreeItem::Expandable {
+                        id: PathBuf::from("/a/1"),
+                        value: "1",
+                        state: ExpandableItemState::Closed,
+                    },
+                    TreeItem::Standalone {
+                        id: PathBuf::from("/a/2"),
+                        value: "2",
+                    },
+                ]),
+            },
+            TreeItem::Standalone {
+                id: PathBuf::from("/b"),
+                value: "b",
+            },
+        ];
+
+        let rows = flatten_items(&items);
+        let summary = rows
+            .iter()
+            .map(|row| (row.value, row.depth, row.is_expandable, row.is_open))
+            .collect::<Vec<_>>();
+        assert_eq!(
+            summary,
+            vec![
+                ("a", 0, true, true),
+                ("1", 1, true, false),
+                ("2", 1, false, false),
+                ("b", 0, false, false),
+            ]
+        );
+        assert_eq!(rows[2].root_id, PathBuf::from("/a"));
+
+        assert_eq!(parent_row(&rows, 2), Some(0));
+        assert_eq!(parent_row(&rows, 3), None);
+    }
+
+    #[tokio::test]
+    pub async fn tree_view() {
+        fn tree_view_app() -> Element {
+            let items = use_signal(|| {
+                vec![
+                    TreeItem::Expandable {
+                        id: PathBuf::from("/a"),
+                        value: "a".to_string(),
+                        state: ExpandableItemState::Closed,
+                    },
+                    TreeItem::Standalone {
+                        id: PathBuf::from("/b"),
+                        value: "b".to_string(),
+                    },
+                ]
+            });
+            let mut selected = use_signal(Vec::new);
+
+            rsx!(
+                TreeView {
+                    items,
+                    height: "300",
+                    load: |path: PathBuf| async move {
+                        ["1", "2"]
+                            .map(|name| TreeItem::Standalone {
+                                id: path.join(name),
+                                value: name.to_string(),
+                            })
+                            .to_vec()
+                    },
+                    onselect: move |items| selected.set(items),
+                }
+                label { "{selected:?}" }
+            )
+        }
+
+        fn press(utils: &mut TestingHandler<()>, key: Key) {
+            utils.push_event(TestEvent::Keyboard {
+                name: EventName::KeyDown,
+                key,
+                code: Code::Unidentified,
+                modifiers: Modifiers::default(),
+            });
+        }
+
+        let mut utils = launch_test(tree_view_app);
+        utils.wait_for_update().await;
+
+        let rows = |utils: &TestingHandler<()>| {
+            let content = utils.root().get(0).get(0).get(0).get(0);
+            content.children_ids().len()
+        };
+        let selected =
+            |utils: &TestingHandler<()>| utils.root().get(1).get(0).text().map(str::to_string);
+        assert_eq!(rows(&utils), 2);
+
+        // Expand the first item by clicking its arrow
+        utils.click_cursor((8., 13.)).await;
+        utils.wait_for_update().await;
+        utils.wait_for_update().await;
+        assert_eq!(rows(&utils), 4);
+
+        // Select the second child and go back to its parent with the keyboard
+        utils.click_cursor((100., 65.)).await;
+        assert_eq!(selected(&utils).as_deref(), Some("[\"/a/2\"]"));
+        press(&mut utils, Key::ArrowLeft);
+        utils.wait_for_update().await;
+        assert_eq!(selected(&utils).as_deref(), Some("[\"/a\"]"));
+
+        // Collapse it and move to the next item
+        press(&mut utils, Key::ArrowLeft);
+        utils.wait_for_update().await;
+        assert_eq!(rows(&utils), 2);
+        press(&mut utils, Key::ArrowDown);
+        utils.wait_for_update().await;
+        assert_eq!(selected(&utils).as_deref(), Some("[\"/b\"]"));
+    }
+}
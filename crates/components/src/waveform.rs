@@ -0,0 +1,522 @@
+use std::{
+    f32::consts::PI,
+    ops::Range,
+};
+
+use dioxus::prelude::*;
+use freya_core::{
+    custom_attributes::CanvasRunnerContext,
+    parsing::Parse,
+};
+use freya_elements::{
+    self as dioxus_elements,
+    events::{
+        MouseEvent,
+        WheelEvent,
+    },
+};
+use freya_engine::prelude::*;
+use freya_hooks::{
+    use_applied_theme,
+    use_canvas_with_deps,
+    use_node_signal,
+    use_platform,
+    WaveformThemeWith,
+};
+
+/// How much the visible duration changes for every pixel scrolled with the wheel.
+const WHEEL_ZOOM_SPEED: f64 = 0.01;
+/// Least amount of samples that can be visible when zoomed in.
+const MIN_VISIBLE_SAMPLES: f64 = 64.;
+/// Biggest window of samples analyzed by [`Spectrum`].
+const MAX_FFT_SIZE: usize = 4096;
+
+/// Lowest and highest sample of every pixel, starting at `start` and covering `samples_per_pixel` samples each.
+fn compute_peaks(
+    samples: &[f32],
+    start: f64,
+    samples_per_pixel: f64,
+    width: usize,
+) -> Vec<(f32, f32)> {
+    (0..width)
+        .map(|pixel| {
+            let from = (start + pixel as f64 * samples_per_pixel) as usize;
+            let to = ((start + (pixel + 1) as f64 * samples_per_pixel) as usize).max(from + 1);
+            let Some(chunk) = samples.get(from..to.min(samples.len())) else {
+                return (0., 0.);
+            };
+            chunk
+                .iter()
+                .fold((f32::MAX, f32::MIN), |(min, max), sample| {
+                    (min.min(*sample), max.max(*sample))
+                })
+        })
+        .map(|(min, max)| if min > max { (0., 0.) } else { (min, max) })
+        .collect()
+}
+
+/// In-place radix-2 Fast Fourier Transform of complex numbers, the length must be a power of two.
+fn fft(buffer: &mut [(f32, f32)]) {
+    let n = buffer.len();
+
+    // Bit-reversal permutation
+    let mut j = 0;
+    for i in 1..n {
+        let mut bit = n >> 1;
+        while j & bit != 0 {
+            j ^= bit;
+            bit >>= 1;
+        }
+        j |= bit;
+        if i < j {
+            buffer.swap(i, j);
+        }
+    }
+
+    let mut len = 2;
+    while len <= n {
+        let angle = -2. * PI / len as f32;
+        for start in (0..n).step_by(len) {
+            for k in 0..len / 2 {
+                let (sin, cos) = (angle * k as f32).sin_cos();
+                let (re, im) = buffer[start + k + len / 2];
+                let twiddled = (re * cos - im * sin, re * sin + im * cos);
+                let even = buffer[start + k];
+                buffer[start + k] = (even.0 + twiddled.0, even.1 + twiddled.1);
+                buffer[start + k + len / 2] = (even.0 - twiddled.0, even.1 - twiddled.1);
+            }
+        }
+        len <<= 1;
+    }
+}
+
+/// Level of the frequencies of the last samples, from `0` to `1`, grouped logarithmically in `bars`.
+fn spectrum_bars(samples: &[f32], bars: usize, min_decibels: f32) -> Vec<f32> {
+    let size = samples.len().min(MAX_FFT_SIZE);
+    if size < 4 || bars == 0 {
+        return vec![0.; bars];
+    }
+    // Biggest power of two that fits
+    let size = 1 << (usize::BITS - 1 - size.leading_zeros());
+
+    // Hann window to reduce the leakage between frequencies
+    let window = &samples[samples.len() - size..];
+    let mut buffer = window
+        .iter()
+        .enumerate()
+        .map(|(i, sample)| {
+            let hann = 0.5 - 0.5 * (2. * PI * i as f32 / (size - 1) as f32).cos();
+            (sample * hann, 0.)
+        })
+        .collect::<Vec<_>>();
+    fft(&mut buffer);
+
+    // Scaled so a full scale sine is `1`, the window halves the amplitude
+    let magnitudes = buffer[..size / 2]
+        .iter()
+        .map(|(re, im)| (re * re + im * im).sqrt() * 4. / size as f32)
+        .collect::<Vec<_>>();
+    let bins = magnitudes.len() as f32;
+
+    (0..bars)
+        .map(|bar| {
+            // The first bin is the constant offset, not a frequency
+            let start =
+                (bins.powf(bar as f32 / bars as f32) as usize).clamp(1, magnitudes.len() - 1);
+            let end = (bins.powf((bar + 1) as f32 / bars as f32) as usize)
+                .clamp(start + 1, magnitudes.len());
+            let peak = magnitudes[start..end].iter().copied().fold(0., f32::max);
+            let decibels = 20. * peak.max(f32::MIN_POSITIVE).log10();
+            ((decibels - min_decibels) / -min_decibels).clamp(0., 1.)
+        })
+        .collect()
+}
+
+/// Peaks painted by a [`Waveform`].
+#[derive(Clone, PartialEq)]
+struct WaveformPeaks {
+    peaks: Vec<(f32, f32)>,
+    color: String,
+}
+
+/// Properties for the [`Waveform`] component.
+#[derive(Props, Clone, PartialEq)]
+pub struct WaveformProps {
+    /// Mono audio samples, from `-1` to `1`.
+    pub samples: ReadOnlySignal<Vec<f32>>,
+    /// Samples per second.
+    pub sample_rate: u32,
+    /// Position of the playhead, in seconds.
+    pub position: Option<f64>,
+    /// Handler called with the clicked position, in seconds.
+    pub onseek: Option<EventHandler<f64>>,
+    /// Handler called with the selected range, in seconds, after dragging over the waveform.
+    pub onselect: Option<EventHandler<Range<f64>>>,
+    /// Width of the Waveform. Default `fill`.
+    #[props(default = "fill".into())]
+    pub width: String,
+    /// Height of the Waveform. Default `100`.
+    #[props(default = "100".into())]
+    pub height: String,
+    /// Theme override.
+    pub theme: Option<WaveformThemeWith>,
+}
+
+/// Waveform of a buffer of audio samples, with a playhead and selection.
+///
+/// Zoom around the cursor with the vertical wheel and move with the horizontal wheel.
+/// Clicking seeks to that position and dragging selects a range.
+///
+/// # Example
+///
+/// ```no_run
+/// # use freya::prelude::*;
+/// fn app() -> Element {
+///     let samples = use_signal(|| {
+///         (0..44100)
+///             .map(|i| (i as f32 * 440. * std::f32::consts::TAU / 44100.).sin())
+///             .collect::<Vec<f32>>()
+///     });
+///     let mut position = use_signal(|| 0.);
+///
+///     rsx!(Waveform {
+///         samples,
+///         sample_rate: 44100,
+///         position: position(),
+///         onseek: move |seconds| position.set(seconds),
+///         onselect: move |range| println!("Selected {range:?}"),
+///     })
+/// }
+/// ```
+#[allow(non_snake_case)]
+pub fn Waveform(
+    WaveformProps {
+        samples,
+        sample_rate,
+        position,
+        onseek,
+        onselect,
+        width,
+        height,
+        theme,
+    }: WaveformProps,
+) -> Element {
+    let theme = use_applied_theme!(&theme, waveform);
+    let platform = use_platform();
+    let (reference, layout) = use_node_signal();
+    // Visible range, in seconds, or everything if `None`
+    let mut view = use_signal::<Option<(f64, f64)>>(|| None);
+    let mut selection = use_signal::<Option<(f64, f64)>>(|| None);
+    let mut anchor = use_signal::<Option<f64>>(|| None);
+    let mut cursor = use_signal(|| 0.);
+
+    let sample_rate = sample_rate.max(1) as f64;
+    let samples_length = samples.read().len();
+    let total_duration = samples_length as f64 / sample_rate;
+    let view_width = layout.read().area.width() as f64;
+    let (start, duration) = view().unwrap_or((0., total_duration));
+
+    // Positions from and to seconds
+    let time_at = move |x: f64| {
+        let (start, duration) = view.peek().unwrap_or((0., total_duration));
+        start + x / view_width.max(1.) * duration
+    };
+    let x_at = |seconds: f64| (seconds - start) / duration.max(f64::EPSILON) * view_width;
+
+    let peaks = WaveformPeaks {
+        peaks: compute_peaks(
+            &samples.read(),
+            start * sample_rate,
+            duration * sample_rate / view_width.max(1.),
+            view_width as usize,
+        ),
+        color: theme.color.to_string(),
+    };
+
+    let canvas = use_canvas_with_deps(&peaks, move |peaks| {
+        platform.invalidate_drawing_area(layout.peek().area);
+        platform.request_animation_frame();
+        let color = Color::parse(&peaks.color).unwrap_or(Color::BLACK);
+        move |ctx: &mut CanvasRunnerContext| {
+            if peaks.peaks.is_empty() {
+                return;
+            }
+            let center = ctx.area.center().y;
+            let half_height = ctx.area.height() / 2.;
+
+            // Outline through the highest samples and back through the lowest
+            let mut path = Path::new();
+            for (x, (_, max)) in peaks.peaks.iter().enumerate() {
+                let point = (ctx.area.min_x() + x as f32, center - max * half_height);
+                if x == 0 {
+                    path.move_to(point);
+                } else {
+                    path.line_to(point);
+                }
+            }
+            for (x, (min, _)) in peaks.peaks.iter().enumerate().rev() {
+                // Keep at least a pixel so silence is still visible
+                path.line_to((
+                    ctx.area.min_x() + x as f32,
+                    (center - min * half_height).max(center + 0.5),
+                ));
+            }
+            path.close();
+
+            let mut paint = Paint::default();
+            paint.set_anti_alias(true);
+            paint.set_style(PaintStyle::Fill);
+            paint.set_color(color);
+            ctx.canvas.draw_path(&path, &paint);
+        }
+    });
+
+    let onmousedown = move |e: MouseEvent| {
+        let time = time_at(e.get_element_coordinates().x);
+        anchor.set(Some(time));
+        selection.set(None);
+    };
+
+    let onmousemove = move |e: MouseEvent| {
+        let x = e.get_element_coordinates().x;
+        cursor.set(x);
+        if let Some(anchor) = *anchor.peek() {
+            selection.set(Some((anchor, time_at(x))));
+        }
+    };
+
+    let onglobalclick = move |_: MouseEvent| {
+        let Some(anchor_time) = *anchor.peek() else {
+            return;
+        };
+        anchor.set(None);
+        let (start, duration) = view.peek().unwrap_or((0., total_duration));
+        let pixel = duration / view_width.max(1.);
+        match *selection.peek() {
+            // Dragged a few pixels at least
+            Some((from, to)) if (to - from).abs() > pixel * 2. => {
+                if let Some(onselect) = onselect {
+                    onselect.call(from.min(to).max(0.)..from.max(to).min(total_duration));
+                }
+            }
+            _ => {
+                selection.set(None);
+                if let Some(onseek) = onseek {
+                    onseek.call(anchor_time.clamp(start, start + duration));
+                }
+            }
+        }
+    };
+
+    let onwheel = move |e: WheelEvent| {
+        if total_duration == 0. {
+            return;
+        }
+        e.stop_propagation();
+        let (start, duration) = view.peek().unwrap_or((0., total_duration));
+        let min_duration = (MIN_VISIBLE_SAMPLES / sample_rate).min(total_duration);
+
+        // Zoom around the cursor
+        let new_duration = (duration * 2f64.powf(-e.get_delta_y() * WHEEL_ZOOM_SPEED))
+            .clamp(min_duration, total_duration);
+        let cursor_ratio = *cursor.peek() / view_width.max(1.);
+        let anchor_time = start + cursor_ratio * duration;
+        let new_start = anchor_time
+            - cursor_ratio * new_duration
+            - e.get_delta_x() / view_width.max(1.) * new_duration;
+
+        let new_start = new_start.clamp(0., total_duration - new_duration);
+        if new_duration == total_duration {
+            view.set(None);
+        } else {
+            view.set(Some((new_start, new_duration)));
+        }
+    };
+
+    let selection_area = selection().map(|(from, to)| {
+        let (from, to) = (x_at(from.min(to)), x_at(from.max(to)));
+        (from, to - from)
+    });
+    let playhead = position
+        .map(x_at)
+        .filter(|x| (0.0..=view_width).contains(x));
+
+    rsx!(
+        rect {
+            reference,
+            width: "{width}",
+            height: "{height}",
+            background: "{theme.background}",
+            overflow: "clip",
+            canvas_reference: canvas.attribute(),
+            onmousedown,
+            onmousemove,
+            onglobalclick,
+            onwheel,
+            if let Some((left, width)) = selection_area {
+                rect {
+                    position: "absolute",
+                    position_top: "0",
+                    position_left: "{left}",
+                    width: "{width}",
+                    height: "fill",
+                    background: "{theme.selection_background}",
+                }
+            }
+            if let Some(left) = playhead {
+                rect {
+                    position: "absolute",
+                    position_top: "0",
+                    position_left: "{left - 1.}",
+                    width: "2",
+                    height: "fill",
+                    background: "{theme.playhead_color}",
+                }
+            }
+        }
+    )
+}
+
+/// Levels painted by a [`Spectrum`].
+#[derive(Clone, PartialEq)]
+struct SpectrumBars {
+    bars: Vec<f32>,
+    color: String,
+}
+
+/// Properties for the [`Spectrum`] component.
+#[derive(Props, Clone, PartialEq)]
+pub struct SpectrumProps {
+    /// Latest mono audio samples, from `-1` to `1`. Up to the last 4096 are analyzed.
+    pub samples: ReadOnlySignal<Vec<f32>>,
+    /// Amount of bars, the frequencies are grouped logarithmically. Default `64`.
+    #[props(default = 64)]
+    pub bars: usize,
+    /// Level, in decibels, of an empty bar. Default `-80`.
+    #[props(default = -80.)]
+    pub min_decibels: f32,
+    /// Width of the Spectrum. Default `fill`.
+    #[props(default = "fill".into())]
+    pub width: String,
+    /// Height of the Spectrum. Default `100`.
+    #[props(default = "100".into())]
+    pub height: String,
+    /// Theme override.
+    pub theme: Option<WaveformThemeWith>,
+}
+
+/// Frequency spectrum of the latest audio samples, meant to be fed from a live stream.
+///
+/// # Example
+///
+/// ```no_run
+/// # use freya::prelude::*;
+/// fn app() -> Element {
+///     let samples = use_signal(Vec::<f32>::new);
+///
+///     // Replace the samples with the latest ones from the audio stream
+///
+///     rsx!(Spectrum { samples, bars: 32 })
+/// }
+/// ```
+#[allow(non_snake_case)]
+pub fn Spectrum(
+    SpectrumProps {
+        samples,
+        bars,
+        min_decibels,
+        width,
+        height,
+        theme,
+    }: SpectrumProps,
+) -> Element {
+    let theme = use_applied_theme!(&theme, waveform);
+    let platform = use_platform();
+    let (reference, layout) = use_node_signal();
+
+    let bars = SpectrumBars {
+        bars: spectrum_bars(&samples.read(), bars, min_decibels),
+        color: theme.color.to_string(),
+    };
+
+    let canvas = use_canvas_with_deps(&bars, move |bars| {
+        platform.invalidate_drawing_area(layout.peek().area);
+        platform.request_animation_frame();
+        let color = Color::parse(&bars.color).unwrap_or(Color::BLACK);
+        move |ctx: &mut CanvasRunnerContext| {
+            let bar_width = ctx.area.width() / bars.bars.len().max(1) as f32;
+            let mut paint = Paint::default();
+            paint.set_anti_alias(true);
+            paint.set_style(PaintStyle::Fill);
+            paint.set_color(color);
+
+            for (i, level) in bars.bars.iter().enumerate() {
+                let height = level * ctx.area.height();
+                ctx.canvas.draw_rect(
+                    Rect::from_xywh(
+                        ctx.area.min_x() + i as f32 * bar_width,
+                        ctx.area.max_y() - height,
+                        (bar_width - 1.).max(1.),
+                        height,
+                    ),
+                    &paint,
+                );
+            }
+        }
+    });
+
+    rsx!(rect {
+        reference,
+        width: "{width}",
+        height: "{height}",
+        background: "{theme.background}",
+        canvas_reference: canvas.attribute(),
+    })
+}
+
+#[cfg(test)]
+mod test {
+    use std::f32::consts::TAU;
+
+    use super::{
+        compute_peaks,
+        spectrum_bars,
+    };
+
+    #[test]
+    fn peaks() {
+        let samples = [0., 1., -1., 0.5, 0.25];
+        assert_eq!(
+            compute_peaks(&samples, 0., 2., 4),
+            vec![(0., 1.), (-1., 0.5), (0.25, 0.25), (0., 0.)]
+        );
+
+        // Zoomed in further than a sample per pixel
+        assert_eq!(
+            compute_peaks(&samples, 1., 0.5, 3),
+            vec![(1., 1.), (1., 1.), (-1., -1.)]
+        );
+    }
+
+    #[test]
+    fn spectrum() {
+        // A full scale sine of 64 cycles every 1024 samples
+        let samples = (0..1024)
+            .map(|i| (i as f32 * 64. * TAU / 1024.).sin())
+            .collect::<Vec<f32>>();
+        let bars = spectrum_bars(&samples, 9, -80.);
+        assert_eq!(bars.len(), 9);
+
+        // The bins from 64 to 128 of 512
+        let loudest = bars
+            .iter()
+            .enumerate()
+            .max_by(|(_, a), (_, b)| a.total_cmp(b))
+            .unwrap();
+        assert_eq!(loudest.0, 6);
+        assert!(*loudest.1 > 0.99);
+        assert!(bars[2] < 0.5);
+
+        assert_eq!(spectrum_bars(&[], 4, -80.), vec![0.; 4]);
+    }
+}
@@ -0,0 +1,207 @@
+use dioxus::prelude::*;
+use freya_core::platform::{
+    CursorIcon,
+    MouseButton,
+    ResizeDirection,
+};
+use freya_elements::{
+    self as dioxus_elements,
+    events::MouseEvent,
+};
+use freya_hooks::use_platform;
+
+use crate::CursorArea;
+
+/// Invisible borders around its children that resize the window when dragged with a left mouse click.
+///
+/// Meant for frameless windows, launched with `LaunchConfig::with_decorations(false)`,
+/// in combination with a custom titlebar using [`crate::WindowDragArea`].
+///
+/// # Example
+///
+/// ```no_run
+/// # use freya::prelude::*;
+/// fn app() -> Element {
+///     rsx!(
+///         WindowResizeBorders {
+///             WindowDragArea {
+///                 rect {
+///                     width: "100%",
+///                     height: "32",
+///                     background: "rgb(40, 40, 40)",
+///                     label { "My App" }
+///                 }
+///             }
+///         }
+///     )
+/// }
+/// ```
+#[allow(non_snake_case)]
+#[component]
+pub fn WindowResizeBorders(
+    /// Width of the borders. Defaults to `6`.
+    #[props(default = 6.)]
+    border_width: f32,
+    /// Radius of the corners of the window, see `LaunchConfig::with_corner_radius`.
+    /// The corners resize diagonally in the whole rounded area. Defaults to `0`.
+    #[props(default = 0.)]
+    corner_radius: f32,
+    /// The inner children for the WindowResizeBorders
+    children: Element,
+) -> Element {
+    use ResizeDirection::*;
+    let width = border_width.to_string();
+    let corner_size = border_width.max(corner_radius).to_string();
+    let edge = |full: bool| {
+        if full {
+            "100%".to_string()
+        } else {
+            width.clone()
+        }
+    };
+    let is_corner = |direction: ResizeDirection| {
+        matches!(direction, NorthWest | NorthEast | SouthWest | SouthEast)
+    };
+
+    // Direction, whether it sticks to the top and left sides, and whether it spans the whole width and height
+    let borders = [
+        (North, true, true, true, false),
+        (South, false, true, true, false),
+        (West, true, true, false, true),
+        (East, true, false, false, true),
+        (NorthWest, true, true, false, false),
+        (NorthEast, true, false, false, false),
+        (SouthWest, false, true, false, false),
+        (SouthEast, false, false, false, false),
+    ];
+
+    rsx!(
+        rect {
+            width: "100%",
+            height: "100%",
+            {children}
+            for (direction, is_top, is_left, full_width, full_height) in borders {
+                WindowResizeBorder {
+                    key: "{direction:?}",
+                    direction,
+                    is_top,
+                    is_left,
+                    width: if is_corner(direction) { corner_size.clone() } else { edge(full_width) },
+                    height: if is_corner(direction) { corner_size.clone() } else { edge(full_height) },
+                }
+            }
+        }
+    )
+}
+
+#[allow(non_snake_case)]
+#[component]
+fn WindowResizeBorder(
+    direction: ResizeDirection,
+    is_top: bool,
+    is_left: bool,
+    width: String,
+    height: String,
+) -> Element {
+    let platform = use_platform();
+
+    let onmousedown = move |e: MouseEvent| {
+        if let Some(MouseButton::Left) = e.trigger_button {
+            e.stop_propagation();
+            platform.drag_resize_window(direction);
+        }
+    };
+
+    let icon = match direction {
+        ResizeDirection::North => CursorIcon::NResize,
+        ResizeDirection::South => CursorIcon::SResize,
+        ResizeDirection::West => CursorIcon::WResize,
+        ResizeDirection::East => CursorIcon::EResize,
+        ResizeDirection::NorthWest => CursorIcon::NwResize,
+        ResizeDirection::NorthEast => CursorIcon::NeResize,
+        ResizeDirection::SouthWest => CursorIcon::SwResize,
+        ResizeDirection::SouthEast => CursorIcon::SeResize,
+    };
+
+    rsx!(
+        rect {
+            position: "absolute",
+            position_top: if is_top { Some("0") } else { None },
+            position_bottom: if is_top { None } else { Some("0") },
+            position_left: if is_left { Some("0") } else { None },
+            position_right: if is_left { None } else { Some("0") },
+            width,
+            height,
+            layer: "-999",
+            onmousedown,
+            CursorArea {
+                icon,
+                rect {
+                    width: "100%",
+                    height: "100%",
+                }
+            }
+        }
+    )
+}
+
+#[cfg(test)]
+mod test {
+    use freya::prelude::*;
+    use freya_core::platform::CursorIcon;
+    use freya_testing::prelude::*;
+
+    #[tokio::test]
+    pub async fn window_resize_borders() {
+        fn window_resize_borders_app() -> Element {
+            rsx!(
+                WindowResizeBorders {
+                    rect {
+                        width: "100%",
+                        height: "100%",
+                    }
+                }
+            )
+        }
+
+        let mut utils = launch_test(window_resize_borders_app);
+        utils.wait_for_update().await;
+
+        utils.move_cursor((2., 250.)).await;
+        assert_eq!(utils.cursor_icon(), CursorIcon::WResize);
+
+        utils.move_cursor((498., 2.)).await;
+        assert_eq!(utils.cursor_icon(), CursorIcon::NeResize);
+
+        utils.move_cursor((250., 250.)).await;
+        assert_eq!(utils.cursor_icon(), CursorIcon::default());
+    }
+
+    #[tokio::test]
+    pub async fn window_resize_borders_rounded_corners() {
+        fn window_resize_borders_app() -> Element {
+            rsx!(
+                WindowResizeBorders {
+                    corner_radius: 12.,
+                    rect {
+                        width: "100%",
+                        height: "100%",
+                    }
+                }
+            )
+        }
+
+        let mut utils = launch_test(window_resize_borders_app);
+        utils.wait_for_update().await;
+
+        // The whole rounded corner resizes diagonally
+        utils.move_cursor((10., 2.)).await;
+        assert_eq!(utils.cursor_icon(), CursorIcon::NwResize);
+
+        utils.move_cursor((490., 498.)).await;
+        assert_eq!(utils.cursor_icon(), CursorIcon::SeResize);
+
+        utils.move_cursor((250., 2.)).await;
+        assert_eq!(utils.cursor_icon(), CursorIcon::NResize);
+    }
+}
@@ -8,7 +8,10 @@ pub use tree::*;
 
 use crate::{
     dom::DioxusNode,
-    states::AccessibilityNodeState,
+    states::{
+        AccessibilityNodeState,
+        LayerState,
+    },
     types::AccessibilityId,
 };
 
@@ -21,6 +24,10 @@ pub trait NodeAccessibility {
 
     /// Collect all the AccessibilityIDs from a Node's children
     fn get_accessibility_children(&self) -> Vec<AccessibilityId>;
+
+    /// Whether this Node is left out of the Accessibility Tree by the `inert` or `offscreen`
+    /// attributes of itself or an ancestor.
+    fn is_hidden(&self) -> bool;
 }
 
 impl NodeAccessibility for DioxusNode<'_> {
@@ -49,7 +56,14 @@ impl NodeAccessibility for DioxusNode<'_> {
     fn get_accessibility_children(&self) -> Vec<AccessibilityId> {
         self.children()
             .into_iter()
+            .filter(|child| !child.is_hidden())
             .filter_map(|child| child.get_accessibility_id())
             .collect_vec()
     }
+
+    fn is_hidden(&self) -> bool {
+        self.get::<LayerState>().is_some_and(|layer_state| {
+            layer_state.inert.is_some() || layer_state.offscreen.is_some()
+        })
+    }
 }
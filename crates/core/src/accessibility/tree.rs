@@ -1,6 +1,9 @@
-use std::sync::atomic::{
-    AtomicU64,
-    Ordering,
+use std::{
+    collections::VecDeque,
+    sync::atomic::{
+        AtomicU64,
+        Ordering,
+    },
 };
 
 use accesskit::{
@@ -25,6 +28,7 @@ use freya_native_core::{
     node::NodeType,
     prelude::NodeImmutable,
     tags::TagName,
+    tree::TreeRef,
     NodeId,
 };
 use rustc_hash::{
@@ -60,6 +64,19 @@ pub enum AccessibilityFocusStrategy {
     Forward,
     Backward,
     Node(accesskit::NodeId),
+    /// Focus the most recently focused Node that still exists.
+    Previous,
+    /// Focus the next focusable Node inside of the given Node, wrapping around.
+    ForwardWithin(accesskit::NodeId),
+    /// Focus the previous focusable Node inside of the given Node, wrapping around.
+    BackwardWithin(accesskit::NodeId),
+}
+
+impl AccessibilityFocusStrategy {
+    /// Whether the focus is moved to the next Node rather than the previous one.
+    fn is_forward(&self) -> bool {
+        matches!(self, Self::Forward | Self::ForwardWithin(_))
+    }
 }
 
 #[derive(Default)]
@@ -109,10 +126,15 @@ impl AccessibilityGenerator {
 
 pub const ACCESSIBILITY_ROOT_ID: AccessibilityId = AccessibilityId(0);
 
+/// Amount of previously focused Nodes that are remembered.
+pub const FOCUS_HISTORY_LENGTH: usize = 16;
+
 pub struct AccessibilityTree {
     pub map: FxHashMap<AccessibilityId, NodeId>,
     // Current focused Accessibility Node.
     pub focused_id: AccessibilityId,
+    // Previously focused Accessibility Nodes that still exist, the most recent goes last.
+    pub focus_history: VecDeque<AccessibilityId>,
 }
 
 impl AccessibilityTree {
@@ -120,6 +142,7 @@ impl AccessibilityTree {
         Self {
             focused_id,
             map: FxHashMap::default(),
+            focus_history: VecDeque::new(),
         }
     }
 
@@ -139,7 +162,7 @@ impl AccessibilityTree {
         let mut nodes = vec![];
 
         rdom.traverse_depth_first_advanced(|node_ref| {
-            if !node_ref.node_type().is_element() {
+            if !node_ref.node_type().is_element() || node_ref.is_hidden() {
                 return false;
             }
 
@@ -217,14 +240,24 @@ impl AccessibilityTree {
             let node_ref = rdom.get(node_id).unwrap();
             let node_ref_parent = node_ref.parent_id().unwrap_or(rdom.root_id());
             added_or_updated_ids.insert(node_ref_parent);
-            self.map
-                .insert(node_ref.get_accessibility_id().unwrap(), node_id);
+            let Some(accessibility_id) = node_ref.get_accessibility_id() else {
+                continue;
+            };
+            // Inert and offscreen nodes are left out, so they can't be focused either
+            if node_ref.is_hidden() {
+                self.map.remove(&accessibility_id);
+            } else {
+                self.map.insert(accessibility_id, node_id);
+            }
         }
 
         // Create the updated nodes
         let mut nodes = Vec::new();
         for node_id in added_or_updated_ids {
             let node_ref = rdom.get(node_id).unwrap();
+            if node_ref.is_hidden() {
+                continue;
+            }
             let node_accessibility_state = node_ref.get::<AccessibilityNodeState>();
             let layout_node = layout.get(node_id);
 
@@ -249,6 +282,9 @@ impl AccessibilityTree {
             self.focused_id = ACCESSIBILITY_ROOT_ID;
         }
 
+        // Forget the Nodes that no longer exist
+        self.focus_history.retain(|id| self.map.contains_key(id));
+
         let node_id = self.map.get(&self.focused_id).cloned().unwrap();
 
         (
@@ -267,15 +303,54 @@ impl AccessibilityTree {
         stragegy: AccessibilityFocusStrategy,
         rdom: &DioxusDOM,
     ) {
-        if let AccessibilityFocusStrategy::Node(id) = stragegy {
-            self.focused_id = id;
-            return;
+        match stragegy {
+            AccessibilityFocusStrategy::Node(id) => {
+                self.set_focused_id(id);
+                return;
+            }
+            AccessibilityFocusStrategy::Previous => {
+                while let Some(id) = self.focus_history.pop_back() {
+                    if id != self.focused_id && self.map.contains_key(&id) {
+                        self.focused_id = id;
+                        break;
+                    }
+                }
+                return;
+            }
+            _ => {}
         }
 
+        // Only the Nodes inside of this one can be focused
+        let scope = match &stragegy {
+            AccessibilityFocusStrategy::ForwardWithin(id)
+            | AccessibilityFocusStrategy::BackwardWithin(id) => {
+                let Some(scope) = self.map.get(id) else {
+                    return;
+                };
+                Some(*scope)
+            }
+            _ => None,
+        };
+        let tree = rdom.tree_ref();
+        let is_in_scope = |mut node_id: NodeId| {
+            let Some(scope) = scope else {
+                return true;
+            };
+            loop {
+                if node_id == scope {
+                    return true;
+                }
+                match tree.parent_id(node_id) {
+                    Some(parent_id) => node_id = parent_id,
+                    None => return false,
+                }
+            }
+        };
+
         let mut nodes = Vec::new();
 
         rdom.traverse_depth_first_advanced(|node_ref| {
-            if !node_ref.node_type().is_element() {
+            if !node_ref.node_type().is_element() || node_ref.is_hidden() {
                 return false;
             }
 
@@ -283,7 +358,7 @@ impl AccessibilityTree {
 
             if let Some(accessibility_id) = accessibility_id {
                 let accessibility_state = node_ref.get::<AccessibilityNodeState>().unwrap();
-                if accessibility_state.a11y_focusable.is_enabled() {
+                if accessibility_state.a11y_focusable.is_enabled() && is_in_scope(node_ref.id()) {
                     nodes.push(accessibility_id)
                 }
             }
@@ -297,11 +372,15 @@ impl AccessibilityTree {
             true
         });
 
+        if scope.is_some() && nodes.is_empty() {
+            return;
+        }
+
         let node_index = nodes
             .iter()
             .position(|accessibility_id| *accessibility_id == self.focused_id);
 
-        let target_node = if stragegy == AccessibilityFocusStrategy::Forward {
+        let target_node = if stragegy.is_forward() {
             // Find the next Node
             if let Some(node_index) = node_index {
                 if node_index == nodes.len() - 1 {
@@ -325,12 +404,29 @@ impl AccessibilityTree {
             }
         };
 
-        self.focused_id = target_node.copied().unwrap_or(ACCESSIBILITY_ROOT_ID);
+        self.set_focused_id(target_node.copied().unwrap_or(ACCESSIBILITY_ROOT_ID));
 
         #[cfg(debug_assertions)]
         tracing::info!("Focused {:?} node.", self.focused_id);
     }
 
+    /// Focus a Node, remembering the one focused until now.
+    fn set_focused_id(&mut self, id: AccessibilityId) {
+        let previous_id = self.focused_id;
+        if previous_id == id {
+            return;
+        }
+        if previous_id != ACCESSIBILITY_ROOT_ID {
+            self.focus_history
+                .retain(|history_id| *history_id != previous_id);
+            self.focus_history.push_back(previous_id);
+            if self.focus_history.len() > FOCUS_HISTORY_LENGTH {
+                self.focus_history.pop_front();
+            }
+        }
+        self.focused_id = id;
+    }
+
     /// Create an accessibility node
     pub fn create_node(
         node_ref: &DioxusNode,
@@ -372,7 +468,11 @@ impl AccessibilityTree {
             if matches!(node.tag, TagName::Label | TagName::Paragraph) && builder.value().is_none()
             {
                 if let Some(inner_text) = node_ref.get_inner_texts() {
-                    builder.set_value(inner_text);
+                    if node_accessibility.sensitive {
+                        builder.set_value(AccessibilityNodeState::mask_text(&inner_text));
+                    } else {
+                        builder.set_value(inner_text);
+                    }
                 }
             }
         }
@@ -439,16 +539,22 @@ impl AccessibilityTree {
         }
 
         // Text alignment
+        let is_rtl = font_style_state.is_rtl();
         builder.set_text_align(match font_style_state.text_align {
             TextAlign::Center => accesskit::TextAlign::Center,
             TextAlign::Justify => accesskit::TextAlign::Justify,
-            // TODO: change representation of `Start` and `End` once RTL text/writing modes are supported.
+            TextAlign::Start if is_rtl => accesskit::TextAlign::Right,
+            TextAlign::End if is_rtl => accesskit::TextAlign::Left,
             TextAlign::Left | TextAlign::Start => accesskit::TextAlign::Left,
             TextAlign::Right | TextAlign::End => accesskit::TextAlign::Right,
         });
 
-        // TODO: Adjust this once text direction support other than RTL is properly added
-        builder.set_text_direction(TextDirection::LeftToRight);
+        // Text direction
+        builder.set_text_direction(if is_rtl {
+            TextDirection::RightToLeft
+        } else {
+            TextDirection::LeftToRight
+        });
 
         // Set italic property for italic/oblique font slants
         match font_style_state.font_slant {
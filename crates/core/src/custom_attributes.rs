@@ -14,6 +14,7 @@ use std::{
 use accesskit::NodeId as AccessibilityId;
 use bytes::Bytes;
 use dioxus_core::AttributeValue;
+use freya_elements::events::UnmountedListener;
 use freya_engine::prelude::*;
 use freya_native_core::node::FromAnyValue;
 use tokio::sync::{
@@ -25,6 +26,8 @@ use torin::geometry::{
     Size2D,
 };
 
+use crate::node::ComputedStyle;
+
 /// Image Reference
 #[derive(Clone, Debug)]
 pub struct ImageReference(pub Arc<Mutex<Option<Bytes>>>);
@@ -55,6 +58,22 @@ impl NodeReferenceLayout {
     }
 }
 
+/// Computed style of a certain Node, used by `use_computed_style`.
+#[derive(Debug, Clone)]
+pub struct StyleReference(pub Arc<watch::Sender<ComputedStyle>>);
+
+impl PartialEq for StyleReference {
+    fn eq(&self, other: &Self) -> bool {
+        Arc::ptr_eq(&self.0, &other.0)
+    }
+}
+
+impl Display for StyleReference {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("StyleReference").finish_non_exhaustive()
+    }
+}
+
 /// Messages emitted from the layout library to the Nodes. Used in `use_editable`.
 #[derive(Debug)]
 pub enum CursorLayoutResponse {
@@ -139,34 +158,132 @@ impl AttributesBytes {
     }
 }
 
+/// Style of a [`TextGridCell`].
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct TextGridStyle {
+    /// Color of the character, the `color` of the `textgrid` if `None`.
+    pub color: Option<Color>,
+    /// Background of the cell, transparent if `None`.
+    pub background: Option<Color>,
+    pub bold: bool,
+    pub italic: bool,
+    pub underline: bool,
+    pub strikethrough: bool,
+}
+
+/// Cell of a [`TextGrid`].
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct TextGridCell {
+    pub character: char,
+    pub style: TextGridStyle,
+}
+
+impl Default for TextGridCell {
+    fn default() -> Self {
+        Self {
+            character: ' ',
+            style: TextGridStyle::default(),
+        }
+    }
+}
+
+/// Fixed size grid of cells displayed by the `textgrid` element, one character per cell.
+#[derive(Clone, Debug, PartialEq)]
+pub struct TextGrid {
+    columns: usize,
+    rows: usize,
+    cells: Arc<Vec<TextGridCell>>,
+}
+
+impl TextGrid {
+    /// Create a grid filled with empty cells.
+    pub fn new(columns: usize, rows: usize) -> Self {
+        Self {
+            columns,
+            rows,
+            cells: Arc::new(vec![TextGridCell::default(); columns * rows]),
+        }
+    }
+
+    pub fn columns(&self) -> usize {
+        self.columns
+    }
+
+    pub fn rows(&self) -> usize {
+        self.rows
+    }
+
+    /// Get the cells of a row.
+    pub fn row(&self, row: usize) -> &[TextGridCell] {
+        let start = (row * self.columns).min(self.cells.len());
+        let end = (start + self.columns).min(self.cells.len());
+        &self.cells[start..end]
+    }
+
+    pub fn get(&self, column: usize, row: usize) -> Option<&TextGridCell> {
+        (column < self.columns)
+            .then(|| self.cells.get(row * self.columns + column))
+            .flatten()
+    }
+
+    /// Replace a cell, ignored if it's out of the grid.
+    pub fn set(&mut self, column: usize, row: usize, cell: TextGridCell) {
+        if column < self.columns && row < self.rows {
+            Arc::make_mut(&mut self.cells)[row * self.columns + column] = cell;
+        }
+    }
+
+    /// Write some text in a row starting at the given column, cut at the end of the row.
+    pub fn write_str(&mut self, column: usize, row: usize, text: &str, style: TextGridStyle) {
+        for (i, character) in text.chars().enumerate() {
+            self.set(column + i, row, TextGridCell { character, style });
+        }
+    }
+
+    /// Create the value of the `text_grid` attribute.
+    pub fn attribute(&self) -> AttributeValue {
+        AttributeValue::any_value(CustomAttributeValues::TextGrid(self.clone()))
+    }
+}
+
 /// Group all the custom attribute types
 #[derive(Clone, PartialEq)]
 pub enum CustomAttributeValues {
     Reference(NodeReference),
+    StyleReference(StyleReference),
     CursorReference(CursorReference),
     Bytes(AttributesBytes),
     ImageReference(ImageReference),
     AccessibilityId(AccessibilityId),
     TextHighlights(Vec<(usize, usize)>),
     Canvas(CanvasReference),
+    TextGrid(TextGrid),
+    Unmounted(UnmountedListener),
 }
 
 impl Debug for CustomAttributeValues {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         match self {
             Self::Reference(_) => f.debug_tuple("Reference").finish(),
+            Self::StyleReference(_) => f.debug_tuple("StyleReference").finish(),
             Self::CursorReference(_) => f.debug_tuple("CursorReference").finish(),
             Self::Bytes(_) => f.debug_tuple("Bytes").finish(),
             Self::ImageReference(_) => f.debug_tuple("ImageReference").finish(),
             Self::AccessibilityId(_) => f.debug_tuple("AccessibilityId").finish(),
             Self::TextHighlights(_) => f.debug_tuple("TextHighlights").finish(),
             Self::Canvas(_) => f.debug_tuple("Canvas").finish(),
+            Self::TextGrid(_) => f.debug_tuple("TextGrid").finish(),
+            Self::Unmounted(_) => f.debug_tuple("Unmounted").finish(),
         }
     }
 }
 
 impl FromAnyValue for CustomAttributeValues {
     fn from_any_value(b: &dyn Any) -> Self {
+        // `onunmounted` is declared by freya-elements, which can't build these values
+        if let Some(listener) = b.downcast_ref::<UnmountedListener>() {
+            return Self::Unmounted(listener.clone());
+        }
         b.downcast_ref::<CustomAttributeValues>().unwrap().clone()
     }
 }
@@ -7,25 +7,35 @@ use freya_native_core::NodeId;
 use rustc_hash::FxHashSet;
 
 #[derive(Clone, Default, Debug)]
-pub struct CompositorDirtyNodes(FxHashSet<NodeId>);
+pub struct CompositorDirtyNodes {
+    nodes: FxHashSet<NodeId>,
+    /// Nodes whose own content changed, as opposed to the ones only moved or resized by the layout.
+    invalidated: FxHashSet<NodeId>,
+}
 
 impl Deref for CompositorDirtyNodes {
     type Target = FxHashSet<NodeId>;
 
     fn deref(&self) -> &Self::Target {
-        &self.0
+        &self.nodes
     }
 }
 
 impl DerefMut for CompositorDirtyNodes {
     fn deref_mut(&mut self) -> &mut Self::Target {
-        &mut self.0
+        &mut self.nodes
     }
 }
 
 impl CompositorDirtyNodes {
     /// Mark a certain node as invalidated.
     pub fn invalidate(&mut self, node_id: NodeId) {
-        self.0.insert(node_id);
+        self.nodes.insert(node_id);
+        self.invalidated.insert(node_id);
+    }
+
+    /// Take the nodes invalidated since the last call.
+    pub fn take_invalidated(&mut self) -> FxHashSet<NodeId> {
+        std::mem::take(&mut self.invalidated)
     }
 }
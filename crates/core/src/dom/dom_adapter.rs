@@ -16,6 +16,8 @@ use crate::{
 pub struct DioxusDOMAdapter<'a> {
     pub rdom: &'a DioxusDOM,
     pub scale_factor: f32,
+    /// Mirror the horizontal layout, for right-to-left layouts.
+    pub mirrored: bool,
     cache: FxHashMap<NodeId, bool>,
 }
 
@@ -24,9 +26,30 @@ impl<'a> DioxusDOMAdapter<'a> {
         Self {
             rdom,
             scale_factor,
+            mirrored: false,
             cache: FxHashMap::default(),
         }
     }
+
+    /// Mirror the horizontal layout, see [`Node::mirror_horizontally`].
+    pub fn with_mirrored(mut self, mirrored: bool) -> Self {
+        self.mirrored = mirrored;
+        self
+    }
+
+    /// Layout direction of the parent of the given Node.
+    fn parent_direction(&self, node_id: &NodeId) -> Direction {
+        self.rdom
+            .tree_ref()
+            .parent_id(*node_id)
+            .and_then(|parent_id| self.rdom.get(parent_id))
+            .and_then(|parent| {
+                parent
+                    .get::<LayoutState>()
+                    .map(|layout| layout.direction.clone())
+            })
+            .unwrap_or_default()
+    }
 }
 
 impl DOMAdapter<NodeId> for DioxusDOMAdapter<'_> {
@@ -40,6 +63,17 @@ impl DOMAdapter<NodeId> for DioxusDOMAdapter<'_> {
 
         let mut layout = node.get::<LayoutState>()?.clone();
 
+        // Offscreen Nodes take no space and are not part of the flow of their siblings
+        if layout.offscreen {
+            return Some(Node {
+                width: Size::Pixels(Length::new(0.0)),
+                height: Size::Pixels(Length::new(0.0)),
+                position: Position::new_absolute(),
+                contains_text,
+                ..Node::default()
+            });
+        }
+
         // The root node expands by default
         if *node_id == self.rdom.root_id() {
             layout.width = Size::Percentage(Length::new(100.0));
@@ -58,17 +92,34 @@ impl DOMAdapter<NodeId> for DioxusDOMAdapter<'_> {
             direction: layout.direction,
             padding: layout.padding,
             margin: layout.margin,
+            auto_margins: layout.auto_margins,
             main_alignment: layout.main_alignment,
             cross_alignment: layout.cross_alignment,
+            align_self: layout.align_self,
             offset_x: layout.offset_x,
             offset_y: layout.offset_y,
+            scroll_container: layout.scroll_container,
             has_layout_references: layout.node_ref.is_some(),
             position: layout.position,
             content: layout.content,
+            wrap_content: layout.wrap_content,
             contains_text,
             spacing: layout.spacing,
+            cross_spacing: layout.cross_spacing,
+            flex_grow: layout.flex_grow,
+            flex_shrink: layout.flex_shrink,
+            flex_basis: layout.flex_basis,
+            grid_columns: layout.grid_columns,
+            grid_rows: layout.grid_rows,
+            grid_column: layout.grid_column,
+            grid_row: layout.grid_row,
+            aspect_ratio: layout.aspect_ratio,
         };
 
+        if self.mirrored {
+            node.mirror_horizontally(&self.parent_direction(node_id));
+        }
+
         node.scale_if_needed(self.scale_factor);
 
         Some(node)
@@ -85,6 +136,26 @@ impl DOMAdapter<NodeId> for DioxusDOMAdapter<'_> {
     fn children_of(&mut self, node_id: &NodeId) -> Vec<NodeId> {
         let mut children = self.rdom.tree_ref().children_ids(*node_id);
         children.retain(|id| is_node_valid(self.rdom, &mut self.cache, id));
+
+        // Horizontal Nodes stack their children from right to left when mirrored,
+        // except grids and wrapped content
+        if self.mirrored {
+            let is_horizontal = self
+                .rdom
+                .get(*node_id)
+                .and_then(|node| {
+                    node.get::<LayoutState>().map(|layout| {
+                        layout.direction == Direction::Horizontal
+                            && !layout.content.is_grid()
+                            && !layout.wrap_content.is_wrap()
+                    })
+                })
+                .unwrap_or_default();
+            if is_horizontal {
+                children.reverse();
+            }
+        }
+
         children
     }
 
@@ -32,7 +32,10 @@ use super::{
     mutations_writer::MutationsWriter,
     CompositorDirtyNodes,
     ImagesCache,
+    ParagraphCache,
     ParagraphElements,
+    StyleReferences,
+    WindowPortals,
 };
 use crate::{
     accessibility::{
@@ -43,20 +46,25 @@ use crate::{
     elements::ParagraphElement,
     event_loop_messages::TextGroupMeasurement,
     layers::Layers,
+    node::get_computed_style,
     render::{
         CompositorCache,
         CompositorDirtyArea,
+        FrameCounters,
     },
     states::{
         AccessibilityNodeState,
         CanvasState,
         CursorState,
+        CustomElementState,
         FontStyleState,
         ImageState,
         LayerState,
         LayoutState,
+        StyleReferenceState,
         StyleState,
         SvgState,
+        TextGridState,
         TransformState,
         ViewportState,
     },
@@ -141,6 +149,10 @@ pub struct FreyaDOM {
     accessibility_dirty_nodes: Arc<Mutex<AccessibilityDirtyNodes>>,
     accessibility_generator: Arc<AccessibilityGenerator>,
     images_cache: Arc<Mutex<ImagesCache>>,
+    paragraph_cache: Arc<Mutex<ParagraphCache>>,
+    frame_counters: Arc<Mutex<FrameCounters>>,
+    window_portals: Arc<Mutex<WindowPortals>>,
+    style_references: Arc<Mutex<StyleReferences>>,
 }
 
 impl Default for FreyaDOM {
@@ -157,6 +169,9 @@ impl Default for FreyaDOM {
             LayerState::to_type_erased(),
             SvgState::to_type_erased(),
             ImageState::to_type_erased(),
+            CustomElementState::to_type_erased(),
+            TextGridState::to_type_erased(),
+            StyleReferenceState::to_type_erased(),
         ]);
         let dioxus_integration_state = DioxusState::create(&mut rdom);
         Self {
@@ -171,6 +186,10 @@ impl Default for FreyaDOM {
             accessibility_dirty_nodes: Arc::default(),
             accessibility_generator: Arc::default(),
             images_cache: Arc::default(),
+            paragraph_cache: Arc::default(),
+            frame_counters: Arc::default(),
+            window_portals: Arc::default(),
+            style_references: Arc::default(),
         }
     }
 }
@@ -212,6 +231,18 @@ impl FreyaDOM {
         self.images_cache.lock().unwrap()
     }
 
+    pub fn paragraph_cache(&self) -> MutexGuard<ParagraphCache> {
+        self.paragraph_cache.lock().unwrap()
+    }
+
+    pub fn frame_counters(&self) -> MutexGuard<FrameCounters> {
+        self.frame_counters.lock().unwrap()
+    }
+
+    pub fn window_portals(&self) -> MutexGuard<WindowPortals> {
+        self.window_portals.lock().unwrap()
+    }
+
     /// Create the initial DOM from the given Mutations
     pub fn init_dom(&mut self, vdom: &mut VirtualDom, scale_factor: f32) {
         // Build the RealDOM
@@ -228,6 +259,8 @@ impl FreyaDOM {
             compositor_cache: &mut self.compositor_cache.lock().unwrap(),
             accessibility_dirty_nodes: &mut self.accessibility_dirty_nodes.lock().unwrap(),
             images_cache: &mut self.images_cache.lock().unwrap(),
+            paragraph_cache: &mut self.paragraph_cache.lock().unwrap(),
+            window_portals: &mut self.window_portals.lock().unwrap(),
         });
 
         let mut ctx = SendAnyMap::new();
@@ -239,8 +272,12 @@ impl FreyaDOM {
         ctx.insert(self.rdom.root_id());
         ctx.insert(self.accessibility_generator.clone());
         ctx.insert(self.images_cache.clone());
+        ctx.insert(self.window_portals.clone());
+        ctx.insert(self.style_references.clone());
 
         self.rdom.update_state(ctx);
+
+        self.notify_style_references();
     }
 
     /// Process the given mutations from the [`VirtualDOM`](dioxus_core::VirtualDom).
@@ -259,6 +296,8 @@ impl FreyaDOM {
             compositor_cache: &mut self.compositor_cache.lock().unwrap(),
             accessibility_dirty_nodes: &mut self.accessibility_dirty_nodes.lock().unwrap(),
             images_cache: &mut self.images_cache.lock().unwrap(),
+            paragraph_cache: &mut self.paragraph_cache.lock().unwrap(),
+            window_portals: &mut self.window_portals.lock().unwrap(),
         });
 
         // Update the Nodes states
@@ -271,11 +310,17 @@ impl FreyaDOM {
         ctx.insert(self.rdom.root_id());
         ctx.insert(self.accessibility_generator.clone());
         ctx.insert(self.images_cache.clone());
+        ctx.insert(self.window_portals.clone());
+        ctx.insert(self.style_references.clone());
 
         // Update the Node's states
         let diff = self.rdom.update_state(ctx);
 
         let must_repaint = !diff.is_empty();
+
+        if must_repaint {
+            self.notify_style_references();
+        }
         let must_relayout = !self.layout().get_dirty_nodes().is_empty();
 
         #[cfg(debug_assertions)]
@@ -290,6 +335,32 @@ impl FreyaDOM {
         (must_repaint, must_relayout)
     }
 
+    /// Send the latest computed style to the elements that observe it.
+    pub fn notify_style_references(&self) {
+        self.style_references.lock().unwrap().retain(|node_id| {
+            // Forget the elements that were removed
+            let Some(node) = self.rdom.get(*node_id) else {
+                return false;
+            };
+            let Some(style_ref) = node
+                .get::<StyleReferenceState>()
+                .and_then(|state| state.style_ref.clone())
+            else {
+                return false;
+            };
+
+            let computed_style = get_computed_style(&node);
+            style_ref.0.send_if_modified(|current| {
+                let modified = *current != computed_style;
+                if modified {
+                    *current = computed_style;
+                }
+                modified
+            });
+            true
+        });
+    }
+
     /// Get a reference to the [`DioxusDOM`].
     pub fn rdom(&self) -> &DioxusDOM {
         &self.rdom
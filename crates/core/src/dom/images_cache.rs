@@ -6,12 +6,59 @@ use std::ops::{
 use freya_engine::prelude::Image;
 use rustc_hash::FxHashMap;
 
+use crate::render::{
+    AtlasKey,
+    TextureAtlas,
+};
+
 #[derive(Default, PartialEq, Eq, Hash, Clone, Debug)]
 pub struct ImageCacheKey(pub String);
 
+impl ImageCacheKey {
+    /// Key of the image as the source of its resized copies in the [`TextureAtlas`].
+    pub fn atlas_source(&self) -> AtlasKey {
+        AtlasKey::new(&self.0)
+    }
+}
+
 #[derive(Default, Debug)]
 pub struct ImagesCache {
     cache: FxHashMap<ImageCacheKey, Image>,
+    atlas: TextureAtlas,
+    bypass_atlas: bool,
+}
+
+impl ImagesCache {
+    /// Shared textures for small images that are drawn frequently.
+    pub fn atlas(&mut self) -> &mut TextureAtlas {
+        &mut self.atlas
+    }
+
+    /// The atlas to draw images with, unless it's bypassed.
+    pub fn drawing_atlas(&mut self) -> Option<&mut TextureAtlas> {
+        (!self.bypass_atlas).then_some(&mut self.atlas)
+    }
+
+    /// Draw the images directly instead of using the atlas, e.g. in a surface
+    /// other than the one its pages were created from.
+    pub fn set_bypass_atlas(&mut self, bypass_atlas: bool) {
+        self.bypass_atlas = bypass_atlas;
+    }
+
+    /// Cache the image of a key, its resized copies of a previous image are removed from the atlas.
+    pub fn insert(&mut self, key: ImageCacheKey, image: Image) -> Option<Image> {
+        let previous_image = self.cache.insert(key.clone(), image);
+        if previous_image.is_some() {
+            self.atlas.remove_source(&key.atlas_source());
+        }
+        previous_image
+    }
+
+    /// Remove the image of a key, along with its resized copies in the atlas.
+    pub fn remove(&mut self, key: &ImageCacheKey) -> Option<Image> {
+        self.atlas.remove_source(&key.atlas_source());
+        self.cache.remove(key)
+    }
 }
 
 impl Deref for ImagesCache {
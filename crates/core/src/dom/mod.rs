@@ -3,10 +3,16 @@ pub mod dom_adapter;
 pub mod doms;
 pub mod images_cache;
 mod mutations_writer;
+pub mod paragraph_cache;
 pub mod paragraphs;
+pub mod style_references;
+pub mod window_portals;
 
 pub use compositor_dirty_nodes::*;
 pub use dom_adapter::*;
 pub use doms::*;
 pub use images_cache::*;
+pub use paragraph_cache::*;
 pub use paragraphs::*;
+pub use style_references::*;
+pub use window_portals::*;
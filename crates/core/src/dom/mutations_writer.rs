@@ -3,10 +3,15 @@ use dioxus_core::{
     Template,
     WriteMutations,
 };
+use freya_elements::events::UnmountedData;
 use freya_native_core::{
+    attributes::AttributeName,
+    node::OwnedAttributeValue,
     prelude::{
         DioxusNativeCoreMutationWriter,
+        ElementNode,
         NodeImmutable,
+        NodeType,
     },
     tree::TreeRef,
     NodeId,
@@ -20,7 +25,9 @@ use super::{
     CompositorDirtyNodes,
     DioxusDOMAdapter,
     ImagesCache,
+    ParagraphCache,
     ParagraphElements,
+    WindowPortals,
 };
 use crate::{
     accessibility::{
@@ -52,6 +59,8 @@ pub struct MutationsWriter<'a> {
     pub compositor_cache: &'a mut CompositorCache,
     pub accessibility_dirty_nodes: &'a mut AccessibilityDirtyNodes,
     pub images_cache: &'a mut ImagesCache,
+    pub paragraph_cache: &'a mut ParagraphCache,
+    pub window_portals: &'a mut WindowPortals,
 }
 
 impl MutationsWriter<'_> {
@@ -59,9 +68,32 @@ impl MutationsWriter<'_> {
         let node_id = self.native_writer.state.element_to_node_id(id);
         let mut dom_adapter = DioxusDOMAdapter::new(self.native_writer.rdom, self.scale_factor);
 
+        let tree = self.native_writer.rdom.tree_ref();
+
+        // Notify the `unmounted` listeners of the whole removed subtree
+        let mut stack = vec![node_id];
+        while let Some(node_id) = stack.pop() {
+            let Some(node) = self.native_writer.rdom.get(node_id) else {
+                continue;
+            };
+            if let NodeType::Element(ElementNode { attributes, .. }) = &*node.node_type() {
+                if let Some(OwnedAttributeValue::Custom(CustomAttributeValues::Unmounted(
+                    listener,
+                ))) = attributes.get(&AttributeName::Unmounted)
+                {
+                    let area = self
+                        .layout
+                        .get(node_id)
+                        .map(|layout_node| layout_node.area / self.scale_factor)
+                        .unwrap_or_default();
+                    listener.notify(UnmountedData::new(area));
+                }
+            }
+            stack.extend(tree.children_ids(node_id));
+        }
+
         // Remove from layers , paragraph elements and unite the removed areas with the compositor dirty area
         let mut stack = vec![node_id];
-        let tree = self.native_writer.rdom.tree_ref();
         while let Some(node_id) = stack.pop() {
             if let Some(node) = self.native_writer.rdom.get(node_id) {
                 if !node.node_type().is_visible_element() {
@@ -118,8 +150,19 @@ impl MutationsWriter<'_> {
                     self.compositor_dirty_area.unite_or_insert(&area);
                 }
 
-                // Remove the node from the compositor cache
+                // Remove the node from the compositor and paragraph caches
                 self.compositor_cache.remove(&node_id);
+                self.paragraph_cache.remove(&node_id);
+
+                // Paint again the cached layer that contained this node
+                if let Some(cached_layer) = layer_state.cached_layer {
+                    self.compositor_dirty_nodes.invalidate(cached_layer);
+                }
+
+                // Close the window of the removed portals
+                if layer_state.window_portal == Some(node_id) {
+                    self.window_portals.remove(&node_id);
+                }
 
                 if let Some(image_state) = node.get::<ImageState>() {
                     if let Some(image_cache_key) = &image_state.image_cache_key {
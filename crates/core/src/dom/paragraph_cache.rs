@@ -0,0 +1,128 @@
+use std::sync::Arc;
+
+use freya_native_core::{
+    prelude::{
+        ElementNode,
+        NodeType,
+    },
+    real_dom::NodeImmutable,
+    tags::TagName,
+    NodeId,
+};
+use rustc_hash::FxHashMap;
+use torin::prelude::{
+    SendAnyMap,
+    Size2D,
+};
+
+use super::DioxusNode;
+use crate::states::FontStyleState;
+
+/// Everything the measurement of a `label` or a `paragraph` depends on.
+#[derive(Clone, Debug, PartialEq)]
+pub struct ParagraphCacheKey {
+    font_style: FontStyleState,
+    /// Texts of the element, along with their own font style in the case of `text` spans.
+    texts: Vec<(Option<FontStyleState>, String)>,
+    layout_width: f32,
+    inner_sized: bool,
+    scale_factor: f32,
+}
+
+impl ParagraphCacheKey {
+    pub fn new(node: &DioxusNode, layout_width: f32, inner_sized: bool, scale_factor: f32) -> Self {
+        let font_style = node.get::<FontStyleState>().unwrap().clone();
+        let texts = node
+            .children()
+            .iter()
+            .filter_map(|child| match &*child.node_type() {
+                NodeType::Text(text) => Some((None, text.clone())),
+                NodeType::Element(ElementNode {
+                    tag: TagName::Text, ..
+                }) => {
+                    let text_node = child.children().into_iter().next()?;
+                    let NodeType::Text(text) = &*text_node.node_type() else {
+                        return None;
+                    };
+                    let text_font_style = child.get::<FontStyleState>().unwrap().clone();
+                    Some((Some(text_font_style), text.clone()))
+                }
+                _ => None,
+            })
+            .collect();
+
+        Self {
+            font_style,
+            texts,
+            layout_width,
+            inner_sized,
+            scale_factor,
+        }
+    }
+}
+
+/// Measurements of `label` and `paragraph` elements, reused as long as their texts, styles and constraints don't change.
+#[derive(Default)]
+pub struct ParagraphCache {
+    entries: FxHashMap<NodeId, (ParagraphCacheKey, Size2D, Arc<SendAnyMap>)>,
+    hits: usize,
+    misses: usize,
+}
+
+impl ParagraphCache {
+    /// Get the measurement of an element if it was made with the same key.
+    pub fn get(
+        &mut self,
+        node_id: NodeId,
+        key: &ParagraphCacheKey,
+    ) -> Option<(Size2D, Arc<SendAnyMap>)> {
+        match self.entries.get(&node_id) {
+            Some((cached_key, size, data)) if cached_key == key => {
+                self.hits += 1;
+                Some((*size, data.clone()))
+            }
+            _ => {
+                self.misses += 1;
+                None
+            }
+        }
+    }
+
+    pub fn insert(
+        &mut self,
+        node_id: NodeId,
+        key: ParagraphCacheKey,
+        size: Size2D,
+        data: Arc<SendAnyMap>,
+    ) {
+        self.entries.insert(node_id, (key, size, data));
+    }
+
+    pub fn remove(&mut self, node_id: &NodeId) {
+        self.entries.remove(node_id);
+    }
+
+    /// Remove every measurement, e.g. because the available fonts changed.
+    pub fn clear(&mut self) {
+        self.entries.clear();
+    }
+
+    /// How many elements have a cached measurement.
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+
+    /// How many measurements were reused.
+    pub fn hits(&self) -> usize {
+        self.hits
+    }
+
+    /// How many measurements had to be made again.
+    pub fn misses(&self) -> usize {
+        self.misses
+    }
+}
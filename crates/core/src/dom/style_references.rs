@@ -0,0 +1,25 @@
+use std::ops::{
+    Deref,
+    DerefMut,
+};
+
+use freya_native_core::NodeId;
+use rustc_hash::FxHashSet;
+
+/// Elements whose computed style is observed, see the `style_reference` attribute.
+#[derive(Clone, Default, Debug)]
+pub struct StyleReferences(FxHashSet<NodeId>);
+
+impl Deref for StyleReferences {
+    type Target = FxHashSet<NodeId>;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl DerefMut for StyleReferences {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.0
+    }
+}
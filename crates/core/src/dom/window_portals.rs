@@ -0,0 +1,25 @@
+use std::ops::{
+    Deref,
+    DerefMut,
+};
+
+use freya_native_core::NodeId;
+use rustc_hash::FxHashSet;
+
+/// Elements rendered in their own window, see the `window_portal` attribute.
+#[derive(Clone, Default, Debug)]
+pub struct WindowPortals(FxHashSet<NodeId>);
+
+impl Deref for WindowPortals {
+    type Target = FxHashSet<NodeId>;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl DerefMut for WindowPortals {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.0
+    }
+}
@@ -0,0 +1,134 @@
+use std::sync::{
+    Arc,
+    RwLock,
+};
+
+use freya_engine::prelude::*;
+use freya_native_core::{
+    attributes::register_custom_attribute,
+    tags::register_custom_tag,
+};
+use torin::prelude::{
+    LayoutNode,
+    Size2D,
+};
+
+use super::utils::ElementUtils;
+use crate::dom::{
+    DioxusNode,
+    ImagesCache,
+};
+
+/// Elements registered with [`register_custom_element`].
+static CUSTOM_ELEMENTS: RwLock<Vec<(&'static str, Arc<dyn CustomElement>)>> =
+    RwLock::new(Vec::new());
+
+/// Element implemented outside of Freya, registered with [`register_custom_element`].
+///
+/// Its attributes can be read from the [`CustomElementState`](crate::states::CustomElementState) of the node.
+pub trait CustomElement: Send + Sync + 'static {
+    /// Measure the size of the element given the available space.
+    ///
+    /// Returning `None` sizes it with its layout attributes (`width`, `height`, etc), just like a `rect`.
+    fn measure(
+        &self,
+        _node_ref: &DioxusNode,
+        _available_size: &Size2D,
+        _scale_factor: f32,
+    ) -> Option<Size2D> {
+        None
+    }
+
+    /// Draw the element in its area.
+    fn render(
+        &self,
+        layout_node: &LayoutNode,
+        node_ref: &DioxusNode,
+        canvas: &Canvas,
+        font_collection: &mut FontCollection,
+        scale_factor: f32,
+    );
+}
+
+/// Register a new element, so it can be used in the DOM like the built-in ones.
+///
+/// `attributes` are the names of its own attributes, besides the layout and style attributes shared by all the elements.
+/// These must be registered before the element is used, ideally before launching the app.
+///
+/// Tags containing a `-` (e.g `color-dot`) can be used directly in `rsx!`, along with any of their attributes.
+///
+/// ```rust,no_run
+/// # use freya_core::{dom::DioxusNode, elements::*, states::CustomElementState};
+/// # use freya_engine::prelude::*;
+/// # use freya_native_core::real_dom::NodeImmutable;
+/// # use torin::prelude::LayoutNode;
+/// struct Dot;
+///
+/// impl CustomElement for Dot {
+///     fn render(
+///         &self,
+///         layout_node: &LayoutNode,
+///         node_ref: &DioxusNode,
+///         canvas: &Canvas,
+///         _font_collection: &mut FontCollection,
+///         scale_factor: f32,
+///     ) {
+///         let state = node_ref.get::<CustomElementState>().unwrap();
+///         let radius = state
+///             .get("dot_radius")
+///             .and_then(|radius| radius.parse::<f32>().ok())
+///             .unwrap_or(4.0);
+///         let color = state.parse::<Color>("dot_color").unwrap_or(Color::BLACK);
+///
+///         let center = layout_node.area.center();
+///         let mut paint = Paint::default();
+///         paint.set_color(color);
+///         canvas.draw_circle((center.x, center.y), radius * scale_factor, &paint);
+///     }
+/// }
+///
+/// register_custom_element("color-dot", &["dot_radius", "dot_color"], Dot);
+/// ```
+pub fn register_custom_element(
+    tag: &'static str,
+    attributes: &[&'static str],
+    element: impl CustomElement,
+) {
+    register_custom_tag(tag);
+    for attribute in attributes {
+        register_custom_attribute(attribute);
+    }
+
+    let mut custom_elements = CUSTOM_ELEMENTS.write().unwrap();
+    custom_elements.retain(|(registered_tag, _)| *registered_tag != tag);
+    custom_elements.push((tag, Arc::new(element)));
+}
+
+/// Get a registered [`CustomElement`] given its tag.
+pub fn custom_element(tag: &str) -> Option<Arc<dyn CustomElement>> {
+    CUSTOM_ELEMENTS
+        .read()
+        .unwrap()
+        .iter()
+        .find(|(registered_tag, _)| *registered_tag == tag)
+        .map(|(_, element)| element.clone())
+}
+
+pub struct CustomElementWrapper(pub Arc<dyn CustomElement>);
+
+impl ElementUtils for CustomElementWrapper {
+    fn render(
+        self,
+        layout_node: &LayoutNode,
+        node_ref: &DioxusNode,
+        canvas: &Canvas,
+        font_collection: &mut FontCollection,
+        _font_manager: &FontMgr,
+        _default_fonts: &[String],
+        _images_cache: &mut ImagesCache,
+        scale_factor: f32,
+    ) {
+        self.0
+            .render(layout_node, node_ref, canvas, font_collection, scale_factor);
+    }
+}
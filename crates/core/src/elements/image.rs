@@ -9,7 +9,9 @@ use crate::{
     },
     render::{
         get_or_create_image,
+        AtlasKey,
         ImageData,
+        TextureAtlas,
     },
     states::ImageState,
     values::{
@@ -74,13 +76,46 @@ impl ElementUtils for ImageElement {
             SamplingMode::CatmullRom => SamplingOptions::from(CubicResampler::catmull_rom()),
         };
 
-        canvas.draw_image_rect_with_sampling_options(
-            image,
-            None,
-            rect,
-            sampling,
-            &Paint::default(),
-        );
+        let (width, height) = (rect.width().round(), rect.height().round());
+
+        let atlas = images_cache
+            .drawing_atlas()
+            .filter(|_| TextureAtlas::fits(width, height));
+
+        // Small cached images are resampled once and reused from the atlas
+        if let (Some(cache_key), Some(atlas)) = (image_state.image_cache_key.as_ref(), atlas) {
+            let key = AtlasKey::new((
+                &cache_key.0,
+                width as i32,
+                height as i32,
+                image_state.image_sampling.clone() as u8,
+            ));
+            let dst = Rect::new(rect.left, rect.top, rect.left + width, rect.top + height);
+
+            if !atlas.draw(&key, canvas, dst, &paint) {
+                if let Some(mut surface) = raster_n32_premul((width as i32, height as i32)) {
+                    surface.canvas().clear(Color::TRANSPARENT);
+                    surface.canvas().draw_image_rect_with_sampling_options(
+                        image,
+                        None,
+                        Rect::new(0., 0., width, height),
+                        sampling,
+                        &Paint::default(),
+                    );
+                    let image = surface.image_snapshot();
+                    canvas.draw_image_rect(&image, None, dst, &paint);
+                    atlas.insert_from(cache_key.atlas_source(), key, image);
+                }
+            }
+        } else {
+            canvas.draw_image_rect_with_sampling_options(
+                image,
+                None,
+                rect,
+                sampling,
+                &Paint::default(),
+            );
+        }
 
         canvas.restore();
     }
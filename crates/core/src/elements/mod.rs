@@ -1,13 +1,17 @@
+mod custom;
 mod image;
 mod label;
 mod paragraph;
 mod rect;
 mod svg;
+mod text_grid;
 mod utils;
 
+pub use custom::*;
 pub use image::*;
 pub use label::*;
 pub use paragraph::*;
 pub use rect::*;
 pub use svg::*;
+pub use text_grid::*;
 pub use utils::*;
@@ -32,6 +32,7 @@ use crate::{
         align_main_align_paragraph,
         create_paragraph,
         draw_cursor,
+        paragraph_text,
         run_cursor_highlights,
         ParagraphData,
     },
@@ -42,7 +43,8 @@ use crate::{
     },
 };
 
-pub struct CachedParagraph(pub Paragraph);
+/// Paragraph created while measuring the layout, along with the font scale factor used to create it.
+pub struct CachedParagraph(pub Paragraph, pub f32);
 
 /// # Safety
 /// Skia `Paragraph` are neither Sync or Send, but in order to store them in the Associated
@@ -78,6 +80,13 @@ impl ParagraphElement {
 
         let y = align_main_align_paragraph(node, &layout_node.area, paragraph);
 
+        let text_wrap = node.get::<FontStyleState>().unwrap().text_wrap;
+        let text = if text_wrap.inserts_breaks() {
+            paragraph_text(node)
+        } else {
+            String::new()
+        };
+
         if let Some(cursor_reference) = &cursor_state.cursor_ref {
             if let Some(cursor_position) = text_measurement.cursor_position {
                 let position = CursorPoint::new(cursor_position.x, cursor_position.y - y as f64);
@@ -91,7 +100,8 @@ impl ParagraphElement {
                 cursor_reference
                     .cursor_sender
                     .send(CursorLayoutResponse::CursorPosition {
-                        position: char_position.position as usize,
+                        position: text_wrap
+                            .from_paragraph_offset(&text, char_position.position as usize),
                         id: text_measurement.cursor_id,
                     })
                     .ok();
@@ -113,8 +123,8 @@ impl ParagraphElement {
                 cursor_reference
                     .cursor_sender
                     .send(CursorLayoutResponse::TextSelection {
-                        from: origin_char.position as usize,
-                        to: dist_char.position as usize,
+                        from: text_wrap.from_paragraph_offset(&text, origin_char.position as usize),
+                        to: text_wrap.from_paragraph_offset(&text, dist_char.position as usize),
                         id: text_measurement.cursor_id,
                     })
                     .ok();
@@ -133,7 +143,7 @@ impl ElementUtils for ParagraphElement {
         _font_manager: &FontMgr,
         default_fonts: &[String],
         _images_cache: &mut ImagesCache,
-        scale_factor: f32,
+        _scale_factor: f32,
     ) {
         let area = layout_node.visible_area();
         let node_cursor_state = &*node_ref.get::<CursorState>().unwrap();
@@ -158,6 +168,13 @@ impl ElementUtils for ParagraphElement {
             paragraph.paint(canvas, (x, y));
         };
 
+        let cached_paragraph = layout_node
+            .data
+            .as_ref()
+            .unwrap()
+            .get::<CachedParagraph>()
+            .unwrap();
+
         if node_cursor_state.position.is_some() {
             let ParagraphData { paragraph, .. } = create_paragraph(
                 node_ref,
@@ -165,18 +182,11 @@ impl ElementUtils for ParagraphElement {
                 font_collection,
                 true,
                 default_fonts,
-                scale_factor,
+                cached_paragraph.1,
             );
             paint(&paragraph);
         } else {
-            let paragraph = &layout_node
-                .data
-                .as_ref()
-                .unwrap()
-                .get::<CachedParagraph>()
-                .unwrap()
-                .0;
-            paint(paragraph);
+            paint(&cached_paragraph.0);
         };
     }
 
@@ -8,10 +8,15 @@ use crate::{
         DioxusNode,
         ImagesCache,
     },
+    render::{
+        AtlasKey,
+        TextureAtlas,
+    },
     states::{
         FontStyleState,
         SvgState,
     },
+    values::SvgPaint,
 };
 
 pub struct SvgElement;
@@ -25,35 +30,112 @@ impl ElementUtils for SvgElement {
         _font_collection: &mut FontCollection,
         font_manager: &FontMgr,
         _default_fonts: &[String],
-        _images_cache: &mut ImagesCache,
+        images_cache: &mut ImagesCache,
         _scale_factor: f32,
     ) {
         let area = layout_node.visible_area();
         let svg_state = &*node_ref.get::<SvgState>().unwrap();
         let font_style = &*node_ref.get::<FontStyleState>().unwrap();
 
+        let Some(svg_data) = &svg_state.svg_data else {
+            return;
+        };
+
         let x = area.min_x();
         let y = area.min_y();
-        if let Some(svg_data) = &svg_state.svg_data {
-            let resource_provider = LocalResourceProvider::new(font_manager);
-            let svg_dom = svg::Dom::from_bytes(svg_data.as_slice(), resource_provider);
-            if let Ok(mut svg_dom) = svg_dom {
-                canvas.save();
-                canvas.translate((x, y));
-                svg_dom.set_container_size((area.width() as i32, area.height() as i32));
-                let mut root = svg_dom.root();
-                root.set_width(svg::Length::new(100.0, svg::LengthUnit::Percentage));
-                root.set_height(svg::Length::new(100.0, svg::LengthUnit::Percentage));
-                root.set_color(font_style.color);
-                if let Some(paint) = svg_state.svg_fill.as_ref() {
-                    root.set_fill((*paint).into());
-                }
-                if let Some(paint) = svg_state.svg_stroke.as_ref() {
-                    root.set_stroke((*paint).into());
-                }
-                svg_dom.render(canvas);
-                canvas.restore();
-            }
+        let (width, height) = (area.width().round(), area.height().round());
+
+        let atlas = images_cache
+            .drawing_atlas()
+            .filter(|_| TextureAtlas::fits(width, height));
+
+        // Big SVGs are drawn directly, and so are all of them when the atlas is bypassed
+        let Some(atlas) = atlas else {
+            canvas.save();
+            canvas.translate((x, y));
+            render_svg(
+                svg_data.as_slice(),
+                canvas,
+                font_manager,
+                (area.width() as i32, area.height() as i32),
+                font_style,
+                svg_state,
+            );
+            canvas.restore();
+            return;
+        };
+
+        // Small SVGs are rasterized once and reused from the atlas
+        let key = AtlasKey::new((
+            svg_data.as_slice(),
+            width as i32,
+            height as i32,
+            color_key(font_style.color),
+            svg_state.svg_fill.map(paint_key),
+            svg_state.svg_stroke.map(paint_key),
+        ));
+        let dst = Rect::new(x, y, x + width, y + height);
+        let paint = Paint::default();
+
+        if atlas.draw(&key, canvas, dst, &paint) {
+            return;
+        }
+
+        let Some(mut surface) = raster_n32_premul((width as i32, height as i32)) else {
+            return;
+        };
+        surface.canvas().clear(Color::TRANSPARENT);
+        if render_svg(
+            svg_data.as_slice(),
+            surface.canvas(),
+            font_manager,
+            (width as i32, height as i32),
+            font_style,
+            svg_state,
+        ) {
+            let image = surface.image_snapshot();
+            canvas.draw_image_rect(&image, None, dst, &paint);
+            atlas.insert(key, image);
         }
     }
 }
+
+/// Render an SVG at the origin of the canvas, returns `false` if it couldn't be parsed.
+fn render_svg(
+    svg_data: &[u8],
+    canvas: &Canvas,
+    font_manager: &FontMgr,
+    size: (i32, i32),
+    font_style: &FontStyleState,
+    svg_state: &SvgState,
+) -> bool {
+    let resource_provider = LocalResourceProvider::new(font_manager);
+    let Ok(mut svg_dom) = svg::Dom::from_bytes(svg_data, resource_provider) else {
+        return false;
+    };
+    svg_dom.set_container_size(size);
+    let mut root = svg_dom.root();
+    root.set_width(svg::Length::new(100.0, svg::LengthUnit::Percentage));
+    root.set_height(svg::Length::new(100.0, svg::LengthUnit::Percentage));
+    root.set_color(font_style.color);
+    if let Some(paint) = svg_state.svg_fill.as_ref() {
+        root.set_fill((*paint).into());
+    }
+    if let Some(paint) = svg_state.svg_stroke.as_ref() {
+        root.set_stroke((*paint).into());
+    }
+    svg_dom.render(canvas);
+    true
+}
+
+fn color_key(color: Color) -> [u8; 4] {
+    [color.a(), color.r(), color.g(), color.b()]
+}
+
+fn paint_key(paint: SvgPaint) -> (u8, [u8; 4]) {
+    match paint {
+        SvgPaint::None => (0, [0; 4]),
+        SvgPaint::CurrentColor => (1, [0; 4]),
+        SvgPaint::Color(color) => (2, color_key(color)),
+    }
+}
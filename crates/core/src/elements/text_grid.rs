@@ -0,0 +1,221 @@
+use freya_engine::prelude::*;
+use freya_native_core::real_dom::NodeImmutable;
+use torin::prelude::{
+    LayoutNode,
+    Size2D,
+};
+
+use super::utils::ElementUtils;
+use crate::{
+    custom_attributes::TextGridStyle,
+    dom::{
+        DioxusNode,
+        ImagesCache,
+    },
+    states::{
+        FontStyleState,
+        TextGridState,
+    },
+};
+
+/// Fonts of every style of a `textgrid`, along with the size of its cells.
+pub(crate) struct TextGridFonts {
+    regular: Font,
+    bold: Font,
+    italic: Font,
+    bold_italic: Font,
+    pub cell_size: Size2D,
+    /// Distance from the top of a cell to the baseline of its character.
+    pub baseline: f32,
+}
+
+impl TextGridFonts {
+    pub fn new(
+        font_collection: &FontCollection,
+        font_style: &FontStyleState,
+        default_fonts: &[String],
+        scale_factor: f32,
+    ) -> Option<Self> {
+        let mut font_collection = font_collection.clone();
+        let mut families = font_style.font_family.to_vec();
+        families.extend_from_slice(default_fonts);
+        let font_size = font_style.font_size * scale_factor;
+
+        let mut font = |bold: bool, italic: bool| {
+            let weight = if bold {
+                Weight::BOLD
+            } else {
+                font_style.font_weight
+            };
+            let slant = if italic {
+                Slant::Italic
+            } else {
+                font_style.font_slant
+            };
+            let typeface = font_collection
+                .find_typefaces(
+                    &families,
+                    FontStyle::new(weight, font_style.font_width, slant),
+                )
+                .into_iter()
+                .next()?;
+            Some(Font::from_typeface(typeface, font_size))
+        };
+
+        let regular = font(false, false)?;
+        let bold = font(true, false).unwrap_or_else(|| regular.clone());
+        let italic = font(false, true).unwrap_or_else(|| regular.clone());
+        let bold_italic = font(true, true).unwrap_or_else(|| bold.clone());
+
+        // All the cells are as wide as the characters of a monospaced font
+        let (cell_width, _) = regular.measure_str("M", None);
+        let (_, metrics) = regular.metrics();
+        let text_height = metrics.descent - metrics.ascent;
+        let cell_height = font_style
+            .line_height
+            .map(|line_height| line_height * font_size)
+            .unwrap_or(text_height + metrics.leading);
+
+        Some(Self {
+            regular,
+            bold,
+            italic,
+            bold_italic,
+            cell_size: Size2D::new(cell_width, cell_height),
+            baseline: (cell_height - text_height) / 2. - metrics.ascent,
+        })
+    }
+
+    fn font(&self, style: &TextGridStyle) -> &Font {
+        match (style.bold, style.italic) {
+            (false, false) => &self.regular,
+            (true, false) => &self.bold,
+            (false, true) => &self.italic,
+            (true, true) => &self.bold_italic,
+        }
+    }
+}
+
+pub struct TextGridElement;
+
+impl ElementUtils for TextGridElement {
+    fn clip(
+        &self,
+        layout_node: &LayoutNode,
+        _node_ref: &DioxusNode,
+        canvas: &Canvas,
+        _scale_factor: f32,
+    ) {
+        canvas.clip_rect(
+            Rect::new(
+                layout_node.area.min_x(),
+                layout_node.area.min_y(),
+                layout_node.area.max_x(),
+                layout_node.area.max_y(),
+            ),
+            ClipOp::Intersect,
+            true,
+        );
+    }
+
+    fn render(
+        self,
+        layout_node: &LayoutNode,
+        node_ref: &DioxusNode,
+        canvas: &Canvas,
+        font_collection: &mut FontCollection,
+        _font_manager: &FontMgr,
+        default_fonts: &[String],
+        _images_cache: &mut ImagesCache,
+        scale_factor: f32,
+    ) {
+        let area = layout_node.visible_area();
+        let text_grid_state = &*node_ref.get::<TextGridState>().unwrap();
+        let font_style = &*node_ref.get::<FontStyleState>().unwrap();
+
+        let Some(grid) = &text_grid_state.grid else {
+            return;
+        };
+        let Some(fonts) =
+            TextGridFonts::new(font_collection, font_style, default_fonts, scale_factor)
+        else {
+            return;
+        };
+        let Size2D {
+            width: cell_width,
+            height: cell_height,
+            ..
+        } = fonts.cell_size;
+
+        let mut paint = Paint::default();
+        paint.set_anti_alias(true);
+        let mut runs = Vec::new();
+        let mut text = String::new();
+        let mut positions = Vec::new();
+
+        for row in 0..grid.rows() {
+            let y = area.min_y() + row as f32 * cell_height;
+            if y > area.max_y() {
+                break;
+            }
+
+            // Consecutive cells with the same style are drawn together
+            runs.clear();
+            let cells = grid.row(row);
+            let mut start = 0;
+            while start < cells.len() {
+                let style = cells[start].style;
+                let end = cells[start..]
+                    .iter()
+                    .position(|cell| cell.style != style)
+                    .map_or(cells.len(), |len| start + len);
+                runs.push((start, end, style));
+                start = end;
+            }
+
+            // Backgrounds first so they don't cover the characters that overflow their cells
+            for (start, end, style) in &runs {
+                if let Some(background) = style.background {
+                    paint.set_color(background);
+                    canvas.draw_rect(
+                        Rect::from_xywh(
+                            area.min_x() + *start as f32 * cell_width,
+                            y,
+                            (end - start) as f32 * cell_width,
+                            cell_height,
+                        ),
+                        &paint,
+                    );
+                }
+            }
+
+            for (start, end, style) in &runs {
+                let x = area.min_x() + *start as f32 * cell_width;
+                let width = (end - start) as f32 * cell_width;
+                paint.set_color(style.color.unwrap_or(font_style.color));
+
+                text.clear();
+                text.extend(cells[*start..*end].iter().map(|cell| cell.character));
+                if !text.trim().is_empty() {
+                    positions.clear();
+                    positions.extend((0..end - start).map(|i| i as f32 * cell_width));
+                    if let Some(blob) =
+                        TextBlob::from_pos_text_h(text.as_str(), &positions, 0., fonts.font(style))
+                    {
+                        canvas.draw_text_blob(blob, (x, y + fonts.baseline), &paint);
+                    }
+                }
+
+                let thickness = scale_factor.max(1.);
+                if style.underline {
+                    let line_y = y + fonts.baseline + thickness;
+                    canvas.draw_rect(Rect::from_xywh(x, line_y, width, thickness), &paint);
+                }
+                if style.strikethrough {
+                    let line_y = y + cell_height / 2.;
+                    canvas.draw_rect(Rect::from_xywh(x, line_y, width, thickness), &paint);
+                }
+            }
+        }
+    }
+}
@@ -175,6 +175,9 @@ impl ElementUtilsResolver for TagName {
             TagName::Paragraph => Some(ElementWithUtils::Paragraph(ParagraphElement)),
             TagName::Image => Some(ElementWithUtils::Image(ImageElement)),
             TagName::Label => Some(ElementWithUtils::Label(LabelElement)),
+            TagName::TextGrid => Some(ElementWithUtils::TextGrid(TextGridElement)),
+            TagName::Custom(tag) => custom_element(tag)
+                .map(|element| ElementWithUtils::Custom(CustomElementWrapper(element))),
             _ => None,
         }
     }
@@ -186,6 +189,8 @@ pub enum ElementWithUtils {
     Paragraph(ParagraphElement),
     Image(ImageElement),
     Label(LabelElement),
+    TextGrid(TextGridElement),
+    Custom(CustomElementWrapper),
 }
 
 impl ElementUtils for ElementWithUtils {
@@ -202,6 +207,8 @@ impl ElementUtils for ElementWithUtils {
             Self::Paragraph(el) => el.clip(layout_node, node_ref, canvas, scale_factor),
             Self::Image(el) => el.clip(layout_node, node_ref, canvas, scale_factor),
             Self::Label(el) => el.clip(layout_node, node_ref, canvas, scale_factor),
+            Self::TextGrid(el) => el.clip(layout_node, node_ref, canvas, scale_factor),
+            Self::Custom(el) => el.clip(layout_node, node_ref, canvas, scale_factor),
         }
     }
 
@@ -220,6 +227,10 @@ impl ElementUtils for ElementWithUtils {
             }
             Self::Image(el) => el.is_point_inside_area(point, node_ref, layout_node, scale_factor),
             Self::Label(el) => el.is_point_inside_area(point, node_ref, layout_node, scale_factor),
+            Self::TextGrid(el) => {
+                el.is_point_inside_area(point, node_ref, layout_node, scale_factor)
+            }
+            Self::Custom(el) => el.is_point_inside_area(point, node_ref, layout_node, scale_factor),
         }
     }
 
@@ -285,6 +296,26 @@ impl ElementUtils for ElementWithUtils {
                 images_cache,
                 scale_factor,
             ),
+            Self::TextGrid(el) => el.render(
+                layout_node,
+                node_ref,
+                canvas,
+                font_collection,
+                font_manager,
+                default_fonts,
+                images_cache,
+                scale_factor,
+            ),
+            Self::Custom(el) => el.render(
+                layout_node,
+                node_ref,
+                canvas,
+                font_collection,
+                font_manager,
+                default_fonts,
+                images_cache,
+                scale_factor,
+            ),
         }
     }
 
@@ -338,6 +369,22 @@ impl ElementUtils for ElementWithUtils {
                 node_style,
                 transform_state,
             ),
+            Self::TextGrid(el) => el.drawing_area(
+                layout_node,
+                node_ref,
+                layout,
+                scale_factor,
+                node_style,
+                transform_state,
+            ),
+            Self::Custom(el) => el.drawing_area(
+                layout_node,
+                node_ref,
+                layout,
+                scale_factor,
+                node_style,
+                transform_state,
+            ),
         }
     }
 
@@ -353,6 +400,8 @@ impl ElementUtils for ElementWithUtils {
             Self::Paragraph(el) => el.needs_cached_area(node_ref, transform_state, style_state),
             Self::Image(el) => el.needs_cached_area(node_ref, transform_state, style_state),
             Self::Label(el) => el.needs_cached_area(node_ref, transform_state, style_state),
+            Self::TextGrid(el) => el.needs_cached_area(node_ref, transform_state, style_state),
+            Self::Custom(el) => el.needs_cached_area(node_ref, transform_state, style_state),
         }
     }
 }
@@ -1,15 +1,28 @@
+use dioxus_core::VirtualDom;
+use freya_engine::prelude::Color;
 use torin::prelude::{
     Area,
     CursorPoint,
 };
-use winit::window::{
-    CursorIcon,
-    Window,
+use winit::{
+    dpi::{
+        LogicalPosition,
+        LogicalSize,
+    },
+    window::{
+        CursorIcon,
+        Fullscreen,
+        Window,
+        WindowId,
+        WindowLevel,
+    },
 };
 
 use crate::{
     accessibility::AccessibilityFocusStrategy,
     events::PlatformEvent,
+    parsing::Parse,
+    platform_state::LayoutDirection,
 };
 
 pub struct TextGroupMeasurement {
@@ -19,18 +32,155 @@ pub struct TextGroupMeasurement {
     pub cursor_selection: Option<(CursorPoint, CursorPoint)>,
 }
 
+/// How a window sizes itself to fit the size of its content.
+///
+/// The content is sized by the elements at the top of the app, so these should have an `auto`
+/// or fixed size rather than a `fill` or percentage one.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum SizeToContent {
+    /// Keep the given size. Default.
+    #[default]
+    Never,
+    /// Fit the content once, when the window is opened.
+    OnLaunch,
+    /// Fit the content every time its size changes.
+    Always,
+}
+
+/// Settings of a window opened from the app.
+#[derive(Clone, Debug, PartialEq)]
+pub struct WindowSettings {
+    pub title: String,
+    pub size: (f64, f64),
+    pub decorations: bool,
+    pub transparent: bool,
+    pub background: Color,
+    pub size_to_content: SizeToContent,
+}
+
+impl Default for WindowSettings {
+    fn default() -> Self {
+        Self {
+            title: "Freya App".to_string(),
+            size: (700.0, 500.0),
+            decorations: true,
+            transparent: false,
+            background: Color::WHITE,
+            size_to_content: SizeToContent::Never,
+        }
+    }
+}
+
+impl WindowSettings {
+    /// Specify the Window title.
+    pub fn with_title(mut self, title: impl Into<String>) -> Self {
+        self.title = title.into();
+        self
+    }
+
+    /// Specify a Window size.
+    pub fn with_size(mut self, width: f64, height: f64) -> Self {
+        self.size = (width, height);
+        self
+    }
+
+    /// Whether the Window will have decorations or not.
+    pub fn with_decorations(mut self, decorations: bool) -> Self {
+        self.decorations = decorations;
+        self
+    }
+
+    /// Make the Window transparent or not.
+    pub fn with_transparency(mut self, transparency: bool) -> Self {
+        self.transparent = transparency;
+        self
+    }
+
+    /// Specify the Window background color.
+    pub fn with_background(mut self, background: &str) -> Self {
+        self.background = Color::parse(background).unwrap_or(Color::WHITE);
+        self
+    }
+
+    /// Size the Window to fit its content, see [`SizeToContent`].
+    pub fn with_size_to_content(mut self, size_to_content: SizeToContent) -> Self {
+        self.size_to_content = size_to_content;
+        self
+    }
+}
+
+/// Window opened from the app, with its own VirtualDOM.
+pub struct NewWindow {
+    /// Create the VirtualDOM of the window, called from the main thread.
+    pub vdom: Box<dyn FnOnce() -> VirtualDom + Send>,
+    pub settings: WindowSettings,
+}
+
+/// Change applied to the window at runtime.
+#[derive(Clone, Debug, PartialEq)]
+pub enum WindowAction {
+    /// Change the title.
+    SetTitle(String),
+    /// Resize the inner size, in logical pixels.
+    SetSize(f64, f64),
+    /// Move the window, in logical pixels relative to the screen.
+    SetPosition(f64, f64),
+    /// Minimize or restore the window.
+    SetMinimized(bool),
+    /// Maximize or restore the window.
+    SetMaximized(bool),
+    /// Enter or leave borderless fullscreen.
+    SetFullscreen(bool),
+    /// Show or hide the window, e.g. to minimize it to the tray.
+    SetVisible(bool),
+    /// Keep the window above the others or not.
+    SetAlwaysOnTop(bool),
+}
+
+impl WindowAction {
+    /// Apply the change to the given window.
+    pub fn apply(self, window: &Window) {
+        match self {
+            Self::SetTitle(title) => window.set_title(&title),
+            Self::SetSize(width, height) => {
+                let _ = window.request_inner_size(LogicalSize::new(width, height));
+            }
+            Self::SetPosition(x, y) => window.set_outer_position(LogicalPosition::new(x, y)),
+            Self::SetMinimized(minimized) => window.set_minimized(minimized),
+            Self::SetMaximized(maximized) => window.set_maximized(maximized),
+            Self::SetFullscreen(fullscreen) => {
+                window.set_fullscreen(fullscreen.then_some(Fullscreen::Borderless(None)))
+            }
+            Self::SetVisible(visible) => window.set_visible(visible),
+            Self::SetAlwaysOnTop(always_on_top) => window.set_window_level(if always_on_top {
+                WindowLevel::AlwaysOnTop
+            } else {
+                WindowLevel::Normal
+            }),
+        }
+    }
+}
+
 /// Custom EventLoop messages
 pub enum EventLoopMessage {
     /// Poll the VirtualDOM
     PollVDOM,
-    /// Request a rerender
+    /// Request a rerender, only rendered if something changed
     RequestRerender,
+    /// Request a new frame that is rendered even if nothing changed, for apps that animate continuously
+    RequestRedraw,
     /// Request a full rerender
     RequestFullRerender,
     /// Invalidate a certain drawing area
     InvalidateArea(Area),
     /// Remeasure a text elements group
     RemeasureTextGroup(TextGroupMeasurement),
+    /// Register a font at runtime given its name and data
+    RegisterFont(String, Vec<u8>),
+    /// Change the scale factor applied to the font size of all the text
+    SetTextScaleFactor(f32),
+    /// Change the horizontal direction of the layout
+    SetLayoutDirection(LayoutDirection),
     /// Change the cursor icon
     SetCursorIcon(CursorIcon),
     /// Accessibility Window Event
@@ -41,12 +191,33 @@ pub enum EventLoopMessage {
     ExitApp,
     /// Callback to access the Window.
     WithWindow(Box<dyn FnOnce(&Window) + Send + Sync>),
+    /// Change the Window at runtime.
+    WindowAction(WindowAction),
     /// Raw platform event, this are low level events.
     PlatformEvent(PlatformEvent),
+    /// Open a new window
+    OpenWindow(NewWindow),
+    /// Close a window opened from the app, closing the main window exits the app
+    CloseWindow(WindowId),
+    /// Message for a certain window, the rest of messages go to the main window
+    ForWindow(WindowId, Box<EventLoopMessage>),
+    /// Access the system clipboard
+    Clipboard(crate::platform::ClipboardRequest),
+    /// Power event of the system, sent to all the windows
+    Power(crate::platform_state::PowerEvent),
+    /// Event of the native menus, sent to all the windows
+    #[cfg(feature = "menu")]
+    Menu(crate::platform::muda::MenuEvent),
+    /// Event of the tray icon, sent to all the windows
+    #[cfg(feature = "tray")]
+    Tray(crate::platform::TrayEvent),
 }
 
 impl From<accesskit_winit::Event> for EventLoopMessage {
     fn from(value: accesskit_winit::Event) -> Self {
-        Self::Accessibility(value.window_event)
+        Self::ForWindow(
+            value.window_id,
+            Box::new(Self::Accessibility(value.window_event)),
+        )
     }
 }
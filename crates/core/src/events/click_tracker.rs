@@ -0,0 +1,134 @@
+use std::time::{
+    Duration,
+    Instant,
+};
+
+use freya_elements::events::MouseButton;
+use freya_native_core::events::EventName;
+use torin::prelude::CursorPoint;
+
+use super::{
+    PlatformEvent,
+    PlatformEventData,
+};
+
+/// Max time between two clicks of a double-click.
+pub const DOUBLE_CLICK_TIMEOUT: Duration = Duration::from_millis(500);
+
+/// Max distance the cursor can move between two clicks of a double-click.
+pub const DOUBLE_CLICK_DISTANCE: f64 = 4.0;
+
+/// Detect double-clicks from the left clicks of the mouse, so they can be emitted as `doubleclick` events.
+#[derive(Default)]
+pub struct ClickTracker {
+    last_click: Option<(Instant, CursorPoint)>,
+}
+
+impl ClickTracker {
+    /// Track a platform event, returning the `doubleclick` event to also emit if it completes a double-click.
+    pub fn track(&mut self, event: &PlatformEvent, now: Instant) -> Option<PlatformEvent> {
+        let PlatformEventData::Mouse {
+            cursor,
+            button: Some(MouseButton::Left),
+        } = event.data
+        else {
+            return None;
+        };
+
+        if event.name != EventName::MouseUp {
+            return None;
+        }
+
+        let is_double_click = self.last_click.is_some_and(|(time, position)| {
+            now.duration_since(time) <= DOUBLE_CLICK_TIMEOUT
+                && position.distance_to(cursor) <= DOUBLE_CLICK_DISTANCE
+        });
+
+        if is_double_click {
+            // The next click starts a new double-click
+            self.last_click = None;
+            Some(PlatformEvent {
+                name: EventName::DoubleClick,
+                data: event.data.clone(),
+            })
+        } else {
+            self.last_click = Some((now, cursor));
+            None
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use std::time::{
+        Duration,
+        Instant,
+    };
+
+    use freya_elements::events::MouseButton;
+    use freya_native_core::events::EventName;
+
+    use super::ClickTracker;
+    use crate::events::{
+        PlatformEvent,
+        PlatformEventData,
+    };
+
+    fn mouse(name: EventName, cursor: (f64, f64), button: MouseButton) -> PlatformEvent {
+        PlatformEvent {
+            name,
+            data: PlatformEventData::Mouse {
+                cursor: cursor.into(),
+                button: Some(button),
+            },
+        }
+    }
+
+    #[test]
+    fn double_click() {
+        let mut tracker = ClickTracker::default();
+        let now = Instant::now();
+        let click = mouse(EventName::MouseUp, (10., 10.), MouseButton::Left);
+
+        assert!(tracker.track(&click, now).is_none());
+        let double_click = tracker.track(&click, now + Duration::from_millis(200));
+        assert_eq!(double_click.unwrap().name, EventName::DoubleClick);
+
+        // A third click starts over
+        assert!(tracker
+            .track(&click, now + Duration::from_millis(300))
+            .is_none());
+    }
+
+    #[test]
+    fn double_click_thresholds() {
+        let mut tracker = ClickTracker::default();
+        let now = Instant::now();
+
+        // Too slow
+        let click = mouse(EventName::MouseUp, (10., 10.), MouseButton::Left);
+        assert!(tracker.track(&click, now).is_none());
+        assert!(tracker
+            .track(&click, now + Duration::from_millis(600))
+            .is_none());
+
+        // Too far
+        let far_click = mouse(EventName::MouseUp, (30., 10.), MouseButton::Left);
+        assert!(tracker
+            .track(&far_click, now + Duration::from_millis(700))
+            .is_none());
+
+        // Other buttons and events are ignored
+        let right_click = mouse(EventName::RightClick, (30., 10.), MouseButton::Right);
+        assert!(tracker
+            .track(&right_click, now + Duration::from_millis(800))
+            .is_none());
+        let mouse_down = mouse(EventName::MouseDown, (30., 10.), MouseButton::Left);
+        assert!(tracker
+            .track(&mouse_down, now + Duration::from_millis(800))
+            .is_none());
+        assert!(tracker
+            .track(&far_click, now + Duration::from_millis(900))
+            .is_some());
+    }
+}
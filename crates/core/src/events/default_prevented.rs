@@ -0,0 +1,53 @@
+use super::{
+    DomEvent,
+    EventName,
+};
+
+/// Keep track of the events whose default action was prevented with `prevent_default`
+/// while emitting a batch of [`DomEvent`]s, so their global counterparts
+/// (e.g. `keydown` -> `globalkeydown`) are emitted already prevented.
+#[derive(Default)]
+pub struct DefaultPrevented(Vec<EventName>);
+
+impl DefaultPrevented {
+    /// Order the events so the element events are emitted before the global events.
+    pub fn sort(events: Vec<DomEvent>) -> impl Iterator<Item = DomEvent> {
+        let (global, element): (Vec<_>, Vec<_>) =
+            events.into_iter().partition(|event| event.name.is_global());
+        element.into_iter().chain(global)
+    }
+
+    /// Mark the default action of the given event as prevented.
+    pub fn insert(&mut self, name: EventName) {
+        if !self.0.contains(&name) {
+            self.0.push(name);
+        }
+    }
+
+    /// Check whether the given global event comes from an event that was prevented.
+    pub fn is_prevented(&self, global_name: EventName) -> bool {
+        self.0.iter().any(|name| {
+            name.get_global_event() == Some(global_name)
+                || (*name == EventName::Click && global_name == EventName::GlobalClick)
+        })
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::DefaultPrevented;
+    use crate::events::EventName;
+
+    #[test]
+    fn default_prevented() {
+        let mut prevented = DefaultPrevented::default();
+        assert!(!prevented.is_prevented(EventName::GlobalKeyDown));
+
+        prevented.insert(EventName::KeyDown);
+        prevented.insert(EventName::Click);
+        assert!(prevented.is_prevented(EventName::GlobalKeyDown));
+        assert!(prevented.is_prevented(EventName::GlobalClick));
+        assert!(!prevented.is_prevented(EventName::GlobalKeyUp));
+        assert!(!prevented.is_prevented(EventName::GlobalMouseMove));
+    }
+}
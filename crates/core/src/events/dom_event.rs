@@ -7,7 +7,9 @@ use freya_elements::events::{
     pointer::PointerType,
     ErasedEventData,
     FileData,
+    FocusData,
     KeyboardData,
+    MountedData,
     MouseData,
     PointerData,
     TouchData,
@@ -89,21 +91,43 @@ impl DomEvent {
                     bubbles,
                 }
             }
-            PlatformEventData::Wheel { scroll, .. } => Self {
+            PlatformEventData::Wheel {
+                scroll,
+                raw_scroll,
+                delta_mode,
+                phase,
+                momentum,
+                ..
+            } => Self {
                 node_id,
                 name,
-                data: DomEventData::Wheel(WheelData::new(scroll.x, scroll.y)),
+                data: DomEventData::Wheel(WheelData::new(
+                    scroll.x,
+                    scroll.y,
+                    raw_scroll.x,
+                    raw_scroll.y,
+                    delta_mode,
+                    phase,
+                    momentum,
+                )),
                 bubbles,
             },
             PlatformEventData::Keyboard {
                 ref key,
                 code,
                 modifiers,
-                ..
+                repeat,
+                location,
             } => Self {
                 node_id,
                 name,
-                data: DomEventData::Keyboard(KeyboardData::new(key.clone(), code, modifiers)),
+                data: DomEventData::Keyboard(KeyboardData::new(
+                    key.clone(),
+                    code,
+                    modifiers,
+                    repeat,
+                    location,
+                )),
                 bubbles,
             },
             PlatformEventData::Touch {
@@ -166,6 +190,8 @@ pub enum DomEventData {
     Touch(TouchData),
     Pointer(PointerData),
     File(FileData),
+    Mounted(MountedData),
+    Focus(FocusData),
 }
 
 impl DomEventData {
@@ -177,6 +203,8 @@ impl DomEventData {
             DomEventData::Touch(t) => Rc::new(ErasedEventData::new(Box::new(t))),
             DomEventData::Pointer(p) => Rc::new(ErasedEventData::new(Box::new(p))),
             DomEventData::File(fd) => Rc::new(ErasedEventData::new(Box::new(fd))),
+            DomEventData::Mounted(m) => Rc::new(ErasedEventData::new(Box::new(m))),
+            DomEventData::Focus(f) => Rc::new(ErasedEventData::new(Box::new(f))),
         }
     }
 }
@@ -5,6 +5,7 @@ use freya_native_core::{
     NodeId,
 };
 use itertools::sorted;
+use torin::prelude::CursorPoint;
 
 use super::{
     PlatformEventData,
@@ -24,7 +25,9 @@ use crate::{
         ElementUtils,
         ElementUtilsResolver,
     },
+    platform::CursorIcon,
     states::{
+        LayerState,
         StyleState,
         ViewportState,
     },
@@ -36,7 +39,9 @@ use crate::{
     values::Fill,
 };
 
-/// Process the events and emit them to the VirtualDOM
+/// Process the events and emit them to the VirtualDOM.
+///
+/// Returns the icon the mouse cursor must change to, if the `cursor` attribute of the hovered elements changed it.
 pub fn process_events(
     fdom: &FreyaDOM,
     events: &mut EventsQueue,
@@ -45,10 +50,13 @@ pub fn process_events(
     scale_factor: f64,
 
     focus_id: Option<NodeId>,
-) {
+) -> Option<CursorIcon> {
     // Get potential events that could be emitted based on the elements layout and viewports
     let potential_events = measure_potential_event_listeners(events, fdom, scale_factor, focus_id);
 
+    // Get the cursor icon of the hovered elements
+    let cursor_icon = nodes_state.process_cursor_icon(fdom, &potential_events, events);
+
     // Get what events can be actually emitted based on what elements are listening
     let mut dom_events = measure_dom_events(&potential_events, fdom, scale_factor);
 
@@ -72,6 +80,36 @@ pub fn process_events(
 
     // Clear the events queue
     events.clear();
+
+    cursor_icon
+}
+
+/// Emit the `mounted` events to the VirtualDOM, must be called after the layout has been measured
+pub fn process_mounted_events(
+    fdom: &FreyaDOM,
+    event_emitter: &EventEmitter,
+    nodes_state: &mut NodesState,
+    scale_factor: f64,
+) {
+    let dom_events = nodes_state.process_mounted(fdom, scale_factor);
+
+    if !dom_events.is_empty() {
+        event_emitter.send(dom_events).unwrap();
+    }
+}
+
+/// Emit the `blur` and `focus` events to the VirtualDOM, must be called after the focus has been updated
+pub fn process_focus_events(
+    fdom: &FreyaDOM,
+    event_emitter: &EventEmitter,
+    nodes_state: &mut NodesState,
+    focus_id: Option<NodeId>,
+) {
+    let dom_events = nodes_state.process_focus(fdom, focus_id);
+
+    if !dom_events.is_empty() {
+        event_emitter.send(dom_events).unwrap();
+    }
 }
 
 /// For every event in the queue, a global event is created
@@ -93,6 +131,11 @@ pub fn measure_platform_global_events(
             let listeners = rdom.get_listeners(&global_name);
 
             for listener in listeners {
+                // Hidden and inert elements don't receive global events
+                if is_non_interactive(rdom, listener.id()) {
+                    continue;
+                }
+
                 let event = DomEvent::new(
                     PotentialEvent {
                         node_id: listener.id(),
@@ -121,91 +164,164 @@ pub fn measure_potential_event_listeners(
     let layout = fdom.layout();
     let rdom = fdom.rdom();
     let layers = fdom.layers();
-
-    // Walk layer by layer from the bottom to the top
-    for (layer, layer_nodes) in sorted(layers.iter()) {
-        for node_id in layer_nodes.iter() {
-            let Some(layout_node) = layout.get(*node_id) else {
-                continue;
-            };
-            'events: for PlatformEvent { name, data } in events {
-                let cursor = match data {
-                    PlatformEventData::Mouse { cursor, .. } => cursor,
-                    PlatformEventData::Wheel { cursor, .. } => cursor,
-                    PlatformEventData::Touch { location, .. } => location,
-                    PlatformEventData::File { cursor, .. } => cursor,
-                    PlatformEventData::Keyboard { .. } if focus_id == Some(*node_id) => {
-                        let potential_event = PotentialEvent {
-                            node_id: *node_id,
-                            layer: Some(*layer),
-                            name: *name,
-                            data: data.clone(),
-                        };
-                        potential_events
-                            .entry(*name)
-                            .or_default()
-                            .push(potential_event);
-                        continue;
-                    }
-                    _ => continue,
+    let mut frame_counters = fdom.frame_counters();
+
+    frame_counters.hit_tested_nodes = 0;
+    frame_counters.culled_hit_tested_nodes = 0;
+
+    let cursors = events
+        .iter()
+        .filter_map(|PlatformEvent { data, .. }| event_cursor(data))
+        .collect::<Vec<_>>();
+
+    // Only the events with a cursor can target the elements under it
+    if !cursors.is_empty() {
+        // Walk layer by layer from the bottom to the top
+        for (layer, layer_nodes) in sorted(layers.iter()) {
+            for node_id in layer_nodes.iter() {
+                let Some(layout_node) = layout.get(*node_id) else {
+                    continue;
                 };
 
-                let node = rdom.get(*node_id).unwrap();
-                let node_type = node.node_type();
-
-                let Some(element_utils) = node_type.tag().and_then(|tag| tag.utils()) else {
+                // Skip hidden and inert elements
+                if is_non_interactive(rdom, *node_id) {
                     continue;
-                };
+                }
 
-                // Make sure the cursor is inside the node area
-                if !element_utils.is_point_inside_area(
-                    cursor,
-                    &node,
-                    layout_node,
-                    scale_factor as f32,
-                ) {
+                // Skip elements that are not under any of the cursors before checking them in detail
+                if !cursors
+                    .iter()
+                    .any(|cursor| layout_node.area.contains(cursor.to_f32()))
+                {
+                    frame_counters.culled_hit_tested_nodes += 1;
                     continue;
                 }
 
-                let node = rdom.get(*node_id).unwrap();
-                let node_viewports = node.get::<ViewportState>().unwrap();
+                frame_counters.hit_tested_nodes += 1;
+
+                'events: for PlatformEvent { name, data } in events {
+                    let Some(cursor) = event_cursor(data) else {
+                        continue;
+                    };
+
+                    let node = rdom.get(*node_id).unwrap();
+                    let node_type = node.node_type();
 
-                // Make sure the cursor is inside all the inherited viewports of the node
-                for node_id in &node_viewports.viewports {
-                    let node_ref = rdom.get(*node_id).unwrap();
-                    let node_type = node_ref.node_type();
                     let Some(element_utils) = node_type.tag().and_then(|tag| tag.utils()) else {
                         continue;
                     };
-                    let layout_node = layout.get(*node_id).unwrap();
+
+                    // Make sure the cursor is inside the node area
                     if !element_utils.is_point_inside_area(
                         cursor,
-                        &node_ref,
+                        &node,
                         layout_node,
                         scale_factor as f32,
                     ) {
-                        continue 'events;
+                        continue;
                     }
+
+                    let node = rdom.get(*node_id).unwrap();
+                    let node_viewports = node.get::<ViewportState>().unwrap();
+
+                    // Elements of a portal are not clipped by the viewports of the portal ancestors
+                    let portal_viewports = node
+                        .get::<LayerState>()
+                        .and_then(|layer_state| layer_state.window_portal)
+                        .and_then(|portal_id| rdom.get(portal_id))
+                        .map(|portal| portal.get::<ViewportState>().unwrap().viewports.len())
+                        .unwrap_or_default();
+
+                    // Make sure the cursor is inside all the inherited viewports of the node
+                    for node_id in &node_viewports.viewports[portal_viewports..] {
+                        let node_ref = rdom.get(*node_id).unwrap();
+                        let node_type = node_ref.node_type();
+                        let Some(element_utils) = node_type.tag().and_then(|tag| tag.utils())
+                        else {
+                            continue;
+                        };
+                        let layout_node = layout.get(*node_id).unwrap();
+                        if !element_utils.is_point_inside_area(
+                            cursor,
+                            &node_ref,
+                            layout_node,
+                            scale_factor as f32,
+                        ) {
+                            continue 'events;
+                        }
+                    }
+
+                    let potential_event = PotentialEvent {
+                        node_id: *node_id,
+                        layer: Some(*layer),
+                        name: *name,
+                        data: data.clone(),
+                    };
+
+                    potential_events
+                        .entry(*name)
+                        .or_insert_with(Vec::new)
+                        .push(potential_event);
                 }
+            }
+        }
+    }
+
+    // Keyboard events target the focused Node, if it's not listening they go to its closest listening ancestor,
+    // and from there they bubble up through the rest of ancestors
+    if let Some(focus_id) = focus_id {
+        let tree = rdom.tree_ref();
+        let mut focused_branch = vec![focus_id];
+        while let Some(parent_id) = focused_branch.last().and_then(|id| tree.parent_id(*id)) {
+            focused_branch.push(parent_id);
+        }
+        focused_branch.retain(|node_id| {
+            layout.get(*node_id).is_some() && !is_non_interactive(rdom, *node_id)
+        });
+
+        for PlatformEvent { name, data } in events {
+            if !matches!(data, PlatformEventData::Keyboard { .. }) {
+                continue;
+            }
 
-                let potential_event = PotentialEvent {
+            // The deepest Nodes go last as they are the first candidates
+            potential_events
+                .entry(*name)
+                .or_default()
+                .extend(focused_branch.iter().rev().map(|node_id| PotentialEvent {
                     node_id: *node_id,
-                    layer: Some(*layer),
+                    layer: None,
                     name: *name,
                     data: data.clone(),
-                };
-
-                potential_events
-                    .entry(*name)
-                    .or_insert_with(Vec::new)
-                    .push(potential_event);
-            }
+                }));
         }
     }
 
     potential_events
 }
 
+/// Get the position of the cursor of an event, if it has any.
+fn event_cursor(data: &PlatformEventData) -> Option<&CursorPoint> {
+    match data {
+        PlatformEventData::Mouse { cursor, .. } => Some(cursor),
+        PlatformEventData::Wheel { cursor, .. } => Some(cursor),
+        PlatformEventData::Touch { location, .. } => Some(location),
+        PlatformEventData::File { cursor, .. } => Some(cursor),
+        _ => None,
+    }
+}
+
+/// Whether the given Node is hidden by the `offscreen` attribute or made inert by the `inert`
+/// attribute, of itself or an ancestor.
+fn is_non_interactive(rdom: &DioxusDOM, node_id: NodeId) -> bool {
+    rdom.get(node_id)
+        .and_then(|node| {
+            node.get::<LayerState>()
+                .map(|state| state.offscreen.is_some() || state.inert.is_some())
+        })
+        .unwrap_or_default()
+}
+
 pub fn is_node_parent_of(rdom: &DioxusDOM, node: NodeId, parent_node: NodeId) -> bool {
     let mut head = Some(node);
     while let Some(id) = head.take() {
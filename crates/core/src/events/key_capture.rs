@@ -0,0 +1,93 @@
+use std::sync::{
+    Arc,
+    Mutex,
+};
+
+use freya_elements::events::keyboard::{
+    Code,
+    Key,
+    Modifiers,
+};
+use freya_native_core::events::EventName;
+use tokio::sync::oneshot;
+
+use crate::events::{
+    PlatformEvent,
+    PlatformEventData,
+};
+
+/// Combination of keys pressed by the user, e.g. `Ctrl + Shift + K`.
+#[derive(Debug, Clone, PartialEq)]
+pub struct KeyCombination {
+    pub key: Key,
+    pub code: Code,
+    pub modifiers: Modifiers,
+}
+
+/// Route the next key combination pressed by the user to whoever started a capture,
+/// instead of emitting it to the elements.
+#[derive(Clone, Default)]
+pub struct KeyCapture(Arc<Mutex<Option<oneshot::Sender<KeyCombination>>>>);
+
+impl KeyCapture {
+    /// Capture the next key combination, cancelling any capture in progress.
+    pub fn start(&self) -> oneshot::Receiver<KeyCombination> {
+        let (sender, receiver) = oneshot::channel();
+        *self.0.lock().unwrap() = Some(sender);
+        receiver
+    }
+
+    /// Cancel the capture in progress, if any.
+    pub fn cancel(&self) {
+        self.0.lock().unwrap().take();
+    }
+
+    /// Whether a capture is in progress.
+    pub fn is_capturing(&self) -> bool {
+        self.0
+            .lock()
+            .unwrap()
+            .as_ref()
+            .is_some_and(|sender| !sender.is_closed())
+    }
+
+    /// Capture the given event if a capture is in progress, returns whether it was captured
+    /// so it's not emitted to the elements.
+    ///
+    /// The capture finishes with the first pressed key that is not a modifier,
+    /// all the other keyboard events are swallowed in the meantime.
+    pub fn capture(&self, event: &PlatformEvent) -> bool {
+        let mut capture = self.0.lock().unwrap();
+        if capture.as_ref().is_none_or(|sender| sender.is_closed()) {
+            return false;
+        }
+
+        let PlatformEventData::Keyboard {
+            key,
+            code,
+            modifiers,
+            ..
+        } = &event.data
+        else {
+            return false;
+        };
+
+        let is_modifier = matches!(
+            key,
+            Key::Shift | Key::Control | Key::Alt | Key::AltGraph | Key::Meta | Key::Super
+        );
+        if event.name == EventName::KeyDown && !is_modifier {
+            if let Some(sender) = capture.take() {
+                sender
+                    .send(KeyCombination {
+                        key: key.clone(),
+                        code: *code,
+                        modifiers: *modifiers,
+                    })
+                    .ok();
+            }
+        }
+
+        true
+    }
+}
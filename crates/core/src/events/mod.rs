@@ -1,12 +1,22 @@
+pub mod click_tracker;
+pub mod default_prevented;
 pub mod dom_event;
 pub mod events_measurer;
+pub mod key_capture;
 pub mod nodes_state;
 pub mod platform_event;
 pub mod potential_event;
+pub mod shortcuts;
+pub mod wheel_tracker;
 
+pub use click_tracker::*;
+pub use default_prevented::*;
 pub use dom_event::*;
 pub use events_measurer::*;
 pub use freya_native_core::events::*;
+pub use key_capture::*;
 pub use nodes_state::*;
 pub use platform_event::*;
 pub use potential_event::*;
+pub use shortcuts::*;
+pub use wheel_tracker::*;
@@ -1,12 +1,20 @@
 #![allow(clippy::type_complexity)]
 
+use freya_elements::events::{
+    FocusData,
+    MountedData,
+    NodeHandle,
+};
 use freya_engine::prelude::Color;
 use freya_native_core::{
     events::EventName,
     prelude::NodeImmutable,
+    tree::TreeRef,
     NodeId,
 };
 use rustc_hash::FxHashMap;
+use tokio::sync::watch;
+use torin::geometry::Area;
 
 use super::PlatformEventData;
 use crate::{
@@ -14,9 +22,11 @@ use crate::{
     events::{
         is_node_parent_of,
         DomEvent,
+        DomEventData,
         PlatformEvent,
         PotentialEvent,
     },
+    platform::CursorIcon,
     states::StyleState,
     types::PotentialEvents,
     values::Fill,
@@ -32,6 +42,9 @@ struct NodeMetadata {
 pub struct NodesState {
     pressed_nodes: FxHashMap<NodeId, NodeMetadata>,
     hovered_nodes: FxHashMap<NodeId, NodeMetadata>,
+    mounted_nodes: FxHashMap<NodeId, watch::Sender<Option<(Area, Area)>>>,
+    focused_node: Option<NodeId>,
+    cursor_icon: Option<CursorIcon>,
 }
 
 impl NodesState {
@@ -182,6 +195,140 @@ impl NodesState {
     }
 }
 
+impl NodesState {
+    /// Get the `mounted` events for the listening Nodes that have been laid out for the first time,
+    /// and keep the handles of the already mounted Nodes up to date with their layout.
+    pub fn process_mounted(&mut self, fdom: &FreyaDOM, scale_factor: f64) -> Vec<DomEvent> {
+        let rdom = fdom.rdom();
+        let layout = fdom.layout();
+        let listeners = rdom.get_listeners(&EventName::Mounted);
+
+        // Forget the Nodes that were removed or no longer listen, their handles become unmounted
+        self.mounted_nodes.retain(|node_id, handle| {
+            let is_listening = listeners.iter().any(|listener| listener.id() == *node_id);
+            if !is_listening {
+                handle.send_replace(None);
+            }
+            is_listening
+        });
+
+        let mut dom_events = Vec::new();
+
+        for listener in listeners {
+            let node_id = listener.id();
+
+            let Some(layout_node) = layout.get(node_id) else {
+                continue;
+            };
+            let area = layout_node.area / scale_factor as f32;
+            let inner_area = layout_node.inner_area / scale_factor as f32;
+
+            if let Some(handle) = self.mounted_nodes.get(&node_id) {
+                handle.send_if_modified(|layout| {
+                    let modified = *layout != Some((area, inner_area));
+                    *layout = Some((area, inner_area));
+                    modified
+                });
+                continue;
+            }
+
+            #[cfg(debug_assertions)]
+            tracing::info!("Marked as mounted {:?}", node_id);
+
+            let (sender, handle) = NodeHandle::channel(area, inner_area);
+            self.mounted_nodes.insert(node_id, sender);
+
+            dom_events.push(DomEvent {
+                name: EventName::Mounted,
+                node_id,
+                data: DomEventData::Mounted(MountedData::new(area, inner_area, handle)),
+                bubbles: false,
+            })
+        }
+
+        dom_events
+    }
+
+    /// Get the `blur` and `focus` events if the focused Node changed since the last call
+    pub fn process_focus(&mut self, fdom: &FreyaDOM, focus_id: Option<NodeId>) -> Vec<DomEvent> {
+        if self.focused_node == focus_id {
+            return Vec::new();
+        }
+
+        let rdom = fdom.rdom();
+        let previous_focus_id = std::mem::replace(&mut self.focused_node, focus_id);
+
+        let mut dom_events = Vec::new();
+
+        for (name, node_id) in [
+            (EventName::Blur, previous_focus_id),
+            (EventName::Focus, focus_id),
+        ] {
+            let Some(node_id) = node_id else {
+                continue;
+            };
+
+            let is_listening = rdom
+                .get_listeners(&name)
+                .iter()
+                .any(|listener| listener.id() == node_id);
+
+            if is_listening {
+                dom_events.push(DomEvent {
+                    name,
+                    node_id,
+                    data: DomEventData::Focus(FocusData),
+                    bubbles: false,
+                })
+            }
+        }
+
+        dom_events
+    }
+
+    /// Get the icon the mouse cursor must change to if the `cursor` attribute of the hovered Nodes changed it.
+    /// The default icon is restored once no hovered Node specifies one.
+    pub fn process_cursor_icon(
+        &mut self,
+        fdom: &FreyaDOM,
+        potential_events: &PotentialEvents,
+        events: &[PlatformEvent],
+    ) -> Option<CursorIcon> {
+        if !events
+            .iter()
+            .any(|event| event.name == EventName::MouseMove)
+        {
+            return None;
+        }
+
+        let rdom = fdom.rdom();
+        let tree = rdom.tree_ref();
+
+        // The topmost Node under the cursor or its closest ancestor with a cursor icon
+        let mut head = potential_events
+            .get(&EventName::MouseMove)
+            .and_then(|events| events.last())
+            .map(|event| event.node_id);
+        let mut cursor_icon = None;
+        while let Some(node_id) = head {
+            cursor_icon = rdom
+                .get(node_id)
+                .and_then(|node| node.get::<StyleState>().and_then(|style| style.cursor));
+            if cursor_icon.is_some() {
+                break;
+            }
+            head = tree.parent_id(node_id);
+        }
+
+        if self.cursor_icon == cursor_icon {
+            return None;
+        }
+
+        self.cursor_icon = cursor_icon;
+        Some(cursor_icon.unwrap_or_default())
+    }
+}
+
 fn any_event_of(
     events: &[PlatformEvent],
     filter: impl Fn(EventName) -> bool,
@@ -1,9 +1,14 @@
 use std::path::PathBuf;
 
-use freya_elements::events::keyboard::{
-    Code,
-    Key,
-    Modifiers,
+use freya_elements::events::{
+    keyboard::{
+        Code,
+        Key,
+        Location,
+        Modifiers,
+    },
+    WheelDeltaMode,
+    WheelPhase,
 };
 use freya_native_core::events::EventName;
 use torin::prelude::*;
@@ -32,12 +37,19 @@ pub enum PlatformEventData {
     Wheel {
         scroll: CursorPoint,
         cursor: CursorPoint,
+        /// Scroll as reported by the device, in lines or pixels depending on `delta_mode`.
+        raw_scroll: CursorPoint,
+        delta_mode: WheelDeltaMode,
+        phase: WheelPhase,
+        momentum: bool,
     },
     /// A Keyboard event.
     Keyboard {
         key: Key,
         code: Code,
         modifiers: Modifiers,
+        repeat: bool,
+        location: Location,
     },
     /// A Touch event.
     Touch {
@@ -0,0 +1,351 @@
+use std::{
+    fmt,
+    str::FromStr,
+    sync::{
+        Arc,
+        Mutex,
+    },
+};
+
+use freya_elements::events::keyboard::{
+    Code,
+    Key,
+    Modifiers,
+};
+use freya_native_core::events::EventName;
+use tokio::sync::mpsc::{
+    unbounded_channel,
+    UnboundedReceiver,
+    UnboundedSender,
+};
+
+use crate::events::{
+    PlatformEvent,
+    PlatformEventData,
+};
+
+/// Modifiers that are part of a [`Shortcut`], the rest (e.g. caps lock) are ignored.
+const SHORTCUT_MODIFIERS: Modifiers = Modifiers::CONTROL
+    .union(Modifiers::SHIFT)
+    .union(Modifiers::ALT)
+    .union(Modifiers::META);
+
+/// Keyboard shortcut, e.g. `ctrl+k` or `ctrl+shift+f1`.
+///
+/// The modifiers are `ctrl`, `shift`, `alt` (or `option`), `meta` (or `cmd`, `super`)
+/// and `cmdorctrl`, which is `meta` in macOS and `ctrl` elsewhere.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Shortcut {
+    pub key: Key,
+    /// Physical key that also triggers the shortcut, so letters and digits
+    /// work the same when combined with `shift`.
+    pub code: Option<Code>,
+    pub modifiers: Modifiers,
+}
+
+impl Shortcut {
+    /// Whether the given key combination triggers this shortcut.
+    pub fn matches(&self, key: &Key, code: Code, modifiers: Modifiers) -> bool {
+        if modifiers & SHORTCUT_MODIFIERS != self.modifiers {
+            return false;
+        }
+        let same_key = match (&self.key, key) {
+            (Key::Character(a), Key::Character(b)) => a.to_lowercase() == b.to_lowercase(),
+            (a, b) => a == b,
+        };
+        same_key || self.code == Some(code)
+    }
+}
+
+impl FromStr for Shortcut {
+    type Err = ShortcutError;
+
+    fn from_str(shortcut: &str) -> Result<Self, Self::Err> {
+        let invalid = || ShortcutError::Invalid(shortcut.to_string());
+        let mut modifiers = Modifiers::empty();
+        let mut key = None;
+
+        for part in shortcut.split('+').map(str::trim) {
+            let modifier = match part.to_lowercase().as_str() {
+                "ctrl" | "control" => Modifiers::CONTROL,
+                "shift" => Modifiers::SHIFT,
+                "alt" | "option" => Modifiers::ALT,
+                "meta" | "cmd" | "command" | "super" => Modifiers::META,
+                "cmdorctrl" if cfg!(target_os = "macos") => Modifiers::META,
+                "cmdorctrl" => Modifiers::CONTROL,
+                _ => {
+                    if key.is_some() {
+                        return Err(invalid());
+                    }
+                    key = Some(parse_key(part).ok_or_else(invalid)?);
+                    continue;
+                }
+            };
+            modifiers |= modifier;
+        }
+
+        let key = key.ok_or_else(invalid)?;
+        let code = match &key {
+            Key::Character(character) => character_code(character),
+            _ => None,
+        };
+
+        Ok(Self {
+            key,
+            code,
+            modifiers,
+        })
+    }
+}
+
+impl fmt::Display for Shortcut {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for (modifier, name) in [
+            (Modifiers::CONTROL, "Ctrl"),
+            (Modifiers::SHIFT, "Shift"),
+            (Modifiers::ALT, "Alt"),
+            (Modifiers::META, "Meta"),
+        ] {
+            if self.modifiers.contains(modifier) {
+                write!(f, "{name}+")?;
+            }
+        }
+        match &self.key {
+            Key::Character(character) => f.write_str(&character.to_uppercase()),
+            key => write!(f, "{key}"),
+        }
+    }
+}
+
+fn parse_key(key: &str) -> Option<Key> {
+    let key = match key.to_lowercase().as_str() {
+        "" => return None,
+        "esc" | "escape" => Key::Escape,
+        "enter" | "return" => Key::Enter,
+        "tab" => Key::Tab,
+        "space" => Key::Character(" ".to_string()),
+        "plus" => Key::Character("+".to_string()),
+        "backspace" => Key::Backspace,
+        "del" | "delete" => Key::Delete,
+        "insert" => Key::Insert,
+        "up" | "arrowup" => Key::ArrowUp,
+        "down" | "arrowdown" => Key::ArrowDown,
+        "left" | "arrowleft" => Key::ArrowLeft,
+        "right" | "arrowright" => Key::ArrowRight,
+        "home" => Key::Home,
+        "end" => Key::End,
+        "pageup" => Key::PageUp,
+        "pagedown" => Key::PageDown,
+        lowercase if lowercase.chars().count() == 1 => Key::Character(lowercase.to_string()),
+        lowercase => match lowercase.strip_prefix('f').map(str::parse::<u8>) {
+            Some(Ok(_)) => Key::from_str(&key.to_uppercase()).ok()?,
+            _ => Key::from_str(key).ok()?,
+        },
+    };
+    Some(key)
+}
+
+fn character_code(character: &str) -> Option<Code> {
+    let mut chars = character.chars();
+    let (Some(character), None) = (chars.next(), chars.next()) else {
+        return None;
+    };
+    let code = if character.is_ascii_alphabetic() {
+        format!("Key{}", character.to_ascii_uppercase())
+    } else if character.is_ascii_digit() {
+        format!("Digit{character}")
+    } else if character == ' ' {
+        "Space".to_string()
+    } else {
+        return None;
+    };
+    Code::from_str(&code).ok()
+}
+
+/// Error registering a [`Shortcut`].
+#[derive(Debug, Clone, PartialEq)]
+pub enum ShortcutError {
+    /// The shortcut could not be parsed.
+    Invalid(String),
+    /// The shortcut is already registered.
+    Conflict(Shortcut),
+    /// There is no shortcut registry, e.g. when rendering outside of a Freya renderer.
+    Unavailable,
+}
+
+impl fmt::Display for ShortcutError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Invalid(shortcut) => write!(f, "Invalid shortcut '{shortcut}'"),
+            Self::Conflict(shortcut) => {
+                write!(f, "The shortcut '{shortcut}' is already registered")
+            }
+            Self::Unavailable => write!(f, "Global shortcuts are not available"),
+        }
+    }
+}
+
+impl std::error::Error for ShortcutError {}
+
+/// Identifier of a registered [`Shortcut`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct ShortcutId(usize);
+
+#[derive(Default)]
+struct Shortcuts {
+    next_id: usize,
+    shortcuts: Vec<(ShortcutId, Shortcut, UnboundedSender<()>)>,
+}
+
+/// Route the key combinations of the registered shortcuts to their handlers
+/// regardless of the focused element, instead of emitting them to the elements.
+#[derive(Clone, Default)]
+pub struct ShortcutRegistry(Arc<Mutex<Shortcuts>>);
+
+impl ShortcutRegistry {
+    /// Register a shortcut, the returned receiver gets a message every time it's pressed.
+    ///
+    /// Fails if the same shortcut is already registered.
+    pub fn register(
+        &self,
+        shortcut: Shortcut,
+    ) -> Result<(ShortcutId, UnboundedReceiver<()>), ShortcutError> {
+        let mut shortcuts = self.0.lock().unwrap();
+        shortcuts
+            .shortcuts
+            .retain(|(_, _, sender)| !sender.is_closed());
+
+        let is_conflict = shortcuts.shortcuts.iter().any(|(_, registered, _)| {
+            registered.modifiers == shortcut.modifiers
+                && (registered.key == shortcut.key
+                    || registered.code.is_some() && registered.code == shortcut.code)
+        });
+        if is_conflict {
+            return Err(ShortcutError::Conflict(shortcut));
+        }
+
+        let id = ShortcutId(shortcuts.next_id);
+        shortcuts.next_id += 1;
+        let (sender, receiver) = unbounded_channel();
+        shortcuts.shortcuts.push((id, shortcut, sender));
+        Ok((id, receiver))
+    }
+
+    /// Unregister a shortcut so its key combination is emitted to the elements again.
+    pub fn unregister(&self, id: ShortcutId) {
+        self.0
+            .lock()
+            .unwrap()
+            .shortcuts
+            .retain(|(shortcut_id, _, _)| *shortcut_id != id);
+    }
+
+    /// Whether the given shortcut is registered.
+    pub fn is_registered(&self, shortcut: &Shortcut) -> bool {
+        self.0
+            .lock()
+            .unwrap()
+            .shortcuts
+            .iter()
+            .any(|(_, registered, sender)| registered == shortcut && !sender.is_closed())
+    }
+
+    /// Notify the shortcut triggered by the given event, returns whether
+    /// the event was handled so it's not emitted to the elements.
+    pub fn dispatch(&self, event: &PlatformEvent) -> bool {
+        let PlatformEventData::Keyboard {
+            key,
+            code,
+            modifiers,
+            ..
+        } = &event.data
+        else {
+            return false;
+        };
+        if event.name != EventName::KeyDown {
+            return false;
+        }
+
+        let shortcuts = self.0.lock().unwrap();
+        let shortcut = shortcuts
+            .shortcuts
+            .iter()
+            .find(|(_, shortcut, _)| shortcut.matches(key, *code, *modifiers));
+
+        match shortcut {
+            Some((_, _, sender)) => sender.send(()).is_ok(),
+            None => false,
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use freya_elements::events::keyboard::{
+        Code,
+        Key,
+        Modifiers,
+    };
+
+    use super::{
+        Shortcut,
+        ShortcutError,
+        ShortcutRegistry,
+    };
+
+    #[test]
+    fn parse_shortcuts() {
+        let shortcut = "ctrl+shift+k".parse::<Shortcut>().unwrap();
+        assert_eq!(shortcut.key, Key::Character("k".to_string()));
+        assert_eq!(shortcut.code, Some(Code::KeyK));
+        assert_eq!(shortcut.modifiers, Modifiers::CONTROL | Modifiers::SHIFT);
+        assert_eq!(shortcut.to_string(), "Ctrl+Shift+K");
+
+        let shortcut = "Alt + F4".parse::<Shortcut>().unwrap();
+        assert_eq!(shortcut.key, Key::F4);
+        assert_eq!(shortcut.modifiers, Modifiers::ALT);
+
+        assert_eq!("esc".parse::<Shortcut>().unwrap().key, Key::Escape);
+
+        assert!(matches!(
+            "ctrl+".parse::<Shortcut>(),
+            Err(ShortcutError::Invalid(_))
+        ));
+        assert!(matches!(
+            "ctrl+a+b".parse::<Shortcut>(),
+            Err(ShortcutError::Invalid(_))
+        ));
+    }
+
+    #[test]
+    fn match_shortcuts() {
+        let shortcut = "ctrl+shift+1".parse::<Shortcut>().unwrap();
+        let modifiers = Modifiers::CONTROL | Modifiers::SHIFT;
+        assert!(shortcut.matches(&Key::Character("!".to_string()), Code::Digit1, modifiers));
+        assert!(!shortcut.matches(
+            &Key::Character("1".to_string()),
+            Code::Digit1,
+            Modifiers::CONTROL
+        ));
+        assert!(shortcut.matches(
+            &Key::Character("1".to_string()),
+            Code::Digit1,
+            modifiers | Modifiers::CAPS_LOCK
+        ));
+    }
+
+    #[test]
+    fn detect_conflicts() {
+        let registry = ShortcutRegistry::default();
+        let (id, _receiver) = registry.register("ctrl+k".parse().unwrap()).unwrap();
+
+        assert!(matches!(
+            registry.register("Ctrl+K".parse().unwrap()),
+            Err(ShortcutError::Conflict(_))
+        ));
+        assert!(registry.register("ctrl+shift+k".parse().unwrap()).is_ok());
+
+        registry.unregister(id);
+        assert!(registry.register("ctrl+k".parse().unwrap()).is_ok());
+    }
+}
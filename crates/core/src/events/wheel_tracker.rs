@@ -0,0 +1,132 @@
+use freya_elements::events::{
+    WheelDeltaMode,
+    WheelPhase,
+};
+
+use super::{
+    PlatformEvent,
+    PlatformEventData,
+};
+
+/// Detect the momentum scroll that trackpads emit after the fingers are lifted, until a new gesture starts.
+#[derive(Default)]
+pub struct WheelTracker {
+    momentum: bool,
+}
+
+impl WheelTracker {
+    /// Track a platform event, marking it as momentum if it's part of a momentum scroll.
+    pub fn track(&mut self, event: &mut PlatformEvent) {
+        let PlatformEventData::Wheel {
+            delta_mode,
+            phase,
+            momentum,
+            ..
+        } = &mut event.data
+        else {
+            return;
+        };
+
+        self.momentum = match (*phase, *delta_mode) {
+            // Mouse wheels have no momentum
+            (_, WheelDeltaMode::Line) | (WheelPhase::Started, _) => false,
+            (WheelPhase::Ended, _) => true,
+            (WheelPhase::Moved, _) => self.momentum,
+        };
+        *momentum = self.momentum && *phase == WheelPhase::Moved;
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use freya_elements::events::{
+        WheelDeltaMode,
+        WheelPhase,
+    };
+    use freya_native_core::events::EventName;
+
+    use super::WheelTracker;
+    use crate::events::{
+        PlatformEvent,
+        PlatformEventData,
+    };
+
+    fn wheel(phase: WheelPhase, delta_mode: WheelDeltaMode) -> PlatformEvent {
+        PlatformEvent {
+            name: EventName::Wheel,
+            data: PlatformEventData::Wheel {
+                scroll: (0., 10.).into(),
+                cursor: (0., 0.).into(),
+                raw_scroll: (0., 10.).into(),
+                delta_mode,
+                phase,
+                momentum: false,
+            },
+        }
+    }
+
+    fn track(tracker: &mut WheelTracker, phase: WheelPhase, delta_mode: WheelDeltaMode) -> bool {
+        let mut event = wheel(phase, delta_mode);
+        tracker.track(&mut event);
+        let PlatformEventData::Wheel { momentum, .. } = event.data else {
+            unreachable!()
+        };
+        momentum
+    }
+
+    #[test]
+    fn momentum_follows_the_end_of_a_gesture() {
+        let mut tracker = WheelTracker::default();
+
+        assert!(!track(
+            &mut tracker,
+            WheelPhase::Started,
+            WheelDeltaMode::Pixel
+        ));
+        assert!(!track(
+            &mut tracker,
+            WheelPhase::Moved,
+            WheelDeltaMode::Pixel
+        ));
+        assert!(!track(
+            &mut tracker,
+            WheelPhase::Ended,
+            WheelDeltaMode::Pixel
+        ));
+        assert!(track(
+            &mut tracker,
+            WheelPhase::Moved,
+            WheelDeltaMode::Pixel
+        ));
+        assert!(track(
+            &mut tracker,
+            WheelPhase::Moved,
+            WheelDeltaMode::Pixel
+        ));
+
+        // A new gesture stops the momentum
+        assert!(!track(
+            &mut tracker,
+            WheelPhase::Started,
+            WheelDeltaMode::Pixel
+        ));
+        assert!(!track(
+            &mut tracker,
+            WheelPhase::Moved,
+            WheelDeltaMode::Pixel
+        ));
+
+        // And so does a mouse wheel
+        track(&mut tracker, WheelPhase::Ended, WheelDeltaMode::Pixel);
+        assert!(!track(
+            &mut tracker,
+            WheelPhase::Moved,
+            WheelDeltaMode::Line
+        ));
+        assert!(!track(
+            &mut tracker,
+            WheelPhase::Moved,
+            WheelDeltaMode::Pixel
+        ));
+    }
+}
@@ -1,15 +1,28 @@
 use freya_engine::prelude::*;
-use freya_native_core::prelude::NodeImmutable;
+use freya_native_core::{
+    prelude::NodeImmutable,
+    NodeId,
+};
 use itertools::Itertools;
-use torin::geometry::Area;
+use torin::{
+    geometry::Area,
+    prelude::{
+        Point2D,
+        Size,
+        Size2D,
+    },
+    torin::Torin,
+};
 
 use crate::{
     accessibility::NodeAccessibility,
     dom::*,
+    platform_state::LayoutDirection,
     render::{
         Compositor,
         SkiaMeasurer,
     },
+    states::LayoutState,
 };
 
 /// Process the layout of the DOM
@@ -18,18 +31,24 @@ pub fn process_layout(
     area: Area,
     font_collection: &mut FontCollection,
     scale_factor: f32,
+    text_scale_factor: f32,
+    layout_direction: LayoutDirection,
     default_fonts: &[String],
 ) {
     {
         let rdom = fdom.rdom();
         let mut images_cache = fdom.images_cache();
-        let mut dom_adapter = DioxusDOMAdapter::new(rdom, scale_factor);
+        let mut paragraph_cache = fdom.paragraph_cache();
+        let mut dom_adapter =
+            DioxusDOMAdapter::new(rdom, scale_factor).with_mirrored(layout_direction.is_rtl());
         let skia_measurer = SkiaMeasurer::new(
             rdom,
             font_collection,
             default_fonts,
             scale_factor,
+            text_scale_factor,
             &mut images_cache,
+            &mut paragraph_cache,
         );
 
         let mut layout = fdom.layout();
@@ -67,3 +86,45 @@ pub fn process_layout(
         layout.measure(root_id, area, &mut Some(skia_measurer), &mut dom_adapter);
     }
 }
+
+/// Size taken by the content of the DOM, e.g. to size a window so it fits it.
+///
+/// Elements sized relative to their parent (e.g. `fill` or `100%`) take the size of their
+/// children instead, so wrappers that fill the window don't count.
+pub fn content_size(fdom: &FreyaDOM) -> Size2D {
+    fn content_end(node: &DioxusNode, layout: &Torin<NodeId>) -> Option<Point2D> {
+        let layout_node = layout.get(node.id())?;
+        let layout_state = node.get::<LayoutState>()?;
+        let fits_content = |size: &Size| size.inner_sized() || matches!(size, Size::Pixels(_));
+        let fits_width = fits_content(&layout_state.width);
+        let fits_height = fits_content(&layout_state.height);
+
+        let mut end = Point2D::new(layout_node.area.max_x(), layout_node.area.max_y());
+        if !fits_width || !fits_height {
+            let children_end = node
+                .children()
+                .iter()
+                .filter_map(|child| content_end(child, layout))
+                .fold(layout_node.inner_area.origin, Point2D::max);
+            // Keep the padding and margin of the element
+            if !fits_width {
+                end.x = children_end.x + layout_node.area.max_x() - layout_node.inner_area.max_x();
+            }
+            if !fits_height {
+                end.y = children_end.y + layout_node.area.max_y() - layout_node.inner_area.max_y();
+            }
+        }
+        Some(end)
+    }
+
+    let rdom = fdom.rdom();
+    let layout = fdom.layout();
+    let root = rdom.get(rdom.root_id()).unwrap();
+    let end = root
+        .children()
+        .iter()
+        .filter_map(|child| content_end(child, &layout))
+        .fold(Point2D::zero(), Point2D::max);
+
+    Size2D::new(end.x, end.y)
+}
@@ -42,6 +42,107 @@ pub struct NodeState {
     pub transform: TransformState,
     pub accessibility: AccessibilityNodeState,
     pub svg: SvgState,
+    pub backdrop: Color,
+}
+
+/// Final style of a Node, once the inherited and default values are resolved.
+#[derive(Clone, Debug, PartialEq)]
+pub struct ComputedStyle {
+    pub font_style: FontStyleState,
+    pub style: StyleState,
+    /// Color painted behind the Node, see [`get_backdrop`].
+    pub backdrop: Color,
+}
+
+impl Default for ComputedStyle {
+    fn default() -> Self {
+        Self {
+            font_style: FontStyleState::default(),
+            style: StyleState::default(),
+            backdrop: Color::TRANSPARENT,
+        }
+    }
+}
+
+impl ComputedStyle {
+    /// Relative luminance of the backdrop, from `0.0` (black) to `1.0` (white).
+    pub fn backdrop_luminance(&self) -> f32 {
+        let channel = |value: u8| {
+            let value = value as f32 / 255.;
+            if value <= 0.04045 {
+                value / 12.92
+            } else {
+                ((value + 0.055) / 1.055).powf(2.4)
+            }
+        };
+        0.2126 * channel(self.backdrop.r())
+            + 0.7152 * channel(self.backdrop.g())
+            + 0.0722 * channel(self.backdrop.b())
+    }
+
+    /// Check whether the backdrop is dark, so light content should be painted over it.
+    pub fn is_backdrop_dark(&self) -> bool {
+        self.backdrop_luminance() < 0.5
+    }
+}
+
+pub fn get_computed_style(node: &DioxusNode) -> ComputedStyle {
+    let font_style = node
+        .get::<FontStyleState>()
+        .as_deref()
+        .cloned()
+        .unwrap_or_default();
+    let style = node
+        .get::<StyleState>()
+        .as_deref()
+        .cloned()
+        .unwrap_or_default();
+
+    ComputedStyle {
+        font_style,
+        style,
+        backdrop: get_backdrop(node),
+    }
+}
+
+/// Color painted behind a Node, its background blended over the backgrounds of its ancestors.
+///
+/// Gradients are ignored, and it's transparent if there is no background at all.
+pub fn get_backdrop(node: &DioxusNode) -> Color {
+    let mut backgrounds = Vec::new();
+    let mut current = Some(*node);
+    while let Some(node) = current {
+        if let Some(Fill::Color(color)) = node
+            .get::<StyleState>()
+            .map(|style| style.background.clone())
+        {
+            if color.a() > 0 {
+                backgrounds.push(color);
+                if color.a() == u8::MAX {
+                    break;
+                }
+            }
+        }
+        current = node.parent();
+    }
+
+    backgrounds
+        .into_iter()
+        .rev()
+        .fold(Color::TRANSPARENT, |behind, color| {
+            let alpha = color.a() as f32 / 255.;
+            let behind_alpha = behind.a() as f32 / 255. * (1. - alpha);
+            let out_alpha = alpha + behind_alpha;
+            let blend = |front: u8, back: u8| {
+                ((front as f32 * alpha + back as f32 * behind_alpha) / out_alpha).round() as u8
+            };
+            Color::from_argb(
+                (out_alpha * 255.).round() as u8,
+                blend(color.r(), behind.r()),
+                blend(color.g(), behind.g()),
+                blend(color.b(), behind.b()),
+            )
+        })
 }
 
 pub fn get_node_state(node: &DioxusNode) -> NodeState {
@@ -89,6 +190,7 @@ pub fn get_node_state(node: &DioxusNode) -> NodeState {
         transform,
         accessibility,
         svg,
+        backdrop: get_backdrop(node),
     }
 }
 
@@ -121,6 +223,16 @@ impl NodeState {
                 "cross_alignment",
                 AttributeType::Alignment(&self.size.cross_alignment),
             ),
+            (
+                "align_self",
+                AttributeType::Text(
+                    self.size
+                        .align_self
+                        .as_ref()
+                        .map(Alignment::pretty)
+                        .unwrap_or_else(|| "auto".to_string()),
+                ),
+            ),
             {
                 let background = &self.style.background;
                 let fill = match *background {
@@ -131,6 +243,7 @@ impl NodeState {
                 };
                 ("background", fill)
             },
+            ("backdrop", AttributeType::Color(self.backdrop.into())),
             (
                 "corner_radius",
                 AttributeType::CornerRadius(self.style.corner_radius),
@@ -156,9 +269,73 @@ impl NodeState {
                 "text_overflow",
                 AttributeType::TextOverflow(&self.font_style.text_overflow),
             ),
+            (
+                "text_wrap",
+                AttributeType::Text(self.font_style.text_wrap.pretty()),
+            ),
+            (
+                "text_direction",
+                AttributeType::Text(
+                    if self.font_style.is_rtl() {
+                        "rtl"
+                    } else {
+                        "ltr"
+                    }
+                    .to_string(),
+                ),
+            ),
             ("offset_x", AttributeType::Measure(self.size.offset_x.get())),
             ("offset_y", AttributeType::Measure(self.size.offset_y.get())),
             ("content", AttributeType::Content(&self.size.content)),
+            (
+                "wrap_content",
+                AttributeType::Text(self.size.wrap_content.pretty()),
+            ),
+            (
+                "flex_grow",
+                AttributeType::Measure(self.size.flex_grow.get()),
+            ),
+            (
+                "flex_shrink",
+                AttributeType::Measure(self.size.flex_shrink.get()),
+            ),
+            ("flex_basis", AttributeType::Size(&self.size.flex_basis)),
+            (
+                "grid_template_columns",
+                AttributeType::Text(
+                    self.size
+                        .grid_columns
+                        .iter()
+                        .map(|track| track.pretty())
+                        .collect::<Vec<_>>()
+                        .join(" "),
+                ),
+            ),
+            (
+                "grid_template_rows",
+                AttributeType::Text(
+                    self.size
+                        .grid_rows
+                        .iter()
+                        .map(|track| track.pretty())
+                        .collect::<Vec<_>>()
+                        .join(" "),
+                ),
+            ),
+            (
+                "grid_column",
+                AttributeType::Text(self.size.grid_column.pretty()),
+            ),
+            ("grid_row", AttributeType::Text(self.size.grid_row.pretty())),
+            (
+                "aspect_ratio",
+                AttributeType::Text(
+                    self.size
+                        .aspect_ratio
+                        .map(|ratio| ratio.to_string())
+                        .unwrap_or_else(|| "auto".to_string()),
+                ),
+            ),
             (
                 "svg_fill",
                 AttributeType::OptionalColor(self.svg.svg_fill.and_then(|fill| match fill {
@@ -0,0 +1,44 @@
+use std::fmt;
+
+use tokio::sync::oneshot;
+
+/// Image stored in the clipboard, with its pixels in the RGBA8 format.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ClipboardImage {
+    pub width: usize,
+    pub height: usize,
+    pub bytes: Vec<u8>,
+}
+
+/// Error accessing the system clipboard.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ClipboardError {
+    /// The clipboard has no content of the requested kind.
+    Empty,
+    /// The clipboard is not supported or could not be accessed.
+    Unavailable(String),
+}
+
+impl fmt::Display for ClipboardError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Empty => f.write_str("The clipboard is empty"),
+            Self::Unavailable(err) => write!(f, "The clipboard is unavailable: {err}"),
+        }
+    }
+}
+
+impl std::error::Error for ClipboardError {}
+
+/// Access to the system clipboard, processed in the event loop thread.
+#[derive(Debug)]
+pub enum ClipboardRequest {
+    /// Read the text of the clipboard.
+    GetText(oneshot::Sender<Result<String, ClipboardError>>),
+    /// Replace the content of the clipboard with the given text.
+    SetText(String, oneshot::Sender<Result<(), ClipboardError>>),
+    /// Read the image of the clipboard.
+    GetImage(oneshot::Sender<Result<ClipboardImage, ClipboardError>>),
+    /// Replace the content of the clipboard with the given image.
+    SetImage(ClipboardImage, oneshot::Sender<Result<(), ClipboardError>>),
+}
@@ -1,12 +1,22 @@
+mod clipboard;
 mod keyboard;
+#[cfg(feature = "menu")]
+mod menu;
 mod mouse;
 mod pointer;
 mod touch;
+#[cfg(feature = "tray")]
+mod tray;
 mod window;
 
+pub use clipboard::*;
 pub use keyboard::*;
+#[cfg(feature = "menu")]
+pub use menu::*;
 pub use mouse::*;
 pub use pointer::*;
 #[allow(unused_imports)]
 pub use touch::*;
+#[cfg(feature = "tray")]
+pub use tray::*;
 pub use window::*;
@@ -0,0 +1,14 @@
+pub use tray_icon;
+use tray_icon::{
+    menu::MenuEvent,
+    TrayIconEvent,
+};
+
+/// Event of the tray icon of the app.
+#[derive(Debug, Clone)]
+pub enum TrayEvent {
+    /// The icon was clicked, hovered...
+    Icon(TrayIconEvent),
+    /// An item of the tray menu was clicked.
+    Menu(MenuEvent),
+}
@@ -3,7 +3,9 @@ pub use winit::{
     window::{
         CursorIcon,
         Fullscreen,
+        ResizeDirection,
         Window,
         WindowAttributes,
+        WindowId,
     },
 };
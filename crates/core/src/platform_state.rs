@@ -1,3 +1,5 @@
+use std::time::Duration;
+
 use accesskit::{
     Node as AccessibilityNode,
     NodeId as AccessibilityId,
@@ -10,10 +12,13 @@ use winit::window::Window;
 pub struct NativePlatformState {
     pub focused_accessibility_id: AccessibilityId,
     pub focused_accessibility_node: AccessibilityNode,
+    pub focus_history: Vec<AccessibilityId>,
     pub preferred_theme: PreferredTheme,
     pub navigation_mode: NavigationMode,
     pub information: PlatformInformation,
     pub scale_factor: f64,
+    pub text_scale_factor: f32,
+    pub layout_direction: LayoutDirection,
 }
 
 #[derive(Clone, Copy, Debug, PartialEq, Eq, Default)]
@@ -35,6 +40,36 @@ impl From<winit::window::Theme> for PreferredTheme {
     }
 }
 
+/// Power event of the system or the display, see `use_power_events`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum PowerEvent {
+    /// The app is about to be suspended by the system.
+    Suspended,
+    /// The app resumed after the system was suspended or asleep, with how long it slept when known.
+    Resumed { slept: Option<Duration> },
+    /// The window stopped being visible, e.g. the display was turned off or the window was minimized.
+    DisplayOff,
+    /// The window is visible again.
+    DisplayOn,
+}
+
+/// Horizontal direction of the layout.
+#[derive(Clone, Copy, PartialEq, Eq, Default, Debug)]
+pub enum LayoutDirection {
+    /// Left-to-right.
+    #[default]
+    Ltr,
+
+    /// Right-to-left, the horizontal layout is mirrored.
+    Rtl,
+}
+
+impl LayoutDirection {
+    pub fn is_rtl(&self) -> bool {
+        *self == Self::Rtl
+    }
+}
+
 #[derive(Clone, Copy, PartialEq, Eq, Default, Debug)]
 pub enum NavigationMode {
     #[default]
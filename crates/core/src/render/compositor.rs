@@ -6,6 +6,7 @@ use std::{
     },
 };
 
+use freya_engine::prelude::Image;
 use freya_native_core::{
     exports::shipyard::{
         IntoIter,
@@ -18,6 +19,7 @@ use rustc_hash::FxHashMap;
 use torin::prelude::{
     Area,
     LayoutNode,
+    Size2D,
     Torin,
 };
 
@@ -65,41 +67,109 @@ impl DerefMut for CompositorCache {
     }
 }
 
+/// Image of an element and its children, rasterized with `cache_layer`.
+#[derive(Clone, Debug)]
+pub struct CachedLayer {
+    pub size: Size2D,
+    pub image: Image,
+}
+
+/// Cached layers of the elements with `cache_layer` enabled, reused until their content changes.
 #[derive(Clone, Default, Debug)]
-pub struct CompositorDirtyArea(Option<Area>);
+pub struct LayerCache(FxHashMap<NodeId, CachedLayer>);
+
+impl Deref for LayerCache {
+    type Target = FxHashMap<NodeId, CachedLayer>;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl DerefMut for LayerCache {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.0
+    }
+}
+
+impl LayerCache {
+    /// Drop the cached layer that contains the given node, if any.
+    pub fn invalidate(&mut self, node_id: NodeId, rdom: &DioxusDOM) {
+        let cached_layer = rdom.get(node_id).and_then(|node| {
+            // Text nodes are painted by their parent element
+            let node = if node.node_type().is_text() {
+                node.parent()?
+            } else {
+                node
+            };
+            node.get::<LayerState>()?.cached_layer
+        });
+
+        // Removed nodes are no longer in the DOM, so they are dropped directly
+        self.0.remove(&cached_layer.unwrap_or(node_id));
+    }
+}
+
+/// Separate dirty areas kept before uniting them all into one.
+const MAX_DIRTY_AREAS: usize = 8;
+
+/// Areas of the canvas that must be painted again.
+///
+/// Overlapping areas are united, but separate ones are kept apart so the space between them is not painted again.
+/// If there are too many of them they are all united into one.
+#[derive(Clone, Default, Debug)]
+pub struct CompositorDirtyArea(Vec<Area>);
 
 impl CompositorDirtyArea {
-    /// Take the area, leaving nothing behind.
-    pub fn take(&mut self) -> Option<Area> {
-        self.0.take()
+    /// Take the areas, leaving nothing behind.
+    pub fn take(&mut self) -> Vec<Area> {
+        std::mem::take(&mut self.0)
     }
 
-    /// Unite the area or insert it if none is yet present.
+    /// Unite the area with the areas it overlaps, or insert it separately otherwise.
     pub fn unite_or_insert(&mut self, other: &Area) {
-        if let Some(dirty_area) = &mut self.0 {
-            *dirty_area = dirty_area.union(other);
-        } else {
-            self.0 = Some(*other);
+        if other.is_empty() {
+            return;
+        }
+
+        let mut area = *other;
+        while let Some(i) = self
+            .0
+            .iter()
+            .position(|dirty_area| dirty_area.intersects(&area))
+        {
+            area = area.union(&self.0.swap_remove(i));
         }
+        self.0.push(area);
+
+        if self.0.len() > MAX_DIRTY_AREAS {
+            self.0 = self.bounds().into_iter().collect();
+        }
+    }
+
+    /// Get the area that contains all the dirty areas.
+    pub fn bounds(&self) -> Option<Area> {
+        self.0
+            .iter()
+            .copied()
+            .reduce(|bounds, dirty_area| bounds.union(&dirty_area))
     }
 
-    /// Round the dirty area to the out bounds to prevent float pixel issues.
+    /// Round the dirty areas to the out bounds to prevent float pixel issues.
     pub fn round_out(&mut self) {
-        if let Some(dirty_area) = &mut self.0 {
+        for dirty_area in &mut self.0 {
             *dirty_area = dirty_area.round_out();
         }
     }
 
-    /// Checks if the area (in case of being any) interesects with another area.
+    /// Checks if any of the areas interesects with another area.
     pub fn intersects(&self, other: &Area) -> bool {
-        self.0
-            .map(|dirty_area| dirty_area.intersects(other))
-            .unwrap_or_default()
+        self.0.iter().any(|dirty_area| dirty_area.intersects(other))
     }
 }
 
 impl Deref for CompositorDirtyArea {
-    type Target = Option<Area>;
+    type Target = [Area];
 
     fn deref(&self) -> &Self::Target {
         &self.0
@@ -109,11 +179,15 @@ impl Deref for CompositorDirtyArea {
 #[derive(Debug)]
 pub struct Compositor {
     full_render: bool,
+    pub(crate) layer_cache: LayerCache,
 }
 
 impl Default for Compositor {
     fn default() -> Self {
-        Self { full_render: true }
+        Self {
+            full_render: true,
+            layer_cache: LayerCache::default(),
+        }
     }
 }
 
@@ -318,6 +392,7 @@ impl Compositor {
     /// Reset the compositor, thus causing a full render in the next frame.
     pub fn reset(&mut self) {
         self.full_render = true;
+        self.layer_cache.clear();
     }
 }
 
@@ -326,12 +401,16 @@ mod test {
     use freya::{
         core::{
             layers::Layers,
-            render::Compositor,
+            render::{
+                Compositor,
+                CompositorDirtyArea,
+            },
         },
         prelude::*,
     };
     use freya_testing::prelude::*;
     use itertools::sorted;
+    use torin::prelude::Area;
 
     fn run_compositor(
         utils: &TestingHandler<()>,
@@ -768,4 +847,36 @@ mod test {
         let (_, _, painted_nodes) = run_compositor(&utils, &mut compositor);
         assert_eq!(painted_nodes, 5);
     }
+
+    #[test]
+    pub fn separate_dirty_areas() {
+        let mut dirty_area = CompositorDirtyArea::default();
+
+        dirty_area.unite_or_insert(&Area::new((0., 0.).into(), (50., 50.).into()));
+        dirty_area.unite_or_insert(&Area::new((200., 200.).into(), (50., 50.).into()));
+
+        // Separate areas are kept apart
+        assert_eq!(dirty_area.len(), 2);
+        assert!(!dirty_area.intersects(&Area::new((100., 100.).into(), (50., 50.).into())));
+
+        // Overlapping areas are united
+        dirty_area.unite_or_insert(&Area::new((25., 25.).into(), (50., 50.).into()));
+        assert_eq!(
+            &*dirty_area,
+            &[
+                Area::new((200., 200.).into(), (50., 50.).into()),
+                Area::new((0., 0.).into(), (75., 75.).into())
+            ]
+        );
+
+        // Too many areas are united into one
+        for i in 0..7 {
+            let offset = 100. * i as f32;
+            dirty_area.unite_or_insert(&Area::new((offset, 500.).into(), (10., 10.).into()));
+        }
+        assert_eq!(
+            &*dirty_area,
+            &[Area::new((0., 0.).into(), (610., 510.).into())]
+        );
+    }
 }
@@ -0,0 +1,12 @@
+/// Counters of the work done in the last frame, useful to profile apps.
+#[derive(Clone, Copy, Default, Debug, PartialEq)]
+pub struct FrameCounters {
+    /// Elements painted in the last render.
+    pub painted_nodes: usize,
+    /// Elements skipped in the last render because they were out of the window or of their viewports.
+    pub culled_nodes: usize,
+    /// Elements checked against the cursor when processing the last events.
+    pub hit_tested_nodes: usize,
+    /// Elements skipped when processing the last events because the cursor was out of them.
+    pub culled_hit_tested_nodes: usize,
+}
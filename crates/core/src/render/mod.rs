@@ -1,10 +1,14 @@
 pub mod compositor;
+pub mod frame_counters;
 pub mod pipeline;
 pub mod skia_measurer;
+pub mod texture_atlas;
 pub mod utils;
 mod wireframe_renderer;
 
 pub use compositor::*;
+pub use frame_counters::*;
 pub use pipeline::*;
 pub use skia_measurer::*;
+pub use texture_atlas::*;
 pub use utils::*;
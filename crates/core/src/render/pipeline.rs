@@ -1,10 +1,12 @@
 use freya_engine::prelude::{
+    Canvas,
     ClipOp,
     Color,
     FontCollection,
     FontMgr,
     Matrix,
     Paint,
+    Path,
     Point,
     Rect,
     SamplingOptions,
@@ -29,9 +31,11 @@ use torin::prelude::{
 
 use super::{
     wireframe_renderer,
+    CachedLayer,
     Compositor,
     CompositorCache,
     CompositorDirtyArea,
+    FrameCounters,
 };
 use crate::{
     dom::{
@@ -46,6 +50,7 @@ use crate::{
     },
     layers::Layers,
     states::{
+        LayerState,
         TransformState,
         ViewportState,
     },
@@ -70,12 +75,18 @@ pub struct RenderPipeline<'a> {
     pub scale_factor: f32,
     pub selected_node: Option<NodeId>,
     pub default_fonts: &'a [String],
+    pub frame_counters: &'a mut FrameCounters,
 }
 
 impl RenderPipeline<'_> {
     pub fn run(&mut self) {
         let mut dirty_layers = Layers::default();
 
+        // Drop the cached layers whose content changed
+        for node_id in self.compositor_dirty_nodes.take_invalidated() {
+            self.compositor.layer_cache.invalidate(node_id, self.rdom);
+        }
+
         // Process what nodes need to be rendered
         let rendering_layers = self.compositor.run(
             self.compositor_dirty_nodes,
@@ -91,7 +102,7 @@ impl RenderPipeline<'_> {
         #[cfg(feature = "fade-cached-incremental-areas")]
         {
             // Slowly fade into white non-rerendered areas
-            if self.compositor_dirty_area.is_some() {
+            if !self.compositor_dirty_area.is_empty() {
                 use freya_engine::prelude::{
                     Paint,
                     PaintStyle,
@@ -116,51 +127,97 @@ impl RenderPipeline<'_> {
         self.compositor_dirty_area.round_out();
 
         // Clear using the the background only, but only the dirty
-        // area in which it will render the intersected nodes again
-        if let Some(dirty_area) = self.compositor_dirty_area.take() {
+        // areas in which it will render the intersected nodes again
+        let dirty_areas = self.compositor_dirty_area.take();
+        if !dirty_areas.is_empty() {
             #[cfg(debug_assertions)]
-            tracing::info!("Marked {dirty_area:?} as dirty area");
+            tracing::info!("Marked {dirty_areas:?} as dirty areas");
 
-            self.dirty_surface.canvas().clip_rect(
+            let mut dirty_rects = dirty_areas.iter().map(|dirty_area| {
                 Rect::new(
                     dirty_area.min_x(),
                     dirty_area.min_y(),
                     dirty_area.max_x(),
                     dirty_area.max_y(),
-                ),
-                ClipOp::Intersect,
-                false,
-            );
+                )
+            });
+
+            if dirty_areas.len() == 1 {
+                self.dirty_surface.canvas().clip_rect(
+                    dirty_rects.next().unwrap(),
+                    ClipOp::Intersect,
+                    false,
+                );
+            } else {
+                // Leave the space between the separate areas untouched
+                let mut path = Path::new();
+                for dirty_rect in dirty_rects {
+                    path.add_rect(dirty_rect, None);
+                }
+                self.dirty_surface
+                    .canvas()
+                    .clip_path(&path, ClipOp::Intersect, false);
+            }
             self.dirty_surface.canvas().clear(self.background);
         }
 
-        #[cfg(debug_assertions)]
-        // Counter of painted nodes for debugging purposes
-        let mut painted = 0;
+        self.frame_counters.painted_nodes = 0;
+        self.frame_counters.culled_nodes = 0;
 
         // Render the dirty nodes
         for (_, nodes) in sorted(rendering_layers.iter()) {
             'elements: for node_id in sorted(nodes) {
                 let node_ref = self.rdom.get(*node_id).unwrap();
                 let node_viewports = node_ref.get::<ViewportState>().unwrap();
+                let layer_state = node_ref.get::<LayerState>().unwrap();
+                let cached_layer = layer_state.cached_layer;
                 let layout_node = self.layout.get(*node_id);
 
+                // Skip elements rendered in the window of a portal
+                if layer_state.window_portal.is_some() {
+                    continue;
+                }
+
+                // Skip hidden elements
+                if layer_state.offscreen.is_some() {
+                    continue;
+                }
+
+                // Skip elements painted in the cached layer of an ancestor
+                if cached_layer.is_some_and(|cached_layer| cached_layer != *node_id) {
+                    continue;
+                }
+
                 if let Some(layout_node) = layout_node {
+                    // Skip elements that are completely out of the window, including their shadows, borders and transforms
+                    let drawing_area = Compositor::get_drawing_area(
+                        *node_id,
+                        self.layout,
+                        self.rdom,
+                        self.scale_factor,
+                    );
+                    if drawing_area.is_some_and(|area| !self.canvas_area.intersects(&area)) {
+                        self.frame_counters.culled_nodes += 1;
+                        continue;
+                    }
+
                     // Skip elements that are completely out of any their parent's viewport
                     for viewport_id in &node_viewports.viewports {
                         let viewport = self.layout.get(*viewport_id).unwrap().visible_area();
                         if !viewport.intersects(&layout_node.area) {
+                            self.frame_counters.culled_nodes += 1;
                             continue 'elements;
                         }
                     }
 
                     // Render the element
-                    self.render(node_ref, layout_node);
-
-                    #[cfg(debug_assertions)]
-                    {
-                        painted += 1;
+                    if cached_layer.is_some() {
+                        self.render_cached_layer(node_ref, layout_node);
+                    } else {
+                        self.render(node_ref, layout_node);
                     }
+
+                    self.frame_counters.painted_nodes += 1;
                 }
             }
         }
@@ -176,8 +233,12 @@ impl RenderPipeline<'_> {
 
         #[cfg(debug_assertions)]
         {
-            if painted > 0 {
-                tracing::info!("Painted {painted} nodes");
+            if self.frame_counters.painted_nodes > 0 {
+                tracing::info!(
+                    "Painted {} nodes, culled {} nodes",
+                    self.frame_counters.painted_nodes,
+                    self.frame_counters.culled_nodes
+                );
             }
         }
 
@@ -192,10 +253,217 @@ impl RenderPipeline<'_> {
         );
 
         self.compositor_dirty_nodes.clear();
+
+        // Pack the small images drawn in this frame so the next ones can reuse the shared textures
+        self.images_cache.atlas().flush(Some(&mut *self.surface));
+    }
+
+    /// Render the elements of a portal in the surface of its own window, relative to the area of the portal element.
+    ///
+    /// The clipping and effects of the ancestors of the portal are not applied.
+    pub fn run_window_portal(&mut self, portal_id: NodeId) {
+        let Some(portal_ref) = self.rdom.get(portal_id) else {
+            return;
+        };
+        let Some(portal_layout) = self.layout.get(portal_id) else {
+            return;
+        };
+        let area = portal_layout.area;
+        let inherited_effects = InheritedEffects::of(&portal_ref, self.rdom);
+        let nodes = self.contained_nodes(portal_id, &portal_ref, area, |layer_state| {
+            layer_state.window_portal
+        });
+
+        self.dirty_surface.canvas().save();
+        self.dirty_surface.canvas().clear(Color::TRANSPARENT);
+        self.dirty_surface
+            .canvas()
+            .translate((-area.min_x(), -area.min_y()));
+
+        // The atlas pages are created from the surface of the main window,
+        // which might belong to another GPU context than this window
+        self.images_cache.set_bypass_atlas(true);
+        for node_id in nodes {
+            let node_ref = self.rdom.get(node_id).unwrap();
+            let layout_node = self.layout.get(node_id).unwrap();
+            self.render_element(None, node_ref, layout_node, inherited_effects);
+        }
+        self.images_cache.set_bypass_atlas(false);
+
+        self.dirty_surface.canvas().restore();
+        self.surface.canvas().clear(Color::TRANSPARENT);
+        self.dirty_surface.draw(
+            self.surface.canvas(),
+            (0, 0),
+            SamplingOptions::default(),
+            None,
+        );
     }
 
     pub fn render(&mut self, node_ref: DioxusNode, layout_node: &LayoutNode) {
+        self.render_element(None, node_ref, layout_node, InheritedEffects::default());
+    }
+
+    /// Paint an element and its children with their cached layer, rasterizing it first if needed.
+    fn render_cached_layer(&mut self, node_ref: DioxusNode, layout_node: &LayoutNode) {
+        let node_id = node_ref.id();
+        let area = layout_node.area;
+        let size = area.size.ceil();
+        let inherited_effects = InheritedEffects::of(&node_ref, self.rdom);
+
+        let is_cached = self
+            .compositor
+            .layer_cache
+            .get(&node_id)
+            .is_some_and(|cached_layer| cached_layer.size == size);
+
+        if !is_cached {
+            let nodes = self.contained_nodes(node_id, &node_ref, area, |layer_state| {
+                // Portals are rendered in their own window
+                layer_state
+                    .cached_layer
+                    .filter(|_| layer_state.window_portal.is_none())
+            });
+            let layer_surface = if size.width >= 1. && size.height >= 1. {
+                self.dirty_surface
+                    .new_surface_with_dimensions((size.width as i32, size.height as i32))
+            } else {
+                None
+            };
+
+            let Some(mut layer_surface) = layer_surface else {
+                // Paint them directly if the layer can't be created
+                for node_id in nodes {
+                    let node_ref = self.rdom.get(node_id).unwrap();
+                    let layout_node = self.layout.get(node_id).unwrap();
+                    self.render(node_ref, layout_node);
+                }
+                return;
+            };
+
+            layer_surface.canvas().clear(Color::TRANSPARENT);
+            layer_surface
+                .canvas()
+                .translate((-area.min_x(), -area.min_y()));
+
+            for node_id in nodes {
+                let node_ref = self.rdom.get(node_id).unwrap();
+                let layout_node = self.layout.get(node_id).unwrap();
+                self.render_element(
+                    Some(&mut layer_surface),
+                    node_ref,
+                    layout_node,
+                    inherited_effects,
+                );
+            }
+
+            self.compositor.layer_cache.insert(
+                node_id,
+                CachedLayer {
+                    size,
+                    image: layer_surface.image_snapshot(),
+                },
+            );
+        }
+
         let dirty_canvas = self.dirty_surface.canvas();
+        let initial_layer = dirty_canvas.save();
+        let node_viewports = node_ref.get::<ViewportState>().unwrap();
+        let node_transform = node_ref.get::<TransformState>().unwrap();
+        let rect = Rect::new(area.min_x(), area.min_y(), area.max_x(), area.max_y());
+
+        // Only apply the effects of the ancestors, the rest are already part of the layer
+        apply_inherited_effects(
+            dirty_canvas,
+            self.rdom,
+            self.layout,
+            self.scale_factor,
+            &node_viewports.viewports,
+            &node_transform.scales[..inherited_effects.scales],
+            &node_transform.rotations[..inherited_effects.rotations],
+        );
+
+        for opacity in &node_transform.opacities[..inherited_effects.opacities] {
+            dirty_canvas.save_layer_alpha_f(rect, *opacity);
+        }
+
+        let cached_layer = &self.compositor.layer_cache[&node_id];
+        dirty_canvas.draw_image_rect(
+            &cached_layer.image,
+            None,
+            Rect::new(
+                area.min_x(),
+                area.min_y(),
+                area.min_x() + size.width,
+                area.min_y() + size.height,
+            ),
+            &Paint::default(),
+        );
+
+        dirty_canvas.restore_to_count(initial_layer);
+    }
+
+    /// Get the elements painted in the cached layer or portal of the given element, in the order they must be painted.
+    ///
+    /// `container` returns the element containing each node, only the ones contained by the given element are painted.
+    fn contained_nodes(
+        &self,
+        node_id: NodeId,
+        node_ref: &DioxusNode,
+        area: Area,
+        container: impl Fn(&LayerState) -> Option<NodeId>,
+    ) -> Vec<NodeId> {
+        let inherited_viewports = node_ref.get::<ViewportState>().unwrap().viewports.len();
+        let mut nodes = Vec::new();
+        let mut stack = vec![node_id];
+
+        while let Some(child_id) = stack.pop() {
+            let child = self.rdom.get(child_id).unwrap();
+            let Some(layer_state) = child.get::<LayerState>() else {
+                continue;
+            };
+            if container(&layer_state) != Some(node_id) || layer_state.offscreen.is_some() {
+                continue;
+            }
+            stack.extend(child.child_ids());
+
+            let Some(layout_node) = self.layout.get(child_id) else {
+                continue;
+            };
+
+            // Skip elements that are out of the layer or out of their parent's viewports inside of it
+            let child_viewports = child.get::<ViewportState>().unwrap();
+            let is_visible = area.intersects(&layout_node.area)
+                && child_viewports.viewports[inherited_viewports..]
+                    .iter()
+                    .all(|viewport_id| {
+                        let viewport = self.layout.get(*viewport_id).unwrap().visible_area();
+                        viewport.intersects(&layout_node.area)
+                    });
+
+            if is_visible {
+                nodes.push((layer_state.layer, child_id));
+            }
+        }
+
+        nodes.sort();
+        nodes.into_iter().map(|(_, node_id)| node_id).collect()
+    }
+
+    /// Render an element, either in the dirty surface or in a cached layer.
+    ///
+    /// The given inherited effects are skipped, as these are applied when painting the cached layer.
+    fn render_element(
+        &mut self,
+        layer_surface: Option<&mut Surface>,
+        node_ref: DioxusNode,
+        layout_node: &LayoutNode,
+        inherited_effects: InheritedEffects,
+    ) {
+        let dirty_canvas = match layer_surface {
+            Some(layer_surface) => layer_surface.canvas(),
+            None => self.dirty_surface.canvas(),
+        };
         let node_type = &*node_ref.node_type();
         if let NodeType::Element(ElementNode { tag, .. }) = node_type {
             let Some(element_utils) = tag.utils() else {
@@ -210,40 +478,15 @@ impl RenderPipeline<'_> {
             let node_transform = &*node_ref.get::<TransformState>().unwrap();
             let node_viewports = node_ref.get::<ViewportState>().unwrap();
 
-            for node_id in &node_viewports.viewports {
-                let node_ref = self.rdom.get(*node_id).unwrap();
-                let node_type = node_ref.node_type();
-                let Some(element_utils) = node_type.tag().and_then(|tag| tag.utils()) else {
-                    continue;
-                };
-                let layout_node = self.layout.get(*node_id).unwrap();
-                element_utils.clip(layout_node, &node_ref, dirty_canvas, self.scale_factor);
-            }
-
-            // Apply inherited scale effects
-            for (id, scale_x, scale_y) in &node_transform.scales {
-                let layout_node = self.layout.get(*id).unwrap();
-                let area = layout_node.visible_area();
-                let center = area.center();
-                dirty_canvas.translate((center.x, center.y));
-                dirty_canvas.scale((*scale_x, *scale_y));
-                dirty_canvas.translate((-center.x, -center.y));
-            }
-
-            // Pass rotate effect to children
-            for (id, rotate_degs) in &node_transform.rotations {
-                let layout_node = self.layout.get(*id).unwrap();
-                let area = layout_node.visible_area();
-                let mut matrix = Matrix::new_identity();
-                matrix.set_rotate(
-                    *rotate_degs,
-                    Some(Point {
-                        x: area.min_x() + area.width() / 2.0,
-                        y: area.min_y() + area.height() / 2.0,
-                    }),
-                );
-                dirty_canvas.concat(&matrix);
-            }
+            apply_inherited_effects(
+                dirty_canvas,
+                self.rdom,
+                self.layout,
+                self.scale_factor,
+                &node_viewports.viewports[inherited_effects.viewports..],
+                &node_transform.scales[inherited_effects.scales..],
+                &node_transform.rotations[inherited_effects.rotations..],
+            );
 
             // Apply blend mode
             if let Some(blend) = node_transform.blend_mode {
@@ -255,13 +498,17 @@ impl RenderPipeline<'_> {
             }
 
             // Apply inherited opacity effects
-            for opacity in &node_transform.opacities {
+            for opacity in &node_transform.opacities[inherited_effects.opacities..] {
                 dirty_canvas.save_layer_alpha_f(rect, *opacity);
             }
 
             // Clip the element itself if non-children content can overflow, like an image in case of `image`
-            // or text in the case of `label` or `paragraph`
-            if *tag == TagName::Paragraph || *tag == TagName::Label || *tag == TagName::Image {
+            // or text in the case of `label`, `paragraph` or `textgrid`
+            if *tag == TagName::Paragraph
+                || *tag == TagName::Label
+                || *tag == TagName::Image
+                || *tag == TagName::TextGrid
+            {
                 element_utils.clip(layout_node, &node_ref, dirty_canvas, self.scale_factor);
             }
 
@@ -280,3 +527,83 @@ impl RenderPipeline<'_> {
         }
     }
 }
+
+/// How many of the viewports and transform effects of an element come from its ancestors.
+#[derive(Default, Clone, Copy)]
+struct InheritedEffects {
+    viewports: usize,
+    opacities: usize,
+    scales: usize,
+    rotations: usize,
+}
+
+impl InheritedEffects {
+    fn of(node_ref: &DioxusNode, rdom: &DioxusDOM) -> Self {
+        let viewports = node_ref.get::<ViewportState>().unwrap().viewports.len();
+        let parent = node_ref
+            .parent_id()
+            .and_then(|parent_id| rdom.get(parent_id));
+        let Some(parent_transform) = parent
+            .as_ref()
+            .and_then(|parent| parent.get::<TransformState>())
+        else {
+            return Self {
+                viewports,
+                ..Default::default()
+            };
+        };
+
+        Self {
+            viewports,
+            opacities: parent_transform.opacities.len(),
+            scales: parent_transform.scales.len(),
+            rotations: parent_transform.rotations.len(),
+        }
+    }
+}
+
+/// Clip the canvas with the given viewports and apply the given scale and rotate effects.
+fn apply_inherited_effects(
+    canvas: &Canvas,
+    rdom: &DioxusDOM,
+    layout: &Torin<NodeId>,
+    scale_factor: f32,
+    viewports: &[NodeId],
+    scales: &[(NodeId, f32, f32)],
+    rotations: &[(NodeId, f32)],
+) {
+    for node_id in viewports {
+        let node_ref = rdom.get(*node_id).unwrap();
+        let node_type = node_ref.node_type();
+        let Some(element_utils) = node_type.tag().and_then(|tag| tag.utils()) else {
+            continue;
+        };
+        let layout_node = layout.get(*node_id).unwrap();
+        element_utils.clip(layout_node, &node_ref, canvas, scale_factor);
+    }
+
+    // Apply inherited scale effects
+    for (id, scale_x, scale_y) in scales {
+        let layout_node = layout.get(*id).unwrap();
+        let area = layout_node.visible_area();
+        let center = area.center();
+        canvas.translate((center.x, center.y));
+        canvas.scale((*scale_x, *scale_y));
+        canvas.translate((-center.x, -center.y));
+    }
+
+    // Pass rotate effect to children
+    for (id, rotate_degs) in rotations {
+        let layout_node = layout.get(*id).unwrap();
+        let area = layout_node.visible_area();
+        let mut matrix = Matrix::new_identity();
+        matrix.set_rotate(
+            *rotate_degs,
+            Some(Point {
+                x: area.min_x() + area.width() / 2.0,
+                y: area.min_y() + area.height() / 2.0,
+            }),
+        );
+        canvas.concat(&matrix);
+    }
+}
@@ -22,14 +22,23 @@ use super::{
     create_label,
     create_paragraph,
     get_or_create_image,
+    paragraph_layout_width,
     ImageData,
 };
 use crate::{
     custom_attributes::NodeReferenceLayout,
     dom::*,
-    elements::CachedParagraph,
+    elements::{
+        custom_element,
+        CachedParagraph,
+        TextGridFonts,
+    },
     render::ParagraphData,
-    states::LayoutState,
+    states::{
+        FontStyleState,
+        LayoutState,
+        TextGridState,
+    },
 };
 
 /// Provides Text measurements using Skia APIs like SkParagraph
@@ -38,7 +47,9 @@ pub struct SkiaMeasurer<'a> {
     pub rdom: &'a DioxusDOM,
     pub default_fonts: &'a [String],
     pub scale_factor: f32,
+    pub text_scale_factor: f32,
     pub images_cache: &'a mut ImagesCache,
+    pub paragraph_cache: &'a mut ParagraphCache,
 }
 
 impl<'a> SkiaMeasurer<'a> {
@@ -47,14 +58,18 @@ impl<'a> SkiaMeasurer<'a> {
         font_collection: &'a FontCollection,
         default_fonts: &'a [String],
         scale_factor: f32,
+        text_scale_factor: f32,
         images_cache: &'a mut ImagesCache,
+        paragraph_cache: &'a mut ParagraphCache,
     ) -> Self {
         Self {
             font_collection,
             rdom,
             default_fonts,
             scale_factor,
+            text_scale_factor,
             images_cache,
+            paragraph_cache,
         }
     }
 }
@@ -69,7 +84,26 @@ impl LayoutMeasurer<NodeId> for SkiaMeasurer<'_> {
         let node = self.rdom.get(node_id).unwrap();
         let node_type = node.node_type();
 
-        match &*node_type {
+        let font_scale_factor = self.scale_factor * self.text_scale_factor;
+
+        // Reuse the measurement of texts that didn't change
+        let paragraph_cache_key =
+            matches!(node_type.tag(), Some(TagName::Label | TagName::Paragraph)).then(|| {
+                let font_style = node.get::<FontStyleState>().unwrap();
+                ParagraphCacheKey::new(
+                    &node,
+                    paragraph_layout_width(&font_style, area_size),
+                    torin_node.width.inner_sized(),
+                    font_scale_factor,
+                )
+            });
+        if let Some(paragraph_cache_key) = &paragraph_cache_key {
+            if let Some(measurement) = self.paragraph_cache.get(node_id, paragraph_cache_key) {
+                return Some(measurement);
+            }
+        }
+
+        let measurement = match &*node_type {
             NodeType::Element(ElementNode { tag, .. }) if tag == &TagName::Label => {
                 let ParagraphData { paragraph, size } = create_label(
                     &node,
@@ -77,10 +111,10 @@ impl LayoutMeasurer<NodeId> for SkiaMeasurer<'_> {
                     area_size,
                     self.font_collection,
                     self.default_fonts,
-                    self.scale_factor,
+                    font_scale_factor,
                 );
                 let mut map = SendAnyMap::new();
-                map.insert(CachedParagraph(paragraph));
+                map.insert(CachedParagraph(paragraph, font_scale_factor));
                 Some((size, Arc::new(map)))
             }
             NodeType::Element(ElementNode { tag, .. }) if tag == &TagName::Paragraph => {
@@ -90,10 +124,10 @@ impl LayoutMeasurer<NodeId> for SkiaMeasurer<'_> {
                     self.font_collection,
                     false,
                     self.default_fonts,
-                    self.scale_factor,
+                    font_scale_factor,
                 );
                 let mut map = SendAnyMap::new();
-                map.insert(CachedParagraph(paragraph));
+                map.insert(CachedParagraph(paragraph, font_scale_factor));
                 Some((size, Arc::new(map)))
             }
             NodeType::Element(ElementNode { tag, .. }) if tag == &TagName::Image => {
@@ -104,8 +138,46 @@ impl LayoutMeasurer<NodeId> for SkiaMeasurer<'_> {
                 };
                 Some((size, Arc::default()))
             }
+            NodeType::Element(ElementNode { tag, .. }) if tag == &TagName::TextGrid => {
+                let font_style = node.get::<FontStyleState>().unwrap();
+                let text_grid = node.get::<TextGridState>().unwrap();
+                let (columns, rows) = text_grid
+                    .grid
+                    .as_ref()
+                    .map(|grid| (grid.columns(), grid.rows()))
+                    .unwrap_or_default();
+                let cell_size = TextGridFonts::new(
+                    self.font_collection,
+                    &font_style,
+                    self.default_fonts,
+                    self.scale_factor,
+                )
+                .map(|fonts| fonts.cell_size)
+                .unwrap_or_default();
+                let size = Size2D::new(
+                    columns as f32 * cell_size.width,
+                    rows as f32 * cell_size.height,
+                );
+                Some((size, Arc::default()))
+            }
+            NodeType::Element(ElementNode {
+                tag: TagName::Custom(tag),
+                ..
+            }) => {
+                let size = custom_element(tag)?.measure(&node, area_size, self.scale_factor)?;
+                Some((size, Arc::default()))
+            }
             _ => None,
+        };
+
+        if let Some(((size, data), paragraph_cache_key)) =
+            measurement.as_ref().zip(paragraph_cache_key)
+        {
+            self.paragraph_cache
+                .insert(node_id, paragraph_cache_key, *size, data.clone());
         }
+
+        measurement
     }
 
     fn should_measure(&mut self, node_id: NodeId) -> bool {
@@ -114,7 +186,16 @@ impl LayoutMeasurer<NodeId> for SkiaMeasurer<'_> {
 
         node_type
             .tag()
-            .map(|tag| [TagName::Image, TagName::Label, TagName::Paragraph].contains(tag))
+            .map(|tag| {
+                [
+                    TagName::Image,
+                    TagName::Label,
+                    TagName::Paragraph,
+                    TagName::TextGrid,
+                ]
+                .contains(tag)
+                    || matches!(tag, TagName::Custom(_))
+            })
             .unwrap_or_default()
     }
 
@@ -122,6 +203,14 @@ impl LayoutMeasurer<NodeId> for SkiaMeasurer<'_> {
         let node = self.rdom.get(node_id).unwrap();
         let node_type: &NodeType<_> = &node.node_type();
 
+        // The children of offscreen nodes are kept as they are until the node is shown again
+        if node
+            .get::<LayoutState>()
+            .is_some_and(|layout| layout.offscreen)
+        {
+            return false;
+        }
+
         node_type
             .tag()
             .map(|tag| tag.has_children_with_intrinsic_layout())
@@ -0,0 +1,290 @@
+use std::hash::{
+    Hash,
+    Hasher,
+};
+
+use freya_engine::prelude::*;
+use rustc_hash::{
+    FxHashMap,
+    FxHasher,
+};
+
+/// Biggest width or height (in physical pixels) of the images that get stored in the atlas.
+pub const ATLAS_MAX_ITEM_SIZE: f32 = 128.0;
+
+/// Size of every page of the atlas.
+const ATLAS_PAGE_SIZE: i32 = 1024;
+
+/// Maximum amount of pages, the least recently used page gets evicted when all are full.
+const ATLAS_MAX_PAGES: usize = 4;
+
+/// Empty space around every item so they don't bleed into each other when sampled.
+const ATLAS_ITEM_PADDING: i32 = 1;
+
+/// Identifies an image stored in the [`TextureAtlas`].
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug)]
+pub struct AtlasKey(u64);
+
+impl AtlasKey {
+    pub fn new(value: impl Hash) -> Self {
+        let mut hasher = FxHasher::default();
+        value.hash(&mut hasher);
+        Self(hasher.finish())
+    }
+}
+
+#[derive(Debug)]
+struct AtlasEntry {
+    /// Image it was made from, e.g. a cached image that was resized.
+    source: Option<AtlasKey>,
+    page: usize,
+    x: i32,
+    y: i32,
+    width: i32,
+    height: i32,
+}
+
+#[derive(Debug)]
+struct Shelf {
+    y: i32,
+    height: i32,
+    used_width: i32,
+}
+
+#[derive(Debug, Default)]
+struct AtlasPage {
+    image: Option<Image>,
+    shelves: Vec<Shelf>,
+    last_used: u64,
+}
+
+impl AtlasPage {
+    /// Find a free spot for the given size, filling rows (shelves) of similar heights.
+    fn allocate(&mut self, width: i32, height: i32) -> Option<(i32, i32)> {
+        let width = width + ATLAS_ITEM_PADDING;
+        let height = height + ATLAS_ITEM_PADDING;
+
+        for shelf in &mut self.shelves {
+            let fits_height = height <= shelf.height && shelf.height <= height * 2;
+            if fits_height && shelf.used_width + width <= ATLAS_PAGE_SIZE {
+                let x = shelf.used_width;
+                shelf.used_width += width;
+                return Some((x, shelf.y));
+            }
+        }
+
+        let y = self
+            .shelves
+            .last()
+            .map(|shelf| shelf.y + shelf.height)
+            .unwrap_or_default();
+        if y + height <= ATLAS_PAGE_SIZE && width <= ATLAS_PAGE_SIZE {
+            self.shelves.push(Shelf {
+                y,
+                height,
+                used_width: width,
+            });
+            Some((0, y))
+        } else {
+            None
+        }
+    }
+}
+
+/// Packs small images (e.g icons or rasterized SVGs) into a few shared textures,
+/// so drawing many of them doesn't need a different texture for each one.
+///
+/// Images are queued with [`TextureAtlas::insert`] and packed once per frame in [`TextureAtlas::flush`],
+/// they can be drawn with [`TextureAtlas::draw`] from then on.
+/// Images made from another one are removed with [`TextureAtlas::remove_source`] once it changes.
+#[derive(Debug, Default)]
+pub struct TextureAtlas {
+    pages: Vec<AtlasPage>,
+    entries: FxHashMap<AtlasKey, AtlasEntry>,
+    pending: Vec<(AtlasKey, Option<AtlasKey>, Image)>,
+    frame: u64,
+}
+
+impl TextureAtlas {
+    /// Whether an image of this size is small enough to be stored.
+    pub fn fits(width: f32, height: f32) -> bool {
+        width >= 1.0
+            && height >= 1.0
+            && width <= ATLAS_MAX_ITEM_SIZE
+            && height <= ATLAS_MAX_ITEM_SIZE
+    }
+
+    /// Whether an image is stored.
+    pub fn contains(&self, key: &AtlasKey) -> bool {
+        self.entries.contains_key(key)
+    }
+
+    /// Draw a stored image into the given rect, returns `false` if there is no such image.
+    pub fn draw(&mut self, key: &AtlasKey, canvas: &Canvas, dst: Rect, paint: &Paint) -> bool {
+        let Some(entry) = self.entries.get(key) else {
+            return false;
+        };
+        let page = &mut self.pages[entry.page];
+        let Some(image) = &page.image else {
+            return false;
+        };
+        page.last_used = self.frame;
+
+        let src = Rect::new(
+            entry.x as f32,
+            entry.y as f32,
+            (entry.x + entry.width) as f32,
+            (entry.y + entry.height) as f32,
+        );
+        canvas.draw_image_rect(image, Some((&src, SrcRectConstraint::Strict)), dst, paint);
+        true
+    }
+
+    /// Queue an image to be stored in the next [`TextureAtlas::flush`].
+    pub fn insert(&mut self, key: AtlasKey, image: Image) {
+        self.queue(key, None, image);
+    }
+
+    /// Queue an image made from another one (its source) to be stored in the next flush.
+    /// It's removed along with the rest of images of its source in [`TextureAtlas::remove_source`].
+    pub fn insert_from(&mut self, source: AtlasKey, key: AtlasKey, image: Image) {
+        self.queue(key, Some(source), image);
+    }
+
+    /// Remove the images made from a source, e.g. because it changed.
+    ///
+    /// Pages left without images are emptied so their space can be used again.
+    pub fn remove_source(&mut self, source: &AtlasKey) {
+        self.pending
+            .retain(|(_, pending_source, _)| pending_source.as_ref() != Some(source));
+
+        let mut affected_pages = Vec::new();
+        self.entries.retain(|_, entry| {
+            let is_removed = entry.source.as_ref() == Some(source);
+            if is_removed {
+                affected_pages.push(entry.page);
+            }
+            !is_removed
+        });
+
+        for page in affected_pages {
+            let is_empty = !self.entries.values().any(|entry| entry.page == page);
+            if is_empty {
+                self.pages[page] = AtlasPage::default();
+            }
+        }
+    }
+
+    fn queue(&mut self, key: AtlasKey, source: Option<AtlasKey>, image: Image) {
+        let is_pending = self
+            .pending
+            .iter()
+            .any(|(pending_key, ..)| *pending_key == key);
+        if !is_pending
+            && !self.contains(&key)
+            && Self::fits(image.width() as f32, image.height() as f32)
+        {
+            self.pending.push((key, source, image));
+        }
+    }
+
+    /// Pack the queued images, evicting the least recently used page if there is no space left.
+    ///
+    /// Pages are created from the given surface, so they are GPU textures when it's backed by
+    /// the GPU, otherwise they are rasterized in the CPU.
+    pub fn flush(&mut self, mut surface: Option<&mut Surface>) {
+        self.frame += 1;
+
+        if self.pending.is_empty() {
+            return;
+        }
+
+        let mut updated_pages = FxHashMap::<usize, Vec<(AtlasKey, Image)>>::default();
+
+        for (key, source, image) in std::mem::take(&mut self.pending) {
+            let (width, height) = (image.width(), image.height());
+            let Some((page, (x, y))) = self.allocate(width, height, &updated_pages) else {
+                continue;
+            };
+            self.entries.insert(
+                key,
+                AtlasEntry {
+                    source,
+                    page,
+                    x,
+                    y,
+                    width,
+                    height,
+                },
+            );
+            updated_pages.entry(page).or_default().push((key, image));
+        }
+
+        for (page_index, images) in updated_pages {
+            let page_surface = match surface.as_deref_mut() {
+                Some(surface) => {
+                    surface.new_surface_with_dimensions((ATLAS_PAGE_SIZE, ATLAS_PAGE_SIZE))
+                }
+                None => raster_n32_premul((ATLAS_PAGE_SIZE, ATLAS_PAGE_SIZE)),
+            };
+            let Some(mut page_surface) = page_surface else {
+                continue;
+            };
+            let page = &mut self.pages[page_index];
+            let canvas = page_surface.canvas();
+            canvas.clear(Color::TRANSPARENT);
+
+            let paint = Paint::default();
+            if let Some(image) = &page.image {
+                let page_rect = Rect::new(0., 0., ATLAS_PAGE_SIZE as f32, ATLAS_PAGE_SIZE as f32);
+                canvas.draw_image_rect(image, None, page_rect, &paint);
+            }
+            for (key, image) in images {
+                let entry = &self.entries[&key];
+                let dst = Rect::new(
+                    entry.x as f32,
+                    entry.y as f32,
+                    (entry.x + entry.width) as f32,
+                    (entry.y + entry.height) as f32,
+                );
+                canvas.draw_image_rect(image, None, dst, &paint);
+            }
+
+            page.image = Some(page_surface.image_snapshot());
+            page.last_used = self.frame;
+        }
+    }
+
+    fn allocate(
+        &mut self,
+        width: i32,
+        height: i32,
+        updated_pages: &FxHashMap<usize, Vec<(AtlasKey, Image)>>,
+    ) -> Option<(usize, (i32, i32))> {
+        for (i, page) in self.pages.iter_mut().enumerate() {
+            if let Some(position) = page.allocate(width, height) {
+                return Some((i, position));
+            }
+        }
+
+        if self.pages.len() < ATLAS_MAX_PAGES {
+            let mut page = AtlasPage::default();
+            let position = page.allocate(width, height)?;
+            self.pages.push(page);
+            return Some((self.pages.len() - 1, position));
+        }
+
+        // Evict the least recently used page, as long as it wasn't just filled
+        let (evicted, _) = self
+            .pages
+            .iter()
+            .enumerate()
+            .filter(|(i, _)| !updated_pages.contains_key(i))
+            .min_by_key(|(_, page)| page.last_used)?;
+        self.entries.retain(|_, entry| entry.page != evicted);
+        self.pages[evicted] = AtlasPage::default();
+
+        let position = self.pages[evicted].allocate(width, height)?;
+        Some((evicted, position))
+    }
+}
@@ -63,7 +63,7 @@ pub fn get_or_create_image(
 
             Size2D::new(image_width * ratio, image_height * ratio)
         }
-        AspectRatio::Min => {
+        AspectRatio::Min | AspectRatio::Ratio(_) => {
             let ratio = width_ratio.min(height_ratio);
 
             Size2D::new(image_width * ratio, image_height * ratio)
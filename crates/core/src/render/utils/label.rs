@@ -8,7 +8,10 @@ use torin::{
     prelude::Size2D,
 };
 
-use super::ParagraphData;
+use super::{
+    layout_paragraph,
+    ParagraphData,
+};
 use crate::{
     dom::*,
     states::FontStyleState,
@@ -26,6 +29,7 @@ pub fn create_label(
 
     let mut paragraph_style = ParagraphStyle::default();
     paragraph_style.set_text_align(font_style.text_align);
+    paragraph_style.set_text_direction(font_style.text_direction);
     paragraph_style.set_max_lines(font_style.max_lines);
     paragraph_style.set_replace_tab_characters(true);
     paragraph_style.set_text_height_behavior(font_style.text_height);
@@ -42,18 +46,12 @@ pub fn create_label(
 
     for child in node.children() {
         if let NodeType::Text(text) = &*child.node_type() {
-            paragraph_builder.add_text(text);
+            paragraph_builder.add_text(font_style.text_wrap.break_text(text));
         }
     }
 
     let mut paragraph = paragraph_builder.build();
-    paragraph.layout(
-        if font_style.max_lines == Some(1) && font_style.text_align == TextAlign::default() {
-            f32::MAX
-        } else {
-            area_size.width + 1.0
-        },
-    );
+    layout_paragraph(&mut paragraph, font_style, area_size);
 
     // Relayout the paragraph so that its aligned based on its longest width
     match font_style.text_align {
@@ -62,6 +60,10 @@ pub fn create_label(
         {
             paragraph.layout(paragraph.longest_line() + 1.);
         }
+        // Right-to-left text starts at the right edge
+        TextAlign::Start if font_style.is_rtl() && torin_node.width.inner_sized() => {
+            paragraph.layout(paragraph.longest_line() + 1.);
+        }
         _ => {}
     }
 
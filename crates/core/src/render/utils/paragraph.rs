@@ -18,7 +18,10 @@ use crate::{
         FontStyleState,
         LayoutState,
     },
-    values::HighlightMode,
+    values::{
+        HighlightMode,
+        TextWrap,
+    },
 };
 
 pub struct ParagraphData {
@@ -26,6 +29,52 @@ pub struct ParagraphData {
     pub size: Size2D,
 }
 
+/// Width in which the text of a `label` or `paragraph` is laid out, given the available size.
+pub fn paragraph_layout_width(font_style: &FontStyleState, area_size: &Size2D) -> f32 {
+    if (font_style.max_lines == Some(1) || font_style.text_wrap == TextWrap::None)
+        && font_style.text_align == TextAlign::default()
+        && !font_style.is_rtl()
+    {
+        f32::MAX
+    } else {
+        area_size.width + 1.0
+    }
+}
+
+/// Layout the text of a `label` or `paragraph` given the available size.
+pub fn layout_paragraph(
+    paragraph: &mut Paragraph,
+    font_style: &FontStyleState,
+    area_size: &Size2D,
+) {
+    let layout_width = paragraph_layout_width(font_style, area_size);
+    if font_style.text_wrap == TextWrap::None && layout_width != f32::MAX {
+        // Aligned text that is never wrapped is aligned in its longest line if it doesn't fit
+        paragraph.layout(f32::MAX);
+        paragraph.layout(area_size.width.max(paragraph.longest_line()) + 1.0);
+    } else {
+        paragraph.layout(layout_width);
+    }
+}
+
+/// Text of a `paragraph`, made of the texts of all its `text` spans.
+pub fn paragraph_text(node: &DioxusNode) -> String {
+    let mut text = String::new();
+    for text_span in node.children() {
+        if let NodeType::Element(ElementNode {
+            tag: TagName::Text, ..
+        }) = &*text_span.node_type()
+        {
+            if let Some(text_node) = text_span.children().first() {
+                if let NodeType::Text(span_text) = &*text_node.node_type() {
+                    text.push_str(span_text);
+                }
+            }
+        }
+    }
+    text
+}
+
 /// Compose a new SkParagraph
 pub fn create_paragraph(
     node: &DioxusNode,
@@ -39,6 +88,7 @@ pub fn create_paragraph(
 
     let mut paragraph_style = ParagraphStyle::default();
     paragraph_style.set_text_align(font_style.text_align);
+    paragraph_style.set_text_direction(font_style.text_direction);
     paragraph_style.set_max_lines(font_style.max_lines);
     paragraph_style.set_replace_tab_characters(true);
     paragraph_style.set_text_height_behavior(font_style.text_height);
@@ -70,7 +120,7 @@ pub fn create_paragraph(
             paragraph_builder.push_style(&text_style);
 
             if let NodeType::Text(text) = text_node_type {
-                paragraph_builder.add_text(text);
+                paragraph_builder.add_text(font_style.text_wrap.break_text(text));
             }
         }
     }
@@ -81,15 +131,11 @@ pub fn create_paragraph(
     }
 
     let mut paragraph = paragraph_builder.build();
-    paragraph.layout(
-        if font_style.max_lines == Some(1) && font_style.text_align == TextAlign::default() {
-            f32::MAX
-        } else {
-            area_size.width + 1.0
-        },
-    );
+    layout_paragraph(&mut paragraph, font_style, area_size);
 
     let width = match font_style.text_align {
+        // Right-to-left text starts at the right edge
+        TextAlign::Start if font_style.is_rtl() => paragraph.max_width(),
         TextAlign::Start | TextAlign::Left => paragraph.longest_line(),
         _ => paragraph.max_width(),
     };
@@ -109,20 +155,25 @@ pub fn run_cursor_highlights(
     let node_cursor_state = &*node_ref.get::<CursorState>().unwrap();
 
     let highlights = node_cursor_state.highlights.as_ref()?;
+    let text_wrap = node_ref.get::<FontStyleState>().unwrap().text_wrap;
+    let text = if text_wrap.inserts_breaks() {
+        paragraph_text(node_ref)
+    } else {
+        String::new()
+    };
 
     for (from, to) in highlights.iter() {
         let (from, to) = {
             if from < to {
-                (from, to)
+                (*from, *to)
             } else {
-                (to, from)
+                (*to, *from)
             }
         };
-        let cursor_rects = paragraph.get_rects_for_range(
-            *from..*to,
-            RectHeightStyle::Tight,
-            RectWidthStyle::Tight,
-        );
+        let from = text_wrap.to_paragraph_offset(&text, from);
+        let to = text_wrap.to_paragraph_offset(&text, to);
+        let cursor_rects =
+            paragraph.get_rects_for_range(from..to, RectHeightStyle::Tight, RectWidthStyle::Tight);
 
         for cursor_rect in cursor_rects {
             let rect = align_highlights_and_cursor_paragraph(
@@ -150,7 +201,12 @@ pub fn draw_cursor(
 
     let cursor = node_cursor_state.position?;
     let cursor_color = node_cursor_state.color;
-    let cursor_position = cursor as usize;
+    let text_wrap = node_ref.get::<FontStyleState>().unwrap().text_wrap;
+    let cursor_position = if text_wrap.inserts_breaks() {
+        text_wrap.to_paragraph_offset(&paragraph_text(node_ref), cursor as usize)
+    } else {
+        cursor as usize
+    };
 
     let cursor_rects = paragraph.get_rects_for_range(
         cursor_position..cursor_position + 1,
@@ -182,7 +238,13 @@ pub fn align_highlights_and_cursor_paragraph(
 ) -> Rect {
     let cursor_state = node.get::<CursorState>().unwrap();
 
-    let left = area.min_x() + cursor_rect.rect.left;
+    let left = match width {
+        // The caret sits at the leading edge of the glyph, which is the right edge for RTL runs
+        Some(width) if cursor_rect.direct == TextDirection::RTL => {
+            area.min_x() + cursor_rect.rect.right - width
+        }
+        _ => area.min_x() + cursor_rect.rect.left,
+    };
     let right = left + width.unwrap_or(cursor_rect.rect.right - cursor_rect.rect.left);
 
     match cursor_state.highlight_mode {
@@ -49,7 +49,12 @@ use crate::{
         ParseAttribute,
         ParseError,
     },
-    values::Focusable,
+    values::{
+        AutoCapitalize,
+        Focusable,
+        InputHint,
+        TextInputHints,
+    },
 };
 
 #[derive(Clone, Debug, PartialEq, Default, Component)]
@@ -58,9 +63,44 @@ pub struct AccessibilityNodeState {
     pub a11y_id: Option<AccessibilityId>,
     pub a11y_auto_focus: bool,
     pub a11y_focusable: Focusable,
+    pub sensitive: bool,
+    pub text_input_hints: TextInputHints,
     pub builder: Option<Node>,
 }
 
+impl AccessibilityNodeState {
+    /// Mask a text so its content is not exposed, used for sensitive nodes.
+    pub fn mask_text(text: &str) -> String {
+        "•".repeat(text.chars().count())
+    }
+
+    /// Mask the accessibility value of sensitive nodes.
+    fn mask_sensitive_value(&mut self) {
+        if !self.sensitive {
+            return;
+        }
+        if let Some(builder) = self.builder.as_mut() {
+            if let Some(value) = builder.value() {
+                let masked = Self::mask_text(value);
+                builder.set_value(masked);
+            }
+        }
+    }
+}
+
+impl AccessibilityNodeState {
+    /// Use a more specific role for text inputs based on their input hint.
+    fn apply_text_input_hints(&mut self) {
+        if let Some(builder) = self.builder.as_mut() {
+            if builder.role() == Role::TextInput {
+                if let Some(role) = self.text_input_hints.input_hint.text_input_role() {
+                    builder.set_role(role);
+                }
+            }
+        }
+    }
+}
+
 impl ParseAttribute for AccessibilityNodeState {
     fn parse_attribute(
         &mut self,
@@ -83,7 +123,7 @@ impl ParseAttribute for AccessibilityNodeState {
             AttributeName::A11yFocusable => {
                 self.a11y_focusable = Focusable::parse(attr.value.as_text().ok_or(ParseError)?)?;
             }
-            AttributeName::A11yAutoFocus => {
+            AttributeName::A11yAutoFocus | AttributeName::AutoFocus => {
                 self.a11y_auto_focus = attr
                     .value
                     .as_text()
@@ -91,6 +131,30 @@ impl ParseAttribute for AccessibilityNodeState {
                     .parse()
                     .unwrap_or_default()
             }
+            AttributeName::Sensitive => {
+                self.sensitive = attr
+                    .value
+                    .as_text()
+                    .ok_or(ParseError)?
+                    .parse()
+                    .unwrap_or_default()
+            }
+            AttributeName::InputHint => {
+                self.text_input_hints.input_hint =
+                    InputHint::parse(attr.value.as_text().ok_or(ParseError)?)?;
+            }
+            AttributeName::AutoCapitalize => {
+                self.text_input_hints.autocapitalize =
+                    AutoCapitalize::parse(attr.value.as_text().ok_or(ParseError)?)?;
+            }
+            AttributeName::AutoCorrect => {
+                self.text_input_hints.autocorrect = attr
+                    .value
+                    .as_text()
+                    .ok_or(ParseError)?
+                    .parse()
+                    .unwrap_or(true)
+            }
             AttributeName::A11yMemberOf => {
                 if let OwnedAttributeValue::Custom(CustomAttributeValues::AccessibilityId(id)) =
                     attr.value
@@ -321,6 +385,7 @@ impl State<CustomAttributeValues> for AccessibilityNodeState {
             AttributeName::A11yId,
             AttributeName::A11yFocusable,
             AttributeName::A11yAutoFocus,
+            AttributeName::AutoFocus,
             AttributeName::A11yName,
             AttributeName::A11yDescription,
             AttributeName::A11yValue,
@@ -385,6 +450,10 @@ impl State<CustomAttributeValues> for AccessibilityNodeState {
             AttributeName::A11yHasPopup,
             AttributeName::A11yListStyle,
             AttributeName::A11yVerticalOffset,
+            AttributeName::Sensitive,
+            AttributeName::InputHint,
+            AttributeName::AutoCapitalize,
+            AttributeName::AutoCorrect,
         ]))
         .with_tag();
 
@@ -411,7 +480,9 @@ impl State<CustomAttributeValues> for AccessibilityNodeState {
                     TagName::Paragraph => Some(Node::new(Role::Paragraph)),
                     TagName::Rect => Some(Node::new(Role::GenericContainer)),
                     TagName::Svg => Some(Node::new(Role::GraphicsObject)),
+                    TagName::TextGrid => Some(Node::new(Role::GenericContainer)),
                     TagName::Root => Some(Node::new(Role::Window)),
+                    TagName::Custom(_) => Some(Node::new(Role::GenericContainer)),
                     // TODO: make this InlineTextBox and supply computed text span properties
                     TagName::Text => None,
                 }
@@ -425,6 +496,9 @@ impl State<CustomAttributeValues> for AccessibilityNodeState {
             }
         }
 
+        accessibility.mask_sensitive_value();
+        accessibility.apply_text_input_hints();
+
         let changed = &accessibility != self;
         let had_id = self.a11y_id.is_some();
 
@@ -0,0 +1,115 @@
+use std::sync::{
+    Arc,
+    Mutex,
+};
+
+use freya_native_core::{
+    attributes::AttributeName,
+    exports::shipyard::Component,
+    node::NodeType,
+    node_ref::NodeView,
+    prelude::{
+        AttributeMaskBuilder,
+        Dependancy,
+        NodeMaskBuilder,
+        OwnedAttributeView,
+        State,
+    },
+    tags::TagName,
+    NodeId,
+    SendAnyMap,
+};
+use freya_native_core_macro::partial_derive_state;
+use rustc_hash::FxHashMap;
+use torin::torin::Torin;
+
+use crate::{
+    custom_attributes::CustomAttributeValues,
+    dom::CompositorDirtyNodes,
+    parsing::{
+        Parse,
+        ParseAttribute,
+        ParseError,
+    },
+};
+
+/// Values of the attributes registered by a [`CustomElement`](crate::elements::CustomElement).
+#[derive(Default, Debug, Clone, PartialEq, Component)]
+pub struct CustomElementState {
+    pub attributes: FxHashMap<&'static str, String>,
+}
+
+impl CustomElementState {
+    /// Get the raw value of an attribute.
+    pub fn get(&self, attribute: &str) -> Option<&str> {
+        self.attributes.get(attribute).map(String::as_str)
+    }
+
+    /// Parse the value of an attribute.
+    pub fn parse<T: Parse>(&self, attribute: &str) -> Option<T> {
+        self.get(attribute).and_then(|value| T::parse(value).ok())
+    }
+}
+
+impl ParseAttribute for CustomElementState {
+    fn parse_attribute(
+        &mut self,
+        attr: OwnedAttributeView<CustomAttributeValues>,
+    ) -> Result<(), ParseError> {
+        if let (AttributeName::Custom(name), Some(value)) = (attr.attribute, attr.value.as_text()) {
+            self.attributes.insert(name, value.to_string());
+        }
+
+        Ok(())
+    }
+}
+
+#[partial_derive_state]
+impl State<CustomAttributeValues> for CustomElementState {
+    type ParentDependencies = ();
+
+    type ChildDependencies = ();
+
+    type NodeDependencies = ();
+
+    const NODE_MASK: NodeMaskBuilder<'static> = NodeMaskBuilder::new()
+        .with_attrs(AttributeMaskBuilder::All)
+        .with_tag();
+
+    fn allow_node(node_type: &NodeType<CustomAttributeValues>) -> bool {
+        matches!(node_type.tag(), Some(TagName::Custom(_)))
+    }
+
+    fn update<'a>(
+        &mut self,
+        node_view: NodeView<CustomAttributeValues>,
+        _node: <Self::NodeDependencies as Dependancy>::ElementBorrowed<'a>,
+        _parent: Option<<Self::ParentDependencies as Dependancy>::ElementBorrowed<'a>>,
+        _children: Vec<<Self::ChildDependencies as Dependancy>::ElementBorrowed<'a>>,
+        context: &SendAnyMap,
+    ) -> bool {
+        let mut custom_element = CustomElementState::default();
+
+        if let Some(attributes) = node_view.attributes() {
+            for attr in attributes {
+                custom_element.parse_safe(attr);
+            }
+        }
+
+        let changed = custom_element != *self;
+
+        if changed {
+            // The measurement of the element might depend on its attributes
+            let torin_layout = context.get::<Arc<Mutex<Torin<NodeId>>>>().unwrap();
+            torin_layout.lock().unwrap().invalidate(node_view.node_id());
+            let compositor_dirty_nodes = context.get::<Arc<Mutex<CompositorDirtyNodes>>>().unwrap();
+            compositor_dirty_nodes
+                .lock()
+                .unwrap()
+                .invalidate(node_view.node_id());
+        }
+
+        *self = custom_element;
+        changed
+    }
+}
@@ -30,8 +30,10 @@ use crate::{
         ParseError,
     },
     values::{
+        OpenTypeFeature,
         TextHeight,
         TextOverflow,
+        TextWrap,
     },
 };
 
@@ -48,13 +50,21 @@ pub struct FontStyleState {
     pub decoration: Decoration,
     pub word_spacing: f32,
     pub letter_spacing: f32,
+    pub font_features: Arc<[OpenTypeFeature]>,
     pub text_align: TextAlign,
     pub max_lines: Option<usize>,
     pub text_overflow: TextOverflow,
+    pub text_wrap: TextWrap,
     pub text_height: TextHeightBehavior,
+    pub text_direction: TextDirection,
 }
 
 impl FontStyleState {
+    /// Whether the text flows from right to left.
+    pub fn is_rtl(&self) -> bool {
+        self.text_direction == TextDirection::RTL
+    }
+
     pub fn text_style(
         &self,
         default_font_family: &[String],
@@ -79,6 +89,10 @@ impl FontStyleState {
             .set_word_spacing(self.word_spacing)
             .set_letter_spacing(self.letter_spacing);
 
+        for feature in self.font_features.iter() {
+            text_style.add_font_feature(&feature.tag, feature.value);
+        }
+
         if paragraph_text_height.needs_custom_height() {
             text_style.set_height_override(true);
             text_style.set_half_leading(true);
@@ -113,6 +127,7 @@ impl Default for FontStyleState {
             line_height: None,
             word_spacing: 0.0,
             letter_spacing: 0.0,
+            font_features: Arc::default(),
             decoration: Decoration {
                 thickness_multiplier: 1.0, // Defaults to 0.0, even though 0.0 won't render anything
                 ..Decoration::default()
@@ -120,7 +135,9 @@ impl Default for FontStyleState {
             text_align: TextAlign::default(),
             max_lines: None,
             text_overflow: TextOverflow::default(),
+            text_wrap: TextWrap::default(),
             text_height: TextHeightBehavior::DisableAll,
+            text_direction: TextDirection::LTR,
         }
     }
 }
@@ -189,6 +206,9 @@ impl ParseAttribute for FontStyleState {
             AttributeName::TextOverflow => {
                 self.text_overflow = TextOverflow::parse(attr.value.as_text().ok_or(ParseError)?)?;
             }
+            AttributeName::TextWrap => {
+                self.text_wrap = TextWrap::parse(attr.value.as_text().ok_or(ParseError)?)?;
+            }
             AttributeName::FontStyle => {
                 self.font_slant = Slant::parse(attr.value.as_text().ok_or(ParseError)?)?;
             }
@@ -228,10 +248,32 @@ impl ParseAttribute for FontStyleState {
                     .parse()
                     .map_err(|_| ParseError)?;
             }
+            AttributeName::FontFeatures => {
+                self.font_features = attr
+                    .value
+                    .as_text()
+                    .ok_or(ParseError)?
+                    .split(',')
+                    .filter(|feature| !feature.trim().is_empty())
+                    .map(OpenTypeFeature::parse)
+                    .collect::<Result<_, _>>()?;
+            }
             AttributeName::TextHeight => {
                 self.text_height =
                     TextHeightBehavior::parse(attr.value.as_text().ok_or(ParseError)?)?;
             }
+            AttributeName::TextDirection => {
+                self.text_direction =
+                    TextDirection::parse(attr.value.as_text().ok_or(ParseError)?)?;
+            }
+            AttributeName::Direction => {
+                // `direction` is shared with the layout, only its text directions apply here
+                match attr.value.as_text().ok_or(ParseError)? {
+                    "ltr" => self.text_direction = TextDirection::LTR,
+                    "rtl" => self.text_direction = TextDirection::RTL,
+                    _ => {}
+                }
+            }
             _ => {}
         }
 
@@ -261,11 +303,15 @@ impl State<CustomAttributeValues> for FontStyleState {
             AttributeName::FontWidth,
             AttributeName::WordSpacing,
             AttributeName::LetterSpacing,
+            AttributeName::FontFeatures,
             AttributeName::Decoration,
             AttributeName::DecorationColor,
             AttributeName::DecorationStyle,
             AttributeName::TextOverflow,
+            AttributeName::TextWrap,
             AttributeName::TextHeight,
+            AttributeName::TextDirection,
+            AttributeName::Direction,
         ]));
 
     fn update<'a>(
@@ -19,7 +19,12 @@ use freya_native_core::{
 use freya_native_core_macro::partial_derive_state;
 
 use crate::{
+    accessibility::AccessibilityDirtyNodes,
     custom_attributes::CustomAttributeValues,
+    dom::{
+        CompositorDirtyNodes,
+        WindowPortals,
+    },
     layers::Layers,
     parsing::{
         ParseAttribute,
@@ -31,6 +36,22 @@ use crate::{
 pub struct LayerState {
     pub layer: i16,
     pub layer_for_children: i16,
+    /// Whether this node rasterizes itself and its children in a cached layer.
+    pub cache_layer: bool,
+    /// The node whose cached layer contains this node, if any.
+    pub cached_layer: Option<NodeId>,
+    /// Whether this node and its children are rendered in their own window.
+    pub is_window_portal: bool,
+    /// The node whose portal window contains this node, if any.
+    pub window_portal: Option<NodeId>,
+    /// Whether this node and its children are hidden, see the `offscreen` attribute.
+    pub is_offscreen: bool,
+    /// The outermost offscreen node containing this node, if any.
+    pub offscreen: Option<NodeId>,
+    /// Whether this node and its children can't be interacted with, see the `inert` attribute.
+    pub is_inert: bool,
+    /// The outermost inert node containing this node, if any.
+    pub inert: Option<NodeId>,
 }
 
 impl ParseAttribute for LayerState {
@@ -38,7 +59,6 @@ impl ParseAttribute for LayerState {
         &mut self,
         attr: freya_native_core::prelude::OwnedAttributeView<CustomAttributeValues>,
     ) -> Result<(), ParseError> {
-        #[allow(clippy::single_match)]
         match attr.attribute {
             AttributeName::Layer => {
                 let layer = attr
@@ -50,6 +70,18 @@ impl ParseAttribute for LayerState {
                 self.layer -= layer;
                 self.layer_for_children += layer;
             }
+            AttributeName::CacheLayer => {
+                self.cache_layer = attr.value.as_text().ok_or(ParseError)? == "true";
+            }
+            AttributeName::WindowPortal => {
+                self.is_window_portal = attr.value.as_text().ok_or(ParseError)? == "true";
+            }
+            AttributeName::Offscreen => {
+                self.is_offscreen = attr.value.as_text().ok_or(ParseError)? == "true";
+            }
+            AttributeName::Inert => {
+                self.is_inert = attr.value.as_text().ok_or(ParseError)? == "true";
+            }
             _ => {}
         }
 
@@ -66,7 +98,13 @@ impl State<CustomAttributeValues> for LayerState {
     type NodeDependencies = ();
 
     const NODE_MASK: NodeMaskBuilder<'static> = NodeMaskBuilder::new()
-        .with_attrs(AttributeMaskBuilder::Some(&[AttributeName::Layer]))
+        .with_attrs(AttributeMaskBuilder::Some(&[
+            AttributeName::Layer,
+            AttributeName::CacheLayer,
+            AttributeName::WindowPortal,
+            AttributeName::Offscreen,
+            AttributeName::Inert,
+        ]))
         .with_tag();
 
     fn update<'a>(
@@ -83,11 +121,25 @@ impl State<CustomAttributeValues> for LayerState {
 
         let root_id = context.get::<NodeId>().unwrap();
         let layers = context.get::<Arc<Mutex<Layers>>>().unwrap();
+        let compositor_dirty_nodes = context.get::<Arc<Mutex<CompositorDirtyNodes>>>().unwrap();
+        let window_portals = context.get::<Arc<Mutex<WindowPortals>>>().unwrap();
         let inherited_layer = parent.map(|(p,)| p.layer_for_children).unwrap_or(0i16);
+        let inherited_cached_layer = parent.and_then(|(p,)| p.cached_layer);
+        let inherited_window_portal = parent.and_then(|(p,)| p.window_portal);
+        let inherited_offscreen = parent.and_then(|(p,)| p.offscreen);
+        let inherited_inert = parent.and_then(|(p,)| p.inert);
 
         let mut layer_state = LayerState {
             layer: node_view.height() as i16 - inherited_layer,
             layer_for_children: inherited_layer,
+            cache_layer: false,
+            cached_layer: inherited_cached_layer,
+            is_window_portal: false,
+            window_portal: inherited_window_portal,
+            is_offscreen: false,
+            offscreen: inherited_offscreen,
+            is_inert: false,
+            inert: inherited_inert,
         };
 
         if let Some(attributes) = node_view.attributes() {
@@ -96,6 +148,24 @@ impl State<CustomAttributeValues> for LayerState {
             }
         }
 
+        // Nested cached layers are simply painted as part of the outermost one
+        if layer_state.cache_layer && layer_state.cached_layer.is_none() {
+            layer_state.cached_layer = Some(node_view.node_id());
+        }
+
+        // Nested portals are simply rendered as part of the outermost one
+        if layer_state.is_window_portal && layer_state.window_portal.is_none() {
+            layer_state.window_portal = Some(node_view.node_id());
+        }
+
+        if layer_state.is_offscreen && layer_state.offscreen.is_none() {
+            layer_state.offscreen = Some(node_view.node_id());
+        }
+
+        if layer_state.is_inert && layer_state.inert.is_none() {
+            layer_state.inert = Some(node_view.node_id());
+        }
+
         let changed = &layer_state != self;
 
         let is_orphan = node_view.height() == 0 && node_view.node_id() != *root_id;
@@ -105,6 +175,35 @@ impl State<CustomAttributeValues> for LayerState {
                 .lock()
                 .unwrap()
                 .insert_node_in_layer(node_view.node_id(), layer_state.layer);
+
+            // New nodes and nodes moved to another layer must be painted again
+            if layer_state.cached_layer.is_some() || self.cached_layer.is_some() {
+                compositor_dirty_nodes
+                    .lock()
+                    .unwrap()
+                    .invalidate(node_view.node_id());
+            }
+
+            // Inert and offscreen nodes are removed from the accessibility tree,
+            // and added back once they aren't
+            if layer_state.inert.is_some() != self.inert.is_some()
+                || layer_state.offscreen.is_some() != self.offscreen.is_some()
+            {
+                let accessibility_dirty_nodes = context
+                    .get::<Arc<Mutex<AccessibilityDirtyNodes>>>()
+                    .unwrap();
+                accessibility_dirty_nodes
+                    .lock()
+                    .unwrap()
+                    .add_or_update(node_view.node_id());
+            }
+
+            let mut window_portals = window_portals.lock().unwrap();
+            if layer_state.window_portal == Some(node_view.node_id()) {
+                window_portals.insert(node_view.node_id());
+            } else {
+                window_portals.remove(&node_view.node_id());
+            }
         }
 
         *self = layer_state;
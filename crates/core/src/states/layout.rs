@@ -32,6 +32,7 @@ use crate::{
         ParseAttribute,
         ParseError,
     },
+    values::AspectRatio,
 };
 
 #[derive(Default, Clone, Debug, Component, PartialEq)]
@@ -46,16 +47,32 @@ pub struct LayoutState {
     pub visible_height: VisibleSize,
     pub padding: Gaps,
     pub margin: Gaps,
+    pub auto_margins: AutoMargins,
     pub direction: Direction,
     pub offset_y: Length,
     pub offset_x: Length,
+    /// Whether the children can be scrolled, set once any of the offsets is declared.
+    pub scroll_container: bool,
     pub main_alignment: Alignment,
     pub cross_alignment: Alignment,
+    pub align_self: Option<Alignment>,
     pub position: Position,
     pub content: Content,
+    pub wrap_content: WrapContent,
     pub node_ref: Option<NodeReference>,
     pub node_id: NodeId,
     pub spacing: Length,
+    pub cross_spacing: Option<Length>,
+    pub flex_grow: Length,
+    pub flex_shrink: Length,
+    pub flex_basis: Size,
+    pub grid_columns: Vec<GridTrack>,
+    pub grid_rows: Vec<GridTrack>,
+    pub grid_column: GridPlacement,
+    pub grid_row: GridPlacement,
+    pub aspect_ratio: Option<f32>,
+    /// Whether this node and its children are hidden from the layout.
+    pub offscreen: bool,
 }
 
 impl ParseAttribute for LayoutState {
@@ -92,7 +109,9 @@ impl ParseAttribute for LayoutState {
                 self.padding = Gaps::parse(attr.value.as_text().ok_or(ParseError)?)?;
             }
             AttributeName::Margin => {
-                self.margin = Gaps::parse(attr.value.as_text().ok_or(ParseError)?)?;
+                let value = attr.value.as_text().ok_or(ParseError)?;
+                self.auto_margins = AutoMargins::parse(value)?;
+                self.margin = Gaps::parse(&value.replace("auto", "0"))?;
             }
             AttributeName::Direction => {
                 self.direction = match attr.value.as_text().ok_or(ParseError)? {
@@ -101,6 +120,7 @@ impl ParseAttribute for LayoutState {
                 };
             }
             AttributeName::OffsetY => {
+                self.scroll_container = true;
                 self.offset_y = Length::new(
                     attr.value
                         .as_text()
@@ -110,6 +130,7 @@ impl ParseAttribute for LayoutState {
                 );
             }
             AttributeName::OffsetX => {
+                self.scroll_container = true;
                 self.offset_x = Length::new(
                     attr.value
                         .as_text()
@@ -124,6 +145,14 @@ impl ParseAttribute for LayoutState {
             AttributeName::CrossAlign => {
                 self.cross_alignment = Alignment::parse(attr.value.as_text().ok_or(ParseError)?)?;
             }
+            AttributeName::AlignSelf => {
+                let value = attr.value.as_text().ok_or(ParseError)?;
+                self.align_self = if value == "auto" {
+                    None
+                } else {
+                    Some(Alignment::parse(value)?)
+                };
+            }
             AttributeName::Position => {
                 self.position
                     .swap_for(Position::parse(attr.value.as_text().ok_or(ParseError)?)?);
@@ -167,6 +196,9 @@ impl ParseAttribute for LayoutState {
             AttributeName::Content => {
                 self.content = Content::parse(attr.value.as_text().ok_or(ParseError)?)?;
             }
+            AttributeName::WrapContent => {
+                self.wrap_content = WrapContent::parse(attr.value.as_text().ok_or(ParseError)?)?;
+            }
             AttributeName::Reference => {
                 if let OwnedAttributeValue::Custom(CustomAttributeValues::Reference(reference)) =
                     attr.value
@@ -175,14 +207,83 @@ impl ParseAttribute for LayoutState {
                 }
             }
             AttributeName::Spacing => {
-                self.spacing = Length::new(
+                let values = attr
+                    .value
+                    .as_text()
+                    .ok_or(ParseError)?
+                    .split_ascii_whitespace()
+                    .map(|value| value.parse::<f32>().map_err(|_| ParseError))
+                    .collect::<Result<Vec<_>, _>>()?;
+                match values[..] {
+                    [spacing] => {
+                        self.spacing = Length::new(spacing);
+                        self.cross_spacing = None;
+                    }
+                    [cross_spacing, spacing] => {
+                        self.spacing = Length::new(spacing);
+                        self.cross_spacing = Some(Length::new(cross_spacing));
+                    }
+                    _ => return Err(ParseError),
+                }
+            }
+            AttributeName::FlexGrow => {
+                self.flex_grow = Length::new(
                     attr.value
                         .as_text()
                         .ok_or(ParseError)?
                         .parse::<f32>()
-                        .map_err(|_| ParseError)?,
+                        .map_err(|_| ParseError)?
+                        .max(0.),
+                );
+            }
+            AttributeName::FlexShrink => {
+                self.flex_shrink = Length::new(
+                    attr.value
+                        .as_text()
+                        .ok_or(ParseError)?
+                        .parse::<f32>()
+                        .map_err(|_| ParseError)?
+                        .max(0.),
                 );
             }
+            AttributeName::FlexBasis => {
+                self.flex_basis = Size::parse(attr.value.as_text().ok_or(ParseError)?)?;
+            }
+            AttributeName::GridTemplateColumns => {
+                self.grid_columns = attr
+                    .value
+                    .as_text()
+                    .ok_or(ParseError)?
+                    .split_ascii_whitespace()
+                    .map(GridTrack::parse)
+                    .collect::<Result<_, _>>()?;
+            }
+            AttributeName::GridTemplateRows => {
+                self.grid_rows = attr
+                    .value
+                    .as_text()
+                    .ok_or(ParseError)?
+                    .split_ascii_whitespace()
+                    .map(GridTrack::parse)
+                    .collect::<Result<_, _>>()?;
+            }
+            AttributeName::GridColumn => {
+                self.grid_column = GridPlacement::parse(attr.value.as_text().ok_or(ParseError)?)?;
+            }
+            AttributeName::GridRow => {
+                self.grid_row = GridPlacement::parse(attr.value.as_text().ok_or(ParseError)?)?;
+            }
+            AttributeName::AspectRatio => {
+                let aspect_ratio = AspectRatio::parse(attr.value.as_text().ok_or(ParseError)?)?;
+                // The rest of values are only meant for how images are rendered
+                self.aspect_ratio = match aspect_ratio {
+                    AspectRatio::Ratio(ratio) => Some(ratio),
+                    _ => None,
+                };
+            }
+            AttributeName::Offscreen => {
+                self.offscreen = attr.value.as_text().ok_or(ParseError)? == "true";
+            }
             _ => {}
         }
         Ok(())
@@ -213,6 +314,7 @@ impl State<CustomAttributeValues> for LayoutState {
             AttributeName::OffsetY,
             AttributeName::MainAlign,
             AttributeName::CrossAlign,
+            AttributeName::AlignSelf,
             AttributeName::Reference,
             AttributeName::Margin,
             AttributeName::Position,
@@ -221,7 +323,17 @@ impl State<CustomAttributeValues> for LayoutState {
             AttributeName::PositionBottom,
             AttributeName::PositionLeft,
             AttributeName::Content,
+            AttributeName::WrapContent,
             AttributeName::Spacing,
+            AttributeName::FlexGrow,
+            AttributeName::FlexShrink,
+            AttributeName::FlexBasis,
+            AttributeName::GridTemplateColumns,
+            AttributeName::GridTemplateRows,
+            AttributeName::GridColumn,
+            AttributeName::GridRow,
+            AttributeName::AspectRatio,
+            AttributeName::Offscreen,
         ]));
 
     fn update<'a>(
@@ -1,23 +1,29 @@
 mod accessibility;
 mod canvas;
 mod cursor;
+mod custom_element;
 mod font_style;
 mod image;
 mod layer;
 mod layout;
 mod style;
+mod style_reference;
 mod svg;
+mod text_grid;
 mod transform;
 mod viewport;
 
 pub use accessibility::*;
 pub use canvas::*;
 pub use cursor::*;
+pub use custom_element::*;
 pub use font_style::*;
 pub use image::*;
 pub use layer::*;
 pub use layout::*;
 pub use style::*;
+pub use style_reference::*;
 pub use svg::*;
+pub use text_grid::*;
 pub use transform::*;
 pub use viewport::*;
@@ -26,6 +26,7 @@ use crate::{
         ParseAttribute,
         ParseError,
     },
+    platform::CursorIcon,
     values::{
         parse_alpha,
         Border,
@@ -44,6 +45,7 @@ pub struct StyleState {
     pub shadows: Arc<[Shadow]>,
     pub corner_radius: CornerRadius,
     pub overflow: OverflowMode,
+    pub cursor: Option<CursorIcon>,
 }
 
 impl ParseAttribute for StyleState {
@@ -102,6 +104,9 @@ impl ParseAttribute for StyleState {
             AttributeName::Overflow => {
                 self.overflow = OverflowMode::parse(attr.value.as_text().ok_or(ParseError)?)?;
             }
+            AttributeName::Cursor => {
+                self.cursor = Some(CursorIcon::parse(attr.value.as_text().ok_or(ParseError)?)?);
+            }
             _ => {}
         }
 
@@ -130,6 +135,7 @@ impl State<CustomAttributeValues> for StyleState {
             AttributeName::ImageData,
             AttributeName::Overflow,
             AttributeName::ImageCacheKey,
+            AttributeName::Cursor,
         ]));
 
     fn update<'a>(
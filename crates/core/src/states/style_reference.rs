@@ -0,0 +1,87 @@
+use std::sync::{
+    Arc,
+    Mutex,
+};
+
+use freya_native_core::{
+    attributes::AttributeName,
+    exports::shipyard::Component,
+    node::OwnedAttributeValue,
+    node_ref::NodeView,
+    prelude::{
+        AttributeMaskBuilder,
+        Dependancy,
+        NodeMaskBuilder,
+        State,
+    },
+    SendAnyMap,
+};
+use freya_native_core_macro::partial_derive_state;
+
+use crate::{
+    custom_attributes::{
+        CustomAttributeValues,
+        StyleReference,
+    },
+    dom::StyleReferences,
+};
+
+#[derive(Default, Debug, Clone, PartialEq, Component)]
+pub struct StyleReferenceState {
+    pub style_ref: Option<StyleReference>,
+}
+
+#[partial_derive_state]
+impl State<CustomAttributeValues> for StyleReferenceState {
+    type ParentDependencies = ();
+
+    type ChildDependencies = ();
+
+    type NodeDependencies = ();
+
+    const NODE_MASK: NodeMaskBuilder<'static> = NodeMaskBuilder::new()
+        .with_attrs(AttributeMaskBuilder::Some(&[AttributeName::StyleReference]));
+
+    fn update<'a>(
+        &mut self,
+        node_view: NodeView<CustomAttributeValues>,
+        _node: <Self::NodeDependencies as Dependancy>::ElementBorrowed<'a>,
+        _parent: Option<<Self::ParentDependencies as Dependancy>::ElementBorrowed<'a>>,
+        _children: Vec<<Self::ChildDependencies as Dependancy>::ElementBorrowed<'a>>,
+        context: &SendAnyMap,
+    ) -> bool {
+        let mut style_reference = StyleReferenceState::default();
+
+        if let Some(attributes) = node_view.attributes() {
+            for attr in attributes {
+                #[allow(clippy::single_match)]
+                match attr.attribute {
+                    AttributeName::StyleReference => {
+                        if let OwnedAttributeValue::Custom(CustomAttributeValues::StyleReference(
+                            reference,
+                        )) = attr.value
+                        {
+                            style_reference.style_ref = Some(reference.clone());
+                        }
+                    }
+                    _ => {}
+                }
+            }
+        }
+
+        let changed = &style_reference != self;
+
+        if changed {
+            let style_references = context.get::<Arc<Mutex<StyleReferences>>>().unwrap();
+            let mut style_references = style_references.lock().unwrap();
+            if style_reference.style_ref.is_some() {
+                style_references.insert(node_view.node_id());
+            } else {
+                style_references.remove(&node_view.node_id());
+            }
+        }
+
+        *self = style_reference;
+        changed
+    }
+}
@@ -0,0 +1,105 @@
+use std::sync::{
+    Arc,
+    Mutex,
+};
+
+use freya_native_core::{
+    attributes::AttributeName,
+    exports::shipyard::Component,
+    node::{
+        NodeType,
+        OwnedAttributeValue,
+    },
+    node_ref::NodeView,
+    prelude::{
+        AttributeMaskBuilder,
+        Dependancy,
+        NodeMaskBuilder,
+        State,
+    },
+    tags::TagName,
+    NodeId,
+    SendAnyMap,
+};
+use freya_native_core_macro::partial_derive_state;
+use torin::torin::Torin;
+
+use crate::{
+    custom_attributes::{
+        CustomAttributeValues,
+        TextGrid,
+    },
+    dom::CompositorDirtyNodes,
+};
+
+#[derive(Default, Debug, Clone, PartialEq, Component)]
+pub struct TextGridState {
+    pub grid: Option<TextGrid>,
+}
+
+#[partial_derive_state]
+impl State<CustomAttributeValues> for TextGridState {
+    type ParentDependencies = ();
+
+    type ChildDependencies = ();
+
+    type NodeDependencies = ();
+
+    const NODE_MASK: NodeMaskBuilder<'static> =
+        NodeMaskBuilder::new().with_attrs(AttributeMaskBuilder::Some(&[AttributeName::TextGrid]));
+
+    fn allow_node(node_type: &NodeType<CustomAttributeValues>) -> bool {
+        node_type.tag() == Some(&TagName::TextGrid)
+    }
+
+    fn update<'a>(
+        &mut self,
+        node_view: NodeView<CustomAttributeValues>,
+        _node: <Self::NodeDependencies as Dependancy>::ElementBorrowed<'a>,
+        _parent: Option<<Self::ParentDependencies as Dependancy>::ElementBorrowed<'a>>,
+        _children: Vec<<Self::ChildDependencies as Dependancy>::ElementBorrowed<'a>>,
+        context: &SendAnyMap,
+    ) -> bool {
+        let mut text_grid = TextGridState::default();
+
+        if let Some(attributes) = node_view.attributes() {
+            for attr in attributes {
+                #[allow(clippy::single_match)]
+                match attr.attribute {
+                    AttributeName::TextGrid => {
+                        if let OwnedAttributeValue::Custom(CustomAttributeValues::TextGrid(grid)) =
+                            attr.value
+                        {
+                            text_grid.grid = Some(grid.clone());
+                        }
+                    }
+                    _ => {}
+                }
+            }
+        }
+
+        let changed = &text_grid != self;
+
+        if changed {
+            let compositor_dirty_nodes = context.get::<Arc<Mutex<CompositorDirtyNodes>>>().unwrap();
+            compositor_dirty_nodes
+                .lock()
+                .unwrap()
+                .invalidate(node_view.node_id());
+
+            // The size of the grid might have changed
+            let old_size = self.grid.as_ref().map(|grid| (grid.columns(), grid.rows()));
+            let new_size = text_grid
+                .grid
+                .as_ref()
+                .map(|grid| (grid.columns(), grid.rows()));
+            if old_size != new_size {
+                let torin_layout = context.get::<Arc<Mutex<Torin<NodeId>>>>().unwrap();
+                torin_layout.lock().unwrap().invalidate(node_view.node_id());
+            }
+        }
+
+        *self = text_grid;
+        changed
+    }
+}
@@ -1,3 +1,5 @@
+/// Font families used as fallback chain when a text doesn't specify a `font_family`
+/// or its families can't render a certain glyph, e.g. emojis.
 pub fn default_fonts() -> Vec<String> {
     let mut fonts = vec!["Noto Sans".to_string(), "Arial".to_string()];
     if cfg!(target_os = "windows") {
@@ -5,8 +7,10 @@ pub fn default_fonts() -> Vec<String> {
         fonts.insert(1, "Segoe UI Emoji".to_string());
     } else if cfg!(target_os = "macos") {
         fonts.insert(0, ".AppleSystemUIFont".to_string());
+        fonts.insert(1, "Apple Color Emoji".to_string());
     } else if cfg!(target_os = "linux") {
         fonts.insert(0, "Ubuntu".to_string());
+        fonts.push("Noto Color Emoji".to_string());
     }
     fonts
 }
@@ -1,3 +1,5 @@
+use std::time::Instant;
+
 pub use accesskit::{
     Node as AccessibilityNode,
     NodeId as AccessibilityId,
@@ -28,6 +30,29 @@ pub type NativePlatformSender = watch::Sender<NativePlatformState>;
 /// Receive updates by the platform
 pub type NativePlatformReceiver = watch::Receiver<NativePlatformState>;
 
+/// Send the instant of the last input of the user
+pub type UserActivitySender = watch::Sender<Instant>;
+
+/// Receive the instant of the last input of the user
+pub type UserActivityReceiver = watch::Receiver<Instant>;
+
+/// Send the keyboard modifiers currently pressed
+pub type ModifiersSender = watch::Sender<freya_elements::events::keyboard::Modifiers>;
+
+/// Receive the keyboard modifiers currently pressed
+pub type ModifiersReceiver = watch::Receiver<freya_elements::events::keyboard::Modifiers>;
+
+/// Send the power events of the system to the components
+pub type PowerEventsSender = tokio::sync::broadcast::Sender<crate::platform_state::PowerEvent>;
+
+/// Send the events of the native menus to the components
+#[cfg(feature = "menu")]
+pub type MenuEventsSender = tokio::sync::broadcast::Sender<crate::platform::muda::MenuEvent>;
+
+/// Send the events of the tray icon to the components
+#[cfg(feature = "tray")]
+pub type TrayEventsSender = tokio::sync::broadcast::Sender<crate::platform::TrayEvent>;
+
 /// Emit events to the VirtualDOM
 pub type EventEmitter = UnboundedSender<Vec<DomEvent>>;
 
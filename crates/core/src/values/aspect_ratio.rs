@@ -10,6 +10,8 @@ pub enum AspectRatio {
     Max,
     Fit,
     None,
+    /// Width divided by the height, e.g `16/9`.
+    Ratio(f32),
 }
 
 impl Parse for AspectRatio {
@@ -19,7 +21,20 @@ impl Parse for AspectRatio {
             "max" => Ok(Self::Max),
             "fit" => Ok(Self::Fit),
             "none" => Ok(Self::None),
-            _ => Err(ParseError),
+            value => {
+                let ratio = match value.split_once('/') {
+                    Some((width, height)) => {
+                        width.trim().parse::<f32>().map_err(|_| ParseError)?
+                            / height.trim().parse::<f32>().map_err(|_| ParseError)?
+                    }
+                    None => value.trim().parse::<f32>().map_err(|_| ParseError)?,
+                };
+                if ratio.is_finite() && ratio > 0.0 {
+                    Ok(Self::Ratio(ratio))
+                } else {
+                    Err(ParseError)
+                }
+            }
         }
     }
 }
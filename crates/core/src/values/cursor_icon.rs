@@ -0,0 +1,15 @@
+use std::str::FromStr;
+
+use crate::{
+    parsing::{
+        Parse,
+        ParseError,
+    },
+    platform::CursorIcon,
+};
+
+impl Parse for CursorIcon {
+    fn parse(value: &str) -> Result<Self, ParseError> {
+        CursorIcon::from_str(value).map_err(|_| ParseError)
+    }
+}
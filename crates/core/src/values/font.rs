@@ -1,3 +1,5 @@
+use std::borrow::Cow;
+
 use freya_engine::prelude::*;
 
 use crate::parsing::{
@@ -19,6 +21,15 @@ impl Parse for TextAlign {
     }
 }
 
+impl Parse for TextDirection {
+    fn parse(value: &str) -> Result<Self, ParseError> {
+        Ok(match value {
+            "rtl" => TextDirection::RTL,
+            _ => TextDirection::LTR,
+        })
+    }
+}
+
 impl Parse for Slant {
     fn parse(value: &str) -> Result<Self, ParseError> {
         Ok(match value {
@@ -117,3 +128,163 @@ impl Parse for TextOverflow {
         })
     }
 }
+
+/// How the text of a `label` or `paragraph` is broken into lines.
+#[derive(Default, Clone, Copy, Debug, PartialEq)]
+pub enum TextWrap {
+    /// Break lines between words. Words that don't fit in a line by themselves are broken wherever needed.
+    #[default]
+    Word,
+    /// Break lines between any characters, useful for CJK text or long tokens.
+    Char,
+    /// Break lines between words and also after the separators inside of them (e.g `/`, `.` or `-`), so long URLs and paths can be wrapped.
+    Anywhere,
+    /// Never break lines, the text is as wide as its longest line.
+    None,
+}
+
+/// Invisible character inserted in the text where a line can be broken.
+const BREAK_OPPORTUNITY: char = '\u{200B}';
+
+impl TextWrap {
+    pub fn pretty(&self) -> String {
+        match self {
+            Self::Word => "word".to_string(),
+            Self::Char => "char".to_string(),
+            Self::Anywhere => "anywhere".to_string(),
+            Self::None => "none".to_string(),
+        }
+    }
+
+    /// Whether a line can be broken right after the given character.
+    fn breaks_after(&self, character: char) -> bool {
+        match self {
+            Self::Char => !character.is_whitespace(),
+            Self::Anywhere => matches!(
+                character,
+                '/' | '\\'
+                    | '.'
+                    | '-'
+                    | '_'
+                    | '?'
+                    | '&'
+                    | '='
+                    | '#'
+                    | ':'
+                    | '@'
+                    | ','
+                    | ';'
+                    | '+'
+                    | '~'
+            ),
+            Self::Word | Self::None => false,
+        }
+    }
+
+    /// Whether the text is modified by [TextWrap::break_text].
+    pub fn inserts_breaks(&self) -> bool {
+        matches!(self, Self::Char | Self::Anywhere)
+    }
+
+    /// Insert the line break opportunities of this mode in the given text.
+    pub fn break_text<'a>(&self, text: &'a str) -> Cow<'a, str> {
+        if !self.inserts_breaks() {
+            return Cow::Borrowed(text);
+        }
+
+        let mut broken_text = String::with_capacity(text.len() * 2);
+        for character in text.chars() {
+            broken_text.push(character);
+            if self.breaks_after(character) {
+                broken_text.push(BREAK_OPPORTUNITY);
+            }
+        }
+        Cow::Owned(broken_text)
+    }
+
+    /// Convert a UTF-16 offset in the given text to its offset in the text with the line break opportunities.
+    pub fn to_paragraph_offset(&self, text: &str, offset: usize) -> usize {
+        if !self.inserts_breaks() {
+            return offset;
+        }
+
+        let mut text_offset = 0;
+        let mut inserted = 0;
+        for character in text.chars() {
+            if text_offset >= offset {
+                break;
+            }
+            text_offset += character.len_utf16();
+            if self.breaks_after(character) {
+                inserted += 1;
+            }
+        }
+        offset + inserted
+    }
+
+    /// Convert a UTF-16 offset in the text with the line break opportunities to its offset in the given text.
+    pub fn from_paragraph_offset(&self, text: &str, offset: usize) -> usize {
+        if !self.inserts_breaks() {
+            return offset;
+        }
+
+        let mut paragraph_offset = 0;
+        let mut text_offset = 0;
+        for character in text.chars() {
+            if paragraph_offset >= offset {
+                return text_offset;
+            }
+            paragraph_offset += character.len_utf16();
+            text_offset += character.len_utf16();
+            if self.breaks_after(character) {
+                if paragraph_offset >= offset {
+                    return text_offset;
+                }
+                paragraph_offset += 1;
+            }
+        }
+        text_offset + offset.saturating_sub(paragraph_offset)
+    }
+}
+
+impl Parse for TextWrap {
+    fn parse(value: &str) -> Result<Self, ParseError> {
+        Ok(match value {
+            "char" => TextWrap::Char,
+            "anywhere" => TextWrap::Anywhere,
+            "none" => TextWrap::None,
+            _ => TextWrap::Word,
+        })
+    }
+}
+
+/// OpenType feature of a font, like `liga` or `tnum`, and its value.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct OpenTypeFeature {
+    /// Four letter tag of the feature.
+    pub tag: String,
+    /// `0` disables the feature, `1` enables it and higher values pick one of its alternates.
+    pub value: i32,
+}
+
+impl Parse for OpenTypeFeature {
+    fn parse(value: &str) -> Result<Self, ParseError> {
+        let mut parts = value.split_whitespace();
+        let tag = parts.next().ok_or(ParseError)?.trim_matches(['"', '\'']);
+        if tag.len() != 4 || !tag.chars().all(|c| c.is_ascii_alphanumeric()) {
+            return Err(ParseError);
+        }
+        let value = match parts.next() {
+            None | Some("on") => 1,
+            Some("off") => 0,
+            Some(value) => value.parse().map_err(|_| ParseError)?,
+        };
+        if parts.next().is_some() {
+            return Err(ParseError);
+        }
+        Ok(Self {
+            tag: tag.to_string(),
+            value,
+        })
+    }
+}
@@ -1,4 +1,7 @@
-use torin::gaps::Gaps;
+use torin::gaps::{
+    AutoMargins,
+    Gaps,
+};
 
 use crate::parsing::{
     Parse,
@@ -96,3 +99,20 @@ impl Parse for Gaps {
         Ok(paddings)
     }
 }
+
+impl Parse for AutoMargins {
+    fn parse(value: &str) -> Result<Self, ParseError> {
+        let values = value
+            .split_ascii_whitespace()
+            .map(|value| value == "auto")
+            .collect::<Vec<bool>>();
+
+        Ok(match values[..] {
+            [all] => AutoMargins::new(all, all, all, all),
+            [vertical, horizontal] => AutoMargins::new(vertical, horizontal, vertical, horizontal),
+            [top, horizontal, bottom] => AutoMargins::new(top, horizontal, bottom, horizontal),
+            [top, right, bottom, left] => AutoMargins::new(top, right, bottom, left),
+            _ => AutoMargins::default(),
+        })
+    }
+}
@@ -0,0 +1,69 @@
+use torin::{
+    geometry::Length,
+    grid::{
+        GridPlacement,
+        GridTrack,
+    },
+};
+
+use crate::parsing::{
+    Parse,
+    ParseError,
+};
+
+impl Parse for GridTrack {
+    fn parse(value: &str) -> Result<Self, ParseError> {
+        if value == "auto" {
+            Ok(GridTrack::Auto)
+        } else if let Some(fraction) = value.strip_suffix("fr") {
+            Ok(GridTrack::Fr(Length::new(
+                fraction.parse::<f32>().map_err(|_| ParseError)?.max(0.),
+            )))
+        } else {
+            Ok(GridTrack::Pixels(Length::new(
+                value.parse::<f32>().map_err(|_| ParseError)?,
+            )))
+        }
+    }
+}
+
+impl Parse for GridPlacement {
+    fn parse(value: &str) -> Result<Self, ParseError> {
+        // Lines start at 1
+        let parse_line = |line: &str| -> Result<usize, ParseError> {
+            match line.trim().parse::<usize>() {
+                Ok(line) if line > 0 => Ok(line - 1),
+                _ => Err(ParseError),
+            }
+        };
+        let parse_span = |span: &str| -> Result<Option<usize>, ParseError> {
+            span.trim()
+                .strip_prefix("span")
+                .map(|span| span.trim().parse::<usize>().map_err(|_| ParseError))
+                .transpose()
+        };
+
+        let value = value.trim();
+
+        if value == "auto" {
+            return Ok(GridPlacement::default());
+        }
+
+        match value.split_once('/') {
+            // e.g `1 / 3` or `1 / span 2`
+            Some((start, end)) => {
+                let start = parse_line(start)?;
+                let span = match parse_span(end)? {
+                    Some(span) => span,
+                    None => parse_line(end)?.checked_sub(start).ok_or(ParseError)?,
+                };
+                Ok(GridPlacement::new(Some(start), span))
+            }
+            // e.g `2` or `span 2`
+            None => match parse_span(value)? {
+                Some(span) => Ok(GridPlacement::new(None, span)),
+                None => Ok(GridPlacement::new(Some(parse_line(value)?), 1)),
+            },
+        }
+    }
+}
@@ -7,12 +7,14 @@ mod color;
 mod content;
 mod corner_radius;
 mod cursor;
+mod cursor_icon;
 mod decoration;
 mod fill;
 mod focusable;
 mod font;
 mod gaps;
 mod gradient;
+mod grid;
 mod highlight;
 mod image_cover;
 mod overflow;
@@ -22,8 +24,10 @@ mod sampling;
 mod shadow;
 mod size;
 mod text_height;
+mod text_input_hints;
 mod text_shadow;
 mod visible_size;
+mod wrap_content;
 
 pub use aspect_ratio::*;
 pub use border::*;
@@ -42,3 +46,4 @@ pub use sampling::*;
 pub use shadow::*;
 pub use size::*;
 pub use text_height::*;
+pub use text_input_hints::*;
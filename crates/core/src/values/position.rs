@@ -10,6 +10,7 @@ impl Parse for Position {
         Ok(match value {
             "absolute" => Position::new_absolute(),
             "global" => Position::new_global(),
+            "sticky" => Position::new_sticky(),
             _ => Position::new_stacked(),
         })
     }
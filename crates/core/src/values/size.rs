@@ -44,6 +44,11 @@ impl Parse for Size {
             Ok(Size::FillMinimum)
         } else if value.contains("calc") {
             Ok(Size::DynamicCalculations(1.0, Box::new(parse_calc(value)?)))
+        } else if is_calc_expression(value) {
+            Ok(Size::DynamicCalculations(
+                1.0,
+                Box::new(parse_calc_expression(value)?),
+            ))
         } else if value.contains('%') {
             Ok(Size::Percentage(Length::new(
                 value
@@ -66,14 +71,25 @@ impl Parse for Size {
     }
 }
 
-pub fn parse_calc(mut value: &str) -> Result<Vec<DynamicCalculation>, ParseError> {
-    // No need to parse this using nom
+/// Whether the value is an expression without the `calc()` wrapper, e.g `50% - 2 * 10`.
+fn is_calc_expression(value: &str) -> bool {
+    let value = value.trim();
+    value.contains(['+', '*', '/', '(', ')'])
+        || value.strip_prefix('-').unwrap_or(value).contains('-')
+}
 
-    value = value
+pub fn parse_calc(value: &str) -> Result<Vec<DynamicCalculation>, ParseError> {
+    let value = value
         .strip_prefix("calc(")
         .ok_or(ParseError)?
         .strip_suffix(')')
         .ok_or(ParseError)?;
+
+    parse_calc_expression(value)
+}
+
+/// Parse the tokens of an expression like `100% - 50`.
+pub fn parse_calc_expression(value: &str) -> Result<Vec<DynamicCalculation>, ParseError> {
     fn inner_parse(value: &str) -> IResult<&str, Vec<DynamicCalculation>> {
         many1(preceded(
             multispace0,
@@ -94,7 +110,11 @@ pub fn parse_calc(mut value: &str) -> Result<Vec<DynamicCalculation>, ParseError
             )),
         ))(value)
     }
-    let tokens = inner_parse(value).map_err(|_| ParseError)?.1;
+    let (rest, tokens) = inner_parse(value).map_err(|_| ParseError)?;
+
+    if !rest.trim().is_empty() {
+        return Err(ParseError);
+    }
 
     Ok(tokens)
 }
@@ -0,0 +1,112 @@
+use accesskit::Role;
+use winit::window::ImePurpose;
+
+use crate::parsing::{
+    Parse,
+    ParseError,
+};
+
+/// Kind of text expected by an editable, used by the platform to pick the right soft keyboard.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Default)]
+pub enum InputHint {
+    #[default]
+    Text,
+    Email,
+    Url,
+    Numeric,
+    Search,
+    Password,
+    Terminal,
+}
+
+impl InputHint {
+    /// Purpose of the IME for this kind of text.
+    pub fn ime_purpose(&self) -> ImePurpose {
+        match self {
+            Self::Password => ImePurpose::Password,
+            Self::Terminal => ImePurpose::Terminal,
+            _ => ImePurpose::Normal,
+        }
+    }
+
+    /// More specific accessibility role for text inputs expecting this kind of text.
+    pub fn text_input_role(&self) -> Option<Role> {
+        match self {
+            Self::Email => Some(Role::EmailInput),
+            Self::Url => Some(Role::UrlInput),
+            Self::Numeric => Some(Role::NumberInput),
+            Self::Search => Some(Role::SearchInput),
+            Self::Password => Some(Role::PasswordInput),
+            Self::Text | Self::Terminal => None,
+        }
+    }
+}
+
+impl std::fmt::Display for InputHint {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(match self {
+            Self::Text => "text",
+            Self::Email => "email",
+            Self::Url => "url",
+            Self::Numeric => "numeric",
+            Self::Search => "search",
+            Self::Password => "password",
+            Self::Terminal => "terminal",
+        })
+    }
+}
+
+impl Parse for InputHint {
+    fn parse(value: &str) -> Result<Self, ParseError> {
+        Ok(match value {
+            "text" => Self::Text,
+            "email" => Self::Email,
+            "url" => Self::Url,
+            "numeric" => Self::Numeric,
+            "search" => Self::Search,
+            "password" => Self::Password,
+            "terminal" => Self::Terminal,
+            _ => return Err(ParseError),
+        })
+    }
+}
+
+/// Which letters the platform should capitalize automatically while typing.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Default)]
+pub enum AutoCapitalize {
+    #[default]
+    None,
+    Sentences,
+    Words,
+    Characters,
+}
+
+impl Parse for AutoCapitalize {
+    fn parse(value: &str) -> Result<Self, ParseError> {
+        Ok(match value {
+            "none" => Self::None,
+            "sentences" => Self::Sentences,
+            "words" => Self::Words,
+            "characters" => Self::Characters,
+            _ => return Err(ParseError),
+        })
+    }
+}
+
+/// Hints for the platform text input system of an editable element.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct TextInputHints {
+    pub input_hint: InputHint,
+    pub autocapitalize: AutoCapitalize,
+    pub autocorrect: bool,
+}
+
+impl Default for TextInputHints {
+    fn default() -> Self {
+        Self {
+            input_hint: InputHint::default(),
+            autocapitalize: AutoCapitalize::default(),
+            autocorrect: true,
+        }
+    }
+}
@@ -0,0 +1,15 @@
+use torin::wrap_content::WrapContent;
+
+use crate::parsing::{
+    Parse,
+    ParseError,
+};
+
+impl Parse for WrapContent {
+    fn parse(value: &str) -> Result<Self, ParseError> {
+        Ok(match value {
+            "wrap" => WrapContent::Wrap,
+            _ => WrapContent::NoWrap,
+        })
+    }
+}
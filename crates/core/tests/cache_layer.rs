@@ -0,0 +1,61 @@
+use freya::prelude::*;
+use freya_core::states::LayerState;
+use freya_native_core::{
+    real_dom::NodeImmutable,
+    NodeId,
+};
+use freya_testing::prelude::*;
+
+fn cached_layer(utils: &TestingHandler<()>, node_id: NodeId) -> Option<NodeId> {
+    let sdom = utils.sdom();
+    let fdom = sdom.get();
+    let node = fdom.rdom().get(node_id).unwrap();
+    let layer_state = node.get::<LayerState>().unwrap();
+    layer_state.cached_layer
+}
+
+#[tokio::test]
+pub async fn cache_layer() {
+    fn cache_layer_app() -> Element {
+        let mut cached = use_signal(|| true);
+
+        rsx!(
+            rect {
+                width: "100%",
+                height: "100%",
+                cache_layer: "{cached}",
+                onclick: move |_| cached.toggle(),
+                rect {
+                    cache_layer: "true",
+                    label {
+                        "Hello, World!"
+                    }
+                }
+            }
+        )
+    }
+
+    let mut utils = launch_test(cache_layer_app);
+    utils.wait_for_update().await;
+
+    let root = utils.root();
+    let outer_id = root.children_ids()[0];
+    let inner_id = root.get(0).children_ids()[0];
+    let label_id = root.get(0).get(0).children_ids()[0];
+
+    // Nested cached layers are painted as part of the outermost one
+    assert_eq!(cached_layer(&utils, outer_id), Some(outer_id));
+    assert_eq!(cached_layer(&utils, inner_id), Some(outer_id));
+    assert_eq!(cached_layer(&utils, label_id), Some(outer_id));
+
+    // Simulate a render
+    let _ = utils.create_snapshot();
+
+    utils.click_cursor((5., 5.)).await;
+
+    assert_eq!(cached_layer(&utils, outer_id), None);
+    assert_eq!(cached_layer(&utils, inner_id), Some(inner_id));
+    assert_eq!(cached_layer(&utils, label_id), Some(inner_id));
+
+    let _ = utils.create_snapshot();
+}
@@ -0,0 +1,31 @@
+use freya::prelude::*;
+use freya_core::layout::content_size;
+use freya_testing::prelude::*;
+
+#[tokio::test]
+pub async fn content_size_ignores_fill_wrappers() {
+    fn content_size_app() -> Element {
+        rsx!(
+            rect {
+                width: "fill",
+                height: "100%",
+                padding: "10",
+                rect {
+                    width: "120",
+                    height: "80",
+                }
+                label {
+                    width: "auto",
+                    height: "20",
+                    "Hello"
+                }
+            }
+        )
+    }
+
+    let mut utils = launch_test(content_size_app);
+    utils.wait_for_update().await;
+
+    let size = content_size(&utils.sdom().get());
+    assert_eq!(size, Size2D::new(140., 120.));
+}
@@ -0,0 +1,47 @@
+use freya::prelude::*;
+use freya_testing::prelude::*;
+
+#[tokio::test]
+pub async fn cull_offscreen_nodes() {
+    fn culling_app() -> Element {
+        rsx!(
+            rect {
+                height: "300",
+                width: "100%",
+                overflow: "clip",
+                for i in 0..20 {
+                    rect {
+                        key: "{i}",
+                        height: "100",
+                        width: "100%",
+                        background: "red",
+                    }
+                }
+            }
+        )
+    }
+
+    let mut utils = launch_test(culling_app);
+    utils.wait_for_update().await;
+
+    // Simulate a render
+    let _ = utils.create_snapshot();
+
+    {
+        let sdom = utils.sdom();
+        let fdom = sdom.get();
+        let frame_counters = fdom.frame_counters();
+        // Only the first 3 items are inside the viewport
+        assert_eq!(frame_counters.culled_nodes, 17);
+    }
+
+    utils.click_cursor((5., 5.)).await;
+
+    {
+        let sdom = utils.sdom();
+        let fdom = sdom.get();
+        let frame_counters = fdom.frame_counters();
+        // Only the first item is under the cursor
+        assert_eq!(frame_counters.culled_hit_tested_nodes, 19);
+    }
+}
@@ -0,0 +1,71 @@
+use freya::prelude::*;
+use freya_core::{
+    dom::DioxusNode,
+    elements::{
+        register_custom_element,
+        CustomElement,
+    },
+    states::CustomElementState,
+};
+use freya_engine::prelude::{
+    Canvas,
+    FontCollection,
+};
+use freya_native_core::real_dom::NodeImmutable;
+use freya_testing::prelude::*;
+
+struct Square;
+
+impl CustomElement for Square {
+    fn measure(
+        &self,
+        node_ref: &DioxusNode,
+        _available_size: &Size2D,
+        scale_factor: f32,
+    ) -> Option<Size2D> {
+        let state = node_ref.get::<CustomElementState>().unwrap();
+        let side = state.get("square_side")?.parse::<f32>().ok()? * scale_factor;
+        Some(Size2D::new(side, side))
+    }
+
+    fn render(
+        &self,
+        _layout_node: &LayoutNode,
+        _node_ref: &DioxusNode,
+        _canvas: &Canvas,
+        _font_collection: &mut FontCollection,
+        _scale_factor: f32,
+    ) {
+    }
+}
+
+#[tokio::test]
+pub async fn custom_element_measurement() {
+    fn custom_element_app() -> Element {
+        let mut side = use_signal(|| 50);
+
+        rsx!(
+            rect {
+                onclick: move |_| side += 30,
+                test-square {
+                    square_side: "{side}"
+                }
+            }
+        )
+    }
+
+    register_custom_element("test-square", &["square_side"], Square);
+
+    let mut utils = launch_test(custom_element_app);
+    utils.wait_for_update().await;
+
+    let square = utils.root().get(0).get(0);
+    assert_eq!(square.area().unwrap().size, Size2D::new(50., 50.));
+
+    utils.click_cursor((10., 10.)).await;
+    utils.wait_for_update().await;
+
+    // Changing the attributes measures the element again
+    let square = utils.root().get(0).get(0);
+    assert_eq!(square.area().unwrap().size, Size2D::new(80., 80.));
+}
@@ -600,6 +600,51 @@ pub async fn filedrop_events() {
     assert_eq!(root.get(0).style().background, Fill::Color(Color::BLUE));
 }
 
+#[tokio::test]
+pub async fn filehover_events() {
+    fn filehover_events_app() -> Element {
+        let mut hovered = use_signal(|| "None");
+
+        rsx!(
+            rect {
+                height: "50%",
+                width: "100%",
+                onfilehover: move |_| hovered.set("Top"),
+            }
+            rect {
+                height: "50%",
+                width: "100%",
+                onfilehover: move |_| hovered.set("Bottom"),
+                label {
+                    "{hovered}"
+                }
+            }
+        )
+    }
+
+    let mut utils = launch_test(filehover_events_app);
+
+    let root = utils.root();
+    assert_eq!(root.get(1).get(0).get(0).text(), Some("None"));
+
+    // Only the element under the cursor is notified
+    utils.push_event(TestEvent::File {
+        name: EventName::GlobalFileHover,
+        cursor: (5., 400.).into(),
+        file_path: Some(PathBuf::from_str("/nice/path/right.rs").unwrap()),
+    });
+    utils.wait_for_update().await;
+    assert_eq!(root.get(1).get(0).get(0).text(), Some("Bottom"));
+
+    utils.push_event(TestEvent::File {
+        name: EventName::GlobalFileHover,
+        cursor: (5., 5.).into(),
+        file_path: Some(PathBuf::from_str("/nice/path/right.rs").unwrap()),
+    });
+    utils.wait_for_update().await;
+    assert_eq!(root.get(1).get(0).get(0).text(), Some("Top"));
+}
+
 #[tokio::test]
 pub async fn does_bubble_events() {
     fn does_bubble_app() -> Element {
@@ -695,3 +740,529 @@ pub async fn does_not_bubble_global_events() {
 
     assert_eq!(root.get(0).get(1).get(0).text(), Some("2"));
 }
+
+#[tokio::test]
+pub async fn mounted_event() {
+    fn app() -> Element {
+        let mut size = use_signal(|| None);
+        let mut count = use_signal(|| 0);
+
+        rsx!(
+            rect {
+                height: "100%",
+                width: "100%",
+                rect {
+                    height: "100",
+                    width: "200",
+                    padding: "10",
+                    onmounted: move |e: MountedEvent| {
+                        size.set(Some((e.get_area().width(), e.get_inner_area().height())));
+                        count += 1;
+                    }
+                }
+                label {
+                    "{size:?} {count}"
+                }
+            }
+        )
+    }
+
+    let mut utils = launch_test(app);
+
+    let root = utils.root().get(0);
+    let label = root.get(1);
+
+    utils.wait_for_update().await;
+    utils.wait_for_update().await;
+
+    assert_eq!(label.get(0).text(), Some("Some((200.0, 80.0)) 1"));
+
+    // Relayouts don't emit the event again
+    utils.resize((400., 400.).into());
+    utils.wait_for_update().await;
+    utils.wait_for_update().await;
+
+    assert_eq!(label.get(0).text(), Some("Some((200.0, 80.0)) 1"));
+}
+
+#[tokio::test]
+pub async fn mounted_event_handle() {
+    fn app() -> Element {
+        let mut handle = use_signal(|| None::<NodeHandle>);
+        let mut show = use_signal(|| true);
+        let mut state = use_signal(String::new);
+
+        rsx!(
+            rect {
+                height: "100%",
+                width: "100%",
+                onclick: move |_| {
+                    if let Some(handle) = &*handle.read() {
+                        state.set(format!(
+                            "{:?} {}",
+                            handle.area().map(|area| area.width()),
+                            handle.is_mounted()
+                        ));
+                    }
+                    show.set(false);
+                },
+                label {
+                    "{state}"
+                }
+                if show() {
+                    rect {
+                        height: "100",
+                        width: "50%",
+                        onmounted: move |e: MountedEvent| handle.set(Some(e.get_handle())),
+                    }
+                }
+            }
+        )
+    }
+
+    let mut utils = launch_test(app);
+
+    let root = utils.root().get(0);
+
+    utils.wait_for_update().await;
+    utils.wait_for_update().await;
+
+    // The handle follows the layout of the element
+    utils.resize((400., 400.).into());
+    utils.wait_for_update().await;
+    utils.wait_for_update().await;
+
+    utils.click_cursor((5., 5.)).await;
+    utils.wait_for_update().await;
+
+    assert_eq!(root.get(0).get(0).text(), Some("Some(200.0) true"));
+
+    // And knows when the element is removed
+    utils.click_cursor((5., 5.)).await;
+    utils.wait_for_update().await;
+
+    assert_eq!(root.get(0).get(0).text(), Some("None false"));
+}
+
+#[tokio::test]
+pub async fn unmounted_event() {
+    #[allow(non_snake_case)]
+    #[component]
+    fn Child(count: i32) -> Element {
+        let mut unmounted = use_context::<Signal<Vec<f32>>>();
+
+        rsx!(rect {
+            rect {
+                height: "100",
+                width: "{200 - count * 50}",
+                onunmounted: move |e: UnmountedEvent| unmounted.push(e.get_area().width())
+            }
+        })
+    }
+
+    fn app() -> Element {
+        let unmounted = use_context_provider(|| Signal::new(Vec::<f32>::new()));
+        let mut show = use_signal(|| true);
+        let mut count = use_signal(|| 0);
+
+        rsx!(
+            rect {
+                height: "100%",
+                width: "100%",
+                onclick: move |_| {
+                    count += 1;
+                    if count() == 2 {
+                        show.set(false);
+                    }
+                },
+                label {
+                    "{count} {unmounted:?}"
+                }
+                if show() {
+                    Child {
+                        count: count()
+                    }
+                }
+            }
+        )
+    }
+
+    let mut utils = launch_test(app);
+
+    let root = utils.root().get(0);
+
+    utils.wait_for_update().await;
+    utils.wait_for_update().await;
+
+    // Rerenders don't emit the event
+    utils.click_cursor((5., 5.)).await;
+    utils.wait_for_update().await;
+    utils.wait_for_update().await;
+
+    assert_eq!(root.get(0).get(0).text(), Some("1 []"));
+
+    // Removing an ancestor of the listening element, along with its component, emits it once
+    utils.click_cursor((5., 5.)).await;
+    utils.wait_for_update().await;
+    utils.wait_for_update().await;
+
+    assert_eq!(root.get(0).get(0).text(), Some("2 [150.0]"));
+
+    utils.click_cursor((5., 5.)).await;
+    utils.wait_for_update().await;
+    utils.wait_for_update().await;
+
+    assert_eq!(root.get(0).get(0).text(), Some("3 [150.0]"));
+}
+
+#[tokio::test]
+pub async fn keyboard_events_bubble_from_focused_node() {
+    fn app() -> Element {
+        let focus = use_init_focus();
+        let mut outer = use_signal(|| 0);
+        let mut inner = use_signal(|| 0);
+
+        rsx!(
+            rect {
+                onkeydown: move |_| outer += 1,
+                rect {
+                    onkeydown: move |e: KeyboardEvent| {
+                        inner += 1;
+                        if e.code == Code::Escape {
+                            e.stop_propagation();
+                        }
+                    },
+                    // Focused but not listening
+                    rect {
+                        a11y_id: focus.attribute(),
+                        width: "100",
+                        height: "100",
+                    }
+                }
+                label {
+                    "{outer} {inner}"
+                }
+            }
+        )
+    }
+
+    let mut utils = launch_test(app);
+    utils.wait_for_update().await;
+    utils.wait_for_update().await;
+
+    let label = utils.root().get(0).get(1);
+    assert_eq!(label.get(0).text(), Some("0 0"));
+
+    utils.push_event(TestEvent::Keyboard {
+        name: EventName::KeyDown,
+        key: Key::Enter,
+        code: Code::Enter,
+        modifiers: Modifiers::default(),
+    });
+    utils.wait_for_update().await;
+
+    // The closest ancestor receives it and it bubbles up
+    assert_eq!(label.get(0).text(), Some("1 1"));
+
+    utils.push_event(TestEvent::Keyboard {
+        name: EventName::KeyDown,
+        key: Key::Escape,
+        code: Code::Escape,
+        modifiers: Modifiers::default(),
+    });
+    utils.wait_for_update().await;
+
+    // The propagation was stopped
+    assert_eq!(label.get(0).text(), Some("1 2"));
+}
+
+#[tokio::test]
+pub async fn double_click_and_context_menu_events() {
+    fn app() -> Element {
+        let mut clicks = use_signal(|| 0);
+        let mut double_clicks = use_signal(|| 0);
+        let mut context_menus = use_signal(|| 0);
+
+        rsx!(
+            rect {
+                width: "100",
+                height: "100",
+                onclick: move |_| clicks += 1,
+                ondoubleclick: move |_| double_clicks += 1,
+                oncontextmenu: move |_| context_menus += 1,
+            }
+            label {
+                "{clicks} {double_clicks} {context_menus}"
+            }
+        )
+    }
+
+    let mut utils = launch_test(app);
+    utils.wait_for_update().await;
+
+    let label = utils.root().get(1);
+    assert_eq!(label.get(0).text(), Some("0 0 0"));
+
+    // Two quick clicks in the same place are a double-click
+    utils.click_cursor((50., 50.)).await;
+    utils.click_cursor((50., 50.)).await;
+    assert_eq!(label.get(0).text(), Some("2 1 0"));
+
+    // A third click starts over
+    utils.click_cursor((50., 50.)).await;
+    assert_eq!(label.get(0).text(), Some("3 1 0"));
+
+    utils.push_event(TestEvent::Mouse {
+        name: EventName::RightClick,
+        cursor: (50., 50.).into(),
+        button: Some(MouseButton::Right),
+    });
+    utils.wait_for_update().await;
+    assert_eq!(label.get(0).text(), Some("4 1 1"));
+}
+
+#[tokio::test]
+pub async fn prevent_default_events() {
+    fn prevent_default_app() -> Element {
+        let mut outer = use_signal(|| None);
+        let mut global = use_signal(|| None);
+
+        rsx!(
+            rect {
+                onclick: move |e: MouseEvent| outer.set(Some(e.default_action_enabled())),
+                onglobalclick: move |e: MouseEvent| global.set(Some(e.default_action_enabled())),
+                rect {
+                    width: "100",
+                    height: "100",
+                    onclick: move |e: MouseEvent| e.prevent_default(),
+                }
+                label {
+                    "{outer:?} {global:?}"
+                }
+            }
+        )
+    }
+
+    let mut utils = launch_test(prevent_default_app);
+    let label = utils.root().get(0).get(1);
+    utils.wait_for_update().await;
+
+    // Outside of the inner element
+    utils.click_cursor((5., 150.)).await;
+    assert_eq!(label.get(0).text(), Some("Some(true) Some(true)"));
+
+    // The inner element prevents the default action for its ancestors and the global listeners
+    utils.click_cursor((5., 5.)).await;
+    assert_eq!(label.get(0).text(), Some("Some(false) Some(false)"));
+}
+
+#[tokio::test]
+pub async fn focus_and_blur_events() {
+    fn app() -> Element {
+        let mut log = use_signal(Vec::<&str>::new);
+        let mut focus_a = use_focus();
+        let mut focus_b = use_focus();
+        let text = log.read().join(", ");
+
+        rsx!(
+            rect {
+                a11y_id: focus_a.attribute(),
+                width: "100",
+                height: "100",
+                onclick: move |_| focus_a.request_focus(),
+                onfocus: move |_| log.push("focus a"),
+                onblur: move |_| log.push("blur a"),
+            }
+            rect {
+                a11y_id: focus_b.attribute(),
+                width: "100",
+                height: "100",
+                onclick: move |_| focus_b.request_focus(),
+                onfocus: move |_| log.push("focus b"),
+                onblur: move |_| log.push("blur b"),
+            }
+            label {
+                "{text}"
+            }
+        )
+    }
+
+    let mut utils = launch_test(app);
+    let label = utils.root().get(2);
+    utils.wait_for_update().await;
+    assert_eq!(label.get(0).text(), Some(""));
+
+    utils.click_cursor((5., 5.)).await;
+    utils.wait_for_update().await;
+    assert_eq!(label.get(0).text(), Some("focus a"));
+
+    utils.click_cursor((5., 105.)).await;
+    utils.wait_for_update().await;
+    assert_eq!(label.get(0).text(), Some("focus a, blur a, focus b"));
+
+    // Focusing the same element again emits nothing
+    utils.click_cursor((5., 105.)).await;
+    utils.wait_for_update().await;
+    assert_eq!(label.get(0).text(), Some("focus a, blur a, focus b"));
+}
+
+#[tokio::test]
+pub async fn keyboard_repeat_and_location() {
+    fn app() -> Element {
+        let mut log = use_signal(Vec::<String>::new);
+        let text = log.read().join(", ");
+
+        rsx!(
+            rect {
+                onglobalkeydown: move |e: KeyboardEvent| {
+                    log.push(format!("down {:?} {} {:?}", e.key, e.repeat, e.location))
+                },
+                onglobalkeyup: move |e: KeyboardEvent| {
+                    log.push(format!("up {:?} {} {:?}", e.key, e.repeat, e.location))
+                },
+                label {
+                    "{text}"
+                }
+            }
+        )
+    }
+
+    fn shift(name: EventName, repeat: bool) -> PlatformEvent {
+        PlatformEvent {
+            name,
+            data: PlatformEventData::Keyboard {
+                key: Key::Shift,
+                code: Code::ShiftRight,
+                modifiers: Modifiers::SHIFT,
+                repeat,
+                location: Location::Right,
+            },
+        }
+    }
+
+    let mut utils = launch_test(app);
+    let label = utils.root().get(0).get(0);
+    utils.wait_for_update().await;
+
+    // Hold the right shift for a while
+    for event in [
+        shift(EventName::KeyDown, false),
+        shift(EventName::KeyDown, true),
+        shift(EventName::KeyUp, false),
+    ] {
+        utils.push_event(event);
+        utils.wait_for_update().await;
+    }
+
+    assert_eq!(
+        label.get(0).text(),
+        Some("down Shift false Right, down Shift true Right, up Shift false Right")
+    );
+}
+
+#[tokio::test]
+pub async fn cursor_attribute() {
+    fn app() -> Element {
+        rsx!(
+            rect {
+                height: "50%",
+                width: "100%",
+                cursor: "pointer",
+                rect {
+                    height: "50%",
+                    width: "100%",
+                    cursor: "text",
+                }
+            }
+            rect {
+                height: "50%",
+                width: "100%",
+            }
+        )
+    }
+
+    let mut utils = launch_test(app);
+    utils.wait_for_update().await;
+    assert_eq!(utils.cursor_icon(), CursorIcon::default());
+
+    // The child icon has priority
+    utils.move_cursor((100., 50.)).await;
+    assert_eq!(utils.cursor_icon(), CursorIcon::Text);
+
+    // Inherited from the parent
+    utils.move_cursor((100., 150.)).await;
+    assert_eq!(utils.cursor_icon(), CursorIcon::Pointer);
+
+    // Restored when leaving
+    utils.move_cursor((100., 300.)).await;
+    assert_eq!(utils.cursor_icon(), CursorIcon::default());
+}
+
+#[tokio::test]
+pub async fn wheel_phases_and_momentum() {
+    fn app() -> Element {
+        let mut events = use_signal(Vec::new);
+
+        rsx!(
+            rect {
+                height: "100%",
+                width: "100%",
+                onwheel: move |e: WheelEvent| {
+                    events.push((e.get_phase(), e.is_momentum(), e.get_delta_y()));
+                },
+                label {
+                    "{events:?}"
+                }
+            }
+        )
+    }
+
+    let mut utils = launch_test(app);
+
+    let root = utils.root().get(0);
+    utils.wait_for_update().await;
+
+    let gesture = [
+        (WheelPhase::Started, 0.),
+        (WheelPhase::Moved, 10.),
+        (WheelPhase::Ended, 0.),
+        // Momentum after lifting the fingers
+        (WheelPhase::Moved, 5.),
+        (WheelPhase::Moved, 2.),
+        // Until a new gesture starts
+        (WheelPhase::Started, 0.),
+        (WheelPhase::Moved, 3.),
+    ];
+    for (phase, delta_y) in gesture {
+        utils.push_event(TestEvent::TrackpadWheel {
+            name: EventName::Wheel,
+            scroll: (0., delta_y).into(),
+            cursor: (50., 50.).into(),
+            phase,
+        });
+        utils.wait_for_update().await;
+    }
+
+    assert_eq!(
+        root.get(0).get(0).text(),
+        Some(
+            "[(Started, false, 0.0), (Moved, false, 10.0), (Ended, false, 0.0), \
+            (Moved, true, 5.0), (Moved, true, 2.0), (Started, false, 0.0), (Moved, false, 3.0)]"
+        )
+    );
+
+    // Mouse wheels only report scrolls
+    utils.push_event(TestEvent::Wheel {
+        name: EventName::Wheel,
+        scroll: (0., 1.).into(),
+        cursor: (50., 50.).into(),
+    });
+    utils.wait_for_update().await;
+
+    assert!(root
+        .get(0)
+        .get(0)
+        .text()
+        .unwrap()
+        .ends_with("(Moved, false, 3.0), (Moved, false, 1.0)]"));
+}
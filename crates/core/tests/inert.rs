@@ -0,0 +1,78 @@
+use freya::prelude::*;
+use freya_core::accessibility::AccessibilityFocusStrategy;
+use freya_testing::prelude::*;
+
+#[allow(non_snake_case)]
+#[component]
+fn Field(name: String) -> Element {
+    let focus = use_focus();
+
+    rsx!(rect {
+        a11y_id: focus.attribute(),
+        width: "100",
+        height: "20",
+        label {
+            "{name} {focus.is_focused()}"
+        }
+    })
+}
+
+#[tokio::test]
+pub async fn inert() {
+    fn inert_app() -> Element {
+        let mut inert = use_signal(|| true);
+        let mut clicks = use_signal(|| 0);
+        let platform = use_platform();
+
+        rsx!(
+            rect {
+                width: "100",
+                height: "50",
+                onclick: move |_| inert.toggle(),
+            }
+            rect {
+                width: "100",
+                height: "50",
+                onclick: move |_| platform.focus(AccessibilityFocusStrategy::Forward),
+            }
+            rect {
+                inert: "{inert}",
+                width: "100",
+                height: "50",
+                onclick: move |_| clicks += 1,
+                Field { name: "inside" }
+            }
+            Field { name: "outside" }
+            label { "{clicks}" }
+        )
+    }
+
+    let mut utils = launch_test(inert_app);
+    utils.wait_for_update().await;
+    let root = utils.root();
+    let inside = root.get(2).get(0).get(0).get(0);
+    let outside = root.get(3).get(0).get(0);
+    let clicks = root.get(4).get(0);
+
+    // Inert elements don't receive events
+    utils.click_cursor((5., 110.)).await;
+    assert_eq!(clicks.text(), Some("0"));
+
+    // Inert elements are skipped when moving the focus
+    for _ in 0..2 {
+        utils.click_cursor((5., 55.)).await;
+        utils.wait_for_update().await;
+        assert_eq!(inside.text(), Some("inside false"));
+        assert_eq!(outside.text(), Some("outside true"));
+    }
+
+    // Once they aren't inert they can be interacted with again
+    utils.click_cursor((5., 5.)).await;
+    utils.wait_for_update().await;
+    utils.click_cursor((5., 110.)).await;
+    assert_eq!(clicks.text(), Some("1"));
+    utils.click_cursor((5., 55.)).await;
+    utils.wait_for_update().await;
+    assert_eq!(inside.text(), Some("inside true"));
+    assert_eq!(outside.text(), Some("outside false"));
+}
@@ -0,0 +1,65 @@
+use freya::prelude::*;
+use freya_core::accessibility::AccessibilityFocusStrategy;
+use freya_testing::prelude::*;
+
+#[component]
+fn Field(name: String) -> Element {
+    let focus = use_focus();
+
+    rsx!(rect {
+        a11y_id: focus.attribute(),
+        width: "100",
+        height: "20",
+        label {
+            "{name} {focus.is_focused()}"
+        }
+    })
+}
+
+#[tokio::test]
+pub async fn offscreen_focus() {
+    fn offscreen_focus_app() -> Element {
+        let mut offscreen = use_signal(|| true);
+        let platform = use_platform();
+
+        rsx!(
+            rect {
+                width: "100",
+                height: "50",
+                onclick: move |_| offscreen.toggle(),
+            }
+            rect {
+                width: "100",
+                height: "50",
+                onclick: move |_| platform.focus(AccessibilityFocusStrategy::Forward),
+            }
+            rect {
+                offscreen: "{offscreen}",
+                Field { name: "inside" }
+            }
+            Field { name: "outside" }
+        )
+    }
+
+    let mut utils = launch_test(offscreen_focus_app);
+    utils.wait_for_update().await;
+    let root = utils.root();
+    let inside = root.get(2).get(0).get(0).get(0);
+    let outside = root.get(3).get(0).get(0);
+
+    // Offscreen elements are skipped when moving the focus
+    for _ in 0..2 {
+        utils.click_cursor((5., 55.)).await;
+        utils.wait_for_update().await;
+        assert_eq!(inside.text(), Some("inside false"));
+        assert_eq!(outside.text(), Some("outside true"));
+    }
+
+    // Once they are shown they can be focused again
+    utils.click_cursor((5., 5.)).await;
+    utils.wait_for_update().await;
+    utils.click_cursor((5., 55.)).await;
+    utils.wait_for_update().await;
+    assert_eq!(inside.text(), Some("inside true"));
+    assert_eq!(outside.text(), Some("outside false"));
+}
@@ -0,0 +1,31 @@
+use freya_core::{
+    parsing::Parse,
+    values::AspectRatio,
+};
+
+#[test]
+fn parse_keyword_aspect_ratio() {
+    assert_eq!(AspectRatio::parse("max"), Ok(AspectRatio::Max));
+    assert_eq!(AspectRatio::parse("none"), Ok(AspectRatio::None));
+}
+
+#[test]
+fn parse_fraction_aspect_ratio() {
+    assert_eq!(
+        AspectRatio::parse("16/9"),
+        Ok(AspectRatio::Ratio(16.0 / 9.0))
+    );
+    assert_eq!(AspectRatio::parse("4 / 2"), Ok(AspectRatio::Ratio(2.0)));
+}
+
+#[test]
+fn parse_number_aspect_ratio() {
+    assert_eq!(AspectRatio::parse("1.5"), Ok(AspectRatio::Ratio(1.5)));
+}
+
+#[test]
+fn parse_invalid_aspect_ratio() {
+    assert!(AspectRatio::parse("0").is_err());
+    assert!(AspectRatio::parse("16/0").is_err());
+    assert!(AspectRatio::parse("wide").is_err());
+}
@@ -0,0 +1,16 @@
+use freya_core::{
+    parsing::Parse,
+    platform::CursorIcon,
+};
+
+#[test]
+fn parse_cursor_icon() {
+    assert_eq!(CursorIcon::parse("pointer"), Ok(CursorIcon::Pointer));
+    assert_eq!(CursorIcon::parse("col-resize"), Ok(CursorIcon::ColResize));
+}
+
+#[test]
+fn parse_invalid_cursor_icons() {
+    let incorrect_name = CursorIcon::parse("hand");
+    assert!(incorrect_name.is_err());
+}
@@ -0,0 +1,37 @@
+use freya_core::{
+    parsing::Parse,
+    values::OpenTypeFeature,
+};
+
+#[test]
+fn parse_font_feature() {
+    assert_eq!(
+        OpenTypeFeature::parse("tnum"),
+        Ok(OpenTypeFeature {
+            tag: "tnum".to_string(),
+            value: 1
+        })
+    );
+    assert_eq!(
+        OpenTypeFeature::parse(" liga off "),
+        Ok(OpenTypeFeature {
+            tag: "liga".to_string(),
+            value: 0
+        })
+    );
+    assert_eq!(
+        OpenTypeFeature::parse("'salt' 3"),
+        Ok(OpenTypeFeature {
+            tag: "salt".to_string(),
+            value: 3
+        })
+    );
+}
+
+#[test]
+fn parse_invalid_font_feature() {
+    assert!(OpenTypeFeature::parse("ligatures").is_err());
+    assert!(OpenTypeFeature::parse("liga yes").is_err());
+    assert!(OpenTypeFeature::parse("liga 1 2").is_err());
+    assert!(OpenTypeFeature::parse("").is_err());
+}
@@ -1,5 +1,8 @@
 use freya_core::parsing::Parse;
-use torin::gaps::Gaps;
+use torin::gaps::{
+    AutoMargins,
+    Gaps,
+};
 
 #[test]
 fn parse_all_gaps() {
@@ -24,3 +27,18 @@ fn parse_horizontal_axis_and_vertical_sides() {
     let gaps = Gaps::parse("5 50 30");
     assert_eq!(gaps, Ok(Gaps::new(5.0, 50.0, 30.0, 50.0)));
 }
+
+#[test]
+fn parse_auto_margins() {
+    let auto_margins = AutoMargins::parse("0 0 0 auto");
+    assert_eq!(
+        auto_margins,
+        Ok(AutoMargins::new(false, false, false, true))
+    );
+
+    let auto_margins = AutoMargins::parse("auto 10");
+    assert_eq!(auto_margins, Ok(AutoMargins::new(true, false, true, false)));
+
+    let auto_margins = AutoMargins::parse("10");
+    assert_eq!(auto_margins, Ok(AutoMargins::default()));
+}
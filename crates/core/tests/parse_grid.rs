@@ -0,0 +1,47 @@
+use freya_core::parsing::Parse;
+use torin::{
+    geometry::Length,
+    grid::{
+        GridPlacement,
+        GridTrack,
+    },
+};
+
+#[test]
+fn parse_grid_tracks() {
+    assert_eq!(GridTrack::parse("auto"), Ok(GridTrack::Auto));
+    assert_eq!(
+        GridTrack::parse("150"),
+        Ok(GridTrack::Pixels(Length::new(150.0)))
+    );
+    assert_eq!(GridTrack::parse("2fr"), Ok(GridTrack::Fr(Length::new(2.0))));
+    assert!(GridTrack::parse("fr").is_err());
+}
+
+#[test]
+fn parse_grid_placement() {
+    assert_eq!(GridPlacement::parse("auto"), Ok(GridPlacement::default()));
+    assert_eq!(
+        GridPlacement::parse("2"),
+        Ok(GridPlacement::new(Some(1), 1))
+    );
+    assert_eq!(
+        GridPlacement::parse("1 / 3"),
+        Ok(GridPlacement::new(Some(0), 2))
+    );
+    assert_eq!(
+        GridPlacement::parse("2 / span 3"),
+        Ok(GridPlacement::new(Some(1), 3))
+    );
+    assert_eq!(
+        GridPlacement::parse("span 2"),
+        Ok(GridPlacement::new(None, 2))
+    );
+}
+
+#[test]
+fn parse_invalid_grid_placement() {
+    assert!(GridPlacement::parse("0").is_err());
+    assert!(GridPlacement::parse("3 / 1").is_err());
+    assert!(GridPlacement::parse("span").is_err());
+}
@@ -49,3 +49,27 @@ fn parse_calc_size() {
         ))
     );
 }
+
+#[test]
+fn parse_calc_expression_size() {
+    let size = Size::parse("50% - 2 * 10");
+    assert_eq!(
+        size,
+        Ok(Size::DynamicCalculations(
+            1.0,
+            Box::new(vec![
+                DynamicCalculation::Percentage(50.0),
+                DynamicCalculation::Sub,
+                DynamicCalculation::Pixels(2.0),
+                DynamicCalculation::Mul,
+                DynamicCalculation::Pixels(10.0),
+            ])
+        ))
+    );
+
+    let size = Size::parse("-25");
+    assert_eq!(size, Ok(Size::Pixels(Length::new(-25.0))));
+
+    let size = Size::parse("calc(100% - 50) abc");
+    assert!(size.is_err());
+}
@@ -0,0 +1,36 @@
+use accesskit::Role;
+use freya_core::{
+    parsing::Parse,
+    values::{
+        AutoCapitalize,
+        InputHint,
+    },
+};
+use winit::window::ImePurpose;
+
+#[test]
+fn parse_input_hint() {
+    assert_eq!(InputHint::parse("email"), Ok(InputHint::Email));
+    assert_eq!(InputHint::parse("numeric"), Ok(InputHint::Numeric));
+    assert!(InputHint::parse("phone").is_err());
+}
+
+#[test]
+fn input_hint_round_trip() {
+    let hint = InputHint::parse(&InputHint::Search.to_string());
+    assert_eq!(hint, Ok(InputHint::Search));
+}
+
+#[test]
+fn input_hint_platform_mapping() {
+    assert_eq!(InputHint::Password.ime_purpose(), ImePurpose::Password);
+    assert_eq!(InputHint::Email.ime_purpose(), ImePurpose::Normal);
+    assert_eq!(InputHint::Url.text_input_role(), Some(Role::UrlInput));
+    assert_eq!(InputHint::Text.text_input_role(), None);
+}
+
+#[test]
+fn parse_autocapitalize() {
+    assert_eq!(AutoCapitalize::parse("words"), Ok(AutoCapitalize::Words));
+    assert!(AutoCapitalize::parse("all").is_err());
+}
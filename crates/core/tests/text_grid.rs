@@ -0,0 +1,57 @@
+use freya::prelude::*;
+use freya_testing::prelude::*;
+
+#[tokio::test]
+pub async fn text_grid_measurement() {
+    fn text_grid_app() -> Element {
+        let mut columns = use_signal(|| 10);
+        let mut grid = TextGrid::new(columns(), 2);
+        grid.write_str(0, 0, "Hello, World!", TextGridStyle::default());
+
+        rsx!(
+            rect {
+                onclick: move |_| columns += 10,
+                textgrid {
+                    font_size: "16",
+                    text_grid: grid.attribute(),
+                }
+            }
+        )
+    }
+
+    let mut utils = launch_test(text_grid_app);
+    utils.wait_for_update().await;
+
+    let size = utils.root().get(0).get(0).area().unwrap().size;
+    assert!(size.width > 0. && size.height > 0.);
+
+    utils.click_cursor((5., 5.)).await;
+    utils.wait_for_update().await;
+
+    // The grid is as big as its cells
+    let new_size = utils.root().get(0).get(0).area().unwrap().size;
+    assert_eq!(new_size.width, size.width * 2.);
+    assert_eq!(new_size.height, size.height);
+}
+
+#[test]
+pub fn text_grid_cells() {
+    let mut grid = TextGrid::new(4, 2);
+    let bold = TextGridStyle {
+        bold: true,
+        ..Default::default()
+    };
+    grid.write_str(2, 1, "abc", bold);
+
+    assert_eq!(grid.get(2, 1).map(|cell| cell.character), Some('a'));
+    assert_eq!(grid.get(3, 1).map(|cell| cell.style), Some(bold));
+    // Text past the end of the row is cut
+    assert_eq!(grid.get(0, 2), None);
+    assert_eq!(
+        grid.row(1)
+            .iter()
+            .map(|cell| cell.character)
+            .collect::<String>(),
+        "  ab"
+    );
+}
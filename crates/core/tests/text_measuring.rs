@@ -21,3 +21,137 @@ pub async fn auto_sized_center_text() {
     let width = root.get(0).layout().unwrap().area.width();
     assert!(width > 205. && width < 230.);
 }
+
+#[tokio::test]
+pub async fn reuse_unchanged_text_measurements() {
+    fn reuse_unchanged_text_measurements_app() -> Element {
+        let mut width = use_signal(|| 200);
+
+        rsx!(
+            rect {
+                width: "{width}",
+                onclick: move |_| width += 100,
+                label {
+                    max_lines: "1",
+                    "Hello, World!"
+                }
+            }
+        )
+    }
+
+    let mut utils = launch_test(reuse_unchanged_text_measurements_app);
+    utils.wait_for_update().await;
+
+    let hits = utils.sdom().get().paragraph_cache().hits();
+
+    utils.click_cursor((5., 5.)).await;
+
+    // The label is laid out without a width constraint, so resizing its parent doesn't shape it again
+    let paragraph_cache_hits = utils.sdom().get().paragraph_cache().hits();
+    assert!(paragraph_cache_hits > hits);
+
+    let root = utils.root();
+    assert_eq!(root.get(0).layout().unwrap().area.width(), 300.);
+}
+
+#[tokio::test]
+pub async fn text_wrap_modes() {
+    fn text_wrap_modes_app() -> Element {
+        rsx!(
+            rect {
+                width: "100",
+                label {
+                    "Some text that is wrapped"
+                }
+                label {
+                    text_wrap: "none",
+                    width: "auto",
+                    "Some text that is never wrapped"
+                }
+                label {
+                    text_wrap: "char",
+                    "aaaaaa bbbbbb cccccc"
+                }
+                label {
+                    "aaaaaa bbbbbb cccccc"
+                }
+            }
+        )
+    }
+
+    let mut utils = launch_test(text_wrap_modes_app);
+    utils.wait_for_update().await;
+
+    let root = utils.root().get(0);
+    let wrapped = root.get(0).layout().unwrap().area;
+    let not_wrapped = root.get(1).layout().unwrap().area;
+    assert!(wrapped.height() > not_wrapped.height());
+    assert!(not_wrapped.width() > 100.);
+
+    // Breaking between characters fills the lines instead of moving the whole words to the next ones
+    let char_wrapped = root.get(2).layout().unwrap().area;
+    let word_wrapped = root.get(3).layout().unwrap().area;
+    assert!(char_wrapped.height() < word_wrapped.height());
+}
+
+#[test]
+pub fn text_wrap_offsets() {
+    use freya_core::values::TextWrap;
+
+    let text = "a/b.c";
+    assert_eq!(TextWrap::Anywhere.break_text(text), "a/\u{200B}b.\u{200B}c");
+    assert_eq!(TextWrap::Anywhere.to_paragraph_offset(text, 3), 4);
+    assert_eq!(TextWrap::Anywhere.to_paragraph_offset(text, 5), 7);
+    assert_eq!(TextWrap::Anywhere.from_paragraph_offset(text, 4), 3);
+    assert_eq!(TextWrap::Anywhere.from_paragraph_offset(text, 7), 5);
+    // Offsets after the text, like the one of the cursor of an editable at its end
+    assert_eq!(TextWrap::Anywhere.from_paragraph_offset(text, 8), 6);
+
+    assert_eq!(TextWrap::Word.break_text(text), text);
+    assert_eq!(TextWrap::Word.to_paragraph_offset(text, 3), 3);
+}
+
+#[tokio::test]
+pub async fn measure_text_again_with_registered_fonts() {
+    static SANSITA_SWASHED: &[u8] = include_bytes!("../../../examples/SansitaSwashed-Regular.ttf");
+
+    fn measure_text_again_with_registered_fonts_app() -> Element {
+        let mut load_font = use_signal(|| false);
+
+        rsx!(
+            rect {
+                width: "fill",
+                height: "fill",
+                onclick: move |_| load_font.set(true),
+                label {
+                    font_family: "Sansita Swashed",
+                    "Hello, World!"
+                }
+                if load_font() {
+                    FontLoader { }
+                }
+            }
+        )
+    }
+
+    #[component]
+    fn FontLoader() -> Element {
+        use_font("Sansita Swashed", || SANSITA_SWASHED.into());
+
+        Ok(VNode::placeholder())
+    }
+
+    let mut utils = launch_test(measure_text_again_with_registered_fonts_app);
+    utils.wait_for_update().await;
+
+    let root = utils.root();
+    let fallback_width = root.get(0).get(0).layout().unwrap().area.width();
+
+    utils.click_cursor((5., 5.)).await;
+    utils.wait_for_update().await;
+
+    // The label was measured with a fallback font, so it's measured again with the new one
+    let root = utils.root();
+    let width = root.get(0).get(0).layout().unwrap().area.width();
+    assert_ne!(width, fallback_width);
+}
@@ -0,0 +1,93 @@
+use freya_core::{
+    dom::{
+        ImageCacheKey,
+        ImagesCache,
+    },
+    render::{
+        AtlasKey,
+        TextureAtlas,
+    },
+};
+use freya_engine::prelude::*;
+
+fn square(size: i32) -> Image {
+    let mut surface = raster_n32_premul((size, size)).unwrap();
+    surface.canvas().clear(Color::RED);
+    surface.image_snapshot()
+}
+
+#[test]
+fn texture_atlas_packs_small_images() {
+    let mut atlas = TextureAtlas::default();
+
+    atlas.insert(AtlasKey::new("icon"), square(16));
+    atlas.insert(AtlasKey::new("big"), square(256));
+    assert!(!atlas.contains(&AtlasKey::new("icon")));
+
+    atlas.flush(None);
+
+    assert!(atlas.contains(&AtlasKey::new("icon")));
+    assert!(!atlas.contains(&AtlasKey::new("big")));
+}
+
+#[test]
+fn texture_atlas_evicts_least_recently_used_page() {
+    let mut atlas = TextureAtlas::default();
+
+    // 49 items of 128x128 fit in a page, fill all the 4 pages
+    for i in 0..49 * 4 {
+        atlas.insert(AtlasKey::new(i), square(128));
+    }
+    atlas.flush(None);
+    assert!(atlas.contains(&AtlasKey::new(0)));
+
+    atlas.insert(AtlasKey::new("extra"), square(128));
+    atlas.flush(None);
+
+    assert!(atlas.contains(&AtlasKey::new("extra")));
+    assert!(!atlas.contains(&AtlasKey::new(0)));
+    assert!(atlas.contains(&AtlasKey::new(49 * 3)));
+}
+
+#[test]
+fn texture_atlas_removes_changed_images() {
+    let mut images_cache = ImagesCache::default();
+    let cache_key = ImageCacheKey("avatar".to_string());
+    let resized_key = AtlasKey::new(("avatar", 32, 32));
+    let other_key = AtlasKey::new("icon");
+
+    images_cache.insert(cache_key.clone(), square(256));
+    let atlas = images_cache.atlas();
+    atlas.insert_from(cache_key.atlas_source(), resized_key, square(32));
+    atlas.insert(other_key, square(16));
+    atlas.flush(None);
+    assert!(atlas.contains(&resized_key));
+
+    // The image changes, so its resized copy is no longer valid
+    images_cache.insert(cache_key.clone(), square(128));
+    assert!(!images_cache.atlas().contains(&resized_key));
+    assert!(images_cache.atlas().contains(&other_key));
+
+    // The resized copy of the new image is stored again
+    let atlas = images_cache.atlas();
+    atlas.insert_from(cache_key.atlas_source(), resized_key, square(32));
+    atlas.flush(None);
+    assert!(atlas.contains(&resized_key));
+
+    // The image is dropped
+    images_cache.remove(&cache_key);
+    assert!(!images_cache.atlas().contains(&resized_key));
+}
+
+#[test]
+fn texture_atlas_can_be_bypassed() {
+    let mut images_cache = ImagesCache::default();
+    assert!(images_cache.drawing_atlas().is_some());
+
+    // Window portals draw the images directly
+    images_cache.set_bypass_atlas(true);
+    assert!(images_cache.drawing_atlas().is_none());
+
+    images_cache.set_bypass_atlas(false);
+    assert!(images_cache.drawing_atlas().is_some());
+}
@@ -0,0 +1,85 @@
+use freya::prelude::*;
+use freya_core::states::LayerState;
+use freya_native_core::{
+    real_dom::NodeImmutable,
+    NodeId,
+};
+use freya_testing::prelude::*;
+
+fn window_portal(utils: &TestingHandler<()>, node_id: NodeId) -> Option<NodeId> {
+    let sdom = utils.sdom();
+    let fdom = sdom.get();
+    let node = fdom.rdom().get(node_id).unwrap();
+    let layer_state = node.get::<LayerState>().unwrap();
+    layer_state.window_portal
+}
+
+fn window_portals(utils: &TestingHandler<()>) -> Vec<NodeId> {
+    let sdom = utils.sdom();
+    let fdom = sdom.get();
+    let window_portals = fdom.window_portals();
+    window_portals.iter().copied().collect()
+}
+
+#[tokio::test]
+pub async fn window_portal() {
+    fn window_portal_app() -> Element {
+        let mut open = use_signal(|| true);
+        let mut clicks = use_signal(|| 0);
+
+        rsx!(
+            rect {
+                width: "100%",
+                height: "50",
+                overflow: "clip",
+                if open() {
+                    rect {
+                        position: "global",
+                        position_top: "100",
+                        position_left: "0",
+                        width: "100",
+                        height: "100",
+                        window_portal: "true",
+                        onclick: move |_| clicks += 1,
+                        rect {
+                            window_portal: "true",
+                            label {
+                                "{clicks}"
+                            }
+                        }
+                    }
+                }
+            }
+            rect {
+                width: "100%",
+                height: "50",
+                onclick: move |_| open.toggle(),
+            }
+        )
+    }
+
+    let mut utils = launch_test(window_portal_app);
+    utils.wait_for_update().await;
+
+    let root = utils.root();
+    let portal_id = root.get(0).children_ids()[0];
+    let inner_id = root.get(0).get(0).children_ids()[0];
+    let label_id = root.get(0).get(0).get(0).children_ids()[0];
+
+    // Nested portals are rendered as part of the outermost one
+    assert_eq!(window_portal(&utils, portal_id), Some(portal_id));
+    assert_eq!(window_portal(&utils, inner_id), Some(portal_id));
+    assert_eq!(window_portal(&utils, label_id), Some(portal_id));
+    assert_eq!(window_portals(&utils), vec![portal_id]);
+
+    // Simulate a render
+    let _ = utils.create_snapshot();
+
+    // The portal is not clipped by its ancestors
+    utils.click_cursor((5., 105.)).await;
+    assert_eq!(root.get(0).get(0).get(0).get(0).text(), Some("1"));
+
+    // Close the portal
+    utils.click_cursor((5., 75.)).await;
+    assert!(window_portals(&utils).is_empty());
+}
@@ -0,0 +1,211 @@
+use std::{
+    collections::VecDeque,
+    fmt,
+    sync::{
+        Arc,
+        Mutex,
+    },
+    time::SystemTime,
+};
+
+use tracing::{
+    field::{
+        Field,
+        Visit,
+    },
+    Event,
+    Level,
+    Subscriber,
+};
+use tracing_subscriber::{
+    layer::{
+        Context,
+        SubscriberExt,
+    },
+    registry::LookupSpan,
+    util::SubscriberInitExt,
+    Layer,
+};
+
+/// Amount of entries kept, the oldest ones are forgotten first.
+const MAX_ENTRIES: usize = 500;
+
+/// Warning or error reported while the app was running.
+#[derive(Clone, Debug, PartialEq)]
+pub struct ConsoleEntry {
+    pub level: Level,
+    pub time: SystemTime,
+    pub message: String,
+    /// Module where it was reported.
+    pub target: String,
+    /// File and line where it was reported.
+    pub location: Option<String>,
+    /// Names of the spans it was reported in, from the outermost.
+    pub spans: Vec<String>,
+    /// Fields reported along with the message.
+    pub fields: Vec<(String, String)>,
+}
+
+/// Warnings and errors reported with `tracing` while the app runs, shown in the console of the
+/// devtools.
+///
+/// This includes the errors of components not caught by an error boundary,
+/// as Dioxus reports them with `tracing`.
+#[derive(Clone, Default)]
+pub struct ErrorConsole(Arc<Mutex<VecDeque<ConsoleEntry>>>);
+
+impl ErrorConsole {
+    /// Get the collected entries, from the oldest.
+    pub fn entries(&self) -> Vec<ConsoleEntry> {
+        self.0.lock().unwrap().iter().cloned().collect()
+    }
+
+    /// Forget the entries collected so far.
+    pub fn clear(&self) {
+        self.0.lock().unwrap().clear();
+    }
+
+    /// Collect the entries with the global `tracing` subscriber, unless one was set already.
+    ///
+    /// Use it as a [`Layer`] instead to combine it with other layers.
+    pub fn install(&self) {
+        tracing_subscriber::registry()
+            .with(self.clone())
+            .try_init()
+            .ok();
+    }
+
+    fn report(&self, entry: ConsoleEntry) {
+        let mut entries = self.0.lock().unwrap();
+        if entries.len() == MAX_ENTRIES {
+            entries.pop_front();
+        }
+        entries.push_back(entry);
+    }
+}
+
+impl<S> Layer<S> for ErrorConsole
+where
+    S: Subscriber + for<'a> LookupSpan<'a>,
+{
+    fn on_event(&self, event: &Event<'_>, ctx: Context<'_, S>) {
+        let metadata = event.metadata();
+        // More verbose levels are greater
+        if *metadata.level() > Level::WARN {
+            return;
+        }
+
+        let mut visitor = EntryVisitor::default();
+        event.record(&mut visitor);
+        let spans = ctx
+            .event_scope(event)
+            .map(|scope| {
+                scope
+                    .from_root()
+                    .map(|span| span.name().to_string())
+                    .collect()
+            })
+            .unwrap_or_default();
+
+        self.report(ConsoleEntry {
+            level: *metadata.level(),
+            time: SystemTime::now(),
+            message: visitor.message,
+            target: metadata.target().to_string(),
+            location: metadata
+                .file()
+                .map(|file| format!("{file}:{}", metadata.line().unwrap_or_default())),
+            spans,
+            fields: visitor.fields,
+        });
+    }
+}
+
+#[derive(Default)]
+struct EntryVisitor {
+    message: String,
+    fields: Vec<(String, String)>,
+}
+
+impl Visit for EntryVisitor {
+    fn record_str(&mut self, field: &Field, value: &str) {
+        if field.name() == "message" {
+            self.message = value.to_string();
+        } else {
+            self.fields
+                .push((field.name().to_string(), value.to_string()));
+        }
+    }
+
+    fn record_debug(&mut self, field: &Field, value: &dyn fmt::Debug) {
+        if field.name() == "message" {
+            self.message = format!("{value:?}");
+        } else {
+            self.fields
+                .push((field.name().to_string(), format!("{value:?}")));
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use tracing::Level;
+    use tracing_subscriber::layer::SubscriberExt;
+
+    use super::{
+        ErrorConsole,
+        MAX_ENTRIES,
+    };
+
+    #[test]
+    fn collects_warnings_and_errors() {
+        let console = ErrorConsole::default();
+        let subscriber = tracing_subscriber::registry().with(console.clone());
+
+        tracing::subscriber::with_default(subscriber, || {
+            tracing::info!("Not collected");
+            let span = tracing::warn_span!("render");
+            let _guard = span.enter();
+            tracing::warn!(component = "App", "Slow render of {} ms", 40);
+            tracing::error!("Failed");
+        });
+
+        let entries = console.entries();
+        assert_eq!(entries.len(), 2);
+
+        assert_eq!(entries[0].level, Level::WARN);
+        assert_eq!(entries[0].message, "Slow render of 40 ms");
+        assert_eq!(entries[0].spans, vec!["render".to_string()]);
+        assert_eq!(
+            entries[0].fields,
+            vec![("component".to_string(), "App".to_string())]
+        );
+        assert_eq!(entries[0].target, module_path!());
+        assert!(entries[0]
+            .location
+            .as_ref()
+            .is_some_and(|location| location.contains("error_console.rs")));
+
+        assert_eq!(entries[1].level, Level::ERROR);
+        assert_eq!(entries[1].message, "Failed");
+
+        console.clear();
+        assert!(console.entries().is_empty());
+    }
+
+    #[test]
+    fn forgets_the_oldest_entries() {
+        let console = ErrorConsole::default();
+        let subscriber = tracing_subscriber::registry().with(console.clone());
+
+        tracing::subscriber::with_default(subscriber, || {
+            for i in 0..=MAX_ENTRIES {
+                tracing::error!("Error {i}");
+            }
+        });
+
+        let entries = console.entries();
+        assert_eq!(entries.len(), MAX_ENTRIES);
+        assert_eq!(entries[0].message, "Error 1");
+    }
+}
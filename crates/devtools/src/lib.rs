@@ -18,6 +18,7 @@ use freya_hooks::{
     use_applied_theme,
     use_init_theme,
     use_platform,
+    RenderMetrics,
     DARK_THEME,
 };
 use freya_native_core::NodeId;
@@ -30,32 +31,73 @@ use state::{
     DevtoolsState,
 };
 
+mod error_console;
 mod hooks;
 mod node;
 mod property;
 mod state;
 mod tabs;
 
+pub use error_console::*;
 use tabs::{
+    console::*,
     layout::*,
+    renders::*,
     style::*,
     tree::*,
 };
 
 /// Run the [`VirtualDom`] with a sidepanel where the devtools are located.
+///
+/// Its console tab stays empty, use [`DevtoolsBuilder::with_error_console`] to fill it.
 pub fn with_devtools(
     root: fn() -> Element,
     devtools_receiver: DevtoolsReceiver,
     hovered_node: HoveredNode,
 ) -> VirtualDom {
-    VirtualDom::new_with_props(
-        AppWithDevtools,
-        AppWithDevtoolsProps {
+    DevtoolsBuilder::new(root, devtools_receiver, hovered_node).build()
+}
+
+/// Configure the devtools before running the [`VirtualDom`] with them, like [`with_devtools`] does.
+pub struct DevtoolsBuilder {
+    root: fn() -> Element,
+    devtools_receiver: DevtoolsReceiver,
+    hovered_node: HoveredNode,
+    error_console: ErrorConsole,
+}
+
+impl DevtoolsBuilder {
+    pub fn new(
+        root: fn() -> Element,
+        devtools_receiver: DevtoolsReceiver,
+        hovered_node: HoveredNode,
+    ) -> Self {
+        Self {
             root,
             devtools_receiver,
             hovered_node,
-        },
-    )
+            error_console: ErrorConsole::default(),
+        }
+    }
+
+    /// Show the warnings and errors collected by the given [`ErrorConsole`] in the console tab.
+    pub fn with_error_console(mut self, error_console: ErrorConsole) -> Self {
+        self.error_console = error_console;
+        self
+    }
+
+    /// Run the [`VirtualDom`] with a sidepanel where the devtools are located.
+    pub fn build(self) -> VirtualDom {
+        VirtualDom::new_with_props(
+            AppWithDevtools,
+            AppWithDevtoolsProps {
+                root: self.root,
+                devtools_receiver: self.devtools_receiver,
+                hovered_node: self.hovered_node,
+                error_console: self.error_console,
+            },
+        )
+    }
 }
 
 #[derive(Props, Clone)]
@@ -63,6 +105,7 @@ struct AppWithDevtoolsProps {
     root: fn() -> Element,
     devtools_receiver: DevtoolsReceiver,
     hovered_node: HoveredNode,
+    error_console: ErrorConsole,
 }
 
 impl PartialEq for AppWithDevtoolsProps {
@@ -77,6 +120,8 @@ fn AppWithDevtools(props: AppWithDevtoolsProps) -> Element {
     let Root = props.root;
     let devtools_receiver = props.devtools_receiver;
     let hovered_node = props.hovered_node;
+    use_context_provider(RenderMetrics::default);
+    use_context_provider(|| props.error_console);
 
     rsx!(
         NativeContainer {
@@ -157,6 +202,28 @@ pub fn DevtoolsBar() -> Element {
                     }
                 }
             }
+            Link {
+                to: Route::RenderMetricsInspector { },
+                ActivableRoute {
+                    route: Route::RenderMetricsInspector { },
+                    Tab {
+                        label {
+                            "Renders"
+                        }
+                    }
+                }
+            }
+            Link {
+                to: Route::ConsoleInspector { },
+                ActivableRoute {
+                    route: Route::ConsoleInspector { },
+                    Tab {
+                        label {
+                            "Console"
+                        }
+                    }
+                }
+            }
         }
 
         NativeRouter {
@@ -181,6 +248,10 @@ pub enum Route {
                 #[end_layout]
             #[end_nest]
         #[end_layout]
+        #[route("/renders")]
+        RenderMetricsInspector {},
+        #[route("/console")]
+        ConsoleInspector {},
     #[end_layout]
     #[route("/..route")]
     PageNotFound { },
@@ -0,0 +1,227 @@
+use std::time::{
+    Duration,
+    SystemTime,
+    UNIX_EPOCH,
+};
+
+use dioxus::prelude::*;
+use freya_components::*;
+use freya_elements as dioxus_elements;
+use tracing::Level;
+
+use crate::{
+    error_console::{
+        ConsoleEntry,
+        ErrorConsole,
+    },
+    property::Property,
+};
+
+#[allow(non_snake_case)]
+#[component]
+pub fn ConsoleInspector() -> Element {
+    let console = use_hook(try_consume_context::<ErrorConsole>);
+    let mut entries = use_signal(Vec::<ConsoleEntry>::new);
+    let mut filter = use_signal::<Option<Level>>(|| None);
+
+    use_hook({
+        let console = console.clone();
+        move || {
+            let Some(console) = console else {
+                return;
+            };
+            spawn(async move {
+                let mut interval = tokio::time::interval(Duration::from_millis(500));
+                loop {
+                    interval.tick().await;
+                    let new_entries = console.entries();
+                    if *entries.peek() != new_entries {
+                        entries.set(new_entries);
+                    }
+                }
+            });
+        }
+    });
+
+    let errors = entries
+        .read()
+        .iter()
+        .filter(|entry| entry.level == Level::ERROR)
+        .count();
+    let warnings = entries.read().len() - errors;
+
+    let clear = move |_| {
+        if let Some(console) = &console {
+            console.clear();
+        }
+        entries.write().clear();
+    };
+
+    let shown = entries
+        .read()
+        .iter()
+        .rev()
+        .filter(|entry| filter().is_none_or(|level| entry.level == level))
+        .cloned()
+        .collect::<Vec<_>>();
+
+    rsx!(
+        rect {
+            width: "fill",
+            height: "fill",
+            rect {
+                width: "fill",
+                direction: "horizontal",
+                main_align: "space-between",
+                padding: "10",
+                rect {
+                    direction: "horizontal",
+                    spacing: "6",
+                    FilterButton {
+                        active: filter().is_none(),
+                        onpress: move |_| filter.set(None),
+                        text: "All"
+                    }
+                    FilterButton {
+                        active: filter() == Some(Level::ERROR),
+                        onpress: move |_| filter.set(Some(Level::ERROR)),
+                        text: "Errors ({errors})"
+                    }
+                    FilterButton {
+                        active: filter() == Some(Level::WARN),
+                        onpress: move |_| filter.set(Some(Level::WARN)),
+                        text: "Warnings ({warnings})"
+                    }
+                }
+                Button {
+                    onpress: clear,
+                    label {
+                        "Clear"
+                    }
+                }
+            }
+            if shown.is_empty() {
+                rect {
+                    main_align: "center",
+                    cross_align: "center",
+                    width: "fill",
+                    height: "fill",
+                    label {
+                        "No warnings or errors were reported."
+                    }
+                }
+            } else {
+                ScrollView {
+                    show_scrollbar: true,
+                    height: "fill",
+                    width: "fill",
+                    padding: "0 10 10 10",
+                    spacing: "8",
+                    for (i, entry) in shown.into_iter().enumerate() {
+                        ConsoleEntryView {
+                            key: "{i}",
+                            entry
+                        }
+                    }
+                }
+            }
+        }
+    )
+}
+
+#[allow(non_snake_case)]
+#[component]
+fn FilterButton(active: bool, onpress: EventHandler<PressEvent>, text: String) -> Element {
+    if active {
+        rsx!(FilledButton {
+            onpress,
+            label {
+                "{text}"
+            }
+        })
+    } else {
+        rsx!(Button {
+            onpress,
+            label {
+                "{text}"
+            }
+        })
+    }
+}
+
+#[allow(non_snake_case)]
+#[component]
+fn ConsoleEntryView(entry: ConsoleEntry) -> Element {
+    let level_color = if entry.level == Level::ERROR {
+        "rgb(240, 90, 90)"
+    } else {
+        "rgb(240, 200, 90)"
+    };
+
+    rsx!(
+        rect {
+            width: "fill",
+            padding: "8 10",
+            spacing: "4",
+            corner_radius: "8",
+            background: "rgb(30, 30, 30)",
+            paragraph {
+                width: "fill",
+                text {
+                    font_size: "15",
+                    font_weight: "bold",
+                    color: "{level_color}",
+                    "{entry.level} "
+                }
+                text {
+                    font_size: "13",
+                    color: "rgb(160, 160, 160)",
+                    "{format_time(entry.time)}"
+                }
+            }
+            paragraph {
+                width: "fill",
+                text {
+                    font_size: "15",
+                    "{entry.message}"
+                }
+            }
+            Property {
+                name: "Target",
+                value: entry.target
+            }
+            if let Some(location) = entry.location {
+                Property {
+                    name: "Location",
+                    value: location
+                }
+            }
+            if !entry.spans.is_empty() {
+                Property {
+                    name: "Spans",
+                    value: entry.spans.join(" > ")
+                }
+            }
+            for (i, (name, value)) in entry.fields.into_iter().enumerate() {
+                Property {
+                    key: "{i}",
+                    name,
+                    value
+                }
+            }
+        }
+    )
+}
+
+/// Time of the day in UTC, with milliseconds.
+fn format_time(time: SystemTime) -> String {
+    let elapsed = time.duration_since(UNIX_EPOCH).unwrap_or_default();
+    let seconds = elapsed.as_secs() % 86_400;
+    format!(
+        "{:02}:{:02}:{:02}.{:03} UTC",
+        seconds / 3600,
+        seconds / 60 % 60,
+        seconds % 60,
+        elapsed.subsec_millis()
+    )
+}
@@ -1,3 +1,5 @@
+pub mod console;
 pub mod layout;
+pub mod renders;
 pub mod style;
 pub mod tree;
@@ -0,0 +1,81 @@
+use std::time::Duration;
+
+use dioxus::prelude::*;
+use freya_components::*;
+use freya_elements as dioxus_elements;
+use freya_hooks::{
+    RenderInfo,
+    RenderMetrics,
+};
+
+use crate::property::Property;
+
+#[allow(non_snake_case)]
+#[component]
+pub fn RenderMetricsInspector() -> Element {
+    let metrics = use_hook(try_consume_context::<RenderMetrics>);
+    let mut snapshot = use_signal(Vec::<(String, RenderInfo)>::new);
+
+    use_hook(move || {
+        let Some(metrics) = metrics else {
+            return;
+        };
+        spawn(async move {
+            let mut interval = tokio::time::interval(Duration::from_millis(500));
+            loop {
+                interval.tick().await;
+                let new_snapshot = metrics.snapshot();
+                if *snapshot.peek() != new_snapshot {
+                    snapshot.set(new_snapshot);
+                }
+            }
+        });
+    });
+
+    if snapshot.read().is_empty() {
+        return rsx!(
+            rect {
+                main_align: "center",
+                cross_align: "center",
+                width: "fill",
+                height: "fill",
+                label {
+                    "Use `use_render_info` in your components to see their renders."
+                }
+            }
+        );
+    }
+
+    rsx!(
+        ScrollView {
+            show_scrollbar: true,
+            height: "fill",
+            width: "fill",
+            padding: "10",
+            spacing: "10",
+            for (location, info) in snapshot.read().iter() {
+                rect {
+                    key: "{location}",
+                    width: "fill",
+                    label {
+                        font_size: "15",
+                        font_weight: "bold",
+                        "{location}"
+                    }
+                    Property {
+                        name: "Renders",
+                        value: info.renders.to_string()
+                    }
+                    Property {
+                        name: "Last render",
+                        value: format!("{:?}", info.last_render_duration)
+                    }
+                    Property {
+                        name: "Changed",
+                        value: info.changed_dependencies.join(", ")
+                    }
+                }
+            }
+        }
+    )
+}
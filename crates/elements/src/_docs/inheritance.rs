@@ -13,6 +13,7 @@
 //! - `max_lines`
 //! - `letter_spacing`
 //! - `word_spacing`
+//! - `font_features`
 //! - `decoration`
 //! - `decoration_style`
 //! - `decoration_color`
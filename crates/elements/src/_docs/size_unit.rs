@@ -57,6 +57,8 @@
 //! }
 //! ```
 //!
+//! The `calc()` wrapper is optional, so `width: "50% - 2 * 10"` is the same as `width: "calc(50% - 2 * 10)"`.
+//!
 //! #### fill
 //! Use the remaining available space from the parent area:
 //!
@@ -4,6 +4,30 @@ def_attribute!(
     a11y_id,
     a11y_focusable,
     a11y_auto_focus,
+    /// Focus the element once it's mounted, useful to decide which element of a dialog or form
+    /// receives the focus when it's shown. Same as `a11y_auto_focus`.
+    ///
+    /// The focus is resolved after the element has been laid out. See also `use_init_focus`.
+    ///
+    /// ### Example
+    ///
+    /// ```rust, no_run
+    /// # use freya::prelude::*;
+    /// fn app() -> Element {
+    ///     let focus = use_focus();
+    ///
+    ///     rsx!(
+    ///         rect {
+    ///             a11y_id: focus.attribute(),
+    ///             autofocus: "true",
+    ///             label {
+    ///                 "Focused on mount: {focus.is_focused()}"
+    ///             }
+    ///         }
+    ///     )
+    /// }
+    /// ```
+    autofocus,
     a11y_name,
     a11y_description,
     a11y_value,
@@ -379,6 +379,39 @@ def_attribute!(
     /// ```
     text_overflow,
 
+    /// Determines where the lines of a text can be broken when it doesn't fit in the width of its element.
+    ///
+    /// Accepted values:
+    ///
+    /// - `word` (default): Break lines between words.
+    /// - `char`: Break lines between any characters, useful for CJK text or long tokens.
+    /// - `anywhere`: Break lines between words and also after the separators inside of them (e.g `/`, `.` or `-`), useful for long URLs and paths.
+    /// - `none`: Never break lines. Combined with `width: "auto"` inside a horizontal `ScrollView` the text can be scrolled horizontally.
+    ///
+    /// ### Example
+    ///
+    /// ```rust, no_run
+    /// # use freya::prelude::*;
+    /// fn app() -> Element {
+    ///     rsx!(
+    ///         label {
+    ///             width: "150",
+    ///             text_wrap: "anywhere",
+    ///             "https://github.com/marc2332/freya/blob/main/crates/elements/src/attributes/font_style_attributes.rs"
+    ///         }
+    ///         ScrollView {
+    ///             direction: "horizontal",
+    ///             height: "auto",
+    ///             label {
+    ///                 text_wrap: "none",
+    ///                 "This text is never wrapped, so it can be scrolled horizontally"
+    ///             }
+    ///         }
+    ///     )
+    /// }
+    /// ```
+    text_wrap,
+
     /// Specify the spacing between characters of the text.
     ///
     /// ### Example
@@ -413,6 +446,38 @@ def_attribute!(
     /// ```
     word_spacing,
 
+    /// Enable or disable OpenType features of the font, as a comma separated list of
+    /// four letter tags optionally followed by their value. Features without a value are enabled,
+    /// and `0` or `off` disables them.
+    ///
+    /// Some commonly used features:
+    ///
+    /// - `liga`: Standard ligatures.
+    /// - `calt`: Contextual alternates, used by code fonts for ligatures like `=>`.
+    /// - `tnum`: Tabular numerals, so all the digits have the same width.
+    /// - `zero`: Slashed zero.
+    /// - `ss01` to `ss20`: Stylistic sets.
+    ///
+    /// ### Example
+    ///
+    /// ```rust, no_run
+    /// # use freya::prelude::*;
+    /// fn app() -> Element {
+    ///     rsx!(
+    ///         label {
+    ///             font_family: "Fira Code",
+    ///             font_features: "calt, liga, zero",
+    ///             "if a != b => 0"
+    ///         }
+    ///         label {
+    ///             font_features: "tnum, liga 0",
+    ///             "1,111.10"
+    ///         }
+    ///     )
+    /// }
+    /// ```
+    font_features,
+
     /// Specify the text height behavior.
     ///
     /// Accepted values:
@@ -436,4 +501,29 @@ def_attribute!(
     /// }
     /// ```
     text_height,
+
+    /// Specify the direction in which the text flows.
+    /// Right-to-left text such as Arabic or Hebrew should use `rtl`, which also makes `text_align: "start"` align to the right.
+    /// Mixed-direction content is reordered following the Unicode Bidirectional Algorithm.
+    /// The `direction` attribute accepts the same values, e.g. `direction: "rtl"`.
+    ///
+    /// Accepted values:
+    ///
+    /// - `ltr` (default)
+    /// - `rtl`
+    ///
+    /// ### Example
+    ///
+    /// ```rust, no_run
+    /// # use freya::prelude::*;
+    /// fn app() -> Element {
+    ///     rsx!(
+    ///         label {
+    ///             text_direction: "rtl",
+    ///             "שלום עולם"
+    ///         }
+    ///     )
+    /// }
+    /// ```
+    text_direction,
 );
@@ -55,6 +55,9 @@ def_attribute!(
     /// - `none`: The image will be rendered stretching in all the maximum dimensions.
     /// - `min` (default): The image will be rendered with the minimum dimensions possible.
     /// - `max`: The image will be rendered with the maximum dimensions possible.
+    /// - A ratio such as `16/9` or `1.5`: The `auto` dimension of any element will be derived from the other one,
+    ///   e.g a `width` of `320` and a `height` of `auto` with `16/9` result in a height of `180`.
+    ///   It has no effect if both or neither dimensions are `auto`. Images are rendered as with `min`.
     ///
     /// ### Example
     ///
@@ -74,6 +77,19 @@ def_attribute!(
     ///     )
     /// }
     /// ```
+    ///
+    /// ```rust, no_run
+    /// # use freya::prelude::*;
+    /// fn app() -> Element {
+    ///     rsx!(
+    ///         rect {
+    ///             width: "100%",
+    ///             aspect_ratio: "16/9",
+    ///             background: "black",
+    ///         }
+    ///     )
+    /// }
+    /// ```
     aspect_ratio,
 
     /// `cover` controls how an `image` element position is rendered inside the given dimensions.
@@ -185,6 +185,26 @@ def_attribute!(
     ///     )
     /// }
     /// ```
+    ///
+    /// Sides in the main axis of the parent can also be `auto`, they take an equal share of the free space of the parent.
+    /// For example, an `auto` left margin pushes an element to the end of a `horizontal` parent:
+    ///
+    /// ```rust, no_run
+    /// # use freya::prelude::*;
+    /// fn app() -> Element {
+    ///     rsx!(
+    ///         rect {
+    ///             width: "fill",
+    ///             direction: "horizontal",
+    ///             label { "Title" }
+    ///             label {
+    ///                 margin: "0 0 0 auto",
+    ///                 "Settings"
+    ///             }
+    ///         }
+    ///     )
+    /// }
+    /// ```
     margin,
 
     /// Specify the inner paddings of an element. You can do so by four different ways, just like in CSS.
@@ -213,8 +233,9 @@ def_attribute!(
     /// - `stacked` (default)
     /// - `absolute` (Floating element relative to the parent element)
     /// - `global` (Floating element relative to the window)
+    /// - `sticky` (Stacked element that stays inside the visible area of its closest scroll container, e.g a `ScrollView`)
     ///
-    /// When using the `absolute` or `global` modes, you can also combine them with the following attributes:
+    /// When using the `absolute`, `global` or `sticky` modes, you can also combine them with the following attributes:
     ///
     /// - `position_top`
     /// - `position_right`
@@ -244,9 +265,40 @@ def_attribute!(
     ///     )
     /// }
     /// ```
+    ///
+    /// Sticky elements are useful for section headers, they are pinned at the given distances from the edges of the
+    /// scrolled viewport but never leave their parent, so the next section pushes them away.
+    /// Use the `layer` attribute to draw them over the rest of the content.
+    ///
+    /// ```rust, no_run
+    /// # use freya::prelude::*;
+    /// fn app() -> Element {
+    ///     rsx!(
+    ///         ScrollView {
+    ///             for section in 0..5 {
+    ///                 rect {
+    ///                     key: "{section}",
+    ///                     width: "fill",
+    ///                     rect {
+    ///                         position: "sticky",
+    ///                         position_top: "0",
+    ///                         layer: "-1",
+    ///                         width: "fill",
+    ///                         background: "white",
+    ///                         label { "Section {section}" }
+    ///                     }
+    ///                     for item in 0..10 {
+    ///                         label { key: "{item}", "Item {item}" }
+    ///                     }
+    ///                 }
+    ///             }
+    ///         }
+    ///     )
+    /// }
+    /// ```
     position,
 
-    /// Specify the top position of an element when using `position: "absolute"` or `position: "global"`.
+    /// Specify the top position of an element when using `position: "absolute"`, `position: "global"` or `position: "sticky"`.
     /// This supports pixels only.
     ///
     /// ### Example
@@ -272,7 +324,7 @@ def_attribute!(
     /// ```
     position_top,
 
-    /// Specify the right position of an element when using `position: "absolute"` or `position: "global"`.
+    /// Specify the right position of an element when using `position: "absolute"`, `position: "global"` or `position: "sticky"`.
     /// This supports pixels only.
     ///
     /// ### Example
@@ -298,7 +350,7 @@ def_attribute!(
     /// ```
     position_right,
 
-    /// Specify the bottom position of an element when using `position: "absolute"` or `position: "global"`.
+    /// Specify the bottom position of an element when using `position: "absolute"`, `position: "global"` or `position: "sticky"`.
     /// This supports pixels only.
     ///
     /// ### Example
@@ -324,7 +376,7 @@ def_attribute!(
     /// ```
     position_bottom,
 
-    /// Specify the left position of an element when using `position: "absolute"` or `position: "global"`.
+    /// Specify the left position of an element when using `position: "absolute"`, `position: "global"` or `position: "sticky"`.
     /// This supports pixels only.
     ///
     /// ### Example
@@ -357,6 +409,9 @@ def_attribute!(
     /// - `vertical` (default)
     /// - `horizontal`
     ///
+    /// It also accepts `ltr` and `rtl` to specify the direction in which the text flows,
+    /// just like the `text_direction` attribute.
+    ///
     /// ### Example
     ///
     /// ```rust, no_run
@@ -390,6 +445,7 @@ def_attribute!(
     /// - `normal` (default): Uses parent bounds.
     /// - `fit`: Uses parent bounds but later shrunks to the size of the biggest element inside.
     /// - `flex`: Marks the container as flex container, children of this element will be able to use `size`/`size(n)` in their `width` and `height` attributes.
+    /// - `grid`: Places the children of this element in the cells of a grid, see [`grid_template_columns`](#grid_template_columns).
     ///
     ///
     /// ### `fit`
@@ -431,6 +487,266 @@ def_attribute!(
     /// ```
     content,
 
+    /// Specify whether the inner elements that overflow the main axis flow onto a new line (or column, in a vertical `direction`).
+    ///
+    /// Accepted values:
+    ///
+    /// - `no-wrap` (default)
+    /// - `wrap`
+    ///
+    /// `spacing` is used as the gap between the elements and between the lines,
+    /// use two values (`spacing: "16 8"`) for a different gap between the lines.
+    /// `main_align` and `cross_align` are applied to every line on its own.
+    ///
+    /// ### Example
+    ///
+    /// ```rust, no_run
+    /// # use freya::prelude::*;
+    /// fn app() -> Element {
+    ///     rsx!(
+    ///         rect {
+    ///             direction: "horizontal",
+    ///             wrap_content: "wrap",
+    ///             spacing: "8",
+    ///             width: "200",
+    ///             for tag in ["rust", "gui", "skia", "dioxus", "layout"] {
+    ///                 rect {
+    ///                     key: "{tag}",
+    ///                     padding: "4 8",
+    ///                     corner_radius: "8",
+    ///                     background: "rgb(230, 230, 230)",
+    ///                     label { "{tag}" }
+    ///                 }
+    ///             }
+    ///         }
+    ///     )
+    /// }
+    /// ```
+    wrap_content,
+
+    /// Specify how much of the leftover space of its parent this element takes on top of its own size,
+    /// relative to the `flex_grow` of its siblings. It only applies along the main axis of a parent with `content: "flex"`.
+    ///
+    /// Unlike `flex(n)` sizes, the element starts from its `flex_basis`, which is its own `width`/`height` by default.
+    ///
+    /// ### Example
+    ///
+    /// ```rust, no_run
+    /// # use freya::prelude::*;
+    /// fn app() -> Element {
+    ///     rsx!(
+    ///         rect {
+    ///             content: "flex",
+    ///             direction: "horizontal",
+    ///             width: "500",
+    ///             rect {
+    ///                 width: "100",
+    ///                 height: "50",
+    ///                 flex_grow: "1", // Will have a width of 200px
+    ///                 background: "red",
+    ///             }
+    ///             rect {
+    ///                 width: "100",
+    ///                 height: "50",
+    ///                 flex_grow: "2", // Will have a width of 300px
+    ///                 background: "blue",
+    ///             }
+    ///         }
+    ///     )
+    /// }
+    /// ```
+    flex_grow,
+
+    /// Specify how much this element shrinks when its siblings don't fit in its parent,
+    /// relative to its size and the `flex_shrink` of its siblings. It only applies along the main axis of a parent with `content: "flex"`.
+    ///
+    /// Elements don't shrink by default.
+    ///
+    /// ### Example
+    ///
+    /// ```rust, no_run
+    /// # use freya::prelude::*;
+    /// fn app() -> Element {
+    ///     rsx!(
+    ///         rect {
+    ///             content: "flex",
+    ///             direction: "horizontal",
+    ///             width: "300",
+    ///             rect {
+    ///                 width: "200",
+    ///                 height: "50",
+    ///                 flex_shrink: "1", // Will have a width of 150px
+    ///                 background: "red",
+    ///             }
+    ///             rect {
+    ///                 width: "200",
+    ///                 height: "50",
+    ///                 flex_shrink: "1", // Will have a width of 150px
+    ///                 background: "blue",
+    ///             }
+    ///         }
+    ///     )
+    /// }
+    /// ```
+    flex_shrink,
+
+    /// Specify the size along the main axis from where this element grows or shrinks in a parent with `content: "flex"`,
+    /// with the same values as `width`/`height`. Defaults to `auto`, which is the own `width`/`height` of the element.
+    ///
+    /// ### Example
+    ///
+    /// ```rust, no_run
+    /// # use freya::prelude::*;
+    /// fn app() -> Element {
+    ///     rsx!(
+    ///         rect {
+    ///             content: "flex",
+    ///             direction: "horizontal",
+    ///             width: "500",
+    ///             rect {
+    ///                 width: "50",
+    ///                 height: "50",
+    ///                 flex_basis: "200",
+    ///                 flex_grow: "1", // Will have a width of 400px
+    ///                 background: "red",
+    ///             }
+    ///             rect {
+    ///                 width: "50",
+    ///                 height: "50",
+    ///                 flex_basis: "20%", // Will have a width of 100px
+    ///                 background: "blue",
+    ///             }
+    ///         }
+    ///     )
+    /// }
+    /// ```
+    flex_basis,
+
+    /// Specify the columns of an element with `content: "grid"`, separated by spaces.
+    ///
+    /// Accepted values for each column:
+    ///
+    /// - Fixed size: `100`.
+    /// - Fraction of the space left by the rest of columns: `1fr`, `2fr`...
+    /// - `auto`: As wide as the widest element inside it.
+    ///
+    /// `spacing` is used as the gap between columns and rows,
+    /// with two values the first one is used for the cross axis and the second one for the main axis.
+    ///
+    /// ### Example
+    ///
+    /// ```rust, no_run
+    /// # use freya::prelude::*;
+    /// fn app() -> Element {
+    ///     rsx!(
+    ///         rect {
+    ///             content: "grid",
+    ///             width: "400",
+    ///             height: "200",
+    ///             spacing: "10",
+    ///             grid_template_columns: "100 1fr 2fr",
+    ///             grid_template_rows: "auto 1fr",
+    ///             rect {
+    ///                 width: "100%",
+    ///                 height: "50",
+    ///                 background: "red",
+    ///             }
+    ///             rect {
+    ///                 width: "100%",
+    ///                 height: "100%",
+    ///                 background: "green",
+    ///             }
+    ///         }
+    ///     )
+    /// }
+    /// ```
+    grid_template_columns,
+
+    /// Specify the rows of an element with `content: "grid"`, separated by spaces.
+    /// Rows are added as needed with an `auto` size.
+    ///
+    /// Accepts the same values as [`grid_template_columns`](#grid_template_columns).
+    ///
+    /// ### Example
+    ///
+    /// ```rust, no_run
+    /// # use freya::prelude::*;
+    /// fn app() -> Element {
+    ///     rsx!(
+    ///         rect {
+    ///             content: "grid",
+    ///             width: "200",
+    ///             height: "200",
+    ///             grid_template_columns: "1fr 1fr",
+    ///             grid_template_rows: "1fr 3fr",
+    ///         }
+    ///     )
+    /// }
+    /// ```
+    grid_template_rows,
+
+    /// Specify the columns of its parent grid this element occupies.
+    /// By default elements take the next free cell.
+    ///
+    /// Accepted values:
+    ///
+    /// - `auto` (default)
+    /// - Starting column, beginning at `1`: `2`.
+    /// - Starting and ending lines: `1 / 3`.
+    /// - Starting column and span: `1 / span 2`.
+    /// - Just the span: `span 2`.
+    ///
+    /// ### Example
+    ///
+    /// ```rust, no_run
+    /// # use freya::prelude::*;
+    /// fn app() -> Element {
+    ///     rsx!(
+    ///         rect {
+    ///             content: "grid",
+    ///             width: "300",
+    ///             height: "200",
+    ///             grid_template_columns: "1fr 1fr 1fr",
+    ///             rect {
+    ///                 grid_column: "2 / span 2", // Will take the second and third columns
+    ///                 width: "100%",
+    ///                 height: "100%",
+    ///                 background: "red",
+    ///             }
+    ///         }
+    ///     )
+    /// }
+    /// ```
+    grid_column,
+
+    /// Specify the rows of its parent grid this element occupies.
+    ///
+    /// Accepts the same values as [`grid_column`](#grid_column).
+    ///
+    /// ### Example
+    ///
+    /// ```rust, no_run
+    /// # use freya::prelude::*;
+    /// fn app() -> Element {
+    ///     rsx!(
+    ///         rect {
+    ///             content: "grid",
+    ///             width: "200",
+    ///             height: "200",
+    ///             grid_template_columns: "1fr 1fr",
+    ///             grid_template_rows: "1fr 1fr",
+    ///             rect {
+    ///                 grid_row: "1 / span 2", // Will take both rows
+    ///                 width: "100%",
+    ///                 height: "100%",
+    ///                 background: "red",
+    ///             }
+    ///         }
+    ///     )
+    /// }
+    /// ```
+    grid_row,
+
     /// ### main_align
     ///
     /// Control how the inner elements are positioned inside the element. You can combine it with the `direction` attribute to create complex flows.
@@ -504,9 +820,46 @@ def_attribute!(
     /// ```
     cross_align,
 
+    /// ### align_self
+    ///
+    /// Override the `cross_align` of the parent for this element only.
+    ///
+    /// Accepted values:
+    ///
+    /// - `auto` (default): Use the `cross_align` of the parent
+    /// - `start`
+    /// - `center`
+    /// - `end`
+    ///
+    /// ### Example
+    ///
+    /// ```rust, no_run
+    /// # use freya::prelude::*;
+    /// fn app() -> Element {
+    ///     rsx!(
+    ///         rect {
+    ///             width: "100%",
+    ///             height: "100%",
+    ///             direction: "horizontal",
+    ///             cross_align: "center",
+    ///             label { "Centered" }
+    ///             label {
+    ///                 align_self: "end",
+    ///                 "At the bottom"
+    ///             }
+    ///         }
+    ///     )
+    /// }
+    /// ```
+    align_self,
+
     /// Specify a space between the inner elements. Think it as a margin for every element but defined by its parent.
     /// It only applies to the side of the direction.
     ///
+    /// Accepts one value, or two for the cross and main axis (`spacing: "16 8"`).
+    /// The cross axis spacing is used between the lines of wrapped content (`wrap_content: "wrap"`)
+    /// and between the tracks of grids (`content: "grid"`), it's the same as the main axis spacing when not specified.
+    ///
     /// ### Example
     ///
     /// ```rust, no_run
@@ -571,6 +924,7 @@ def_attribute!(
 
     /// Moves the content inside of the container horizontally by the specified pixel amount.
     /// This attribute only supports pixels.
+    /// Declaring it, even as `0`, makes the element a scroll container for `sticky` descendants.
     ///
     /// ### Example
     ///
@@ -596,6 +950,7 @@ def_attribute!(
 
     /// Moves the content inside of the container vertically by the specified pixel amount.
     /// This attribute only supports pixels.
+    /// Declaring it, even as `0`, makes the element a scroll container for `sticky` descendants.
     ///
     /// ### Example
     ///
@@ -6,6 +6,7 @@ mod reference_attributes;
 mod style_attributes;
 mod svg_attributes;
 mod text_editing_attributes;
+mod text_grid_attributes;
 mod transform_attributes;
 
 pub use accessibility_attributes::*;
@@ -16,4 +17,5 @@ pub use reference_attributes::*;
 pub use style_attributes::*;
 pub use svg_attributes::*;
 pub use text_editing_attributes::*;
+pub use text_grid_attributes::*;
 pub use transform_attributes::*;
@@ -67,6 +67,26 @@ def_attribute!(
     /// ```
     reference,
 
+    /// Attach a reference to an element to track its computed style, once its inherited and
+    /// default values are resolved. Obtained from the `use_computed_style` hook.
+    ///
+    /// ### Example
+    ///
+    /// ```rust, no_run
+    /// # use freya::prelude::*;
+    /// fn app() -> Element {
+    ///     let (style_reference, style) = use_computed_style();
+    ///
+    ///     rsx!(
+    ///         label {
+    ///             style_reference,
+    ///             "Font size: {style.read().font_style.font_size}"
+    ///         }
+    ///     )
+    /// }
+    /// ```
+    style_reference,
+
     /// This attribute is typically used with text components or custom editors that need to
     /// control cursor placement and selection programmatically. It's obtained from hooks like
     /// `use_editable` that manage text editing functionality.
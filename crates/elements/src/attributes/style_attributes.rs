@@ -257,4 +257,142 @@ def_attribute!(
     /// }
     /// ```
     backdrop_blur,
+
+    /// Change the icon of the mouse cursor while it hovers this element or any of its children,
+    /// unless they specify their own. The previous icon is restored when the cursor leaves it.
+    ///
+    /// Accepts the CSS cursor names, e.g. `default`, `pointer`, `text`, `grab`, `grabbing`,
+    /// `move`, `not-allowed`, `wait`, `crosshair`, `col-resize` or `row-resize`.
+    ///
+    /// ### Example
+    ///
+    /// ```rust, no_run
+    /// # use freya::prelude::*;
+    /// fn app() -> Element {
+    ///     rsx!(
+    ///         rect {
+    ///             cursor: "pointer",
+    ///             label {
+    ///                 "Click me"
+    ///             }
+    ///         }
+    ///     )
+    /// }
+    /// ```
+    cursor,
+
+    /// Rasterize this element and its children once and reuse the result in the next frames,
+    /// until any of them changes. Useful to scroll complex content that rarely changes.
+    ///
+    /// Accepted values are `true` and `false` (default).
+    ///
+    /// The cached content is clipped to the area of the element and painted in its layer.
+    /// Nested cached elements are painted as part of the outermost one.
+    ///
+    /// ### Example
+    ///
+    /// ```rust, no_run
+    /// # use freya::prelude::*;
+    /// fn app() -> Element {
+    ///     rsx!(
+    ///         ScrollView {
+    ///             rect {
+    ///                 cache_layer: "true",
+    ///                 for i in 0..100 {
+    ///                     label {
+    ///                         "Static item {i}"
+    ///                     }
+    ///                 }
+    ///             }
+    ///         }
+    ///     )
+    /// }
+    /// ```
+    cache_layer,
+
+    /// Render this element and its children in a separate borderless and always-on-top window,
+    /// placed over the area of the element in screen coordinates. Useful for dropdowns, menus and tooltips
+    /// that need to overflow outside the bounds of the main window.
+    ///
+    /// Accepted values are `true` and `false` (default).
+    ///
+    /// The element is still laid out and receives events as part of the main window,
+    /// but the clipping of its ancestors is not applied. Nested portals are rendered as part of the outermost one.
+    ///
+    /// ### Example
+    ///
+    /// ```rust, no_run
+    /// # use freya::prelude::*;
+    /// fn app() -> Element {
+    ///     rsx!(
+    ///         rect {
+    ///             position: "global",
+    ///             position_top: "40",
+    ///             position_left: "40",
+    ///             window_portal: "true",
+    ///             background: "white",
+    ///             label {
+    ///                 "Hello from another window"
+    ///             }
+    ///         }
+    ///     )
+    /// }
+    /// ```
+    window_portal,
+
+    /// Hide this element and its children from the layout, painting and events, without unmounting them.
+    /// Their components keep their state, so it can be shown again cheaply. See the `KeepAlive` component.
+    ///
+    /// Accepted values are `true` and `false` (default).
+    ///
+    /// ### Example
+    ///
+    /// ```rust, no_run
+    /// # use freya::prelude::*;
+    /// fn app() -> Element {
+    ///     let mut hidden = use_signal(|| false);
+    ///     rsx!(
+    ///         Button {
+    ///             onpress: move |_| hidden.toggle(),
+    ///             label { "Toggle" }
+    ///         }
+    ///         rect {
+    ///             offscreen: "{hidden}",
+    ///             label {
+    ///                 "Hello"
+    ///             }
+    ///         }
+    ///     )
+    /// }
+    /// ```
+    offscreen,
+
+    /// Make this element and its children inert, while still rendering them. They don't receive
+    /// any events, can't be focused and are left out of the accessibility tree.
+    /// Useful for the content behind a modal.
+    ///
+    /// Accepted values are `true` and `false` (default).
+    ///
+    /// ### Example
+    ///
+    /// ```rust, no_run
+    /// # use freya::prelude::*;
+    /// fn app() -> Element {
+    ///     let mut modal = use_modal();
+    ///     rsx!(
+    ///         rect {
+    ///             inert: "{modal.is_open()}",
+    ///             Button {
+    ///                 onpress: move |_| modal.open(),
+    ///                 label { "Open" }
+    ///             }
+    ///         }
+    ///         Modal {
+    ///             modal,
+    ///             label { "Hello" }
+    ///         }
+    ///     )
+    /// }
+    /// ```
+    inert,
 );
@@ -15,4 +15,68 @@ def_attribute!(
     highlight_color,
     /// Defines a highlight_mode attribute. For more information, see `use_editable`.
     highlight_mode,
+    /// Mark the content of an element as sensitive, e.g. a password.
+    /// Its text will be masked in the accessibility tree.
+    ///
+    /// To also disable copying and exclude the window from screen captures use `EditableConfig::with_sensitive`.
+    ///
+    /// ### Example
+    ///
+    /// ```rust, no_run
+    /// # use freya::prelude::*;
+    /// fn app() -> Element {
+    ///     rsx!(
+    ///         paragraph {
+    ///             sensitive: "true",
+    ///             text {
+    ///                 "hunter2"
+    ///             }
+    ///         }
+    ///     )
+    /// }
+    /// ```
+    sensitive,
+    /// Kind of text expected by an editable element, so the platform can show the right soft keyboard.
+    /// It is also exposed to assistive technologies through the role of `text-input` elements.
+    ///
+    /// Accepted values:
+    ///
+    /// - `text` (default)
+    /// - `email`
+    /// - `url`
+    /// - `numeric`
+    /// - `search`
+    /// - `password`
+    /// - `terminal`
+    ///
+    /// ### Example
+    ///
+    /// ```rust, no_run
+    /// # use freya::prelude::*;
+    /// fn app() -> Element {
+    ///     rsx!(
+    ///         paragraph {
+    ///             a11y_role: "text-input",
+    ///             input_hint: "email",
+    ///             text {
+    ///                 "user@freyaui.dev"
+    ///             }
+    ///         }
+    ///     )
+    /// }
+    /// ```
+    input_hint,
+    /// Which letters the platform should capitalize automatically while typing in an editable element.
+    /// Only forwarded on platforms whose text input system supports it.
+    ///
+    /// Accepted values:
+    ///
+    /// - `none` (default)
+    /// - `sentences`
+    /// - `words`
+    /// - `characters`
+    autocapitalize,
+    /// Whether the platform should autocorrect the text typed in an editable element, `true` by default.
+    /// Only forwarded on platforms whose text input system supports it.
+    autocorrect,
 );
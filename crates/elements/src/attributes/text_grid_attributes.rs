@@ -0,0 +1,24 @@
+use crate::def_attribute;
+
+def_attribute!(
+    /// The `text_grid` attribute lets you provide the cells displayed by a `textgrid` element.
+    ///
+    /// Create a `TextGrid`, fill its cells and pass it with `TextGrid::attribute`.
+    ///
+    /// ### Example
+    ///
+    /// ```rust, no_run
+    /// # use freya::prelude::*;
+    /// fn app() -> Element {
+    ///     let mut grid = TextGrid::new(16, 1);
+    ///     grid.write_str(0, 0, "00 ff 1a 2b", TextGridStyle::default());
+    ///
+    ///     rsx!(
+    ///         textgrid {
+    ///             text_grid: grid.attribute(),
+    ///         }
+    ///     )
+    /// }
+    /// ```
+    text_grid,
+);
@@ -27,9 +27,19 @@ def_element!(
         min_width,
         max_height,
         max_width,
+        flex_grow,
+        flex_shrink,
+        flex_basis,
+        grid_template_columns,
+        grid_template_rows,
+        wrap_content,
+        grid_column,
+        grid_row,
+        aspect_ratio,
         visible_width,
         visible_height,
         margin,
+        align_self,
         padding,
         position,
         position_top,
@@ -57,6 +67,11 @@ def_element!(
         corner_smoothing,
         blend_mode,
         backdrop_blur,
+        cursor,
+        cache_layer,
+        window_portal,
+        offscreen,
+        inert,
 
         // Font style
         color,
@@ -73,9 +88,12 @@ def_element!(
         decoration_style,
         decoration_color,
         text_overflow,
+        text_wrap,
         letter_spacing,
         word_spacing,
+        font_features,
         text_height,
+        text_direction,
 
         // Transform
         rotate,
@@ -85,11 +103,19 @@ def_element!(
         // Reference
         canvas_reference,
         reference,
+        style_reference,
+
+        // Text Editing
+        sensitive,
+        input_hint,
+        autocapitalize,
+        autocorrect,
 
         // Accessibility
         a11y_id,
         a11y_focusable,
         a11y_auto_focus,
+        autofocus,
         a11y_name,
         a11y_description,
         a11y_value,
@@ -179,7 +205,14 @@ def_element!(
         min_width,
         max_height,
         max_width,
+        flex_grow,
+        flex_shrink,
+        flex_basis,
+        grid_column,
+        grid_row,
+        aspect_ratio,
         margin,
+        align_self,
         position,
         position_top,
         position_right,
@@ -205,13 +238,18 @@ def_element!(
         decoration_style,
         decoration_color,
         text_overflow,
+        text_wrap,
         letter_spacing,
         word_spacing,
+        font_features,
         text_height,
+        direction,
+        text_direction,
 
         // Style
         blend_mode,
         backdrop_blur,
+        cursor,
 
 
         // Transform
@@ -220,10 +258,18 @@ def_element!(
 
         // Reference
         reference,
+        style_reference,
+
+        // Text Editing
+        sensitive,
+        input_hint,
+        autocapitalize,
+        autocorrect,
 
         // Accessibility
         a11y_id,
         a11y_auto_focus,
+        autofocus,
         a11y_focusable,
         a11y_name,
         a11y_description,
@@ -320,7 +366,14 @@ def_element!(
         min_width,
         max_height,
         max_width,
+        flex_grow,
+        flex_shrink,
+        flex_basis,
+        grid_column,
+        grid_row,
+        aspect_ratio,
         margin,
+        align_self,
         position,
         position_top,
         position_right,
@@ -346,14 +399,21 @@ def_element!(
         decoration_style,
         decoration_color,
         text_overflow,
+        text_wrap,
         letter_spacing,
         word_spacing,
+        font_features,
         text_height,
+        direction,
+        text_direction,
 
         // Transform
         rotate,
         opacity,
 
+        // Style
+        cursor,
+
         // Text Editing
         cursor_index,
         cursor_color,
@@ -363,11 +423,16 @@ def_element!(
         highlight_color,
         highlight_mode,
         cursor_reference,
+        sensitive,
+        input_hint,
+        autocapitalize,
+        autocorrect,
 
         // Accessibility
         a11y_id,
         a11y_focusable,
         a11y_auto_focus,
+        autofocus,
         a11y_name,
         a11y_description,
         a11y_value,
@@ -451,6 +516,7 @@ def_element!(
         decoration_color,
         letter_spacing,
         word_spacing,
+        font_features,
     };
     /// `image` element let's you show an image.
     ///
@@ -481,7 +547,13 @@ def_element!(
         min_width,
         max_height,
         max_width,
+        flex_grow,
+        flex_shrink,
+        flex_basis,
+        grid_column,
+        grid_row,
         margin,
+        align_self,
         position,
         position_top,
         position_right,
@@ -503,6 +575,9 @@ def_element!(
         rotate,
         opacity,
 
+        // Style
+        cursor,
+
         // Image
         image_data,
         aspect_ratio,
@@ -512,12 +587,14 @@ def_element!(
 
         // Reference
         reference,
+        style_reference,
         image_reference,
 
         // Accessibility
         a11y_id,
         a11y_focusable,
         a11y_auto_focus,
+        autofocus,
         a11y_name,
         a11y_description,
         a11y_value,
@@ -613,7 +690,14 @@ def_element!(
         min_width,
         max_height,
         max_width,
+        flex_grow,
+        flex_shrink,
+        flex_basis,
+        grid_column,
+        grid_row,
+        aspect_ratio,
         margin,
+        align_self,
         position,
         position_top,
         position_right,
@@ -625,6 +709,9 @@ def_element!(
         rotate,
         opacity,
 
+        // Style
+        cursor,
+
         // Svg
         color,
         svg_data,
@@ -636,6 +723,152 @@ def_element!(
         a11y_id,
         a11y_focusable,
         a11y_auto_focus,
+        autofocus,
+        a11y_name,
+        a11y_description,
+        a11y_value,
+        a11y_access_key,
+        a11y_author_id,
+        a11y_keyboard_shortcut,
+        a11y_language,
+        a11y_placeholder,
+        a11y_role_description,
+        a11y_state_description,
+        a11y_tooltip,
+        a11y_url,
+        a11y_row_index_text,
+        a11y_column_index_text,
+        a11y_scroll_x,
+        a11y_scroll_x_min,
+        a11y_scroll_x_max,
+        a11y_scroll_y,
+        a11y_scroll_y_min,
+        a11y_scroll_y_max,
+        a11y_numeric_value,
+        a11y_min_numeric_value,
+        a11y_max_numeric_value,
+        a11y_numeric_value_step,
+        a11y_numeric_value_jump,
+        a11y_row_count,
+        a11y_column_count,
+        a11y_row_index,
+        a11y_column_index,
+        a11y_row_span,
+        a11y_column_span,
+        a11y_level,
+        a11y_size_of_set,
+        a11y_position_in_set,
+        a11y_color_value,
+        a11y_expanded,
+        a11y_selected,
+        a11y_hovered,
+        a11y_hidden,
+        a11y_linked,
+        a11y_multiselectable,
+        a11y_required,
+        a11y_visited,
+        a11y_busy,
+        a11y_live_atomic,
+        a11y_modal,
+        a11y_touch_transparent,
+        a11y_read_only,
+        a11y_disabled,
+        a11y_is_spelling_error,
+        a11y_is_grammar_error,
+        a11y_is_search_match,
+        a11y_is_suggestion,
+        a11y_role,
+        a11y_invalid,
+        a11y_toggled,
+        a11y_live,
+        a11y_default_action_verb,
+        a11y_orientation,
+        a11y_sort_direction,
+        a11y_current,
+        a11y_auto_complete,
+        a11y_has_popup,
+        a11y_list_style,
+        a11y_vertical_offset,
+    };
+
+    /// `textgrid` element let's you display a grid of monospaced characters, e.g. a terminal
+    /// or a hex viewer.
+    ///
+    /// Every cell holds a character along with its own colors and attributes. Cells of the same
+    /// style are drawn together, so it's much faster than using a `label` or `paragraph` per cell.
+    /// Its size is given by the number of columns and rows of the grid unless specified otherwise.
+    ///
+    /// ### Example
+    ///
+    /// ```rust, no_run
+    /// # use freya::prelude::*;
+    /// fn app() -> Element {
+    ///     let grid = use_hook(|| {
+    ///         let mut grid = TextGrid::new(20, 2);
+    ///         grid.write_str(0, 0, "$ cargo run", TextGridStyle::default());
+    ///         let error = TextGridStyle {
+    ///             bold: true,
+    ///             underline: true,
+    ///             ..Default::default()
+    ///         };
+    ///         grid.write_str(0, 1, "error", error);
+    ///         grid
+    ///     });
+    ///
+    ///     rsx!(
+    ///         textgrid {
+    ///             font_family: "Jetbrains Mono",
+    ///             text_grid: grid.attribute(),
+    ///         }
+    ///     )
+    /// }
+    /// ```
+    textgrid {
+        // Layout
+        height,
+        width,
+        min_height,
+        min_width,
+        max_height,
+        max_width,
+        flex_grow,
+        flex_shrink,
+        flex_basis,
+        grid_column,
+        grid_row,
+        margin,
+        align_self,
+        position,
+        position_top,
+        position_right,
+        position_bottom,
+        position_left,
+        layer,
+
+        // Transform
+        rotate,
+        opacity,
+
+        // Style
+        cursor,
+
+        // Font style
+        color,
+        font_family,
+        font_size,
+        font_weight,
+        font_style,
+        font_width,
+        line_height,
+
+        // Text grid
+        text_grid,
+
+        // Accessibility
+        a11y_id,
+        a11y_focusable,
+        a11y_auto_focus,
+        autofocus,
         a11y_name,
         a11y_description,
         a11y_value,
@@ -1,5 +1,14 @@
+//! Data of the events emitted to the elements.
+//!
+//! Most events bubble up from the target element to its ancestors, call `e.stop_propagation()` to stop them.
+//! Call `e.prevent_default()` to skip the default action of an event, like the scrolling of a `ScrollView` on `wheel`
+//! or the keyboard navigation on `Tab`. Ancestors and global listeners (e.g. `onglobalkeydown`)
+//! can check it with `e.default_action_enabled()`.
+
 pub mod file;
+pub mod focus;
 pub mod keyboard;
+pub mod mounted;
 pub mod mouse;
 pub mod pointer;
 pub mod touch;
@@ -9,7 +18,9 @@ use std::any::Any;
 
 use dioxus_core::Event;
 pub use file::*;
+pub use focus::*;
 pub use keyboard::*;
+pub use mounted::*;
 pub use mouse::*;
 pub use pointer::*;
 pub use touch::*;
@@ -20,6 +31,9 @@ pub type MouseEvent = Event<MouseData>;
 pub type WheelEvent = Event<WheelData>;
 pub type TouchEvent = Event<TouchData>;
 pub type PointerEvent = Event<PointerData>;
+pub type MountedEvent = Event<MountedData>;
+pub type UnmountedEvent = Event<UnmountedData>;
+pub type FocusEvent = Event<FocusData>;
 
 /// A platform specific event.
 #[doc(hidden)]
@@ -28,6 +28,27 @@ impl_event! [
     /// ```
     onfiledrop
 
+    /// The `filehover` event fires when the user starts hovering a file over the element, dragged from the OS.
+    ///
+    /// Event Data: [`FileData`](crate::events::FileData)
+    ///
+    /// ### Example
+    ///
+    /// ```rust, no_run
+    /// # use freya::prelude::*;
+    /// fn app() -> Element {
+    ///     rsx!(
+    ///         rect {
+    ///             width: "100%",
+    ///             height: "100%",
+    ///             background: "black",
+    ///             onfilehover: |e| println!("File hovering: {e:?}")
+    ///         }
+    ///     )
+    /// }
+    /// ```
+    onfilehover
+
     /// The `onglobalfilehover` event fires when the user hovers a file over the window.
     ///
     /// Event Data: [`FileData`](crate::events::FileData)
@@ -0,0 +1,67 @@
+use crate::{
+    events::ErasedEventData,
+    impl_event,
+};
+
+impl_event! [
+    FocusData;
+
+    /// The `focus` event fires when the element gets focused, either with the keyboard or programmatically.
+    /// The element must have an `a11y_id` to be focusable. This event does not bubble.
+    ///
+    /// Event Data: [`FocusData`](crate::events::FocusData)
+    ///
+    /// ### Example
+    ///
+    /// ```rust, no_run
+    /// # use freya::prelude::*;
+    /// fn app() -> Element {
+    ///     let focus = use_focus();
+    ///
+    ///     rsx!(
+    ///         rect {
+    ///             a11y_id: focus.attribute(),
+    ///             width: "100",
+    ///             height: "100",
+    ///             background: "red",
+    ///             onfocus: |_| println!("Focused!")
+    ///         }
+    ///     )
+    /// }
+    /// ```
+    onfocus
+
+    /// The `blur` event fires when the element loses the focus. This event does not bubble.
+    ///
+    /// Event Data: [`FocusData`](crate::events::FocusData)
+    ///
+    /// ### Example
+    ///
+    /// ```rust, no_run
+    /// # use freya::prelude::*;
+    /// fn app() -> Element {
+    ///     let focus = use_focus();
+    ///
+    ///     rsx!(
+    ///         rect {
+    ///             a11y_id: focus.attribute(),
+    ///             width: "100",
+    ///             height: "100",
+    ///             background: "red",
+    ///             onblur: |_| println!("Not focused anymore!")
+    ///         }
+    ///     )
+    /// }
+    /// ```
+    onblur
+];
+
+/// Data of a Focus event.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct FocusData;
+
+impl From<&ErasedEventData> for FocusData {
+    fn from(val: &ErasedEventData) -> Self {
+        val.downcast::<FocusData>().cloned().unwrap()
+    }
+}
@@ -1,6 +1,7 @@
 pub use keyboard_types::{
     Code,
     Key,
+    Location,
     Modifiers,
 };
 
@@ -12,7 +13,8 @@ use crate::{
 impl_event! [
     KeyboardData;
 
-    /// The `keydown` event fires when the user starts pressing any key in the currently focused element.
+    /// The `keydown` event fires when the user starts pressing any key in the currently focused element
+    /// or any of its descendants, it bubbles up from the focused element until its propagation is stopped.
     ///
     /// Event Data: [`KeyboardData`](crate::events::KeyboardData)
     ///
@@ -30,7 +32,8 @@ impl_event! [
     /// ```
     onkeydown
 
-    /// The `keyup` event fires when the user releases any key being pressed in the currently focused element.
+    /// The `keyup` event fires when the user releases any key being pressed in the currently focused element
+    /// or any of its descendants, it bubbles up from the focused element until its propagation is stopped.
     ///
     /// Event Data: [`KeyboardData`](crate::events::KeyboardData)
     ///
@@ -91,14 +94,26 @@ pub struct KeyboardData {
     pub key: Key,
     pub code: Code,
     pub modifiers: Modifiers,
+    /// Whether the event was generated by the key being held down, instead of an actual press.
+    pub repeat: bool,
+    /// Location of the key in the keyboard, e.g. the left or right `Shift`.
+    pub location: Location,
 }
 
 impl KeyboardData {
-    pub fn new(key: Key, code: Code, modifiers: Modifiers) -> Self {
+    pub fn new(
+        key: Key,
+        code: Code,
+        modifiers: Modifiers,
+        repeat: bool,
+        location: Location,
+    ) -> Self {
         Self {
             key,
             code,
             modifiers,
+            repeat,
+            location,
         }
     }
 }
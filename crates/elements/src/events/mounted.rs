@@ -0,0 +1,315 @@
+use std::{
+    cell::RefCell,
+    fmt::Debug,
+    rc::Rc,
+    sync::{
+        Arc,
+        Mutex,
+    },
+};
+
+use dioxus_core::{
+    prelude::{
+        spawn_forever,
+        use_hook,
+        with_owner,
+        EventHandler,
+        SuperInto,
+    },
+    Attribute,
+    AttributeValue,
+    Event,
+};
+use generational_box::{
+    AnyStorage,
+    Owner,
+    UnsyncStorage,
+};
+use tokio::sync::{
+    oneshot,
+    watch,
+};
+use torin::prelude::Area;
+
+use crate::{
+    events::ErasedEventData,
+    impl_event,
+};
+
+impl_event! [
+    MountedData;
+
+    /// The `mounted` event fires once the element has been laid out for the first time,
+    /// so its size and position are already known.
+    ///
+    /// Event Data: [`MountedData`](crate::events::MountedData)
+    ///
+    /// ### Example
+    ///
+    /// ```rust, no_run
+    /// # use freya::prelude::*;
+    /// fn app() -> Element {
+    ///     rsx!(
+    ///         rect {
+    ///             width: "100",
+    ///             height: "100",
+    ///             background: "red",
+    ///             onmounted: |e| println!("Mounted with size {:?}", e.get_area().size)
+    ///         }
+    ///     )
+    /// }
+    /// ```
+    onmounted
+];
+
+/// Data of a Mounted event.
+#[derive(Debug, Clone)]
+pub struct MountedData {
+    area: Area,
+    inner_area: Area,
+    handle: NodeHandle,
+}
+
+impl MountedData {
+    pub fn new(area: Area, inner_area: Area, handle: NodeHandle) -> Self {
+        Self {
+            area,
+            inner_area,
+            handle,
+        }
+    }
+}
+
+impl MountedData {
+    /// Get the area occupied by the element.
+    pub fn get_area(&self) -> Area {
+        self.area
+    }
+
+    /// Get the area inside the element, this is, without the paddings.
+    pub fn get_inner_area(&self) -> Area {
+        self.inner_area
+    }
+
+    /// Get a handle to the element, it keeps following its layout after this event.
+    pub fn get_handle(&self) -> NodeHandle {
+        self.handle.clone()
+    }
+}
+
+impl PartialEq for MountedData {
+    fn eq(&self, other: &Self) -> bool {
+        self.area == other.area && self.inner_area == other.inner_area
+    }
+}
+
+impl From<&ErasedEventData> for MountedData {
+    fn from(val: &ErasedEventData) -> Self {
+        val.downcast::<MountedData>().cloned().unwrap()
+    }
+}
+
+/// Area and inner area of a mounted element, `None` once it has been removed.
+type NodeHandleLayout = Option<(Area, Area)>;
+
+/// Handle to a mounted element, given by its `mounted` event.
+///
+/// ### Example
+///
+/// ```rust, no_run
+/// # use freya::prelude::*;
+/// fn app() -> Element {
+///     let mut handle = use_signal(|| None::<NodeHandle>);
+///
+///     rsx!(rect {
+///         width: "50%",
+///         height: "100",
+///         onmounted: move |e: MountedEvent| handle.set(Some(e.get_handle())),
+///         onclick: move |_| {
+///             if let Some(handle) = &*handle.read() {
+///                 println!("Now sized {:?}", handle.area().map(|area| area.size));
+///             }
+///         }
+///     })
+/// }
+/// ```
+#[derive(Debug, Clone)]
+pub struct NodeHandle {
+    layout: watch::Receiver<NodeHandleLayout>,
+}
+
+impl NodeHandle {
+    /// Create a handle for a mounted element, along with the sender that updates its layout.
+    #[doc(hidden)]
+    pub fn channel(area: Area, inner_area: Area) -> (watch::Sender<NodeHandleLayout>, Self) {
+        let (sender, layout) = watch::channel(Some((area, inner_area)));
+        (sender, Self { layout })
+    }
+
+    /// Check whether the element is still mounted.
+    pub fn is_mounted(&self) -> bool {
+        self.layout.borrow().is_some()
+    }
+
+    /// Get the latest area occupied by the element, `None` once it has been removed.
+    pub fn area(&self) -> Option<Area> {
+        self.layout.borrow().map(|(area, _)| area)
+    }
+
+    /// Get the latest area inside the element, `None` once it has been removed.
+    pub fn inner_area(&self) -> Option<Area> {
+        self.layout.borrow().map(|(_, inner_area)| inner_area)
+    }
+
+    /// Wait until the element is removed.
+    pub async fn unmounted(&mut self) {
+        // A closed channel also means that the element is gone
+        let _ = self.layout.wait_for(Option::is_none).await;
+    }
+}
+
+/// The `unmounted` event fires once the element has been removed.
+///
+/// Unlike other events it keeps some state in the component, so just like a hook,
+/// it must not be used conditionally inside the component that renders the element.
+///
+/// Event Data: [`UnmountedData`](crate::events::UnmountedData)
+///
+/// ### Example
+///
+/// ```rust, no_run
+/// # use freya::prelude::*;
+/// fn app() -> Element {
+///     let mut show = use_signal(|| true);
+///
+///     rsx!(
+///         Button {
+///             onpress: move |_| show.toggle(),
+///             label { "Toggle" }
+///         }
+///         if show() {
+///             Square {}
+///         }
+///     )
+/// }
+///
+/// #[component]
+/// fn Square() -> Element {
+///     rsx!(
+///         rect {
+///             width: "100",
+///             height: "100",
+///             background: "red",
+///             onunmounted: |e| println!("Unmounted from {:?}", e.get_area().origin)
+///         }
+///     )
+/// }
+/// ```
+#[inline]
+pub fn onunmounted<__Marker>(
+    mut _f: impl SuperInto<EventHandler<Event<UnmountedData>>, __Marker>,
+) -> Attribute {
+    // Same as with the other events, the handler is owned by an owner moved into the listener.
+    let owner = <UnsyncStorage as AnyStorage>::owner();
+    let event_handler = with_owner(owner.clone(), || _f.super_into());
+
+    // The listener and its task are only created once, so the attribute stays the same
+    // across renders and only the handler is replaced.
+    let (listener, handler) = use_hook(|| {
+        let handler = Rc::new(RefCell::new(None::<UnmountedHandler>));
+        let (sender, receiver) = oneshot::channel();
+
+        // The VirtualDOM has already dropped the listeners of an element by the time
+        // the renderer removes it, so the renderer notifies the handler through a channel instead.
+        // The task outlives the scope of the element, it ends once the renderer notifies it
+        // or when the listener is dropped.
+        spawn_forever({
+            let handler = handler.clone();
+            async move {
+                if let Ok(data) = receiver.await {
+                    let handler = handler.borrow_mut().take();
+                    if let Some((_owner, event_handler)) = handler {
+                        event_handler.call(Event::new(Rc::new(data), false));
+                    }
+                }
+            }
+        });
+
+        (
+            UnmountedListener(Arc::new(Mutex::new(Some(sender)))),
+            handler,
+        )
+    });
+
+    *handler.borrow_mut() = Some((owner, event_handler));
+
+    Attribute::new(
+        "unmounted",
+        AttributeValue::any_value(listener),
+        None,
+        false,
+    )
+}
+
+/// Latest handler of an `unmounted` event, along with the owner of its closure.
+type UnmountedHandler = (Owner<UnsyncStorage>, EventHandler<Event<UnmountedData>>);
+
+#[doc(hidden)]
+pub mod onunmounted {
+    use super::*;
+
+    /// Used by `rsx!` for inline closures, to give them a better type inference.
+    pub fn call_with_explicit_closure<
+        __Marker,
+        Return: ::dioxus_core::SpawnIfAsync<__Marker> + 'static,
+    >(
+        event_handler: impl FnMut(Event<UnmountedData>) -> Return + 'static,
+    ) -> Attribute {
+        super::onunmounted(event_handler)
+    }
+}
+
+/// Listener of the `unmounted` event, notified by the renderer once its element is removed.
+#[doc(hidden)]
+#[derive(Clone)]
+pub struct UnmountedListener(Arc<Mutex<Option<oneshot::Sender<UnmountedData>>>>);
+
+impl UnmountedListener {
+    /// Notify the handler, only the first notification is delivered.
+    pub fn notify(&self, data: UnmountedData) {
+        if let Some(sender) = self.0.lock().unwrap().take() {
+            let _ = sender.send(data);
+        }
+    }
+}
+
+impl PartialEq for UnmountedListener {
+    fn eq(&self, other: &Self) -> bool {
+        Arc::ptr_eq(&self.0, &other.0)
+    }
+}
+
+impl Debug for UnmountedListener {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_tuple("UnmountedListener").finish()
+    }
+}
+
+/// Data of an Unmounted event.
+#[derive(Debug, Clone, PartialEq)]
+pub struct UnmountedData {
+    area: Area,
+}
+
+impl UnmountedData {
+    pub fn new(area: Area) -> Self {
+        Self { area }
+    }
+}
+
+impl UnmountedData {
+    /// Get the last area that the element occupied.
+    pub fn get_area(&self) -> Area {
+        self.area
+    }
+}
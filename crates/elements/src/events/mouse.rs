@@ -97,6 +97,49 @@ impl_event! [
     /// ```
     onrightclick
 
+    /// The `doubleclick` event fires when the user clicks an element twice in a row with the left button of the mouse,
+    /// quickly and without moving the cursor. Both clicks also fire their own [`onclick`](crate::events::onclick()) event.
+    ///
+    /// Event Data: [`MouseData`](crate::events::MouseData)
+    ///
+    /// ### Example
+    ///
+    /// ```rust, no_run
+    /// # use freya::prelude::*;
+    /// fn app() -> Element {
+    ///     rsx!(
+    ///         rect {
+    ///             width: "100",
+    ///             height: "100",
+    ///             background: "red",
+    ///             ondoubleclick: |_| println!("Double clicked!")
+    ///         }
+    ///     )
+    /// }
+    /// ```
+    ondoubleclick
+
+    /// The `contextmenu` event fires when the user requests the context menu of an element, by clicking it with the right button of the mouse.
+    ///
+    /// Event Data: [`MouseData`](crate::events::MouseData)
+    ///
+    /// ### Example
+    ///
+    /// ```rust, no_run
+    /// # use freya::prelude::*;
+    /// fn app() -> Element {
+    ///     rsx!(
+    ///         rect {
+    ///             width: "100",
+    ///             height: "100",
+    ///             background: "red",
+    ///             oncontextmenu: |e| println!("Open a menu at {:?}", e.get_screen_coordinates())
+    ///         }
+    ///     )
+    /// }
+    /// ```
+    oncontextmenu
+
     /// The `mouseup` event fires when the user ends the click in an element with the left button of the mouse.
     ///
     /// Event Data: [`MouseData`](crate::events::MouseData)
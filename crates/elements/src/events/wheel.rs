@@ -8,6 +8,10 @@ impl_event! [
 
     /// The `wheel` event fires when the user scrolls the mouse wheel while hovering over the element.
     ///
+    /// Trackpads also emit it when a gesture starts and ends, these events have no deltas,
+    /// so handlers that only scroll can skip them by checking
+    /// [`WheelData::get_phase`](crate::events::WheelData::get_phase).
+    ///
     /// Event Data: [`WheelData`](crate::events::WheelData)
     ///
     /// ### Example
@@ -28,30 +32,101 @@ impl_event! [
     onwheel
 ];
 
+/// Unit of the deltas reported by the device of a Wheel event.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum WheelDeltaMode {
+    /// Lines, reported by mouse wheels.
+    #[default]
+    Line,
+    /// Pixels, reported by trackpads and precise wheels.
+    Pixel,
+}
+
+/// Phase of the gesture of a Wheel event.
+///
+/// Only some devices, like trackpads, report when the gesture starts and ends,
+/// the rest only emit [`WheelPhase::Moved`]. Only [`WheelPhase::Moved`] events carry deltas.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum WheelPhase {
+    /// The fingers were placed on the trackpad.
+    Started,
+    /// The content must be scrolled.
+    #[default]
+    Moved,
+    /// The fingers were lifted from the trackpad, momentum events might follow.
+    Ended,
+}
+
 /// Data of a Wheel event.
 #[derive(Debug, Clone, PartialEq)]
 pub struct WheelData {
-    #[allow(dead_code)]
     delta_x: f64,
     delta_y: f64,
+    raw_delta_x: f64,
+    raw_delta_y: f64,
+    delta_mode: WheelDeltaMode,
+    phase: WheelPhase,
+    momentum: bool,
 }
 
 impl WheelData {
-    pub fn new(delta_x: f64, delta_y: f64) -> Self {
-        Self { delta_x, delta_y }
+    pub fn new(
+        delta_x: f64,
+        delta_y: f64,
+        raw_delta_x: f64,
+        raw_delta_y: f64,
+        delta_mode: WheelDeltaMode,
+        phase: WheelPhase,
+        momentum: bool,
+    ) -> Self {
+        Self {
+            delta_x,
+            delta_y,
+            raw_delta_x,
+            raw_delta_y,
+            delta_mode,
+            phase,
+            momentum,
+        }
     }
 }
 
 impl WheelData {
-    /// Get the X delta.
+    /// Get the X delta, in pixels adjusted to a comfortable scroll speed.
     pub fn get_delta_x(&self) -> f64 {
         self.delta_x
     }
 
-    /// Get the Y delta.
+    /// Get the Y delta, in pixels adjusted to a comfortable scroll speed.
     pub fn get_delta_y(&self) -> f64 {
         self.delta_y
     }
+
+    /// Get the X delta as reported by the device, in lines or pixels depending on [`WheelData::get_delta_mode`].
+    pub fn get_raw_delta_x(&self) -> f64 {
+        self.raw_delta_x
+    }
+
+    /// Get the Y delta as reported by the device, in lines or pixels depending on [`WheelData::get_delta_mode`].
+    pub fn get_raw_delta_y(&self) -> f64 {
+        self.raw_delta_y
+    }
+
+    /// Get the unit of the raw deltas.
+    pub fn get_delta_mode(&self) -> WheelDeltaMode {
+        self.delta_mode
+    }
+
+    /// Get the phase of the gesture.
+    pub fn get_phase(&self) -> WheelPhase {
+        self.phase
+    }
+
+    /// Check whether this event is part of the momentum scroll that trackpads emit
+    /// after the fingers are lifted.
+    pub fn is_momentum(&self) -> bool {
+        self.momentum
+    }
 }
 
 impl From<&ErasedEventData> for WheelData {
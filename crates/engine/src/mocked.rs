@@ -668,6 +668,10 @@ impl Paint {
         unimplemented!("This is mocked")
     }
 
+    pub fn set_stroke_cap(&mut self, _cap: PaintCap) -> &mut Self {
+        unimplemented!("This is mocked")
+    }
+
     pub fn set_mask_filter(&mut self, _mask_filter: impl Into<Option<MaskFilter>>) -> &mut Self {
         unimplemented!("This is mocked")
     }
@@ -679,6 +683,12 @@ pub enum PaintStyle {
     StrokeAndFill = 2,
 }
 
+pub enum PaintCap {
+    Butt = 0,
+    Round = 1,
+    Square = 2,
+}
+
 pub struct FontStyle;
 
 impl FontStyle {
@@ -702,6 +712,7 @@ impl FontMgr {
 
 pub struct FontFeature;
 
+#[derive(Clone)]
 pub struct TypefaceFontProvider;
 
 impl TypefaceFontProvider {
@@ -743,6 +754,18 @@ impl FontCollection {
     pub fn set_dynamic_font_manager(&mut self, _font_manager: impl Into<Option<FontMgr>>) {
         unimplemented!("This is mocked")
     }
+
+    pub fn clear_caches(&mut self) {
+        unimplemented!("This is mocked")
+    }
+
+    pub fn find_typefaces(
+        &mut self,
+        _family_names: &[impl AsRef<str>],
+        _font_style: FontStyle,
+    ) -> Vec<Typeface> {
+        unimplemented!("This is mocked")
+    }
 }
 
 pub struct Paragraph;
@@ -1035,6 +1058,10 @@ impl Canvas {
         unimplemented!("This is mocked")
     }
 
+    pub fn reset_matrix(&self) -> &Self {
+        unimplemented!("This is mocked")
+    }
+
     pub fn clip_rect(&self, _rect: Rect, _clip: ClipOp, _: bool) {
         unimplemented!("This is mocked")
     }
@@ -1079,6 +1106,15 @@ impl Canvas {
         unimplemented!("This is mocked")
     }
 
+    pub fn draw_text_blob(
+        &self,
+        _blob: impl AsRef<TextBlob>,
+        _origin: impl Into<Point>,
+        _paint: &Paint,
+    ) -> &Self {
+        unimplemented!("This is mocked")
+    }
+
     pub fn draw_drrect(
         &self,
         outer: impl AsRef<RRect>,
@@ -1250,16 +1286,46 @@ pub struct StyleMetrics {
 pub struct FontMetrics {
     pub ascent: f32,
     pub descent: f32,
+    pub leading: f32,
 }
 
 pub struct GlyphClusterInfo;
 
 pub struct TextBox {
     pub rect: Rect,
+    pub direct: TextDirection,
 }
 
+#[derive(Clone)]
 pub struct Font;
 
+impl Font {
+    pub fn from_typeface(_typeface: impl Into<Typeface>, _size: impl Into<Option<f32>>) -> Self {
+        unimplemented!("This is mocked")
+    }
+
+    pub fn measure_str(&self, _str: impl AsRef<str>, _paint: Option<&Paint>) -> (f32, Rect) {
+        unimplemented!("This is mocked")
+    }
+
+    pub fn metrics(&self) -> (f32, FontMetrics) {
+        unimplemented!("This is mocked")
+    }
+}
+
+pub struct TextBlob;
+
+impl TextBlob {
+    pub fn from_pos_text_h(
+        _text: impl AsRef<str>,
+        _x_pos: &[f32],
+        _const_y: f32,
+        _font: &Font,
+    ) -> Option<Self> {
+        unimplemented!("This is mocked")
+    }
+}
+
 pub struct FontInfo;
 
 pub struct PositionWithAffinity {
@@ -1326,6 +1392,10 @@ impl Rect {
         unimplemented!("This is mocked")
     }
 
+    pub fn from_xywh(_x: f32, _y: f32, _w: f32, _h: f32) -> Self {
+        unimplemented!("This is mocked")
+    }
+
     pub fn x(&self) -> f32 {
         unimplemented!("This is mocked")
     }
@@ -1390,6 +1460,10 @@ impl Data {
         unimplemented!("This is mocked")
     }
 
+    pub fn as_bytes(&self) -> &[u8] {
+        unimplemented!("This is mocked")
+    }
+
     pub unsafe fn new_bytes(_bytes: &[u8]) -> Self {
         unimplemented!("This is mocked")
     }
@@ -1478,6 +1552,10 @@ impl Path {
         unimplemented!("This is mocked")
     }
 
+    pub fn quad_to(&mut self, _p1: impl Into<Point>, _p2: impl Into<Point>) -> &mut Self {
+        unimplemented!("This is mocked")
+    }
+
     pub fn cubic_to(
         &mut self,
         _p1: impl Into<Point>,
@@ -1502,6 +1580,14 @@ impl Path {
         unimplemented!("This is mocked")
     }
 
+    pub fn add_rect(
+        &mut self,
+        _rect: impl AsRef<Rect>,
+        _dir_start: Option<(PathDirection, usize)>,
+    ) -> &mut Self {
+        unimplemented!("This is mocked")
+    }
+
     pub fn add_rrect(
         &mut self,
         _rrect: impl AsRef<RRect>,
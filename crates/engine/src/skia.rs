@@ -72,7 +72,9 @@ pub use skia_safe::{
     Data,
     EncodedImageFormat,
     FilterMode,
+    Font,
     FontArguments,
+    FontMetrics,
     FontMgr,
     FontStyle,
     IPoint,
@@ -84,6 +86,7 @@ pub use skia_safe::{
     Matrix,
     MipmapMode,
     Paint,
+    PaintCap,
     PaintStyle,
     Path,
     PathDirection,
@@ -96,6 +99,7 @@ pub use skia_safe::{
     SamplingOptions,
     Shader,
     Surface,
+    TextBlob,
     TileMode,
     Typeface,
     HSV,
@@ -8,3 +8,12 @@
 //! [dependencies]
 //! freya = { .., features = ["devtools"] }
 //! ```
+//!
+//! ## Console
+//!
+//! The `Console` tab lists the warnings and errors reported with [`tracing`](https://docs.rs/tracing)
+//! while the app runs, along with when and where they were reported, so they can be inspected
+//! without running the app from a terminal. This includes the errors of components not caught
+//! by an error boundary.
+//!
+//! With the `tracing-subscriber` feature they are also still logged to the terminal.
@@ -1,9 +1,31 @@
-use dioxus_core::Element;
+use std::time::Duration;
+
+use dioxus_core::{
+    Element,
+    VirtualDom,
+};
+use freya_core::{
+    dom::{
+        FreyaDOM,
+        SafeDOM,
+    },
+    event_loop_messages::EventLoopMessage,
+    platform::EventLoopProxy,
+};
 use freya_winit::{
+    devtools::{
+        Devtools,
+        HoveredNode,
+    },
     LaunchConfig,
     WindowConfig,
     WinitRenderer,
 };
+#[cfg(not(any(target_os = "ios", target_arch = "wasm32")))]
+use freya_winit::{
+    PumpStatus,
+    PumpedEventLoop,
+};
 
 /// Launch a new window with the default config.
 ///
@@ -181,14 +203,121 @@ pub fn launch_cfg<T: 'static + Clone>(app: AppComponent, config: LaunchConfig<T>
     #[cfg(feature = "performance-overlay")]
     let config = config.with_plugin(crate::plugins::PerformanceOverlayPlugin::default());
 
-    use freya_core::dom::{
-        FreyaDOM,
-        SafeDOM,
+    let (vdom, sdom, devtools, hovered_node) = create_app(app);
+
+    #[cfg(not(feature = "custom-tokio-rt"))]
+    {
+        let rt = create_runtime();
+        let _guard = rt.enter();
+
+        WinitRenderer::launch(vdom, sdom, config, devtools, hovered_node);
+    }
+
+    #[cfg(feature = "custom-tokio-rt")]
+    WinitRenderer::launch(vdom, sdom, config, devtools, hovered_node);
+}
+
+/// Create the event loop of an app without running it, so it can be driven from an existing main loop.
+///
+/// Unlike [`launch_cfg`], this doesn't block. Instead, [`FreyaEventLoop::pump`] must be called periodically from the main thread.
+///
+/// # Example
+/// ```rust,no_run
+/// # use freya::prelude::*;
+/// # use std::time::Duration;
+///
+/// fn main() {
+///     let mut event_loop = launch_pumped(app, LaunchConfig::<()>::new());
+///
+///     loop {
+///         // Process audio, game logic, etc.
+///
+///         if let PumpStatus::Exit(_) = event_loop.pump(Some(Duration::from_millis(16))) {
+///             break;
+///         }
+///     }
+/// }
+///
+/// fn app() -> Element {
+///    rsx!(
+///         label {
+///             "Hello World!"
+///         }
+///     )
+/// }
+/// ```
+#[cfg(not(any(target_os = "ios", target_arch = "wasm32")))]
+pub fn launch_pumped<'a, T: 'static + Clone>(
+    app: AppComponent,
+    config: LaunchConfig<'a, T>,
+) -> FreyaEventLoop<'a, T> {
+    #[cfg(feature = "performance-overlay")]
+    let config = config.with_plugin(crate::plugins::PerformanceOverlayPlugin::default());
+
+    let (vdom, sdom, devtools, hovered_node) = create_app(app);
+
+    #[cfg(not(feature = "custom-tokio-rt"))]
+    let rt = create_runtime();
+    let event_loop = {
+        #[cfg(not(feature = "custom-tokio-rt"))]
+        let _guard = rt.enter();
+
+        PumpedEventLoop::new(vdom, sdom, config, devtools, hovered_node)
     };
 
+    FreyaEventLoop {
+        event_loop,
+        #[cfg(not(feature = "custom-tokio-rt"))]
+        rt,
+    }
+}
+
+/// Event loop of an app created with [`launch_pumped`].
+#[cfg(not(any(target_os = "ios", target_arch = "wasm32")))]
+pub struct FreyaEventLoop<'a, T: 'static + Clone> {
+    event_loop: PumpedEventLoop<'a, T>,
+    #[cfg(not(feature = "custom-tokio-rt"))]
+    rt: tokio::runtime::Runtime,
+}
+
+#[cfg(not(any(target_os = "ios", target_arch = "wasm32")))]
+impl<T: 'static + Clone> FreyaEventLoop<'_, T> {
+    /// Process the pending events, render and poll the app, without blocking for longer than `timeout`.
+    ///
+    /// A `timeout` of `Some(Duration::ZERO)` returns immediately, while `None` waits until there is a new event.
+    /// Returns [`PumpStatus::Exit`] once the app has been closed.
+    ///
+    /// With the `custom-tokio-rt` feature a Tokio runtime must be entered when calling this.
+    pub fn pump(&mut self, timeout: Option<Duration>) -> PumpStatus {
+        #[cfg(not(feature = "custom-tokio-rt"))]
+        let _guard = self.rt.enter();
+
+        self.event_loop.pump(timeout)
+    }
+
+    /// Get a proxy to send messages to the event loop, e.g to wake it up from another thread.
+    pub fn proxy(&self) -> EventLoopProxy<EventLoopMessage> {
+        self.event_loop.proxy()
+    }
+}
+
+#[cfg(not(feature = "custom-tokio-rt"))]
+fn create_runtime() -> tokio::runtime::Runtime {
+    tokio::runtime::Builder::new_multi_thread()
+        .enable_all()
+        .build()
+        .unwrap()
+}
+
+fn create_app(app: AppComponent) -> (VirtualDom, SafeDOM, Option<Devtools>, HoveredNode) {
     let fdom = FreyaDOM::default();
     let sdom = SafeDOM::new(fdom);
 
+    // Collects the warnings and errors to show them in the devtools
+    #[cfg(feature = "devtools")]
+    #[cfg(debug_assertions)]
+    let error_console = freya_devtools::ErrorConsole::default();
+
     #[cfg(feature = "tracing-subscriber")]
     {
         use tracing_subscriber::{
@@ -196,55 +325,47 @@ pub fn launch_cfg<T: 'static + Clone>(app: AppComponent, config: LaunchConfig<T>
             prelude::__tracing_subscriber_SubscriberExt,
             util::SubscriberInitExt,
             EnvFilter,
+            Layer,
         };
 
-        tracing_subscriber::registry()
-            .with(fmt::layer())
-            .with(EnvFilter::from_default_env())
-            .init();
-    }
-
-    let (vdom, devtools, hovered_node) = {
+        let registry = tracing_subscriber::registry()
+            .with(fmt::layer().with_filter(EnvFilter::from_default_env()));
         #[cfg(feature = "devtools")]
         #[cfg(debug_assertions)]
-        {
-            use std::sync::{
-                Arc,
-                Mutex,
-            };
-
-            use freya_devtools::with_devtools;
-            use freya_winit::devtools::Devtools;
-
-            let hovered_node = Some(Arc::new(Mutex::new(None)));
-            let (devtools, devtools_receiver) = Devtools::new();
-            let vdom = with_devtools(app, devtools_receiver.clone(), hovered_node.clone());
-            (vdom, Some(devtools), hovered_node)
-        }
-
-        #[cfg(any(not(feature = "devtools"), not(debug_assertions)))]
-        {
-            let vdom = with_accessibility(app);
-            (vdom, None, None)
-        }
-    };
-    #[cfg(not(feature = "custom-tokio-rt"))]
+        let registry = registry.with(error_console.clone());
+        registry.init();
+    }
+
+    #[cfg(not(feature = "tracing-subscriber"))]
+    #[cfg(feature = "devtools")]
+    #[cfg(debug_assertions)]
+    error_console.install();
+
+    #[cfg(feature = "devtools")]
+    #[cfg(debug_assertions)]
     {
-        let rt = tokio::runtime::Builder::new_multi_thread()
-            .enable_all()
-            .build()
-            .unwrap();
-        let _guard = rt.enter();
+        use std::sync::{
+            Arc,
+            Mutex,
+        };
 
-        WinitRenderer::launch(vdom, sdom, config, devtools, hovered_node);
+        use freya_devtools::DevtoolsBuilder;
+
+        let hovered_node = Some(Arc::new(Mutex::new(None)));
+        let (devtools, devtools_receiver) = Devtools::new();
+        let vdom = DevtoolsBuilder::new(app, devtools_receiver.clone(), hovered_node.clone())
+            .with_error_console(error_console)
+            .build();
+        (vdom, sdom, Some(devtools), hovered_node)
     }
 
-    #[cfg(feature = "custom-tokio-rt")]
-    WinitRenderer::launch(vdom, sdom, config, devtools, hovered_node);
+    #[cfg(any(not(feature = "devtools"), not(debug_assertions)))]
+    {
+        let vdom = with_accessibility(app);
+        (vdom, sdom, None, None)
+    }
 }
 
-#[cfg(any(not(feature = "devtools"), not(debug_assertions)))]
-use dioxus_core::VirtualDom;
 #[cfg(any(not(feature = "devtools"), not(debug_assertions)))]
 fn with_accessibility(app: AppComponent) -> VirtualDom {
     use dioxus::prelude::Props;
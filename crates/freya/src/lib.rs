@@ -50,6 +50,10 @@
 //!
 //! - `devtools`: enables a side panel to inspect your App tree, styles and computed layout.
 //! - `use_camera`: enables the [use_camera](self::hooks::use_camera) hook.
+//! - `dialogs`: enables native message dialogs in [UsePlatform](self::hooks::UsePlatform) and the [use_file_dialog](self::hooks::use_file_dialog) hook.
+//! - `eyedropper`: enables sampling colors from the whole screen with [UsePlatform::sample_screen_color](self::hooks::UsePlatform::sample_screen_color).
+//! - `menu`: enables the native menu bar of the app with `LaunchConfig::with_menu` and the [use_menu_event](self::hooks::use_menu_event) hook.
+//! - `tray`: enables installing a tray icon with `LaunchConfig::with_tray_icon` and the [use_tray_event](self::hooks::use_tray_event) hook.
 //! - `network-image`: enables the [NetworkImage](self::components::NetworkImage) component.
 //! - `custom-tokio-rt`: disables the default Tokio runtime created by Freya.
 //! - `performance-overlay`: enables the performance overlay plugin.
@@ -115,10 +119,19 @@ pub mod prelude {
             dynamic_bytes,
             static_bytes,
             CustomAttributeValues,
+            TextGrid,
+            TextGridCell,
+            TextGridStyle,
         },
+        event_loop_messages::{
+            SizeToContent,
+            WindowSettings,
+        },
+        node::ComputedStyle,
         platform::*,
         platform_state::*,
         types::AccessibilityId,
+        values::InputHint,
     };
     pub use freya_elements::{
         self as dioxus_elements,
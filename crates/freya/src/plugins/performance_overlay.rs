@@ -143,6 +143,42 @@ impl FreyaPlugin for PerformanceOverlayPlugin {
                     14.0,
                 );
 
+                let frame_counters = *freya_dom.frame_counters();
+
+                // Painted nodes
+                add_text(
+                    &mut paragraph_builder,
+                    format!(
+                        "{} Painted Nodes ({} culled) \n",
+                        frame_counters.painted_nodes, frame_counters.culled_nodes
+                    ),
+                    14.0,
+                );
+
+                // Hit-tested nodes
+                add_text(
+                    &mut paragraph_builder,
+                    format!(
+                        "{} Hit-tested Nodes ({} culled) \n",
+                        frame_counters.hit_tested_nodes, frame_counters.culled_hit_tested_nodes
+                    ),
+                    14.0,
+                );
+
+                let paragraph_cache = freya_dom.paragraph_cache();
+
+                // Text measurements cache
+                add_text(
+                    &mut paragraph_builder,
+                    format!(
+                        "{} Cached Texts ({} hits, {} misses) \n",
+                        paragraph_cache.len(),
+                        paragraph_cache.hits(),
+                        paragraph_cache.misses()
+                    ),
+                    14.0,
+                );
+
                 let mut paragraph = paragraph_builder.build();
                 paragraph.layout(f32::MAX);
                 paragraph.paint(canvas, (5.0, 0.0));
@@ -156,9 +192,9 @@ impl FreyaPlugin for PerformanceOverlayPlugin {
                     .max_fps
                     .max(self.fps_historic.iter().max().copied().unwrap_or_default());
                 let start_x = 5.0;
-                let start_y = 170.0 + self.max_fps.max(60) as f32;
+                let start_y = 230.0 + self.max_fps.max(60) as f32;
 
-                canvas.draw_rect(Rect::new(5., 150., 200., start_y), &paint);
+                canvas.draw_rect(Rect::new(5., 210., 200., start_y), &paint);
 
                 for (i, fps) in self.fps_historic.iter().enumerate() {
                     let mut paint = Paint::default();
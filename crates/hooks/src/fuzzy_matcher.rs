@@ -0,0 +1,176 @@
+use std::ops::Range;
+
+/// Score bonus of every matched character.
+const MATCH_SCORE: i32 = 16;
+/// Score bonus of a character matched right after the previous one.
+const CONSECUTIVE_BONUS: i32 = 24;
+/// Score bonus of a character matched at the start of a word.
+const WORD_START_BONUS: i32 = 20;
+/// Score penalty for every character skipped between two matched characters.
+const GAP_PENALTY: i32 = 2;
+/// Score penalty for every character skipped before the first matched character.
+const LEADING_GAP_PENALTY: i32 = 1;
+
+/// Result of [`fuzzy_match`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FuzzyMatch {
+    /// How good the match is, higher is better.
+    pub score: i32,
+    /// Byte ranges of the text that matched the pattern, e.g. to highlight them.
+    pub ranges: Vec<Range<usize>>,
+}
+
+fn chars_match(a: char, b: char) -> bool {
+    a == b || a.to_lowercase().eq(b.to_lowercase())
+}
+
+fn is_word_start(prev: Option<char>, current: char) -> bool {
+    match prev {
+        None => true,
+        Some(prev) => !prev.is_alphanumeric() || (prev.is_lowercase() && current.is_uppercase()),
+    }
+}
+
+/// Match all the characters of the pattern, in order, starting from the given character of the text.
+fn match_from(pattern: &[char], text: &[(usize, char)], start: usize) -> Option<(i32, Vec<usize>)> {
+    let mut score = -(start as i32) * LEADING_GAP_PENALTY;
+    let mut matched = Vec::with_capacity(pattern.len());
+    let mut text_index = start;
+
+    for pattern_char in pattern {
+        let found = text[text_index..]
+            .iter()
+            .position(|(_, text_char)| chars_match(*pattern_char, *text_char))?;
+        let index = text_index + found;
+
+        score += MATCH_SCORE;
+        if let Some(prev_index) = matched.last() {
+            if index == prev_index + 1 {
+                score += CONSECUTIVE_BONUS;
+            } else {
+                score -= (index - prev_index - 1) as i32 * GAP_PENALTY;
+            }
+        }
+        let prev_char = index.checked_sub(1).map(|prev| text[prev].1);
+        if is_word_start(prev_char, text[index].1) {
+            score += WORD_START_BONUS;
+        }
+
+        matched.push(index);
+        text_index = index + 1;
+    }
+
+    Some((score, matched))
+}
+
+/// Check whether all the characters of `pattern` appear in `text` in the same order, ignoring the case.
+///
+/// Consecutive characters and characters at the start of words score higher,
+/// so `"fb"` matches `"FooBar"` better than `"fabric"`.
+///
+/// ```rust
+/// # use freya_hooks::fuzzy_match;
+/// let res = fuzzy_match("fb", "FooBar").unwrap();
+/// assert_eq!(res.ranges, vec![0..1, 3..4]);
+/// assert!(res.score > fuzzy_match("fb", "fabric").unwrap().score);
+///
+/// assert!(fuzzy_match("bf", "FooBar").is_none());
+/// ```
+pub fn fuzzy_match(pattern: &str, text: &str) -> Option<FuzzyMatch> {
+    let pattern = pattern.chars().collect::<Vec<_>>();
+    let Some(first) = pattern.first() else {
+        return Some(FuzzyMatch {
+            score: 0,
+            ranges: Vec::new(),
+        });
+    };
+    let text = text.char_indices().collect::<Vec<_>>();
+
+    // Try every occurrence of the first character and keep the best one
+    let (score, matched) = text
+        .iter()
+        .enumerate()
+        .filter(|(_, (_, text_char))| chars_match(*first, *text_char))
+        .filter_map(|(start, _)| match_from(&pattern, &text, start))
+        .max_by_key(|(score, _)| *score)?;
+
+    // Join the consecutive characters in the same range
+    let mut ranges: Vec<Range<usize>> = Vec::new();
+    for index in matched {
+        let (byte, ch) = text[index];
+        let end = byte + ch.len_utf8();
+        match ranges.last_mut() {
+            Some(range) if range.end == byte => range.end = end,
+            _ => ranges.push(byte..end),
+        }
+    }
+
+    Some(FuzzyMatch { score, ranges })
+}
+
+/// Filter the items that match the pattern with [`fuzzy_match`], sorted from the best to the worst match.
+/// Items with the same score keep their original order.
+///
+/// ```rust
+/// # use freya_hooks::fuzzy_filter;
+/// let items = ["Open File", "Save File", "Close Window"];
+/// let res = fuzzy_filter("sf", items, |item| *item);
+/// assert_eq!(res[0].0, "Save File");
+/// assert_eq!(res.len(), 1);
+/// ```
+pub fn fuzzy_filter<T>(
+    pattern: &str,
+    items: impl IntoIterator<Item = T>,
+    text: impl Fn(&T) -> &str,
+) -> Vec<(T, FuzzyMatch)> {
+    let mut matches = items
+        .into_iter()
+        .filter_map(|item| {
+            let res = fuzzy_match(pattern, text(&item))?;
+            Some((item, res))
+        })
+        .collect::<Vec<_>>();
+    matches.sort_by(|(_, a), (_, b)| b.score.cmp(&a.score));
+    matches
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn matches_in_order_ignoring_case() {
+        assert!(fuzzy_match("abc", "a_b_c").is_some());
+        assert!(fuzzy_match("ABC", "abc").is_some());
+        assert!(fuzzy_match("acb", "abc").is_none());
+        assert!(fuzzy_match("abcd", "abc").is_none());
+        assert_eq!(fuzzy_match("", "abc").unwrap().ranges, vec![]);
+    }
+
+    #[test]
+    fn joins_consecutive_ranges() {
+        let res = fuzzy_match("ope", "Open File").unwrap();
+        assert_eq!(res.ranges, vec![0..3]);
+
+        let res = fuzzy_match("of", "Open File").unwrap();
+        assert_eq!(res.ranges, vec![0..1, 5..6]);
+
+        // Byte ranges of multi-byte characters
+        let res = fuzzy_match("ñú", "Ñandú").unwrap();
+        assert_eq!(res.ranges, vec![0..2, 5..7]);
+    }
+
+    #[test]
+    fn prefers_better_matches() {
+        let score = |pattern, text| fuzzy_match(pattern, text).unwrap().score;
+
+        // Consecutive characters
+        assert!(score("file", "Open File") > score("file", "Fix Inline Lens Editor"));
+        // Start of words
+        assert!(score("sw", "Save Window") > score("sw", "Answer"));
+        // CamelCase words
+        assert!(score("gb", "getBuffer") > score("gb", "gabble"));
+        // Best occurrence, not the first one
+        assert_eq!(fuzzy_match("fi", "a fox file").unwrap().ranges, vec![6..8]);
+    }
+}
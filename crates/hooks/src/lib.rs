@@ -4,47 +4,103 @@
 //! A collection of hooks to be used in Freya.
 
 mod editor_history;
+mod fuzzy_matcher;
 mod rope_editor;
 mod shader_uniforms;
+mod text_diff;
 mod text_editor;
 mod theming;
 
 mod use_activable_route;
 mod use_animation;
+mod use_asset;
 mod use_asset_cacher;
 mod use_canvas;
+mod use_clipboard;
+mod use_clipboard_watcher;
+mod use_computed_style;
 mod use_editable;
+#[cfg(feature = "dialogs")]
+mod use_file_dialog;
 mod use_focus;
+mod use_font;
+mod use_gestures;
+mod use_global_shortcut;
 mod use_id;
+mod use_idle;
 mod use_init_native_platform;
+mod use_key_capture;
+mod use_layout_direction;
+#[cfg(feature = "menu")]
+mod use_menu;
 mod use_node;
 mod use_platform;
 mod use_platform_information;
 mod use_popup;
+mod use_power_events;
 mod use_preferred_theme;
+mod use_render_info;
+mod use_selection;
+mod use_style;
+mod use_text_scale_factor;
 mod use_theme;
+mod use_window;
 
+#[cfg(feature = "eyedropper")]
+mod screen_color;
 #[cfg(feature = "use_camera")]
 mod use_camera;
+#[cfg(feature = "use_screen_capture")]
+mod use_screen_capture;
+#[cfg(feature = "tray")]
+mod use_tray;
 
 pub use editor_history::*;
+pub use fuzzy_matcher::*;
 pub use rope_editor::*;
+#[cfg(feature = "eyedropper")]
+pub use screen_color::ScreenColorError;
 pub use shader_uniforms::*;
+pub use text_diff::*;
 pub use text_editor::*;
 pub use theming::*;
 pub use use_activable_route::*;
 pub use use_animation::*;
+pub use use_asset::*;
 pub use use_asset_cacher::*;
 #[cfg(feature = "use_camera")]
 pub use use_camera::*;
 pub use use_canvas::*;
+pub use use_clipboard::*;
+pub use use_clipboard_watcher::*;
+pub use use_computed_style::*;
 pub use use_editable::*;
+#[cfg(feature = "dialogs")]
+pub use use_file_dialog::*;
 pub use use_focus::*;
+pub use use_font::*;
+pub use use_gestures::*;
+pub use use_global_shortcut::*;
 pub use use_id::*;
+pub use use_idle::*;
 pub use use_init_native_platform::*;
+pub use use_key_capture::*;
+pub use use_layout_direction::*;
+#[cfg(feature = "menu")]
+pub use use_menu::*;
 pub use use_node::*;
 pub use use_platform::*;
 pub use use_platform_information::*;
 pub use use_popup::*;
+pub use use_power_events::*;
 pub use use_preferred_theme::*;
+pub use use_render_info::*;
+#[cfg(feature = "use_screen_capture")]
+pub use use_screen_capture::*;
+pub use use_selection::*;
+pub use use_style::*;
+pub use use_text_scale_factor::*;
 pub use use_theme::*;
+#[cfg(feature = "tray")]
+pub use use_tray::*;
+pub use use_window::*;
@@ -24,6 +24,8 @@ pub struct RopeEditor {
     pub(crate) selected: Option<(usize, usize)>,
     pub(crate) clipboard: UseClipboard,
     pub(crate) history: EditorHistory,
+    pub(crate) direction: EditorDirection,
+    pub(crate) sensitive: bool,
 }
 
 impl Display for RopeEditor {
@@ -50,9 +52,23 @@ impl RopeEditor {
             mode,
             clipboard,
             history,
+            direction: EditorDirection::default(),
+            sensitive: false,
         }
     }
 
+    /// Specify the base direction of the text.
+    pub fn with_text_direction(mut self, direction: EditorDirection) -> Self {
+        self.direction = direction;
+        self
+    }
+
+    /// Mark the text as sensitive, which disables copying it to the clipboard.
+    pub fn with_sensitive(mut self, sensitive: bool) -> Self {
+        self.sensitive = sensitive;
+        self
+    }
+
     pub fn rope(&self) -> &Rope {
         &self.rope
     }
@@ -167,6 +183,14 @@ impl TextEditor for RopeEditor {
         &mut self.cursor
     }
 
+    fn text_direction(&self) -> EditorDirection {
+        self.direction
+    }
+
+    fn is_sensitive(&self) -> bool {
+        self.sensitive
+    }
+
     fn expand_selection_to_cursor(&mut self) {
         let pos = self.cursor_pos();
         if let Some(selected) = self.selected.as_mut() {
@@ -0,0 +1,36 @@
+use freya_engine::prelude::Color;
+use xcap::Monitor;
+
+/// Errors when sampling a color from the screen, see [`crate::UsePlatform::sample_screen_color`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ScreenColorError {
+    /// There is no monitor at the given position.
+    NoMonitor,
+    /// The screen could not be captured, usually because the permission was denied.
+    CaptureFailed(String),
+}
+
+impl std::fmt::Display for ScreenColorError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::NoMonitor => f.write_str("There is no monitor at the given position"),
+            Self::CaptureFailed(err) => write!(f, "Failed to capture the screen: {err}"),
+        }
+    }
+}
+
+impl std::error::Error for ScreenColorError {}
+
+/// Capture the monitor at the given position of the screen, in physical pixels, and read the color of that pixel.
+pub(crate) fn sample_screen_color(x: i32, y: i32) -> Result<Color, ScreenColorError> {
+    let monitor = Monitor::from_point(x, y).map_err(|_| ScreenColorError::NoMonitor)?;
+    let image = monitor
+        .capture_image()
+        .map_err(|err| ScreenColorError::CaptureFailed(err.to_string()))?;
+
+    let pixel_x = (x - monitor.x()).clamp(0, image.width() as i32 - 1) as u32;
+    let pixel_y = (y - monitor.y()).clamp(0, image.height() as i32 - 1) as u32;
+    let [r, g, b, a] = image.get_pixel(pixel_x, pixel_y).0;
+
+    Ok(Color::from_argb(a, r, g, b))
+}
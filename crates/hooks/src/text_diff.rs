@@ -0,0 +1,307 @@
+use std::ops::Range;
+
+/// Kind of a line in a diff created with [`diff_lines`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum DiffLineKind {
+    /// The line is in both texts.
+    Unchanged,
+    /// The line is only in the new text.
+    Added,
+    /// The line is only in the old text.
+    Removed,
+}
+
+/// Line of a diff created with [`diff_lines`].
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct DiffLine {
+    pub kind: DiffLineKind,
+    /// Line number, starting at `1`, in the old text.
+    pub old_number: Option<usize>,
+    /// Line number, starting at `1`, in the new text.
+    pub new_number: Option<usize>,
+    pub text: String,
+    /// Ranges of chars of the text that changed in an edited line.
+    pub changes: Vec<Range<usize>>,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum DiffOp {
+    Equal(usize, usize),
+    Delete(usize),
+    Insert(usize),
+}
+
+/// Shortest edit script between two sequences, using the Myers algorithm.
+fn diff_ops<T: PartialEq>(old: &[T], new: &[T]) -> Vec<DiffOp> {
+    // The common prefix and suffix don't need to be diffed
+    let prefix = old.iter().zip(new).take_while(|(a, b)| a == b).count();
+    let suffix = old[prefix..]
+        .iter()
+        .rev()
+        .zip(new[prefix..].iter().rev())
+        .take_while(|(a, b)| a == b)
+        .count();
+
+    let mut ops = (0..prefix).map(|i| DiffOp::Equal(i, i)).collect::<Vec<_>>();
+    ops.extend(
+        myers(
+            &old[prefix..old.len() - suffix],
+            &new[prefix..new.len() - suffix],
+        )
+        .into_iter()
+        .map(|op| match op {
+            DiffOp::Equal(a, b) => DiffOp::Equal(a + prefix, b + prefix),
+            DiffOp::Delete(a) => DiffOp::Delete(a + prefix),
+            DiffOp::Insert(b) => DiffOp::Insert(b + prefix),
+        }),
+    );
+    ops.extend((0..suffix).map(|i| DiffOp::Equal(old.len() - suffix + i, new.len() - suffix + i)));
+    ops
+}
+
+fn myers<T: PartialEq>(old: &[T], new: &[T]) -> Vec<DiffOp> {
+    let (n, m) = (old.len() as isize, new.len() as isize);
+    let max = n + m;
+    let offset = max + 1;
+    let mut v = vec![0isize; 2 * offset as usize + 1];
+    // Only the diagonals reachable in every step are kept for the backtracking
+    let mut trace = Vec::new();
+
+    'search: for d in 0..=max {
+        trace.push(v[(offset - d) as usize..=(offset + d) as usize].to_vec());
+        for k in (-d..=d).step_by(2) {
+            let index = (offset + k) as usize;
+            let mut x = if k == -d || (k != d && v[index - 1] < v[index + 1]) {
+                v[index + 1]
+            } else {
+                v[index - 1] + 1
+            };
+            let mut y = x - k;
+            while x < n && y < m && old[x as usize] == new[y as usize] {
+                x += 1;
+                y += 1;
+            }
+            v[index] = x;
+            if x >= n && y >= m {
+                break 'search;
+            }
+        }
+    }
+
+    let mut ops = Vec::new();
+    let (mut x, mut y) = (n, m);
+    for (d, v) in trace.iter().enumerate().rev() {
+        let d = d as isize;
+        if d == 0 {
+            while x > 0 && y > 0 {
+                ops.push(DiffOp::Equal(x as usize - 1, y as usize - 1));
+                x -= 1;
+                y -= 1;
+            }
+            break;
+        }
+
+        let k = x - y;
+        let at = |k: isize| v[(k + d) as usize];
+        let prev_k = if k == -d || (k != d && at(k - 1) < at(k + 1)) {
+            k + 1
+        } else {
+            k - 1
+        };
+        let prev_x = at(prev_k);
+        let prev_y = prev_x - prev_k;
+
+        while x > prev_x && y > prev_y {
+            ops.push(DiffOp::Equal(x as usize - 1, y as usize - 1));
+            x -= 1;
+            y -= 1;
+        }
+        if x == prev_x {
+            ops.push(DiffOp::Insert(y as usize - 1));
+        } else {
+            ops.push(DiffOp::Delete(x as usize - 1));
+        }
+        x = prev_x;
+        y = prev_y;
+    }
+
+    ops.reverse();
+    ops
+}
+
+/// Merge sorted indexes into ranges.
+fn to_ranges(indexes: impl Iterator<Item = usize>) -> Vec<Range<usize>> {
+    let mut ranges: Vec<Range<usize>> = Vec::new();
+    for index in indexes {
+        match ranges.last_mut() {
+            Some(range) if range.end == index => range.end += 1,
+            _ => ranges.push(index..index + 1),
+        }
+    }
+    ranges
+}
+
+/// Ranges of chars that changed between two versions of a line.
+fn diff_chars(old: &str, new: &str) -> (Vec<Range<usize>>, Vec<Range<usize>>) {
+    let old = old.chars().collect::<Vec<_>>();
+    let new = new.chars().collect::<Vec<_>>();
+    let ops = diff_ops(&old, &new);
+
+    // Lines without anything in common are highlighted as a whole already
+    if !ops.iter().any(|op| matches!(op, DiffOp::Equal(..))) {
+        return (Vec::new(), Vec::new());
+    }
+
+    let removed = to_ranges(ops.iter().filter_map(|op| match op {
+        DiffOp::Delete(a) => Some(*a),
+        _ => None,
+    }));
+    let added = to_ranges(ops.iter().filter_map(|op| match op {
+        DiffOp::Insert(b) => Some(*b),
+        _ => None,
+    }));
+    (removed, added)
+}
+
+/// Diff two texts line by line, with the changed chars of the edited lines.
+///
+/// Every block of changes lists its removed lines before its added lines, and removed
+/// and added lines in the same position of a block are compared char by char.
+///
+/// ```rust
+/// # use freya_hooks::{diff_lines, DiffLineKind};
+/// let diff = diff_lines("a\nb\nc", "a\nB\nc");
+/// let kinds = diff.iter().map(|line| line.kind).collect::<Vec<_>>();
+/// assert_eq!(
+///     kinds,
+///     [
+///         DiffLineKind::Unchanged,
+///         DiffLineKind::Removed,
+///         DiffLineKind::Added,
+///         DiffLineKind::Unchanged
+///     ]
+/// );
+/// ```
+pub fn diff_lines(old: &str, new: &str) -> Vec<DiffLine> {
+    let old_lines = old.lines().collect::<Vec<_>>();
+    let new_lines = new.lines().collect::<Vec<_>>();
+    let ops = diff_ops(&old_lines, &new_lines);
+
+    let mut lines = Vec::with_capacity(ops.len());
+    let mut removed = Vec::new();
+    let mut added = Vec::new();
+
+    let flush = |lines: &mut Vec<DiffLine>, removed: &mut Vec<usize>, added: &mut Vec<usize>| {
+        let block_start = lines.len();
+        for a in removed.drain(..) {
+            lines.push(DiffLine {
+                kind: DiffLineKind::Removed,
+                old_number: Some(a + 1),
+                new_number: None,
+                text: old_lines[a].to_string(),
+                changes: Vec::new(),
+            });
+        }
+        let removed_len = lines.len() - block_start;
+        for (i, b) in added.drain(..).enumerate() {
+            let mut line = DiffLine {
+                kind: DiffLineKind::Added,
+                old_number: None,
+                new_number: Some(b + 1),
+                text: new_lines[b].to_string(),
+                changes: Vec::new(),
+            };
+            if i < removed_len {
+                let removed_line = &mut lines[block_start + i];
+                let (removed_changes, added_changes) = diff_chars(&removed_line.text, &line.text);
+                removed_line.changes = removed_changes;
+                line.changes = added_changes;
+            }
+            lines.push(line);
+        }
+    };
+
+    for op in ops {
+        match op {
+            DiffOp::Equal(a, b) => {
+                flush(&mut lines, &mut removed, &mut added);
+                lines.push(DiffLine {
+                    kind: DiffLineKind::Unchanged,
+                    old_number: Some(a + 1),
+                    new_number: Some(b + 1),
+                    text: old_lines[a].to_string(),
+                    changes: Vec::new(),
+                });
+            }
+            DiffOp::Delete(a) => removed.push(a),
+            DiffOp::Insert(b) => added.push(b),
+        }
+    }
+    flush(&mut lines, &mut removed, &mut added);
+
+    lines
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn kinds(lines: &[DiffLine]) -> String {
+        lines
+            .iter()
+            .map(|line| match line.kind {
+                DiffLineKind::Unchanged => ' ',
+                DiffLineKind::Added => '+',
+                DiffLineKind::Removed => '-',
+            })
+            .collect()
+    }
+
+    #[test]
+    fn equal_texts() {
+        let lines = diff_lines("a\nb", "a\nb");
+        assert_eq!(kinds(&lines), "  ");
+        assert_eq!(lines[1].old_number, Some(2));
+        assert_eq!(lines[1].new_number, Some(2));
+    }
+
+    #[test]
+    fn added_and_removed_lines() {
+        assert_eq!(kinds(&diff_lines("", "a\nb")), "++");
+        assert_eq!(kinds(&diff_lines("a\nb", "")), "--");
+
+        let lines = diff_lines("a\nb\nc\nd", "a\nc\nx\nd");
+        assert_eq!(kinds(&lines), " - + ");
+        assert_eq!(lines[1].text, "b");
+        assert_eq!(lines[3].text, "x");
+        assert_eq!(lines[3].old_number, None);
+        assert_eq!(lines[3].new_number, Some(3));
+        assert_eq!(lines[4].old_number, Some(4));
+        assert_eq!(lines[4].new_number, Some(4));
+    }
+
+    #[test]
+    fn intra_line_changes() {
+        let lines = diff_lines("let value = 1;", "let values = 2;");
+        assert_eq!(kinds(&lines), "-+");
+        assert_eq!(lines[0].changes, vec![12..13]);
+        assert_eq!(lines[1].changes, vec![9..10, 13..14]);
+
+        // Completely different lines are not highlighted char by char
+        let lines = diff_lines("abc", "xyz");
+        assert!(lines[0].changes.is_empty());
+        assert!(lines[1].changes.is_empty());
+    }
+
+    #[test]
+    fn minimal_edit_script() {
+        let old = "abcabba".chars().collect::<Vec<_>>();
+        let new = "cbabac".chars().collect::<Vec<_>>();
+        let ops = diff_ops(&old, &new);
+        let edits = ops
+            .iter()
+            .filter(|op| !matches!(op, DiffOp::Equal(..)))
+            .count();
+        assert_eq!(edits, 5);
+    }
+}
@@ -60,6 +60,56 @@ impl Display for Line<'_> {
     }
 }
 
+/// Base direction of the text of a [TextEditor].
+///
+/// Used to turn the arrow keys into visual cursor movements,
+/// e.g. `ArrowLeft` moves the cursor forward in right-to-left text.
+#[derive(Clone, Copy, Default, PartialEq, Eq, Debug)]
+pub enum EditorDirection {
+    /// Left-to-right text.
+    #[default]
+    Ltr,
+    /// Right-to-left text, e.g. Arabic or Hebrew.
+    Rtl,
+    /// Resolve the direction of each line from its first strong character.
+    Auto,
+}
+
+impl EditorDirection {
+    /// Resolve the direction of the given text from its first strong character,
+    /// falling back to left-to-right when there is none.
+    pub fn resolve(&self, text: &str) -> EditorDirection {
+        match self {
+            Self::Auto => text
+                .chars()
+                .find_map(|ch| {
+                    if is_rtl_char(ch) {
+                        Some(EditorDirection::Rtl)
+                    } else if ch.is_alphabetic() {
+                        Some(EditorDirection::Ltr)
+                    } else {
+                        None
+                    }
+                })
+                .unwrap_or(EditorDirection::Ltr),
+            direction => *direction,
+        }
+    }
+}
+
+/// Check if the given char is a strong right-to-left char,
+/// such as Hebrew, Arabic, Syriac or Thaana letters.
+pub fn is_rtl_char(ch: char) -> bool {
+    matches!(
+        ch,
+        '\u{0590}'..='\u{08FF}'
+            | '\u{FB1D}'..='\u{FDFF}'
+            | '\u{FE70}'..='\u{FEFF}'
+            | '\u{10800}'..='\u{10FFF}'
+            | '\u{1E800}'..='\u{1EFFF}'
+    ) && ch.is_alphabetic()
+}
+
 bitflags::bitflags! {
     /// Events for [TextEditor]
     #[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, Default)]
@@ -220,6 +270,48 @@ pub trait TextEditor {
         }
     }
 
+    /// Get the base direction of the text
+    fn text_direction(&self) -> EditorDirection {
+        EditorDirection::Ltr
+    }
+
+    /// Check whether the text is sensitive, sensitive texts can't be copied to the clipboard
+    fn is_sensitive(&self) -> bool {
+        false
+    }
+
+    /// Check whether the line where the cursor is flows from right to left
+    fn is_cursor_line_rtl(&self) -> bool {
+        match self.text_direction() {
+            EditorDirection::Ltr => false,
+            EditorDirection::Rtl => true,
+            EditorDirection::Auto => self
+                .line(self.cursor_row())
+                .map(|line| EditorDirection::Auto.resolve(&line.text) == EditorDirection::Rtl)
+                .unwrap_or_default(),
+        }
+    }
+
+    /// Move the cursor 1 char to the visual left, this is backwards in left-to-right lines
+    /// and forward in right-to-left lines
+    fn cursor_visual_left(&mut self) -> bool {
+        if self.is_cursor_line_rtl() {
+            self.cursor_right()
+        } else {
+            self.cursor_left()
+        }
+    }
+
+    /// Move the cursor 1 char to the visual right, this is forward in left-to-right lines
+    /// and backwards in right-to-left lines
+    fn cursor_visual_right(&mut self) -> bool {
+        if self.is_cursor_line_rtl() {
+            self.cursor_left()
+        } else {
+            self.cursor_right()
+        }
+    }
+
     /// Get the cursor position
     fn cursor_pos(&self) -> usize {
         self.cursor().pos()
@@ -305,7 +397,7 @@ pub trait TextEditor {
                     self.expand_selection_to_cursor();
                 }
 
-                if self.cursor_left() {
+                if self.cursor_visual_left() {
                     event.insert(TextEvent::CURSOR_CHANGED);
                 }
 
@@ -319,7 +411,7 @@ pub trait TextEditor {
                     self.expand_selection_to_cursor();
                 }
 
-                if self.cursor_right() {
+                if self.cursor_visual_right() {
                     event.insert(TextEvent::CURSOR_CHANGED);
                 }
 
@@ -412,6 +504,11 @@ pub trait TextEditor {
                         event.remove(TextEvent::SELECTION_CHANGED);
                     }
 
+                    // Sensitive text can't leave the editor
+                    Code::KeyC | Code::KeyX if meta_or_ctrl && self.is_sensitive() => {
+                        event.remove(TextEvent::SELECTION_CHANGED);
+                    }
+
                     // Copy selected text
                     Code::KeyC if meta_or_ctrl && allow_clipboard => {
                         let selected = self.get_selected_text();
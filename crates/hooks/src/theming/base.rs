@@ -113,6 +113,7 @@ pub(crate) const BASE_THEME: Theme = Theme {
         hover_thumb_background: cow_borrowed!("key(secondary_opposite_surface)"),
         active_thumb_background: cow_borrowed!("key(tertiary_opposite_surface)"),
         size: cow_borrowed!("15"),
+        overscroll_glow: cow_borrowed!("key(secondary_opposite_surface)"),
     },
     tooltip: TooltipTheme {
         background: cow_borrowed!("key(neutral_surface)"),
@@ -168,6 +169,7 @@ pub(crate) const BASE_THEME: Theme = Theme {
         arrow_fill: cow_borrowed!("key(solid)"),
         row_background: cow_borrowed!("transparent"),
         hover_row_background: cow_borrowed!("key(secondary_surface)"),
+        selected_row_background: cow_borrowed!("key(primary_surface)"),
         divider_fill: cow_borrowed!("key(primary_surface)"),
         corner_radius: cow_borrowed!("6"),
     },
@@ -223,6 +225,18 @@ pub(crate) const BASE_THEME: Theme = Theme {
         border_fill: cow_borrowed!("key(primary_surface)"),
         corner_radius: cow_borrowed!("6"),
     },
+    menu_bar: MenuBarTheme {
+        background: cow_borrowed!("key(neutral_surface)"),
+        padding: cow_borrowed!("2 4"),
+        hover_background: cow_borrowed!("key(focused_surface)"),
+        corner_radius: cow_borrowed!("6"),
+        shortcut_color: cow_borrowed!("key(placeholder_color)"),
+        disabled_color: cow_borrowed!("key(placeholder_color)"),
+        separator_fill: cow_borrowed!("key(primary_surface)"),
+        font_theme: FontTheme {
+            color: cow_borrowed!("key(color)"),
+        },
+    },
     snackbar: SnackBarTheme {
         background: cow_borrowed!("key(focused_surface)"),
         color: cow_borrowed!("key(color)"),
@@ -259,4 +273,108 @@ pub(crate) const BASE_THEME: Theme = Theme {
         background: cow_borrowed!("key(secondary_surface)"),
         hover_background: cow_borrowed!("key(primary_surface)"),
     },
+    diff_view: DiffViewTheme {
+        font_theme: FontTheme {
+            color: cow_borrowed!("key(color)"),
+        },
+        background: cow_borrowed!("key(neutral_surface)"),
+        line_number_color: cow_borrowed!("key(placeholder_color)"),
+        added_background: cow_borrowed!("rgb(46, 160, 67, 0.15)"),
+        added_highlight: cow_borrowed!("rgb(46, 160, 67, 0.4)"),
+        removed_background: cow_borrowed!("rgb(248, 81, 73, 0.15)"),
+        removed_highlight: cow_borrowed!("rgb(248, 81, 73, 0.4)"),
+        collapsed_background: cow_borrowed!("key(secondary_surface)"),
+    },
+    data_inspector: DataInspectorTheme {
+        font_theme: FontTheme {
+            color: cow_borrowed!("key(color)"),
+        },
+        background: cow_borrowed!("key(neutral_surface)"),
+        key_color: cow_borrowed!("key(color)"),
+        string_color: cow_borrowed!("rgb(80, 160, 80)"),
+        number_color: cow_borrowed!("rgb(60, 130, 220)"),
+        boolean_color: cow_borrowed!("rgb(190, 100, 190)"),
+        null_color: cow_borrowed!("key(placeholder_color)"),
+    },
+    hex_view: HexViewTheme {
+        font_theme: FontTheme {
+            color: cow_borrowed!("key(color)"),
+        },
+        background: cow_borrowed!("key(neutral_surface)"),
+        offset_color: cow_borrowed!("key(placeholder_color)"),
+        selection_background: cow_borrowed!("key(highlight_color)"),
+        changed_color: cow_borrowed!("rgb(230, 80, 70)"),
+    },
+    before_after: BeforeAfterTheme {
+        divider_background: cow_borrowed!("key(secondary_accent)"),
+        handle_background: cow_borrowed!("key(background)"),
+        border_fill: cow_borrowed!("key(focused_border)"),
+    },
+    drawing_pad: DrawingPadTheme {
+        background: cow_borrowed!("key(background)"),
+        stroke_color: cow_borrowed!("key(color)"),
+    },
+    map_view: MapViewTheme {
+        background: cow_borrowed!("key(neutral_surface)"),
+        tile_background: cow_borrowed!("key(secondary_surface)"),
+    },
+    qr_code: QrCodeTheme {
+        background: cow_borrowed!("white"),
+        color: cow_borrowed!("black"),
+    },
+    pdf_view: PdfViewTheme {
+        background: cow_borrowed!("key(neutral_surface)"),
+        page_background: cow_borrowed!("white"),
+        selection_background: cow_borrowed!("rgb(0, 110, 255, 0.3)"),
+        font_theme: FontTheme {
+            color: cow_borrowed!("key(color)"),
+        },
+    },
+    tree_view: TreeViewTheme {
+        background: cow_borrowed!("key(background)"),
+        hover_background: cow_borrowed!("key(neutral_surface)"),
+        selected_background: cow_borrowed!("key(focused_surface)"),
+        guide_color: cow_borrowed!("key(secondary_surface)"),
+        arrow_fill: cow_borrowed!("key(solid)"),
+        loading_color: cow_borrowed!("key(placeholder_color)"),
+        font_theme: FontTheme {
+            color: cow_borrowed!("key(color)"),
+        },
+    },
+    waveform: WaveformTheme {
+        background: cow_borrowed!("key(neutral_surface)"),
+        color: cow_borrowed!("key(primary_accent)"),
+        playhead_color: cow_borrowed!("key(color)"),
+        selection_background: cow_borrowed!("rgb(0, 110, 255, 0.3)"),
+    },
+    gantt: GanttTheme {
+        background: cow_borrowed!("key(background)"),
+        row_background: cow_borrowed!("key(neutral_surface)"),
+        bar_background: cow_borrowed!("key(primary_accent)"),
+        grid_color: cow_borrowed!("key(secondary_surface)"),
+        today_color: cow_borrowed!("rgb(230, 60, 60)"),
+        font_theme: FontTheme {
+            color: cow_borrowed!("key(color)"),
+        },
+    },
+    calendar: CalendarTheme {
+        background: cow_borrowed!("key(background)"),
+        border_fill: cow_borrowed!("key(primary_surface)"),
+        focus_border_fill: cow_borrowed!("key(focused_border)"),
+        hover_background: cow_borrowed!("key(focused_surface)"),
+        selected_background: cow_borrowed!("key(primary_accent)"),
+        selected_color: cow_borrowed!("key(primary_color)"),
+        range_background: cow_borrowed!("key(secondary_surface)"),
+        today_border_fill: cow_borrowed!("key(primary_accent)"),
+        disabled_color: cow_borrowed!("key(placeholder_color)"),
+        weekday_color: cow_borrowed!("key(secondary_opposite_surface)"),
+        arrow_fill: cow_borrowed!("key(solid)"),
+        font_theme: FontTheme {
+            color: cow_borrowed!("key(color)"),
+        },
+    },
+    skeleton: SkeletonTheme {
+        background: cow_borrowed!("key(secondary_surface)"),
+        highlight_background: cow_borrowed!("key(neutral_surface)"),
+    },
 };
@@ -283,6 +283,7 @@ define_theme! {
         hover_thumb_background: str,
         active_thumb_background: str,
         size: str,
+        overscroll_glow: str,
     }
 }
 
@@ -361,6 +362,7 @@ define_theme! {
         background: str,
         arrow_fill: str,
         hover_row_background: str,
+        selected_row_background: str,
         row_background: str,
         divider_fill: str,
         corner_radius: str,
@@ -430,6 +432,22 @@ define_theme! {
     }
 }
 
+define_theme! {
+    %[component]
+    pub MenuBar {
+        %[cows]
+        background: str,
+        padding: str,
+        hover_background: str,
+        corner_radius: str,
+        shortcut_color: str,
+        disabled_color: str,
+        separator_fill: str,
+        %[subthemes]
+        font_theme: FontTheme,
+    }
+}
+
 define_theme! {
     %[component]
     pub MenuContainer {
@@ -522,6 +540,168 @@ define_theme! {
     }
 }
 
+define_theme! {
+    %[component]
+    pub DiffView {
+        %[cows]
+        background: str,
+        line_number_color: str,
+        added_background: str,
+        added_highlight: str,
+        removed_background: str,
+        removed_highlight: str,
+        collapsed_background: str,
+        %[subthemes]
+        font_theme: FontTheme,
+    }
+}
+
+define_theme! {
+    %[component]
+    pub DataInspector {
+        %[cows]
+        background: str,
+        key_color: str,
+        string_color: str,
+        number_color: str,
+        boolean_color: str,
+        null_color: str,
+        %[subthemes]
+        font_theme: FontTheme,
+    }
+}
+
+define_theme! {
+    %[component]
+    pub HexView {
+        %[cows]
+        background: str,
+        offset_color: str,
+        selection_background: str,
+        changed_color: str,
+        %[subthemes]
+        font_theme: FontTheme,
+    }
+}
+
+define_theme! {
+    %[component]
+    pub BeforeAfter {
+        %[cows]
+        divider_background: str,
+        handle_background: str,
+        border_fill: str,
+    }
+}
+
+define_theme! {
+    %[component]
+    pub DrawingPad {
+        %[cows]
+        background: str,
+        stroke_color: str,
+    }
+}
+
+define_theme! {
+    %[component]
+    pub MapView {
+        %[cows]
+        background: str,
+        tile_background: str,
+    }
+}
+
+define_theme! {
+    %[component]
+    pub QrCode {
+        %[cows]
+        background: str,
+        color: str,
+    }
+}
+
+define_theme! {
+    %[component]
+    pub PdfView {
+        %[cows]
+        background: str,
+        page_background: str,
+        selection_background: str,
+        %[subthemes]
+        font_theme: FontTheme,
+    }
+}
+
+define_theme! {
+    %[component]
+    pub TreeView {
+        %[cows]
+        background: str,
+        hover_background: str,
+        selected_background: str,
+        guide_color: str,
+        arrow_fill: str,
+        loading_color: str,
+        %[subthemes]
+        font_theme: FontTheme,
+    }
+}
+
+define_theme! {
+    %[component]
+    pub Waveform {
+        %[cows]
+        background: str,
+        color: str,
+        playhead_color: str,
+        selection_background: str,
+    }
+}
+
+define_theme! {
+    %[component]
+    pub Gantt {
+        %[cows]
+        background: str,
+        row_background: str,
+        bar_background: str,
+        grid_color: str,
+        today_color: str,
+        %[subthemes]
+        font_theme: FontTheme,
+    }
+}
+
+define_theme! {
+    %[component]
+    pub Skeleton {
+        %[cows]
+        background: str,
+        highlight_background: str,
+    }
+}
+
+define_theme! {
+    %[component]
+    pub Calendar {
+        %[cows]
+        background: str,
+        border_fill: str,
+        focus_border_fill: str,
+        hover_background: str,
+        selected_background: str,
+        selected_color: str,
+        range_background: str,
+        today_border_fill: str,
+        disabled_color: str,
+        weekday_color: str,
+        arrow_fill: str,
+        %[subthemes]
+        font_theme: FontTheme,
+    }
+}
+
 #[derive(Clone, Debug, PartialEq, Eq)]
 pub struct ColorsSheet {
     pub primary_accent: Cow<'static, str>,
@@ -610,11 +790,25 @@ pub struct Theme {
     pub checkbox: CheckboxTheme,
     pub menu_item: MenuItemTheme,
     pub menu_container: MenuContainerTheme,
+    pub menu_bar: MenuBarTheme,
     pub snackbar: SnackBarTheme,
     pub popup: PopupTheme,
     pub tab: TabTheme,
     pub bottom_tab: BottomTabTheme,
     pub resizable_handle: ResizableHandleTheme,
+    pub diff_view: DiffViewTheme,
+    pub data_inspector: DataInspectorTheme,
+    pub hex_view: HexViewTheme,
+    pub before_after: BeforeAfterTheme,
+    pub drawing_pad: DrawingPadTheme,
+    pub map_view: MapViewTheme,
+    pub qr_code: QrCodeTheme,
+    pub pdf_view: PdfViewTheme,
+    pub tree_view: TreeViewTheme,
+    pub waveform: WaveformTheme,
+    pub gantt: GanttTheme,
+    pub calendar: CalendarTheme,
+    pub skeleton: SkeletonTheme,
 }
 
 impl Default for Theme {
@@ -36,6 +36,18 @@ impl AnimColor {
         }
     }
 
+    pub(crate) fn from_colors(origin: Color, destination: Color) -> Self {
+        Self {
+            origin,
+            destination,
+            time: Duration::default(),
+            ease: Ease::default(),
+            function: Function::default(),
+
+            value: origin,
+        }
+    }
+
     /// Set the animation duration using milliseconds. Use `Self::duration` if you want to specify the duration in another form.
     pub fn time(mut self, time: u64) -> Self {
         self.time = Duration::from_millis(time);
@@ -60,6 +72,10 @@ impl AnimColor {
         self
     }
 
+    pub(crate) fn value(&self) -> Color {
+        self.value
+    }
+
     /// Read the value of the [AnimColor] as a String.
     pub fn read(&self) -> String {
         format!(
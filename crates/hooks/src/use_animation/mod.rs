@@ -3,9 +3,11 @@ mod anim_num;
 mod anim_sequential;
 mod animated_value;
 mod hook;
+mod use_animated;
 
 pub use anim_color::*;
 pub use anim_num::*;
 pub use anim_sequential::*;
 pub use animated_value::*;
 pub use hook::*;
+pub use use_animated::*;
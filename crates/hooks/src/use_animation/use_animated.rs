@@ -0,0 +1,232 @@
+use std::time::Duration;
+
+use dioxus_hooks::{
+    use_effect,
+    use_reactive,
+    use_signal,
+};
+use dioxus_signals::{
+    Readable,
+    Writable,
+};
+use freya_core::parsing::Parse;
+use freya_engine::prelude::Color;
+
+use super::{
+    apply_value,
+    use_animation,
+    AnimColor,
+    AnimNum,
+    AnimatedValue,
+    Ease,
+    Function,
+};
+
+/// Duration and easing of the transitions made by [`use_animated`].
+#[derive(Default, Clone, Copy, PartialEq)]
+pub struct Transition {
+    time: Duration,
+    ease: Ease,
+    function: Function,
+}
+
+impl Transition {
+    /// Create a transition that lasts the given milliseconds.
+    pub fn new(time: u64) -> Self {
+        Self {
+            time: Duration::from_millis(time),
+            ..Default::default()
+        }
+    }
+
+    /// Set the transition duration.
+    pub fn duration(mut self, duration: Duration) -> Self {
+        self.time = duration;
+        self
+    }
+
+    /// Set the easing type. See `Ease` for all the types.
+    pub fn ease(mut self, ease: Ease) -> Self {
+        self.ease = ease;
+        self
+    }
+
+    /// Set the easing function. See `Function` for all the types.
+    pub fn function(mut self, function: Function) -> Self {
+        self.function = function;
+        self
+    }
+
+    /// Value between `origin` and `destination` once the given time of the transition has elapsed.
+    pub fn value_at(&self, origin: f32, destination: f32, elapsed: Duration) -> f32 {
+        apply_value(
+            origin,
+            destination,
+            elapsed.as_millis(),
+            self.time,
+            self.ease,
+            self.function,
+        )
+    }
+
+    /// Whether the transition is over once the given time has elapsed.
+    pub fn is_finished(&self, elapsed: Duration) -> bool {
+        elapsed >= self.time
+    }
+}
+
+/// Animated values that can move to a new destination starting from their current value.
+pub trait AnimatedTransition: AnimatedValue {
+    type Destination: PartialEq + Clone + 'static;
+
+    type Output;
+
+    /// Create the animated value resting at the given destination.
+    fn resting(destination: Self::Destination) -> Self;
+
+    /// Animate from the current value to a new destination.
+    fn retarget(&mut self, destination: Self::Destination, transition: Transition);
+
+    /// Read the current value.
+    fn output(&self) -> Self::Output;
+}
+
+impl AnimatedTransition for AnimNum {
+    type Destination = f32;
+
+    type Output = f32;
+
+    fn resting(destination: f32) -> Self {
+        AnimNum::new(destination, destination)
+    }
+
+    fn retarget(&mut self, destination: f32, transition: Transition) {
+        *self = AnimNum::new(self.read(), destination)
+            .duration(transition.time)
+            .ease(transition.ease)
+            .function(transition.function);
+    }
+
+    fn output(&self) -> f32 {
+        self.read()
+    }
+}
+
+impl AnimatedTransition for AnimColor {
+    type Destination = Color;
+
+    type Output = String;
+
+    fn resting(destination: Color) -> Self {
+        AnimColor::from_colors(destination, destination)
+    }
+
+    fn retarget(&mut self, destination: Color, transition: Transition) {
+        *self = AnimColor::from_colors(self.value(), destination)
+            .duration(transition.time)
+            .ease(transition.ease)
+            .function(transition.function);
+    }
+
+    fn output(&self) -> String {
+        self.read()
+    }
+}
+
+/// Values of attributes that can be animated with [`use_animated`].
+pub trait Transitionable {
+    type Animated: AnimatedTransition;
+
+    fn into_destination(self) -> <Self::Animated as AnimatedTransition>::Destination;
+}
+
+impl Transitionable for f32 {
+    type Animated = AnimNum;
+
+    fn into_destination(self) -> f32 {
+        self
+    }
+}
+
+impl Transitionable for Color {
+    type Animated = AnimColor;
+
+    fn into_destination(self) -> Color {
+        self
+    }
+}
+
+impl Transitionable for &str {
+    type Animated = AnimColor;
+
+    fn into_destination(self) -> Color {
+        Color::parse(self).unwrap_or(Color::TRANSPARENT)
+    }
+}
+
+impl Transitionable for String {
+    type Animated = AnimColor;
+
+    fn into_destination(self) -> Color {
+        self.as_str().into_destination()
+    }
+}
+
+/// Transition a value every time it changes, instead of wiring an animation with [`use_animation`](crate::use_animation) manually.
+///
+/// Numbers (e.g width, padding, opacity, rotation) are returned as `f32` and colors (e.g background, color) as a `String`,
+/// ready to be used in the attributes. The first value is used as it is.
+///
+/// # Example
+///
+/// ```rust,no_run
+/// # use freya::prelude::*;
+/// fn app() -> Element {
+///     let mut expanded = use_signal(|| false);
+///
+///     let (target_width, target_background) = if expanded() {
+///         (300., "rgb(40, 120, 250)")
+///     } else {
+///         (100., "rgb(200, 200, 200)")
+///     };
+///
+///     let width = use_animated(target_width, Transition::new(250).ease(Ease::InOut));
+///     let background = use_animated(target_background, Transition::new(250));
+///
+///     rsx!(rect {
+///         width: "{width}",
+///         height: "100",
+///         background: "{background}",
+///         onclick: move |_| expanded.toggle(),
+///     })
+/// }
+/// ```
+pub fn use_animated<T: Transitionable>(
+    value: T,
+    transition: Transition,
+) -> <T::Animated as AnimatedTransition>::Output {
+    let destination = value.into_destination();
+    let initial_destination = destination.clone();
+    let mut last_destination = use_signal(|| destination.clone());
+    let animation = use_animation(move |_conf| T::Animated::resting(initial_destination.clone()));
+
+    use_effect(use_reactive(
+        &(destination, transition),
+        move |(destination, transition)| {
+            if *last_destination.peek() == destination {
+                return;
+            }
+            last_destination.set(destination.clone());
+
+            animation
+                .context
+                .peek()
+                .value
+                .write_unchecked()
+                .retarget(destination, transition);
+            animation.start();
+        },
+    ));
+
+    animation.get().with(AnimatedTransition::output)
+}
@@ -0,0 +1,337 @@
+use std::{
+    cell::RefCell,
+    collections::HashMap,
+    path::PathBuf,
+    rc::Rc,
+    sync::Arc,
+};
+
+use bytes::Bytes;
+use dioxus_core::prelude::try_consume_context;
+use dioxus_hooks::{
+    use_context,
+    use_context_provider,
+};
+use dioxus_signals::{
+    Readable,
+    Signal,
+};
+
+use crate::UsePlatform;
+
+#[derive(Debug, Clone, PartialEq)]
+struct AssetEntry {
+    /// Content of the asset embedded in the binary, if any.
+    embedded: Option<&'static [u8]>,
+    /// Family under which the asset is registered, in the case of fonts.
+    font_family: Option<String>,
+}
+
+/// Declaration of the assets of an app, loaded with [`use_asset`].
+///
+/// Assets are read from the files inside the root directory, unless they are embedded in the binary.
+/// Use [`asset_manifest!`](crate::asset_manifest) to read them from disk in debug builds and embed them in release builds.
+///
+/// ```rust,no_run
+/// # use freya::prelude::*;
+/// let manifest = AssetManifest::new("assets")
+///     .with_asset("icons/save.svg")
+///     .with_font("Inter", "fonts/Inter.ttf");
+/// ```
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct AssetManifest {
+    root: PathBuf,
+    assets: HashMap<String, AssetEntry>,
+}
+
+impl AssetManifest {
+    /// Create a manifest whose assets are read from the given directory.
+    pub fn new(root: impl Into<PathBuf>) -> Self {
+        Self {
+            root: root.into(),
+            assets: HashMap::default(),
+        }
+    }
+
+    /// Declare an asset, read from its file relative to the root directory.
+    pub fn with_asset(mut self, path: impl Into<String>) -> Self {
+        self.assets.insert(
+            path.into(),
+            AssetEntry {
+                embedded: None,
+                font_family: None,
+            },
+        );
+        self
+    }
+
+    /// Declare an asset whose content is embedded in the binary.
+    pub fn with_embedded_asset(mut self, path: impl Into<String>, bytes: &'static [u8]) -> Self {
+        self.assets.insert(
+            path.into(),
+            AssetEntry {
+                embedded: Some(bytes),
+                font_family: None,
+            },
+        );
+        self
+    }
+
+    /// Declare a font, registered under the given family when the assets are initialized.
+    pub fn with_font(self, family: impl Into<String>, path: impl Into<String>) -> Self {
+        let path = path.into();
+        let mut manifest = self.with_asset(path.clone());
+        manifest.set_font_family(&path, family);
+        manifest
+    }
+
+    /// Declare a font embedded in the binary, registered under the given family when the assets are initialized.
+    pub fn with_embedded_font(
+        self,
+        family: impl Into<String>,
+        path: impl Into<String>,
+        bytes: &'static [u8],
+    ) -> Self {
+        let path = path.into();
+        let mut manifest = self.with_embedded_asset(path.clone(), bytes);
+        manifest.set_font_family(&path, family);
+        manifest
+    }
+
+    fn set_font_family(&mut self, path: &str, family: impl Into<String>) {
+        if let Some(entry) = self.assets.get_mut(path) {
+            entry.font_family = Some(family.into());
+        }
+    }
+
+    /// Whether the given asset is declared.
+    pub fn contains(&self, path: &str) -> bool {
+        self.assets.contains_key(path)
+    }
+
+    /// Path of the file of an asset, unless it's embedded.
+    pub fn file_path(&self, path: &str) -> Option<PathBuf> {
+        let entry = self.assets.get(path)?;
+        if entry.embedded.is_some() {
+            None
+        } else {
+            Some(self.root.join(path))
+        }
+    }
+
+    /// Read the content of an asset.
+    pub fn read(&self, path: &str) -> Option<Bytes> {
+        let Some(entry) = self.assets.get(path) else {
+            tracing::error!("Asset '{path}' is not declared in the asset manifest");
+            return None;
+        };
+
+        if let Some(bytes) = entry.embedded {
+            return Some(Bytes::from_static(bytes));
+        }
+
+        let file_path = self.root.join(path);
+        match std::fs::read(&file_path) {
+            Ok(bytes) => Some(Bytes::from(bytes)),
+            Err(err) => {
+                tracing::error!("Failed to read asset {file_path:?}: {err}");
+                None
+            }
+        }
+    }
+
+    /// Declared fonts, along with their family.
+    fn fonts(&self) -> impl Iterator<Item = (&str, &str)> {
+        self.assets.iter().filter_map(|(path, entry)| {
+            entry
+                .font_family
+                .as_deref()
+                .map(|family| (path.as_str(), family))
+        })
+    }
+}
+
+/// Declare an [`AssetManifest`] whose assets are read from disk in debug builds, so they can be hot reloaded,
+/// and embedded in the binary in release builds.
+///
+/// The root directory is relative to the directory of the crate manifest.
+///
+/// ```rust,no_run
+/// # use freya::prelude::*;
+/// fn app() -> Element {
+///     use_init_assets(|| {
+///         asset_manifest!("assets", ["icons/save.svg", "images/logo.png"], fonts: {
+///             "Inter" => "fonts/Inter.ttf",
+///         })
+///     });
+///
+///     rsx!(Body {})
+/// }
+/// # #[component]
+/// # fn Body() -> Element { VNode::empty() }
+/// ```
+#[macro_export]
+macro_rules! asset_manifest {
+    ($root:literal, [$($path:literal),* $(,)?] $(, fonts: { $($family:literal => $font_path:literal),* $(,)? })? $(,)?) => {{
+        #[cfg(debug_assertions)]
+        let manifest = ::freya_hooks::AssetManifest::new(concat!(env!("CARGO_MANIFEST_DIR"), "/", $root))
+            $(.with_asset($path))*
+            $($(.with_font($family, $font_path))*)?;
+
+        #[cfg(not(debug_assertions))]
+        let manifest = ::freya_hooks::AssetManifest::new(concat!(env!("CARGO_MANIFEST_DIR"), "/", $root))
+            $(.with_embedded_asset($path, include_bytes!(concat!(env!("CARGO_MANIFEST_DIR"), "/", $root, "/", $path))))*
+            $($(.with_embedded_font($family, $font_path, include_bytes!(concat!(env!("CARGO_MANIFEST_DIR"), "/", $root, "/", $font_path))))*)?;
+
+        manifest
+    }};
+}
+
+/// Assets declared in an [`AssetManifest`], cached once they are read.
+#[derive(Clone)]
+pub struct Assets {
+    manifest: Arc<AssetManifest>,
+    cache: Rc<RefCell<HashMap<String, Bytes>>>,
+    /// Increased every time an asset is reloaded.
+    revision: Signal<usize>,
+}
+
+impl Assets {
+    /// Get the content of an asset, subscribing the current component to its reloads.
+    pub fn get(&self, path: &str) -> Option<Bytes> {
+        self.revision.read();
+
+        if let Some(bytes) = self.cache.borrow().get(path) {
+            return Some(bytes.clone());
+        }
+
+        let bytes = self.manifest.read(path)?;
+        self.cache
+            .borrow_mut()
+            .insert(path.to_string(), bytes.clone());
+        Some(bytes)
+    }
+
+    /// Get the manifest of the assets.
+    pub fn manifest(&self) -> &AssetManifest {
+        &self.manifest
+    }
+
+    fn register_font(&self, path: &str, family: &str) {
+        if let Some(bytes) = self.get(path) {
+            UsePlatform::current().register_font(family, bytes.to_vec());
+        }
+    }
+
+    /// Read again the assets whose file changed since the last time they were read.
+    #[cfg(debug_assertions)]
+    fn watch(self) {
+        use std::time::{
+            Duration,
+            SystemTime,
+        };
+
+        use dioxus_core::prelude::spawn;
+        use dioxus_signals::Writable;
+
+        fn modified_times(manifest: &AssetManifest) -> HashMap<String, SystemTime> {
+            manifest
+                .assets
+                .keys()
+                .filter_map(|path| {
+                    let file_path = manifest.file_path(path)?;
+                    let modified = std::fs::metadata(file_path).ok()?.modified().ok()?;
+                    Some((path.clone(), modified))
+                })
+                .collect()
+        }
+
+        let mut revision = self.revision;
+        spawn(async move {
+            let mut last_modified_times = modified_times(&self.manifest);
+            loop {
+                tokio::time::sleep(Duration::from_millis(500)).await;
+
+                let modified_times = modified_times(&self.manifest);
+                let changed = modified_times
+                    .iter()
+                    .filter(|(path, modified)| last_modified_times.get(*path) != Some(modified))
+                    .map(|(path, _)| path.clone())
+                    .collect::<Vec<_>>();
+                last_modified_times = modified_times;
+
+                if changed.is_empty() {
+                    continue;
+                }
+
+                for path in &changed {
+                    tracing::info!("Reloading asset '{path}'");
+                    self.cache.borrow_mut().remove(path);
+                }
+
+                for (path, family) in self.manifest.fonts() {
+                    if changed.iter().any(|changed| changed == path) {
+                        self.register_font(path, family);
+                    }
+                }
+
+                *revision.write() += 1;
+            }
+        });
+    }
+}
+
+/// Initialize the assets of the app, usually in its root component.
+///
+/// The declared fonts are registered right away, and in debug builds the assets are read again when their files change.
+pub fn use_init_assets(manifest: impl FnOnce() -> AssetManifest) -> Assets {
+    use_context_provider(|| {
+        let assets = Assets {
+            manifest: Arc::new(manifest()),
+            cache: Rc::default(),
+            revision: Signal::new(0),
+        };
+
+        for (path, family) in assets.manifest.fonts() {
+            assets.register_font(path, family);
+        }
+
+        #[cfg(debug_assertions)]
+        assets.clone().watch();
+
+        assets
+    })
+}
+
+/// Get access to the assets initialized with [`use_init_assets`].
+pub fn use_assets() -> Assets {
+    use_context()
+}
+
+/// Get the content of an asset declared in the [`AssetManifest`] of the app, see [`use_init_assets`].
+///
+/// The content is cached, and in debug builds the component runs again when the file of the asset changes.
+///
+/// ```rust,no_run
+/// # use freya::prelude::*;
+/// #[component]
+/// fn SaveIcon() -> Element {
+///     let Some(icon) = use_asset("icons/save.svg") else {
+///         return VNode::empty();
+///     };
+///
+///     rsx!(svg {
+///         width: "24",
+///         height: "24",
+///         svg_data: dynamic_bytes(icon),
+///     })
+/// }
+/// ```
+pub fn use_asset(path: &str) -> Option<Bytes> {
+    let assets = try_consume_context::<Assets>();
+    let Some(assets) = assets else {
+        tracing::error!("Assets are not initialized, call `use_init_assets` in a parent component");
+        return None;
+    };
+    assets.get(path)
+}
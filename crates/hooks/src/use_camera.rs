@@ -1,11 +1,21 @@
-use std::sync::{
-    Arc,
-    Mutex,
+use std::{
+    sync::{
+        atomic::{
+            AtomicUsize,
+            Ordering,
+        },
+        Arc,
+        Mutex,
+    },
+    thread,
 };
 
 use bytes::Bytes;
 use dioxus_core::{
-    prelude::spawn,
+    prelude::{
+        spawn,
+        use_drop,
+    },
     use_hook,
     AttributeValue,
 };
@@ -28,15 +38,21 @@ use freya_core::custom_attributes::{
 };
 pub use nokhwa::utils::{
     CameraIndex,
+    CameraInfo,
     RequestedFormatType,
     Resolution,
 };
 use nokhwa::{
     pixel_format::RgbFormat,
-    utils::RequestedFormat,
+    query,
+    utils::{
+        ApiBackend,
+        RequestedFormat,
+    },
     Camera,
     NokhwaError,
 };
+use tokio::sync::mpsc::unbounded_channel;
 
 use crate::{
     use_node_with_reference,
@@ -88,6 +104,12 @@ pub struct UseCamera {
     image: Arc<Mutex<Option<Bytes>>>,
 }
 
+impl PartialEq for UseCamera {
+    fn eq(&self, other: &Self) -> bool {
+        self.error == other.error && Arc::ptr_eq(&self.image, &other.image)
+    }
+}
+
 impl UseCamera {
     /// Get a [AttributeValue] for the `reference` attribute.
     pub fn attribute(&self) -> AttributeValue {
@@ -107,15 +129,30 @@ impl UseCamera {
     pub fn error(&self) -> ReadOnlySignal<Option<NokhwaError>> {
         self.error.into()
     }
+
+    /// Get the last captured frame, as given by the camera (usually encoded as MJPEG).
+    pub fn snapshot(&self) -> Option<Bytes> {
+        self.image.lock().unwrap().clone()
+    }
+}
+
+/// Get the cameras available in the system, their index can be used in [CameraSettings::with_camera_index].
+///
+/// Requires the `use_camera` feature.
+pub fn available_cameras() -> Result<Vec<CameraInfo>, NokhwaError> {
+    query(ApiBackend::Auto)
 }
 
-/// Connect to a given camera and render its frames into an image element
+/// Connect to a given camera and render its frames into an image element.
+/// Frames are captured in a separate thread, which stops when the settings change or the component is dropped.
 ///
 /// Requires the `use_camera` feature.
 pub fn use_camera(camera_settings: CameraSettings) -> UseCamera {
     let platform = use_platform();
     let mut error = use_signal(|| None);
     let image = use_hook(|| Arc::new(Mutex::new(None)));
+    // Incremented to stop the current capture thread
+    let generation = use_hook(|| Arc::new(AtomicUsize::new(0)));
     let (node_reference, size) = use_node_with_reference();
 
     let camera = UseCamera {
@@ -124,45 +161,64 @@ pub fn use_camera(camera_settings: CameraSettings) -> UseCamera {
         node_reference,
     };
 
+    use_drop({
+        to_owned![generation];
+        move || {
+            generation.fetch_add(1, Ordering::Relaxed);
+        }
+    });
+
     use_effect(use_reactive!(|camera_settings| {
-        to_owned![image];
-        spawn(async move {
-            let requested = RequestedFormat::new::<RgbFormat>(camera_settings.camera_format);
-            let camera = Camera::new(camera_settings.camera_index, requested);
+        to_owned![image, generation];
+        let current_generation = generation.fetch_add(1, Ordering::Relaxed) + 1;
+        let (frames_sender, mut frames_receiver) = unbounded_channel();
+        error.set(None);
 
-            if let Ok(mut camera) = camera {
-                // Set the custom resolution if specified
-                if let Some(resolution) = camera_settings.resolution {
-                    if let Err(err) = camera.set_resolution(resolution) {
-                        error.set(Some(err));
-                    }
+        thread::spawn(move || {
+            let requested = RequestedFormat::new::<RgbFormat>(camera_settings.camera_format);
+            let mut camera = match Camera::new(camera_settings.camera_index, requested) {
+                Ok(camera) => camera,
+                Err(err) => {
+                    frames_sender.send(Err(err)).ok();
+                    return;
                 }
+            };
 
-                let mut ticker = platform.new_ticker();
-
-                loop {
-                    // Wait for the event loop to tick
-                    ticker.tick().await;
-
-                    // Capture the next frame
-                    let frame = camera.frame();
-
-                    if let Ok(frame) = frame {
-                        let new_frame = frame.buffer_bytes();
+            // Set the custom resolution if specified
+            if let Some(resolution) = camera_settings.resolution {
+                if let Err(err) = camera.set_resolution(resolution) {
+                    frames_sender.send(Err(err)).ok();
+                }
+            }
 
+            while generation.load(Ordering::Relaxed) == current_generation {
+                // Wait for the next frame
+                match camera.frame() {
+                    Ok(frame) => {
                         // Replace the old frame with the new
-                        image.lock().unwrap().replace(new_frame);
+                        image.lock().unwrap().replace(frame.buffer_bytes());
+                        if frames_sender.send(Ok(())).is_err() {
+                            break;
+                        }
+                    }
+                    Err(err) => {
+                        frames_sender.send(Err(err)).ok();
+                        break;
+                    }
+                }
+            }
+        });
 
+        spawn(async move {
+            while let Some(frame) = frames_receiver.recv().await {
+                match frame {
+                    Ok(()) => {
                         // Request a rerender
                         platform.invalidate_drawing_area(size.peek().area);
                         platform.request_animation_frame();
-                    } else if let Err(err) = frame {
-                        error.set(Some(err));
-                        break;
                     }
+                    Err(err) => error.set(Some(err)),
                 }
-            } else if let Err(err) = camera {
-                error.set(Some(err));
             }
         });
     }));
@@ -0,0 +1,95 @@
+use dioxus_core::prelude::use_hook;
+pub use freya_core::platform::{
+    ClipboardError,
+    ClipboardImage,
+};
+use freya_core::{
+    event_loop_messages::EventLoopMessage,
+    platform::ClipboardRequest,
+};
+use tokio::sync::oneshot;
+
+use crate::UsePlatform;
+
+/// Handle returned by [`use_clipboard`].
+#[derive(Clone, Copy, PartialEq)]
+pub struct UseClipboard {
+    platform: UsePlatform,
+}
+
+impl UseClipboard {
+    async fn request<T>(
+        &self,
+        request: impl FnOnce(oneshot::Sender<Result<T, ClipboardError>>) -> ClipboardRequest,
+    ) -> Result<T, ClipboardError> {
+        let (sender, receiver) = oneshot::channel();
+        self.platform
+            .send(EventLoopMessage::Clipboard(request(sender)))
+            .map_err(|_| ClipboardError::Unavailable("The event loop is closed".to_string()))?;
+        receiver
+            .await
+            .map_err(|_| ClipboardError::Unavailable("The request was dropped".to_string()))?
+    }
+
+    /// Read the text of the clipboard.
+    pub async fn get_text(&self) -> Result<String, ClipboardError> {
+        self.request(ClipboardRequest::GetText).await
+    }
+
+    /// Replace the content of the clipboard with the given text.
+    pub async fn set_text(&self, text: impl Into<String>) -> Result<(), ClipboardError> {
+        let text = text.into();
+        self.request(|sender| ClipboardRequest::SetText(text, sender))
+            .await
+    }
+
+    /// Read the image of the clipboard.
+    pub async fn get_image(&self) -> Result<ClipboardImage, ClipboardError> {
+        self.request(ClipboardRequest::GetImage).await
+    }
+
+    /// Replace the content of the clipboard with the given image.
+    pub async fn set_image(&self, image: ClipboardImage) -> Result<(), ClipboardError> {
+        self.request(|sender| ClipboardRequest::SetImage(image, sender))
+            .await
+    }
+}
+
+/// Read and write the system clipboard from anywhere in the app.
+///
+/// The clipboard is accessed from the event loop thread, so it's safe to use in any platform.
+///
+/// ```rust,no_run
+/// # use freya::prelude::*;
+/// fn app() -> Element {
+///     let clipboard = use_clipboard();
+///     let mut pasted = use_signal(String::new);
+///
+///     rsx!(
+///         Button {
+///             onpress: move |_| {
+///                 spawn(async move {
+///                     clipboard.set_text("Hello, World!").await.ok();
+///                 });
+///             },
+///             label { "Copy" }
+///         }
+///         Button {
+///             onpress: move |_| {
+///                 spawn(async move {
+///                     if let Ok(text) = clipboard.get_text().await {
+///                         pasted.set(text);
+///                     }
+///                 });
+///             },
+///             label { "Paste" }
+///         }
+///         label { "{pasted}" }
+///     )
+/// }
+/// ```
+pub fn use_clipboard() -> UseClipboard {
+    use_hook(|| UseClipboard {
+        platform: UsePlatform::current(),
+    })
+}
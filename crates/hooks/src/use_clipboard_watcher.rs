@@ -0,0 +1,117 @@
+use std::time::Duration;
+
+use dioxus_core::prelude::{
+    spawn,
+    use_hook,
+};
+use dioxus_signals::{
+    ReadOnlySignal,
+    Readable,
+    Signal,
+    Writable,
+};
+use freya_core::platform::{
+    ClipboardError,
+    ClipboardImage,
+};
+use tokio::time::sleep;
+
+use crate::{
+    use_clipboard,
+    UseClipboard,
+};
+
+/// Content of the system clipboard, see [`use_clipboard_watcher`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ClipboardContent {
+    Empty,
+    Text(String),
+    Image(ClipboardImage),
+}
+
+impl ClipboardContent {
+    /// The text of the clipboard, if it has text.
+    pub fn text(&self) -> Option<&str> {
+        match self {
+            Self::Text(text) => Some(text),
+            _ => None,
+        }
+    }
+
+    /// The text of the clipboard if it's a `http` or `https` link, e.g. to offer pasting it.
+    pub fn link(&self) -> Option<&str> {
+        self.text().map(str::trim).filter(|text| {
+            (text.starts_with("http://") || text.starts_with("https://"))
+                && !text.contains(char::is_whitespace)
+        })
+    }
+}
+
+/// Read the text of the clipboard, or its image if it has no text.
+async fn read_content(clipboard: UseClipboard) -> Result<ClipboardContent, ClipboardError> {
+    match clipboard.get_text().await {
+        Ok(text) => return Ok(ClipboardContent::Text(text)),
+        Err(ClipboardError::Empty) => {}
+        Err(err) => return Err(err),
+    }
+    match clipboard.get_image().await {
+        Ok(image) => Ok(ClipboardContent::Image(image)),
+        Err(ClipboardError::Empty) => Ok(ClipboardContent::Empty),
+        Err(err) => Err(err),
+    }
+}
+
+/// Watch the system clipboard, which is read every `interval`.
+///
+/// The content is `None` until it's read the first time. Changes made by other apps are detected too,
+/// so it can be used for clipboard managers or to offer pasting a copied link.
+///
+/// ```rust,no_run
+/// # use std::time::Duration;
+/// # use freya::prelude::*;
+/// fn app() -> Element {
+///     let content = use_clipboard_watcher(Duration::from_millis(500));
+///
+///     let link = content
+///         .read()
+///         .as_ref()
+///         .and_then(|content| content.link().map(str::to_string));
+///
+///     rsx!(if let Some(link) = link {
+///         label { "Paste {link}?" }
+///     })
+/// }
+/// ```
+pub fn use_clipboard_watcher(interval: Duration) -> ReadOnlySignal<Option<ClipboardContent>> {
+    use_clipboard_watcher_with_onchange(interval, |_| {})
+}
+
+/// Same as [`use_clipboard_watcher`], but also calls `onchange` with the new content every time it changes,
+/// except the first time it's read. The interval and `onchange` are only read the first time.
+pub fn use_clipboard_watcher_with_onchange(
+    interval: Duration,
+    mut onchange: impl FnMut(&ClipboardContent) + 'static,
+) -> ReadOnlySignal<Option<ClipboardContent>> {
+    let clipboard = use_clipboard();
+
+    use_hook(|| {
+        let mut content = Signal::new(None);
+
+        spawn(async move {
+            loop {
+                // Try again in the next interval if the clipboard is unavailable
+                if let Ok(new_content) = read_content(clipboard).await {
+                    if content.peek().as_ref() != Some(&new_content) {
+                        if content.peek().is_some() {
+                            onchange(&new_content);
+                        }
+                        content.set(Some(new_content));
+                    }
+                }
+                sleep(interval).await;
+            }
+        });
+
+        content.into()
+    })
+}
@@ -0,0 +1,117 @@
+use std::sync::Arc;
+
+use dioxus_core::{
+    prelude::spawn,
+    use_hook,
+    AttributeValue,
+};
+use dioxus_signals::{
+    ReadOnlySignal,
+    Readable,
+    Signal,
+    Writable,
+};
+use freya_core::{
+    custom_attributes::{
+        CustomAttributeValues,
+        StyleReference,
+    },
+    node::ComputedStyle,
+};
+use tokio::sync::watch::channel;
+
+/// Get a signal to read the latest computed style of an element, once its inherited
+/// and default values are resolved.
+///
+/// Pass the returned attribute to the `style_reference` attribute of the element.
+///
+/// ```rust,no_run
+/// # use freya::prelude::*;
+/// fn app() -> Element {
+///     let (style_reference, style) = use_computed_style();
+///     let icon = if style.read().is_backdrop_dark() {
+///         "light icon"
+///     } else {
+///         "dark icon"
+///     };
+///
+///     rsx!(
+///         rect {
+///             background: "rgb(20, 20, 20)",
+///             label {
+///                 style_reference,
+///                 "{icon}"
+///             }
+///         }
+///     )
+/// }
+/// ```
+pub fn use_computed_style() -> (AttributeValue, ReadOnlySignal<ComputedStyle>) {
+    let (tx, signal) = use_hook(|| {
+        let (tx, mut rx) = channel::<ComputedStyle>(ComputedStyle::default());
+        let mut signal = Signal::new(ComputedStyle::default());
+
+        spawn(async move {
+            while rx.changed().await.is_ok() {
+                if *signal.peek() != *rx.borrow() {
+                    signal.set(rx.borrow().clone());
+                }
+            }
+        });
+
+        (Arc::new(tx), signal)
+    });
+
+    (
+        AttributeValue::any_value(CustomAttributeValues::StyleReference(StyleReference(tx))),
+        signal.into(),
+    )
+}
+
+#[cfg(test)]
+mod test {
+    use freya::prelude::*;
+    use freya_testing::prelude::*;
+
+    use crate::use_computed_style;
+
+    #[tokio::test]
+    pub async fn computed_style() {
+        fn use_computed_style_app() -> Element {
+            let (style_reference, style) = use_computed_style();
+            let mut dark = use_signal(|| true);
+            let style = style.read();
+
+            rsx!(
+                rect {
+                    background: if dark() { "black" } else { "white" },
+                    color: "rgb(0, 0, 255)",
+                    font_size: "20",
+                    onclick: move |_| dark.toggle(),
+                    rect {
+                        background: "rgb(255, 255, 0, 0.5)",
+                        width: "100",
+                        height: "100",
+                        label {
+                            style_reference,
+                            "{style.font_style.font_size} {style.is_backdrop_dark()}"
+                        }
+                    }
+                }
+            )
+        }
+
+        let mut utils = launch_test(use_computed_style_app);
+        utils.wait_for_update().await;
+        utils.wait_for_update().await;
+        let label = utils.root().get(0).get(0).get(0);
+
+        // Inherited from the ancestors
+        assert_eq!(label.get(0).text(), Some("20 true"));
+
+        utils.click_cursor((5., 5.)).await;
+        utils.wait_for_update().await;
+        utils.wait_for_update().await;
+        assert_eq!(label.get(0).text(), Some("20 false"));
+    }
+}
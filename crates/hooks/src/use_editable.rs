@@ -1,11 +1,19 @@
-use std::rc::Rc;
+use std::{
+    cell::Cell,
+    rc::Rc,
+};
 
 use dioxus_clipboard::prelude::{
     use_clipboard,
     UseClipboard,
 };
 use dioxus_core::{
-    prelude::spawn,
+    prelude::{
+        provide_root_context,
+        spawn,
+        try_consume_context,
+        use_drop,
+    },
     use_hook,
     AttributeValue,
 };
@@ -38,6 +46,7 @@ use torin::geometry::CursorPoint;
 
 use crate::{
     use_platform,
+    EditorDirection,
     EditorHistory,
     RopeEditor,
     TextCursor,
@@ -145,14 +154,18 @@ impl UseEditable {
         mode: EditableMode,
     ) -> Self {
         let text_id = UseId::<UseEditable>::get_in_hook();
-        let mut editor = Signal::new(RopeEditor::new(
-            config.content,
-            config.cursor,
-            config.identation,
-            mode,
-            clipboard,
-            EditorHistory::new(),
-        ));
+        let mut editor = Signal::new(
+            RopeEditor::new(
+                config.content,
+                config.cursor,
+                config.identation,
+                mode,
+                clipboard,
+                EditorHistory::new(),
+            )
+            .with_text_direction(config.direction)
+            .with_sensitive(config.sensitive),
+        );
         let dragging = Signal::new(TextDragging::None);
         let (cursor_sender, mut cursor_receiver) = unbounded_channel::<CursorLayoutResponse>();
         let cursor_reference = CursorReference {
@@ -231,6 +244,20 @@ impl UseEditable {
         &mut self.editor
     }
 
+    /// Mark the content as sensitive or not once created, see [`EditableConfig::with_sensitive`].
+    pub fn set_sensitive(&mut self, sensitive: bool) {
+        if self.editor.peek().is_sensitive() == sensitive {
+            return;
+        }
+        let sensitive_editables = SensitiveEditables::current();
+        if sensitive {
+            sensitive_editables.acquire(self.platform);
+        } else {
+            sensitive_editables.release(self.platform);
+        }
+        self.editor.write().sensitive = sensitive;
+    }
+
     /// Create a cursor attribute.
     pub fn cursor_attr(&self) -> AttributeValue {
         AttributeValue::any_value(CustomAttributeValues::CursorReference(
@@ -363,6 +390,8 @@ pub struct EditableConfig {
     pub(crate) allow_tabs: bool,
     pub(crate) allow_changes: bool,
     pub(crate) allow_clipboard: bool,
+    pub(crate) direction: EditorDirection,
+    pub(crate) sensitive: bool,
 }
 
 impl EditableConfig {
@@ -375,6 +404,8 @@ impl EditableConfig {
             allow_tabs: false,
             allow_changes: true,
             allow_clipboard: true,
+            direction: EditorDirection::default(),
+            sensitive: false,
         }
     }
 
@@ -407,6 +438,21 @@ impl EditableConfig {
         self.allow_clipboard = allow_clipboard;
         self
     }
+
+    /// Mark the content as sensitive, e.g. a password. Sensitive content can't be copied or cut
+    /// and the window will be excluded from screen captures while the editable is alive, where the OS supports it.
+    ///
+    /// Use it together with the `sensitive` attribute to also mask the content in the accessibility tree.
+    pub fn with_sensitive(mut self, sensitive: bool) -> Self {
+        self.sensitive = sensitive;
+        self
+    }
+
+    /// Specify the base direction of the text, used for the visual cursor movement of the arrow keys
+    pub fn with_text_direction(mut self, direction: EditorDirection) -> Self {
+        self.direction = direction;
+        self
+    }
 }
 
 /// Hook to create an editable text.
@@ -422,5 +468,48 @@ pub fn use_editable(
     let platform = use_platform();
     let clipboard = use_clipboard();
 
-    use_hook(|| UseEditable::new_in_hook(clipboard, platform, initializer(), mode))
+    let editable = use_hook(|| {
+        let config = initializer();
+        if config.sensitive {
+            SensitiveEditables::current().acquire(platform);
+        }
+        UseEditable::new_in_hook(clipboard, platform, config, mode)
+    });
+
+    use_drop(move || {
+        if editable.editor.peek().is_sensitive() {
+            SensitiveEditables::current().release(platform);
+        }
+    });
+
+    editable
+}
+
+/// Amount of alive sensitive editables, the window content stays protected while there is any.
+#[derive(Clone, Default)]
+struct SensitiveEditables(Rc<Cell<usize>>);
+
+impl SensitiveEditables {
+    fn current() -> Self {
+        match try_consume_context() {
+            Some(editables) => editables,
+            None => provide_root_context(SensitiveEditables::default()),
+        }
+    }
+
+    fn acquire(&self, platform: UsePlatform) {
+        let count = self.0.get();
+        if count == 0 {
+            platform.set_content_protected(true);
+        }
+        self.0.set(count + 1);
+    }
+
+    fn release(&self, platform: UsePlatform) {
+        let count = self.0.get().saturating_sub(1);
+        if count == 0 {
+            platform.set_content_protected(false);
+        }
+        self.0.set(count);
+    }
 }
@@ -0,0 +1,151 @@
+use std::path::PathBuf;
+
+use dioxus_hooks::use_signal;
+use dioxus_signals::{
+    Readable,
+    Signal,
+    Writable,
+};
+
+/// Options of the file dialogs opened with [`use_file_dialog`].
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct FileDialogOptions {
+    title: Option<String>,
+    directory: Option<PathBuf>,
+    file_name: Option<String>,
+    filters: Vec<(String, Vec<String>)>,
+}
+
+impl FileDialogOptions {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Title of the dialog.
+    pub fn with_title(mut self, title: impl Into<String>) -> Self {
+        self.title = Some(title.into());
+        self
+    }
+
+    /// Directory shown when the dialog is opened.
+    pub fn with_directory(mut self, directory: impl Into<PathBuf>) -> Self {
+        self.directory = Some(directory.into());
+        self
+    }
+
+    /// Suggested name of the file, mostly useful when saving.
+    pub fn with_file_name(mut self, file_name: impl Into<String>) -> Self {
+        self.file_name = Some(file_name.into());
+        self
+    }
+
+    /// Only show the files with any of the given extensions, e.g. `("Images", &["png", "jpg"])`.
+    pub fn with_filter(mut self, name: impl Into<String>, extensions: &[&str]) -> Self {
+        self.filters.push((
+            name.into(),
+            extensions.iter().map(|ext| ext.to_string()).collect(),
+        ));
+        self
+    }
+
+    fn dialog(&self) -> rfd::AsyncFileDialog {
+        let mut dialog = rfd::AsyncFileDialog::new();
+        if let Some(title) = &self.title {
+            dialog = dialog.set_title(title);
+        }
+        if let Some(directory) = &self.directory {
+            dialog = dialog.set_directory(directory);
+        }
+        if let Some(file_name) = &self.file_name {
+            dialog = dialog.set_file_name(file_name);
+        }
+        for (name, extensions) in &self.filters {
+            dialog = dialog.add_filter(name, extensions);
+        }
+        dialog
+    }
+}
+
+/// Open native file dialogs without blocking the app, see [`use_file_dialog`].
+#[derive(Clone, Copy, PartialEq)]
+pub struct UseFileDialog {
+    is_open: Signal<bool>,
+}
+
+impl UseFileDialog {
+    /// Whether a dialog is currently open.
+    pub fn is_open(&self) -> bool {
+        *self.is_open.read()
+    }
+
+    /// Ask for a file to open, `None` if the dialog was cancelled.
+    pub async fn pick_file(&self, options: FileDialogOptions) -> Option<PathBuf> {
+        let file = self.open(options.dialog().pick_file()).await?;
+        Some(file.path().to_path_buf())
+    }
+
+    /// Ask for one or more files to open, `None` if the dialog was cancelled.
+    pub async fn pick_files(&self, options: FileDialogOptions) -> Option<Vec<PathBuf>> {
+        let files = self.open(options.dialog().pick_files()).await?;
+        Some(
+            files
+                .into_iter()
+                .map(|file| file.path().to_path_buf())
+                .collect(),
+        )
+    }
+
+    /// Ask for a folder, `None` if the dialog was cancelled.
+    pub async fn pick_folder(&self, options: FileDialogOptions) -> Option<PathBuf> {
+        let folder = self.open(options.dialog().pick_folder()).await?;
+        Some(folder.path().to_path_buf())
+    }
+
+    /// Ask where to save a file, `None` if the dialog was cancelled.
+    pub async fn save_file(&self, options: FileDialogOptions) -> Option<PathBuf> {
+        let file = self.open(options.dialog().save_file()).await?;
+        Some(file.path().to_path_buf())
+    }
+
+    async fn open<T>(&self, dialog: impl std::future::Future<Output = T>) -> T {
+        let mut is_open = self.is_open;
+        is_open.set(true);
+        let res = dialog.await;
+        is_open.set(false);
+        res
+    }
+}
+
+/// Open native dialogs to pick files and folders or to choose where to save a file.
+///
+/// The dialogs don't block the app, so it keeps rendering while they are open.
+/// Requires the `dialogs` feature.
+///
+/// ```rust,no_run
+/// # use freya::prelude::*;
+/// fn app() -> Element {
+///     let file_dialog = use_file_dialog();
+///     let mut path = use_signal(|| None);
+///
+///     let onpress = move |_| {
+///         spawn(async move {
+///             let options = FileDialogOptions::new().with_filter("Text", &["txt", "md"]);
+///             if let Some(picked) = file_dialog.pick_file(options).await {
+///                 path.set(Some(picked));
+///             }
+///         });
+///     };
+///
+///     rsx!(
+///         Button {
+///             onpress,
+///             label { "Open" }
+///         }
+///         label { "{path:?}" }
+///     )
+/// }
+/// ```
+pub fn use_file_dialog() -> UseFileDialog {
+    let is_open = use_signal(|| false);
+    UseFileDialog { is_open }
+}
@@ -52,6 +52,7 @@ pub struct UseFocus {
     platform: UsePlatform,
     focused_id: Signal<AccessibilityId>,
     focused_node: Signal<AccessibilityNode>,
+    focus_history: Signal<Vec<AccessibilityId>>,
 }
 
 impl UseFocus {
@@ -83,6 +84,13 @@ impl UseFocus {
         UsePlatform::current().focus(AccessibilityFocusStrategy::Node(id));
     }
 
+    /// Focus the most recently focused Node that still exists.
+    ///
+    /// Useful to give the focus back to the element that opened a popup or menu once it's closed.
+    pub fn focus_previous(&self) {
+        self.platform.focus(AccessibilityFocusStrategy::Previous);
+    }
+
     /// Get [AccessibilityId] of this accessibility node.
     pub fn id(&self) -> AccessibilityId {
         self.id
@@ -130,6 +138,11 @@ impl UseFocus {
     pub fn focused_node(&self) -> ReadOnlySignal<AccessibilityNode> {
         self.focused_node.into()
     }
+
+    /// Get a readable of the previously focused Node Ids that still exist, the most recent goes last.
+    pub fn focus_history(&self) -> ReadOnlySignal<Vec<AccessibilityId>> {
+        self.focus_history.into()
+    }
 }
 
 /// Create a focus manager for a node.
@@ -217,12 +230,41 @@ pub fn use_focus() -> UseFocus {
     use_focus_for_id(id)
 }
 
+/// Same as [use_focus] but the node gets focused once it's mounted,
+/// useful to decide which node receives the focus when a dialog or form is shown.
+///
+/// The focus is resolved after the node has been laid out, just like the `a11y_auto_focus` attribute.
+///
+/// ```rust
+/// # use freya::prelude::*;
+/// fn app() -> Element {
+///     let my_focus = use_init_focus();
+///
+///     rsx!(
+///         rect {
+///             a11y_id: my_focus.attribute(),
+///             label {
+///                 "Am I focused? {my_focus.is_focused()}"
+///             }
+///         }
+///     )
+/// }
+/// ```
+pub fn use_init_focus() -> UseFocus {
+    let mut focus = use_focus();
+
+    use_hook(move || focus.request_focus());
+
+    focus
+}
+
 /// Same as [use_focus] but providing a Node instead of generating a new one.
 ///
 /// This is an advance hook so you probably just want to use [use_focus].
 pub fn use_focus_for_id(id: AccessibilityId) -> UseFocus {
     let focused_id = use_context::<Signal<AccessibilityId>>();
     let focused_node = use_context::<Signal<AccessibilityNode>>();
+    let focus_history = use_context::<Signal<Vec<AccessibilityId>>>();
     let navigation_mode = use_context::<Signal<NavigationMode>>();
     let navigation_mark = use_context::<Signal<NavigationMark>>();
     let platform = use_platform();
@@ -241,5 +283,6 @@ pub fn use_focus_for_id(id: AccessibilityId) -> UseFocus {
         platform,
         focused_id,
         focused_node,
+        focus_history,
     })
 }
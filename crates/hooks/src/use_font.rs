@@ -0,0 +1,71 @@
+use std::{
+    borrow::Cow,
+    path::PathBuf,
+};
+
+use dioxus_core::use_hook;
+
+use crate::UsePlatform;
+
+/// Where to load a font from.
+#[derive(Debug, Clone, PartialEq)]
+pub enum FontSource {
+    /// Font data, e.g. embedded with `include_bytes!`.
+    Bytes(Cow<'static, [u8]>),
+    /// Path to a font file.
+    File(PathBuf),
+}
+
+impl From<&'static [u8]> for FontSource {
+    fn from(bytes: &'static [u8]) -> Self {
+        Self::Bytes(Cow::Borrowed(bytes))
+    }
+}
+
+impl From<Vec<u8>> for FontSource {
+    fn from(bytes: Vec<u8>) -> Self {
+        Self::Bytes(Cow::Owned(bytes))
+    }
+}
+
+impl From<PathBuf> for FontSource {
+    fn from(path: PathBuf) -> Self {
+        Self::File(path)
+    }
+}
+
+/// Register a font at runtime so it can be used with the `font_family` attribute.
+/// The font is only registered once, when the component is first run.
+///
+/// Families in `font_family` are tried in order, so you can specify a fallback chain
+/// in case the font is not loaded yet or doesn't contain a certain glyph.
+///
+/// ```rust,no_run
+/// # use freya::prelude::*;
+/// # static SANSITA_SWASHED: &[u8] = &[];
+/// fn app() -> Element {
+///     use_font("Sansita Swashed", || SANSITA_SWASHED.into());
+///
+///     rsx!(
+///         label {
+///             font_family: "Sansita Swashed, Inter",
+///             "Hello, World!"
+///         }
+///     )
+/// }
+/// ```
+pub fn use_font(font_name: &str, source: impl FnOnce() -> FontSource) {
+    use_hook(|| {
+        let font_data = match source() {
+            FontSource::Bytes(bytes) => bytes.into_owned(),
+            FontSource::File(path) => match std::fs::read(&path) {
+                Ok(bytes) => bytes,
+                Err(err) => {
+                    tracing::error!("Failed to read font file {path:?}: {err}");
+                    return;
+                }
+            },
+        };
+        UsePlatform::current().register_font(font_name, font_data);
+    });
+}
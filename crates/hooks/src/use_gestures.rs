@@ -0,0 +1,231 @@
+use std::time::Duration;
+
+use dioxus_core::{
+    prelude::{
+        spawn,
+        use_hook,
+    },
+    Task,
+};
+use dioxus_signals::{
+    CopyValue,
+    Readable,
+    Writable,
+};
+use freya_elements::events::{
+    TouchData,
+    TouchPhase,
+};
+use torin::geometry::CursorPoint;
+
+/// How long a finger must stay pressed to trigger a [`TouchGesture::LongPress`].
+const LONG_PRESS_DURATION: Duration = Duration::from_millis(500);
+
+/// How far a finger can move without cancelling a [`TouchGesture::LongPress`].
+const LONG_PRESS_TOLERANCE: f64 = 10.0;
+
+/// Gesture recognized by [`use_gestures`].
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum TouchGesture {
+    /// Two fingers moved closer or further apart.
+    Pinch {
+        /// Distance between the fingers relative to when the pinch started, `2.0` means twice as far apart.
+        scale: f64,
+        /// Point between the two fingers.
+        center: CursorPoint,
+    },
+    /// Two fingers moved together.
+    Pan {
+        /// Displacement of the point between the two fingers since the last [`TouchGesture::Pan`].
+        delta: CursorPoint,
+    },
+    /// A single finger was kept pressed without moving.
+    LongPress {
+        /// Position of the finger.
+        position: CursorPoint,
+    },
+}
+
+#[derive(Default)]
+struct GesturesState {
+    /// Fingers touching the screen and their last position.
+    touches: Vec<(u64, CursorPoint)>,
+    /// Distance between the two fingers when the pinch started.
+    pinch_distance: Option<f64>,
+    /// Point between the two fingers in the last event.
+    last_center: Option<CursorPoint>,
+    /// Where the finger of a pending long press started.
+    long_press_origin: Option<CursorPoint>,
+}
+
+impl GesturesState {
+    fn two_fingers(&self) -> Option<(CursorPoint, CursorPoint)> {
+        match self.touches.as_slice() {
+            [(_, a), (_, b)] => Some((*a, *b)),
+            _ => None,
+        }
+    }
+}
+
+/// Touch gestures recognizer created with [`use_gestures`].
+#[derive(Clone, Copy, PartialEq)]
+pub struct UseGestures {
+    state: CopyValue<GesturesState>,
+    long_press: CopyValue<Option<Task>>,
+    ongesture: CopyValue<Box<dyn FnMut(TouchGesture)>>,
+}
+
+impl UseGestures {
+    /// Amount of fingers touching the element.
+    pub fn touches(&self) -> usize {
+        self.state.read().touches.len()
+    }
+
+    fn emit(&mut self, gesture: TouchGesture) {
+        let mut ongesture = self.ongesture.write();
+        ongesture(gesture);
+    }
+
+    fn cancel_long_press(&mut self) {
+        self.state.write().long_press_origin = None;
+        if let Some(task) = self.long_press.write().take() {
+            task.cancel();
+        }
+    }
+
+    fn start_long_press(&mut self, position: CursorPoint) {
+        self.state.write().long_press_origin = Some(position);
+        let mut gestures = *self;
+        let task = spawn(async move {
+            tokio::time::sleep(LONG_PRESS_DURATION).await;
+            gestures.long_press.set(None);
+            let origin = gestures.state.write().long_press_origin.take();
+            if let Some(origin) = origin {
+                let position = gestures
+                    .state
+                    .read()
+                    .touches
+                    .first()
+                    .map_or(origin, |(_, position)| *position);
+                gestures.emit(TouchGesture::LongPress { position });
+            }
+        });
+        self.long_press.set(Some(task));
+    }
+
+    /// Process a touch event, pass it every `ontouchstart`, `ontouchmove`, `ontouchend` and `ontouchcancel` event of the element.
+    pub fn process_touch(&mut self, data: &TouchData) {
+        let finger_id = data.get_finger_id();
+        let position = data.get_screen_coordinates();
+
+        match data.get_touch_phase() {
+            TouchPhase::Started => {
+                self.cancel_long_press();
+                let mut state = self.state.write();
+                state.touches.retain(|(id, _)| *id != finger_id);
+                state.touches.push((finger_id, position));
+                let touches = state.touches.len();
+                let fingers = state.two_fingers();
+                state.pinch_distance = fingers.map(|(a, b)| a.distance_to(b));
+                state.last_center = fingers.map(|(a, b)| a.lerp(b, 0.5));
+                drop(state);
+
+                if touches == 1 {
+                    self.start_long_press(position);
+                }
+            }
+            TouchPhase::Moved => {
+                let mut state = self.state.write();
+                let Some(touch) = state.touches.iter_mut().find(|(id, _)| *id == finger_id) else {
+                    return;
+                };
+                touch.1 = position;
+
+                let moved_away = state
+                    .long_press_origin
+                    .is_some_and(|origin| origin.distance_to(position) > LONG_PRESS_TOLERANCE);
+
+                let mut gestures = Vec::new();
+                if let Some((a, b)) = state.two_fingers() {
+                    let center = a.lerp(b, 0.5);
+                    if let Some(pinch_distance) = state.pinch_distance.filter(|d| *d > 0.) {
+                        let scale = a.distance_to(b) / pinch_distance;
+                        gestures.push(TouchGesture::Pinch { scale, center });
+                    }
+                    if let Some(last_center) = state.last_center {
+                        let delta = (center - last_center).to_point();
+                        if delta != CursorPoint::zero() {
+                            gestures.push(TouchGesture::Pan { delta });
+                        }
+                    }
+                    state.last_center = Some(center);
+                }
+                drop(state);
+
+                if moved_away {
+                    self.cancel_long_press();
+                }
+                for gesture in gestures {
+                    self.emit(gesture);
+                }
+            }
+            TouchPhase::Ended | TouchPhase::Cancelled => {
+                self.cancel_long_press();
+                let mut state = self.state.write();
+                state.touches.retain(|(id, _)| *id != finger_id);
+                // Gestures start over with the remaining fingers
+                let fingers = state.two_fingers();
+                state.pinch_distance = fingers.map(|(a, b)| a.distance_to(b));
+                state.last_center = fingers.map(|(a, b)| a.lerp(b, 0.5));
+            }
+        }
+    }
+}
+
+/// Recognize pinch-to-zoom, two-finger pan and long-press gestures from the touch events of an element.
+/// `ongesture` is only read the first time.
+///
+/// ```rust,no_run
+/// # use freya::prelude::*;
+/// fn app() -> Element {
+///     let mut zoom = use_signal(|| 1.0);
+///     let mut offset = use_signal(|| (0.0, 0.0));
+///     let mut zoom_start = use_signal(|| 1.0);
+///     let mut gestures = use_gestures(move |gesture| match gesture {
+///         TouchGesture::Pinch { scale, .. } => zoom.set(zoom_start() * scale),
+///         TouchGesture::Pan { delta } => {
+///             let (x, y) = offset();
+///             offset.set((x + delta.x, y + delta.y));
+///         }
+///         TouchGesture::LongPress { .. } => zoom.set(1.0),
+///     });
+///
+///     let ontouch = move |e: TouchEvent| {
+///         if e.get_touch_phase() == TouchPhase::Started {
+///             zoom_start.set(zoom());
+///         }
+///         gestures.process_touch(&e.data);
+///     };
+///
+///     rsx!(
+///         rect {
+///             width: "fill",
+///             height: "fill",
+///             ontouchstart: ontouch,
+///             ontouchmove: ontouch,
+///             ontouchend: ontouch,
+///             ontouchcancel: ontouch,
+///             label {
+///                 "Zoom {zoom}, offset {offset:?}"
+///             }
+///         }
+///     )
+/// }
+/// ```
+pub fn use_gestures(ongesture: impl FnMut(TouchGesture) + 'static) -> UseGestures {
+    use_hook(|| UseGestures {
+        state: CopyValue::new(GesturesState::default()),
+        long_press: CopyValue::new(None),
+        ongesture: CopyValue::new(Box::new(ongesture)),
+    })
+}
@@ -0,0 +1,85 @@
+use dioxus_core::prelude::{
+    spawn,
+    try_consume_context,
+    use_drop,
+    use_hook,
+};
+use freya_core::events::ShortcutRegistry;
+pub use freya_core::events::{
+    Shortcut,
+    ShortcutError,
+};
+
+/// Registration of a shortcut, see [`use_global_shortcut`].
+#[derive(Clone, PartialEq)]
+pub struct UseGlobalShortcut {
+    error: Option<ShortcutError>,
+}
+
+impl UseGlobalShortcut {
+    /// Why the shortcut could not be registered, e.g. it's invalid, already registered
+    /// or there is no shortcut registry.
+    pub fn error(&self) -> Option<&ShortcutError> {
+        self.error.as_ref()
+    }
+
+    /// Whether the shortcut was registered.
+    pub fn is_registered(&self) -> bool {
+        self.error.is_none()
+    }
+}
+
+/// Call `handler` every time the given shortcut is pressed, regardless of the focused element.
+/// The key combination is not emitted to the elements.
+///
+/// The shortcut and the handler are only read the first time, and the shortcut is unregistered
+/// when the component is dropped. Registering a shortcut that is already registered fails,
+/// see [`UseGlobalShortcut::error`].
+///
+/// ```rust,no_run
+/// # use freya::prelude::*;
+/// fn app() -> Element {
+///     let mut show_palette = use_signal(|| false);
+///     let shortcut = use_global_shortcut("cmdorctrl+k", move || show_palette.toggle());
+///
+///     rsx!(
+///         if let Some(err) = shortcut.error() {
+///             label { "{err}" }
+///         }
+///         if show_palette() {
+///             label { "Command palette" }
+///         }
+///     )
+/// }
+/// ```
+pub fn use_global_shortcut(
+    shortcut: &str,
+    mut handler: impl FnMut() + 'static,
+) -> UseGlobalShortcut {
+    let registration = use_hook(|| -> Result<_, ShortcutError> {
+        let registry =
+            try_consume_context::<ShortcutRegistry>().ok_or(ShortcutError::Unavailable)?;
+        let (id, mut receiver) = registry.register(shortcut.parse()?)?;
+
+        spawn(async move {
+            while receiver.recv().await.is_some() {
+                handler();
+            }
+        });
+
+        Ok((registry, id))
+    });
+
+    use_drop({
+        let registration = registration.clone();
+        move || {
+            if let Ok((registry, id)) = registration {
+                registry.unregister(id);
+            }
+        }
+    });
+
+    UseGlobalShortcut {
+        error: registration.err(),
+    }
+}
@@ -0,0 +1,96 @@
+use std::time::Duration;
+
+use dioxus_core::prelude::{
+    spawn,
+    try_consume_context,
+    use_hook,
+};
+use dioxus_hooks::use_signal;
+use dioxus_signals::{
+    ReadOnlySignal,
+    Writable,
+};
+use freya_core::types::UserActivityReceiver;
+
+/// Check whether the user has been inactive, without any input event, for the given time.
+///
+/// Useful for auto-lock screens, presence indicators or pausing expensive animations.
+/// The timeout is only read the first time.
+///
+/// ```rust,no_run
+/// # use freya::prelude::*;
+/// # use std::time::Duration;
+/// fn app() -> Element {
+///     let is_idle = use_idle(Duration::from_secs(60));
+///
+///     rsx!(
+///         label {
+///             if is_idle() { "Away" } else { "Online" }
+///         }
+///     )
+/// }
+/// ```
+pub fn use_idle(timeout: Duration) -> ReadOnlySignal<bool> {
+    use_idle_with_callback(timeout, |_| {})
+}
+
+/// Same as [`use_idle`], but also calls `on_resume` with how long the user was inactive
+/// every time the activity resumes after being idle.
+///
+/// ```rust,no_run
+/// # use freya::prelude::*;
+/// # use std::time::Duration;
+/// fn app() -> Element {
+///     let mut locked = use_signal(|| false);
+///     let is_idle = use_idle_with_callback(Duration::from_secs(300), move |inactive| {
+///         if inactive > Duration::from_secs(600) {
+///             locked.set(true);
+///         }
+///     });
+///
+///     rsx!(
+///         label {
+///             "Idle: {is_idle}, locked: {locked}"
+///         }
+///     )
+/// }
+/// ```
+pub fn use_idle_with_callback(
+    timeout: Duration,
+    mut on_resume: impl FnMut(Duration) + 'static,
+) -> ReadOnlySignal<bool> {
+    let mut is_idle = use_signal(|| false);
+
+    use_hook(move || {
+        let Some(mut activity) = try_consume_context::<UserActivityReceiver>() else {
+            return;
+        };
+
+        spawn(async move {
+            loop {
+                let last_activity = *activity.borrow_and_update();
+
+                // Wait until the timeout passes since the last input
+                tokio::time::sleep_until((last_activity + timeout).into()).await;
+                match activity.has_changed() {
+                    Ok(true) => continue,
+                    Ok(false) => {}
+                    Err(_) => break,
+                }
+
+                is_idle.set(true);
+
+                // Wait until the user is active again
+                if activity.changed().await.is_err() {
+                    break;
+                }
+
+                let resumed_activity = *activity.borrow_and_update();
+                is_idle.set(false);
+                on_resume(resumed_activity.duration_since(last_activity));
+            }
+        });
+    });
+
+    is_idle.into()
+}
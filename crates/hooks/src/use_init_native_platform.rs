@@ -14,7 +14,10 @@ use dioxus_signals::{
 };
 use freya_core::types::NativePlatformReceiver;
 
-use crate::use_init_asset_cacher;
+use crate::{
+    use_init_asset_cacher,
+    TextScaleFactor,
+};
 
 #[derive(Clone)]
 pub struct NavigationMark(bool);
@@ -50,8 +53,11 @@ pub fn use_init_native_platform() -> UsePlatformEvents {
         let mut preferred_theme = Signal::new(platform_state.preferred_theme);
         let mut focused_id = Signal::new(platform_state.focused_accessibility_id);
         let mut focused_node = Signal::new(platform_state.focused_accessibility_node.clone());
+        let mut focus_history = Signal::new(platform_state.focus_history.clone());
         let mut navigation_mode = Signal::new(platform_state.navigation_mode);
         let mut information = Signal::new(platform_state.information);
+        let mut text_scale_factor = Signal::new(TextScaleFactor(platform_state.text_scale_factor));
+        let mut layout_direction = Signal::new(platform_state.layout_direction);
 
         drop(platform_state);
 
@@ -67,6 +73,10 @@ pub fn use_init_native_platform() -> UsePlatformEvents {
                     *focused_node.write() = state.focused_accessibility_node.clone();
                 }
 
+                if *focus_history.peek() != state.focus_history {
+                    *focus_history.write() = state.focus_history.clone();
+                }
+
                 if *preferred_theme.peek() != state.preferred_theme {
                     *preferred_theme.write() = state.preferred_theme;
                 }
@@ -78,14 +88,25 @@ pub fn use_init_native_platform() -> UsePlatformEvents {
                 if *information.peek() != state.information {
                     *information.write() = state.information;
                 }
+
+                if text_scale_factor.peek().0 != state.text_scale_factor {
+                    *text_scale_factor.write() = TextScaleFactor(state.text_scale_factor);
+                }
+
+                if *layout_direction.peek() != state.layout_direction {
+                    *layout_direction.write() = state.layout_direction;
+                }
             }
         });
 
         provide_context(preferred_theme);
         provide_context(navigation_mode);
         provide_context(information);
+        provide_context(text_scale_factor);
+        provide_context(layout_direction);
         provide_context(focused_id);
         provide_context(focused_node);
+        provide_context(focus_history);
     });
 
     UsePlatformEvents { navigation_mark }
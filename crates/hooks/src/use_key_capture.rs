@@ -0,0 +1,107 @@
+use dioxus_core::prelude::{
+    spawn,
+    try_consume_context,
+    use_drop,
+};
+use dioxus_hooks::use_signal;
+use dioxus_signals::{
+    Readable,
+    Signal,
+    Writable,
+};
+use freya_core::events::{
+    KeyCapture,
+    KeyCombination,
+};
+
+/// Capture the next key combination pressed by the user, see [`use_key_capture`].
+#[derive(Clone, Copy, PartialEq)]
+pub struct UseKeyCapture {
+    capture: Signal<Option<KeyCapture>>,
+    is_capturing: Signal<bool>,
+}
+
+impl UseKeyCapture {
+    /// Call `on_capture` with the next key combination pressed by the user,
+    /// instead of emitting it to the elements. Cancels the capture in progress, if any.
+    pub fn capture(&mut self, mut on_capture: impl FnMut(KeyCombination) + 'static) {
+        let Some(capture) = &*self.capture.peek() else {
+            return;
+        };
+        let receiver = capture.start();
+        self.is_capturing.set(true);
+
+        let mut is_capturing = self.is_capturing;
+        let capture = capture.clone();
+        spawn(async move {
+            if let Ok(combination) = receiver.await {
+                on_capture(combination);
+            }
+            // A newer capture might have replaced this one
+            if !capture.is_capturing() {
+                is_capturing.set(false);
+            }
+        });
+    }
+
+    /// Stop capturing, the key combinations are emitted to the elements again.
+    pub fn cancel(&mut self) {
+        if let Some(capture) = &*self.capture.peek() {
+            capture.cancel();
+        }
+        self.is_capturing.set(false);
+    }
+
+    /// Whether the keys are being captured.
+    pub fn is_capturing(&self) -> bool {
+        *self.is_capturing.read()
+    }
+}
+
+/// Capture the next key combination pressed by the user, bypassing the keyboard events of the elements
+/// and the built-in shortcuts. Useful to record new shortcuts in a settings screen.
+///
+/// ```rust,no_run
+/// # use freya::prelude::*;
+/// fn app() -> Element {
+///     let mut key_capture = use_key_capture();
+///     let mut shortcut = use_signal(|| "Ctrl+S".to_string());
+///
+///     let onpress = move |_| {
+///         key_capture.capture(move |combination| {
+///             shortcut.set(format!("{:?}+{}", combination.modifiers, combination.key));
+///         });
+///     };
+///
+///     rsx!(
+///         Button {
+///             onpress,
+///             label {
+///                 if key_capture.is_capturing() {
+///                     "Press the new shortcut..."
+///                 } else {
+///                     "Save: {shortcut}"
+///                 }
+///             }
+///         }
+///     )
+/// }
+/// ```
+pub fn use_key_capture() -> UseKeyCapture {
+    let capture = use_signal(|| try_consume_context::<KeyCapture>());
+    let is_capturing = use_signal(|| false);
+
+    // Stop capturing if the component is dropped while capturing
+    use_drop(move || {
+        if *is_capturing.peek() {
+            if let Some(capture) = &*capture.peek() {
+                capture.cancel();
+            }
+        }
+    });
+
+    UseKeyCapture {
+        capture,
+        is_capturing,
+    }
+}
@@ -0,0 +1,33 @@
+use dioxus_hooks::use_context;
+use dioxus_signals::{
+    ReadOnlySignal,
+    Signal,
+};
+use freya_core::platform_state::LayoutDirection;
+
+/// Access the horizontal direction of the layout.
+/// It can be changed with [`crate::UsePlatform::set_layout_direction`].
+///
+/// ```rust,no_run
+/// # use freya::prelude::*;
+/// fn app() -> Element {
+///     let platform = use_platform();
+///     let layout_direction = use_layout_direction();
+///
+///     rsx!(
+///         Button {
+///             onpress: move |_| {
+///                 if layout_direction().is_rtl() {
+///                     platform.set_layout_direction(LayoutDirection::Ltr)
+///                 } else {
+///                     platform.set_layout_direction(LayoutDirection::Rtl)
+///                 }
+///             },
+///             label { "Toggle direction" }
+///         }
+///     )
+/// }
+/// ```
+pub fn use_layout_direction() -> ReadOnlySignal<LayoutDirection> {
+    use_context::<Signal<LayoutDirection>>().into()
+}
@@ -0,0 +1,74 @@
+use dioxus_core::prelude::{
+    spawn,
+    try_consume_context,
+    use_hook,
+};
+pub use freya_core::platform::muda;
+use freya_core::{
+    platform::muda::MenuEvent,
+    types::MenuEventsSender,
+};
+use tokio::sync::broadcast::error::RecvError;
+
+/// Listen for the items selected in the native menus, like the menu bar set with `LaunchConfig::with_menu`.
+/// Compare the id of the event with the ids of the menu items to know which one was selected.
+///
+/// Requires the `menu` feature.
+///
+/// ```rust,no_run
+/// # use freya::prelude::*;
+/// fn app() -> Element {
+///     let mut count = use_signal(|| 0);
+///
+///     use_menu_event(move |event| {
+///         if event.id == "increase" {
+///             count += 1;
+///         }
+///     });
+///
+///     rsx!(
+///         label { "{count}" }
+///     )
+/// }
+///
+/// fn main() {
+///     use muda::{
+///         accelerator::Accelerator,
+///         Menu,
+///         MenuItem,
+///         Submenu,
+///     };
+///
+///     let menu = Menu::new();
+///     let counter = Submenu::new("Counter", true);
+///     counter
+///         .append(&MenuItem::with_id(
+///             "increase",
+///             "Increase",
+///             true,
+///             "CmdOrCtrl+I".parse::<Accelerator>().ok(),
+///         ))
+///         .unwrap();
+///     menu.append(&counter).unwrap();
+///
+///     launch_cfg(app, LaunchConfig::<()>::new().with_menu(menu));
+/// }
+/// ```
+pub fn use_menu_event(mut handler: impl FnMut(MenuEvent) + 'static) {
+    use_hook(move || {
+        let Some(sender) = try_consume_context::<MenuEventsSender>() else {
+            return;
+        };
+        let mut receiver = sender.subscribe();
+
+        spawn(async move {
+            loop {
+                match receiver.recv().await {
+                    Ok(event) => handler(event),
+                    Err(RecvError::Lagged(_)) => continue,
+                    Err(RecvError::Closed) => break,
+                }
+            }
+        });
+    });
+}
@@ -15,13 +15,25 @@ use dioxus_signals::{
 };
 use freya_core::{
     accessibility::AccessibilityFocusStrategy,
-    event_loop_messages::EventLoopMessage,
+    event_loop_messages::{
+        EventLoopMessage,
+        NewWindow,
+    },
     platform::{
         CursorIcon,
         EventLoopProxy,
         Fullscreen,
+        ResizeDirection,
         Window,
+        WindowId,
     },
+    platform_state::LayoutDirection,
+};
+#[cfg(feature = "dialogs")]
+pub use rfd::{
+    MessageButtons,
+    MessageDialogResult,
+    MessageLevel,
 };
 use tokio::sync::{
     broadcast,
@@ -34,6 +46,7 @@ pub struct UsePlatform {
     ticker: Signal<Arc<broadcast::Receiver<()>>>,
     event_loop_proxy: Signal<Option<EventLoopProxy<EventLoopMessage>>>,
     platform_emitter: Signal<Option<UnboundedSender<EventLoopMessage>>>,
+    window_id: Signal<Option<WindowId>>,
 }
 
 #[derive(PartialEq, Eq, Debug)]
@@ -59,11 +72,17 @@ impl UsePlatform {
                     consume_context::<Arc<broadcast::Receiver<()>>>(),
                     ScopeId::ROOT,
                 ),
+                window_id: Signal::new_in_scope(try_consume_context::<WindowId>(), ScopeId::ROOT),
             }),
         }
     }
 
     pub fn send(&self, event: EventLoopMessage) -> Result<(), UsePlatformError> {
+        // Target the window in which this app runs
+        let event = match *self.window_id.peek() {
+            Some(window_id) => EventLoopMessage::ForWindow(window_id, Box::new(event)),
+            None => event,
+        };
         if let Some(event_loop_proxy) = &*self.event_loop_proxy.peek() {
             event_loop_proxy
                 .send_event(event)
@@ -76,6 +95,23 @@ impl UsePlatform {
         Ok(())
     }
 
+    /// Id of the window in which this app runs.
+    pub fn window_id(&self) -> Option<WindowId> {
+        *self.window_id.peek()
+    }
+
+    /// Open a new window with its own VirtualDOM.
+    pub fn open_window(&self, new_window: NewWindow) {
+        self.send(EventLoopMessage::OpenWindow(new_window)).ok();
+    }
+
+    /// Close the window in which this app runs, closing the main window exits the app.
+    pub fn close_window(&self) {
+        if let Some(window_id) = self.window_id() {
+            self.send(EventLoopMessage::CloseWindow(window_id)).ok();
+        }
+    }
+
     pub fn set_cursor(&self, cursor_icon: CursorIcon) {
         self.send(EventLoopMessage::SetCursorIcon(cursor_icon)).ok();
     }
@@ -97,6 +133,15 @@ impl UsePlatform {
         });
     }
 
+    /// Start resizing the window from the given border, as if the user dragged the native resize border.
+    ///
+    /// Useful for frameless windows, see `LaunchConfig::with_decorations`.
+    pub fn drag_resize_window(&self, direction: ResizeDirection) {
+        self.with_window(move |window| {
+            window.drag_resize_window(direction).ok();
+        });
+    }
+
     pub fn set_maximize_window(&self, maximize: bool) {
         self.with_window(move |window| {
             window.set_maximized(maximize);
@@ -138,6 +183,38 @@ impl UsePlatform {
         });
     }
 
+    /// Exclude the window content from screenshots and screen recordings.
+    ///
+    /// Only supported on Windows and macOS, it does nothing on other platforms.
+    pub fn set_content_protected(&self, protected: bool) {
+        self.with_window(move |window| {
+            window.set_content_protected(protected);
+        });
+    }
+
+    /// Register a font at runtime, any text using it will be laid out again.
+    pub fn register_font(&self, font_name: impl Into<String>, font_data: impl Into<Vec<u8>>) {
+        self.send(EventLoopMessage::RegisterFont(
+            font_name.into(),
+            font_data.into(),
+        ))
+        .ok();
+    }
+
+    /// Change the scale factor applied to the font size of all the text, any text will be laid out again.
+    ///
+    /// This is independent of the Window scale factor, so only text gets bigger or smaller.
+    pub fn set_text_scale_factor(&self, text_scale_factor: f32) {
+        self.send(EventLoopMessage::SetTextScaleFactor(text_scale_factor))
+            .ok();
+    }
+
+    /// Change the horizontal direction of the layout, [`LayoutDirection::Rtl`] mirrors it for right-to-left locales.
+    pub fn set_layout_direction(&self, layout_direction: LayoutDirection) {
+        self.send(EventLoopMessage::SetLayoutDirection(layout_direction))
+            .ok();
+    }
+
     pub fn invalidate_drawing_area(&self, area: Area) {
         self.send(EventLoopMessage::InvalidateArea(area)).ok();
     }
@@ -146,6 +223,13 @@ impl UsePlatform {
         self.send(EventLoopMessage::RequestRerender).ok();
     }
 
+    /// Request a new frame that is rendered even if nothing changed, paced with the FPS cap of the app.
+    ///
+    /// Useful for apps that animate continuously, call it again on every frame to keep rendering.
+    pub fn request_redraw(&self) {
+        self.send(EventLoopMessage::RequestRedraw).ok();
+    }
+
     pub fn focus(&self, strategy: AccessibilityFocusStrategy) {
         self.send(EventLoopMessage::FocusAccessibilityNode(strategy))
             .ok();
@@ -157,6 +241,107 @@ impl UsePlatform {
         }
     }
 
+    /// Show a native message dialog with a single button and wait until it's closed.
+    ///
+    /// The dialog doesn't block the app, so it keeps rendering in the meantime.
+    #[cfg(feature = "dialogs")]
+    pub async fn alert(
+        &self,
+        title: impl Into<String>,
+        body: impl Into<String>,
+        level: MessageLevel,
+    ) {
+        rfd::AsyncMessageDialog::new()
+            .set_title(title)
+            .set_description(body)
+            .set_level(level)
+            .set_buttons(MessageButtons::Ok)
+            .show()
+            .await;
+    }
+
+    /// Show a native dialog asking for confirmation and wait for the chosen button.
+    ///
+    /// The dialog doesn't block the app, so it keeps rendering in the meantime.
+    ///
+    /// ```rust,no_run
+    /// # use freya::prelude::*;
+    /// fn app() -> Element {
+    ///     let platform = use_platform();
+    ///
+    ///     let onpress = move |_| {
+    ///         spawn(async move {
+    ///             let res = platform
+    ///                 .confirm("Exit", "Are you sure?", MessageButtons::YesNo)
+    ///                 .await;
+    ///             if res == MessageDialogResult::Yes {
+    ///                 platform.exit();
+    ///             }
+    ///         });
+    ///     };
+    ///
+    ///     rsx!(
+    ///         Button {
+    ///             onpress,
+    ///             label { "Exit" }
+    ///         }
+    ///     )
+    /// }
+    /// ```
+    #[cfg(feature = "dialogs")]
+    pub async fn confirm(
+        &self,
+        title: impl Into<String>,
+        body: impl Into<String>,
+        buttons: MessageButtons,
+    ) -> MessageDialogResult {
+        rfd::AsyncMessageDialog::new()
+            .set_title(title)
+            .set_description(body)
+            .set_level(MessageLevel::Info)
+            .set_buttons(buttons)
+            .show()
+            .await
+    }
+
+    /// Read the color of any pixel of the screen, even outside the app window.
+    ///
+    /// The position is in physical pixels relative to the whole screen, like the position of the window.
+    /// On macOS the user is asked for the screen recording permission the first time.
+    ///
+    /// ```rust,no_run
+    /// # use freya::prelude::*;
+    /// fn app() -> Element {
+    ///     let platform = use_platform();
+    ///     let mut color = use_signal(|| "none".to_string());
+    ///
+    ///     let onpress = move |_| {
+    ///         spawn(async move {
+    ///             if let Ok(picked) = platform.sample_screen_color(100, 100).await {
+    ///                 color.set(format!("{picked:?}"));
+    ///             }
+    ///         });
+    ///     };
+    ///
+    ///     rsx!(
+    ///         Button {
+    ///             onpress,
+    ///             label { "Picked: {color}" }
+    ///         }
+    ///     )
+    /// }
+    /// ```
+    #[cfg(feature = "eyedropper")]
+    pub async fn sample_screen_color(
+        &self,
+        x: i32,
+        y: i32,
+    ) -> Result<freya_engine::prelude::Color, crate::ScreenColorError> {
+        tokio::task::spawn_blocking(move || crate::screen_color::sample_screen_color(x, y))
+            .await
+            .map_err(|err| crate::ScreenColorError::CaptureFailed(err.to_string()))?
+    }
+
     /// Closes the whole app.
     pub fn exit(&self) {
         self.send(EventLoopMessage::ExitApp).ok();
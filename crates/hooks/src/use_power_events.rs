@@ -0,0 +1,51 @@
+use dioxus_core::prelude::{
+    spawn,
+    try_consume_context,
+    use_hook,
+};
+use freya_core::{
+    platform_state::PowerEvent,
+    types::PowerEventsSender,
+};
+use tokio::sync::broadcast::error::RecvError;
+
+/// Listen for the system going to sleep and waking up, and for the display of the window turning off and on.
+///
+/// Useful to pause timers while asleep, or to reconnect sockets after waking up.
+/// Waking up from sleep is detected in all desktop platforms, being suspended is only notified in some of them.
+///
+/// ```rust,no_run
+/// # use freya::prelude::*;
+/// fn app() -> Element {
+///     let mut paused = use_signal(|| false);
+///
+///     use_power_events(move |event| match event {
+///         PowerEvent::Suspended | PowerEvent::DisplayOff => paused.set(true),
+///         PowerEvent::Resumed { .. } | PowerEvent::DisplayOn => paused.set(false),
+///     });
+///
+///     rsx!(
+///         label {
+///             if paused() { "Paused" } else { "Running" }
+///         }
+///     )
+/// }
+/// ```
+pub fn use_power_events(mut handler: impl FnMut(PowerEvent) + 'static) {
+    use_hook(move || {
+        let Some(sender) = try_consume_context::<PowerEventsSender>() else {
+            return;
+        };
+        let mut receiver = sender.subscribe();
+
+        spawn(async move {
+            loop {
+                match receiver.recv().await {
+                    Ok(event) => handler(event),
+                    Err(RecvError::Lagged(_)) => continue,
+                    Err(RecvError::Closed) => break,
+                }
+            }
+        });
+    });
+}
@@ -0,0 +1,179 @@
+use std::{
+    any::Any,
+    cell::RefCell,
+    collections::HashMap,
+    panic::Location,
+    rc::Rc,
+    sync::{
+        Arc,
+        Mutex,
+    },
+    time::{
+        Duration,
+        Instant,
+    },
+};
+
+use dioxus_core::prelude::{
+    current_scope_id,
+    try_consume_context,
+    use_after_render,
+    use_drop,
+    use_hook,
+    ScopeId,
+};
+
+/// Render metrics of a component, see [`use_render_info`].
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct RenderInfo {
+    /// How many times the component has rendered.
+    pub renders: usize,
+    /// How long the last render took, measured since [`use_render_info`] was called.
+    pub last_render_duration: Duration,
+    /// Tracked dependencies that changed in the last render, see [`UseRenderInfo::track`].
+    pub changed_dependencies: Vec<&'static str>,
+}
+
+/// Render metrics of every component using [`use_render_info`] under its provider.
+///
+/// Each instance of a component is tracked on its own, along with the location of the hook,
+/// and it's forgotten once the instance is dropped.
+///
+/// Provide it as a context to collect the metrics in a single place, e.g. the devtools.
+#[derive(Clone, Default)]
+pub struct RenderMetrics(Arc<Mutex<HashMap<ScopeId, (&'static Location<'static>, RenderInfo)>>>);
+
+impl RenderMetrics {
+    /// Get the render metrics of all the component instances, sorted by the number of renders.
+    pub fn snapshot(&self) -> Vec<(String, RenderInfo)> {
+        let mut metrics = self
+            .0
+            .lock()
+            .unwrap()
+            .iter()
+            .map(|(scope_id, (location, info))| {
+                (format!("{location} ({scope_id:?})"), info.clone())
+            })
+            .collect::<Vec<_>>();
+        metrics.sort_by(|(_, a), (_, b)| b.renders.cmp(&a.renders));
+        metrics
+    }
+
+    /// Forget the render metrics collected so far.
+    pub fn clear(&self) {
+        self.0.lock().unwrap().clear();
+    }
+
+    fn report(&self, scope_id: ScopeId, location: &'static Location<'static>, info: RenderInfo) {
+        self.0.lock().unwrap().insert(scope_id, (location, info));
+    }
+
+    fn remove(&self, scope_id: ScopeId) {
+        self.0.lock().unwrap().remove(&scope_id);
+    }
+}
+
+struct RenderInfoState {
+    info: RenderInfo,
+    render_started: Instant,
+    dependencies: HashMap<&'static str, Box<dyn Any>>,
+    changed_dependencies: Vec<&'static str>,
+}
+
+/// Handle returned by [`use_render_info`].
+#[derive(Clone)]
+pub struct UseRenderInfo {
+    state: Rc<RefCell<RenderInfoState>>,
+}
+
+impl UseRenderInfo {
+    /// Get the render metrics as of the last finished render.
+    pub fn get(&self) -> RenderInfo {
+        self.state.borrow().info.clone()
+    }
+
+    /// Track a value the component depends on, it will be listed in [`RenderInfo::changed_dependencies`]
+    /// when it changes between two renders.
+    pub fn track<T: PartialEq + Clone + 'static>(&self, name: &'static str, value: &T) {
+        let mut state = self.state.borrow_mut();
+        let previous = state.dependencies.get(name);
+        let changed = previous.is_some_and(|previous| {
+            previous
+                .downcast_ref::<T>()
+                .map_or(true, |previous| previous != value)
+        });
+        if changed {
+            state.changed_dependencies.push(name);
+        }
+        state.dependencies.insert(name, Box::new(value.clone()));
+    }
+}
+
+/// Measure how many times a component renders, how long it took the last time and why it happened.
+///
+/// Call it at the top of the component so the render duration covers its whole body.
+/// The metrics are also reported to the [`RenderMetrics`] context if there is one, which is the case
+/// when running with the devtools.
+///
+/// ```rust,no_run
+/// # use freya::prelude::*;
+/// fn app() -> Element {
+///     let render_info = use_render_info();
+///     let mut count = use_signal(|| 0);
+///     render_info.track("count", &count());
+///
+///     let RenderInfo { renders, changed_dependencies, .. } = render_info.get();
+///
+///     rsx!(
+///         label {
+///             onclick: move |_| count += 1,
+///             "Rendered {renders} times, changed: {changed_dependencies:?}"
+///         }
+///     )
+/// }
+/// ```
+#[track_caller]
+pub fn use_render_info() -> UseRenderInfo {
+    let location = Location::caller();
+    let render_info = use_hook(|| UseRenderInfo {
+        state: Rc::new(RefCell::new(RenderInfoState {
+            info: RenderInfo::default(),
+            render_started: Instant::now(),
+            dependencies: HashMap::default(),
+            changed_dependencies: Vec::default(),
+        })),
+    });
+    let metrics = use_hook(try_consume_context::<RenderMetrics>);
+    let scope_id = current_scope_id().unwrap();
+
+    {
+        let mut state = render_info.state.borrow_mut();
+        state.render_started = Instant::now();
+        state.changed_dependencies.clear();
+    }
+
+    use_drop({
+        let metrics = metrics.clone();
+        move || {
+            if let Some(metrics) = metrics {
+                metrics.remove(scope_id);
+            }
+        }
+    });
+
+    use_after_render({
+        let state = render_info.state.clone();
+        move || {
+            let mut state = state.borrow_mut();
+            state.info.renders += 1;
+            state.info.last_render_duration = state.render_started.elapsed();
+            state.info.changed_dependencies = state.changed_dependencies.clone();
+
+            if let Some(metrics) = &metrics {
+                metrics.report(scope_id, location, state.info.clone());
+            }
+        }
+    });
+
+    render_info
+}
@@ -0,0 +1,302 @@
+use std::{
+    fmt::Display,
+    io::Cursor,
+    sync::{
+        atomic::{
+            AtomicUsize,
+            Ordering,
+        },
+        Arc,
+        Mutex,
+    },
+    thread,
+    time::{
+        Duration,
+        Instant,
+    },
+};
+
+use bytes::Bytes;
+use dioxus_core::{
+    prelude::{
+        spawn,
+        use_drop,
+    },
+    use_hook,
+    AttributeValue,
+};
+use dioxus_hooks::{
+    to_owned,
+    use_effect,
+    use_reactive,
+    use_signal,
+};
+use dioxus_signals::{
+    ReadOnlySignal,
+    Readable,
+    Signal,
+    Writable,
+};
+use freya_core::custom_attributes::{
+    CustomAttributeValues,
+    ImageReference,
+    NodeReference,
+};
+use tokio::sync::mpsc::unbounded_channel;
+use xcap::{
+    image::{
+        ImageFormat,
+        RgbaImage,
+    },
+    Monitor,
+    Window,
+};
+
+use crate::{
+    use_node_with_reference,
+    use_platform,
+};
+
+/// Errors when capturing the screen.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ScreenCaptureError {
+    /// The monitor or window no longer exists.
+    SourceNotFound,
+    /// The source could not be captured, usually because the permission was denied.
+    CaptureFailed(String),
+}
+
+impl Display for ScreenCaptureError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::SourceNotFound => f.write_str("The capture source no longer exists"),
+            Self::CaptureFailed(err) => write!(f, "Failed to capture the screen: {err}"),
+        }
+    }
+}
+
+impl std::error::Error for ScreenCaptureError {}
+
+/// Monitor or window to capture, identified by the ID given by the system.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum CaptureSource {
+    Monitor(u32),
+    Window(u32),
+}
+
+/// Monitor or window that can be captured, see [`capture_sources`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CaptureSourceInfo {
+    pub source: CaptureSource,
+    /// Name of the monitor, or title of the window.
+    pub name: String,
+    /// Name of the app of the window, empty for monitors.
+    pub app_name: String,
+    pub width: u32,
+    pub height: u32,
+}
+
+impl Display for CaptureSourceInfo {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        if self.app_name.is_empty() {
+            write!(f, "{}", self.name)
+        } else {
+            write!(f, "{} - {}", self.app_name, self.name)
+        }
+    }
+}
+
+/// Get the monitors and visible windows that can be captured, monitors first.
+///
+/// Requires the `use_screen_capture` feature.
+pub fn capture_sources() -> Result<Vec<CaptureSourceInfo>, ScreenCaptureError> {
+    let capture_failed = |err: xcap::XCapError| ScreenCaptureError::CaptureFailed(err.to_string());
+
+    let monitors = Monitor::all().map_err(capture_failed)?;
+    let windows = Window::all().map_err(capture_failed)?;
+
+    let monitors = monitors.iter().map(|monitor| CaptureSourceInfo {
+        source: CaptureSource::Monitor(monitor.id()),
+        name: monitor.name().to_string(),
+        app_name: String::new(),
+        width: monitor.width(),
+        height: monitor.height(),
+    });
+    let windows = windows
+        .iter()
+        .filter(|window| !window.is_minimized())
+        .map(|window| CaptureSourceInfo {
+            source: CaptureSource::Window(window.id()),
+            name: window.title().to_string(),
+            app_name: window.app_name().to_string(),
+            width: window.width(),
+            height: window.height(),
+        });
+
+    Ok(monitors.chain(windows).collect())
+}
+
+/// Capture a frame of the given source.
+fn capture_frame(source: CaptureSource) -> Result<RgbaImage, ScreenCaptureError> {
+    let capture_failed = |err: xcap::XCapError| ScreenCaptureError::CaptureFailed(err.to_string());
+
+    match source {
+        CaptureSource::Monitor(id) => Monitor::all()
+            .map_err(capture_failed)?
+            .into_iter()
+            .find(|monitor| monitor.id() == id)
+            .ok_or(ScreenCaptureError::SourceNotFound)?
+            .capture_image()
+            .map_err(capture_failed),
+        CaptureSource::Window(id) => Window::all()
+            .map_err(capture_failed)?
+            .into_iter()
+            .find(|window| window.id() == id)
+            .ok_or(ScreenCaptureError::SourceNotFound)?
+            .capture_image()
+            .map_err(capture_failed),
+    }
+}
+
+/// Configuration for a screen capture
+#[derive(Clone, PartialEq, Debug)]
+pub struct ScreenCaptureSettings {
+    source: CaptureSource,
+    frame_rate: u32,
+}
+
+impl ScreenCaptureSettings {
+    /// Capture the given source, at `10` frames per second by default.
+    pub fn new(source: CaptureSource) -> Self {
+        Self {
+            source,
+            frame_rate: 10,
+        }
+    }
+
+    /// Specify the frames captured per second
+    pub fn with_frame_rate(mut self, frame_rate: u32) -> Self {
+        self.frame_rate = frame_rate.max(1);
+        self
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct UseScreenCapture {
+    error: Signal<Option<ScreenCaptureError>>,
+    node_reference: NodeReference,
+    image: Arc<Mutex<Option<Bytes>>>,
+}
+
+impl PartialEq for UseScreenCapture {
+    fn eq(&self, other: &Self) -> bool {
+        self.error == other.error && Arc::ptr_eq(&self.image, &other.image)
+    }
+}
+
+impl UseScreenCapture {
+    /// Get a [AttributeValue] for the `reference` attribute.
+    pub fn attribute(&self) -> AttributeValue {
+        AttributeValue::any_value(CustomAttributeValues::Reference(
+            self.node_reference.clone(),
+        ))
+    }
+
+    /// Get a [AttributeValue] for the `image_reference` attribute.
+    pub fn image_attribute(&self) -> AttributeValue {
+        AttributeValue::any_value(CustomAttributeValues::ImageReference(ImageReference(
+            self.image.clone(),
+        )))
+    }
+
+    /// Get a [ReadOnlySignal] of the error.
+    pub fn error(&self) -> ReadOnlySignal<Option<ScreenCaptureError>> {
+        self.error.into()
+    }
+
+    /// Get the last captured frame, encoded as BMP.
+    pub fn snapshot(&self) -> Option<Bytes> {
+        self.image.lock().unwrap().clone()
+    }
+}
+
+/// Capture a monitor or window and render its frames into an image element.
+/// Frames are captured in a separate thread, which stops when the settings change or the component is dropped.
+///
+/// Requires the `use_screen_capture` feature.
+pub fn use_screen_capture(settings: ScreenCaptureSettings) -> UseScreenCapture {
+    let platform = use_platform();
+    let mut error = use_signal(|| None);
+    let image = use_hook(|| Arc::new(Mutex::new(None)));
+    // Incremented to stop the current capture thread
+    let generation = use_hook(|| Arc::new(AtomicUsize::new(0)));
+    let (node_reference, size) = use_node_with_reference();
+
+    let capture = UseScreenCapture {
+        error,
+        image: image.clone(),
+        node_reference,
+    };
+
+    use_drop({
+        to_owned![generation];
+        move || {
+            generation.fetch_add(1, Ordering::Relaxed);
+        }
+    });
+
+    use_effect(use_reactive!(|settings| {
+        to_owned![image, generation];
+        let current_generation = generation.fetch_add(1, Ordering::Relaxed) + 1;
+        let (frames_sender, mut frames_receiver) = unbounded_channel();
+        error.set(None);
+
+        thread::spawn(move || {
+            let frame_duration = Duration::from_secs(1) / settings.frame_rate;
+
+            while generation.load(Ordering::Relaxed) == current_generation {
+                let started = Instant::now();
+
+                let frame = capture_frame(settings.source).and_then(|frame| {
+                    // BMP is the cheapest format to encode that the image element can decode
+                    let mut bytes = Cursor::new(Vec::new());
+                    frame
+                        .write_to(&mut bytes, ImageFormat::Bmp)
+                        .map_err(|err| ScreenCaptureError::CaptureFailed(err.to_string()))?;
+                    Ok(bytes.into_inner())
+                });
+
+                match frame {
+                    Ok(frame) => {
+                        // Replace the old frame with the new
+                        image.lock().unwrap().replace(Bytes::from(frame));
+                        if frames_sender.send(Ok(())).is_err() {
+                            break;
+                        }
+                    }
+                    Err(err) => {
+                        frames_sender.send(Err(err)).ok();
+                        break;
+                    }
+                }
+
+                thread::sleep(frame_duration.saturating_sub(started.elapsed()));
+            }
+        });
+
+        spawn(async move {
+            while let Some(frame) = frames_receiver.recv().await {
+                match frame {
+                    Ok(()) => {
+                        // Request a rerender
+                        platform.invalidate_drawing_area(size.peek().area);
+                        platform.request_animation_frame();
+                    }
+                    Err(err) => error.set(Some(err)),
+                }
+            }
+        });
+    }));
+
+    capture
+}
@@ -0,0 +1,259 @@
+use dioxus_core::prelude::{
+    try_consume_context,
+    use_hook,
+};
+use dioxus_signals::{
+    CopyValue,
+    ReadOnlySignal,
+    Readable,
+    Signal,
+    Writable,
+};
+use freya_core::types::ModifiersReceiver;
+use freya_elements::events::{
+    Key,
+    KeyboardData,
+    Modifiers,
+};
+
+/// How many items can be selected with [`use_selection`].
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum SelectionMode {
+    /// Only one item can be selected at a time.
+    Single,
+    /// Multiple items can be selected with `Ctrl` (or `Cmd` in macOS) and `Shift`.
+    #[default]
+    Multiple,
+}
+
+/// Selection of items created with [`use_selection`].
+pub struct UseSelection<T: 'static> {
+    selected: Signal<Vec<T>>,
+    /// Item from which the ranges are selected.
+    anchor: Signal<Option<T>>,
+    /// Item moved with the keyboard.
+    active: Signal<Option<T>>,
+    mode: SelectionMode,
+    modifiers: CopyValue<Option<ModifiersReceiver>>,
+    onchange: CopyValue<Box<dyn FnMut(&[T])>>,
+}
+
+impl<T> Copy for UseSelection<T> {}
+
+impl<T> Clone for UseSelection<T> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<T> PartialEq for UseSelection<T> {
+    fn eq(&self, other: &Self) -> bool {
+        self.selected == other.selected
+    }
+}
+
+impl<T: Clone + PartialEq + 'static> UseSelection<T> {
+    /// The selected items, in the order they were selected.
+    pub fn selected(&self) -> ReadOnlySignal<Vec<T>> {
+        self.selected.into()
+    }
+
+    /// Check whether the given item is selected.
+    pub fn is_selected(&self, item: &T) -> bool {
+        self.selected.read().contains(item)
+    }
+
+    /// Check whether the given item is the one moved with the keyboard.
+    pub fn is_active(&self, item: &T) -> bool {
+        self.active.read().as_ref() == Some(item)
+    }
+
+    /// The item moved with the keyboard, which is the last one clicked.
+    pub fn active(&self) -> Option<T> {
+        self.active.read().clone()
+    }
+
+    fn set_selected(&mut self, selected: Vec<T>) {
+        if *self.selected.peek() != selected {
+            self.selected.set(selected);
+            let selected = self.selected.peek();
+            let mut onchange = self.onchange.write();
+            onchange(&selected);
+        }
+    }
+
+    /// Select only the given item.
+    pub fn select(&mut self, item: T) {
+        self.anchor.set(Some(item.clone()));
+        self.active.set(Some(item.clone()));
+        self.set_selected(vec![item]);
+    }
+
+    /// Select or unselect the given item, keeping the rest of the selection in [`SelectionMode::Multiple`].
+    pub fn toggle(&mut self, item: T) {
+        let mut selected = self.selected.peek().clone();
+        if let Some(index) = selected.iter().position(|selected| *selected == item) {
+            selected.remove(index);
+        } else if self.mode == SelectionMode::Multiple {
+            selected.push(item.clone());
+        } else {
+            selected = vec![item.clone()];
+        }
+        self.anchor.set(Some(item.clone()));
+        self.active.set(Some(item));
+        self.set_selected(selected);
+    }
+
+    /// Select all the items between the last selected one and the given one,
+    /// `items` are all the items in the order they are rendered.
+    pub fn select_range(&mut self, item: T, items: &[T]) {
+        if self.mode == SelectionMode::Single {
+            return self.select(item);
+        }
+        let anchor = self.anchor.peek().clone().unwrap_or_else(|| item.clone());
+        let start = items.iter().position(|i| *i == anchor);
+        let end = items.iter().position(|i| *i == item);
+        let (Some(start), Some(end)) = (start, end) else {
+            return self.select(item);
+        };
+
+        self.active.set(Some(item));
+        self.set_selected(items[start.min(end)..=start.max(end)].to_vec());
+    }
+
+    /// Select all the given items in [`SelectionMode::Multiple`].
+    pub fn select_all(&mut self, items: &[T]) {
+        if self.mode == SelectionMode::Multiple {
+            self.set_selected(items.to_vec());
+        }
+    }
+
+    /// Unselect all the items.
+    pub fn clear(&mut self) {
+        self.anchor.set(None);
+        self.set_selected(Vec::new());
+    }
+
+    /// Select the clicked item, `Ctrl` (or `Cmd` in macOS) toggles it and `Shift` selects a range
+    /// in [`SelectionMode::Multiple`]. `items` are all the items in the order they are rendered.
+    pub fn click(&mut self, item: T, items: &[T]) {
+        let modifiers = self
+            .modifiers
+            .read()
+            .as_ref()
+            .map(|modifiers| *modifiers.borrow())
+            .unwrap_or_default();
+
+        if modifiers.contains(Modifiers::SHIFT) {
+            self.select_range(item, items);
+        } else if modifiers.intersects(Modifiers::CONTROL | Modifiers::META) {
+            self.toggle(item);
+        } else {
+            self.select(item);
+        }
+    }
+
+    /// Move the selection with the keyboard, returns whether the key was used.
+    ///
+    /// - `ArrowUp` / `ArrowDown` / `Home` / `End` move the selection, extending it with `Shift`.
+    /// - `Space` toggles the active item.
+    /// - `Ctrl + A` (or `Cmd + A` in macOS) selects all the items.
+    /// - `Escape` clears the selection.
+    pub fn keydown(&mut self, data: &KeyboardData, items: &[T]) -> bool {
+        let active = self.active.peek().clone();
+        let active_index = active
+            .as_ref()
+            .and_then(|active| items.iter().position(|item| item == active));
+        let is_control_pressed = data
+            .modifiers
+            .intersects(Modifiers::CONTROL | Modifiers::META);
+
+        let target = match &data.key {
+            Key::ArrowDown => match active_index {
+                Some(index) => items.get(index + 1).or(items.last()),
+                None => items.first(),
+            },
+            Key::ArrowUp => match active_index {
+                Some(index) => items.get(index.saturating_sub(1)),
+                None => items.last(),
+            },
+            Key::Home => items.first(),
+            Key::End => items.last(),
+            Key::Character(character) if character == " " => {
+                if let Some(active) = active {
+                    self.toggle(active);
+                }
+                return true;
+            }
+            Key::Character(character) if is_control_pressed && character.to_lowercase() == "a" => {
+                self.select_all(items);
+                return true;
+            }
+            Key::Escape => {
+                self.clear();
+                return true;
+            }
+            _ => return false,
+        };
+
+        if let Some(target) = target.cloned() {
+            if data.modifiers.contains(Modifiers::SHIFT) {
+                self.select_range(target, items);
+            } else {
+                self.select(target);
+            }
+        }
+        true
+    }
+}
+
+/// Select items of lists, tables or trees with the mouse and the keyboard.
+///
+/// The items are identified by any value, e.g. their index or path, and the
+/// list of all the items in the order they are rendered is passed when selecting
+/// ranges, so virtualized lists work too.
+///
+/// ```rust,no_run
+/// # use freya::prelude::*;
+/// fn app() -> Element {
+///     let items = use_signal(|| (0..100).collect::<Vec<usize>>());
+///     let mut selection = use_selection(SelectionMode::Multiple);
+///     let focus = use_focus();
+///
+///     rsx!(
+///         rect {
+///             a11y_id: focus.attribute(),
+///             onkeydown: move |e| {
+///                 selection.keydown(&e.data, &items.read());
+///             },
+///             for item in items() {
+///                 rect {
+///                     key: "{item}",
+///                     background: if selection.is_selected(&item) { "rgb(200, 200, 255)" } else { "transparent" },
+///                     onclick: move |_| selection.click(item, &items.read()),
+///                     label { "Item {item}" }
+///                 }
+///             }
+///         }
+///     )
+/// }
+/// ```
+pub fn use_selection<T: Clone + PartialEq + 'static>(mode: SelectionMode) -> UseSelection<T> {
+    use_selection_with_onchange(mode, |_| {})
+}
+
+/// Same as [`use_selection`], but also calls `onchange` with the selected items every time the selection changes.
+/// The mode and `onchange` are only read the first time.
+pub fn use_selection_with_onchange<T: Clone + PartialEq + 'static>(
+    mode: SelectionMode,
+    onchange: impl FnMut(&[T]) + 'static,
+) -> UseSelection<T> {
+    use_hook(|| UseSelection {
+        selected: Signal::new(Vec::new()),
+        anchor: Signal::new(None),
+        active: Signal::new(None),
+        mode,
+        modifiers: CopyValue::new(try_consume_context::<ModifiersReceiver>()),
+        onchange: CopyValue::new(Box::new(onchange)),
+    })
+}
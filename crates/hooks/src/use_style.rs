@@ -0,0 +1,252 @@
+use std::{
+    borrow::Cow,
+    collections::HashMap,
+};
+
+use dioxus_core::{
+    prelude::try_consume_context,
+    use_hook,
+    Attribute,
+};
+use dioxus_hooks::{
+    use_context_provider,
+    use_memo,
+};
+use dioxus_signals::{
+    Memo,
+    Readable,
+    Signal,
+};
+
+use crate::{
+    ColorsSheet,
+    Theme,
+};
+
+type StyleAttributes = Vec<(&'static str, Cow<'static, str>)>;
+
+/// Reusable set of attributes, with variants for when the element is hovered, focused or disabled.
+///
+/// Values can reference the colors of the [`Theme`], e.g `key(primary_accent)`.
+///
+/// ```rust,no_run
+/// # use freya::prelude::*;
+/// let card = Style::new()
+///     .set("background", "key(neutral_surface)")
+///     .set("corner_radius", "8")
+///     .set("padding", "12")
+///     .hover(Style::new().set("background", "key(focused_surface)"));
+/// ```
+#[derive(Clone, Default, PartialEq, Debug)]
+pub struct Style {
+    attributes: StyleAttributes,
+    hover: StyleAttributes,
+    focus: StyleAttributes,
+    disabled: StyleAttributes,
+}
+
+/// State of the element a [`Style`] is applied to.
+#[derive(Clone, Copy, Default, PartialEq, Eq, Debug)]
+pub struct InteractionState {
+    pub hovered: bool,
+    pub focused: bool,
+    pub disabled: bool,
+}
+
+impl Style {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Set the value of an attribute.
+    pub fn set(mut self, attribute: &'static str, value: impl Into<Cow<'static, str>>) -> Self {
+        Self::set_in(&mut self.attributes, attribute, value.into());
+        self
+    }
+
+    /// Attributes to use when the element is hovered.
+    pub fn hover(mut self, style: Style) -> Self {
+        Self::extend_in(&mut self.hover, style.attributes);
+        self
+    }
+
+    /// Attributes to use when the element is focused.
+    pub fn focus(mut self, style: Style) -> Self {
+        Self::extend_in(&mut self.focus, style.attributes);
+        self
+    }
+
+    /// Attributes to use when the element is disabled, these take priority over the hover and focus variants.
+    pub fn disabled(mut self, style: Style) -> Self {
+        Self::extend_in(&mut self.disabled, style.attributes);
+        self
+    }
+
+    /// Merge another [`Style`] on top of this one.
+    pub fn extend(mut self, other: &Style) -> Self {
+        Self::extend_in(&mut self.attributes, other.attributes.clone());
+        Self::extend_in(&mut self.hover, other.hover.clone());
+        Self::extend_in(&mut self.focus, other.focus.clone());
+        Self::extend_in(&mut self.disabled, other.disabled.clone());
+        self
+    }
+
+    /// Resolve the references to colors of the theme.
+    pub fn apply_colors(&mut self, colors: &ColorsSheet) {
+        for attributes in [
+            &mut self.attributes,
+            &mut self.hover,
+            &mut self.focus,
+            &mut self.disabled,
+        ] {
+            for (_, value) in attributes.iter_mut() {
+                *value = colors.resolve(value.clone());
+            }
+        }
+    }
+
+    /// Get the value of an attribute given the state of the element.
+    pub fn get(&self, attribute: &str, state: InteractionState) -> Option<&str> {
+        self.resolved(state)
+            .into_iter()
+            .find(|(name, _)| *name == attribute)
+            .map(|(_, value)| value.as_ref())
+    }
+
+    /// Get the attributes given the state of the element, ready to be spread in an element.
+    ///
+    /// ```rust,no_run
+    /// # use freya::prelude::*;
+    /// fn app() -> Element {
+    ///     let style = Style::new().set("background", "red");
+    ///     rsx!(rect {
+    ///         width: "100",
+    ///         height: "100",
+    ///         ..style.attributes(InteractionState::default())
+    ///     })
+    /// }
+    /// ```
+    pub fn attributes(&self, state: InteractionState) -> Vec<Attribute> {
+        self.resolved(state)
+            .into_iter()
+            .map(|(name, value)| Attribute::new(name, value.to_string(), None, false))
+            .collect()
+    }
+
+    fn resolved(&self, state: InteractionState) -> Vec<&(&'static str, Cow<'static, str>)> {
+        let mut resolved = self.attributes.iter().collect::<Vec<_>>();
+
+        let variants = [
+            (state.hovered, &self.hover),
+            (state.focused, &self.focus),
+            (state.disabled, &self.disabled),
+        ];
+
+        for (_, attributes) in variants.into_iter().filter(|(enabled, _)| *enabled) {
+            for attribute in attributes {
+                resolved.retain(|(name, _)| *name != attribute.0);
+                resolved.push(attribute);
+            }
+        }
+
+        resolved
+    }
+
+    fn set_in(attributes: &mut StyleAttributes, attribute: &'static str, value: Cow<'static, str>) {
+        if let Some((_, current)) = attributes.iter_mut().find(|(name, _)| *name == attribute) {
+            *current = value;
+        } else {
+            attributes.push((attribute, value));
+        }
+    }
+
+    fn extend_in(attributes: &mut StyleAttributes, other: StyleAttributes) {
+        for (attribute, value) in other {
+            Self::set_in(attributes, attribute, value);
+        }
+    }
+}
+
+/// Named collection of [`Style`]s.
+#[derive(Clone, Default, PartialEq, Debug)]
+pub struct StyleSheet {
+    styles: HashMap<&'static str, Style>,
+}
+
+impl StyleSheet {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Add a named [`Style`].
+    pub fn with(mut self, name: &'static str, style: Style) -> Self {
+        self.styles.insert(name, style);
+        self
+    }
+
+    /// Get a [`Style`] by its name.
+    pub fn get(&self, name: &str) -> Option<&Style> {
+        self.styles.get(name)
+    }
+}
+
+/// Provide a [`StyleSheet`] to the descendant components.
+pub fn use_init_style_sheet(init: impl FnOnce() -> StyleSheet) -> Signal<StyleSheet> {
+    use_context_provider(|| Signal::new(init()))
+}
+
+/// Get a [`Style`] from the inherited [`StyleSheet`], with the colors of the [`Theme`] already resolved.
+/// An empty [`Style`] is returned if there is no such style.
+///
+/// ```rust,no_run
+/// # use freya::prelude::*;
+/// fn app() -> Element {
+///     use_init_style_sheet(|| {
+///         StyleSheet::new().with(
+///             "card",
+///             Style::new()
+///                 .set("background", "key(neutral_surface)")
+///                 .set("padding", "12")
+///                 .hover(Style::new().set("background", "key(focused_surface)")),
+///         )
+///     });
+///
+///     rsx!(Card {})
+/// }
+///
+/// #[component]
+/// fn Card() -> Element {
+///     let card = use_style("card");
+///     let mut hovered = use_signal(|| false);
+///
+///     rsx!(
+///         rect {
+///             onmouseenter: move |_| hovered.set(true),
+///             onmouseleave: move |_| hovered.set(false),
+///             ..card.read().attributes(InteractionState {
+///                 hovered: hovered(),
+///                 ..Default::default()
+///             }),
+///             label { "Hello, World!" }
+///         }
+///     )
+/// }
+/// ```
+pub fn use_style(name: &'static str) -> Memo<Style> {
+    let (style_sheet, theme) = use_hook(|| {
+        (
+            try_consume_context::<Signal<StyleSheet>>(),
+            try_consume_context::<Signal<Theme>>(),
+        )
+    });
+    use_memo(move || {
+        let mut style = style_sheet
+            .and_then(|style_sheet| style_sheet.read().get(name).cloned())
+            .unwrap_or_default();
+        let colors = theme
+            .map(|theme| theme.read().colors.clone())
+            .unwrap_or_else(|| Theme::default().colors);
+        style.apply_colors(&colors);
+        style
+    })
+}
@@ -0,0 +1,30 @@
+use dioxus_hooks::use_context;
+use dioxus_signals::{
+    ReadOnlySignal,
+    Signal,
+};
+
+/// Scale factor applied to the font size of all the text.
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub struct TextScaleFactor(pub f32);
+
+/// Access the scale factor applied to the font size of all the text.
+/// It starts with the text size preference of the OS and can be changed with [`crate::UsePlatform::set_text_scale_factor`].
+///
+/// ```rust,no_run
+/// # use freya::prelude::*;
+/// fn app() -> Element {
+///     let platform = use_platform();
+///     let text_scale_factor = use_text_scale_factor();
+///
+///     rsx!(
+///         Button {
+///             onpress: move |_| platform.set_text_scale_factor(text_scale_factor().0 + 0.25),
+///             label { "Bigger text" }
+///         }
+///     )
+/// }
+/// ```
+pub fn use_text_scale_factor() -> ReadOnlySignal<TextScaleFactor> {
+    use_context::<Signal<TextScaleFactor>>().into()
+}
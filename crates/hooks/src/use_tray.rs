@@ -0,0 +1,54 @@
+use dioxus_core::prelude::{
+    spawn,
+    try_consume_context,
+    use_hook,
+};
+pub use freya_core::platform::{
+    tray_icon,
+    TrayEvent,
+};
+use freya_core::types::TrayEventsSender;
+use tokio::sync::broadcast::error::RecvError;
+
+/// Listen for the click and menu events of the tray icon installed with `LaunchConfig::with_tray_icon`.
+///
+/// Requires the `tray` feature.
+///
+/// ```rust,no_run
+/// # use freya::prelude::*;
+/// fn app() -> Element {
+///     let window = use_window();
+///
+///     use_tray_event(move |event| {
+///         // Bring the window back when clicking the tray icon
+///         if let TrayEvent::Icon(tray_icon::TrayIconEvent::Click { .. }) = event {
+///             window.set_visible(true);
+///         }
+///     });
+///
+///     rsx!(
+///         Button {
+///             onclick: move |_| window.set_visible(false),
+///             label { "Minimize to tray" }
+///         }
+///     )
+/// }
+/// ```
+pub fn use_tray_event(mut handler: impl FnMut(TrayEvent) + 'static) {
+    use_hook(move || {
+        let Some(sender) = try_consume_context::<TrayEventsSender>() else {
+            return;
+        };
+        let mut receiver = sender.subscribe();
+
+        spawn(async move {
+            loop {
+                match receiver.recv().await {
+                    Ok(event) => handler(event),
+                    Err(RecvError::Lagged(_)) => continue,
+                    Err(RecvError::Closed) => break,
+                }
+            }
+        });
+    });
+}
@@ -0,0 +1,85 @@
+use dioxus_core::prelude::use_hook;
+use freya_core::event_loop_messages::{
+    EventLoopMessage,
+    WindowAction,
+};
+
+use crate::UsePlatform;
+
+/// Handle returned by [`use_window`].
+#[derive(Clone, Copy, PartialEq)]
+pub struct UseWindow {
+    platform: UsePlatform,
+}
+
+impl UseWindow {
+    fn apply(&self, action: WindowAction) {
+        self.platform
+            .send(EventLoopMessage::WindowAction(action))
+            .ok();
+    }
+
+    /// Change the title of the window.
+    pub fn set_title(&self, title: impl Into<String>) {
+        self.apply(WindowAction::SetTitle(title.into()));
+    }
+
+    /// Resize the window, in logical pixels.
+    pub fn set_size(&self, width: f64, height: f64) {
+        self.apply(WindowAction::SetSize(width, height));
+    }
+
+    /// Move the window, in logical pixels relative to the screen.
+    pub fn set_position(&self, x: f64, y: f64) {
+        self.apply(WindowAction::SetPosition(x, y));
+    }
+
+    /// Minimize or restore the window.
+    pub fn set_minimized(&self, minimized: bool) {
+        self.apply(WindowAction::SetMinimized(minimized));
+    }
+
+    /// Maximize or restore the window.
+    pub fn set_maximized(&self, maximized: bool) {
+        self.apply(WindowAction::SetMaximized(maximized));
+    }
+
+    /// Enter or leave borderless fullscreen.
+    pub fn set_fullscreen(&self, fullscreen: bool) {
+        self.apply(WindowAction::SetFullscreen(fullscreen));
+    }
+
+    /// Show or hide the window, e.g. to minimize it to the tray.
+    pub fn set_visible(&self, visible: bool) {
+        self.apply(WindowAction::SetVisible(visible));
+    }
+
+    /// Keep the window above the others or not.
+    pub fn set_always_on_top(&self, always_on_top: bool) {
+        self.apply(WindowAction::SetAlwaysOnTop(always_on_top));
+    }
+}
+
+/// Control the window in which the component runs: title, size, position, fullscreen and more.
+///
+/// ```rust,no_run
+/// # use freya::prelude::*;
+/// fn app() -> Element {
+///     let window = use_window();
+///
+///     rsx!(
+///         Button {
+///             onpress: move |_| {
+///                 window.set_title("Presentation");
+///                 window.set_fullscreen(true);
+///             },
+///             label { "Present" }
+///         }
+///     )
+/// }
+/// ```
+pub fn use_window() -> UseWindow {
+    use_hook(|| UseWindow {
+        platform: UsePlatform::current(),
+    })
+}
@@ -269,3 +269,50 @@ pub async fn sequential() {
     assert_eq!(width_a, 100.0);
     assert_eq!(width_b, 100.0);
 }
+
+#[tokio::test]
+pub async fn animated_transition() {
+    fn use_animated_app() -> Element {
+        let mut expanded = use_signal(|| false);
+
+        let target_width = if expanded() { 100. } else { 10. };
+        let width = use_animated(target_width, Transition::new(50));
+
+        rsx!(rect {
+            background: "white",
+            height: "100%",
+            onclick: move |_| expanded.toggle(),
+            width: "{width}",
+        })
+    }
+
+    let mut utils = launch_test(use_animated_app);
+
+    // Disable event loop ticker
+    utils.config().event_loop_ticker = false;
+
+    // The initial value is not animated
+    utils.wait_for_update().await;
+
+    assert_eq!(utils.root().get(0).area().unwrap().width(), 10.0);
+
+    // Changing the value starts the transition
+    utils.click_cursor((5., 5.)).await;
+
+    sleep(Duration::from_millis(32)).await;
+    utils.wait_for_update().await;
+
+    let width = utils.root().get(0).area().unwrap().width();
+    assert!(width > 10.0 && width < 100.0);
+
+    // Enable event loop ticker
+    utils.config().event_loop_ticker = true;
+
+    // Already finished
+    sleep(Duration::from_millis(50)).await;
+
+    utils.wait_for_update().await;
+
+    let width = utils.root().get(0).area().unwrap().width();
+    assert_eq!(width, 100.0);
+}
@@ -0,0 +1,66 @@
+use freya::prelude::*;
+use freya_testing::prelude::*;
+
+#[tokio::test]
+pub async fn use_asset_reads_embedded_assets() {
+    fn use_asset_app() -> Element {
+        use_init_assets(|| {
+            AssetManifest::new("assets").with_embedded_asset("icons/save.svg", b"<svg></svg>")
+        });
+
+        rsx!(AssetSize {})
+    }
+
+    #[component]
+    fn AssetSize() -> Element {
+        let save = use_asset("icons/save.svg").map(|bytes| bytes.len());
+        let missing = use_asset("icons/missing.svg").map(|bytes| bytes.len());
+
+        rsx!(
+            label {
+                "{save:?}"
+            }
+            label {
+                "{missing:?}"
+            }
+        )
+    }
+
+    let mut utils = launch_test(use_asset_app);
+    utils.wait_for_update().await;
+
+    let root = utils.root();
+    assert_eq!(root.get(0).get(0).text(), Some("Some(11)"));
+    assert_eq!(root.get(1).get(0).text(), Some("None"));
+}
+
+#[tokio::test]
+pub async fn use_asset_reads_assets_from_disk() {
+    fn assets_dir() -> std::path::PathBuf {
+        std::env::temp_dir().join("freya_use_asset_test")
+    }
+
+    fn use_asset_app() -> Element {
+        use_init_assets(|| AssetManifest::new(assets_dir()).with_asset("images/logo.png"));
+
+        rsx!(AssetSize {})
+    }
+
+    #[component]
+    fn AssetSize() -> Element {
+        let logo = use_asset("images/logo.png").map(|bytes| bytes.len());
+
+        rsx!(label {
+            "{logo:?}"
+        })
+    }
+
+    std::fs::create_dir_all(assets_dir().join("images")).unwrap();
+    std::fs::write(assets_dir().join("images/logo.png"), [1, 2, 3, 4]).unwrap();
+
+    let mut utils = launch_test(use_asset_app);
+    utils.wait_for_update().await;
+
+    let root = utils.root();
+    assert_eq!(root.get(0).get(0).text(), Some("Some(4)"));
+}
@@ -0,0 +1,57 @@
+use freya::prelude::*;
+use freya_testing::prelude::*;
+
+#[tokio::test]
+pub async fn use_clipboard_copy_and_paste() {
+    fn use_clipboard_app() -> Element {
+        let clipboard = use_clipboard();
+        let mut pasted = use_signal(String::new);
+
+        rsx!(
+            rect {
+                width: "100%",
+                height: "50%",
+                onclick: move |_| {
+                    spawn(async move {
+                        clipboard.set_text("Hello, World!").await.unwrap();
+                    });
+                }
+            }
+            rect {
+                width: "100%",
+                height: "50%",
+                onclick: move |_| {
+                    spawn(async move {
+                        let text = clipboard.get_text().await.unwrap_or_else(|err| err.to_string());
+                        pasted.set(text);
+                    });
+                },
+                label {
+                    "{pasted}"
+                }
+            }
+        )
+    }
+
+    let mut utils = launch_test(use_clipboard_app);
+    utils.wait_for_update().await;
+    let label = utils.root().get(1).get(0);
+
+    // Nothing was copied yet
+    utils.click_cursor((5., 300.)).await;
+    utils.wait_for_update().await;
+    utils.wait_for_update().await;
+    assert_eq!(label.get(0).text(), Some("The clipboard is empty"));
+
+    // Copy
+    utils.click_cursor((5., 5.)).await;
+    utils.wait_for_update().await;
+    utils.wait_for_update().await;
+    assert_eq!(utils.clipboard_text(), Some("Hello, World!"));
+
+    // Paste
+    utils.click_cursor((5., 300.)).await;
+    utils.wait_for_update().await;
+    utils.wait_for_update().await;
+    assert_eq!(label.get(0).text(), Some("Hello, World!"));
+}
@@ -0,0 +1,54 @@
+use std::time::Duration;
+
+use freya::prelude::*;
+use freya_testing::prelude::*;
+use tokio::time::sleep;
+
+#[tokio::test]
+pub async fn use_clipboard_watcher_changes() {
+    fn use_clipboard_watcher_app() -> Element {
+        let mut changes = use_signal(|| 0);
+        let content =
+            use_clipboard_watcher_with_onchange(Duration::from_millis(20), move |_| changes += 1);
+
+        let content = match &*content.read() {
+            None => "Unknown".to_string(),
+            Some(content) if content.link().is_some() => {
+                format!("Link {}", content.link().unwrap())
+            }
+            Some(ClipboardContent::Text(text)) => format!("Text {text}"),
+            Some(ClipboardContent::Image(_)) => "Image".to_string(),
+            Some(ClipboardContent::Empty) => "Empty".to_string(),
+        };
+
+        rsx!(
+            label { "{content}" }
+            label { "{changes}" }
+        )
+    }
+
+    let mut utils = launch_test(use_clipboard_watcher_app);
+    let content = utils.root().get(0);
+    let changes = utils.root().get(1);
+
+    utils.wait_for_update().await;
+    utils.wait_for_update().await;
+    assert_eq!(content.get(0).text(), Some("Empty"));
+    assert_eq!(changes.get(0).text(), Some("0"));
+
+    // Another app copies some text
+    utils.set_clipboard_text("Hello");
+    sleep(Duration::from_millis(50)).await;
+    utils.wait_for_update().await;
+    utils.wait_for_update().await;
+    assert_eq!(content.get(0).text(), Some("Text Hello"));
+    assert_eq!(changes.get(0).text(), Some("1"));
+
+    // And then a link
+    utils.set_clipboard_text(" https://freyaui.dev ");
+    sleep(Duration::from_millis(50)).await;
+    utils.wait_for_update().await;
+    utils.wait_for_update().await;
+    assert_eq!(content.get(0).text(), Some("Link https://freyaui.dev"));
+    assert_eq!(changes.get(0).text(), Some("2"));
+}
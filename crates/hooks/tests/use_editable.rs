@@ -1285,3 +1285,181 @@ pub async fn navigate_empty_lines() {
     let cursor = root.get(1).get(0);
     assert_eq!(cursor.text(), Some("1:0"));
 }
+
+#[tokio::test]
+pub async fn rtl_visual_cursor_movement() {
+    fn rtl_app() -> Element {
+        let mut editable = use_editable(
+            || {
+                EditableConfig::new("שלום\nHello".to_string())
+                    .with_text_direction(EditorDirection::Auto)
+            },
+            EditableMode::MultipleLinesSingleEditor,
+        );
+        let cursor_attr = editable.cursor_attr();
+        let editor = editable.editor().read();
+        let cursor_pos = editor.cursor_pos();
+
+        let onglobalkeydown = move |e: Event<KeyboardData>| {
+            editable.process_event(&EditableEvent::KeyDown(e.data));
+        };
+
+        rsx!(
+            rect {
+                width: "100%",
+                height: "100%",
+                paragraph {
+                    cursor_reference: cursor_attr,
+                    height: "50%",
+                    width: "100%",
+                    cursor_id: "0",
+                    cursor_index: "{cursor_pos}",
+                    cursor_mode: "editable",
+                    direction: "rtl",
+                    onglobalkeydown,
+                    text {
+                        "{editor}"
+                    }
+                }
+                label {
+                    height: "50%",
+                    "{editor.cursor_row()}:{editor.cursor_col()}"
+                }
+            }
+        )
+    }
+
+    let mut utils = launch_test(rtl_app);
+
+    let root = utils.root().get(0);
+    let cursor = root.get(1).get(0);
+    assert_eq!(cursor.text(), Some("0:0"));
+
+    // ArrowLeft moves forward in a right-to-left line
+    utils.push_event(TestEvent::Keyboard {
+        name: EventName::KeyDown,
+        key: Key::ArrowLeft,
+        code: Code::ArrowLeft,
+        modifiers: Modifiers::default(),
+    });
+    utils.wait_for_update().await;
+
+    let cursor = root.get(1).get(0);
+    assert_eq!(cursor.text(), Some("0:1"));
+
+    // ArrowRight moves backwards in a right-to-left line
+    utils.push_event(TestEvent::Keyboard {
+        name: EventName::KeyDown,
+        key: Key::ArrowRight,
+        code: Code::ArrowRight,
+        modifiers: Modifiers::default(),
+    });
+    utils.wait_for_update().await;
+
+    let cursor = root.get(1).get(0);
+    assert_eq!(cursor.text(), Some("0:0"));
+
+    // Move to the left-to-right line
+    utils.push_event(TestEvent::Keyboard {
+        name: EventName::KeyDown,
+        key: Key::ArrowDown,
+        code: Code::ArrowDown,
+        modifiers: Modifiers::default(),
+    });
+    utils.wait_for_update().await;
+
+    let cursor = root.get(1).get(0);
+    assert_eq!(cursor.text(), Some("1:0"));
+
+    // ArrowRight moves forward in a left-to-right line
+    utils.push_event(TestEvent::Keyboard {
+        name: EventName::KeyDown,
+        key: Key::ArrowRight,
+        code: Code::ArrowRight,
+        modifiers: Modifiers::default(),
+    });
+    utils.wait_for_update().await;
+
+    let cursor = root.get(1).get(0);
+    assert_eq!(cursor.text(), Some("1:1"));
+}
+
+#[tokio::test]
+pub async fn sensitive_text_cannot_be_cut() {
+    fn sensitive_app() -> Element {
+        let mut editable = use_editable(
+            || EditableConfig::new("hunter2".to_string()).with_sensitive(true),
+            EditableMode::MultipleLinesSingleEditor,
+        );
+        let cursor_reference = editable.cursor_attr();
+        let editor = editable.editor().read();
+        let cursor_pos = editor.cursor_pos();
+        let highlights = editable.highlights_attr(0);
+
+        let onglobalkeydown = move |e: Event<KeyboardData>| {
+            editable.process_event(&EditableEvent::KeyDown(e.data));
+        };
+
+        rsx!(
+            rect {
+                width: "100%",
+                height: "100%",
+                background: "white",
+                paragraph {
+                    cursor_reference,
+                    height: "50%",
+                    width: "100%",
+                    cursor_id: "0",
+                    cursor_index: "{cursor_pos}",
+                    cursor_color: "black",
+                    cursor_mode: "editable",
+                    sensitive: "true",
+                    highlights,
+                    onglobalkeydown,
+                    text {
+                        color: "black",
+                        "{editor}"
+                    }
+                }
+            }
+        )
+    }
+
+    let mut utils = launch_test(sensitive_app);
+
+    let root = utils.root().get(0);
+
+    #[cfg(target_os = "macos")]
+    let modifiers = Modifiers::META;
+
+    #[cfg(not(target_os = "macos"))]
+    let modifiers = Modifiers::CONTROL;
+
+    // Select all text
+    utils.push_event(TestEvent::Keyboard {
+        name: EventName::KeyDown,
+        key: Key::Character("a".to_string()),
+        code: Code::KeyA,
+        modifiers,
+    });
+    utils.wait_for_update().await;
+    utils.wait_for_update().await;
+
+    // Try to cut it
+    utils.push_event(TestEvent::Keyboard {
+        name: EventName::KeyDown,
+        key: Key::Character("x".to_string()),
+        code: Code::KeyX,
+        modifiers,
+    });
+    utils.wait_for_update().await;
+    utils.wait_for_update().await;
+
+    // Text remains untouched and selected
+    let content = root.get(0).get(0).get(0);
+    assert_eq!(content.text(), Some("hunter2"));
+    assert_eq!(
+        root.get(0).state().cursor.highlights.clone(),
+        Some(vec![(0, 7)])
+    );
+}
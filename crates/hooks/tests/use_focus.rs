@@ -165,3 +165,171 @@ pub async fn block_focus() {
     assert_eq!(root.get(0).get(0).get(0).text(), Some("false"));
     assert_eq!(root.get(1).get(0).get(0).text(), Some("true"));
 }
+
+#[tokio::test]
+pub async fn init_focus() {
+    #[component]
+    fn UnfocusedChild() -> Element {
+        let focus_manager = use_focus();
+
+        rsx!(
+            rect {
+                a11y_id: focus_manager.attribute(),
+                width: "100%",
+                height: "50%",
+                label {
+                    "{focus_manager.is_focused()}"
+                }
+            }
+        )
+    }
+
+    #[component]
+    fn FocusedChild() -> Element {
+        let focus_manager = use_init_focus();
+
+        rsx!(
+            rect {
+                a11y_id: focus_manager.attribute(),
+                width: "100%",
+                height: "50%",
+                label {
+                    "{focus_manager.is_focused()}"
+                }
+            }
+        )
+    }
+
+    fn use_focus_app() -> Element {
+        rsx!(
+            UnfocusedChild {}
+            FocusedChild {}
+        )
+    }
+
+    let mut utils = launch_test_with_config(
+        use_focus_app,
+        TestingConfig::<()> {
+            size: (100.0, 100.0).into(),
+            ..TestingConfig::default()
+        },
+    );
+
+    utils.wait_for_update().await;
+    utils.wait_for_update().await;
+
+    // The second rect is focused on mount
+    let root = utils.root();
+    assert_eq!(root.get(0).get(0).get(0).text(), Some("false"));
+    assert_eq!(root.get(1).get(0).get(0).text(), Some("true"));
+}
+
+#[tokio::test]
+pub async fn focus_previous() {
+    #[component]
+    fn Popup(onclose: EventHandler) -> Element {
+        let focus_manager = use_init_focus();
+
+        rsx!(rect {
+            a11y_id: focus_manager.attribute(),
+            width: "100%",
+            height: "50%",
+            onclick: move |_| {
+                focus_manager.focus_previous();
+                onclose.call(());
+            },
+        })
+    }
+
+    fn use_focus_app() -> Element {
+        let mut show_popup = use_signal(|| false);
+        let mut focus_manager = use_focus();
+        let focus_history = focus_manager.focus_history();
+
+        rsx!(
+            rect {
+                a11y_id: focus_manager.attribute(),
+                width: "100%",
+                height: "50%",
+                onclick: move |_| {
+                    focus_manager.request_focus();
+                    show_popup.set(true);
+                },
+                label {
+                    "{focus_manager.is_focused()} {focus_history.read().len()}"
+                }
+            }
+            if show_popup() {
+                Popup {
+                    onclose: move |_| show_popup.set(false)
+                }
+            }
+        )
+    }
+
+    let mut utils = launch_test_with_config(
+        use_focus_app,
+        TestingConfig::<()> {
+            size: (100.0, 100.0).into(),
+            ..TestingConfig::default()
+        },
+    );
+    utils.wait_for_update().await;
+
+    // Open the popup, it takes the focus from the trigger
+    utils.click_cursor((5., 5.)).await;
+    utils.wait_for_update().await;
+    utils.wait_for_update().await;
+    let root = utils.root();
+    assert_eq!(root.get(0).get(0).get(0).text(), Some("false 1"));
+
+    // Close the popup, the focus goes back to the trigger
+    utils.click_cursor((5., 75.)).await;
+    utils.wait_for_update().await;
+    utils.wait_for_update().await;
+    assert_eq!(root.get(0).get(0).get(0).text(), Some("true 0"));
+    assert_eq!(root.children_ids().len(), 1);
+}
+
+#[tokio::test]
+pub async fn autofocus_attribute() {
+    #[component]
+    fn Child(autofocus: bool) -> Element {
+        let focus_manager = use_focus();
+
+        rsx!(
+            rect {
+                a11y_id: focus_manager.attribute(),
+                autofocus: "{autofocus}",
+                width: "100%",
+                height: "50%",
+                label {
+                    "{focus_manager.is_focused()}"
+                }
+            }
+        )
+    }
+
+    fn use_focus_app() -> Element {
+        rsx!(
+            Child { autofocus: true }
+            Child { autofocus: false }
+        )
+    }
+
+    let mut utils = launch_test_with_config(
+        use_focus_app,
+        TestingConfig::<()> {
+            size: (100.0, 100.0).into(),
+            ..TestingConfig::default()
+        },
+    );
+
+    utils.wait_for_update().await;
+    utils.wait_for_update().await;
+
+    // The first rect is focused on mount
+    let root = utils.root();
+    assert_eq!(root.get(0).get(0).get(0).text(), Some("true"));
+    assert_eq!(root.get(1).get(0).get(0).text(), Some("false"));
+}
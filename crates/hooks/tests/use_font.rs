@@ -0,0 +1,38 @@
+use freya::prelude::*;
+use freya_testing::prelude::*;
+
+#[tokio::test]
+pub async fn use_font_registers_font() {
+    fn use_font_app() -> Element {
+        use_font("Sansita Swashed", || {
+            FontSource::File(
+                concat!(
+                    env!("CARGO_MANIFEST_DIR"),
+                    "/../../examples/SansitaSwashed-Regular.ttf"
+                )
+                .into(),
+            )
+        });
+
+        rsx!(
+            label {
+                width: "auto",
+                "Hello, World!"
+            }
+            label {
+                width: "auto",
+                font_family: "Sansita Swashed",
+                "Hello, World!"
+            }
+        )
+    }
+
+    let mut utils = launch_test(use_font_app);
+    utils.wait_for_update().await;
+    utils.wait_for_update().await;
+
+    let root = utils.root();
+    let default_width = root.get(0).layout().unwrap().area.width();
+    let custom_width = root.get(1).layout().unwrap().area.width();
+    assert_ne!(default_width, custom_width);
+}
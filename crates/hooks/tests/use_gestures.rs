@@ -0,0 +1,146 @@
+use std::time::Duration;
+
+use freya::prelude::*;
+use freya_testing::prelude::*;
+
+fn use_gestures_app() -> Element {
+    let mut last_gesture = use_signal(String::new);
+    let mut gestures = use_gestures(move |gesture| {
+        let gesture = match gesture {
+            TouchGesture::Pinch { scale, .. } => format!("pinch {scale}"),
+            TouchGesture::Pan { delta } => format!("pan {} {}", delta.x, delta.y),
+            TouchGesture::LongPress { position } => {
+                format!("long press {} {}", position.x, position.y)
+            }
+        };
+        last_gesture.set(gesture);
+    });
+
+    let ontouch = move |e: TouchEvent| gestures.process_touch(&e.data);
+
+    rsx!(
+        rect {
+            width: "fill",
+            height: "fill",
+            ontouchstart: ontouch,
+            ontouchmove: ontouch,
+            ontouchend: ontouch,
+            ontouchcancel: ontouch,
+            label {
+                "{last_gesture}"
+            }
+        }
+    )
+}
+
+fn touch(utils: &mut TestingHandler<()>, location: (f64, f64), phase: TouchPhase, finger_id: u64) {
+    let name = match phase {
+        TouchPhase::Started => EventName::TouchStart,
+        TouchPhase::Moved => EventName::TouchMove,
+        TouchPhase::Ended => EventName::TouchEnd,
+        TouchPhase::Cancelled => EventName::TouchCancel,
+    };
+    utils.push_event(TestEvent::Touch {
+        name,
+        location: location.into(),
+        phase,
+        finger_id,
+        force: None,
+    });
+}
+
+#[tokio::test]
+pub async fn use_gestures_pinch_and_pan() {
+    let mut utils = launch_test(use_gestures_app);
+    utils.wait_for_update().await;
+    let label = utils.root().get(0).get(0);
+
+    touch(&mut utils, (100., 100.), TouchPhase::Started, 0);
+    touch(&mut utils, (200., 100.), TouchPhase::Started, 1);
+    utils.wait_for_update().await;
+
+    // The fingers move apart
+    touch(&mut utils, (300., 100.), TouchPhase::Moved, 1);
+    utils.wait_for_update().await;
+    assert_eq!(label.get(0).text(), Some("pan 50 0"));
+
+    touch(&mut utils, (0., 100.), TouchPhase::Moved, 0);
+    utils.wait_for_update().await;
+    assert_eq!(label.get(0).text(), Some("pan -50 0"));
+
+    // Both fingers move down
+    touch(&mut utils, (0., 150.), TouchPhase::Moved, 0);
+    touch(&mut utils, (300., 150.), TouchPhase::Moved, 1);
+    utils.wait_for_update().await;
+    assert_eq!(label.get(0).text(), Some("pan 0 25"));
+
+    touch(&mut utils, (300., 150.), TouchPhase::Ended, 1);
+    touch(&mut utils, (0., 150.), TouchPhase::Ended, 0);
+    utils.wait_for_update().await;
+}
+
+#[tokio::test]
+pub async fn use_gestures_pinch_scale() {
+    fn use_gestures_pinch_app() -> Element {
+        let mut scale = use_signal(|| 1.0);
+        let mut gestures = use_gestures(move |gesture| {
+            if let TouchGesture::Pinch {
+                scale: new_scale, ..
+            } = gesture
+            {
+                scale.set(new_scale);
+            }
+        });
+
+        let ontouch = move |e: TouchEvent| gestures.process_touch(&e.data);
+
+        rsx!(
+            rect {
+                width: "fill",
+                height: "fill",
+                ontouchstart: ontouch,
+                ontouchmove: ontouch,
+                ontouchend: ontouch,
+                label {
+                    "{scale}"
+                }
+            }
+        )
+    }
+
+    let mut utils = launch_test(use_gestures_pinch_app);
+    utils.wait_for_update().await;
+    let label = utils.root().get(0).get(0);
+
+    touch(&mut utils, (100., 100.), TouchPhase::Started, 0);
+    touch(&mut utils, (200., 100.), TouchPhase::Started, 1);
+    touch(&mut utils, (300., 100.), TouchPhase::Moved, 1);
+    utils.wait_for_update().await;
+    assert_eq!(label.get(0).text(), Some("2"));
+
+    touch(&mut utils, (150., 100.), TouchPhase::Moved, 1);
+    utils.wait_for_update().await;
+    assert_eq!(label.get(0).text(), Some("0.5"));
+}
+
+#[tokio::test]
+pub async fn use_gestures_long_press() {
+    let mut utils = launch_test(use_gestures_app);
+    utils.wait_for_update().await;
+    let label = utils.root().get(0).get(0);
+
+    // Moving the finger cancels the long press
+    touch(&mut utils, (100., 100.), TouchPhase::Started, 0);
+    touch(&mut utils, (150., 100.), TouchPhase::Moved, 0);
+    utils.wait_for_update().await;
+    tokio::time::sleep(Duration::from_millis(600)).await;
+    utils.wait_for_update().await;
+    assert_eq!(label.get(0).text(), Some(""));
+    touch(&mut utils, (150., 100.), TouchPhase::Ended, 0);
+
+    touch(&mut utils, (100., 100.), TouchPhase::Started, 0);
+    utils.wait_for_update().await;
+    tokio::time::sleep(Duration::from_millis(600)).await;
+    utils.wait_for_update().await;
+    assert_eq!(label.get(0).text(), Some("long press 100 100"));
+}
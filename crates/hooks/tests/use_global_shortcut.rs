@@ -0,0 +1,81 @@
+use std::{
+    cell::RefCell,
+    rc::Rc,
+};
+
+use dioxus_core::VirtualDom;
+use freya::prelude::*;
+use freya_testing::prelude::*;
+
+#[tokio::test]
+pub async fn use_global_shortcut_dispatches_shortcut() {
+    fn use_global_shortcut_app() -> Element {
+        let mut triggered = use_signal(|| 0);
+        let mut pressed = use_signal(|| 0);
+        let shortcut = use_global_shortcut("ctrl+k", move || triggered += 1);
+        let conflict = use_global_shortcut("Ctrl+K", || {});
+
+        rsx!(
+            rect {
+                width: "100%",
+                height: "100%",
+                onglobalkeydown: move |_| pressed += 1,
+                label { "{triggered}" }
+                label { "{pressed}" }
+                label { "{shortcut.is_registered()}" }
+                label { "{conflict.error().unwrap()}" }
+            }
+        )
+    }
+
+    let mut utils = launch_test(use_global_shortcut_app);
+    utils.wait_for_update().await;
+
+    let root = utils.root().get(0);
+    assert_eq!(root.get(2).get(0).text(), Some("true"));
+    assert_eq!(
+        root.get(3).get(0).text(),
+        Some("The shortcut 'Ctrl+K' is already registered")
+    );
+
+    // The shortcut is not emitted to the elements
+    utils.push_event(TestEvent::Keyboard {
+        name: EventName::KeyDown,
+        key: Key::Character("k".to_string()),
+        code: Code::KeyK,
+        modifiers: Modifiers::CONTROL,
+    });
+    utils.wait_for_update().await;
+    utils.wait_for_update().await;
+    assert_eq!(root.get(0).get(0).text(), Some("1"));
+    assert_eq!(root.get(1).get(0).text(), Some("0"));
+
+    // Other combinations are emitted as usual
+    utils.push_event(TestEvent::Keyboard {
+        name: EventName::KeyDown,
+        key: Key::Character("k".to_string()),
+        code: Code::KeyK,
+        modifiers: Modifiers::empty(),
+    });
+    utils.wait_for_update().await;
+    assert_eq!(root.get(0).get(0).text(), Some("1"));
+    assert_eq!(root.get(1).get(0).text(), Some("1"));
+}
+
+#[test]
+pub fn use_global_shortcut_without_registry() {
+    fn use_global_shortcut_app(result: Rc<RefCell<Option<UseGlobalShortcut>>>) -> Element {
+        let shortcut = use_global_shortcut("ctrl+k", || {});
+        result.borrow_mut().replace(shortcut);
+
+        rsx!(rect {})
+    }
+
+    let result = Rc::new(RefCell::new(None));
+    let mut vdom = VirtualDom::new_with_props(use_global_shortcut_app, result.clone());
+    vdom.rebuild_in_place();
+
+    let shortcut = result.borrow_mut().take().unwrap();
+    assert!(!shortcut.is_registered());
+    assert_eq!(shortcut.error(), Some(&ShortcutError::Unavailable));
+}
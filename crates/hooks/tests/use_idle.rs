@@ -0,0 +1,42 @@
+use std::time::Duration;
+
+use freya::prelude::*;
+use freya_testing::prelude::*;
+
+#[tokio::test]
+pub async fn use_idle_detects_inactivity() {
+    fn use_idle_app() -> Element {
+        let mut resumed = use_signal(|| 0);
+        let is_idle = use_idle_with_callback(Duration::from_millis(50), move |inactive| {
+            assert!(inactive >= Duration::from_millis(50));
+            resumed += 1;
+        });
+
+        rsx!(
+            label {
+                "{is_idle}"
+            }
+            label {
+                "{resumed}"
+            }
+        )
+    }
+
+    let mut utils = launch_test(use_idle_app);
+    utils.wait_for_update().await;
+
+    let root = utils.root();
+    assert_eq!(root.get(0).get(0).text(), Some("false"));
+
+    // The user has been inactive for longer than the timeout
+    tokio::time::sleep(Duration::from_millis(100)).await;
+    utils.wait_for_update().await;
+    assert_eq!(root.get(0).get(0).text(), Some("true"));
+    assert_eq!(root.get(1).get(0).text(), Some("0"));
+
+    // Any input resumes the activity
+    utils.move_cursor((5., 5.)).await;
+    utils.wait_for_update().await;
+    assert_eq!(root.get(0).get(0).text(), Some("false"));
+    assert_eq!(root.get(1).get(0).text(), Some("1"));
+}
@@ -0,0 +1,69 @@
+use freya::prelude::*;
+use freya_testing::prelude::*;
+
+#[tokio::test]
+pub async fn use_key_capture_captures_next_combination() {
+    fn use_key_capture_app() -> Element {
+        let mut key_capture = use_key_capture();
+        let mut shortcut = use_signal(|| "None".to_string());
+        let mut pressed = use_signal(|| 0);
+
+        rsx!(
+            rect {
+                width: "100%",
+                height: "100%",
+                onglobalkeydown: move |_| pressed += 1,
+                onclick: move |_| {
+                    key_capture.capture(move |combination| {
+                        let ctrl = combination.modifiers.contains(Modifiers::CONTROL);
+                        shortcut.set(format!("{ctrl} {}", combination.key));
+                    });
+                },
+                label { "{shortcut}" }
+                label { "{pressed}" }
+                label { "{key_capture.is_capturing()}" }
+            }
+        )
+    }
+
+    let mut utils = launch_test(use_key_capture_app);
+    utils.wait_for_update().await;
+
+    let root = utils.root().get(0);
+    utils.click_cursor((5., 5.)).await;
+    assert_eq!(root.get(2).get(0).text(), Some("true"));
+
+    // Modifiers alone don't finish the capture
+    utils.push_event(TestEvent::Keyboard {
+        name: EventName::KeyDown,
+        key: Key::Control,
+        code: Code::ControlLeft,
+        modifiers: Modifiers::CONTROL,
+    });
+    utils.wait_for_update().await;
+    assert_eq!(root.get(2).get(0).text(), Some("true"));
+
+    utils.push_event(TestEvent::Keyboard {
+        name: EventName::KeyDown,
+        key: Key::Character("k".to_string()),
+        code: Code::KeyK,
+        modifiers: Modifiers::CONTROL,
+    });
+    utils.wait_for_update().await;
+    utils.wait_for_update().await;
+
+    // The captured keys are not emitted to the elements
+    assert_eq!(root.get(0).get(0).text(), Some("true k"));
+    assert_eq!(root.get(1).get(0).text(), Some("0"));
+    assert_eq!(root.get(2).get(0).text(), Some("false"));
+
+    // Keys are emitted again once captured
+    utils.push_event(TestEvent::Keyboard {
+        name: EventName::KeyDown,
+        key: Key::Character("k".to_string()),
+        code: Code::KeyK,
+        modifiers: Modifiers::empty(),
+    });
+    utils.wait_for_update().await;
+    assert_eq!(root.get(1).get(0).text(), Some("1"));
+}
@@ -0,0 +1,51 @@
+use freya::prelude::*;
+use freya_testing::prelude::*;
+
+#[tokio::test]
+pub async fn layout_direction_mirrors_layout() {
+    fn layout_direction_app() -> Element {
+        let platform = use_platform();
+        let layout_direction = use_layout_direction();
+
+        rsx!(
+            rect {
+                width: "100%",
+                height: "50%",
+                onclick: move |_| platform.set_layout_direction(LayoutDirection::Rtl),
+                label { "{layout_direction():?}" }
+            }
+            rect {
+                width: "100%",
+                direction: "horizontal",
+                padding: "0 0 0 10",
+                rect {
+                    width: "100",
+                    height: "50"
+                }
+                rect {
+                    width: "50",
+                    height: "50"
+                }
+            }
+        )
+    }
+
+    let mut utils = launch_test(layout_direction_app);
+    utils.wait_for_update().await;
+
+    let root = utils.root();
+    let label = root.get(0).get(0);
+    let first = root.get(1).get(0);
+    let second = root.get(1).get(1);
+    assert_eq!(label.get(0).text(), Some("Ltr"));
+    assert_eq!(first.layout().unwrap().area.min_x(), 10.0);
+    assert_eq!(second.layout().unwrap().area.min_x(), 110.0);
+
+    utils.click_cursor((5.0, 5.0)).await;
+    utils.wait_for_update().await;
+    utils.wait_for_update().await;
+
+    assert_eq!(label.get(0).text(), Some("Rtl"));
+    assert_eq!(first.layout().unwrap().area.min_x(), 390.0);
+    assert_eq!(second.layout().unwrap().area.min_x(), 340.0);
+}
@@ -0,0 +1,115 @@
+use freya::prelude::*;
+use freya_testing::prelude::*;
+
+#[tokio::test]
+pub async fn use_render_info_tracks_renders() {
+    fn use_render_info_app() -> Element {
+        let render_info = use_render_info();
+        let mut count = use_signal(|| 0);
+        let mut other = use_signal(|| 0);
+        render_info.track("count", &count());
+        render_info.track("other", &other());
+
+        let RenderInfo {
+            renders,
+            changed_dependencies,
+            ..
+        } = render_info.get();
+
+        rsx!(
+            rect {
+                width: "100%",
+                height: "50%",
+                onclick: move |_| count += 1,
+            }
+            rect {
+                width: "100%",
+                height: "50%",
+                onclick: move |_| other += 1,
+            }
+            label {
+                "{renders}"
+            }
+            label {
+                "{changed_dependencies:?}"
+            }
+        )
+    }
+
+    let mut utils = launch_test(use_render_info_app);
+    utils.wait_for_update().await;
+
+    let root = utils.root();
+    assert_eq!(root.get(2).get(0).text(), Some("0"));
+    assert_eq!(root.get(3).get(0).text(), Some("[]"));
+
+    // The metrics shown are from the previous render
+    utils.click_cursor((5., 5.)).await;
+    assert_eq!(root.get(2).get(0).text(), Some("1"));
+    assert_eq!(root.get(3).get(0).text(), Some("[]"));
+
+    utils.click_cursor((5., 300.)).await;
+    assert_eq!(root.get(2).get(0).text(), Some("2"));
+    assert_eq!(root.get(3).get(0).text(), Some("[\"count\"]"));
+
+    utils.click_cursor((5., 300.)).await;
+    assert_eq!(root.get(2).get(0).text(), Some("3"));
+    assert_eq!(root.get(3).get(0).text(), Some("[\"other\"]"));
+}
+
+#[tokio::test]
+pub async fn use_render_info_reports_every_instance() {
+    #[component]
+    fn Counter() -> Element {
+        let render_info = use_render_info();
+
+        rsx!(rect {
+            width: "100%",
+            height: "100",
+            label {
+                "{render_info.get().renders}"
+            }
+        })
+    }
+
+    fn use_render_info_app() -> Element {
+        let metrics = use_context_provider(RenderMetrics::default);
+        let mut refresh = use_signal(|| 0);
+        let mut show = use_signal(|| true);
+        refresh.read();
+
+        rsx!(
+            rect {
+                width: "100%",
+                height: "100",
+                onclick: move |_| refresh += 1,
+                label {
+                    "{metrics.snapshot().len()}"
+                }
+            }
+            rect {
+                width: "100%",
+                height: "100",
+                onclick: move |_| show.toggle(),
+            }
+            Counter {}
+            if show() {
+                Counter {}
+            }
+        )
+    }
+
+    let mut utils = launch_test(use_render_info_app);
+    utils.wait_for_update().await;
+
+    let root = utils.root();
+
+    // Both instances of the same component are reported
+    utils.click_cursor((5., 5.)).await;
+    assert_eq!(root.get(0).get(0).get(0).text(), Some("2"));
+
+    // And the dropped instance is forgotten
+    utils.click_cursor((5., 105.)).await;
+    utils.click_cursor((5., 5.)).await;
+    assert_eq!(root.get(0).get(0).get(0).text(), Some("1"));
+}
@@ -0,0 +1,114 @@
+use freya::prelude::*;
+use freya_testing::prelude::*;
+
+fn use_selection_app() -> Element {
+    let items = use_signal(|| (0..5).collect::<Vec<usize>>());
+    let mut changes = use_signal(|| 0);
+    let mut selection = use_selection_with_onchange(SelectionMode::Multiple, move |_| {
+        changes += 1;
+    });
+    let selected = selection.selected();
+
+    rsx!(
+        rect {
+            width: "100%",
+            onglobalkeydown: move |e| {
+                selection.keydown(&e.data, &items.read());
+            },
+            for item in items() {
+                rect {
+                    key: "{item}",
+                    width: "100%",
+                    height: "20",
+                    onclick: move |_| selection.click(item, &items.read()),
+                }
+            }
+        }
+        label {
+            "{selected:?} {changes}"
+        }
+    )
+}
+
+fn press_key(utils: &mut TestingHandler<()>, key: Key, code: Code, modifiers: Modifiers) {
+    utils.push_event(TestEvent::Keyboard {
+        name: EventName::KeyDown,
+        key,
+        code,
+        modifiers,
+    });
+}
+
+#[tokio::test]
+pub async fn use_selection_with_mouse() {
+    let mut utils = launch_test(use_selection_app);
+    utils.wait_for_update().await;
+    let label = utils.root().get(1);
+
+    utils.click_cursor((5., 25.)).await;
+    assert_eq!(label.get(0).text(), Some("[1] 1"));
+
+    // Shift selects a range
+    press_key(&mut utils, Key::Shift, Code::ShiftLeft, Modifiers::SHIFT);
+    utils.click_cursor((5., 65.)).await;
+    assert_eq!(label.get(0).text(), Some("[1, 2, 3] 2"));
+
+    // Ctrl toggles a single item
+    press_key(
+        &mut utils,
+        Key::Control,
+        Code::ControlLeft,
+        Modifiers::CONTROL,
+    );
+    utils.click_cursor((5., 45.)).await;
+    assert_eq!(label.get(0).text(), Some("[1, 3] 3"));
+
+    // Without modifiers only the clicked item is selected
+    press_key(&mut utils, Key::Enter, Code::Enter, Modifiers::empty());
+    utils.click_cursor((5., 5.)).await;
+    assert_eq!(label.get(0).text(), Some("[0] 4"));
+}
+
+#[tokio::test]
+pub async fn use_selection_with_keyboard() {
+    let mut utils = launch_test(use_selection_app);
+    utils.wait_for_update().await;
+    let label = utils.root().get(1);
+
+    press_key(
+        &mut utils,
+        Key::ArrowDown,
+        Code::ArrowDown,
+        Modifiers::empty(),
+    );
+    utils.wait_for_update().await;
+    assert_eq!(label.get(0).text(), Some("[0] 1"));
+
+    press_key(
+        &mut utils,
+        Key::ArrowDown,
+        Code::ArrowDown,
+        Modifiers::SHIFT,
+    );
+    press_key(
+        &mut utils,
+        Key::ArrowDown,
+        Code::ArrowDown,
+        Modifiers::SHIFT,
+    );
+    utils.wait_for_update().await;
+    assert_eq!(label.get(0).text(), Some("[0, 1, 2] 3"));
+
+    press_key(
+        &mut utils,
+        Key::Character("a".to_string()),
+        Code::KeyA,
+        Modifiers::CONTROL,
+    );
+    utils.wait_for_update().await;
+    assert_eq!(label.get(0).text(), Some("[0, 1, 2, 3, 4] 4"));
+
+    press_key(&mut utils, Key::Escape, Code::Escape, Modifiers::empty());
+    utils.wait_for_update().await;
+    assert_eq!(label.get(0).text(), Some("[] 5"));
+}
@@ -0,0 +1,95 @@
+use freya::prelude::*;
+use freya_engine::prelude::Color;
+use freya_testing::prelude::*;
+
+#[test]
+pub fn style_variants() {
+    let style = Style::new()
+        .set("background", "red")
+        .set("padding", "10")
+        .hover(Style::new().set("background", "blue"))
+        .disabled(Style::new().set("background", "gray"));
+
+    assert_eq!(
+        style.get("background", InteractionState::default()),
+        Some("red")
+    );
+    assert_eq!(
+        style.get(
+            "background",
+            InteractionState {
+                hovered: true,
+                ..Default::default()
+            }
+        ),
+        Some("blue")
+    );
+    assert_eq!(
+        style.get(
+            "background",
+            InteractionState {
+                hovered: true,
+                disabled: true,
+                ..Default::default()
+            }
+        ),
+        Some("gray")
+    );
+    assert_eq!(
+        style.get(
+            "padding",
+            InteractionState {
+                hovered: true,
+                ..Default::default()
+            }
+        ),
+        Some("10")
+    );
+}
+
+#[tokio::test]
+pub async fn use_style_from_sheet() {
+    fn style_app() -> Element {
+        use_init_theme(|| DARK_THEME);
+        use_init_style_sheet(|| {
+            StyleSheet::new().with(
+                "box",
+                Style::new()
+                    .set("width", "100")
+                    .set("height", "50")
+                    .set("background", "red")
+                    .hover(Style::new().set("background", "key(primary_accent)")),
+            )
+        });
+
+        rsx!(StyledBox {})
+    }
+
+    #[allow(non_snake_case)]
+    fn StyledBox() -> Element {
+        let style = use_style("box");
+        let mut hovered = use_signal(|| false);
+
+        rsx!(rect {
+            onmouseenter: move |_| hovered.set(true),
+            ..style.read().attributes(InteractionState {
+                hovered: hovered(),
+                ..Default::default()
+            })
+        })
+    }
+
+    let mut utils = launch_test(style_app);
+
+    utils.wait_for_update().await;
+    let rect = utils.root().get(0);
+    assert_eq!(rect.area().unwrap().size, (100., 50.).into());
+    assert_eq!(rect.style().background, Fill::Color(Color::RED));
+
+    utils.move_cursor((5., 5.)).await;
+    utils.wait_for_update().await;
+    assert_eq!(
+        rect.style().background,
+        Fill::Color(Color::from_rgb(103, 80, 164))
+    );
+}
@@ -0,0 +1,45 @@
+use freya::prelude::*;
+use freya_testing::prelude::*;
+
+#[tokio::test]
+pub async fn text_scale_factor_relayouts_text() {
+    fn text_scale_app() -> Element {
+        let platform = use_platform();
+        let text_scale_factor = use_text_scale_factor();
+
+        rsx!(
+            rect {
+                width: "100%",
+                height: "50%",
+                onclick: move |_| platform.set_text_scale_factor(2.0),
+            }
+            label {
+                width: "auto",
+                font_size: "16",
+                "{text_scale_factor().0}"
+            }
+            rect {
+                width: "50",
+                height: "50"
+            }
+        )
+    }
+
+    let mut utils = launch_test(text_scale_app);
+    utils.wait_for_update().await;
+
+    let root = utils.root();
+    let label = root.get(1);
+    let square = root.get(2);
+    assert_eq!(label.get(0).text(), Some("1"));
+    let initial_height = label.layout().unwrap().area.height();
+
+    utils.click_cursor((5.0, 5.0)).await;
+    utils.wait_for_update().await;
+    utils.wait_for_update().await;
+
+    assert_eq!(label.get(0).text(), Some("2"));
+    assert!(label.layout().unwrap().area.height() > initial_height * 1.5);
+    // Only the text is scaled
+    assert_eq!(square.layout().unwrap().area.width(), 50.0);
+}
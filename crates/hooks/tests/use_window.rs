@@ -0,0 +1,34 @@
+use freya::prelude::*;
+use freya_core::event_loop_messages::WindowAction;
+use freya_testing::prelude::*;
+
+#[tokio::test]
+pub async fn use_window_sends_actions() {
+    fn use_window_app() -> Element {
+        let window = use_window();
+
+        rsx!(rect {
+            width: "100%",
+            height: "100%",
+            onclick: move |_| {
+                window.set_title("Hello");
+                window.set_size(400., 300.);
+                window.set_always_on_top(true);
+            }
+        })
+    }
+
+    let mut utils = launch_test(use_window_app);
+    utils.wait_for_update().await;
+    assert!(utils.window_actions().is_empty());
+
+    utils.click_cursor((5., 5.)).await;
+    assert_eq!(
+        utils.window_actions(),
+        &[
+            WindowAction::SetTitle("Hello".to_string()),
+            WindowAction::SetSize(400., 300.),
+            WindowAction::SetAlwaysOnTop(true),
+        ]
+    );
+}
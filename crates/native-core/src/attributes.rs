@@ -1,5 +1,18 @@
 use std::str::FromStr;
 
+use parking_lot::RwLock;
+
+/// Attributes registered with [`register_custom_attribute`].
+static CUSTOM_ATTRIBUTES: RwLock<Vec<&'static str>> = RwLock::new(Vec::new());
+
+/// Register a new attribute so it can be used in the DOM, it will be parsed as [`AttributeName::Custom`].
+pub fn register_custom_attribute(attribute: &'static str) {
+    let mut custom_attributes = CUSTOM_ATTRIBUTES.write();
+    if !custom_attributes.contains(&attribute) {
+        custom_attributes.push(attribute);
+    }
+}
+
 #[derive(Clone, Copy, PartialEq, Debug, Hash, Eq)]
 pub enum AttributeName {
     Width,
@@ -28,17 +41,21 @@ pub enum AttributeName {
     FontWidth,
     MainAlign,
     CrossAlign,
+    AlignSelf,
     TextAlign,
     TextShadow,
     MaxLines,
     LineHeight,
     LetterSpacing,
     WordSpacing,
+    FontFeatures,
     Decoration,
     DecorationColor,
     DecorationStyle,
     TextOverflow,
+    TextWrap,
     TextHeight,
+    TextDirection,
     Rotate,
     Overflow,
     Margin,
@@ -51,10 +68,16 @@ pub enum AttributeName {
     Content,
     CanvasReference,
     Layer,
+    CacheLayer,
+    WindowPortal,
+    Offscreen,
+    Inert,
     OffsetY,
     OffsetX,
     Reference,
+    StyleReference,
     CursorReference,
+    Unmounted,
     CursorIndex,
     CursorColor,
     CursorMode,
@@ -62,14 +85,28 @@ pub enum AttributeName {
     Highlights,
     HighlightColor,
     HighlightMode,
+    Sensitive,
+    InputHint,
+    AutoCapitalize,
+    AutoCorrect,
     ImageReference,
     ImageData,
     SvgData,
     SvgContent,
+    TextGrid,
     Spacing,
+    FlexGrow,
+    FlexShrink,
+    FlexBasis,
+    GridTemplateColumns,
+    GridTemplateRows,
+    GridColumn,
+    GridRow,
+    WrapContent,
     BlendMode,
     BackdropBlur,
     Scale,
+    Cursor,
 
     // Image element
     AspectRatio,
@@ -81,6 +118,7 @@ pub enum AttributeName {
     A11yId,
     A11yFocusable,
     A11yAutoFocus,
+    AutoFocus,
 
     // Some internal notes about these accessibility attributes:
     //
@@ -228,6 +266,8 @@ pub enum AttributeName {
 
     // TODO: Some way to specify builtin AccessKit actions, as well as a way to
     //       handle actions in the form of an event.
+    /// Attribute registered with [`register_custom_attribute`].
+    Custom(&'static str),
 }
 
 impl FromStr for AttributeName {
@@ -261,17 +301,21 @@ impl FromStr for AttributeName {
             "font_width" => Ok(AttributeName::FontWidth),
             "main_align" => Ok(AttributeName::MainAlign),
             "cross_align" => Ok(AttributeName::CrossAlign),
+            "align_self" => Ok(AttributeName::AlignSelf),
             "text_align" => Ok(AttributeName::TextAlign),
             "text_shadow" => Ok(AttributeName::TextShadow),
             "max_lines" => Ok(AttributeName::MaxLines),
             "line_height" => Ok(AttributeName::LineHeight),
             "letter_spacing" => Ok(AttributeName::LetterSpacing),
             "word_spacing" => Ok(AttributeName::WordSpacing),
+            "font_features" => Ok(AttributeName::FontFeatures),
             "decoration" => Ok(AttributeName::Decoration),
             "decoration_color" => Ok(AttributeName::DecorationColor),
             "decoration_style" => Ok(AttributeName::DecorationStyle),
             "text_overflow" => Ok(AttributeName::TextOverflow),
+            "text_wrap" => Ok(AttributeName::TextWrap),
             "text_height" => Ok(AttributeName::TextHeight),
+            "text_direction" => Ok(AttributeName::TextDirection),
             "rotate" => Ok(AttributeName::Rotate),
             "overflow" => Ok(AttributeName::Overflow),
             "margin" => Ok(AttributeName::Margin),
@@ -284,10 +328,16 @@ impl FromStr for AttributeName {
             "content" => Ok(AttributeName::Content),
             "canvas_reference" => Ok(AttributeName::CanvasReference),
             "layer" => Ok(AttributeName::Layer),
+            "cache_layer" => Ok(AttributeName::CacheLayer),
+            "window_portal" => Ok(AttributeName::WindowPortal),
+            "offscreen" => Ok(AttributeName::Offscreen),
+            "inert" => Ok(AttributeName::Inert),
             "offset_y" => Ok(AttributeName::OffsetY),
             "offset_x" => Ok(AttributeName::OffsetX),
             "reference" => Ok(AttributeName::Reference),
+            "style_reference" => Ok(AttributeName::StyleReference),
             "cursor_reference" => Ok(AttributeName::CursorReference),
+            "unmounted" => Ok(AttributeName::Unmounted),
             "cursor_index" => Ok(AttributeName::CursorIndex),
             "cursor_color" => Ok(AttributeName::CursorColor),
             "cursor_mode" => Ok(AttributeName::CursorMode),
@@ -295,14 +345,28 @@ impl FromStr for AttributeName {
             "highlights" => Ok(AttributeName::Highlights),
             "highlight_color" => Ok(AttributeName::HighlightColor),
             "highlight_mode" => Ok(AttributeName::HighlightMode),
+            "sensitive" => Ok(AttributeName::Sensitive),
+            "input_hint" => Ok(AttributeName::InputHint),
+            "autocapitalize" => Ok(AttributeName::AutoCapitalize),
+            "autocorrect" => Ok(AttributeName::AutoCorrect),
             "image_reference" => Ok(AttributeName::ImageReference),
             "image_data" => Ok(AttributeName::ImageData),
             "svg_data" => Ok(AttributeName::SvgData),
             "svg_content" => Ok(AttributeName::SvgContent),
+            "text_grid" => Ok(AttributeName::TextGrid),
             "spacing" => Ok(AttributeName::Spacing),
+            "flex_grow" => Ok(AttributeName::FlexGrow),
+            "flex_shrink" => Ok(AttributeName::FlexShrink),
+            "flex_basis" => Ok(AttributeName::FlexBasis),
+            "grid_template_columns" => Ok(AttributeName::GridTemplateColumns),
+            "grid_template_rows" => Ok(AttributeName::GridTemplateRows),
+            "grid_column" => Ok(AttributeName::GridColumn),
+            "grid_row" => Ok(AttributeName::GridRow),
+            "wrap_content" => Ok(AttributeName::WrapContent),
             "blend_mode" => Ok(AttributeName::BlendMode),
             "backdrop_blur" => Ok(AttributeName::BackdropBlur),
             "scale" => Ok(AttributeName::Scale),
+            "cursor" => Ok(AttributeName::Cursor),
             "aspect_ratio" => Ok(AttributeName::AspectRatio),
             "cover" => Ok(AttributeName::ImageCover),
             "cache_key" => Ok(AttributeName::ImageCacheKey),
@@ -310,6 +374,7 @@ impl FromStr for AttributeName {
             "a11y_id" => Ok(AttributeName::A11yId),
             "a11y_focusable" => Ok(AttributeName::A11yFocusable),
             "a11y_auto_focus" => Ok(AttributeName::A11yAutoFocus),
+            "autofocus" => Ok(AttributeName::AutoFocus),
             "a11y_name" => Ok(AttributeName::A11yName),
             "a11y_description" => Ok(AttributeName::A11yDescription),
             "a11y_value" => Ok(AttributeName::A11yValue),
@@ -374,7 +439,13 @@ impl FromStr for AttributeName {
             "a11y_list_style" => Ok(AttributeName::A11yListStyle),
             "a11y_vertical_offset" => Ok(AttributeName::A11yVerticalOffset),
             "a11y_member_of" => Ok(AttributeName::A11yMemberOf),
-            _ => Err(format!("{attr} not supported.")),
+            _ => CUSTOM_ATTRIBUTES
+                .read()
+                .iter()
+                .copied()
+                .find(|custom_attribute| *custom_attribute == attr)
+                .map(AttributeName::Custom)
+                .ok_or_else(|| format!("{attr} not supported.")),
         }
     }
 }
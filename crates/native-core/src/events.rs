@@ -7,6 +7,8 @@ pub enum EventName {
     Click,
     MiddleClick,
     RightClick,
+    DoubleClick,
+    ContextMenu,
 
     MouseUp,
     MouseDown,
@@ -40,6 +42,12 @@ pub enum EventName {
     GlobalFileHoverCancelled,
 
     FileDrop,
+    FileHover,
+
+    Mounted,
+
+    Focus,
+    Blur,
 }
 
 impl FromStr for EventName {
@@ -50,6 +58,8 @@ impl FromStr for EventName {
             "click" => Ok(EventName::Click),
             "rightclick" => Ok(EventName::RightClick),
             "middleclick" => Ok(EventName::MiddleClick),
+            "doubleclick" => Ok(EventName::DoubleClick),
+            "contextmenu" => Ok(EventName::ContextMenu),
             "mouseup" => Ok(EventName::MouseUp),
             "mousedown" => Ok(EventName::MouseDown),
             "mousemove" => Ok(EventName::MouseMove),
@@ -74,8 +84,12 @@ impl FromStr for EventName {
             "globalmousedown" => Ok(EventName::GlobalMouseDown),
             "globalmousemove" => Ok(EventName::GlobalMouseMove),
             "filedrop" => Ok(EventName::FileDrop),
+            "filehover" => Ok(EventName::FileHover),
             "globalfilehover" => Ok(EventName::GlobalFileHover),
             "globalfilehovercancelled" => Ok(EventName::GlobalFileHoverCancelled),
+            "mounted" => Ok(EventName::Mounted),
+            "focus" => Ok(EventName::Focus),
+            "blur" => Ok(EventName::Blur),
             _ => Err(()),
         }
     }
@@ -87,6 +101,8 @@ impl From<EventName> for &str {
             EventName::Click => "click",
             EventName::MiddleClick => "middleclick",
             EventName::RightClick => "rightclick",
+            EventName::DoubleClick => "doubleclick",
+            EventName::ContextMenu => "contextmenu",
             EventName::MouseUp => "mouseup",
             EventName::MouseDown => "mousedown",
             EventName::MouseMove => "mousemove",
@@ -111,8 +127,12 @@ impl From<EventName> for &str {
             EventName::GlobalMouseDown => "globalmousedown",
             EventName::GlobalMouseMove => "globalmousemove",
             EventName::FileDrop => "filedrop",
+            EventName::FileHover => "filehover",
             EventName::GlobalFileHover => "globalfilehover",
             EventName::GlobalFileHoverCancelled => "globalfilehovercancelled",
+            EventName::Mounted => "mounted",
+            EventName::Focus => "focus",
+            EventName::Blur => "blur",
         }
     }
 }
@@ -161,6 +181,7 @@ impl EventName {
     /// Some events might cause other events, like for example:
     /// A `mousemove` might also trigger a `mouseenter`
     /// A `mousedown` or a `touchdown` might also trigger a `pointerdown`
+    /// A `rightclick` might also trigger a `contextmenu`
     pub fn get_derived_events(&self) -> SmallVec<[Self; 4]> {
         let mut events = SmallVec::new();
 
@@ -172,10 +193,12 @@ impl EventName {
             }
             Self::TouchMove => events.extend([Self::PointerEnter, Self::PointerOver]),
             Self::MouseDown | Self::TouchStart => events.push(Self::PointerDown),
-            Self::MouseUp | Self::MiddleClick | Self::RightClick | Self::TouchEnd => {
+            Self::MouseUp | Self::MiddleClick | Self::TouchEnd => {
                 events.extend([Self::Click, Self::PointerUp])
             }
+            Self::RightClick => events.extend([Self::Click, Self::PointerUp, Self::ContextMenu]),
             Self::MouseLeave => events.push(Self::PointerLeave),
+            Self::GlobalFileHover => events.push(Self::FileHover),
             _ => {}
         }
 
@@ -230,8 +253,13 @@ impl EventName {
     // Bubble all events except:
     // - Global events
     // - Mouse movements events
+    // - Lifecycle events
+    // - Focus events
     pub fn does_bubble(&self) -> bool {
-        !self.is_moved() && !self.is_left() && !self.is_global()
+        !self.is_moved()
+            && !self.is_left()
+            && !self.is_global()
+            && !matches!(self, Self::Mounted | Self::Focus | Self::Blur)
     }
 
     /// Only let events that do not move the mouse, go through solid nodes
@@ -3,6 +3,19 @@ use std::{
     str::FromStr,
 };
 
+use parking_lot::RwLock;
+
+/// Tags registered with [`register_custom_tag`].
+static CUSTOM_TAGS: RwLock<Vec<&'static str>> = RwLock::new(Vec::new());
+
+/// Register a new element tag so it can be used in the DOM, it will be parsed as [`TagName::Custom`].
+pub fn register_custom_tag(tag: &'static str) {
+    let mut custom_tags = CUSTOM_TAGS.write();
+    if !custom_tags.contains(&tag) {
+        custom_tags.push(tag);
+    }
+}
+
 #[derive(Clone, Copy, PartialEq, Debug, Hash)]
 pub enum TagName {
     Root,
@@ -12,6 +25,9 @@ pub enum TagName {
     Text,
     Image,
     Svg,
+    TextGrid,
+    /// Element registered with [`register_custom_tag`].
+    Custom(&'static str),
 }
 
 impl TagName {
@@ -39,7 +55,14 @@ impl FromStr for TagName {
             "text" => Ok(TagName::Text),
             "image" => Ok(TagName::Image),
             "svg" => Ok(TagName::Svg),
-            _ => Err(()),
+            "textgrid" => Ok(TagName::TextGrid),
+            _ => CUSTOM_TAGS
+                .read()
+                .iter()
+                .copied()
+                .find(|tag| *tag == txt)
+                .map(TagName::Custom)
+                .ok_or(()),
         }
     }
 }
@@ -54,6 +77,8 @@ impl Display for TagName {
             TagName::Text => f.write_str("text"),
             TagName::Image => f.write_str("img"),
             TagName::Svg => f.write_str("svg"),
+            TagName::TextGrid => f.write_str("textgrid"),
+            TagName::Custom(tag) => f.write_str(tag),
         }
     }
 }
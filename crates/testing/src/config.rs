@@ -1,5 +1,6 @@
 use std::time::Duration;
 
+use freya_core::platform_state::LayoutDirection;
 use torin::geometry::Size2D;
 
 /// Configuration for [`crate::test_handler::TestingHandler`].
@@ -9,6 +10,8 @@ pub struct TestingConfig<T: 'static + Clone> {
     pub size: Size2D,
     pub event_loop_ticker: bool,
     pub state: Option<T>,
+    pub text_scale_factor: f32,
+    pub layout_direction: LayoutDirection,
 }
 
 impl<T: 'static + Clone> Default for TestingConfig<T> {
@@ -18,6 +21,8 @@ impl<T: 'static + Clone> Default for TestingConfig<T> {
             size: Size2D::from((500.0, 500.0)),
             event_loop_ticker: true,
             state: None,
+            text_scale_factor: 1.0,
+            layout_direction: LayoutDirection::default(),
         }
     }
 }
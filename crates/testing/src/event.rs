@@ -9,9 +9,12 @@ use freya_elements::events::{
     Code,
     Force,
     Key,
+    Location,
     Modifiers,
     MouseButton,
     TouchPhase,
+    WheelDeltaMode,
+    WheelPhase,
 };
 use torin::prelude::CursorPoint;
 
@@ -28,6 +31,13 @@ pub enum TestEvent {
         scroll: CursorPoint,
         cursor: CursorPoint,
     },
+    /// A Wheel event of a trackpad gesture, its momentum is detected as in a real app.
+    TrackpadWheel {
+        name: EventName,
+        scroll: CursorPoint,
+        cursor: CursorPoint,
+        phase: WheelPhase,
+    },
     /// A Keyboard event.
     Keyboard {
         name: EventName,
@@ -70,6 +80,8 @@ impl From<TestEvent> for PlatformEvent {
                     key,
                     code,
                     modifiers,
+                    repeat: false,
+                    location: Location::Standard,
                 },
             ),
             TestEvent::Mouse {
@@ -81,7 +93,33 @@ impl From<TestEvent> for PlatformEvent {
                 name,
                 scroll,
                 cursor,
-            } => (name, PlatformEventData::Wheel { scroll, cursor }),
+            } => (
+                name,
+                PlatformEventData::Wheel {
+                    scroll,
+                    cursor,
+                    raw_scroll: scroll,
+                    delta_mode: WheelDeltaMode::Pixel,
+                    phase: WheelPhase::Moved,
+                    momentum: false,
+                },
+            ),
+            TestEvent::TrackpadWheel {
+                name,
+                scroll,
+                cursor,
+                phase,
+            } => (
+                name,
+                PlatformEventData::Wheel {
+                    scroll,
+                    cursor,
+                    raw_scroll: scroll,
+                    delta_mode: WheelDeltaMode::Pixel,
+                    phase,
+                    momentum: false,
+                },
+            ),
             TestEvent::Touch {
                 name,
                 location,
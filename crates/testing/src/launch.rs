@@ -1,6 +1,7 @@
 use std::{
     future::Future,
     path::PathBuf,
+    time::Instant,
 };
 
 use accesskit::{
@@ -34,7 +35,13 @@ use freya_core::{
         SafeDOM,
     },
     event_loop_messages::EventLoopMessage,
-    events::NodesState,
+    events::{
+        ClickTracker,
+        KeyCapture,
+        NodesState,
+        ShortcutRegistry,
+        WheelTracker,
+    },
     platform::CursorIcon,
     platform_state::{
         NativePlatformState,
@@ -44,7 +51,10 @@ use freya_core::{
     },
     types::EventsQueue,
 };
-use freya_elements as dioxus_elements;
+use freya_elements::{
+    self as dioxus_elements,
+    events::keyboard::Modifiers,
+};
 use freya_engine::prelude::*;
 use tokio::{
     runtime::Runtime,
@@ -112,14 +122,18 @@ pub fn launch_test_with_config<T: 'static + Clone>(
     let (platform_sender, platform_receiver) = watch::channel(NativePlatformState {
         focused_accessibility_id: ACCESSIBILITY_ROOT_ID,
         focused_accessibility_node: Node::new(Role::Window),
+        focus_history: Vec::new(),
         preferred_theme: PreferredTheme::default(),
         navigation_mode: NavigationMode::default(),
         information: PlatformInformation::new(config.size, false, false, false),
         scale_factor: SCALE_FACTOR,
+        text_scale_factor: config.text_scale_factor,
+        layout_direction: config.layout_direction,
     });
     let mut font_collection = FontCollection::new();
     let font_mgr = FontMgr::default();
-    font_collection.set_dynamic_font_manager(font_mgr.clone());
+    let font_provider = TypefaceFontProvider::new();
+    font_collection.set_dynamic_font_manager(FontMgr::from(font_provider.clone()));
     font_collection.set_default_font_manager(font_mgr.clone(), None);
 
     let mut handler = TestingHandler {
@@ -127,6 +141,7 @@ pub fn launch_test_with_config<T: 'static + Clone>(
         events_queue: EventsQueue::new(),
         nodes_state: NodesState::default(),
         font_collection,
+        font_provider,
         font_mgr,
         event_emitter,
         event_receiver,
@@ -136,7 +151,16 @@ pub fn launch_test_with_config<T: 'static + Clone>(
         platform_event_receiver,
         accessibility_tree: AccessibilityTree::new(ACCESSIBILITY_ROOT_ID),
         ticker_sender: broadcast::channel(5).0,
+        activity_sender: watch::channel(Instant::now()).0,
+        modifiers_sender: watch::channel(Modifiers::empty()).0,
+        key_capture: KeyCapture::default(),
+        shortcuts: ShortcutRegistry::default(),
+        click_tracker: ClickTracker::default(),
+        wheel_tracker: WheelTracker::default(),
         cursor_icon: CursorIcon::default(),
+        window_actions: Vec::new(),
+        clipboard_text: None,
+        clipboard_image: None,
         platform_sender,
         platform_receiver,
     };
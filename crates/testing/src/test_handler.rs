@@ -3,7 +3,10 @@ use std::{
     io::Write,
     path::PathBuf,
     sync::Arc,
-    time::Duration,
+    time::{
+        Duration,
+        Instant,
+    },
 };
 
 use accesskit::NodeId as AccessibilityId;
@@ -14,15 +17,30 @@ use dioxus_core::{
 use freya_core::{
     accessibility::AccessibilityTree,
     dom::SafeDOM,
-    event_loop_messages::EventLoopMessage,
+    event_loop_messages::{
+        EventLoopMessage,
+        WindowAction,
+    },
     events::{
         process_events,
+        process_focus_events,
+        process_mounted_events,
+        ClickTracker,
+        DefaultPrevented,
         EventName,
+        KeyCapture,
         NodesState,
         PlatformEvent,
         PlatformEventData,
+        ShortcutRegistry,
+        WheelTracker,
     },
     layout::process_layout,
+    platform::{
+        ClipboardError,
+        ClipboardImage,
+        ClipboardRequest,
+    },
     render::{
         Compositor,
         RenderPipeline,
@@ -33,8 +51,10 @@ use freya_core::{
         EventEmitter,
         EventReceiver,
         EventsQueue,
+        ModifiersSender,
         NativePlatformReceiver,
         NativePlatformSender,
+        UserActivitySender,
     },
 };
 use freya_engine::prelude::{
@@ -44,6 +64,7 @@ use freya_engine::prelude::{
     EncodedImageFormat,
     FontCollection,
     FontMgr,
+    TypefaceFontProvider,
 };
 use freya_native_core::{
     dioxus::NodeImmutableDioxusExt,
@@ -94,11 +115,21 @@ pub struct TestingHandler<T: 'static + Clone> {
     pub(crate) platform_sender: NativePlatformSender,
     pub(crate) platform_receiver: NativePlatformReceiver,
     pub(crate) font_collection: FontCollection,
+    pub(crate) font_provider: TypefaceFontProvider,
     pub(crate) font_mgr: FontMgr,
     pub(crate) accessibility_tree: AccessibilityTree,
     pub(crate) config: TestingConfig<T>,
     pub(crate) ticker_sender: broadcast::Sender<()>,
+    pub(crate) activity_sender: UserActivitySender,
+    pub(crate) modifiers_sender: ModifiersSender,
+    pub(crate) key_capture: KeyCapture,
+    pub(crate) shortcuts: ShortcutRegistry,
+    pub(crate) click_tracker: ClickTracker,
+    pub(crate) wheel_tracker: WheelTracker,
     pub(crate) cursor_icon: CursorIcon,
+    pub(crate) window_actions: Vec<WindowAction>,
+    pub(crate) clipboard_text: Option<String>,
+    pub(crate) clipboard_image: Option<ClipboardImage>,
 }
 
 impl<T: 'static + Clone> TestingHandler<T> {
@@ -113,6 +144,14 @@ impl<T: 'static + Clone> TestingHandler<T> {
             .insert_any_root_context(Box::new(self.platform_receiver.clone()));
         self.vdom
             .insert_any_root_context(Box::new(Arc::new(self.ticker_sender.subscribe())));
+        self.vdom
+            .insert_any_root_context(Box::new(self.activity_sender.subscribe()));
+        self.vdom
+            .insert_any_root_context(Box::new(self.modifiers_sender.subscribe()));
+        self.vdom
+            .insert_any_root_context(Box::new(self.key_capture.clone()));
+        self.vdom
+            .insert_any_root_context(Box::new(self.shortcuts.clone()));
         self.vdom.insert_any_root_context(Box::new(
             self.utils.sdom.get_mut().accessibility_generator().clone(),
         ));
@@ -132,6 +171,22 @@ impl<T: 'static + Clone> TestingHandler<T> {
         self.cursor_icon
     }
 
+    /// Get the [WindowAction]s sent by the app so far.
+    pub fn window_actions(&self) -> &[WindowAction] {
+        &self.window_actions
+    }
+
+    /// Get the text copied to the in-memory clipboard of the test.
+    pub fn clipboard_text(&self) -> Option<&str> {
+        self.clipboard_text.as_deref()
+    }
+
+    /// Replace the content of the in-memory clipboard of the test, like another app would.
+    pub fn set_clipboard_text(&mut self, text: impl Into<String>) {
+        self.clipboard_text = Some(text.into());
+        self.clipboard_image = None;
+    }
+
     /// Get the [SafeDOM].
     pub fn sdom(&self) -> &SafeDOM {
         self.utils.sdom()
@@ -142,6 +197,32 @@ impl<T: 'static + Clone> TestingHandler<T> {
         self.accessibility_tree.focused_id
     }
 
+    /// Process a clipboard request with the in-memory clipboard of the test.
+    fn handle_clipboard(&mut self, request: ClipboardRequest) {
+        match request {
+            ClipboardRequest::GetText(sender) => {
+                sender
+                    .send(self.clipboard_text.clone().ok_or(ClipboardError::Empty))
+                    .ok();
+            }
+            ClipboardRequest::SetText(text, sender) => {
+                self.clipboard_text = Some(text);
+                self.clipboard_image = None;
+                sender.send(Ok(())).ok();
+            }
+            ClipboardRequest::GetImage(sender) => {
+                sender
+                    .send(self.clipboard_image.clone().ok_or(ClipboardError::Empty))
+                    .ok();
+            }
+            ClipboardRequest::SetImage(image, sender) => {
+                self.clipboard_image = Some(image);
+                self.clipboard_text = None;
+                sender.send(Ok(())).ok();
+            }
+        }
+    }
+
     /// Apply the latest changes of the virtual dom.
     pub async fn wait_for_update(&mut self) -> (bool, bool) {
         self.wait_for_work(self.config.size());
@@ -163,7 +244,7 @@ impl<T: 'static + Clone> TestingHandler<T> {
 
             if let Ok(ev) = platform_ev {
                 match ev {
-                    EventLoopMessage::RequestRerender => {
+                    EventLoopMessage::RequestRerender | EventLoopMessage::RequestRedraw => {
                         if let Some(ticker) = ticker.as_mut() {
                             ticker.tick().await;
                             self.ticker_sender.send(()).unwrap();
@@ -181,10 +262,37 @@ impl<T: 'static + Clone> TestingHandler<T> {
                     EventLoopMessage::SetCursorIcon(icon) => {
                         self.cursor_icon = icon;
                     }
+                    EventLoopMessage::WindowAction(action) => {
+                        self.window_actions.push(action);
+                    }
+                    EventLoopMessage::Clipboard(request) => self.handle_clipboard(request),
                     EventLoopMessage::RemeasureTextGroup(text_measurement) => {
                         let fdom = self.utils.sdom.get();
                         fdom.measure_paragraphs(text_measurement, SCALE_FACTOR);
                     }
+                    EventLoopMessage::RegisterFont(font_name, font_data) => {
+                        if let Some(ft_type) = self.font_mgr.new_from_data(&font_data, None) {
+                            self.font_provider
+                                .register_typeface(ft_type, Some(font_name));
+                            self.font_collection.clear_caches();
+                            self.utils.sdom.get().paragraph_cache().clear();
+                            self.resize(self.config.size);
+                        }
+                    }
+                    EventLoopMessage::SetTextScaleFactor(text_scale_factor) => {
+                        self.config.text_scale_factor = text_scale_factor;
+                        self.platform_sender.send_modify(|state| {
+                            state.text_scale_factor = text_scale_factor;
+                        });
+                        self.resize(self.config.size);
+                    }
+                    EventLoopMessage::SetLayoutDirection(layout_direction) => {
+                        self.config.layout_direction = layout_direction;
+                        self.platform_sender.send_modify(|state| {
+                            state.layout_direction = layout_direction;
+                        });
+                        self.resize(self.config.size);
+                    }
                     _ => {}
                 }
             }
@@ -192,15 +300,25 @@ impl<T: 'static + Clone> TestingHandler<T> {
             if let Ok(events) = vdom_events {
                 let fdom = self.utils.sdom().get();
                 let rdom = fdom.rdom();
-                for event in events {
+                let mut default_prevented = DefaultPrevented::default();
+                for event in DefaultPrevented::sort(events) {
                     if let Some(element_id) =
                         rdom.get(event.node_id).and_then(|node| node.mounted_id())
                     {
-                        let name = event.name.into();
+                        let event_name = event.name;
+                        let name = event_name.into();
                         let data = event.data.any();
                         let event = Event::new(data, event.bubbles);
-                        self.vdom.runtime().handle_event(name, event, element_id);
+                        if default_prevented.is_prevented(event_name) {
+                            event.prevent_default();
+                        }
+                        self.vdom
+                            .runtime()
+                            .handle_event(name, event.clone(), element_id);
                         self.vdom.process_events();
+                        if !event.default_action_enabled() {
+                            default_prevented.insert(event_name);
+                        }
                     }
                 }
             }
@@ -233,9 +351,18 @@ impl<T: 'static + Clone> TestingHandler<T> {
             },
             &mut self.font_collection,
             SCALE_FACTOR as f32,
+            self.config.text_scale_factor,
+            self.config.layout_direction,
             &default_fonts(),
         );
 
+        process_mounted_events(
+            &self.utils.sdom().get(),
+            &self.event_emitter,
+            &mut self.nodes_state,
+            SCALE_FACTOR,
+        );
+
         let fdom = &self.utils.sdom().get_mut();
         {
             let rdom = fdom.rdom();
@@ -250,6 +377,12 @@ impl<T: 'static + Clone> TestingHandler<T> {
             // Notify the components
             self.platform_sender.send_modify(|state| {
                 state.focused_accessibility_id = tree.focus;
+                state.focus_history = self
+                    .accessibility_tree
+                    .focus_history
+                    .iter()
+                    .copied()
+                    .collect();
                 let node_ref = rdom.get(node_id).unwrap();
                 let node_accessibility = node_ref.get::<AccessibilityNodeState>().unwrap();
                 let layout_node = layout.get(node_id).unwrap();
@@ -258,7 +391,14 @@ impl<T: 'static + Clone> TestingHandler<T> {
             });
         }
 
-        process_events(
+        process_focus_events(
+            fdom,
+            &self.event_emitter,
+            &mut self.nodes_state,
+            self.accessibility_tree.focused_node_id(),
+        );
+
+        let cursor_icon = process_events(
             fdom,
             &mut self.events_queue,
             &self.event_emitter,
@@ -266,6 +406,9 @@ impl<T: 'static + Clone> TestingHandler<T> {
             SCALE_FACTOR,
             self.accessibility_tree.focused_node_id(),
         );
+        if let Some(cursor_icon) = cursor_icon {
+            self.cursor_icon = cursor_icon;
+        }
     }
 
     /// Push an event to the events queue
@@ -283,7 +426,18 @@ impl<T: 'static + Clone> TestingHandler<T> {
     ///
     /// For mouse **movements** and **clicks** you can use shortcuts like [TestingHandler::move_cursor] and [TestingHandler::click_cursor].
     pub fn push_event(&mut self, event: impl Into<PlatformEvent>) {
-        self.events_queue.push(event.into());
+        self.activity_sender.send_replace(Instant::now());
+        let mut event = event.into();
+        self.wheel_tracker.track(&mut event);
+        if let PlatformEventData::Keyboard { modifiers, .. } = &event.data {
+            self.modifiers_sender.send_replace(*modifiers);
+        }
+        if self.key_capture.capture(&event) || self.shortcuts.dispatch(&event) {
+            return;
+        }
+        let double_click = self.click_tracker.track(&event, Instant::now());
+        self.events_queue.push(event);
+        self.events_queue.extend(double_click);
     }
 
     /// Get the Root node.
@@ -366,6 +520,7 @@ impl<T: 'static + Clone> TestingHandler<T> {
             font_manager: &self.font_mgr,
             default_fonts: &["Fira Sans".to_string()],
             images_cache: &mut fdom.images_cache(),
+            frame_counters: &mut fdom.frame_counters(),
         };
         render_pipeline.run();
 
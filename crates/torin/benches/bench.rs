@@ -350,5 +350,89 @@ fn criterion_benchmark(c: &mut Criterion) {
     }
 }
 
-criterion_group!(benches, criterion_benchmark);
+fn list_relayout_benchmark(c: &mut Criterion) {
+    let mut g = c.benchmark_group("list relayout");
+
+    for items in [1000, 10000] {
+        for resized in [false, true] {
+            let name = format!("size={items} resized={resized}");
+
+            g.significance_level(0.05).sample_size(100);
+
+            g.bench_function(name, |b| {
+                let root_area = Rect::new(Point2D::new(0.0, 0.0), Size2D::new(1000.0, 1000.0));
+                b.iter_batched(
+                    || {
+                        let mut measurer = None::<NoopMeasurer>;
+                        let mut mocked_dom = TestingDOM::default();
+
+                        // A scrollable list of items, each one with some content
+                        mocked_dom.add(
+                            0,
+                            None,
+                            (1..=items).collect(),
+                            Node::from_size_and_scroll(
+                                Size::Fill,
+                                Size::Fill,
+                                Length::new(0.0),
+                                Length::new(-500.0),
+                            ),
+                        );
+                        for item in 1..=items {
+                            let content = item + items;
+                            mocked_dom.add(
+                                item,
+                                Some(0),
+                                vec![content],
+                                Node::from_size_and_direction(
+                                    Size::Fill,
+                                    Size::Pixels(Length::new(50.0)),
+                                    Direction::Horizontal,
+                                ),
+                            );
+                            mocked_dom.add(
+                                content,
+                                Some(item),
+                                vec![],
+                                Node::from_size_and_direction(
+                                    Size::Pixels(Length::new(100.0)),
+                                    Size::Fill,
+                                    Direction::Vertical,
+                                ),
+                            );
+                        }
+
+                        let mut layout = Torin::<usize>::new();
+                        layout.find_best_root(&mut mocked_dom);
+                        layout.measure(0, root_area, &mut measurer, &mut mocked_dom);
+
+                        // Change an item in the middle of the list
+                        let invalidate_node = items / 2;
+                        let height = if resized { 75.0 } else { 50.0 };
+                        mocked_dom.set_node(
+                            invalidate_node,
+                            Node::from_size_and_direction(
+                                Size::Fill,
+                                Size::Pixels(Length::new(height)),
+                                Direction::Vertical,
+                            ),
+                        );
+                        layout.invalidate(invalidate_node);
+
+                        (mocked_dom, measurer, layout)
+                    },
+                    |(mut mocked_dom, mut measurer, mut layout)| {
+                        layout.find_best_root(&mut mocked_dom);
+                        layout.measure(0, root_area, &mut measurer, &mut mocked_dom);
+                        // Don't measure the time it takes to drop them
+                        (mocked_dom, layout)
+                    },
+                    criterion::BatchSize::LargeInput,
+                )
+            });
+        }
+    }
+}
+
+criterion_group!(benches, criterion_benchmark, list_relayout_benchmark);
 criterion_main!(benches);
@@ -25,6 +25,17 @@ pub trait LayoutMeasurer<Key: NodeKey> {
     fn notify_layout_references(&self, _node_id: Key, _area: Area, _inner_sizes: Size2D) {}
 }
 
+/// Measurer that can be used from multiple threads, see `Torin::measure_parallel`.
+#[cfg(feature = "parallel")]
+pub trait ParallelLayoutMeasurer<Key: NodeKey>: LayoutMeasurer<Key> + Send + Sync + Sized {
+    /// Create a measurer to be used in another thread.
+    #[must_use]
+    fn fork(&self) -> Self;
+
+    /// Merge back a measurer created with [`ParallelLayoutMeasurer::fork`] once its thread is done.
+    fn join(&mut self, _forked: Self) {}
+}
+
 // No-op measurer, use it when you don't need one.
 pub struct NoopMeasurer;
 
@@ -46,3 +57,10 @@ impl LayoutMeasurer<usize> for NoopMeasurer {
         false
     }
 }
+
+#[cfg(feature = "parallel")]
+impl ParallelLayoutMeasurer<usize> for NoopMeasurer {
+    fn fork(&self) -> Self {
+        NoopMeasurer
+    }
+}
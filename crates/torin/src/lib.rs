@@ -13,6 +13,8 @@ pub mod dom_adapter;
 pub mod geometry;
 pub mod measure;
 pub mod node;
+#[cfg(feature = "parallel")]
+mod parallel;
 pub mod scaled;
 pub mod sendanymap;
 pub mod torin;
@@ -1,5 +1,8 @@
 pub use euclid::Rect;
-use rustc_hash::FxHashMap;
+use rustc_hash::{
+    FxHashMap,
+    FxHashSet,
+};
 
 use crate::{
     custom_measurer::LayoutMeasurer,
@@ -10,7 +13,9 @@ use crate::{
     },
     geometry::{
         Area,
+        Point2D,
         Size2D,
+        Vector2D,
     },
     node::Node,
     prelude::{
@@ -19,13 +24,91 @@ use crate::{
         AlignmentDirection,
         AreaModel,
         Direction,
+        GridTrack,
         LayoutMetadata,
         Length,
+        Position,
         Torin,
     },
     size::Size,
+    torin::DirtyReason,
 };
 
+/// Cell of a grid occupied by a Node.
+struct GridCell {
+    column: usize,
+    row: usize,
+    column_span: usize,
+    row_span: usize,
+}
+
+/// Line of a Node that wraps its content.
+struct WrapLine<Key> {
+    /// Stacked children with their position in the main axis and their size in the cross axis.
+    children: Vec<(Key, Node, f32, f32)>,
+    main: f32,
+    cross: f32,
+    cross_offset: f32,
+}
+
+impl<Key> Default for WrapLine<Key> {
+    fn default() -> Self {
+        Self {
+            children: Vec::new(),
+            main: 0.,
+            cross: 0.,
+            cross_offset: 0.,
+        }
+    }
+}
+
+/// Inputs of the measurement of a Node, its layout can be reused as long as these don't change.
+#[derive(Clone, Debug)]
+pub struct MeasureInputs {
+    parent_area: Area,
+    available_parent_area: Area,
+    root_area: Area,
+    width: Size,
+    height: Size,
+    /// Resulting area
+    area: Area,
+    /// Origin of the parent area, relative to the inner area of the parent Node
+    parent_offset: Vector2D,
+    /// Origin of the available parent area, relative to the parent area
+    available_offset: Vector2D,
+    /// Origin of the resulting area, relative to the available parent area
+    area_offset: Vector2D,
+    /// Origin of the resulting inner area, relative to the resulting area
+    inner_offset: Vector2D,
+}
+
+/// Minimum amount of children a Node must have for them to be measured in parallel.
+#[cfg(feature = "parallel")]
+const PARALLEL_MIN_CHILDREN: usize = 16;
+
+/// Node whose children are measured after its siblings, so they can be measured in parallel.
+pub struct DeferredNode<Key: NodeKey> {
+    node_id: Key,
+    node: Node,
+    available_area: Area,
+    area: Area,
+    inner_area: Area,
+    must_cache_children: bool,
+    parent_is_dirty: bool,
+    /// Inner area of the closest scroll container for the children of this Node
+    scroll_viewport: Option<Area>,
+}
+
+impl<Key: NodeKey> DeferredNode<Key> {
+    pub fn node_id(&self) -> Key {
+        self.node_id
+    }
+}
+
+/// Measure the children of the deferred Nodes.
+pub type MeasureDeferred<Key, L, D> =
+    for<'a> fn(&mut MeasureContext<'a, Key, L, D>, Vec<DeferredNode<Key>>);
+
 /// Some layout strategies require two-phase measurements
 /// Example: Alignments or content-fit.
 #[derive(Clone, Copy, PartialEq)]
@@ -44,6 +127,16 @@ where
     pub measurer: &'a mut Option<L>,
     pub dom_adapter: &'a mut D,
     pub layout_metadata: LayoutMetadata,
+    /// Inner area of the closest scroll container, sticky Nodes are kept inside of it
+    pub scroll_viewport: Option<Area>,
+    /// Ancestors of the dirty Nodes
+    pub dirty_ancestors: FxHashSet<Key>,
+    /// Measure the children of the deferred Nodes, nothing is deferred if `None`
+    pub measure_deferred: Option<MeasureDeferred<Key, L, D>>,
+    /// Defer the measurement of the children of the next measured Node
+    pub defer_children: bool,
+    /// Nodes whose children are yet to be measured
+    pub deferred: Vec<DeferredNode<Key>>,
 }
 
 impl<Key, L, D> MeasureContext<'_, Key, L, D>
@@ -71,13 +164,51 @@ where
         // Current phase of measurement
         phase: Phase,
     ) -> (bool, LayoutNode) {
+        let defer_children = std::mem::take(&mut self.defer_children);
+
         // 1. If parent is dirty
         // 2. If this Node has been marked as dirty
         // 3. If there is no know cached data about this Node.
         let must_revalidate = parent_is_dirty
             || self.layout.dirty.contains_key(&node_id)
             || !self.layout.results.contains_key(&node_id);
+
+        // Nodes that haven't changed might be able to reuse their previous layout
+        let is_changed = self
+            .layout
+            .dirty
+            .get(&node_id)
+            .is_some_and(DirtyReason::is_changed);
+        if must_revalidate && !is_changed && phase == Phase::Final {
+            if let Some(layout_node) = self.reuse_node(
+                node_id,
+                node,
+                parent_area,
+                available_parent_area,
+                must_cache_children,
+            ) {
+                return (false, layout_node);
+            }
+        }
+
         if must_revalidate {
+            let must_save_inputs = phase == Phase::Final && must_cache_children;
+            let inputs = must_save_inputs.then(|| MeasureInputs {
+                parent_area: *parent_area,
+                available_parent_area: *available_parent_area,
+                root_area: self.layout_metadata.root_area,
+                width: node.width.clone(),
+                height: node.height.clone(),
+                area: Area::default(),
+                parent_offset: Vector2D::zero(),
+                available_offset: available_parent_area.origin - parent_area.origin,
+                area_offset: Vector2D::zero(),
+                inner_offset: Vector2D::zero(),
+            });
+            if must_save_inputs {
+                self.layout.pinned.remove(&node_id);
+            }
+
             // Create the initial Node area size
             let mut area_size = Size2D::new(node.padding.horizontal(), node.padding.vertical());
 
@@ -105,6 +236,50 @@ where
                 phase,
             );
 
+            // Derive the auto-sized dimension from the other one using the aspect ratio
+            let ratio_node;
+            let node = match node.aspect_ratio {
+                Some(aspect_ratio) if node.height.inner_sized() && !node.width.inner_sized() => {
+                    let width = area_size.width - node.margin.horizontal();
+                    ratio_node = Node {
+                        height: Size::Pixels(Length::new(width / aspect_ratio)),
+                        ..node.clone()
+                    };
+                    area_size.height = ratio_node.height.min_max(
+                        area_size.height,
+                        parent_area.size.height,
+                        available_parent_area.size.height,
+                        node.margin.top(),
+                        node.margin.vertical(),
+                        &node.minimum_height,
+                        &node.maximum_height,
+                        self.layout_metadata.root_area.height(),
+                        phase,
+                    );
+                    &ratio_node
+                }
+                Some(aspect_ratio) if node.width.inner_sized() && !node.height.inner_sized() => {
+                    let height = area_size.height - node.margin.vertical();
+                    ratio_node = Node {
+                        width: Size::Pixels(Length::new(height * aspect_ratio)),
+                        ..node.clone()
+                    };
+                    area_size.width = ratio_node.width.min_max(
+                        area_size.width,
+                        parent_area.size.width,
+                        available_parent_area.size.width,
+                        node.margin.left(),
+                        node.margin.horizontal(),
+                        &node.minimum_width,
+                        &node.maximum_width,
+                        self.layout_metadata.root_area.width(),
+                        phase,
+                    );
+                    &ratio_node
+                }
+                _ => node,
+            };
+
             // If available, run a custom layout measure function
             // This is useful when you use third-party libraries (e.g. rust-skia, cosmic-text) to measure text layouts
             let node_data = if let Some(measurer) = self.measurer {
@@ -254,45 +429,73 @@ where
 
                 available_area.move_with_offsets(&node.offset_x, &node.offset_y);
 
-                // Measure the layout of this Node's children
-                self.measure_children(
-                    &node_id,
-                    node,
-                    &mut available_area,
-                    &mut inner_sizes,
-                    must_cache_children,
-                    &mut area,
-                    &mut inner_area,
-                    true,
-                );
+                if defer_children {
+                    self.deferred.push(DeferredNode {
+                        node_id,
+                        node: node.clone(),
+                        available_area,
+                        area,
+                        inner_area,
+                        must_cache_children,
+                        parent_is_dirty: true,
+                        scroll_viewport: if node.is_scroll_container() {
+                            Some(inner_area)
+                        } else {
+                            self.scroll_viewport
+                        },
+                    });
+                } else {
+                    let parent_scroll_viewport = self.scroll_viewport;
+                    if node.is_scroll_container() {
+                        self.scroll_viewport = Some(inner_area);
+                    }
 
-                // Re apply min max values after measurin with inner sized
-                // Margins are set to 0 because area.size already contains the margins
-                if node.width.inner_sized() {
-                    area.size.width = node.width.min_max(
-                        area.size.width,
-                        parent_area.size.width,
-                        available_parent_area.size.width,
-                        0.,
-                        0.,
-                        &node.minimum_width,
-                        &node.maximum_width,
-                        self.layout_metadata.root_area.width(),
-                        phase,
-                    );
-                }
-                if node.height.inner_sized() {
-                    area.size.height = node.height.min_max(
-                        area.size.height,
-                        parent_area.size.height,
-                        available_parent_area.size.height,
-                        0.,
-                        0.,
-                        &node.minimum_height,
-                        &node.maximum_height,
-                        self.layout_metadata.root_area.height(),
-                        phase,
+                    // Measure the layout of this Node's children
+                    self.measure_children(
+                        &node_id,
+                        node,
+                        &mut available_area,
+                        &mut inner_sizes,
+                        must_cache_children,
+                        &mut area,
+                        &mut inner_area,
+                        true,
                     );
+
+                    if must_cache_children {
+                        self.stick_children(&node_id, node, &inner_area);
+                        self.anchor_children(&node_id, &inner_area);
+                    }
+                    self.scroll_viewport = parent_scroll_viewport;
+
+                    // Re apply min max values after measurin with inner sized
+                    // Margins are set to 0 because area.size already contains the margins
+                    if node.width.inner_sized() {
+                        area.size.width = node.width.min_max(
+                            area.size.width,
+                            parent_area.size.width,
+                            available_parent_area.size.width,
+                            0.,
+                            0.,
+                            &node.minimum_width,
+                            &node.maximum_width,
+                            self.layout_metadata.root_area.width(),
+                            phase,
+                        );
+                    }
+                    if node.height.inner_sized() {
+                        area.size.height = node.height.min_max(
+                            area.size.height,
+                            parent_area.size.height,
+                            available_parent_area.size.height,
+                            0.,
+                            0.,
+                            &node.minimum_height,
+                            &node.maximum_height,
+                            self.layout_metadata.root_area.height(),
+                            phase,
+                        );
+                    }
                 }
             }
 
@@ -313,10 +516,30 @@ where
                 }
             }
 
+            if let Some(mut inputs) = inputs {
+                inputs.area = layout_node.area;
+                inputs.area_offset = layout_node.area.origin - available_parent_area.origin;
+                inputs.inner_offset = layout_node.inner_area.origin - layout_node.area.origin;
+                self.layout.measure_inputs.insert(node_id, inputs);
+
+                if node.position.is_global()
+                    || node.position.is_sticky()
+                    || node.has_layout_references
+                {
+                    self.layout.pinned.insert(node_id);
+                }
+                self.pin_parent(node_id);
+            }
+
             (must_cache_children, layout_node)
         } else {
             let layout_node = self.layout.get(node_id).unwrap().clone();
 
+            // Nothing has changed inside this Node
+            if !self.dirty_ancestors.contains(&node_id) {
+                return (false, layout_node);
+            }
+
             let mut inner_sizes = Size2D::default();
             let mut available_area = layout_node.inner_area;
             let mut area = layout_node.area;
@@ -330,7 +553,27 @@ where
                 true
             };
 
-            if measure_inner_children {
+            if measure_inner_children && defer_children {
+                self.deferred.push(DeferredNode {
+                    node_id,
+                    node: node.clone(),
+                    available_area,
+                    area,
+                    inner_area,
+                    must_cache_children,
+                    parent_is_dirty: false,
+                    scroll_viewport: if node.is_scroll_container() {
+                        Some(inner_area)
+                    } else {
+                        self.scroll_viewport
+                    },
+                });
+            } else if measure_inner_children {
+                let parent_scroll_viewport = self.scroll_viewport;
+                if node.is_scroll_container() {
+                    self.scroll_viewport = Some(inner_area);
+                }
+
                 self.measure_children(
                     &node_id,
                     node,
@@ -341,12 +584,275 @@ where
                     &mut inner_area,
                     false,
                 );
+
+                if must_cache_children {
+                    self.stick_children(&node_id, node, &inner_area);
+                    self.anchor_children(&node_id, &inner_area);
+                }
+                self.scroll_viewport = parent_scroll_viewport;
             }
 
             (false, layout_node)
         }
     }
 
+    /// Move the sticky children of a Node so they stay inside the viewport
+    /// of the closest scroll container.
+    pub(crate) fn stick_children(
+        &mut self,
+        parent_node_id: &Key,
+        parent_node: &Node,
+        inner_area: &Area,
+    ) {
+        let Some(scroll_viewport) = self.scroll_viewport else {
+            return;
+        };
+
+        // Sticky Nodes pin their parent
+        if !self.layout.pinned.contains(parent_node_id) {
+            return;
+        }
+
+        // The content of scroll containers is not limited to their inner area
+        let containing_area = (!parent_node.is_scroll_container()).then_some(inner_area);
+
+        for child_id in self.dom_adapter.children_of(parent_node_id) {
+            let Some(child_data) = self.dom_adapter.get_node(&child_id) else {
+                continue;
+            };
+            let Position::Sticky(sides) = &child_data.position else {
+                continue;
+            };
+            let Some(child_layout) = self.layout.get(child_id) else {
+                continue;
+            };
+            let (offset_x, offset_y) =
+                sides.sticky_offset(&child_layout.area, &scroll_viewport, containing_area);
+            if offset_x != 0. || offset_y != 0. {
+                let offset = Vector2D::new(offset_x, offset_y);
+                if let Some(layout_node) = self.layout.results.get_mut(&child_id) {
+                    layout_node.area.origin += offset;
+                    layout_node.inner_area.origin += offset;
+                }
+                if let Some(inputs) = self.layout.measure_inputs.get_mut(&child_id) {
+                    inputs.area.origin += offset;
+                }
+                self.move_children(child_id);
+            }
+        }
+    }
+
+    /// Remember where the children of a Node are relative to it, to move them along with it.
+    fn anchor_children(&mut self, node_id: &Key, inner_area: &Area) {
+        for child_id in self.dom_adapter.children_of(node_id) {
+            if let Some(inputs) = self.layout.measure_inputs.get_mut(&child_id) {
+                inputs.parent_offset = inputs.parent_area.origin - inner_area.origin;
+            }
+        }
+    }
+
+    /// Move the layout of a Node and its descendants to new parent areas.
+    ///
+    /// They are placed again from the parent areas with the offsets from when they were measured,
+    /// rather than accumulating the distances they were moved by, so they don't drift away.
+    fn move_node(&mut self, node_id: Key, parent_area: &Area, available_parent_area: &Area) {
+        let Some(inputs) = self.layout.measure_inputs.get_mut(&node_id) else {
+            return;
+        };
+        inputs.parent_area = *parent_area;
+        inputs.available_parent_area = *available_parent_area;
+        inputs.area.origin = available_parent_area.origin + inputs.area_offset;
+        let origin = inputs.area.origin;
+        let inner_origin = origin + inputs.inner_offset;
+
+        if let Some(layout_node) = self.layout.results.get_mut(&node_id) {
+            layout_node.area.origin = origin;
+            layout_node.inner_area.origin = inner_origin;
+        }
+        self.move_children(node_id);
+    }
+
+    /// Move the children of a Node to where its inner area is now.
+    fn move_children(&mut self, node_id: Key) {
+        let Some(inner_area) = self
+            .layout
+            .results
+            .get(&node_id)
+            .map(|layout_node| layout_node.inner_area)
+        else {
+            return;
+        };
+        for child_id in self.dom_adapter.children_of(&node_id) {
+            let Some(inputs) = self.layout.measure_inputs.get(&child_id) else {
+                continue;
+            };
+            let mut parent_area = inputs.parent_area;
+            parent_area.origin = inner_area.origin + inputs.parent_offset;
+            let mut available_parent_area = inputs.available_parent_area;
+            available_parent_area.origin = parent_area.origin + inputs.available_offset;
+            self.move_node(child_id, &parent_area, &available_parent_area);
+        }
+    }
+
+    /// Get the previous layout of a Node that hasn't changed, as long as it doesn't depend on what changed around it.
+    ///
+    /// Nodes that were only moved (e.g. by a sibling that grew) are moved along with their descendants,
+    /// unless any of them can't be simply moved, like `global` or `sticky` positions.
+    fn reuse_node(
+        &mut self,
+        node_id: Key,
+        node: &Node,
+        parent_area: &Area,
+        available_parent_area: &Area,
+        must_cache_children: bool,
+    ) -> Option<LayoutNode> {
+        if self.dirty_ancestors.contains(&node_id) {
+            return None;
+        }
+
+        let layout_node = self.layout.results.get(&node_id)?;
+        let inputs = self.layout.measure_inputs.get(&node_id)?;
+
+        // The cached layout must come from these inputs
+        if inputs.area != layout_node.area
+            || inputs.root_area != self.layout_metadata.root_area
+            || inputs.width != node.width
+            || inputs.height != node.height
+        {
+            return None;
+        }
+
+        let is_exact = inputs.parent_area == *parent_area
+            && inputs.available_parent_area == *available_parent_area;
+
+        if !is_exact {
+            // Only stacked Nodes are placed relative to the available area
+            if !node.position.is_stacked() || node.position.is_sticky() {
+                return None;
+            }
+
+            // The size of the Node can't depend on the sizes that changed
+            #[allow(clippy::float_cmp)]
+            let width_changed = inputs.parent_area.width() != parent_area.width()
+                || inputs.available_parent_area.width() != available_parent_area.width();
+            #[allow(clippy::float_cmp)]
+            let height_changed = inputs.parent_area.height() != parent_area.height()
+                || inputs.available_parent_area.height() != available_parent_area.height();
+            if (width_changed && !node.has_fixed_width())
+                || (height_changed && !node.has_fixed_height())
+            {
+                return None;
+            }
+        }
+
+        let is_moved = available_parent_area.origin != inputs.available_parent_area.origin;
+
+        if is_moved {
+            if !must_cache_children || self.layout.pinned.contains(&node_id) {
+                return None;
+            }
+            self.move_node(node_id, parent_area, available_parent_area);
+        }
+
+        if must_cache_children {
+            if let Some(inputs) = self.layout.measure_inputs.get_mut(&node_id) {
+                inputs.parent_area = *parent_area;
+                inputs.available_parent_area = *available_parent_area;
+                inputs.available_offset = available_parent_area.origin - parent_area.origin;
+            }
+            self.pin_parent(node_id);
+        }
+
+        self.layout.get(node_id).cloned()
+    }
+
+    /// Find the children of an unchanged Node that need to be measured again.
+    ///
+    /// Returns the index of the child from where to start measuring, along with the available area for it,
+    /// and the index of the last child that changed, after which the children can be skipped once they are no longer moved.
+    fn changed_children(
+        &mut self,
+        parent_node_id: &Key,
+        parent_node: &Node,
+        children: &[Key],
+        must_cache_children: bool,
+        parent_is_dirty: bool,
+    ) -> Option<(usize, usize, Option<Area>)> {
+        if parent_is_dirty
+            || !must_cache_children
+            || !self.layout.stacked_children.contains(parent_node_id)
+            || parent_node.width.inner_sized()
+            || parent_node.height.inner_sized()
+        {
+            return None;
+        }
+
+        let mut first_child = None;
+        let mut last_changed_child = None;
+        for (i, child_id) in children.iter().enumerate() {
+            let reason = self.layout.dirty.get(child_id);
+            let is_changed = reason.is_some_and(DirtyReason::is_changed)
+                || self.dirty_ancestors.contains(child_id)
+                || !self.layout.results.contains_key(child_id);
+
+            if is_changed {
+                // Changed children could now need to be aligned with their siblings
+                let child_data = self.dom_adapter.get_node(child_id)?;
+                if child_data.align_self.is_some()
+                    || child_data
+                        .auto_margins
+                        .main_axis_count(&parent_node.direction)
+                        > 0
+                {
+                    return None;
+                }
+                last_changed_child = Some(i);
+            }
+
+            if first_child.is_none() && (is_changed || reason.is_some()) {
+                first_child = Some(i);
+            }
+        }
+
+        let last_changed_child = last_changed_child?;
+        let first_child = first_child?;
+
+        // Start from the closest previous stacked sibling, as it knows where the next one goes.
+        // The available area of the siblings in between might not have the spacing after it,
+        // in case it was the last stacked child, or been moved by the `sticky` position.
+        let previous_inputs = children[..first_child]
+            .iter()
+            .rposition(|child_id| {
+                self.dom_adapter
+                    .get_node(child_id)
+                    .is_some_and(|child_data| {
+                        child_data.position.is_stacked() && !child_data.position.is_sticky()
+                    })
+            })
+            .and_then(|previous_child| {
+                self.layout
+                    .measure_inputs
+                    .get(&children[previous_child])
+                    .map(|inputs| (previous_child, inputs.available_parent_area))
+            });
+
+        Some(match previous_inputs {
+            Some((previous_child, available_area)) => {
+                (previous_child, last_changed_child, Some(available_area))
+            }
+            None => (0, last_changed_child, None),
+        })
+    }
+
+    /// Pin the parent of a pinned Node.
+    fn pin_parent(&mut self, node_id: Key) {
+        if self.layout.pinned.contains(&node_id) {
+            if let Some(parent_id) = self.dom_adapter.parent_of(&node_id) {
+                self.layout.pinned.insert(parent_id);
+            }
+        }
+    }
+
     /// Measure the children layouts of a Node
     #[allow(clippy::too_many_arguments)]
     pub fn measure_children(
@@ -366,47 +872,139 @@ where
         // Parent Node is dirty.
         parent_is_dirty: bool,
     ) {
+        if parent_node.content.is_grid() {
+            self.measure_grid_children(
+                parent_node_id,
+                parent_node,
+                available_area,
+                inner_sizes,
+                must_cache_children,
+                area,
+                inner_area,
+                parent_is_dirty,
+            );
+            return;
+        }
+
+        if parent_node.wrap_content.is_wrap() {
+            self.measure_wrap_children(
+                parent_node_id,
+                parent_node,
+                available_area,
+                inner_sizes,
+                must_cache_children,
+                area,
+                inner_area,
+                parent_is_dirty,
+            );
+            return;
+        }
+
         let children = self.dom_adapter.children_of(parent_node_id);
 
+        // Only the children from the first changed one need to be measured again
+        let mut first_measured_child = 0;
+        let last_changed_child = self
+            .changed_children(
+                parent_node_id,
+                parent_node,
+                &children,
+                must_cache_children,
+                parent_is_dirty,
+            )
+            .map(|(first_child, last_changed_child, first_available_area)| {
+                if let Some(first_available_area) = first_available_area {
+                    *available_area = first_available_area;
+                    first_measured_child = first_child;
+                }
+                last_changed_child
+            });
+
         let mut initial_phase_flex_grows = FxHashMap::default();
+        let mut initial_phase_flex_basis = FxHashMap::default();
         let mut initial_phase_sizes = FxHashMap::default();
         let mut initial_phase_inner_sizes = Size2D::default();
 
         // Used to calculate the spacing and some alignments
-        let (non_absolute_children_len, first_child, last_child) = if parent_node.spacing.get() > 0.
-        {
-            let mut last_child = None;
-            let mut first_child = None;
-            let len = children
-                .iter()
-                .filter(|child_id| {
-                    let Some(child_data) = self.dom_adapter.get_node(child_id) else {
-                        return false;
-                    };
-                    let is_stacked = child_data.position.is_stacked();
-                    if is_stacked {
-                        last_child = Some(**child_id);
-
-                        if first_child.is_none() {
-                            first_child = Some(**child_id);
+        let (non_absolute_children_len, first_child, last_child) =
+            if parent_node.spacing.get() > 0. && last_changed_child.is_some() {
+                // Only the last stacked child of all is needed, as the children are not aligned
+                let last_child = children
+                    .iter()
+                    .rev()
+                    .find(|child_id| {
+                        self.dom_adapter
+                            .get_node(child_id)
+                            .is_some_and(|child_data| child_data.position.is_stacked())
+                    })
+                    .copied();
+                (children.len(), children.first().copied(), last_child)
+            } else if parent_node.spacing.get() > 0. {
+                let mut last_child = None;
+                let mut first_child = None;
+                let len = children
+                    .iter()
+                    .filter(|child_id| {
+                        let Some(child_data) = self.dom_adapter.get_node(child_id) else {
+                            return false;
+                        };
+                        let is_stacked = child_data.position.is_stacked();
+                        if is_stacked {
+                            last_child = Some(**child_id);
+
+                            if first_child.is_none() {
+                                first_child = Some(**child_id);
+                            }
                         }
-                    }
-                    is_stacked
-                })
-                .count();
-            (len, first_child, last_child)
+                        is_stacked
+                    })
+                    .count();
+                (len, first_child, last_child)
+            } else {
+                (
+                    children.len(),
+                    children.first().copied(),
+                    children.last().copied(),
+                )
+            };
+
+        // Children that override the alignments of the parent,
+        // the changed ones were already checked if the rest are skipped
+        let (children_align_self, auto_margins_count) = if last_changed_child.is_some() {
+            (false, 0)
         } else {
-            (
-                children.len(),
-                children.first().copied(),
-                children.last().copied(),
+            children.iter().fold(
+                (false, 0),
+                |(align_self, auto_margins_count), child_id| match self
+                    .dom_adapter
+                    .get_node(child_id)
+                {
+                    Some(child_data) if child_data.position.is_stacked() => (
+                        align_self || child_data.align_self.is_some(),
+                        auto_margins_count
+                            + child_data
+                                .auto_margins
+                                .main_axis_count(&parent_node.direction),
+                    ),
+                    _ => (align_self, auto_margins_count),
+                },
             )
         };
 
         let needs_initial_phase = parent_node.cross_alignment.is_not_start()
             || parent_node.main_alignment.is_not_start()
             || parent_node.content.is_fit()
-            || parent_node.content.is_flex();
+            || parent_node.content.is_flex()
+            || children_align_self
+            || auto_margins_count > 0;
+
+        if must_cache_children {
+            if needs_initial_phase {
+                self.layout.stacked_children.remove(parent_node_id);
+            } else {
+                self.layout.stacked_children.insert(*parent_node_id);
+            }
+        }
 
         let mut initial_phase_area = *area;
         let mut initial_phase_inner_area = *inner_area;
@@ -443,6 +1041,35 @@ where
 
                 child_areas.area.adjust_size(&child_data);
 
+                // Flexible Nodes start from their `flex_basis` rather than their own size
+                if parent_node.content.is_flex() {
+                    let root_area = self.layout_metadata.root_area;
+                    match parent_node.direction {
+                        Direction::Vertical => {
+                            if let Some(basis) = child_data.flex_basis.eval(
+                                inner_area.height(),
+                                initial_phase_available_area.height(),
+                                child_data.margin.vertical(),
+                                root_area.height(),
+                                Phase::Initial,
+                            ) {
+                                child_areas.area.size.height = basis;
+                            }
+                        }
+                        Direction::Horizontal => {
+                            if let Some(basis) = child_data.flex_basis.eval(
+                                inner_area.width(),
+                                initial_phase_available_area.width(),
+                                child_data.margin.horizontal(),
+                                root_area.width(),
+                                Phase::Initial,
+                            ) {
+                                child_areas.area.size.width = basis;
+                            }
+                        }
+                    }
+                }
+
                 // Stack this child into the parent
                 Self::stack_child(
                     &mut initial_phase_available_area,
@@ -458,22 +1085,28 @@ where
 
                 if parent_node.cross_alignment.is_not_start()
                     || parent_node.main_alignment.is_spaced()
+                    || child_data.align_self.is_some()
                 {
                     initial_phase_sizes.insert(*child_id, child_areas.area.size);
                 }
 
                 if parent_node.content.is_flex() {
-                    match parent_node.direction {
-                        Direction::Vertical => {
-                            if let Some(ff) = child_data.height.flex_grow() {
-                                initial_phase_flex_grows.insert(*child_id, ff);
-                            }
-                        }
-                        Direction::Horizontal => {
-                            if let Some(ff) = child_data.width.flex_grow() {
-                                initial_phase_flex_grows.insert(*child_id, ff);
-                            }
+                    let (main_size, basis) = match parent_node.direction {
+                        Direction::Vertical => (&child_data.height, child_areas.area.height()),
+                        Direction::Horizontal => (&child_data.width, child_areas.area.width()),
+                    };
+
+                    if let Some(ff) = main_size.flex_grow() {
+                        initial_phase_flex_grows.insert(*child_id, ff);
+                    } else if child_data.flex_grow.get() > 0.
+                        || child_data.flex_shrink.get() > 0.
+                        || !child_data.flex_basis.inner_sized()
+                    {
+                        // Sized Nodes grow or shrink starting from their basis
+                        if child_data.flex_grow.get() > 0. {
+                            initial_phase_flex_grows.insert(*child_id, child_data.flex_grow);
                         }
+                        initial_phase_flex_basis.insert(*child_id, (basis, child_data.flex_shrink));
                     }
                 }
             }
@@ -494,25 +1127,87 @@ where
         let flex_available_height =
             initial_available_area.height() - initial_phase_inner_sizes.height;
 
-        let initial_phase_inner_sizes_with_flex =
-            initial_phase_flex_grows
-                .values()
-                .fold(initial_phase_inner_sizes, |mut acc, f| {
-                    let flex_grow_per = f.get() / flex_grows.get() * 100.;
+        let flex_available_main = match flex_axis {
+            AlignAxis::Height => flex_available_height,
+            AlignAxis::Width => flex_available_width,
+        };
 
-                    match flex_axis {
-                        AlignAxis::Height => {
-                            let size = flex_available_height / 100. * flex_grow_per;
-                            acc.height += size;
-                        }
-                        AlignAxis::Width => {
-                            let size = flex_available_width / 100. * flex_grow_per;
-                            acc.width += size;
-                        }
+        let flex_shrinks = initial_phase_flex_basis
+            .values()
+            .map(|(basis, flex_shrink)| basis * flex_shrink.get())
+            .sum::<f32>();
+
+        // Main size of the Nodes using `flex_grow` or `flex_shrink`
+        let flex_main_size = |child_id: &Key| -> Option<f32> {
+            let (basis, flex_shrink) = initial_phase_flex_basis.get(child_id)?;
+
+            if flex_available_main >= 0. {
+                let flex_grow = initial_phase_flex_grows
+                    .get(child_id)
+                    .map(|f| f.get())
+                    .unwrap_or_default();
+                Some(basis + flex_available_main * flex_grow / flex_grows.get())
+            } else if flex_shrinks > 0. {
+                let shrink = -flex_available_main * basis * flex_shrink.get() / flex_shrinks;
+                Some((basis - shrink).max(0.))
+            } else {
+                Some(*basis)
+            }
+        };
+
+        let initial_phase_inner_sizes_with_flex = initial_phase_flex_grows
+            .iter()
+            .filter(|(child_id, _)| !initial_phase_flex_basis.contains_key(child_id))
+            .fold(initial_phase_inner_sizes, |mut acc, (_, f)| {
+                let flex_grow_per = f.get() / flex_grows.get() * 100.;
+
+                match flex_axis {
+                    AlignAxis::Height => {
+                        let size = flex_available_height / 100. * flex_grow_per;
+                        acc.height += size;
+                    }
+                    AlignAxis::Width => {
+                        let size = flex_available_width / 100. * flex_grow_per;
+                        acc.width += size;
                     }
+                }
 
-                    acc
-                });
+                acc
+            });
+
+        let initial_phase_inner_sizes_with_flex = initial_phase_flex_basis.iter().fold(
+            initial_phase_inner_sizes_with_flex,
+            |mut acc, (child_id, (basis, _))| {
+                let size = flex_main_size(child_id).unwrap_or(*basis) - basis;
+
+                match flex_axis {
+                    AlignAxis::Height => acc.height += size,
+                    AlignAxis::Width => acc.width += size,
+                }
+
+                acc
+            },
+        );
+
+        // Free space of the Main axis shared by the `auto` margins of the children,
+        // unless the parent is sized by its children as there is no free space then
+        let main_is_inner_sized = match flex_axis {
+            AlignAxis::Height => parent_node.height.inner_sized(),
+            AlignAxis::Width => parent_node.width.inner_sized(),
+        };
+        let auto_margin_size = if auto_margins_count > 0 && !main_is_inner_sized {
+            let free_main = match flex_axis {
+                AlignAxis::Height => {
+                    initial_available_area.height() - initial_phase_inner_sizes_with_flex.height
+                }
+                AlignAxis::Width => {
+                    initial_available_area.width() - initial_phase_inner_sizes_with_flex.width
+                }
+            };
+            free_main.max(0.) / auto_margins_count as f32
+        } else {
+            0.
+        };
 
         if needs_initial_phase {
             if parent_node.main_alignment.is_not_start() {
@@ -523,20 +1218,26 @@ where
                     &mut initial_phase_inner_area,
                     parent_node,
                     AlignmentDirection::Main,
+                    children_align_self,
                 );
 
-                // Align the Main axis
-                Self::align_content(
-                    available_area,
-                    &initial_phase_inner_area,
-                    initial_phase_inner_sizes_with_flex,
-                    &parent_node.main_alignment,
-                    &parent_node.direction,
-                    AlignmentDirection::Main,
-                );
+                // Align the Main axis, the `auto` margins take all the free space otherwise
+                if auto_margins_count == 0 {
+                    Self::align_content(
+                        available_area,
+                        &initial_phase_inner_area,
+                        initial_phase_inner_sizes_with_flex,
+                        &parent_node.main_alignment,
+                        &parent_node.direction,
+                        AlignmentDirection::Main,
+                    );
+                }
             }
 
-            if parent_node.cross_alignment.is_not_start() || parent_node.content.is_fit() {
+            if parent_node.cross_alignment.is_not_start()
+                || parent_node.content.is_fit()
+                || children_align_self
+            {
                 // Adjust the available and inner areas of the Cross axis
                 Self::shrink_area_to_fit_when_unbounded(
                     available_area,
@@ -544,15 +1245,38 @@ where
                     &mut initial_phase_inner_area,
                     parent_node,
                     AlignmentDirection::Cross,
+                    children_align_self,
                 );
             }
         }
 
         let initial_available_area = *available_area;
 
+        // Children whose size doesn't depend on their own children can be measured in parallel
+        #[cfg(feature = "parallel")]
+        let defer_children =
+            self.measure_deferred.is_some() && children.len() >= PARALLEL_MIN_CHILDREN;
+        #[cfg(not(feature = "parallel"))]
+        let defer_children = false;
+        let deferred_start = self.deferred.len();
+
         // Final phase: measure the children with all the axis and sizes adjusted
-        for child_id in children {
-            let Some(child_data) = self.dom_adapter.get_node(&child_id) else {
+        for (i, child_id) in children.into_iter().enumerate().skip(first_measured_child) {
+            // The rest of the children have neither changed nor been moved
+            if last_changed_child.is_some_and(|last_changed_child| i > last_changed_child)
+                && self
+                    .layout
+                    .measure_inputs
+                    .get(&child_id)
+                    .is_some_and(|inputs| {
+                        inputs.parent_area == *inner_area
+                            && inputs.available_parent_area == *available_area
+                    })
+            {
+                break;
+            }
+
+            let Some(mut child_data) = self.dom_adapter.get_node(&child_id) else {
                 continue;
             };
 
@@ -564,7 +1288,20 @@ where
             if parent_node.content.is_flex() {
                 let flex_grow = initial_phase_flex_grows.get(&child_id);
 
-                if let Some(flex_grow) = flex_grow {
+                if let Some(main_size) = flex_main_size(&child_id) {
+                    // Fix the main size of the Node to its flexed size
+                    match flex_axis {
+                        AlignAxis::Height => {
+                            child_data.height =
+                                Size::Pixels(Length::new(main_size - child_data.margin.vertical()));
+                        }
+                        AlignAxis::Width => {
+                            child_data.width = Size::Pixels(Length::new(
+                                main_size - child_data.margin.horizontal(),
+                            ));
+                        }
+                    }
+                } else if let Some(flex_grow) = flex_grow {
                     let flex_grow_per = flex_grow.get() / flex_grows.get() * 100.;
 
                     match flex_axis {
@@ -581,7 +1318,10 @@ where
             }
 
             // Only the stacked children will be aligned
-            if parent_node.main_alignment.is_spaced() && child_data.position.is_stacked() {
+            if parent_node.main_alignment.is_spaced()
+                && child_data.position.is_stacked()
+                && auto_margins_count == 0
+            {
                 // Align the Main axis if necessary
                 Self::align_position(
                     AlignmentDirection::Main,
@@ -595,7 +1335,12 @@ where
                 );
             }
 
-            if parent_node.cross_alignment.is_not_start() {
+            let cross_alignment = child_data
+                .align_self
+                .as_ref()
+                .unwrap_or(&parent_node.cross_alignment);
+
+            if cross_alignment.is_not_start() {
                 let initial_phase_size = initial_phase_sizes.get(&child_id);
 
                 if let Some(initial_phase_size) = initial_phase_size {
@@ -604,13 +1349,46 @@ where
                         &mut adapted_available_area,
                         available_area,
                         *initial_phase_size,
-                        &parent_node.cross_alignment,
+                        cross_alignment,
                         &parent_node.direction,
                         AlignmentDirection::Cross,
                     );
                 }
             }
 
+            let (auto_margin_start, auto_margin_end) = if child_data.position.is_stacked() {
+                child_data.auto_margins.main_axis(&parent_node.direction)
+            } else {
+                (false, false)
+            };
+            let auto_margin_start_size = if auto_margin_start {
+                auto_margin_size
+            } else {
+                0.
+            };
+            let auto_margin_end_size = if auto_margin_end {
+                auto_margin_size
+            } else {
+                0.
+            };
+
+            // Push the Node by its `auto` margin in the start of the Main axis
+            match flex_axis {
+                AlignAxis::Height => {
+                    adapted_available_area.origin.y += auto_margin_start_size;
+                    adapted_available_area.size.height -= auto_margin_start_size;
+                }
+                AlignAxis::Width => {
+                    adapted_available_area.origin.x += auto_margin_start_size;
+                    adapted_available_area.size.width -= auto_margin_start_size;
+                }
+            }
+
+            self.defer_children = defer_children
+                && !child_data.width.inner_sized()
+                && !child_data.height.inner_sized()
+                && !child_data.has_layout_references;
+
             // Final measurement
             let (child_revalidated, mut child_areas) = self.measure_node(
                 child_id,
@@ -638,6 +1416,21 @@ where
                     is_last_child,
                     Phase::Final,
                 );
+
+                // Leave the space of the `auto` margins before the next sibling
+                let auto_margins_sizes = auto_margin_start_size + auto_margin_end_size;
+                match flex_axis {
+                    AlignAxis::Height => {
+                        available_area.origin.y += auto_margin_end_size;
+                        available_area.size.height -= auto_margins_sizes;
+                        inner_sizes.height += auto_margins_sizes;
+                    }
+                    AlignAxis::Width => {
+                        available_area.origin.x += auto_margin_end_size;
+                        available_area.size.width -= auto_margins_sizes;
+                        inner_sizes.width += auto_margins_sizes;
+                    }
+                }
             }
 
             // Cache the child layout if it was mutated and children must be cached
@@ -646,6 +1439,647 @@ where
                 self.layout.cache_node(child_id, child_areas);
             }
         }
+
+        if self.deferred.len() > deferred_start {
+            let deferred = self.deferred.split_off(deferred_start);
+            if let Some(measure_deferred) = self.measure_deferred {
+                measure_deferred(self, deferred);
+            }
+        }
+    }
+
+    /// Measure the children of a Node whose measurement was deferred.
+    pub fn measure_deferred_node(&mut self, deferred: DeferredNode<Key>) {
+        let DeferredNode {
+            node_id,
+            node,
+            mut available_area,
+            mut area,
+            mut inner_area,
+            must_cache_children,
+            parent_is_dirty,
+            scroll_viewport,
+        } = deferred;
+
+        let parent_scroll_viewport = self.scroll_viewport;
+        self.scroll_viewport = scroll_viewport;
+
+        let mut inner_sizes = Size2D::default();
+        self.measure_children(
+            &node_id,
+            &node,
+            &mut available_area,
+            &mut inner_sizes,
+            must_cache_children,
+            &mut area,
+            &mut inner_area,
+            parent_is_dirty,
+        );
+
+        if must_cache_children {
+            self.stick_children(&node_id, &node, &inner_area);
+            self.anchor_children(&node_id, &inner_area);
+        }
+        self.scroll_viewport = parent_scroll_viewport;
+
+        // Its descendants might have pinned the Node after it was measured
+        if must_cache_children && parent_is_dirty {
+            self.pin_parent(node_id);
+        }
+    }
+
+    /// Measure the children layouts of a grid Node
+    #[allow(clippy::too_many_arguments)]
+    fn measure_grid_children(
+        &mut self,
+        parent_node_id: &Key,
+        parent_node: &Node,
+        // Area available inside the Node
+        available_area: &mut Area,
+        // Accumulated sizes in both axis in the Node
+        inner_sizes: &mut Size2D,
+        // Whether to cache the measurements of this Node's children
+        must_cache_children: bool,
+        // Parent area.
+        area: &mut Area,
+        // Inner area of the parent.
+        inner_area: &mut Area,
+        // Parent Node is dirty.
+        parent_is_dirty: bool,
+    ) {
+        let children = self.dom_adapter.children_of(parent_node_id);
+        let (column_spacing, row_spacing) = match parent_node.direction {
+            Direction::Horizontal => (parent_node.spacing.get(), parent_node.cross_spacing().get()),
+            Direction::Vertical => (parent_node.cross_spacing().get(), parent_node.spacing.get()),
+        };
+
+        // Place the stacked children in the cells of the grid
+        let cells = self.place_grid_children(&children, parent_node);
+
+        let columns_len = cells
+            .values()
+            .map(|cell| cell.column + cell.column_span)
+            .max()
+            .unwrap_or_default()
+            .max(parent_node.grid_columns.len());
+        let rows_len = cells
+            .values()
+            .map(|cell| cell.row + cell.row_span)
+            .max()
+            .unwrap_or_default()
+            .max(parent_node.grid_rows.len());
+
+        // Tracks that were not declared are sized by their content
+        let columns = (0..columns_len)
+            .map(|i| parent_node.grid_columns.get(i).cloned().unwrap_or_default())
+            .collect::<Vec<_>>();
+        let rows = (0..rows_len)
+            .map(|i| parent_node.grid_rows.get(i).cloned().unwrap_or_default())
+            .collect::<Vec<_>>();
+
+        let is_content_sized = |track: &GridTrack, inner_sized: bool| match track {
+            GridTrack::Auto => true,
+            GridTrack::Fr(_) => inner_sized,
+            GridTrack::Pixels(_) => false,
+        };
+
+        // Measure the width of the content of the columns sized by their content,
+        // Nodes spanning multiple columns are not considered
+        let mut columns_content = vec![0f32; columns_len];
+        for (child_id, cell) in &cells {
+            if cell.column_span > 1
+                || !is_content_sized(&columns[cell.column], parent_node.width.inner_sized())
+            {
+                continue;
+            }
+            let Some(child_data) = self.dom_adapter.get_node(child_id) else {
+                continue;
+            };
+            // Percentages can't be resolved against a track that depends on its content
+            let mut content_area = *available_area;
+            content_area.size.width = 0.;
+            let (_, child_areas) = self.measure_node(
+                *child_id,
+                &child_data,
+                &content_area,
+                available_area,
+                false,
+                parent_is_dirty,
+                Phase::Initial,
+            );
+            columns_content[cell.column] =
+                columns_content[cell.column].max(child_areas.area.width());
+        }
+
+        let columns_sizes = Self::grid_tracks_sizes(
+            &columns,
+            &columns_content,
+            available_area.width(),
+            column_spacing,
+            parent_node.width.inner_sized(),
+        );
+        let columns_offsets =
+            Self::grid_tracks_offsets(&columns_sizes, available_area.min_x(), column_spacing);
+
+        // Measure the height of the content of the rows sized by their content,
+        // now that the width of every column is known
+        let mut rows_content = vec![0f32; rows_len];
+        for (child_id, cell) in &cells {
+            if cell.row_span > 1
+                || !is_content_sized(&rows[cell.row], parent_node.height.inner_sized())
+            {
+                continue;
+            }
+            let Some(child_data) = self.dom_adapter.get_node(child_id) else {
+                continue;
+            };
+            let column_area = Area::new(
+                Point2D::new(columns_offsets[cell.column], available_area.min_y()),
+                Size2D::new(
+                    Self::grid_span_size(
+                        &columns_sizes,
+                        cell.column,
+                        cell.column_span,
+                        column_spacing,
+                    ),
+                    available_area.height(),
+                ),
+            );
+            let mut content_area = column_area;
+            content_area.size.height = 0.;
+            let (_, child_areas) = self.measure_node(
+                *child_id,
+                &child_data,
+                &content_area,
+                &column_area,
+                false,
+                parent_is_dirty,
+                Phase::Initial,
+            );
+            rows_content[cell.row] = rows_content[cell.row].max(child_areas.area.height());
+        }
+
+        let rows_sizes = Self::grid_tracks_sizes(
+            &rows,
+            &rows_content,
+            available_area.height(),
+            row_spacing,
+            parent_node.height.inner_sized(),
+        );
+        let rows_offsets =
+            Self::grid_tracks_offsets(&rows_sizes, available_area.min_y(), row_spacing);
+
+        // Final phase: measure the children inside their cells
+        for child_id in children {
+            let Some(child_data) = self.dom_adapter.get_node(&child_id) else {
+                continue;
+            };
+
+            let (child_revalidated, mut child_areas) = if let Some(cell) = cells.get(&child_id) {
+                let cell_area = Area::new(
+                    Point2D::new(columns_offsets[cell.column], rows_offsets[cell.row]),
+                    Size2D::new(
+                        Self::grid_span_size(
+                            &columns_sizes,
+                            cell.column,
+                            cell.column_span,
+                            column_spacing,
+                        ),
+                        Self::grid_span_size(&rows_sizes, cell.row, cell.row_span, row_spacing),
+                    ),
+                );
+
+                self.measure_node(
+                    child_id,
+                    &child_data,
+                    &cell_area,
+                    &cell_area,
+                    must_cache_children,
+                    parent_is_dirty,
+                    Phase::Final,
+                )
+            } else {
+                self.measure_node(
+                    child_id,
+                    &child_data,
+                    inner_area,
+                    available_area,
+                    must_cache_children,
+                    parent_is_dirty,
+                    Phase::Final,
+                )
+            };
+
+            // Adjust the size of the area if needed
+            child_areas.area.adjust_size(&child_data);
+
+            // Cache the child layout if it was mutated and children must be cached
+            if child_revalidated && must_cache_children {
+                // Finally cache this node areas into Torin
+                self.layout.cache_node(child_id, child_areas);
+            }
+        }
+
+        let grid_width = Self::grid_span_size(&columns_sizes, 0, columns_len, column_spacing);
+        let grid_height = Self::grid_span_size(&rows_sizes, 0, rows_len, row_spacing);
+
+        inner_sizes.width += grid_width;
+        inner_sizes.height += grid_height;
+
+        if parent_node.width.inner_sized() {
+            area.size.width += grid_width;
+            // Keep the inner area in sync
+            inner_area.size.width = area.size.width
+                - parent_node.padding.horizontal()
+                - parent_node.margin.horizontal();
+        }
+
+        if parent_node.height.inner_sized() {
+            area.size.height += grid_height;
+            // Keep the inner area in sync
+            inner_area.size.height =
+                area.size.height - parent_node.padding.vertical() - parent_node.margin.vertical();
+        }
+    }
+
+    /// Measure the children layouts of a Node that wraps its content,
+    /// moving the children that overflow the main axis onto a new line.
+    /// The alignments of the Node are applied to every line on its own.
+    #[allow(clippy::too_many_arguments)]
+    fn measure_wrap_children(
+        &mut self,
+        parent_node_id: &Key,
+        parent_node: &Node,
+        // Area available inside the Node
+        available_area: &mut Area,
+        // Accumulated sizes in both axis in the Node
+        inner_sizes: &mut Size2D,
+        // Whether to cache the measurements of this Node's children
+        must_cache_children: bool,
+        // Parent area.
+        area: &mut Area,
+        // Inner area of the parent.
+        inner_area: &mut Area,
+        // Parent Node is dirty.
+        parent_is_dirty: bool,
+    ) {
+        let children = self.dom_adapter.children_of(parent_node_id);
+        let spacing = parent_node.spacing.get();
+        let cross_spacing = parent_node.cross_spacing().get();
+        let initial_available_area = *available_area;
+
+        let (available_main, available_cross) = match parent_node.direction {
+            Direction::Horizontal => (
+                initial_available_area.width(),
+                initial_available_area.height(),
+            ),
+            Direction::Vertical => (
+                initial_available_area.height(),
+                initial_available_area.width(),
+            ),
+        };
+
+        // Area available for a child given the position in the current line
+        let line_area = |line_main: f32, cross_offset: f32| {
+            let mut child_area = initial_available_area;
+            match parent_node.direction {
+                Direction::Horizontal => {
+                    child_area.origin.x += line_main;
+                    child_area.origin.y += cross_offset;
+                    child_area.size.width = (available_main - line_main).max(0.);
+                    child_area.size.height = (available_cross - cross_offset).max(0.);
+                }
+                Direction::Vertical => {
+                    child_area.origin.y += line_main;
+                    child_area.origin.x += cross_offset;
+                    child_area.size.height = (available_main - line_main).max(0.);
+                    child_area.size.width = (available_cross - cross_offset).max(0.);
+                }
+            }
+            child_area
+        };
+
+        // Size used in the main axis by the current line
+        let mut line_main = 0f32;
+        // Biggest size in the cross axis of the current line
+        let mut line_cross = 0f32;
+        let mut line_is_empty = true;
+        let mut cross_offset = 0f32;
+        let mut max_line_main = 0f32;
+        let mut lines = vec![WrapLine::default()];
+
+        for child_id in children {
+            let Some(child_data) = self.dom_adapter.get_node(&child_id) else {
+                continue;
+            };
+
+            // Non-stacked Nodes float on their own
+            if !child_data.position.is_stacked() {
+                let (child_revalidated, mut child_areas) = self.measure_node(
+                    child_id,
+                    &child_data,
+                    inner_area,
+                    &initial_available_area,
+                    must_cache_children,
+                    parent_is_dirty,
+                    Phase::Final,
+                );
+                child_areas.area.adjust_size(&child_data);
+                if child_revalidated && must_cache_children {
+                    self.layout.cache_node(child_id, child_areas);
+                }
+                continue;
+            }
+
+            let line_spacing = if line_is_empty { 0. } else { spacing };
+
+            let (mut child_revalidated, mut child_areas) = self.measure_node(
+                child_id,
+                &child_data,
+                inner_area,
+                &line_area(line_main + line_spacing, cross_offset),
+                must_cache_children,
+                parent_is_dirty,
+                Phase::Final,
+            );
+
+            let child_main = match parent_node.direction {
+                Direction::Horizontal => child_areas.area.width(),
+                Direction::Vertical => child_areas.area.height(),
+            };
+
+            // Move the child to a new line if it doesn't fit in the current one
+            if !line_is_empty && line_main + line_spacing + child_main > available_main {
+                cross_offset += line_cross + cross_spacing;
+                line_main = 0.;
+                line_cross = 0.;
+                lines.push(WrapLine {
+                    cross_offset,
+                    ..WrapLine::default()
+                });
+
+                (child_revalidated, child_areas) = self.measure_node(
+                    child_id,
+                    &child_data,
+                    inner_area,
+                    &line_area(line_main, cross_offset),
+                    must_cache_children,
+                    true,
+                    Phase::Final,
+                );
+            } else {
+                line_main += line_spacing;
+            }
+
+            child_areas.area.adjust_size(&child_data);
+
+            let (child_main, child_cross) = match parent_node.direction {
+                Direction::Horizontal => (child_areas.area.width(), child_areas.area.height()),
+                Direction::Vertical => (child_areas.area.height(), child_areas.area.width()),
+            };
+
+            let line = lines.last_mut().unwrap();
+            line.children
+                .push((child_id, child_data, line_main, child_cross));
+
+            line_main += child_main;
+            line_cross = line_cross.max(child_cross);
+            line_is_empty = false;
+            max_line_main = max_line_main.max(line_main);
+            line.main = line_main;
+            line.cross = line_cross;
+
+            // Cache the child layout if it was mutated and children must be cached
+            if child_revalidated && must_cache_children {
+                // Finally cache this node areas into Torin
+                self.layout.cache_node(child_id, child_areas);
+            }
+        }
+
+        let used_cross = cross_offset + line_cross;
+
+        let (used_width, used_height) = match parent_node.direction {
+            Direction::Horizontal => (max_line_main, used_cross),
+            Direction::Vertical => (used_cross, max_line_main),
+        };
+
+        let main_is_inner_sized = match parent_node.direction {
+            Direction::Horizontal => parent_node.width.inner_sized(),
+            Direction::Vertical => parent_node.height.inner_sized(),
+        };
+        // Align the lines in the main axis of the Node, or in its longest line if inner sized
+        let main_size = if main_is_inner_sized {
+            max_line_main
+        } else {
+            available_main
+        };
+        let parent_inner_area = *inner_area;
+
+        for line in lines {
+            let children_len = line.children.len();
+            let free_main = (main_size - line.main).max(0.);
+            let (mut main_offset, gap_size) = match parent_node.main_alignment {
+                Alignment::SpaceBetween if children_len > 1 => {
+                    (0., free_main / (children_len - 1) as f32)
+                }
+                Alignment::Start | Alignment::SpaceBetween => (0., 0.),
+                Alignment::Center => (free_main / 2., 0.),
+                Alignment::End => (free_main, 0.),
+                Alignment::SpaceEvenly => {
+                    let gap_size = free_main / (children_len + 1) as f32;
+                    (gap_size, gap_size)
+                }
+                Alignment::SpaceAround => {
+                    let gap_size = free_main / children_len as f32;
+                    (gap_size / 2., gap_size)
+                }
+            };
+
+            for (child_id, child_data, child_main_start, child_cross) in line.children {
+                let cross_alignment = child_data
+                    .align_self
+                    .as_ref()
+                    .unwrap_or(&parent_node.cross_alignment);
+                let child_cross_offset = match cross_alignment {
+                    Alignment::Center => (line.cross - child_cross) / 2.,
+                    Alignment::End => line.cross - child_cross,
+                    _ => 0.,
+                };
+
+                if main_offset != 0. || child_cross_offset != 0. {
+                    // Measure the child again in its aligned position
+                    let (child_revalidated, mut child_areas) = self.measure_node(
+                        child_id,
+                        &child_data,
+                        &parent_inner_area,
+                        &line_area(
+                            child_main_start + main_offset,
+                            line.cross_offset + child_cross_offset,
+                        ),
+                        must_cache_children,
+                        true,
+                        Phase::Final,
+                    );
+                    child_areas.area.adjust_size(&child_data);
+                    if child_revalidated && must_cache_children {
+                        self.layout.cache_node(child_id, child_areas);
+                    }
+                }
+
+                main_offset += gap_size;
+            }
+        }
+
+        inner_sizes.width += used_width;
+        inner_sizes.height += used_height;
+
+        if parent_node.width.inner_sized() {
+            area.size.width += used_width;
+            // Keep the inner area in sync
+            inner_area.size.width = area.size.width
+                - parent_node.padding.horizontal()
+                - parent_node.margin.horizontal();
+        }
+
+        if parent_node.height.inner_sized() {
+            area.size.height += used_height;
+            // Keep the inner area in sync
+            inner_area.size.height =
+                area.size.height - parent_node.padding.vertical() - parent_node.margin.vertical();
+        }
+    }
+
+    /// Find the cells of the grid occupied by the stacked children.
+    /// Nodes without an explicit placement take the next free cell, row by row.
+    fn place_grid_children(
+        &mut self,
+        children: &[Key],
+        parent_node: &Node,
+    ) -> FxHashMap<Key, GridCell> {
+        let columns_len = parent_node.grid_columns.len().max(1);
+
+        let mut cells = FxHashMap::default();
+        let mut occupied = FxHashSet::default();
+        let mut cursor = (0, 0);
+
+        for child_id in children {
+            let Some(child_data) = self.dom_adapter.get_node(child_id) else {
+                continue;
+            };
+
+            // Non-stacked Nodes float on their own
+            if !child_data.position.is_stacked() {
+                continue;
+            }
+
+            let fixed_column = child_data.grid_column.start;
+            let fixed_row = child_data.grid_row.start;
+            let column_span = match fixed_column {
+                Some(_) => child_data.grid_column.span,
+                None => child_data.grid_column.span.min(columns_len),
+            };
+            let row_span = child_data.grid_row.span;
+
+            let mut column = fixed_column.unwrap_or(if fixed_row.is_some() { 0 } else { cursor.1 });
+            let mut row = fixed_row.unwrap_or(cursor.0);
+
+            let is_free = |column: usize, row: usize| {
+                (column..column + column_span)
+                    .all(|c| (row..row + row_span).all(|r| !occupied.contains(&(c, r))))
+            };
+
+            loop {
+                let fits = fixed_column.is_some() || column + column_span <= columns_len;
+                if fits && is_free(column, row) {
+                    break;
+                }
+                match (fixed_column, fixed_row) {
+                    (None, _) if column + column_span < columns_len => column += 1,
+                    (None, None) => {
+                        column = 0;
+                        row += 1;
+                    }
+                    (Some(_), None) => row += 1,
+                    // There is no free cell left, so just overlap
+                    _ => break,
+                }
+            }
+
+            for c in column..column + column_span {
+                for r in row..row + row_span {
+                    occupied.insert((c, r));
+                }
+            }
+
+            if fixed_column.is_none() && fixed_row.is_none() {
+                cursor = (row, column + column_span);
+            }
+
+            cells.insert(
+                *child_id,
+                GridCell {
+                    column,
+                    row,
+                    column_span,
+                    row_span,
+                },
+            );
+        }
+
+        cells
+    }
+
+    /// Calculate the sizes of some grid tracks given the available space and the size of their content.
+    fn grid_tracks_sizes(
+        tracks: &[GridTrack],
+        content_sizes: &[f32],
+        available_size: f32,
+        spacing: f32,
+        inner_sized: bool,
+    ) -> Vec<f32> {
+        let mut sizes = vec![0f32; tracks.len()];
+        let mut fractions = 0.;
+
+        for (i, track) in tracks.iter().enumerate() {
+            match track {
+                GridTrack::Pixels(size) => sizes[i] = size.get(),
+                // Fractions of an unbounded space behave like `auto`
+                GridTrack::Fr(fraction) if !inner_sized => fractions += fraction.get(),
+                _ => sizes[i] = content_sizes[i],
+            }
+        }
+
+        let gaps = spacing * tracks.len().saturating_sub(1) as f32;
+        let remaining = (available_size - gaps - sizes.iter().sum::<f32>()).max(0.);
+        let fractions = fractions.max(1.0);
+
+        for (i, track) in tracks.iter().enumerate() {
+            if let GridTrack::Fr(fraction) = track {
+                if !inner_sized {
+                    sizes[i] = remaining * fraction.get() / fractions;
+                }
+            }
+        }
+
+        sizes
+    }
+
+    /// Calculate where each grid track starts.
+    fn grid_tracks_offsets(sizes: &[f32], origin: f32, spacing: f32) -> Vec<f32> {
+        sizes
+            .iter()
+            .scan(origin, |offset, size| {
+                let track_offset = *offset;
+                *offset += size + spacing;
+                Some(track_offset)
+            })
+            .collect()
+    }
+
+    /// Calculate the size of a group of grid tracks, including the spacing between them.
+    fn grid_span_size(sizes: &[f32], start: usize, span: usize, spacing: f32) -> f32 {
+        let tracks = &sizes[start.min(sizes.len())..(start + span).min(sizes.len())];
+        tracks.iter().sum::<f32>() + spacing * tracks.len().saturating_sub(1) as f32
     }
 
     /// Align the content of this node.
@@ -838,6 +2272,8 @@ where
         inner_area: &mut Area,
         parent_node: &Node,
         alignment_direction: AlignmentDirection,
+        // Whether some child overrides the cross alignment of the parent
+        children_align_self: bool,
     ) {
         struct NodeData<'a> {
             pub inner_origin: &'a mut f32,
@@ -855,10 +2291,14 @@ where
         let (is_vertical_not_start, is_horizontal_not_start) = match parent_node.direction {
             Direction::Vertical => (
                 parent_node.main_alignment.is_not_start(),
-                parent_node.cross_alignment.is_not_start() || parent_node.content.is_fit(),
+                parent_node.cross_alignment.is_not_start()
+                    || parent_node.content.is_fit()
+                    || children_align_self,
             ),
             Direction::Horizontal => (
-                parent_node.cross_alignment.is_not_start() || parent_node.content.is_fit(),
+                parent_node.cross_alignment.is_not_start()
+                    || parent_node.content.is_fit()
+                    || children_align_self,
                 parent_node.main_alignment.is_not_start(),
             ),
         };
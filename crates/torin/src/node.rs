@@ -3,12 +3,18 @@ pub use euclid::Rect;
 use crate::{
     alignment::Alignment,
     direction::Direction,
-    gaps::Gaps,
+    gaps::{
+        AutoMargins,
+        Gaps,
+    },
     geometry::Length,
     prelude::{
         Content,
+        GridPlacement,
+        GridTrack,
         Position,
         VisibleSize,
+        WrapContent,
     },
     scaled::Scaled,
     size::Size,
@@ -43,10 +49,17 @@ pub struct Node {
     /// Inner margin
     pub margin: Gaps,
 
+    /// Sides of the margin set to `auto`
+    pub auto_margins: AutoMargins,
+
     /// Inner position offsets
     pub offset_x: Length,
     pub offset_y: Length,
 
+    /// Whether its inner Nodes can be scrolled with its offsets, even if they are not scrolled yet.
+    /// Sticky inner Nodes stay inside the viewport of the closest scroll container.
+    pub scroll_container: bool,
+
     /// Direction in which it's inner Nodes will be stacked
     pub direction: Direction,
 
@@ -55,12 +68,47 @@ pub struct Node {
 
     pub content: Content,
 
+    /// Whether the inner Nodes that overflow the main axis flow onto a new line
+    pub wrap_content: WrapContent,
+
     /// A Node might depend on inner sizes but have a fixed position, like scroll views.
     pub has_layout_references: bool,
 
     pub contains_text: bool,
 
+    /// Space between the inner Nodes in the main axis
     pub spacing: Length,
+
+    /// Space between the inner Nodes in the cross axis (lines of wrapped content or grid tracks),
+    /// the same as `spacing` if not set
+    pub cross_spacing: Option<Length>,
+
+    /// How much of the leftover space of a flex container this Node takes, on top of its own size
+    pub flex_grow: Length,
+
+    /// How much this Node shrinks relative to its siblings when a flex container overflows
+    pub flex_shrink: Length,
+
+    /// Main size from where this Node grows or shrinks in a flex container, its own size if `auto`
+    pub flex_basis: Size,
+
+    /// Columns of a grid container
+    pub grid_columns: Vec<GridTrack>,
+
+    /// Rows of a grid container, more will be added with `auto` size if needed
+    pub grid_rows: Vec<GridTrack>,
+
+    /// Columns occupied by this Node inside a grid container
+    pub grid_column: GridPlacement,
+
+    /// Rows occupied by this Node inside a grid container
+    pub grid_row: GridPlacement,
+
+    /// Overrides the cross alignment of the parent for this Node
+    pub align_self: Option<Alignment>,
+
+    /// Ratio between the width and the height, used to derive the `auto` dimension from the other one
+    pub aspect_ratio: Option<f32>,
 }
 
 impl Scaled for Node {
@@ -71,12 +119,22 @@ impl Scaled for Node {
         self.minimum_height.scale(scale_factor);
         self.maximum_width.scale(scale_factor);
         self.maximum_height.scale(scale_factor);
+        self.flex_basis.scale(scale_factor);
         self.margin.scale(scale_factor);
         self.padding.scale(scale_factor);
         self.offset_x *= scale_factor;
         self.offset_y *= scale_factor;
         self.position.scale(scale_factor);
         self.spacing *= scale_factor;
+        if let Some(cross_spacing) = &mut self.cross_spacing {
+            *cross_spacing *= scale_factor;
+        }
+        self.grid_columns
+            .iter_mut()
+            .for_each(|track| track.scale(scale_factor));
+        self.grid_rows
+            .iter_mut()
+            .for_each(|track| track.scale(scale_factor));
     }
 }
 
@@ -144,6 +202,7 @@ impl Node {
             height,
             offset_x,
             offset_y,
+            scroll_container: true,
             ..Default::default()
         }
     }
@@ -278,11 +337,142 @@ impl Node {
         }
     }
 
+    /// Construct a new Node given a size and grid tracks
+    pub fn from_size_and_grid(
+        width: Size,
+        height: Size,
+        grid_columns: Vec<GridTrack>,
+        grid_rows: Vec<GridTrack>,
+    ) -> Self {
+        Self {
+            width,
+            height,
+            content: Content::Grid,
+            grid_columns,
+            grid_rows,
+            ..Default::default()
+        }
+    }
+
+    /// Construct a new Node given a size and its placement inside a grid
+    pub fn from_size_and_grid_placement(
+        width: Size,
+        height: Size,
+        grid_column: GridPlacement,
+        grid_row: GridPlacement,
+    ) -> Self {
+        Self {
+            width,
+            height,
+            grid_column,
+            grid_row,
+            ..Default::default()
+        }
+    }
+
+    /// Construct a new Node given a size, a direction, spacing and whether to wrap its content
+    pub fn from_size_and_direction_and_wrap(
+        width: Size,
+        height: Size,
+        direction: Direction,
+        spacing: Length,
+        wrap_content: WrapContent,
+    ) -> Self {
+        Self {
+            width,
+            height,
+            direction,
+            wrap_content,
+            spacing,
+            ..Default::default()
+        }
+    }
+
+    /// Construct a new Node given a size and its own cross alignment
+    pub fn from_size_and_align_self(width: Size, height: Size, align_self: Alignment) -> Self {
+        Self {
+            width,
+            height,
+            align_self: Some(align_self),
+            ..Default::default()
+        }
+    }
+
+    /// Construct a new Node given a size and the sides of its margin that are `auto`
+    pub fn from_size_and_auto_margins(
+        width: Size,
+        height: Size,
+        auto_margins: AutoMargins,
+    ) -> Self {
+        Self {
+            width,
+            height,
+            auto_margins,
+            ..Default::default()
+        }
+    }
+
+    /// Construct a new Node given a size and an aspect ratio
+    pub fn from_size_and_aspect_ratio(width: Size, height: Size, aspect_ratio: f32) -> Self {
+        Self {
+            width,
+            height,
+            aspect_ratio: Some(aspect_ratio),
+            ..Default::default()
+        }
+    }
+
+    /// Mirror the horizontal properties of this Node (paddings, margins, alignments, positions and offsets),
+    /// given the direction of its parent.
+    ///
+    /// Along with reversing the order of the children of horizontal Nodes, this results in a right-to-left layout.
+    pub fn mirror_horizontally(&mut self, parent_direction: &Direction) {
+        self.padding = self.padding.mirrored();
+        self.margin = self.margin.mirrored();
+        self.auto_margins = self.auto_margins.mirrored();
+        self.offset_x = -self.offset_x;
+        self.position.mirror_horizontally();
+
+        match self.direction {
+            Direction::Horizontal => self.main_alignment = self.main_alignment.mirrored(),
+            Direction::Vertical => self.cross_alignment = self.cross_alignment.mirrored(),
+        }
+
+        if *parent_direction == Direction::Vertical {
+            self.align_self = self.align_self.as_ref().map(Alignment::mirrored);
+        }
+    }
+
+    /// Space between the inner Nodes in the cross axis
+    pub fn cross_spacing(&self) -> Length {
+        self.cross_spacing.unwrap_or(self.spacing)
+    }
+
+    /// Can the inner Nodes be scrolled with its offsets?
+    pub fn is_scroll_container(&self) -> bool {
+        self.scroll_container
+    }
+
+    /// Does its width only depend on its own attributes?
+    pub fn has_fixed_width(&self) -> bool {
+        self.width.is_fixed()
+            && (self.minimum_width.is_fixed() || self.minimum_width == Size::Inner)
+            && (self.maximum_width.is_fixed() || self.maximum_width == Size::Inner)
+    }
+
+    /// Does its height only depend on its own attributes?
+    pub fn has_fixed_height(&self) -> bool {
+        self.height.is_fixed()
+            && (self.minimum_height.is_fixed() || self.minimum_height == Size::Inner)
+            && (self.maximum_height.is_fixed() || self.maximum_height == Size::Inner)
+    }
+
     /// Has properties that depend on the inner Nodes?
     pub fn does_depend_on_inner(&self) -> bool {
         self.width.inner_sized()
             || self.height.inner_sized()
             || self.contains_text
+            || self.content.is_grid()
             || self.do_inner_depend_on_parent()
     }
 
@@ -292,4 +482,9 @@ impl Node {
             || self.main_alignment.is_not_start()
             || self.has_layout_references
     }
+
+    /// Is it placed by its parent with `align_self` or `auto` margins?
+    pub fn does_depend_on_parent(&self) -> bool {
+        self.align_self.is_some() || self.auto_margins != AutoMargins::default()
+    }
 }
@@ -0,0 +1,121 @@
+use rayon::prelude::*;
+use rustc_hash::FxHashSet;
+
+use crate::{
+    custom_measurer::ParallelLayoutMeasurer,
+    dom_adapter::{
+        DOMAdapter,
+        NodeKey,
+    },
+    measure::{
+        DeferredNode,
+        MeasureContext,
+    },
+    torin::{
+        LayoutMetadata,
+        Torin,
+    },
+};
+
+/// Measure the children of the deferred Nodes in parallel.
+///
+/// The deferred Nodes are split in a group per thread, every group is measured with its own copy
+/// of the measurer and DOM adapter, and the layout of their descendants is moved out of the main
+/// layout while they are measured.
+pub fn measure_deferred_in_parallel<Key, L, D>(
+    context: &mut MeasureContext<'_, Key, L, D>,
+    deferred: Vec<DeferredNode<Key>>,
+) where
+    Key: NodeKey + Send + Sync,
+    L: ParallelLayoutMeasurer<Key>,
+    D: DOMAdapter<Key> + Clone + Send,
+{
+    let group_size = deferred.len().div_ceil(rayon::current_num_threads());
+    let root_area = context.layout_metadata.root_area;
+    let measure_deferred = context.measure_deferred;
+
+    let mut groups = Vec::new();
+    let mut deferred = deferred.into_iter().peekable();
+    while deferred.peek().is_some() {
+        let nodes = deferred.by_ref().take(group_size).collect::<Vec<_>>();
+        let mut layout = Torin::new();
+        let mut dirty_ancestors = FxHashSet::default();
+        for node in &nodes {
+            fork_subtree(context, node.node_id(), &mut layout, &mut dirty_ancestors);
+        }
+        let measurer = context.measurer.as_ref().map(ParallelLayoutMeasurer::fork);
+        groups.push((
+            nodes,
+            layout,
+            dirty_ancestors,
+            measurer,
+            context.dom_adapter.clone(),
+        ));
+    }
+
+    let measured_groups = groups
+        .into_par_iter()
+        .map(
+            |(nodes, mut layout, dirty_ancestors, mut measurer, mut dom_adapter)| {
+                let mut group_context = MeasureContext {
+                    layout: &mut layout,
+                    measurer: &mut measurer,
+                    dom_adapter: &mut dom_adapter,
+                    layout_metadata: LayoutMetadata { root_area },
+                    scroll_viewport: None,
+                    dirty_ancestors,
+                    measure_deferred,
+                    defer_children: false,
+                    deferred: Vec::new(),
+                };
+                for node in nodes {
+                    group_context.measure_deferred_node(node);
+                }
+                (layout, measurer)
+            },
+        )
+        .collect::<Vec<_>>();
+
+    for (layout, measurer) in measured_groups {
+        context.layout.join(layout);
+        if let (Some(measurer), Some(forked_measurer)) = (context.measurer.as_mut(), measurer) {
+            measurer.join(forked_measurer);
+        }
+    }
+}
+
+/// Move the layout of a Node and its descendants to another layout.
+fn fork_subtree<Key, L, D>(
+    context: &mut MeasureContext<'_, Key, L, D>,
+    node_id: Key,
+    layout: &mut Torin<Key>,
+    dirty_ancestors: &mut FxHashSet<Key>,
+) where
+    Key: NodeKey,
+    L: ParallelLayoutMeasurer<Key>,
+    D: DOMAdapter<Key>,
+{
+    let mut nodes = vec![node_id];
+    while let Some(node_id) = nodes.pop() {
+        let main_layout = &mut *context.layout;
+        if let Some(layout_node) = main_layout.results.remove(&node_id) {
+            layout.results.insert(node_id, layout_node);
+        }
+        if let Some(inputs) = main_layout.measure_inputs.remove(&node_id) {
+            layout.measure_inputs.insert(node_id, inputs);
+        }
+        if main_layout.pinned.remove(&node_id) {
+            layout.pinned.insert(node_id);
+        }
+        if main_layout.stacked_children.remove(&node_id) {
+            layout.stacked_children.insert(node_id);
+        }
+        if let Some(reason) = main_layout.dirty.get(&node_id) {
+            layout.dirty.insert(node_id, *reason);
+        }
+        if context.dirty_ancestors.contains(&node_id) {
+            dirty_ancestors.insert(node_id);
+        }
+        nodes.extend(context.dom_adapter.children_of(&node_id));
+    }
+}
@@ -4,7 +4,10 @@ use std::{
 };
 
 pub use euclid::Rect;
-use rustc_hash::FxHashMap;
+use rustc_hash::{
+    FxHashMap,
+    FxHashSet,
+};
 
 use crate::{
     custom_measurer::LayoutMeasurer,
@@ -16,6 +19,8 @@ use crate::{
     geometry::Area,
     measure::{
         MeasureContext,
+        MeasureDeferred,
+        MeasureInputs,
         Phase,
     },
     prelude::{
@@ -23,6 +28,11 @@ use crate::{
         Gaps,
     },
 };
+#[cfg(feature = "parallel")]
+use crate::{
+    custom_measurer::ParallelLayoutMeasurer,
+    parallel::measure_deferred_in_parallel,
+};
 
 pub struct LayoutMetadata {
     pub root_area: Area,
@@ -70,6 +80,15 @@ pub enum DirtyReason {
     None,
     /// Node was moved from one position to another in its parent' children list.
     Reorder,
+    /// Node might have been moved by a sibling, its layout can be reused if it doesn't depend on its new position.
+    Moved,
+}
+
+impl DirtyReason {
+    /// Whether the Node itself has changed.
+    pub fn is_changed(&self) -> bool {
+        *self != Self::Moved
+    }
 }
 
 pub struct Torin<Key: NodeKey> {
@@ -81,6 +100,15 @@ pub struct Torin<Key: NodeKey> {
 
     /// Best Root node candidate from where to start measuring
     pub root_node_candidate: RootNodeCandidate<Key>,
+
+    /// Inputs of the last measurement of the cached Nodes, used to reuse their layout
+    pub(crate) measure_inputs: FxHashMap<Key, MeasureInputs>,
+
+    /// Nodes that contain Nodes whose layout can't be moved along with their ancestors
+    pub(crate) pinned: FxHashSet<Key>,
+
+    /// Nodes whose children are simply stacked one after another, so their unchanged children can be skipped
+    pub(crate) stacked_children: FxHashSet<Key>,
 }
 
 impl<Key: NodeKey> Default for Torin<Key> {
@@ -96,6 +124,9 @@ impl<Key: NodeKey> Torin<Key> {
             results: HashMap::default(),
             dirty: FxHashMap::default(),
             root_node_candidate: RootNodeCandidate::None,
+            measure_inputs: FxHashMap::default(),
+            pinned: FxHashSet::default(),
+            stacked_children: FxHashSet::default(),
         }
     }
 
@@ -108,6 +139,9 @@ impl<Key: NodeKey> Torin<Key> {
         self.root_node_candidate = RootNodeCandidate::None;
         self.results.clear();
         self.dirty.clear();
+        self.measure_inputs.clear();
+        self.pinned.clear();
+        self.stacked_children.clear();
     }
 
     /// Read the HashSet of dirty nodes
@@ -119,6 +153,9 @@ impl<Key: NodeKey> Torin<Key> {
     pub fn raw_remove(&mut self, node_id: Key) {
         self.results.remove(&node_id);
         self.dirty.remove(&node_id);
+        self.measure_inputs.remove(&node_id);
+        self.pinned.remove(&node_id);
+        self.stacked_children.remove(&node_id);
         if let RootNodeCandidate::Valid(id) = self.root_node_candidate {
             if id == node_id {
                 self.root_node_candidate = RootNodeCandidate::None;
@@ -188,6 +225,15 @@ impl<Key: NodeKey> Torin<Key> {
         let parent_id = dom_adapter.parent_of(&node_id);
 
         if let Some(parent_id) = parent_id {
+            // Sticky Nodes are moved by their parent
+            if dom_adapter
+                .get_node(&node_id)
+                .is_some_and(|node| node.position.is_sticky())
+            {
+                self.root_node_candidate
+                    .propose_new_candidate(&parent_id, dom_adapter);
+            }
+
             let parent = dom_adapter.get_node(&parent_id);
 
             if let Some(parent) = parent {
@@ -199,21 +245,34 @@ impl<Key: NodeKey> Torin<Key> {
                     let multiple_children = parent_children.len() > 1;
 
                     let mut found_node = match reason {
-                        DirtyReason::None => false,
+                        DirtyReason::None | DirtyReason::Moved => false,
                         // Invalidate all siblings if the node was reordered
                         DirtyReason::Reorder => true,
                     };
                     for child_id in parent_children {
-                        if found_node {
-                            self.safe_invalidate(child_id, dom_adapter);
+                        // The next siblings might have been moved
+                        if found_node
+                            && !self.dirty.contains_key(&child_id)
+                            && dom_adapter.is_node_valid(&child_id)
+                        {
+                            self.dirty.insert(child_id, DirtyReason::Moved);
                         }
                         if child_id == node_id {
                             found_node = true;
                         }
                     }
 
-                    // Try using the node's parent as root candidate if it has multiple children
-                    if multiple_children || parent.do_inner_depend_on_parent() {
+                    let placed_by_parent = dom_adapter
+                        .get_node(&node_id)
+                        .is_some_and(|node| node.does_depend_on_parent());
+
+                    // Try using the node's parent as root candidate if it has multiple children,
+                    // it places the node or sizes it as a flex container
+                    if multiple_children
+                        || parent.do_inner_depend_on_parent()
+                        || parent.content.is_flex()
+                        || placed_by_parent
+                    {
                         self.root_node_candidate
                             .propose_new_candidate(&parent_id, dom_adapter);
                     }
@@ -246,6 +305,42 @@ impl<Key: NodeKey> Torin<Key> {
         root_area: Area,
         measurer: &mut Option<impl LayoutMeasurer<Key>>,
         dom_adapter: &mut impl DOMAdapter<Key>,
+    ) {
+        self.measure_with(suggested_root_id, root_area, measurer, dom_adapter, None);
+    }
+
+    /// Measure dirty Nodes, measuring the children of sibling Nodes in parallel
+    /// when their sizes don't depend on them.
+    /// # Panics
+    /// Might panic if the final root node is not found.
+    #[cfg(feature = "parallel")]
+    pub fn measure_parallel<L, D>(
+        &mut self,
+        suggested_root_id: Key,
+        root_area: Area,
+        measurer: &mut Option<L>,
+        dom_adapter: &mut D,
+    ) where
+        Key: Send + Sync,
+        L: ParallelLayoutMeasurer<Key>,
+        D: DOMAdapter<Key> + Clone + Send,
+    {
+        self.measure_with(
+            suggested_root_id,
+            root_area,
+            measurer,
+            dom_adapter,
+            Some(measure_deferred_in_parallel::<Key, L, D>),
+        );
+    }
+
+    fn measure_with<L: LayoutMeasurer<Key>, D: DOMAdapter<Key>>(
+        &mut self,
+        suggested_root_id: Key,
+        root_area: Area,
+        measurer: &mut Option<L>,
+        dom_adapter: &mut D,
+        measure_deferred: Option<MeasureDeferred<Key, L, D>>,
     ) {
         // If there are previosuly cached results
         // But no dirty nodes, we can simply skip the measurement
@@ -290,11 +385,42 @@ impl<Key: NodeKey> Torin<Key> {
             available_area.move_with_offsets(&root_parent.offset_x, &root_parent.offset_y);
         }
 
+        // Sticky Nodes need the viewport of the closest scroll container
+        let mut scroll_viewport = None;
+        let mut ancestor_id = root_parent_id;
+        while let Some(id) = ancestor_id {
+            if dom_adapter
+                .get_node(&id)
+                .is_some_and(|ancestor| ancestor.is_scroll_container())
+            {
+                scroll_viewport = self.get(id).map(|layout_node| layout_node.inner_area);
+                break;
+            }
+            ancestor_id = dom_adapter.parent_of(&id);
+        }
+
+        // Nodes that contain dirty Nodes can't reuse their layout
+        let mut dirty_ancestors = FxHashSet::default();
+        for node_id in self.dirty.keys() {
+            let mut ancestor_id = dom_adapter.parent_of(node_id);
+            while let Some(id) = ancestor_id {
+                if !dirty_ancestors.insert(id) {
+                    break;
+                }
+                ancestor_id = dom_adapter.parent_of(&id);
+            }
+        }
+
         let mut measure_context = MeasureContext {
             layout: self,
             layout_metadata,
             dom_adapter,
             measurer,
+            scroll_viewport,
+            dirty_ancestors,
+            measure_deferred,
+            defer_children: false,
+            deferred: Vec::new(),
         };
 
         let (root_revalidated, mut root_layout_node) = measure_context.measure_node(
@@ -328,4 +454,13 @@ impl<Key: NodeKey> Torin<Key> {
     pub fn cache_node(&mut self, node_id: Key, layout_node: LayoutNode) {
         self.results.insert(node_id, layout_node);
     }
+
+    /// Move back the layout of the Nodes that were measured in another thread.
+    #[cfg(feature = "parallel")]
+    pub(crate) fn join(&mut self, forked: Torin<Key>) {
+        self.results.extend(forked.results);
+        self.measure_inputs.extend(forked.measure_inputs);
+        self.pinned.extend(forked.pinned);
+        self.stacked_children.extend(forked.stacked_children);
+    }
 }
@@ -21,6 +21,16 @@ impl Alignment {
         )
     }
 
+    /// Swap `start` and `end`, used to mirror the horizontal axis.
+    #[must_use]
+    pub fn mirrored(&self) -> Self {
+        match self {
+            Self::Start => Self::End,
+            Self::End => Self::Start,
+            alignment => alignment.clone(),
+        }
+    }
+
     pub fn pretty(&self) -> String {
         match self {
             Self::Start => "start".to_string(),
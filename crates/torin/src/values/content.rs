@@ -4,6 +4,7 @@ pub enum Content {
     Normal,
     Fit,
     Flex,
+    Grid,
 }
 
 impl Content {
@@ -14,6 +15,10 @@ impl Content {
     pub fn is_flex(&self) -> bool {
         self == &Self::Flex
     }
+
+    pub fn is_grid(&self) -> bool {
+        self == &Self::Grid
+    }
 }
 
 impl Content {
@@ -22,6 +27,7 @@ impl Content {
             Self::Normal => "normal".to_owned(),
             Self::Fit => "fit".to_owned(),
             Self::Flex => "flex".to_owned(),
+            Self::Grid => "grid".to_owned(),
         }
     }
 }
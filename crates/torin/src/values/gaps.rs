@@ -1,6 +1,7 @@
 pub use euclid::Rect;
 
 use crate::{
+    direction::Direction,
     geometry::Length,
     scaled::Scaled,
 };
@@ -62,6 +63,16 @@ impl Gaps {
         self.left.get()
     }
 
+    /// Swap the left and right sides.
+    #[must_use]
+    pub fn mirrored(&self) -> Self {
+        Self {
+            right: self.left,
+            left: self.right,
+            ..*self
+        }
+    }
+
     pub fn pretty(&self) -> String {
         format!(
             "({}, {}, {}, {})",
@@ -81,3 +92,52 @@ impl Scaled for Gaps {
         self.bottom *= scale;
     }
 }
+
+/// Sides of a margin set to `auto`.
+///
+/// The auto sides in the main axis of the parent take an equal share of its free space,
+/// e.g an auto left margin pushes a Node to the end of a horizontal parent.
+#[allow(clippy::struct_excessive_bools)]
+#[derive(PartialEq, Eq, Clone, Debug, Default, Copy)]
+pub struct AutoMargins {
+    pub top: bool,
+    pub right: bool,
+    pub bottom: bool,
+    pub left: bool,
+}
+
+impl AutoMargins {
+    #[allow(clippy::fn_params_excessive_bools)]
+    pub const fn new(top: bool, right: bool, bottom: bool, left: bool) -> Self {
+        Self {
+            top,
+            right,
+            bottom,
+            left,
+        }
+    }
+
+    /// Get the auto sides (start and end) along the main axis of the given direction.
+    pub fn main_axis(&self, direction: &Direction) -> (bool, bool) {
+        match direction {
+            Direction::Vertical => (self.top, self.bottom),
+            Direction::Horizontal => (self.left, self.right),
+        }
+    }
+
+    /// Swap the left and right sides.
+    #[must_use]
+    pub fn mirrored(&self) -> Self {
+        Self {
+            right: self.left,
+            left: self.right,
+            ..*self
+        }
+    }
+
+    /// Amount of auto sides along the main axis of the given direction.
+    pub fn main_axis_count(&self, direction: &Direction) -> usize {
+        let (start, end) = self.main_axis(direction);
+        usize::from(start) + usize::from(end)
+    }
+}
@@ -0,0 +1,68 @@
+use crate::{
+    geometry::Length,
+    scaled::Scaled,
+};
+
+/// Size of a column or row of a grid.
+#[derive(PartialEq, Clone, Debug, Default)]
+pub enum GridTrack {
+    /// Fixed size.
+    Pixels(Length),
+    /// Fraction of the space left by the rest of tracks.
+    Fr(Length),
+    /// As big as the biggest Node inside it.
+    #[default]
+    Auto,
+}
+
+impl GridTrack {
+    pub fn pretty(&self) -> String {
+        match self {
+            Self::Pixels(s) => format!("{}", s.get()),
+            Self::Fr(f) => format!("{}fr", f.get()),
+            Self::Auto => "auto".to_owned(),
+        }
+    }
+}
+
+impl Scaled for GridTrack {
+    fn scale(&mut self, scale_factor: f32) {
+        if let Self::Pixels(s) = self {
+            *s *= scale_factor;
+        }
+    }
+}
+
+/// Placement of a Node in the columns or rows of a grid.
+#[derive(PartialEq, Clone, Debug)]
+pub struct GridPlacement {
+    /// Zero-based track where the Node starts, `None` to place it in the next free cell.
+    pub start: Option<usize>,
+    /// How many tracks the Node spans.
+    pub span: usize,
+}
+
+impl Default for GridPlacement {
+    fn default() -> Self {
+        Self {
+            start: None,
+            span: 1,
+        }
+    }
+}
+
+impl GridPlacement {
+    pub fn new(start: Option<usize>, span: usize) -> Self {
+        Self {
+            start,
+            span: span.max(1),
+        }
+    }
+
+    pub fn pretty(&self) -> String {
+        match self.start {
+            Some(start) => format!("{} / span {}", start + 1, self.span),
+            None => format!("span {}", self.span),
+        }
+    }
+}
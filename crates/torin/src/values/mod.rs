@@ -2,9 +2,11 @@ pub mod alignment;
 pub mod content;
 pub mod direction;
 pub mod gaps;
+pub mod grid;
 pub mod position;
 pub mod size;
 pub mod visible_size;
+pub mod wrap_content;
 
 pub mod prelude {
     pub use crate::{
@@ -12,8 +14,10 @@ pub mod prelude {
         content::*,
         direction::*,
         gaps::*,
+        grid::*,
         position::*,
         size::*,
         visible_size::*,
+        wrap_content::*,
     };
 }
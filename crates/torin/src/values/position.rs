@@ -15,12 +15,73 @@ pub struct PositionSides {
     pub left: Option<f32>,
 }
 
+impl PositionSides {
+    /// Offset to move an area so its sides stay at the given distances from the viewport,
+    /// but without leaving the containing area if there is one.
+    #[must_use]
+    pub fn sticky_offset(
+        &self,
+        area: &Area,
+        viewport: &Area,
+        containing_area: Option<&Area>,
+    ) -> (f32, f32) {
+        let offset_x = Self::sticky_axis_offset(
+            (area.min_x(), area.max_x()),
+            (viewport.min_x(), viewport.max_x()),
+            containing_area
+                .map(|containing_area| (containing_area.min_x(), containing_area.max_x())),
+            (self.left, self.right),
+        );
+        let offset_y = Self::sticky_axis_offset(
+            (area.min_y(), area.max_y()),
+            (viewport.min_y(), viewport.max_y()),
+            containing_area
+                .map(|containing_area| (containing_area.min_y(), containing_area.max_y())),
+            (self.top, self.bottom),
+        );
+        (offset_x, offset_y)
+    }
+
+    fn sticky_axis_offset(
+        (start, end): (f32, f32),
+        (viewport_start, viewport_end): (f32, f32),
+        containing: Option<(f32, f32)>,
+        (start_side, end_side): (Option<f32>, Option<f32>),
+    ) -> f32 {
+        let mut offset = start_side.map_or(0., |start_side| {
+            (viewport_start + start_side - start).max(0.)
+        });
+
+        // The start side takes priority
+        if offset <= 0. {
+            if let Some(end_side) = end_side {
+                offset = (viewport_end - end_side - end).min(0.);
+            }
+        }
+
+        // Never leave the containing area
+        if let Some((containing_start, containing_end)) = containing {
+            if offset > 0. {
+                offset = offset.min((containing_end - end).max(0.));
+            } else if offset < 0. {
+                offset = offset.max((containing_start - start).min(0.));
+            }
+        }
+
+        offset
+    }
+}
+
 #[derive(PartialEq, Clone, Debug)]
 pub enum Position {
     Stacked(Box<PositionSides>),
 
     Absolute(Box<PositionSides>),
     Global(Box<PositionSides>),
+
+    /// Stacked like its siblings, but kept inside the scrolled viewport of its closest scrollable ancestor,
+    /// at the specified distances.
+    Sticky(Box<PositionSides>),
 }
 
 impl Default for Position {
@@ -32,9 +93,10 @@ impl Default for Position {
 impl Position {
     pub fn swap_for(&mut self, mut other: Self) {
         let old_positions = match self {
-            Self::Global(positions) | Self::Absolute(positions) | Self::Stacked(positions) => {
-                positions.clone()
-            }
+            Self::Global(positions)
+            | Self::Absolute(positions)
+            | Self::Stacked(positions)
+            | Self::Sticky(positions) => positions.clone(),
         };
 
         match &mut other {
@@ -47,10 +109,16 @@ impl Position {
             Self::Stacked(_) => {
                 *self = Self::new_stacked();
             }
+            Self::Sticky(_) => {
+                *self = Self::new_sticky();
+            }
         };
 
         match self {
-            Self::Absolute(positions) | Self::Global(positions) | Self::Stacked(positions) => {
+            Self::Absolute(positions)
+            | Self::Global(positions)
+            | Self::Stacked(positions)
+            | Self::Sticky(positions) => {
                 *positions = old_positions;
             }
         };
@@ -83,8 +151,22 @@ impl Position {
         }))
     }
 
+    pub fn new_sticky() -> Self {
+        Self::Sticky(Box::new(PositionSides {
+            top: None,
+            right: None,
+            bottom: None,
+            left: None,
+        }))
+    }
+
+    /// Whether it's laid out along its siblings, sticky positions are too.
     pub fn is_stacked(&self) -> bool {
-        matches!(self, Self::Stacked { .. })
+        matches!(self, Self::Stacked { .. } | Self::Sticky { .. })
+    }
+
+    pub fn is_sticky(&self) -> bool {
+        matches!(self, Self::Sticky { .. })
     }
 
     pub fn is_absolute(&self) -> bool {
@@ -95,9 +177,24 @@ impl Position {
         matches!(self, Self::Global { .. })
     }
 
+    /// Swap the left and right sides.
+    pub fn mirror_horizontally(&mut self) {
+        match self {
+            Self::Absolute(position)
+            | Self::Global(position)
+            | Self::Stacked(position)
+            | Self::Sticky(position) => {
+                std::mem::swap(&mut position.left, &mut position.right);
+            }
+        }
+    }
+
     pub fn set_top(&mut self, value: f32) {
         match self {
-            Self::Absolute(position) | Self::Global(position) | Self::Stacked(position) => {
+            Self::Absolute(position)
+            | Self::Global(position)
+            | Self::Stacked(position)
+            | Self::Sticky(position) => {
                 position.top = Some(value);
             }
         }
@@ -105,7 +202,10 @@ impl Position {
 
     pub fn set_right(&mut self, value: f32) {
         match self {
-            Self::Absolute(position) | Self::Global(position) | Self::Stacked(position) => {
+            Self::Absolute(position)
+            | Self::Global(position)
+            | Self::Stacked(position)
+            | Self::Sticky(position) => {
                 position.right = Some(value);
             }
         }
@@ -113,7 +213,10 @@ impl Position {
 
     pub fn set_bottom(&mut self, value: f32) {
         match self {
-            Self::Absolute(position) | Self::Global(position) | Self::Stacked(position) => {
+            Self::Absolute(position)
+            | Self::Global(position)
+            | Self::Stacked(position)
+            | Self::Sticky(position) => {
                 position.bottom = Some(value);
             }
         }
@@ -121,7 +224,10 @@ impl Position {
 
     pub fn set_left(&mut self, value: f32) {
         match self {
-            Self::Absolute(position) | Self::Global(position) | Self::Stacked(position) => {
+            Self::Absolute(position)
+            | Self::Global(position)
+            | Self::Stacked(position)
+            | Self::Sticky(position) => {
                 position.left = Some(value);
             }
         }
@@ -135,7 +241,7 @@ impl Position {
         root_area: &Area,
     ) -> Point2D {
         match self {
-            Self::Stacked(_) => available_parent_area.origin,
+            Self::Stacked(_) | Self::Sticky(_) => available_parent_area.origin,
             Self::Absolute(absolute_position) => {
                 let PositionSides {
                     top,
@@ -197,7 +303,7 @@ impl Position {
 impl Scaled for Position {
     fn scale(&mut self, scale_factor: f32) {
         match self {
-            Self::Absolute(position) | Self::Global(position) => {
+            Self::Absolute(position) | Self::Global(position) | Self::Sticky(position) => {
                 if let Some(top) = &mut position.top {
                     *top *= scale_factor;
                 }
@@ -220,13 +326,15 @@ impl Position {
     pub fn pretty(&self) -> String {
         match self {
             Self::Stacked(_) => "stacked".to_string(),
-            Self::Absolute(positions) | Self::Global(positions) => format!(
-                "{}, {}, {}, {}",
-                positions.top.unwrap_or_default(),
-                positions.right.unwrap_or_default(),
-                positions.bottom.unwrap_or_default(),
-                positions.left.unwrap_or_default()
-            ),
+            Self::Absolute(positions) | Self::Global(positions) | Self::Sticky(positions) => {
+                format!(
+                    "{}, {}, {}, {}",
+                    positions.top.unwrap_or_default(),
+                    positions.right.unwrap_or_default(),
+                    positions.bottom.unwrap_or_default(),
+                    positions.left.unwrap_or_default()
+                )
+            }
         }
     }
 }
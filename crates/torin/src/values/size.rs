@@ -51,6 +51,11 @@ impl Size {
         matches!(self, Self::Inner | Self::FillMinimum)
     }
 
+    /// Whether it only depends on the root area, not on the parent or the children.
+    pub fn is_fixed(&self) -> bool {
+        matches!(self, Self::Pixels(_) | Self::RootPercentage(_))
+    }
+
     pub fn pretty(&self) -> String {
         match self {
             Self::Inner => "auto".to_string(),
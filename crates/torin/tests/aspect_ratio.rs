@@ -0,0 +1,99 @@
+use euclid::Length;
+use torin::{
+    prelude::*,
+    test_utils::*,
+};
+
+#[test]
+pub fn aspect_ratio_height_from_width() {
+    let (mut layout, mut measurer) = test_utils();
+
+    let mut mocked_dom = TestingDOM::default();
+    mocked_dom.add(
+        0,
+        None,
+        vec![1, 2],
+        Node::from_size_and_direction(
+            Size::Pixels(Length::new(320.0)),
+            Size::Pixels(Length::new(1000.0)),
+            Direction::Vertical,
+        ),
+    );
+    mocked_dom.add(
+        1,
+        Some(0),
+        vec![],
+        Node::from_size_and_aspect_ratio(
+            Size::Percentage(Length::new(100.0)),
+            Size::Inner,
+            16.0 / 9.0,
+        ),
+    );
+    mocked_dom.add(
+        2,
+        Some(0),
+        vec![],
+        Node {
+            margin: Gaps::new(10.0, 10.0, 10.0, 10.0),
+            ..Node::from_size_and_aspect_ratio(Size::Pixels(Length::new(100.0)), Size::Inner, 2.0)
+        },
+    );
+
+    layout.measure(
+        0,
+        Rect::new(Point2D::new(0.0, 0.0), Size2D::new(1000.0, 1000.0)),
+        &mut measurer,
+        &mut mocked_dom,
+    );
+
+    assert_eq!(
+        layout.get(1).unwrap().area,
+        Rect::new(Point2D::new(0.0, 0.0), Size2D::new(320.0, 180.0)),
+    );
+    assert_eq!(
+        layout.get(2).unwrap().visible_area(),
+        Rect::new(Point2D::new(10.0, 190.0), Size2D::new(100.0, 50.0)),
+    );
+}
+
+#[test]
+pub fn aspect_ratio_width_from_height() {
+    let (mut layout, mut measurer) = test_utils();
+
+    let mut mocked_dom = TestingDOM::default();
+    mocked_dom.add(
+        0,
+        None,
+        vec![1],
+        Node::from_size_and_direction(
+            Size::Pixels(Length::new(1000.0)),
+            Size::Pixels(Length::new(200.0)),
+            Direction::Horizontal,
+        ),
+    );
+    mocked_dom.add(
+        1,
+        Some(0),
+        vec![],
+        Node {
+            maximum_width: Size::Pixels(Length::new(250.0)),
+            ..Node::from_size_and_aspect_ratio(
+                Size::Inner,
+                Size::Percentage(Length::new(50.0)),
+                3.0,
+            )
+        },
+    );
+
+    layout.measure(
+        0,
+        Rect::new(Point2D::new(0.0, 0.0), Size2D::new(1000.0, 1000.0)),
+        &mut measurer,
+        &mut mocked_dom,
+    );
+
+    assert_eq!(
+        layout.get(1).unwrap().area,
+        Rect::new(Point2D::new(0.0, 0.0), Size2D::new(250.0, 100.0)),
+    );
+}
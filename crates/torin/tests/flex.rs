@@ -495,3 +495,281 @@ pub fn flex_root_candidate_resolution() {
         Rect::new(Point2D::new(0.0, 150.0), Size2D::new(100.0, 50.0)),
     );
 }
+
+#[test]
+pub fn flex_grow_from_size() {
+    let (mut layout, mut measurer) = test_utils();
+
+    let mut mocked_dom = TestingDOM::default();
+    mocked_dom.add(
+        0,
+        None,
+        vec![1, 2, 3],
+        Node {
+            direction: Direction::Horizontal,
+            ..Node::from_size_and_content(
+                Size::Pixels(Length::new(500.0)),
+                Size::Pixels(Length::new(100.0)),
+                Content::Flex,
+            )
+        },
+    );
+    mocked_dom.add(
+        1,
+        Some(0),
+        vec![],
+        Node {
+            flex_grow: Length::new(1.0),
+            ..Node::from_size_and_direction(
+                Size::Pixels(Length::new(100.0)),
+                Size::Pixels(Length::new(100.0)),
+                Direction::Vertical,
+            )
+        },
+    );
+    mocked_dom.add(
+        2,
+        Some(0),
+        vec![],
+        Node::from_size_and_direction(
+            Size::Pixels(Length::new(100.0)),
+            Size::Pixels(Length::new(100.0)),
+            Direction::Vertical,
+        ),
+    );
+    mocked_dom.add(
+        3,
+        Some(0),
+        vec![],
+        Node::from_size_and_direction(
+            Size::Flex(Length::new(1.0)),
+            Size::Pixels(Length::new(100.0)),
+            Direction::Vertical,
+        ),
+    );
+
+    layout.measure(
+        0,
+        Rect::new(Point2D::new(0.0, 0.0), Size2D::new(1000.0, 1000.0)),
+        &mut measurer,
+        &mut mocked_dom,
+    );
+
+    // 300px of leftover space, half for the first Node and the other half for the flex(1) Node
+    assert_eq!(
+        layout.get(1).unwrap().area,
+        Rect::new(Point2D::new(0.0, 0.0), Size2D::new(250.0, 100.0)),
+    );
+    assert_eq!(
+        layout.get(2).unwrap().area,
+        Rect::new(Point2D::new(250.0, 0.0), Size2D::new(100.0, 100.0)),
+    );
+    assert_eq!(
+        layout.get(3).unwrap().area,
+        Rect::new(Point2D::new(350.0, 0.0), Size2D::new(150.0, 100.0)),
+    );
+}
+
+#[test]
+pub fn flex_shrink() {
+    let (mut layout, mut measurer) = test_utils();
+
+    let mut mocked_dom = TestingDOM::default();
+    mocked_dom.add(
+        0,
+        None,
+        vec![1, 2, 3],
+        Node::from_size_and_content(
+            Size::Pixels(Length::new(100.0)),
+            Size::Pixels(Length::new(300.0)),
+            Content::Flex,
+        ),
+    );
+    mocked_dom.add(
+        1,
+        Some(0),
+        vec![],
+        Node {
+            flex_shrink: Length::new(1.0),
+            ..Node::from_size_and_direction(
+                Size::Pixels(Length::new(100.0)),
+                Size::Pixels(Length::new(200.0)),
+                Direction::Vertical,
+            )
+        },
+    );
+    mocked_dom.add(
+        2,
+        Some(0),
+        vec![],
+        Node::from_size_and_direction(
+            Size::Pixels(Length::new(100.0)),
+            Size::Pixels(Length::new(100.0)),
+            Direction::Vertical,
+        ),
+    );
+    mocked_dom.add(
+        3,
+        Some(0),
+        vec![],
+        Node {
+            flex_shrink: Length::new(2.0),
+            ..Node::from_size_and_direction(
+                Size::Pixels(Length::new(100.0)),
+                Size::Pixels(Length::new(100.0)),
+                Direction::Vertical,
+            )
+        },
+    );
+
+    layout.measure(
+        0,
+        Rect::new(Point2D::new(0.0, 0.0), Size2D::new(1000.0, 1000.0)),
+        &mut measurer,
+        &mut mocked_dom,
+    );
+
+    // 100px of overflow, shared equally as 200 * 1 == 100 * 2
+    assert_eq!(
+        layout.get(1).unwrap().area,
+        Rect::new(Point2D::new(0.0, 0.0), Size2D::new(100.0, 150.0)),
+    );
+    assert_eq!(
+        layout.get(2).unwrap().area,
+        Rect::new(Point2D::new(0.0, 150.0), Size2D::new(100.0, 100.0)),
+    );
+    assert_eq!(
+        layout.get(3).unwrap().area,
+        Rect::new(Point2D::new(0.0, 250.0), Size2D::new(100.0, 50.0)),
+    );
+}
+
+#[test]
+pub fn flex_grow_relayout() {
+    let (mut layout, mut measurer) = test_utils();
+    let child = |flex_grow: f32| Node {
+        flex_grow: Length::new(flex_grow),
+        ..Node::from_size_and_direction(
+            Size::Pixels(Length::new(200.0)),
+            Size::Pixels(Length::new(100.0)),
+            Direction::Vertical,
+        )
+    };
+
+    let mut mocked_dom = TestingDOM::default();
+    mocked_dom.add(
+        0,
+        None,
+        vec![1],
+        Node::from_size_and_direction(Size::Fill, Size::Fill, Direction::Vertical),
+    );
+    mocked_dom.add(
+        1,
+        Some(0),
+        vec![2],
+        Node {
+            direction: Direction::Horizontal,
+            ..Node::from_size_and_content(
+                Size::Pixels(Length::new(500.0)),
+                Size::Pixels(Length::new(100.0)),
+                Content::Flex,
+            )
+        },
+    );
+    mocked_dom.add(2, Some(1), vec![], child(0.0));
+
+    layout.measure(
+        0,
+        Rect::new(Point2D::new(0.0, 0.0), Size2D::new(1000.0, 1000.0)),
+        &mut measurer,
+        &mut mocked_dom,
+    );
+
+    assert_eq!(
+        layout.get(2).unwrap().area,
+        Rect::new(Point2D::new(0.0, 0.0), Size2D::new(200.0, 100.0)),
+    );
+
+    // The flex parent sizes its only child, so it is measured again
+    mocked_dom.set_node(2, child(1.0));
+    layout.invalidate(2);
+    layout.find_best_root(&mut mocked_dom);
+
+    assert_eq!(layout.get_root_candidate(), RootNodeCandidate::Valid(1));
+
+    layout.measure(
+        0,
+        Rect::new(Point2D::new(0.0, 0.0), Size2D::new(1000.0, 1000.0)),
+        &mut measurer,
+        &mut mocked_dom,
+    );
+
+    assert_eq!(
+        layout.get(2).unwrap().area,
+        Rect::new(Point2D::new(0.0, 0.0), Size2D::new(500.0, 100.0)),
+    );
+}
+
+#[test]
+pub fn flex_basis() {
+    let (mut layout, mut measurer) = test_utils();
+
+    let mut mocked_dom = TestingDOM::default();
+    mocked_dom.add(
+        0,
+        None,
+        vec![1, 2],
+        Node {
+            direction: Direction::Horizontal,
+            ..Node::from_size_and_content(
+                Size::Pixels(Length::new(500.0)),
+                Size::Pixels(Length::new(100.0)),
+                Content::Flex,
+            )
+        },
+    );
+    mocked_dom.add(
+        1,
+        Some(0),
+        vec![],
+        Node {
+            flex_grow: Length::new(1.0),
+            flex_basis: Size::Pixels(Length::new(200.0)),
+            ..Node::from_size_and_direction(
+                Size::Pixels(Length::new(50.0)),
+                Size::Pixels(Length::new(100.0)),
+                Direction::Vertical,
+            )
+        },
+    );
+    mocked_dom.add(
+        2,
+        Some(0),
+        vec![],
+        Node {
+            flex_basis: Size::Percentage(Length::new(20.0)),
+            ..Node::from_size_and_direction(
+                Size::Pixels(Length::new(50.0)),
+                Size::Pixels(Length::new(100.0)),
+                Direction::Vertical,
+            )
+        },
+    );
+
+    layout.measure(
+        0,
+        Rect::new(Point2D::new(0.0, 0.0), Size2D::new(1000.0, 1000.0)),
+        &mut measurer,
+        &mut mocked_dom,
+    );
+
+    // The second Node takes its basis, and the first one grows from its basis with the leftover
+    assert_eq!(
+        layout.get(1).unwrap().area,
+        Rect::new(Point2D::new(0.0, 0.0), Size2D::new(400.0, 100.0)),
+    );
+    assert_eq!(
+        layout.get(2).unwrap().area,
+        Rect::new(Point2D::new(400.0, 0.0), Size2D::new(100.0, 100.0)),
+    );
+}
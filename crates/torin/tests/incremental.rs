@@ -0,0 +1,408 @@
+use std::sync::Arc;
+
+use torin::{
+    prelude::*,
+    test_utils::*,
+};
+
+/// Counts how many Nodes are measured.
+#[derive(Default)]
+struct CountingMeasurer {
+    measured: usize,
+}
+
+impl LayoutMeasurer<usize> for CountingMeasurer {
+    fn measure(
+        &mut self,
+        _node_id: usize,
+        _node: &Node,
+        _size: &Size2D,
+    ) -> Option<(Size2D, Arc<SendAnyMap>)> {
+        self.measured += 1;
+        None
+    }
+
+    fn should_measure(&mut self, _node_id: usize) -> bool {
+        true
+    }
+
+    fn should_measure_inner_children(&mut self, _node_id: usize) -> bool {
+        true
+    }
+}
+
+fn list_dom(items_position: Position) -> TestingDOM {
+    let mut mocked_dom = TestingDOM::default();
+    mocked_dom.add(
+        0,
+        None,
+        vec![1, 2, 3],
+        Node::from_size_and_direction(Size::Fill, Size::Fill, Direction::Vertical),
+    );
+    for (item, child) in [(1, 4), (2, 5), (3, 6)] {
+        mocked_dom.add(
+            item,
+            Some(0),
+            vec![child],
+            Node::from_size_and_direction(
+                Size::Pixels(Length::new(200.0)),
+                Size::Pixels(Length::new(100.0)),
+                Direction::Vertical,
+            ),
+        );
+        mocked_dom.add(
+            child,
+            Some(item),
+            vec![],
+            Node::from_size_and_position(
+                Size::Pixels(Length::new(50.0)),
+                Size::Pixels(Length::new(50.0)),
+                items_position.clone(),
+            ),
+        );
+    }
+    mocked_dom
+}
+
+#[test]
+pub fn reuse_moved_siblings() {
+    let mut layout = Torin::<usize>::new();
+    let mut measurer = Some(CountingMeasurer::default());
+    let mut mocked_dom = list_dom(Position::new_stacked());
+    let root_area = Rect::new(Point2D::new(0.0, 0.0), Size2D::new(1000.0, 1000.0));
+
+    layout.measure(0, root_area, &mut measurer, &mut mocked_dom);
+    assert_eq!(measurer.as_ref().unwrap().measured, 7);
+
+    // Make the first item taller
+    mocked_dom.set_node(
+        1,
+        Node::from_size_and_direction(
+            Size::Pixels(Length::new(200.0)),
+            Size::Pixels(Length::new(150.0)),
+            Direction::Vertical,
+        ),
+    );
+    layout.invalidate(1);
+    layout.find_best_root(&mut mocked_dom);
+    layout.measure(0, root_area, &mut measurer, &mut mocked_dom);
+
+    // Only the changed item was measured again, the next siblings were simply moved
+    assert_eq!(measurer.as_ref().unwrap().measured, 8);
+
+    assert_eq!(
+        layout.get(1).unwrap().area,
+        Rect::new(Point2D::new(0.0, 0.0), Size2D::new(200.0, 150.0)),
+    );
+    assert_eq!(
+        layout.get(2).unwrap().area,
+        Rect::new(Point2D::new(0.0, 150.0), Size2D::new(200.0, 100.0)),
+    );
+    assert_eq!(
+        layout.get(5).unwrap().area,
+        Rect::new(Point2D::new(0.0, 150.0), Size2D::new(50.0, 50.0)),
+    );
+    assert_eq!(
+        layout.get(3).unwrap().area,
+        Rect::new(Point2D::new(0.0, 250.0), Size2D::new(200.0, 100.0)),
+    );
+    assert_eq!(
+        layout.get(6).unwrap().area,
+        Rect::new(Point2D::new(0.0, 250.0), Size2D::new(50.0, 50.0)),
+    );
+
+    // Nothing changed
+    layout.find_best_root(&mut mocked_dom);
+    layout.measure(0, root_area, &mut measurer, &mut mocked_dom);
+    assert_eq!(measurer.as_ref().unwrap().measured, 8);
+}
+
+#[test]
+pub fn pinned_nodes_are_measured_again() {
+    let mut layout = Torin::<usize>::new();
+    let mut measurer = Some(CountingMeasurer::default());
+    let mut mocked_dom = list_dom(Position::new_global());
+    let root_area = Rect::new(Point2D::new(0.0, 0.0), Size2D::new(1000.0, 1000.0));
+
+    layout.measure(0, root_area, &mut measurer, &mut mocked_dom);
+
+    mocked_dom.set_node(
+        1,
+        Node::from_size_and_direction(
+            Size::Pixels(Length::new(200.0)),
+            Size::Pixels(Length::new(150.0)),
+            Direction::Vertical,
+        ),
+    );
+    layout.invalidate(1);
+    layout.find_best_root(&mut mocked_dom);
+    layout.measure(0, root_area, &mut measurer, &mut mocked_dom);
+
+    // Every item contains a `global` Node, so none of them can be simply moved
+    assert_eq!(measurer.as_ref().unwrap().measured, 7 + 6);
+
+    assert_eq!(
+        layout.get(3).unwrap().area,
+        Rect::new(Point2D::new(0.0, 250.0), Size2D::new(200.0, 100.0)),
+    );
+    assert_eq!(
+        layout.get(6).unwrap().area,
+        Rect::new(Point2D::new(0.0, 0.0), Size2D::new(50.0, 50.0)),
+    );
+}
+
+#[test]
+pub fn skip_unchanged_siblings() {
+    let mut layout = Torin::<usize>::new();
+    let mut measurer = Some(CountingMeasurer::default());
+    let mut mocked_dom = list_dom(Position::new_stacked());
+    let root_area = Rect::new(Point2D::new(0.0, 0.0), Size2D::new(1000.0, 1000.0));
+
+    layout.measure(0, root_area, &mut measurer, &mut mocked_dom);
+
+    // Change the second item without changing its size
+    mocked_dom.set_node(
+        2,
+        Node::from_size_and_direction(
+            Size::Pixels(Length::new(200.0)),
+            Size::Pixels(Length::new(100.0)),
+            Direction::Horizontal,
+        ),
+    );
+    layout.invalidate(2);
+    layout.find_best_root(&mut mocked_dom);
+    layout.measure(0, root_area, &mut measurer, &mut mocked_dom);
+
+    assert_eq!(measurer.as_ref().unwrap().measured, 8);
+
+    assert_eq!(
+        layout.get(2).unwrap().area,
+        Rect::new(Point2D::new(0.0, 100.0), Size2D::new(200.0, 100.0)),
+    );
+    assert_eq!(
+        layout.get(5).unwrap().area,
+        Rect::new(Point2D::new(0.0, 100.0), Size2D::new(50.0, 50.0)),
+    );
+    assert_eq!(
+        layout.get(3).unwrap().area,
+        Rect::new(Point2D::new(0.0, 200.0), Size2D::new(200.0, 100.0)),
+    );
+}
+
+/// Measure the layout of the DOM from scratch.
+fn fresh_layout(mocked_dom: &mut TestingDOM, root_area: Area) -> Torin<usize> {
+    let (mut layout, mut measurer) = test_utils();
+    layout.measure(0, root_area, &mut measurer, mocked_dom);
+    layout
+}
+
+/// Check that every Node has the same layout in both layouts.
+fn assert_same_layout(layout: &Torin<usize>, expected: &Torin<usize>, nodes: &[usize]) {
+    for node_id in nodes {
+        let area = layout.get(*node_id).unwrap().area;
+        let expected_area = expected.get(*node_id).unwrap().area;
+        assert!(
+            (area.origin - expected_area.origin).length() < 0.01
+                && (area.size - expected_area.size).to_vector().length() < 0.01,
+            "Node {node_id} is at {area:?} instead of {expected_area:?}",
+        );
+    }
+}
+
+#[test]
+pub fn spacing_after_absolute_siblings() {
+    let (mut layout, mut measurer) = test_utils();
+    let root_area = Rect::new(Point2D::new(0.0, 0.0), Size2D::new(1000.0, 1000.0));
+    let item = |position: Position| {
+        Node::from_size_and_position(
+            Size::Pixels(Length::new(100.0)),
+            Size::Pixels(Length::new(20.0)),
+            position,
+        )
+    };
+
+    let mut mocked_dom = TestingDOM::default();
+    mocked_dom.add(
+        0,
+        None,
+        vec![1, 2, 3],
+        Node::from_size_and_direction_and_spacing(
+            Size::Fill,
+            Size::Fill,
+            Direction::Vertical,
+            Length::new(10.0),
+        ),
+    );
+    mocked_dom.add(1, Some(0), vec![], item(Position::new_stacked()));
+    mocked_dom.add(2, Some(0), vec![], item(Position::new_absolute()));
+    mocked_dom.add(3, Some(0), vec![], item(Position::new_absolute()));
+
+    layout.measure(0, root_area, &mut measurer, &mut mocked_dom);
+
+    // The first item is no longer the last stacked one, so it gets the spacing after it
+    mocked_dom.set_node(3, item(Position::new_stacked()));
+    layout.invalidate(3);
+    layout.find_best_root(&mut mocked_dom);
+    layout.measure(0, root_area, &mut measurer, &mut mocked_dom);
+
+    assert_eq!(
+        layout.get(3).unwrap().area,
+        Rect::new(Point2D::new(0.0, 30.0), Size2D::new(100.0, 20.0)),
+    );
+    assert_same_layout(
+        &layout,
+        &fresh_layout(&mut mocked_dom, root_area),
+        &[1, 2, 3],
+    );
+}
+
+#[test]
+pub fn incremental_matches_fresh_layout() {
+    // Small xorshift generator, so the changes are the same on every run
+    let mut seed = 0x2545_f491_4f6c_dd1d_u64;
+    let mut random = move |max: usize| {
+        seed ^= seed << 13;
+        seed ^= seed >> 7;
+        seed ^= seed << 17;
+        (seed % max as u64) as usize
+    };
+
+    let heights = [10.0, 20.5, 33.3, 7.25];
+    let positions = [
+        Position::new_stacked(),
+        Position::new_stacked(),
+        Position::new_stacked(),
+        Position::new_absolute(),
+        Position::new_sticky(),
+    ];
+    let item = |random: &mut dyn FnMut(usize) -> usize| Node {
+        position: positions[random(positions.len())].clone(),
+        padding: Gaps::new(1.5, 0.0, 0.0, 2.25),
+        ..Node::from_size_and_direction(
+            Size::Pixels(Length::new(200.0)),
+            Size::Pixels(Length::new(heights[random(heights.len())])),
+            Direction::Vertical,
+        )
+    };
+
+    let (mut layout, mut measurer) = test_utils();
+    let root_area = Rect::new(Point2D::new(0.0, 0.0), Size2D::new(1000.0, 1000.0));
+    let items = (1..=8).collect::<Vec<_>>();
+
+    let mut mocked_dom = TestingDOM::default();
+    mocked_dom.add(
+        0,
+        None,
+        items.clone(),
+        Node::from_size_and_direction_and_spacing(
+            Size::Fill,
+            Size::Fill,
+            Direction::Vertical,
+            Length::new(10.0),
+        ),
+    );
+    for item_id in &items {
+        mocked_dom.add(*item_id, Some(0), vec![item_id + 8], item(&mut random));
+        mocked_dom.add(
+            item_id + 8,
+            Some(*item_id),
+            vec![],
+            Node::from_size_and_direction(
+                Size::Pixels(Length::new(50.0)),
+                Size::Pixels(Length::new(5.0)),
+                Direction::Vertical,
+            ),
+        );
+    }
+    let nodes = (0..=16).collect::<Vec<_>>();
+
+    layout.measure(0, root_area, &mut measurer, &mut mocked_dom);
+
+    for _ in 0..300 {
+        let item_id = items[random(items.len())];
+        mocked_dom.set_node(item_id, item(&mut random));
+        layout.invalidate(item_id);
+        layout.find_best_root(&mut mocked_dom);
+        layout.measure(0, root_area, &mut measurer, &mut mocked_dom);
+
+        assert_same_layout(&layout, &fresh_layout(&mut mocked_dom, root_area), &nodes);
+    }
+}
+
+#[test]
+pub fn self_aligned_child_relayouts_parent() {
+    let (mut layout, mut measurer) = test_utils();
+    let root_area = Rect::new(Point2D::new(0.0, 0.0), Size2D::new(1000.0, 1000.0));
+    let centered = |width: f32| {
+        Node::from_size_and_align_self(
+            Size::Pixels(Length::new(width)),
+            Size::Pixels(Length::new(50.0)),
+            Alignment::Center,
+        )
+    };
+    let auto_margins = |width: f32| {
+        Node::from_size_and_auto_margins(
+            Size::Pixels(Length::new(width)),
+            Size::Pixels(Length::new(50.0)),
+            AutoMargins::new(false, true, false, true),
+        )
+    };
+
+    let mut mocked_dom = TestingDOM::default();
+    mocked_dom.add(
+        0,
+        None,
+        vec![1, 3],
+        Node::from_size_and_direction(Size::Fill, Size::Fill, Direction::Vertical),
+    );
+    for (parent, child, direction) in [(1, 2, Direction::Vertical), (3, 4, Direction::Horizontal)] {
+        mocked_dom.add(
+            parent,
+            Some(0),
+            vec![child],
+            Node::from_size_and_direction(
+                Size::Pixels(Length::new(400.0)),
+                Size::Pixels(Length::new(100.0)),
+                direction,
+            ),
+        );
+    }
+    mocked_dom.add(2, Some(1), vec![], centered(100.0));
+    mocked_dom.add(4, Some(3), vec![], auto_margins(100.0));
+
+    layout.measure(0, root_area, &mut measurer, &mut mocked_dom);
+
+    assert_eq!(
+        layout.get(2).unwrap().area,
+        Rect::new(Point2D::new(150.0, 0.0), Size2D::new(100.0, 50.0)),
+    );
+    assert_eq!(
+        layout.get(4).unwrap().area,
+        Rect::new(Point2D::new(150.0, 100.0), Size2D::new(100.0, 50.0)),
+    );
+
+    // Both children are placed by their parents, so these are measured again
+    mocked_dom.set_node(2, centered(200.0));
+    layout.invalidate(2);
+    layout.find_best_root(&mut mocked_dom);
+    layout.measure(0, root_area, &mut measurer, &mut mocked_dom);
+
+    mocked_dom.set_node(4, auto_margins(200.0));
+    layout.invalidate(4);
+    layout.find_best_root(&mut mocked_dom);
+    layout.measure(0, root_area, &mut measurer, &mut mocked_dom);
+
+    assert_eq!(
+        layout.get(2).unwrap().area,
+        Rect::new(Point2D::new(100.0, 0.0), Size2D::new(200.0, 50.0)),
+    );
+    assert_eq!(
+        layout.get(4).unwrap().area,
+        Rect::new(Point2D::new(100.0, 100.0), Size2D::new(200.0, 50.0)),
+    );
+    assert_same_layout(
+        &layout,
+        &fresh_layout(&mut mocked_dom, root_area),
+        &[1, 2, 3, 4],
+    );
+}
@@ -0,0 +1,94 @@
+use torin::{
+    prelude::*,
+    test_utils::*,
+};
+
+#[test]
+pub fn mirror_horizontally() {
+    let (mut layout, mut measurer) = test_utils();
+
+    let mut parent = Node::from_size_and_alignments_and_direction_and_padding(
+        Size::Pixels(Length::new(300.0)),
+        Size::Pixels(Length::new(100.0)),
+        Alignment::Start,
+        Alignment::Start,
+        Direction::Horizontal,
+        Gaps::new(0.0, 0.0, 0.0, 10.0),
+    );
+    parent.mirror_horizontally(&Direction::Vertical);
+
+    let mut mocked_dom = TestingDOM::default();
+    // The children of horizontal Nodes are reversed
+    mocked_dom.add(0, None, vec![2, 1], parent);
+    mocked_dom.add(
+        1,
+        Some(0),
+        vec![],
+        Node::from_size_and_direction(
+            Size::Pixels(Length::new(50.0)),
+            Size::Pixels(Length::new(50.0)),
+            Direction::Vertical,
+        ),
+    );
+    mocked_dom.add(
+        2,
+        Some(0),
+        vec![],
+        Node::from_size_and_direction(
+            Size::Pixels(Length::new(100.0)),
+            Size::Pixels(Length::new(50.0)),
+            Direction::Vertical,
+        ),
+    );
+
+    layout.measure(
+        0,
+        Rect::new(Point2D::new(0.0, 0.0), Size2D::new(1000.0, 1000.0)),
+        &mut measurer,
+        &mut mocked_dom,
+    );
+
+    assert_eq!(
+        layout.get(1).unwrap().area,
+        Rect::new(Point2D::new(240.0, 0.0), Size2D::new(50.0, 50.0)),
+    );
+    assert_eq!(
+        layout.get(2).unwrap().area,
+        Rect::new(Point2D::new(140.0, 0.0), Size2D::new(100.0, 50.0)),
+    );
+}
+
+#[test]
+pub fn mirror_vertically_stacked() {
+    let (mut layout, mut measurer) = test_utils();
+
+    let mut parent = Node::from_size_and_direction(
+        Size::Pixels(Length::new(300.0)),
+        Size::Pixels(Length::new(300.0)),
+        Direction::Vertical,
+    );
+    parent.mirror_horizontally(&Direction::Vertical);
+
+    let mut child = Node::from_size_and_margin(
+        Size::Pixels(Length::new(100.0)),
+        Size::Pixels(Length::new(100.0)),
+        Gaps::new(0.0, 0.0, 0.0, 20.0),
+    );
+    child.mirror_horizontally(&Direction::Vertical);
+
+    let mut mocked_dom = TestingDOM::default();
+    mocked_dom.add(0, None, vec![1], parent);
+    mocked_dom.add(1, Some(0), vec![], child);
+
+    layout.measure(
+        0,
+        Rect::new(Point2D::new(0.0, 0.0), Size2D::new(1000.0, 1000.0)),
+        &mut measurer,
+        &mut mocked_dom,
+    );
+
+    assert_eq!(
+        layout.get(1).unwrap().visible_area(),
+        Rect::new(Point2D::new(180.0, 0.0), Size2D::new(100.0, 100.0)),
+    );
+}
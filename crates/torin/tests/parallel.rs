@@ -0,0 +1,158 @@
+#![cfg(feature = "parallel")]
+
+use std::sync::Arc;
+
+use torin::{
+    prelude::*,
+    test_utils::*,
+};
+
+/// Counts how many Nodes are measured, measuring the texts as `10` pixels per character.
+#[derive(Default)]
+struct TextMeasurer {
+    measured: usize,
+}
+
+impl LayoutMeasurer<usize> for TextMeasurer {
+    fn measure(
+        &mut self,
+        node_id: usize,
+        _node: &Node,
+        size: &Size2D,
+    ) -> Option<(Size2D, Arc<SendAnyMap>)> {
+        self.measured += 1;
+        let width = (node_id % 7 + 1) as f32 * 10.0;
+        let lines = (width / size.width).ceil();
+        Some((
+            Size2D::new(width.min(size.width), lines * 20.0),
+            Arc::new(SendAnyMap::new()),
+        ))
+    }
+
+    fn should_measure(&mut self, node_id: usize) -> bool {
+        node_id >= 100
+    }
+
+    fn should_measure_inner_children(&mut self, _node_id: usize) -> bool {
+        true
+    }
+}
+
+impl ParallelLayoutMeasurer<usize> for TextMeasurer {
+    fn fork(&self) -> Self {
+        Self::default()
+    }
+
+    fn join(&mut self, forked: Self) {
+        self.measured += forked.measured;
+    }
+}
+
+const ROWS: usize = 40;
+
+fn table_dom() -> TestingDOM {
+    let mut mocked_dom = TestingDOM::default();
+    mocked_dom.add(
+        0,
+        None,
+        (1..=ROWS).collect(),
+        Node::from_size_and_direction(Size::Fill, Size::Fill, Direction::Vertical),
+    );
+    for row in 1..=ROWS {
+        mocked_dom.add(
+            row,
+            Some(0),
+            vec![100 + row * 2, 101 + row * 2],
+            Node::from_size_and_direction(
+                Size::Pixels(Length::new(60.0)),
+                Size::Pixels(Length::new(40.0)),
+                Direction::Horizontal,
+            ),
+        );
+        for cell in [100 + row * 2, 101 + row * 2] {
+            mocked_dom.add(
+                cell,
+                Some(row),
+                vec![],
+                Node::from_size_and_direction(Size::Inner, Size::Inner, Direction::Vertical),
+            );
+        }
+    }
+    mocked_dom
+}
+
+fn assert_same_layout(layout: &Torin<usize>, parallel_layout: &Torin<usize>) {
+    assert_eq!(layout.size(), parallel_layout.size());
+    for node_id in layout.results.keys() {
+        assert_eq!(
+            layout.get(*node_id).unwrap(),
+            parallel_layout.get(*node_id).unwrap(),
+            "Node {node_id} has a different layout"
+        );
+    }
+}
+
+#[test]
+pub fn parallel_layout_matches_sequential_layout() {
+    let root_area = Rect::new(Point2D::new(0.0, 0.0), Size2D::new(1000.0, 1000.0));
+
+    let mut layout = Torin::<usize>::new();
+    let mut measurer = Some(TextMeasurer::default());
+    let mut mocked_dom = table_dom();
+    layout.measure(0, root_area, &mut measurer, &mut mocked_dom);
+
+    let mut parallel_layout = Torin::<usize>::new();
+    let mut parallel_measurer = Some(TextMeasurer::default());
+    let mut parallel_mocked_dom = table_dom();
+    parallel_layout.measure_parallel(
+        0,
+        root_area,
+        &mut parallel_measurer,
+        &mut parallel_mocked_dom,
+    );
+
+    assert_same_layout(&layout, &parallel_layout);
+    // The measurements made in other threads are joined back
+    assert_eq!(
+        parallel_measurer.as_ref().unwrap().measured,
+        measurer.as_ref().unwrap().measured
+    );
+    assert_eq!(
+        parallel_layout.get(106).unwrap().area,
+        Rect::new(Point2D::new(0.0, 80.0), Size2D::new(20.0, 20.0)),
+    );
+    assert_eq!(
+        parallel_layout.get(107).unwrap().area,
+        Rect::new(Point2D::new(20.0, 80.0), Size2D::new(30.0, 20.0)),
+    );
+
+    // Change a cell of the third row
+    for (layout, mocked_dom) in [
+        (&mut layout, &mut mocked_dom),
+        (&mut parallel_layout, &mut parallel_mocked_dom),
+    ] {
+        mocked_dom.set_node(
+            106,
+            Node::from_size_and_direction(
+                Size::Pixels(Length::new(30.0)),
+                Size::Inner,
+                Direction::Vertical,
+            ),
+        );
+        layout.invalidate(106);
+        layout.find_best_root(mocked_dom);
+    }
+    layout.measure(0, root_area, &mut measurer, &mut mocked_dom);
+    parallel_layout.measure_parallel(
+        0,
+        root_area,
+        &mut parallel_measurer,
+        &mut parallel_mocked_dom,
+    );
+
+    assert_same_layout(&layout, &parallel_layout);
+    assert_eq!(
+        parallel_layout.get(107).unwrap().area,
+        Rect::new(Point2D::new(30.0, 80.0), Size2D::new(30.0, 20.0)),
+    );
+}
@@ -225,3 +225,210 @@ pub fn global() {
         Rect::new(Point2D::new(50.0, 700.0), Size2D::new(200.0, 200.0)),
     );
 }
+
+#[test]
+pub fn sticky() {
+    let (mut layout, mut measurer) = test_utils();
+
+    let mut mocked_dom = TestingDOM::default();
+    mocked_dom.add(
+        0,
+        None,
+        vec![1, 4],
+        Node::from_size_and_scroll(
+            Size::Pixels(Length::new(200.0)),
+            Size::Pixels(Length::new(100.0)),
+            Length::new(0.0),
+            Length::new(-50.0),
+        ),
+    );
+    // First section
+    mocked_dom.add(
+        1,
+        Some(0),
+        vec![2, 3],
+        Node::from_size_and_direction(
+            Size::Percentage(Length::new(100.0)),
+            Size::Pixels(Length::new(100.0)),
+            Direction::Vertical,
+        ),
+    );
+    mocked_dom.add(
+        2,
+        Some(1),
+        vec![],
+        Node::from_size_and_position(
+            Size::Percentage(Length::new(100.0)),
+            Size::Pixels(Length::new(20.0)),
+            Position::Sticky(Box::new(PositionSides {
+                top: Some(0.0),
+                ..Default::default()
+            })),
+        ),
+    );
+    mocked_dom.add(
+        3,
+        Some(1),
+        vec![],
+        Node::from_size_and_direction(
+            Size::Percentage(Length::new(100.0)),
+            Size::Pixels(Length::new(80.0)),
+            Direction::Vertical,
+        ),
+    );
+    // Second section
+    mocked_dom.add(
+        4,
+        Some(0),
+        vec![5],
+        Node::from_size_and_direction(
+            Size::Percentage(Length::new(100.0)),
+            Size::Pixels(Length::new(100.0)),
+            Direction::Vertical,
+        ),
+    );
+    mocked_dom.add(
+        5,
+        Some(4),
+        vec![],
+        Node::from_size_and_position(
+            Size::Percentage(Length::new(100.0)),
+            Size::Pixels(Length::new(20.0)),
+            Position::Sticky(Box::new(PositionSides {
+                top: Some(0.0),
+                ..Default::default()
+            })),
+        ),
+    );
+
+    layout.measure(
+        0,
+        Rect::new(Point2D::new(0.0, 0.0), Size2D::new(1000.0, 1000.0)),
+        &mut measurer,
+        &mut mocked_dom,
+    );
+
+    // The first header is pinned to the top of the viewport
+    assert_eq!(
+        layout.get(1).unwrap().area,
+        Rect::new(Point2D::new(0.0, -50.0), Size2D::new(200.0, 100.0)),
+    );
+    assert_eq!(
+        layout.get(2).unwrap().area,
+        Rect::new(Point2D::new(0.0, 0.0), Size2D::new(200.0, 20.0)),
+    );
+    // The second header is where it would be normally
+    assert_eq!(
+        layout.get(5).unwrap().area,
+        Rect::new(Point2D::new(0.0, 50.0), Size2D::new(200.0, 20.0)),
+    );
+
+    // Scroll until the first section is almost hidden
+    mocked_dom.set_node(
+        0,
+        Node::from_size_and_scroll(
+            Size::Pixels(Length::new(200.0)),
+            Size::Pixels(Length::new(100.0)),
+            Length::new(0.0),
+            Length::new(-90.0),
+        ),
+    );
+    layout.invalidate(0);
+    layout.find_best_root(&mut mocked_dom);
+    layout.measure(
+        0,
+        Rect::new(Point2D::new(0.0, 0.0), Size2D::new(1000.0, 1000.0)),
+        &mut measurer,
+        &mut mocked_dom,
+    );
+
+    // The first header is pushed by the end of its section
+    assert_eq!(
+        layout.get(2).unwrap().area,
+        Rect::new(Point2D::new(0.0, -10.0), Size2D::new(200.0, 20.0)),
+    );
+    assert_eq!(
+        layout.get(5).unwrap().area,
+        Rect::new(Point2D::new(0.0, 10.0), Size2D::new(200.0, 20.0)),
+    );
+}
+
+#[test]
+pub fn sticky_inside_unscrolled_container() {
+    let (mut layout, mut measurer) = test_utils();
+
+    let mut mocked_dom = TestingDOM::default();
+    mocked_dom.add(
+        0,
+        None,
+        vec![1],
+        Node::from_size_and_scroll(
+            Size::Pixels(Length::new(200.0)),
+            Size::Pixels(Length::new(100.0)),
+            Length::new(0.0),
+            Length::new(-50.0),
+        ),
+    );
+    // Inner scroll container that hasn't been scrolled
+    mocked_dom.add(
+        1,
+        Some(0),
+        vec![2],
+        Node::from_size_and_scroll(
+            Size::Pixels(Length::new(200.0)),
+            Size::Pixels(Length::new(100.0)),
+            Length::new(0.0),
+            Length::new(0.0),
+        ),
+    );
+    mocked_dom.add(
+        2,
+        Some(1),
+        vec![3, 4],
+        Node::from_size_and_direction(
+            Size::Percentage(Length::new(100.0)),
+            Size::Pixels(Length::new(100.0)),
+            Direction::Vertical,
+        ),
+    );
+    mocked_dom.add(
+        3,
+        Some(2),
+        vec![],
+        Node::from_size_and_position(
+            Size::Percentage(Length::new(100.0)),
+            Size::Pixels(Length::new(20.0)),
+            Position::Sticky(Box::new(PositionSides {
+                top: Some(0.0),
+                ..Default::default()
+            })),
+        ),
+    );
+    mocked_dom.add(
+        4,
+        Some(2),
+        vec![],
+        Node::from_size_and_direction(
+            Size::Percentage(Length::new(100.0)),
+            Size::Pixels(Length::new(80.0)),
+            Direction::Vertical,
+        ),
+    );
+
+    layout.measure(
+        0,
+        Rect::new(Point2D::new(0.0, 0.0), Size2D::new(1000.0, 1000.0)),
+        &mut measurer,
+        &mut mocked_dom,
+    );
+
+    // The header stays inside the viewport of the inner container rather than the outer one
+    assert_eq!(
+        layout.get(1).unwrap().area,
+        Rect::new(Point2D::new(0.0, -50.0), Size2D::new(200.0, 100.0)),
+    );
+    assert_eq!(
+        layout.get(3).unwrap().area,
+        Rect::new(Point2D::new(0.0, -50.0), Size2D::new(200.0, 20.0)),
+    );
+}
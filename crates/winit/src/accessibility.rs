@@ -89,6 +89,12 @@ impl WinitAcessibilityTree {
         // Notify the components
         platform_sender.send_modify(|state| {
             state.focused_accessibility_id = tree.focus;
+            state.focus_history = self
+                .accessibility_tree
+                .focus_history
+                .iter()
+                .copied()
+                .collect();
             let node_ref = rdom.get(node_id).unwrap();
             let node_accessibility = node_ref.get::<AccessibilityNodeState>().unwrap();
             let layout_node = layout.get(node_id).unwrap();
@@ -96,6 +102,14 @@ impl WinitAcessibilityTree {
                 AccessibilityTree::create_node(&node_ref, layout_node, &node_accessibility)
         });
 
+        // Update the Window IME Purpose
+        if let Some(node_ref) = rdom.get(node_id) {
+            if let Some(node_accessibility) = node_ref.get::<AccessibilityNodeState>() {
+                window
+                    .set_ime_purpose(node_accessibility.text_input_hints.input_hint.ime_purpose());
+            }
+        }
+
         // Update the Window IME Position
         let layout_node = layout.get(node_id);
         if let Some(layout_node) = layout_node {
@@ -1,4 +1,7 @@
-use std::sync::Arc;
+use std::{
+    sync::Arc,
+    time::Instant,
+};
 
 use accesskit::{
     Node,
@@ -20,11 +23,22 @@ use freya_core::{
     },
     events::{
         process_events,
+        process_focus_events,
+        process_mounted_events,
+        ClickTracker,
+        DefaultPrevented,
+        KeyCapture,
         NodesState,
         PlatformEvent,
+        ShortcutRegistry,
+        WheelTracker,
+    },
+    layout::{
+        content_size,
+        process_layout,
     },
-    layout::process_layout,
     platform_state::{
+        LayoutDirection,
         NativePlatformState,
         NavigationMode,
         PlatformInformation,
@@ -42,12 +56,18 @@ use freya_core::{
         EventEmitter,
         EventReceiver,
         EventsQueue,
+        ModifiersSender,
         NativePlatformReceiver,
         NativePlatformSender,
+        UserActivitySender,
     },
 };
+use freya_elements::events::keyboard::Modifiers;
 use freya_engine::prelude::*;
-use freya_native_core::prelude::NodeImmutableDioxusExt;
+use freya_native_core::{
+    prelude::NodeImmutableDioxusExt,
+    NodeId,
+};
 use futures_task::Waker;
 use futures_util::Future;
 use tokio::{
@@ -58,11 +78,17 @@ use tokio::{
         watch,
     },
 };
-use torin::geometry::Area;
+use torin::geometry::{
+    Area,
+    Size2D,
+};
 use winit::{
     dpi::PhysicalSize,
     event_loop::EventLoopProxy,
-    window::Window,
+    window::{
+        Window,
+        WindowId,
+    },
 };
 
 use crate::{
@@ -71,8 +97,11 @@ use crate::{
         Devtools,
         HoveredNode,
     },
+    frame_scheduler::FrameScheduler,
     size::WinitSize,
+    text_scale::sanitize_text_scale_factor,
     winit_waker::winit_waker,
+    EmbeddedFontBytes,
     EmbeddedFonts,
 };
 
@@ -98,13 +127,29 @@ pub struct Application {
     pub(crate) platform_receiver: NativePlatformReceiver,
     pub(crate) accessibility: WinitAcessibilityTree,
     pub(crate) font_collection: FontCollection,
+    pub(crate) font_provider: TypefaceFontProvider,
     pub(crate) font_mgr: FontMgr,
     pub(crate) ticker_sender: broadcast::Sender<()>,
+    pub(crate) activity_sender: UserActivitySender,
+    pub(crate) modifiers_sender: ModifiersSender,
+    pub(crate) key_capture: KeyCapture,
+    pub(crate) shortcuts: ShortcutRegistry,
+    pub(crate) click_tracker: ClickTracker,
+    pub(crate) wheel_tracker: WheelTracker,
+    pub(crate) power_sender: freya_core::types::PowerEventsSender,
+    #[cfg(feature = "menu")]
+    pub(crate) menu_sender: freya_core::types::MenuEventsSender,
+    #[cfg(feature = "tray")]
+    pub(crate) tray_sender: freya_core::types::TrayEventsSender,
     pub(crate) plugins: PluginsManager,
     pub(crate) process_layout_on_next_render: bool,
     pub(crate) process_accessibility_task_on_next_render: AccessibilityTask,
     pub(crate) init_accessibility_on_next_render: bool,
     pub(crate) default_fonts: Vec<String>,
+    pub(crate) text_scale_factor: f32,
+    pub(crate) layout_direction: LayoutDirection,
+    pub(crate) frame_scheduler: FrameScheduler,
+    pub(crate) window_id: WindowId,
 }
 
 impl Application {
@@ -116,10 +161,15 @@ impl Application {
         devtools: Option<Devtools>,
         window: &Window,
         fonts_config: EmbeddedFonts,
+        font_bytes_config: EmbeddedFontBytes,
         plugins: PluginsManager,
         default_fonts: Vec<String>,
+        text_scale_factor: f32,
+        layout_direction: LayoutDirection,
         accessibility: WinitAcessibilityTree,
+        max_fps: Option<u32>,
     ) -> Self {
+        let text_scale_factor = sanitize_text_scale_factor(text_scale_factor);
         let mut font_collection = FontCollection::new();
         let def_mgr = FontMgr::default();
 
@@ -130,7 +180,12 @@ impl Application {
             provider.register_typeface(ft_type, Some(font_name));
         }
 
-        let font_mgr: FontMgr = provider.into();
+        for (font_name, font_data) in font_bytes_config {
+            let ft_type = def_mgr.new_from_data(&font_data, None).unwrap();
+            provider.register_typeface(ft_type, Some(font_name));
+        }
+
+        let font_mgr: FontMgr = provider.clone().into();
         font_collection.set_default_font_manager(def_mgr, None);
         font_collection.set_dynamic_font_manager(font_mgr.clone());
 
@@ -138,17 +193,20 @@ impl Application {
         let (platform_sender, platform_receiver) = watch::channel(NativePlatformState {
             focused_accessibility_id: ACCESSIBILITY_ROOT_ID,
             focused_accessibility_node: Node::new(Role::Window),
+            focus_history: Vec::new(),
             preferred_theme: window.theme().map(|theme| theme.into()).unwrap_or_default(),
             navigation_mode: NavigationMode::default(),
             information: PlatformInformation::from_winit(window),
             scale_factor: window.scale_factor(),
+            text_scale_factor,
+            layout_direction,
         });
 
         let mut app = Self {
             sdom,
             vdom,
             events: EventsQueue::new(),
-            vdom_waker: winit_waker(proxy),
+            vdom_waker: winit_waker(proxy, window.id()),
             proxy: proxy.clone(),
             devtools,
             event_emitter,
@@ -158,14 +216,30 @@ impl Application {
             platform_sender,
             platform_receiver,
             font_collection,
+            font_provider: provider,
             font_mgr,
             ticker_sender: broadcast::channel(5).0,
+            activity_sender: watch::channel(Instant::now()).0,
+            modifiers_sender: watch::channel(Modifiers::empty()).0,
+            key_capture: KeyCapture::default(),
+            shortcuts: ShortcutRegistry::default(),
+            click_tracker: ClickTracker::default(),
+            wheel_tracker: WheelTracker::default(),
+            power_sender: broadcast::channel(16).0,
+            #[cfg(feature = "menu")]
+            menu_sender: broadcast::channel(16).0,
+            #[cfg(feature = "tray")]
+            tray_sender: broadcast::channel(16).0,
             plugins,
             process_layout_on_next_render: false,
             process_accessibility_task_on_next_render: AccessibilityTask::None,
             init_accessibility_on_next_render: false,
             default_fonts,
+            text_scale_factor,
+            layout_direction,
             compositor: Compositor::default(),
+            frame_scheduler: FrameScheduler::new(max_fps),
+            window_id: window.id(),
         };
 
         app.plugins.send(
@@ -176,6 +250,13 @@ impl Application {
         app
     }
 
+    /// Use the same fonts as another window, so the fonts registered in any of them are available in both.
+    pub fn share_fonts(&mut self, other: &Application) {
+        self.font_collection = other.font_collection.clone();
+        self.font_provider = other.font_provider.clone();
+        self.font_mgr = other.font_mgr.clone();
+    }
+
     /// Sync the RealDOM with the VirtualDOM
     pub fn init_doms<State: 'static>(&mut self, scale_factor: f32, app_state: Option<State>) {
         self.plugins.send(
@@ -193,6 +274,23 @@ impl Application {
             .insert_any_root_context(Box::new(self.platform_receiver.clone()));
         self.vdom
             .insert_any_root_context(Box::new(Arc::new(self.ticker_sender.subscribe())));
+        self.vdom
+            .insert_any_root_context(Box::new(self.activity_sender.subscribe()));
+        self.vdom
+            .insert_any_root_context(Box::new(self.modifiers_sender.subscribe()));
+        self.vdom
+            .insert_any_root_context(Box::new(self.key_capture.clone()));
+        self.vdom
+            .insert_any_root_context(Box::new(self.shortcuts.clone()));
+        self.vdom.insert_any_root_context(Box::new(self.window_id));
+        self.vdom
+            .insert_any_root_context(Box::new(self.power_sender.clone()));
+        #[cfg(feature = "menu")]
+        self.vdom
+            .insert_any_root_context(Box::new(self.menu_sender.clone()));
+        #[cfg(feature = "tray")]
+        self.vdom
+            .insert_any_root_context(Box::new(self.tray_sender.clone()));
         self.vdom
             .insert_any_root_context(Box::new(self.sdom.get().accessibility_generator().clone()));
 
@@ -235,18 +333,26 @@ impl Application {
                     Some(events) = self.event_receiver.recv() => {
                         let fdom = self.sdom.get();
                         let rdom = fdom.rdom();
-                        for event in events {
+                        let mut default_prevented = DefaultPrevented::default();
+                        for event in DefaultPrevented::sort(events) {
                             if let Some(element_id) = rdom
                                 .get(event.node_id)
                                 .and_then(|node| node.mounted_id())
                             {
-                                let name = event.name.into();
+                                let event_name = event.name;
+                                let name = event_name.into();
                                 let data = event.data.any();
                                 let event = Event::new(data, event.bubbles);
+                                if default_prevented.is_prevented(event_name) {
+                                    event.prevent_default();
+                                }
                                 self.vdom
                                     .runtime()
-                                    .handle_event(name, event, element_id);
+                                    .handle_event(name, event.clone(), element_id);
                                 self.vdom.process_events();
+                                if !event.default_action_enabled() {
+                                    default_prevented.insert(event_name);
+                                }
                             }
                         }
                     },
@@ -256,7 +362,12 @@ impl Application {
 
             match fut.poll(&mut cx) {
                 std::task::Poll::Ready(_) => {
-                    self.proxy.send_event(EventLoopMessage::PollVDOM).ok();
+                    self.proxy
+                        .send_event(EventLoopMessage::ForWindow(
+                            self.window_id,
+                            Box::new(EventLoopMessage::PollVDOM),
+                        ))
+                        .ok();
                 }
                 std::task::Poll::Pending => return,
             }
@@ -276,7 +387,7 @@ impl Application {
         }
 
         if must_relayout || must_repaint {
-            window.request_redraw();
+            self.frame_scheduler.request_frame();
         }
     }
 
@@ -287,7 +398,7 @@ impl Application {
             PluginEvent::StartedMeasuringEvents,
             PluginHandle::new(&self.proxy),
         );
-        process_events(
+        let cursor_icon = process_events(
             &self.sdom.get(),
             &mut self.events,
             &self.event_emitter,
@@ -295,6 +406,14 @@ impl Application {
             scale_factor,
             focus_id,
         );
+        if let Some(cursor_icon) = cursor_icon {
+            self.proxy
+                .send_event(EventLoopMessage::ForWindow(
+                    self.window_id,
+                    Box::new(EventLoopMessage::SetCursorIcon(cursor_icon)),
+                ))
+                .ok();
+        }
         self.plugins.send(
             PluginEvent::FinishedMeasuringEvents,
             PluginHandle::new(&self.proxy),
@@ -322,14 +441,39 @@ impl Application {
             window,
             &mut dirty_accessibility_tree,
         );
+
+        process_focus_events(
+            &fdom,
+            &self.event_emitter,
+            &mut self.nodes_state,
+            self.accessibility.focused_node_id(),
+        );
     }
 
     /// Send an event
-    pub fn send_event(&mut self, event: PlatformEvent, scale_factor: f64) {
+    pub fn send_event(&mut self, mut event: PlatformEvent, scale_factor: f64) {
+        self.activity_sender.send_replace(Instant::now());
+        self.wheel_tracker.track(&mut event);
+        if self.key_capture.capture(&event) || self.shortcuts.dispatch(&event) {
+            return;
+        }
+        let double_click = self.click_tracker.track(&event, Instant::now());
         self.events.push(event);
+        self.events.extend(double_click);
         self.process_events(scale_factor);
     }
 
+    /// Whether the next frame has anything new to paint, once the layout has been processed.
+    pub fn has_pending_frame(&self) -> bool {
+        if self.frame_scheduler.is_redraw_requested() {
+            return true;
+        }
+        let fdom = self.sdom.get();
+        let has_dirty_area = !fdom.compositor_dirty_area().is_empty();
+        let has_dirty_nodes = !fdom.compositor_dirty_nodes().is_empty();
+        has_dirty_area || has_dirty_nodes
+    }
+
     /// Render the App into the Window Canvas
     pub fn render(
         &mut self,
@@ -374,6 +518,15 @@ impl Application {
         self.process_accessibility_task_on_next_render = AccessibilityTask::Process;
         self.init_accessibility_on_next_render = true;
         self.compositor.reset();
+        self.invalidate_window(window);
+        self.sdom.get().layout().reset();
+        self.platform_sender.send_modify(|state| {
+            state.information = PlatformInformation::from_winit(window);
+        })
+    }
+
+    /// Paint the whole Window in the next render
+    pub fn invalidate_window(&mut self, window: &Window) {
         self.sdom
             .get()
             .compositor_dirty_area()
@@ -381,10 +534,45 @@ impl Application {
                 (0.0, 0.0).into(),
                 window.inner_size().to_torin(),
             ));
-        self.sdom.get().layout().reset();
+    }
+
+    /// Register a new font at runtime and relayout all the text with it.
+    pub fn register_font(&mut self, font_name: &str, font_data: &[u8], window: &Window) {
+        let Some(ft_type) = FontMgr::default().new_from_data(font_data, None) else {
+            tracing::error!("Failed to load font {font_name}");
+            return;
+        };
+        self.font_provider
+            .register_typeface(ft_type, Some(font_name));
+        self.font_collection.clear_caches();
+        // The cached texts might have been measured with a fallback font
+        self.sdom.get().paragraph_cache().clear();
+        self.resize(window);
+    }
+
+    /// Change the scale factor of the font size of all the text and relayout it.
+    pub fn set_text_scale_factor(&mut self, text_scale_factor: f32, window: &Window) {
+        let text_scale_factor = sanitize_text_scale_factor(text_scale_factor);
+        if self.text_scale_factor == text_scale_factor {
+            return;
+        }
+        self.text_scale_factor = text_scale_factor;
         self.platform_sender.send_modify(|state| {
-            state.information = PlatformInformation::from_winit(window);
-        })
+            state.text_scale_factor = text_scale_factor;
+        });
+        self.resize(window);
+    }
+
+    /// Change the horizontal direction of the layout and relayout it.
+    pub fn set_layout_direction(&mut self, layout_direction: LayoutDirection, window: &Window) {
+        if self.layout_direction == layout_direction {
+            return;
+        }
+        self.layout_direction = layout_direction;
+        self.platform_sender.send_modify(|state| {
+            state.layout_direction = layout_direction;
+        });
+        self.resize(window);
     }
 
     /// Measure the a text group given it's ID.
@@ -396,7 +584,10 @@ impl Application {
 
     pub fn request_focus_node(&mut self, focus_strategy: AccessibilityFocusStrategy) {
         let task = match focus_strategy {
-            AccessibilityFocusStrategy::Backward | AccessibilityFocusStrategy::Forward => {
+            AccessibilityFocusStrategy::Backward
+            | AccessibilityFocusStrategy::Forward
+            | AccessibilityFocusStrategy::BackwardWithin(_)
+            | AccessibilityFocusStrategy::ForwardWithin(_) => {
                 AccessibilityTask::ProcessWithMode(NavigationMode::Keyboard)
             }
             _ => AccessibilityTask::Process,
@@ -434,9 +625,18 @@ impl Application {
             Area::from_size(window_size.to_torin()),
             &mut self.font_collection,
             scale_factor as f32,
+            self.text_scale_factor,
+            self.layout_direction,
             &self.default_fonts,
         );
 
+        process_mounted_events(
+            &fdom,
+            &self.event_emitter,
+            &mut self.nodes_state,
+            scale_factor,
+        );
+
         self.plugins.send(
             PluginEvent::FinishedMeasuringLayout(&fdom.layout()),
             PluginHandle::new(&self.proxy),
@@ -456,6 +656,11 @@ impl Application {
         }
     }
 
+    /// Size taken by the content of the app, in physical pixels.
+    pub fn content_size(&self) -> Size2D {
+        content_size(&self.sdom.get())
+    }
+
     /// Start rendering the RealDOM to Window
     pub fn start_render(
         &mut self,
@@ -489,7 +694,42 @@ impl Application {
             font_manager: &self.font_mgr,
             default_fonts: &self.default_fonts,
             images_cache: &mut fdom.images_cache(),
+            frame_counters: &mut fdom.frame_counters(),
         };
         render_pipeline.run();
     }
+
+    /// Render the elements of a portal into the surface of its own window.
+    pub fn render_window_portal(
+        &mut self,
+        portal_id: NodeId,
+        surface: &mut Surface,
+        dirty_surface: &mut Surface,
+        window_size: PhysicalSize<u32>,
+        scale_factor: f32,
+    ) {
+        let fdom = self.sdom.get();
+
+        let mut render_pipeline = RenderPipeline {
+            canvas_area: Area::from_size(window_size.to_torin()),
+            rdom: fdom.rdom(),
+            compositor_dirty_area: &mut fdom.compositor_dirty_area(),
+            compositor_dirty_nodes: &mut fdom.compositor_dirty_nodes(),
+            compositor_cache: &mut fdom.compositor_cache(),
+            layers: &mut fdom.layers(),
+            layout: &mut fdom.layout(),
+            background: Color::TRANSPARENT,
+            surface,
+            dirty_surface,
+            compositor: &mut self.compositor,
+            scale_factor,
+            selected_node: None,
+            font_collection: &mut self.font_collection,
+            font_manager: &self.font_mgr,
+            default_fonts: &self.default_fonts,
+            images_cache: &mut fdom.images_cache(),
+            frame_counters: &mut fdom.frame_counters(),
+        };
+        render_pipeline.run_window_portal(portal_id);
+    }
 }
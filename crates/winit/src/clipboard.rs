@@ -0,0 +1,75 @@
+use std::borrow::Cow;
+
+use arboard::{
+    Clipboard,
+    ImageData,
+};
+use freya_core::platform::{
+    ClipboardError,
+    ClipboardImage,
+    ClipboardRequest,
+};
+
+/// System clipboard of the app, only accessed from the event loop thread.
+#[derive(Default)]
+pub struct SystemClipboard {
+    /// Created on first use, it's kept alive so the copied content is still served on X11.
+    clipboard: Option<Clipboard>,
+}
+
+impl SystemClipboard {
+    fn clipboard(&mut self) -> Result<&mut Clipboard, ClipboardError> {
+        if self.clipboard.is_none() {
+            self.clipboard = Some(Clipboard::new().map_err(map_error)?);
+        }
+        Ok(self.clipboard.as_mut().unwrap())
+    }
+
+    /// Process a clipboard request and send back its result.
+    pub fn handle(&mut self, request: ClipboardRequest) {
+        match request {
+            ClipboardRequest::GetText(sender) => {
+                let text = self
+                    .clipboard()
+                    .and_then(|clipboard| clipboard.get_text().map_err(map_error));
+                sender.send(text).ok();
+            }
+            ClipboardRequest::SetText(text, sender) => {
+                let res = self
+                    .clipboard()
+                    .and_then(|clipboard| clipboard.set_text(text).map_err(map_error));
+                sender.send(res).ok();
+            }
+            ClipboardRequest::GetImage(sender) => {
+                let image = self.clipboard().and_then(|clipboard| {
+                    let image = clipboard.get_image().map_err(map_error)?;
+                    Ok(ClipboardImage {
+                        width: image.width,
+                        height: image.height,
+                        bytes: image.bytes.into_owned(),
+                    })
+                });
+                sender.send(image).ok();
+            }
+            ClipboardRequest::SetImage(image, sender) => {
+                let res = self.clipboard().and_then(|clipboard| {
+                    clipboard
+                        .set_image(ImageData {
+                            width: image.width,
+                            height: image.height,
+                            bytes: Cow::Owned(image.bytes),
+                        })
+                        .map_err(map_error)
+                });
+                sender.send(res).ok();
+            }
+        }
+    }
+}
+
+fn map_error(err: arboard::Error) -> ClipboardError {
+    match err {
+        arboard::Error::ContentNotAvailable => ClipboardError::Empty,
+        err => ClipboardError::Unavailable(err.to_string()),
+    }
+}
@@ -1,8 +1,15 @@
-use std::io::Cursor;
+use std::{
+    io::Cursor,
+    path::Path,
+};
 
 use freya_core::{
-    event_loop_messages::EventLoopMessage,
+    event_loop_messages::{
+        EventLoopMessage,
+        SizeToContent,
+    },
     parsing::Parse,
+    platform_state::LayoutDirection,
     plugins::{
         FreyaPlugin,
         PluginsManager,
@@ -24,6 +31,7 @@ pub type WindowCallback = Box<dyn FnOnce(&mut Window)>;
 pub type EventLoopBuilderHook = Box<dyn FnOnce(&mut EventLoopBuilder<EventLoopMessage>)>;
 pub type WindowBuilderHook = Box<dyn FnOnce(WindowAttributes) -> WindowAttributes>;
 pub type EmbeddedFonts<'a> = Vec<(&'a str, &'a [u8])>;
+pub type EmbeddedFontBytes<'a> = Vec<(&'a str, Vec<u8>)>;
 
 /// Configuration for a Window.
 pub struct WindowConfig {
@@ -33,14 +41,22 @@ pub struct WindowConfig {
     pub min_size: Option<(f64, f64)>,
     /// Maximum size of the Window.
     pub max_size: Option<(f64, f64)>,
+    /// Size the Window to fit its content, within the minimum and maximum sizes.
+    pub size_to_content: SizeToContent,
     /// Enable Window decorations.
     pub decorations: bool,
     /// Title for the Window.
     pub title: &'static str,
     /// Make the Window transparent or not.
     pub transparent: bool,
+    /// Blur what is behind the transparent parts of the Window.
+    pub blur: bool,
     /// Background color of the Window.
     pub background: Color,
+    /// Drop shadow of the Window when it has no decorations.
+    pub shadow: bool,
+    /// Radius of the corners of the Window when it has no decorations.
+    pub corner_radius: Option<f32>,
     /// Window visibility. Default to `true`.
     pub visible: bool,
     /// The Icon of the Window.
@@ -55,6 +71,19 @@ pub struct WindowConfig {
     pub event_loop_builder_hook: Option<EventLoopBuilderHook>,
     /// Max resource in bytes to be used by the GPU. Defaults to automatic.
     pub max_gpu_resources_bytes: Option<usize>,
+    /// Max frames rendered per second. Defaults to no limit other than the vsync of the display.
+    pub max_fps: Option<u32>,
+}
+
+impl WindowConfig {
+    /// Radius of the corners that must be clipped when rendering, as the platform can't round them natively.
+    pub(crate) fn drawn_corner_radius(&self) -> Option<f32> {
+        if self.decorations || cfg!(target_os = "windows") {
+            None
+        } else {
+            self.corner_radius
+        }
+    }
 }
 
 impl Default for WindowConfig {
@@ -63,10 +92,14 @@ impl Default for WindowConfig {
             size: (700.0, 500.0),
             min_size: None,
             max_size: None,
+            size_to_content: SizeToContent::Never,
             decorations: true,
             title: "Freya App",
             transparent: false,
+            blur: false,
             background: Color::WHITE,
+            shadow: false,
+            corner_radius: None,
             visible: true,
             icon: None,
             on_setup: None,
@@ -74,6 +107,7 @@ impl Default for WindowConfig {
             window_attributes_hook: None,
             event_loop_builder_hook: None,
             max_gpu_resources_bytes: None,
+            max_fps: None,
         }
     }
 }
@@ -83,8 +117,22 @@ pub struct LaunchConfig<'a, T: Clone = ()> {
     pub state: Option<T>,
     pub window_config: WindowConfig,
     pub embedded_fonts: EmbeddedFonts<'a>,
+    /// Fonts owned by the config, see [LaunchConfig::with_font_bytes].
+    pub embedded_font_bytes: EmbeddedFontBytes<'a>,
     pub plugins: PluginsManager,
+    /// Font families used as fallback chain, in order. See [default_fonts].
     pub default_fonts: Vec<String>,
+    /// Scale factor applied to the font size of all the text, on top of the Window scale factor.
+    /// Read from the accessibility settings of the OS when not specified.
+    pub text_scale_factor: Option<f32>,
+    /// Horizontal direction of the layout, right-to-left mirrors it. Defaults to left-to-right.
+    pub layout_direction: LayoutDirection,
+    /// Menu bar of the main window.
+    #[cfg(feature = "menu")]
+    pub menu: Option<freya_core::platform::muda::Menu>,
+    /// Tray icon installed when the app starts.
+    #[cfg(feature = "tray")]
+    pub tray_icon: Option<freya_core::platform::tray_icon::TrayIconBuilder>,
 }
 
 impl<T: Clone> Default for LaunchConfig<'_, T> {
@@ -93,8 +141,15 @@ impl<T: Clone> Default for LaunchConfig<'_, T> {
             state: None,
             window_config: Default::default(),
             embedded_fonts: Default::default(),
+            embedded_font_bytes: Default::default(),
             plugins: Default::default(),
             default_fonts: default_fonts(),
+            text_scale_factor: None,
+            layout_direction: LayoutDirection::default(),
+            #[cfg(feature = "menu")]
+            menu: None,
+            #[cfg(feature = "tray")]
+            tray_icon: None,
         }
     }
 }
@@ -139,6 +194,15 @@ impl<'a, T: Clone> LaunchConfig<'a, T> {
         self
     }
 
+    /// Size the Window to fit its content, e.g. for utility popups.
+    ///
+    /// The size is kept within the minimum and maximum sizes, and the content is laid out
+    /// in the current size of the Window, which is the one given with `with_size` at launch.
+    pub fn with_size_to_content(mut self, size_to_content: SizeToContent) -> Self {
+        self.window_config.size_to_content = size_to_content;
+        self
+    }
+
     /// Whether the Window will have decorations or not.
     pub fn with_decorations(mut self, decorations: bool) -> Self {
         self.window_config.decorations = decorations;
@@ -152,11 +216,67 @@ impl<'a, T: Clone> LaunchConfig<'a, T> {
     }
 
     /// Make the Window transparent or not.
+    ///
+    /// Combine it with a transparent background and decorations disabled to build rounded,
+    /// shadowed or non-rectangular windows.
+    /// If the platform can't create a transparent surface the window falls back to an opaque background.
     pub fn with_transparency(mut self, transparency: bool) -> Self {
         self.window_config.transparent = transparency;
         self
     }
 
+    /// Add a drop shadow to the Window when it has no decorations.
+    ///
+    /// Only supported on Windows and macOS, it does nothing on other platforms.
+    pub fn with_shadow(mut self, shadow: bool) -> Self {
+        self.window_config.shadow = shadow;
+        self
+    }
+
+    /// Round the corners of the Window when it has no decorations.
+    ///
+    /// Windows 11 rounds them natively with its own radius,
+    /// on other platforms the Window is made transparent and the corners are clipped when rendering.
+    pub fn with_corner_radius(mut self, corner_radius: f32) -> Self {
+        self.window_config.corner_radius = Some(corner_radius);
+        self
+    }
+
+    /// Blur what is behind the transparent parts of the Window.
+    ///
+    /// Only supported on macOS and KDE Wayland, it does nothing on other platforms.
+    pub fn with_blur(mut self, blur: bool) -> Self {
+        self.window_config.blur = blur;
+        self
+    }
+
+    /// Set the native menu bar of the main window, the selected items can be received with `use_menu_event`.
+    ///
+    /// Supported on Windows and macOS, where it becomes the menu of the app.
+    #[cfg(feature = "menu")]
+    pub fn with_menu(mut self, menu: freya_core::platform::muda::Menu) -> Self {
+        self.menu = Some(menu);
+        self
+    }
+
+    /// Install a tray icon when the app starts, its events can be received with `use_tray_event`.
+    ///
+    /// On Linux the tray icon requires GTK, see the [`tray_icon`](freya_core::platform::tray_icon) docs.
+    #[cfg(feature = "tray")]
+    pub fn with_tray_icon(
+        mut self,
+        tray_icon: freya_core::platform::tray_icon::TrayIconBuilder,
+    ) -> Self {
+        self.tray_icon = Some(tray_icon);
+        self
+    }
+
+    /// Limit how many frames are rendered per second, e.g. to save power in apps that animate continuously.
+    pub fn with_max_fps(mut self, max_fps: u32) -> Self {
+        self.window_config.max_fps = Some(max_fps);
+        self
+    }
+
     /// Pass a custom value that your app will consume.
     pub fn with_state(mut self, state: T) -> Self {
         self.state = Some(state);
@@ -181,18 +301,55 @@ impl<'a, T: Clone> LaunchConfig<'a, T> {
         self
     }
 
+    /// Embed a font from owned bytes.
+    pub fn with_font_bytes(mut self, font_name: &'a str, font: Vec<u8>) -> Self {
+        self.embedded_font_bytes.push((font_name, font));
+        self
+    }
+
+    /// Embed a font loaded from a file.
+    /// The font will be skipped if the file can't be read.
+    pub fn with_font_file(mut self, font_name: &'a str, path: impl AsRef<Path>) -> Self {
+        let path = path.as_ref();
+        match std::fs::read(path) {
+            Ok(font) => self.embedded_font_bytes.push((font_name, font)),
+            Err(err) => tracing::error!("Failed to read font file {path:?}: {err}"),
+        }
+        self
+    }
+
     /// Clear default fonts.
     pub fn without_default_fonts(mut self) -> Self {
         self.default_fonts.clear();
         self
     }
 
-    /// Regiter a default font.
+    /// Register a default font.
+    /// Default fonts are tried in order after the `font_family` of a text, so this can also be used to add fallbacks,
+    /// e.g. `"Noto Color Emoji"` if emojis are rendered as empty boxes.
     pub fn with_default_font(mut self, font_name: &str) -> Self {
         self.default_fonts.push(font_name.to_string());
         self
     }
 
+    /// Replace the default fonts with the given fallback chain.
+    pub fn with_default_fonts(mut self, font_names: &[&str]) -> Self {
+        self.default_fonts = font_names.iter().map(|font| font.to_string()).collect();
+        self
+    }
+
+    /// Specify the scale factor applied to the font size of all the text, instead of reading it from the OS.
+    pub fn with_text_scale_factor(mut self, text_scale_factor: f32) -> Self {
+        self.text_scale_factor = Some(text_scale_factor);
+        self
+    }
+
+    /// Specify the horizontal direction of the layout, e.g [`LayoutDirection::Rtl`] for right-to-left locales.
+    pub fn with_layout_direction(mut self, layout_direction: LayoutDirection) -> Self {
+        self.layout_direction = layout_direction;
+        self
+    }
+
     /// Specify the Window icon.
     pub fn with_icon(mut self, icon: Icon) -> Self {
         self.window_config.icon = Some(icon);
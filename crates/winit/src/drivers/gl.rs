@@ -59,7 +59,7 @@ use winit::{
 
 use crate::{
     size::WinitSize,
-    LaunchConfig,
+    WindowConfig,
 };
 
 /// Graphics driver using OpenGL.
@@ -70,6 +70,7 @@ pub struct OpenGLDriver {
     pub(crate) fb_info: FramebufferInfo,
     pub(crate) num_samples: usize,
     pub(crate) stencil_size: usize,
+    pub(crate) supports_transparency: bool,
 }
 
 impl Drop for OpenGLDriver {
@@ -82,14 +83,14 @@ impl Drop for OpenGLDriver {
 }
 
 impl OpenGLDriver {
-    pub fn new<State: Clone + 'static>(
+    pub fn new(
         event_loop: &ActiveEventLoop,
         window_attributes: WindowAttributes,
-        config: &LaunchConfig<State>,
+        window_config: &WindowConfig,
     ) -> (Self, Window, SkiaSurface) {
         let template = ConfigTemplateBuilder::new()
             .with_alpha_size(8)
-            .with_transparency(config.window_config.transparent);
+            .with_transparency(window_config.transparent);
 
         let display_builder = DisplayBuilder::new().with_window_attributes(Some(window_attributes));
         let (window, gl_config) = display_builder
@@ -185,13 +186,14 @@ impl OpenGLDriver {
         };
 
         let num_samples = gl_config.num_samples() as usize;
+        let supports_transparency = gl_config.supports_transparency().unwrap_or(false);
         let stencil_size = gl_config.stencil_size() as usize;
         let size = window.inner_size();
 
         let mut gr_context =
             direct_contexts::make_gl(interface, None).expect("Could not create direct context");
 
-        if let Some(max_gpu_resources_bytes) = config.window_config.max_gpu_resources_bytes {
+        if let Some(max_gpu_resources_bytes) = window_config.max_gpu_resources_bytes {
             gr_context.set_resource_cache_limit(max_gpu_resources_bytes);
         }
 
@@ -214,6 +216,7 @@ impl OpenGLDriver {
             num_samples,
             stencil_size,
             fb_info,
+            supports_transparency,
         };
 
         (driver, window, skia_surface)
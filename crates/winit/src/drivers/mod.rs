@@ -12,22 +12,30 @@ use winit::{
     },
 };
 
-use crate::LaunchConfig;
+use crate::WindowConfig;
 
 pub enum GraphicsDriver {
     OpenGl(OpenGLDriver),
 }
 
 impl GraphicsDriver {
-    pub fn new<State: Clone + 'static>(
+    pub fn new(
         event_loop: &ActiveEventLoop,
         window_attributes: WindowAttributes,
-        config: &LaunchConfig<State>,
+        window_config: &WindowConfig,
     ) -> (Self, Window, SkiaSurface) {
-        let (driver, window, surface) = OpenGLDriver::new(event_loop, window_attributes, config);
+        let (driver, window, surface) =
+            OpenGLDriver::new(event_loop, window_attributes, window_config);
         (Self::OpenGl(driver), window, surface)
     }
 
+    /// Whether the surface has an alpha channel composited by the platform, so transparent windows are possible.
+    pub fn supports_transparency(&self) -> bool {
+        match self {
+            Self::OpenGl(gl) => gl.supports_transparency,
+        }
+    }
+
     pub fn make_current(&mut self) {
         match self {
             Self::OpenGl(gl) => gl.make_current(),
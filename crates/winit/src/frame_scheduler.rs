@@ -0,0 +1,159 @@
+use std::time::{
+    Duration,
+    Instant,
+};
+
+/// When the next frame must be rendered.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum NextFrame {
+    /// There is nothing new to render.
+    Idle,
+    /// Render as soon as possible.
+    Now,
+    /// Wait until the given instant to respect the FPS cap.
+    At(Instant),
+}
+
+/// Schedules the frames of the app, so they are only rendered when requested and no faster than the FPS cap.
+#[derive(Debug, Default)]
+pub struct FrameScheduler {
+    /// Minimum time between two frames.
+    min_frame_interval: Option<Duration>,
+    last_frame: Option<Instant>,
+    frame_requested: bool,
+    redraw_requested: bool,
+    /// Whether the window has been asked to render the requested frame.
+    scheduled: bool,
+}
+
+impl FrameScheduler {
+    pub fn new(max_fps: Option<u32>) -> Self {
+        Self {
+            min_frame_interval: max_fps
+                .filter(|max_fps| *max_fps > 0)
+                .map(|max_fps| Duration::from_secs_f64(1. / max_fps as f64)),
+            ..Self::default()
+        }
+    }
+
+    /// Request a frame, only rendered if there is something new to paint.
+    pub fn request_frame(&mut self) {
+        self.frame_requested = true;
+    }
+
+    /// Request a frame that is rendered even if nothing changed.
+    pub fn request_redraw(&mut self) {
+        self.frame_requested = true;
+        self.redraw_requested = true;
+    }
+
+    /// Whether the current frame must be rendered even if nothing changed.
+    pub fn is_redraw_requested(&self) -> bool {
+        self.redraw_requested
+    }
+
+    /// Mark the requested frame as asked to the window.
+    pub fn frame_scheduled(&mut self) {
+        self.scheduled = true;
+    }
+
+    /// Whether the window was asked to render a frame by this scheduler.
+    /// Otherwise the OS asked for it, e.g. after the window was uncovered.
+    pub fn is_frame_scheduled(&self) -> bool {
+        self.scheduled
+    }
+
+    /// When the next frame must be rendered.
+    pub fn next_frame(&self, now: Instant) -> NextFrame {
+        if !self.frame_requested {
+            return NextFrame::Idle;
+        }
+
+        match (self.last_frame, self.min_frame_interval) {
+            (Some(last_frame), Some(min_frame_interval)) => {
+                let next_frame = last_frame + min_frame_interval;
+                if next_frame > now {
+                    NextFrame::At(next_frame)
+                } else {
+                    NextFrame::Now
+                }
+            }
+            _ => NextFrame::Now,
+        }
+    }
+
+    /// Mark the requested frame as rendered.
+    pub fn frame_rendered(&mut self, now: Instant) {
+        self.last_frame = Some(now);
+        self.frame_requested = false;
+        self.redraw_requested = false;
+        self.scheduled = false;
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use std::time::{
+        Duration,
+        Instant,
+    };
+
+    use super::{
+        FrameScheduler,
+        NextFrame,
+    };
+
+    #[test]
+    fn frames_are_only_rendered_when_requested() {
+        let mut scheduler = FrameScheduler::new(None);
+        let now = Instant::now();
+
+        assert_eq!(scheduler.next_frame(now), NextFrame::Idle);
+
+        scheduler.request_frame();
+        assert_eq!(scheduler.next_frame(now), NextFrame::Now);
+        assert!(!scheduler.is_redraw_requested());
+
+        scheduler.frame_rendered(now);
+        assert_eq!(scheduler.next_frame(now), NextFrame::Idle);
+
+        scheduler.request_redraw();
+        assert!(scheduler.is_redraw_requested());
+    }
+
+    #[test]
+    fn scheduled_frames() {
+        let mut scheduler = FrameScheduler::new(None);
+        let now = Instant::now();
+
+        // Frames the OS asks for were not scheduled
+        assert!(!scheduler.is_frame_scheduled());
+
+        scheduler.request_frame();
+        scheduler.frame_scheduled();
+        assert!(scheduler.is_frame_scheduled());
+
+        scheduler.frame_rendered(now);
+        assert!(!scheduler.is_frame_scheduled());
+    }
+
+    #[test]
+    fn frames_are_capped() {
+        let mut scheduler = FrameScheduler::new(Some(50));
+        let now = Instant::now();
+
+        scheduler.request_frame();
+        assert_eq!(scheduler.next_frame(now), NextFrame::Now);
+        scheduler.frame_rendered(now);
+
+        scheduler.request_frame();
+        assert_eq!(
+            scheduler.next_frame(now + Duration::from_millis(5)),
+            NextFrame::At(now + Duration::from_millis(20))
+        );
+        assert_eq!(
+            scheduler.next_frame(now + Duration::from_millis(20)),
+            NextFrame::Now
+        );
+    }
+}
@@ -1,6 +1,7 @@
 use keyboard_types::Code;
 pub use keyboard_types::{
     Key,
+    Location,
     Modifiers,
 };
 
@@ -179,6 +180,16 @@ pub fn map_winit_key(key: &winit::keyboard::Key) -> Key {
     }
 }
 
+/// Return the equivalent of Winit's `KeyLocation` in keyboard_types
+pub fn map_winit_key_location(location: winit::keyboard::KeyLocation) -> Location {
+    match location {
+        winit::keyboard::KeyLocation::Standard => Location::Standard,
+        winit::keyboard::KeyLocation::Left => Location::Left,
+        winit::keyboard::KeyLocation::Right => Location::Right,
+        winit::keyboard::KeyLocation::Numpad => Location::Numpad,
+    }
+}
+
 /// Return the equivalent of Winit's `PhysicalKey` in keyboard_types
 pub fn map_winit_physical_key(key: &winit::keyboard::PhysicalKey) -> Code {
     if let winit::keyboard::PhysicalKey::Code(key) = key {
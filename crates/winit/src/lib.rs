@@ -2,15 +2,27 @@ pub use config::{
     WindowConfig,
     *,
 };
+#[cfg(not(any(target_os = "ios", target_arch = "wasm32")))]
+pub use pump::{
+    PumpStatus,
+    PumpedEventLoop,
+};
 pub use renderer::WinitRenderer;
 
 mod accessibility;
 mod app;
+mod clipboard;
 mod config;
 pub mod devtools;
 mod drivers;
+mod frame_scheduler;
 mod keyboard;
+mod portals;
+mod power;
+#[cfg(not(any(target_os = "ios", target_arch = "wasm32")))]
+mod pump;
 mod renderer;
 mod size;
+mod text_scale;
 mod window_state;
 mod winit_waker;
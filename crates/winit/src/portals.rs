@@ -0,0 +1,153 @@
+use std::collections::{
+    hash_map::Entry,
+    HashMap,
+};
+
+use freya_engine::prelude::Surface;
+use freya_native_core::NodeId;
+use torin::geometry::CursorPoint;
+use winit::{
+    dpi::{
+        PhysicalPosition,
+        PhysicalSize,
+    },
+    event_loop::ActiveEventLoop,
+    window::{
+        Window,
+        WindowId,
+        WindowLevel,
+    },
+};
+
+use crate::{
+    app::Application,
+    drivers::GraphicsDriver,
+    size::WinitSize,
+    WindowConfig,
+};
+
+/// OS window in which the elements of a portal are rendered, see the `window_portal` attribute.
+pub struct PortalWindow {
+    pub(crate) window: Window,
+    pub(crate) graphics_driver: GraphicsDriver,
+    pub(crate) surface: Surface,
+    pub(crate) dirty_surface: Surface,
+    /// Position of the portal element relative to the main window.
+    pub(crate) offset: PhysicalPosition<i32>,
+    /// Position of the window in the screen.
+    pub(crate) position: PhysicalPosition<i32>,
+}
+
+/// Windows of the portals of the app.
+#[derive(Default)]
+pub struct PortalWindows {
+    windows: HashMap<NodeId, PortalWindow>,
+}
+
+impl PortalWindows {
+    /// Position of the portal rendered in the given window relative to the main window,
+    /// used to translate the cursor of its events.
+    pub fn offset_of(&self, window_id: WindowId) -> Option<CursorPoint> {
+        self.windows
+            .values()
+            .find(|portal| portal.window.id() == window_id)
+            .map(|portal| CursorPoint::new(portal.offset.x as f64, portal.offset.y as f64))
+    }
+
+    /// Open, move and close the portal windows to match the portals of the DOM, and render them.
+    pub fn sync(
+        &mut self,
+        event_loop: &ActiveEventLoop,
+        app: &mut Application,
+        main_window: &Window,
+        main_window_config: &WindowConfig,
+        scale_factor: f32,
+    ) {
+        let portals = {
+            let fdom = app.sdom.get();
+            let layout = fdom.layout();
+            let window_portals = fdom.window_portals();
+            window_portals
+                .iter()
+                .filter_map(|portal_id| Some((*portal_id, layout.get(*portal_id)?.area)))
+                .collect::<Vec<_>>()
+        };
+
+        // Close the windows of the removed portals
+        self.windows
+            .retain(|portal_id, _| portals.iter().any(|(id, _)| id == portal_id));
+
+        if portals.is_empty() {
+            return;
+        }
+
+        let origin = main_window.inner_position().unwrap_or_default();
+
+        for (portal_id, area) in portals {
+            let offset = PhysicalPosition::new(area.min_x() as i32, area.min_y() as i32);
+            let position = PhysicalPosition::new(origin.x + offset.x, origin.y + offset.y);
+            let size = PhysicalSize::new(
+                area.width().ceil().max(1.) as u32,
+                area.height().ceil().max(1.) as u32,
+            );
+
+            let portal = match self.windows.entry(portal_id) {
+                Entry::Occupied(entry) => {
+                    let portal = entry.into_mut();
+                    if portal.position != position {
+                        portal.window.set_outer_position(position);
+                        portal.offset = offset;
+                        portal.position = position;
+                    }
+                    if portal.window.inner_size() != size {
+                        let _ = portal.window.request_inner_size(size);
+                        let (surface, dirty_surface) = portal.graphics_driver.resize(size);
+                        portal.surface = surface;
+                        portal.dirty_surface = dirty_surface;
+                    }
+                    portal
+                }
+                Entry::Vacant(entry) => {
+                    let window_attributes = Window::default_attributes()
+                        .with_decorations(false)
+                        .with_transparent(true)
+                        .with_resizable(false)
+                        .with_active(false)
+                        .with_window_level(WindowLevel::AlwaysOnTop)
+                        .with_position(position)
+                        .with_inner_size(size);
+                    let window_config = WindowConfig {
+                        transparent: true,
+                        max_gpu_resources_bytes: main_window_config.max_gpu_resources_bytes,
+                        ..WindowConfig::default()
+                    };
+
+                    let (graphics_driver, window, mut surface) =
+                        GraphicsDriver::new(event_loop, window_attributes, &window_config);
+                    let dirty_surface =
+                        surface.new_surface_with_dimensions(size.to_skia()).unwrap();
+
+                    entry.insert(PortalWindow {
+                        window,
+                        graphics_driver,
+                        surface,
+                        dirty_surface,
+                        offset,
+                        position,
+                    })
+                }
+            };
+
+            portal.graphics_driver.make_current();
+            app.render_window_portal(
+                portal_id,
+                &mut portal.surface,
+                &mut portal.dirty_surface,
+                size,
+                scale_factor,
+            );
+            portal.window.pre_present_notify();
+            portal.graphics_driver.flush_and_submit();
+        }
+    }
+}
@@ -0,0 +1,70 @@
+use std::{
+    thread,
+    time::{
+        Duration,
+        Instant,
+        SystemTime,
+    },
+};
+
+use freya_core::{
+    event_loop_messages::EventLoopMessage,
+    platform_state::PowerEvent,
+};
+use winit::event_loop::EventLoopProxy;
+
+/// How often the clocks are compared.
+const CHECK_INTERVAL: Duration = Duration::from_secs(2);
+
+/// Minimum time the system must have been asleep to notify it.
+const MIN_SLEEP: Duration = Duration::from_secs(5);
+
+/// How long the system was asleep between two checks, if long enough.
+///
+/// The monotonic clock stops while the system is suspended but the wall clock does not.
+fn slept_time(monotonic: Duration, wall: Duration) -> Option<Duration> {
+    let slept = wall.checked_sub(monotonic)?;
+    (slept >= MIN_SLEEP).then_some(slept)
+}
+
+/// Detect when the system wakes up from sleep and send a [`PowerEvent::Resumed`] to the windows.
+///
+/// Big changes of the system time are also reported as a wake up.
+pub fn watch_system_sleep(proxy: EventLoopProxy<EventLoopMessage>) {
+    thread::spawn(move || {
+        let mut last_instant = Instant::now();
+        let mut last_time = SystemTime::now();
+        loop {
+            thread::sleep(CHECK_INTERVAL);
+
+            let (instant, time) = (Instant::now(), SystemTime::now());
+            let monotonic = instant.duration_since(last_instant);
+            let wall = time.duration_since(last_time).unwrap_or_default();
+            (last_instant, last_time) = (instant, time);
+
+            if let Some(slept) = slept_time(monotonic, wall) {
+                let event = PowerEvent::Resumed { slept: Some(slept) };
+                // The event loop is gone
+                if proxy.send_event(EventLoopMessage::Power(event)).is_err() {
+                    break;
+                }
+            }
+        }
+    });
+}
+
+#[cfg(test)]
+mod test {
+    use std::time::Duration;
+
+    use super::slept_time;
+
+    #[test]
+    fn detects_sleep() {
+        let secs = Duration::from_secs;
+        assert_eq!(slept_time(secs(2), secs(2)), None);
+        assert_eq!(slept_time(secs(2), secs(3)), None);
+        assert_eq!(slept_time(secs(2), secs(1)), None);
+        assert_eq!(slept_time(secs(2), secs(62)), Some(secs(60)));
+    }
+}
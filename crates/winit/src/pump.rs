@@ -0,0 +1,65 @@
+use std::time::Duration;
+
+use dioxus_core::VirtualDom;
+use freya_core::{
+    dom::SafeDOM,
+    event_loop_messages::EventLoopMessage,
+};
+pub use winit::platform::pump_events::PumpStatus;
+use winit::{
+    event_loop::{
+        EventLoop,
+        EventLoopProxy,
+    },
+    platform::pump_events::EventLoopExtPumpEvents,
+};
+
+use crate::{
+    devtools::{
+        Devtools,
+        HoveredNode,
+    },
+    LaunchConfig,
+    WinitRenderer,
+};
+
+/// Event loop of a Freya app that is driven by the caller instead of taking over the main thread.
+///
+/// Useful to embed Freya in apps that already own their main loop, e.g. audio hosts or game engines.
+/// Call [`PumpedEventLoop::pump`] periodically from the main thread to process the pending events, render and poll the app.
+pub struct PumpedEventLoop<'a, State: Clone + 'static> {
+    event_loop: EventLoop<EventLoopMessage>,
+    renderer: WinitRenderer<'a, State>,
+}
+
+impl<'a, State: Clone + 'static> PumpedEventLoop<'a, State> {
+    pub fn new(
+        vdom: VirtualDom,
+        sdom: SafeDOM,
+        mut config: LaunchConfig<'a, State>,
+        devtools: Option<Devtools>,
+        hovered_node: HoveredNode,
+    ) -> Self {
+        let event_loop = WinitRenderer::create_event_loop(&mut config);
+        let proxy = event_loop.create_proxy();
+        let renderer = WinitRenderer::new(vdom, sdom, config, devtools, hovered_node, proxy);
+
+        Self {
+            event_loop,
+            renderer,
+        }
+    }
+
+    /// Process the pending events without blocking for longer than `timeout`.
+    ///
+    /// A `timeout` of `Some(Duration::ZERO)` returns immediately, while `None` waits until there is a new event.
+    /// Returns [`PumpStatus::Exit`] once the app has been closed, so it must not be pumped anymore.
+    pub fn pump(&mut self, timeout: Option<Duration>) -> PumpStatus {
+        self.event_loop.pump_app_events(timeout, &mut self.renderer)
+    }
+
+    /// Get a proxy to send messages to the event loop, e.g to wake it up from another thread.
+    pub fn proxy(&self) -> EventLoopProxy<EventLoopMessage> {
+        self.event_loop.create_proxy()
+    }
+}
@@ -1,20 +1,38 @@
-use std::path::PathBuf;
+use std::{
+    collections::HashMap,
+    mem,
+    path::PathBuf,
+    time::Instant,
+};
 
 use dioxus_core::VirtualDom;
 use freya_core::{
     accessibility::AccessibilityFocusStrategy,
-    dom::SafeDOM,
-    event_loop_messages::EventLoopMessage,
+    dom::{
+        FreyaDOM,
+        SafeDOM,
+    },
+    event_loop_messages::{
+        EventLoopMessage,
+        NewWindow,
+        SizeToContent,
+    },
     events::{
         EventName,
         PlatformEvent,
         PlatformEventData,
     },
-    platform_state::NavigationMode,
+    platform_state::{
+        NavigationMode,
+        PowerEvent,
+    },
 };
 use freya_elements::events::{
     Code,
     Key,
+    Location,
+    WheelDeltaMode,
+    WheelPhase,
 };
 use torin::geometry::CursorPoint;
 use winit::{
@@ -31,24 +49,34 @@ use winit::{
         WindowEvent,
     },
     event_loop::{
+        ActiveEventLoop,
+        ControlFlow,
         EventLoop,
         EventLoopProxy,
     },
     keyboard::ModifiersState,
+    window::WindowId,
 };
 
 use crate::{
     app::AccessibilityTask,
+    clipboard::SystemClipboard,
     devtools::{
         Devtools,
         HoveredNode,
     },
+    frame_scheduler::NextFrame,
     keyboard::{
         map_winit_key,
+        map_winit_key_location,
         map_winit_modifiers,
         map_winit_physical_key,
     },
+    portals::PortalWindows,
+    power::watch_system_sleep,
     window_state::{
+        clip_window_corners,
+        size_window_to_content,
         CreatedState,
         NotCreatedState,
         WindowState,
@@ -67,8 +95,20 @@ pub struct WinitRenderer<'a, State: Clone + 'static> {
     pub(crate) cursor_pos: CursorPoint,
     pub(crate) mouse_state: ElementState,
     pub(crate) modifiers_state: ModifiersState,
-    pub(crate) dropped_file_path: Option<PathBuf>,
+    pub(crate) dropped_file_paths: Vec<PathBuf>,
     pub(crate) custom_scale_factor: f64,
+    pub(crate) portal_windows: PortalWindows,
+    /// Windows opened from the app, besides the main one.
+    pub(crate) windows: HashMap<WindowId, CreatedState>,
+    pub(crate) clipboard: SystemClipboard,
+    /// Menu bar to install once the main window is created.
+    #[cfg(feature = "menu")]
+    pub(crate) menu: Option<freya_core::platform::muda::Menu>,
+    /// Tray icon to install once the event loop starts.
+    #[cfg(feature = "tray")]
+    pub(crate) tray_icon_builder: Option<freya_core::platform::tray_icon::TrayIconBuilder>,
+    #[cfg(feature = "tray")]
+    pub(crate) tray_icon: Option<freya_core::platform::tray_icon::TrayIcon>,
 }
 
 impl<'a, State: Clone + 'static> WinitRenderer<'a, State> {
@@ -80,14 +120,7 @@ impl<'a, State: Clone + 'static> WinitRenderer<'a, State> {
         devtools: Option<Devtools>,
         hovered_node: HoveredNode,
     ) {
-        let mut event_loop_builder = EventLoop::<EventLoopMessage>::with_user_event();
-        let event_loop_builder_hook = config.window_config.event_loop_builder_hook.take();
-        if let Some(event_loop_builder_hook) = event_loop_builder_hook {
-            event_loop_builder_hook(&mut event_loop_builder);
-        }
-        let event_loop = event_loop_builder
-            .build()
-            .expect("Failed to create event loop.");
+        let event_loop = Self::create_event_loop(&mut config);
         let proxy = event_loop.create_proxy();
 
         let mut winit_renderer =
@@ -96,15 +129,49 @@ impl<'a, State: Clone + 'static> WinitRenderer<'a, State> {
         event_loop.run_app(&mut winit_renderer).unwrap();
     }
 
+    /// Create the Winit event loop, applying the `event_loop_builder_hook` of the config.
+    pub(crate) fn create_event_loop(
+        config: &mut LaunchConfig<State>,
+    ) -> EventLoop<EventLoopMessage> {
+        let mut event_loop_builder = EventLoop::<EventLoopMessage>::with_user_event();
+        let event_loop_builder_hook = config.window_config.event_loop_builder_hook.take();
+        if let Some(event_loop_builder_hook) = event_loop_builder_hook {
+            event_loop_builder_hook(&mut event_loop_builder);
+        }
+        // The accelerators of the menu bar are translated by the message loop on Windows
+        #[cfg(all(feature = "menu", target_os = "windows"))]
+        if let Some(menu) = config.menu.clone() {
+            use windows_sys::Win32::UI::WindowsAndMessaging::{
+                TranslateAcceleratorW,
+                MSG,
+            };
+            use winit::platform::windows::EventLoopBuilderExtWindows;
+
+            event_loop_builder.with_msg_hook(move |msg| unsafe {
+                let msg = msg as *const MSG;
+                TranslateAcceleratorW((*msg).hwnd, menu.haccel() as _, msg) == 1
+            });
+        }
+        event_loop_builder
+            .build()
+            .expect("Failed to create event loop.")
+    }
+
     pub fn new(
         vdom: VirtualDom,
         sdom: SafeDOM,
-        config: LaunchConfig<'a, State>,
+        #[allow(unused_mut)] mut config: LaunchConfig<'a, State>,
         devtools: Option<Devtools>,
         hovered_node: HoveredNode,
         proxy: EventLoopProxy<EventLoopMessage>,
     ) -> Self {
         WinitRenderer {
+            #[cfg(feature = "menu")]
+            menu: config.menu.take(),
+            #[cfg(feature = "tray")]
+            tray_icon_builder: config.tray_icon.take(),
+            #[cfg(feature = "tray")]
+            tray_icon: None,
             state: WindowState::NotCreated(NotCreatedState {
                 sdom,
                 devtools,
@@ -116,85 +183,236 @@ impl<'a, State: Clone + 'static> WinitRenderer<'a, State> {
             cursor_pos: CursorPoint::default(),
             mouse_state: ElementState::Released,
             modifiers_state: ModifiersState::default(),
-            dropped_file_path: None,
+            dropped_file_paths: Vec::new(),
+            clipboard: SystemClipboard::default(),
             custom_scale_factor: 0.,
+            portal_windows: PortalWindows::default(),
+            windows: HashMap::default(),
         }
     }
 
-    // Send and process an event
-    fn send_event(&mut self, event: PlatformEvent) {
-        let scale_factor = self.scale_factor();
-        self.state
-            .created_state()
-            .app
-            .send_event(event, scale_factor);
+    /// Forward the events of the native menus, both from the menu bar and the tray, to the windows.
+    #[cfg(feature = "menu")]
+    fn install_menu_event_handler(&self) {
+        use freya_core::platform::muda::MenuEvent;
+
+        let proxy = self.event_loop_proxy.clone();
+        MenuEvent::set_event_handler(Some(move |event| {
+            proxy.send_event(EventLoopMessage::Menu(event)).ok();
+        }));
     }
 
-    /// Get the current scale factor of the Window
-    fn scale_factor(&self) -> f64 {
-        match &self.state {
-            WindowState::Created(CreatedState { window, .. }) => {
-                window.scale_factor() + self.custom_scale_factor
+    /// Install the menu bar in the main window.
+    #[cfg(feature = "menu")]
+    fn install_menu(&mut self) {
+        let Some(menu) = &self.menu else {
+            return;
+        };
+
+        #[cfg(target_os = "windows")]
+        {
+            use winit::raw_window_handle::{
+                HasWindowHandle,
+                RawWindowHandle,
+            };
+
+            let window = &self.state.created_state().window;
+            if let Ok(RawWindowHandle::Win32(handle)) =
+                window.window_handle().map(|handle| handle.as_raw())
+            {
+                if let Err(err) = unsafe { menu.init_for_hwnd(handle.hwnd.get()) } {
+                    tracing::error!("Failed to install the menu bar: {err}");
+                }
             }
-            _ => 0.0,
         }
-    }
 
-    /// Run the `on_setup` callback that was passed to the launch function
-    pub fn run_on_setup(&mut self) {
-        let state = self.state.created_state();
-        if let Some(on_setup) = state.window_config.on_setup.take() {
-            (on_setup)(&mut state.window)
+        #[cfg(target_os = "macos")]
+        menu.init_for_nsapp();
+
+        #[cfg(not(any(target_os = "windows", target_os = "macos")))]
+        {
+            let _ = menu;
+            tracing::warn!("The native menu bar is not supported in this platform.");
         }
     }
 
-    /// Run the `on_exit` callback that was passed to the launch function
-    pub fn run_on_exit(&mut self) {
-        let state = self.state.created_state();
-        if let Some(on_exit) = state.window_config.on_exit.take() {
-            (on_exit)(&mut state.window)
+    /// Install the tray icon and forward its events to the windows.
+    #[cfg(feature = "tray")]
+    fn install_tray_icon(&mut self) {
+        use freya_core::platform::{
+            tray_icon::TrayIconEvent,
+            TrayEvent,
+        };
+
+        let Some(tray_icon_builder) = self.tray_icon_builder.take() else {
+            return;
+        };
+
+        let proxy = self.event_loop_proxy.clone();
+        TrayIconEvent::set_event_handler(Some(move |event| {
+            proxy
+                .send_event(EventLoopMessage::Tray(TrayEvent::Icon(event)))
+                .ok();
+        }));
+        match tray_icon_builder.build() {
+            Ok(tray_icon) => self.tray_icon = Some(tray_icon),
+            Err(err) => tracing::error!("Failed to install the tray icon: {err}"),
         }
     }
-}
 
-impl<State: Clone> ApplicationHandler<EventLoopMessage> for WinitRenderer<'_, State> {
-    fn resumed(&mut self, event_loop: &winit::event_loop::ActiveEventLoop) {
-        if !self.state.has_been_created() {
-            self.state.create(event_loop, &self.event_loop_proxy);
-            self.run_on_setup();
+    /// Get the state of a window, either the main one or any of the ones opened from the app.
+    fn window<'s>(
+        state: &'s mut WindowState<'a, State>,
+        windows: &'s mut HashMap<WindowId, CreatedState>,
+        window_id: WindowId,
+    ) -> Option<&'s mut CreatedState> {
+        match state {
+            WindowState::Created(created_state) if created_state.window.id() == window_id => {
+                Some(created_state)
+            }
+            _ => windows.get_mut(&window_id),
         }
     }
 
-    fn new_events(
-        &mut self,
-        _event_loop: &winit::event_loop::ActiveEventLoop,
-        cause: winit::event::StartCause,
-    ) {
-        if cause == StartCause::Init {
-            self.event_loop_proxy
-                .send_event(EventLoopMessage::PollVDOM)
-                .ok();
+    // Send and process an event
+    fn send_event(&mut self, window_id: WindowId, event: PlatformEvent) {
+        let scale_factor = self.scale_factor(window_id);
+        if let Some(created_state) = Self::window(&mut self.state, &mut self.windows, window_id) {
+            created_state.app.send_event(event, scale_factor);
         }
     }
 
-    fn user_event(
+    /// Get the current scale factor of a Window
+    fn scale_factor(&self, window_id: WindowId) -> f64 {
+        let window = match &self.state {
+            WindowState::Created(CreatedState { window, .. }) if window.id() == window_id => {
+                Some(window)
+            }
+            _ => self
+                .windows
+                .get(&window_id)
+                .map(|created_state| &created_state.window),
+        };
+        window
+            .map(|window| window.scale_factor() + self.custom_scale_factor)
+            .unwrap_or_default()
+    }
+
+    /// Open a new window with its own app, sharing the fonts of the main one.
+    fn open_window(&mut self, event_loop: &ActiveEventLoop, new_window: NewWindow) {
+        let NewWindow { vdom, settings } = new_window;
+        let main_app = &self.state.created_state().app;
+
+        let mut config = LaunchConfig::<State>::default();
+        config.default_fonts = main_app.default_fonts.clone();
+        config.window_config.size = settings.size;
+        config.window_config.decorations = settings.decorations;
+        config.window_config.transparent = settings.transparent;
+        config.window_config.background = settings.background;
+        config.window_config.size_to_content = settings.size_to_content;
+        config.window_config.window_attributes_hook = Some(Box::new(move |window_attributes| {
+            window_attributes.with_title(settings.title)
+        }));
+
+        let created_state = CreatedState::new(
+            event_loop,
+            &self.event_loop_proxy,
+            NotCreatedState {
+                sdom: SafeDOM::new(FreyaDOM::default()),
+                vdom: vdom(),
+                devtools: None,
+                config,
+            },
+            Some(main_app),
+        );
+        let window_id = created_state.window.id();
+        self.windows.insert(window_id, created_state);
+
+        self.event_loop_proxy
+            .send_event(EventLoopMessage::ForWindow(
+                window_id,
+                Box::new(EventLoopMessage::PollVDOM),
+            ))
+            .ok();
+    }
+
+    /// Handle a message for the given window.
+    fn handle_message(
         &mut self,
-        event_loop: &winit::event_loop::ActiveEventLoop,
+        event_loop: &ActiveEventLoop,
+        window_id: WindowId,
         event: EventLoopMessage,
     ) {
-        let scale_factor = self.scale_factor();
-        let CreatedState { window, app, .. } = self.state.created_state();
+        // Power events, the menus and the tray are shared by all the windows
+        if let EventLoopMessage::Power(event) = event {
+            let windows =
+                std::iter::once(self.state.created_state()).chain(self.windows.values_mut());
+            for CreatedState { app, window, .. } in windows {
+                // Paint everything again as the GPU resources might have been lost while asleep
+                if let PowerEvent::Resumed { .. } = event {
+                    app.resize(window);
+                    app.frame_scheduler.request_redraw();
+                }
+                app.power_sender.send(event).ok();
+            }
+            return;
+        }
+
+        if let EventLoopMessage::Clipboard(request) = event {
+            self.clipboard.handle(request);
+            return;
+        }
+
+        #[cfg(feature = "menu")]
+        if let EventLoopMessage::Menu(event) = event {
+            let main_app = &self.state.created_state().app;
+            for app in std::iter::once(main_app).chain(self.windows.values().map(|w| &w.app)) {
+                app.menu_sender.send(event.clone()).ok();
+                #[cfg(feature = "tray")]
+                app.tray_sender
+                    .send(freya_core::platform::TrayEvent::Menu(event.clone()))
+                    .ok();
+            }
+            return;
+        }
+        #[cfg(feature = "tray")]
+        if let EventLoopMessage::Tray(event) = event {
+            let main_app = &self.state.created_state().app;
+            for app in std::iter::once(main_app).chain(self.windows.values().map(|w| &w.app)) {
+                app.tray_sender.send(event.clone()).ok();
+            }
+            return;
+        }
+
+        let scale_factor = self.scale_factor(window_id);
+        let Some(CreatedState { window, app, .. }) =
+            Self::window(&mut self.state, &mut self.windows, window_id)
+        else {
+            return;
+        };
         match event {
+            EventLoopMessage::ForWindow(window_id, event) => {
+                self.handle_message(event_loop, window_id, *event)
+            }
+            EventLoopMessage::OpenWindow(new_window) => self.open_window(event_loop, new_window),
+            EventLoopMessage::CloseWindow(window_id) => {
+                if self.windows.remove(&window_id).is_none() {
+                    event_loop.exit();
+                }
+            }
             EventLoopMessage::FocusAccessibilityNode(strategy) => {
                 app.request_focus_node(strategy);
-                window.request_redraw();
+                app.frame_scheduler.request_frame();
             }
             EventLoopMessage::RequestRerender => {
-                window.request_redraw();
+                app.frame_scheduler.request_frame();
+            }
+            EventLoopMessage::RequestRedraw => {
+                app.frame_scheduler.request_redraw();
             }
             EventLoopMessage::RequestFullRerender => {
                 app.resize(window);
-                window.request_redraw();
+                app.frame_scheduler.request_frame();
             }
             EventLoopMessage::InvalidateArea(mut area) => {
                 let fdom = app.sdom.get();
@@ -205,12 +423,24 @@ impl<State: Clone> ApplicationHandler<EventLoopMessage> for WinitRenderer<'_, St
             EventLoopMessage::RemeasureTextGroup(text_id) => {
                 app.measure_text_group(text_id, scale_factor);
             }
+            EventLoopMessage::RegisterFont(font_name, font_data) => {
+                app.register_font(&font_name, &font_data, window);
+                app.frame_scheduler.request_frame();
+            }
+            EventLoopMessage::SetTextScaleFactor(text_scale_factor) => {
+                app.set_text_scale_factor(text_scale_factor, window);
+                app.frame_scheduler.request_frame();
+            }
+            EventLoopMessage::SetLayoutDirection(layout_direction) => {
+                app.set_layout_direction(layout_direction, window);
+                app.frame_scheduler.request_frame();
+            }
             EventLoopMessage::Accessibility(accesskit_winit::WindowEvent::ActionRequested(
                 request,
             )) => {
                 if accesskit::Action::Focus == request.action {
                     app.request_focus_node(AccessibilityFocusStrategy::Node(request.target));
-                    window.request_redraw();
+                    app.frame_scheduler.request_frame();
                 }
             }
             EventLoopMessage::Accessibility(accesskit_winit::WindowEvent::InitialTreeRequested) => {
@@ -218,8 +448,11 @@ impl<State: Clone> ApplicationHandler<EventLoopMessage> for WinitRenderer<'_, St
             }
             EventLoopMessage::SetCursorIcon(icon) => window.set_cursor(icon),
             EventLoopMessage::WithWindow(use_window) => (use_window)(window),
+            EventLoopMessage::WindowAction(action) => action.apply(window),
             EventLoopMessage::ExitApp => event_loop.exit(),
-            EventLoopMessage::PlatformEvent(platform_event) => self.send_event(platform_event),
+            EventLoopMessage::PlatformEvent(platform_event) => {
+                self.send_event(window_id, platform_event)
+            }
             EventLoopMessage::PollVDOM => {
                 app.poll_vdom(window);
             }
@@ -227,14 +460,122 @@ impl<State: Clone> ApplicationHandler<EventLoopMessage> for WinitRenderer<'_, St
         }
     }
 
+    /// Run the `on_setup` callback that was passed to the launch function
+    pub fn run_on_setup(&mut self) {
+        let state = self.state.created_state();
+        if let Some(on_setup) = state.window_config.on_setup.take() {
+            (on_setup)(&mut state.window)
+        }
+    }
+
+    /// Run the `on_exit` callback that was passed to the launch function
+    pub fn run_on_exit(&mut self) {
+        let state = self.state.created_state();
+        if let Some(on_exit) = state.window_config.on_exit.take() {
+            (on_exit)(&mut state.window)
+        }
+    }
+}
+
+impl<State: Clone> ApplicationHandler<EventLoopMessage> for WinitRenderer<'_, State> {
+    fn resumed(&mut self, event_loop: &winit::event_loop::ActiveEventLoop) {
+        if self.state.has_been_created() {
+            let main_window_id = self.state.created_state().window.id();
+            self.handle_message(
+                event_loop,
+                main_window_id,
+                EventLoopMessage::Power(PowerEvent::Resumed { slept: None }),
+            );
+        } else {
+            self.state.create(event_loop, &self.event_loop_proxy);
+            #[cfg(feature = "menu")]
+            {
+                self.install_menu_event_handler();
+                self.install_menu();
+            }
+            #[cfg(feature = "tray")]
+            self.install_tray_icon();
+            self.run_on_setup();
+            watch_system_sleep(self.event_loop_proxy.clone());
+        }
+    }
+
+    fn suspended(&mut self, event_loop: &winit::event_loop::ActiveEventLoop) {
+        if self.state.has_been_created() {
+            let main_window_id = self.state.created_state().window.id();
+            self.handle_message(
+                event_loop,
+                main_window_id,
+                EventLoopMessage::Power(PowerEvent::Suspended),
+            );
+        }
+    }
+
+    fn new_events(
+        &mut self,
+        _event_loop: &winit::event_loop::ActiveEventLoop,
+        cause: winit::event::StartCause,
+    ) {
+        if cause == StartCause::Init {
+            self.event_loop_proxy
+                .send_event(EventLoopMessage::PollVDOM)
+                .ok();
+        }
+    }
+
+    fn user_event(
+        &mut self,
+        event_loop: &winit::event_loop::ActiveEventLoop,
+        event: EventLoopMessage,
+    ) {
+        // Messages without a target window go to the main one
+        let main_window_id = self.state.created_state().window.id();
+        self.handle_message(event_loop, main_window_id, event);
+    }
+
     fn window_event(
         &mut self,
         event_loop: &winit::event_loop::ActiveEventLoop,
-        _window_id: winit::window::WindowId,
-        event: winit::event::WindowEvent,
+        mut window_id: winit::window::WindowId,
+        mut event: winit::event::WindowEvent,
     ) {
-        let scale_factor = self.scale_factor();
-        let CreatedState {
+        let main_window_id = self.state.created_state().window.id();
+
+        // Pointer events of the portal windows are handled as if they happened in the main window
+        if window_id != main_window_id && !self.windows.contains_key(&window_id) {
+            let Some(offset) = self.portal_windows.offset_of(window_id) else {
+                return;
+            };
+            match &mut event {
+                WindowEvent::CursorMoved { position, .. } => {
+                    position.x += offset.x;
+                    position.y += offset.y;
+                }
+                WindowEvent::Touch(Touch { location, .. }) => {
+                    location.x += offset.x;
+                    location.y += offset.y;
+                }
+                WindowEvent::MouseInput { .. }
+                | WindowEvent::MouseWheel { .. }
+                | WindowEvent::CursorLeft { .. }
+                | WindowEvent::ModifiersChanged(..) => {}
+                WindowEvent::RedrawRequested => {
+                    // Paint the portals again along with the main window
+                    self.state
+                        .created_state()
+                        .app
+                        .frame_scheduler
+                        .request_redraw();
+                    return;
+                }
+                _ => return,
+            }
+            window_id = main_window_id;
+        }
+
+        let is_main_window = window_id == main_window_id;
+        let scale_factor = self.scale_factor(window_id);
+        let Some(CreatedState {
             surface,
             dirty_surface,
             window,
@@ -243,7 +584,10 @@ impl<State: Clone> ApplicationHandler<EventLoopMessage> for WinitRenderer<'_, St
             is_window_focused,
             graphics_driver,
             ..
-        } = self.state.created_state();
+        }) = Self::window(&mut self.state, &mut self.windows, window_id)
+        else {
+            return;
+        };
         app.accessibility
             .process_accessibility_event(&event, window);
         match event {
@@ -252,16 +596,27 @@ impl<State: Clone> ApplicationHandler<EventLoopMessage> for WinitRenderer<'_, St
                     state.preferred_theme = theme.into();
                 });
             }
-            WindowEvent::CloseRequested => event_loop.exit(),
+            WindowEvent::CloseRequested => {
+                if is_main_window {
+                    event_loop.exit();
+                } else {
+                    self.windows.remove(&window_id);
+                }
+            }
             WindowEvent::Ime(Ime::Commit(text)) => {
-                self.send_event(PlatformEvent {
-                    name: EventName::KeyDown,
-                    data: PlatformEventData::Keyboard {
-                        key: Key::Character(text),
-                        code: Code::Unidentified,
-                        modifiers: map_winit_modifiers(self.modifiers_state),
+                self.send_event(
+                    window_id,
+                    PlatformEvent {
+                        name: EventName::KeyDown,
+                        data: PlatformEventData::Keyboard {
+                            key: Key::Character(text),
+                            code: Code::Unidentified,
+                            modifiers: map_winit_modifiers(self.modifiers_state),
+                            repeat: false,
+                            location: Location::Standard,
+                        },
                     },
-                });
+                );
             }
             WindowEvent::RedrawRequested => {
                 app.platform_sender.send_if_modified(|state| {
@@ -274,6 +629,10 @@ impl<State: Clone> ApplicationHandler<EventLoopMessage> for WinitRenderer<'_, St
                     app.process_layout(window.inner_size(), scale_factor);
 
                     app.process_layout_on_next_render = false;
+
+                    if window_config.size_to_content == SizeToContent::Always {
+                        size_window_to_content(window, app, window_config);
+                    }
                 }
 
                 match app.process_accessibility_task_on_next_render {
@@ -292,20 +651,52 @@ impl<State: Clone> ApplicationHandler<EventLoopMessage> for WinitRenderer<'_, St
                     app.init_accessibility_on_next_render = false;
                 }
 
-                graphics_driver.make_current();
+                // The OS might have discarded the content of the window, e.g. after uncovering it,
+                // so the redraws it asks for paint the whole window
+                if !app.frame_scheduler.is_frame_scheduled() {
+                    app.invalidate_window(window);
+                }
+
+                if app.has_pending_frame() {
+                    graphics_driver.make_current();
+
+                    app.render(
+                        &self.hovered_node,
+                        window_config.background,
+                        surface,
+                        dirty_surface,
+                        window,
+                        scale_factor,
+                    );
+
+                    if let Some(corner_radius) = window_config.drawn_corner_radius() {
+                        clip_window_corners(
+                            surface,
+                            window.inner_size(),
+                            corner_radius * scale_factor as f32,
+                        );
+                    }
 
-                app.render(
-                    &self.hovered_node,
-                    window_config.background,
-                    surface,
-                    dirty_surface,
-                    window,
-                    scale_factor,
-                );
+                    window.pre_present_notify();
+                    graphics_driver.flush_and_submit();
+
+                    if is_main_window {
+                        self.portal_windows.sync(
+                            event_loop,
+                            app,
+                            window,
+                            window_config,
+                            scale_factor as f32,
+                        );
+                    }
+                }
 
+                app.frame_scheduler.frame_rendered(Instant::now());
                 app.event_loop_tick();
-                window.pre_present_notify();
-                graphics_driver.flush_and_submit();
+            }
+            WindowEvent::Moved(_) => {
+                // Move the portal windows along with the main window
+                app.frame_scheduler.request_redraw();
             }
             WindowEvent::MouseInput { state, button, .. } => {
                 app.set_navigation_mode(NavigationMode::NotKeyboard);
@@ -322,40 +713,62 @@ impl<State: Clone> ApplicationHandler<EventLoopMessage> for WinitRenderer<'_, St
                     },
                 };
 
-                self.send_event(PlatformEvent {
-                    name,
-                    data: PlatformEventData::Mouse {
-                        cursor: self.cursor_pos,
-                        button: Some(button),
+                self.send_event(
+                    window_id,
+                    PlatformEvent {
+                        name,
+                        data: PlatformEventData::Mouse {
+                            cursor: self.cursor_pos,
+                            button: Some(button),
+                        },
                     },
-                });
+                );
             }
             WindowEvent::MouseWheel { delta, phase, .. } => {
-                if TouchPhase::Moved == phase {
-                    let scroll_data = {
-                        match delta {
-                            MouseScrollDelta::LineDelta(x, y) => (
-                                (x as f64 * WHEEL_SPEED_MODIFIER),
-                                (y as f64 * WHEEL_SPEED_MODIFIER),
-                            ),
-                            MouseScrollDelta::PixelDelta(pos) => (
-                                (pos.x * TOUCHPAD_SPEED_MODIFIER),
-                                (pos.y * TOUCHPAD_SPEED_MODIFIER),
-                            ),
-                        }
-                    };
+                let (scroll_data, raw_scroll, delta_mode) = match delta {
+                    MouseScrollDelta::LineDelta(x, y) => (
+                        (
+                            x as f64 * WHEEL_SPEED_MODIFIER,
+                            y as f64 * WHEEL_SPEED_MODIFIER,
+                        ),
+                        (x as f64, y as f64),
+                        WheelDeltaMode::Line,
+                    ),
+                    MouseScrollDelta::PixelDelta(pos) => (
+                        (
+                            pos.x * TOUCHPAD_SPEED_MODIFIER,
+                            pos.y * TOUCHPAD_SPEED_MODIFIER,
+                        ),
+                        (pos.x, pos.y),
+                        WheelDeltaMode::Pixel,
+                    ),
+                };
+                let phase = match phase {
+                    TouchPhase::Started => WheelPhase::Started,
+                    TouchPhase::Moved => WheelPhase::Moved,
+                    TouchPhase::Ended | TouchPhase::Cancelled => WheelPhase::Ended,
+                };
 
-                    self.send_event(PlatformEvent {
+                self.send_event(
+                    window_id,
+                    PlatformEvent {
                         name: EventName::Wheel,
                         data: PlatformEventData::Wheel {
                             scroll: CursorPoint::from(scroll_data),
                             cursor: self.cursor_pos,
+                            raw_scroll: CursorPoint::from(raw_scroll),
+                            delta_mode,
+                            phase,
+                            // Detected by the wheel tracker of the app
+                            momentum: false,
                         },
-                    });
-                }
+                    },
+                );
             }
             WindowEvent::ModifiersChanged(modifiers) => {
                 self.modifiers_state = modifiers.state();
+                app.modifiers_sender
+                    .send_replace(map_winit_modifiers(self.modifiers_state));
             }
             WindowEvent::KeyboardInput {
                 event:
@@ -363,6 +776,8 @@ impl<State: Clone> ApplicationHandler<EventLoopMessage> for WinitRenderer<'_, St
                         physical_key,
                         logical_key,
                         state,
+                        repeat,
+                        location,
                         ..
                     },
                 ..
@@ -381,7 +796,11 @@ impl<State: Clone> ApplicationHandler<EventLoopMessage> for WinitRenderer<'_, St
                         }
                     };
 
-                    if is_control_pressed && state == ElementState::Pressed {
+                    // Shortcuts can't be used while the keys are being captured
+                    if is_control_pressed
+                        && state == ElementState::Pressed
+                        && !app.key_capture.is_capturing()
+                    {
                         let ch = logical_key.to_text();
                         let render_with_new_scale_factor = if ch == Some("+") {
                             self.custom_scale_factor =
@@ -397,7 +816,7 @@ impl<State: Clone> ApplicationHandler<EventLoopMessage> for WinitRenderer<'_, St
 
                         if render_with_new_scale_factor {
                             app.resize(window);
-                            window.request_redraw();
+                            app.frame_scheduler.request_frame();
                         }
                     }
                 }
@@ -406,47 +825,62 @@ impl<State: Clone> ApplicationHandler<EventLoopMessage> for WinitRenderer<'_, St
                     ElementState::Pressed => EventName::KeyDown,
                     ElementState::Released => EventName::KeyUp,
                 };
-                self.send_event(PlatformEvent {
-                    name,
-                    data: PlatformEventData::Keyboard {
-                        key: map_winit_key(&logical_key),
-                        code: map_winit_physical_key(&physical_key),
-                        modifiers: map_winit_modifiers(self.modifiers_state),
+                self.send_event(
+                    window_id,
+                    PlatformEvent {
+                        name,
+                        data: PlatformEventData::Keyboard {
+                            key: map_winit_key(&logical_key),
+                            code: map_winit_physical_key(&physical_key),
+                            modifiers: map_winit_modifiers(self.modifiers_state),
+                            repeat,
+                            location: map_winit_key_location(location),
+                        },
                     },
-                })
+                )
             }
             WindowEvent::CursorLeft { .. } => {
                 if self.mouse_state == ElementState::Released {
                     self.cursor_pos = CursorPoint::new(-1.0, -1.0);
 
-                    self.send_event(PlatformEvent {
-                        name: EventName::MouseMove,
-                        data: PlatformEventData::Mouse {
-                            cursor: self.cursor_pos,
-                            button: None,
+                    self.send_event(
+                        window_id,
+                        PlatformEvent {
+                            name: EventName::MouseMove,
+                            data: PlatformEventData::Mouse {
+                                cursor: self.cursor_pos,
+                                button: None,
+                            },
                         },
-                    });
+                    );
                 }
             }
             WindowEvent::CursorMoved { position, .. } => {
                 self.cursor_pos = CursorPoint::from((position.x, position.y));
 
-                self.send_event(PlatformEvent {
-                    name: EventName::MouseMove,
-                    data: PlatformEventData::Mouse {
-                        cursor: self.cursor_pos,
-                        button: None,
+                self.send_event(
+                    window_id,
+                    PlatformEvent {
+                        name: EventName::MouseMove,
+                        data: PlatformEventData::Mouse {
+                            cursor: self.cursor_pos,
+                            button: None,
+                        },
                     },
-                });
+                );
 
-                if let Some(dropped_file_path) = self.dropped_file_path.take() {
-                    self.send_event(PlatformEvent {
-                        name: EventName::FileDrop,
-                        data: PlatformEventData::File {
-                            file_path: Some(dropped_file_path),
-                            cursor: self.cursor_pos,
+                // The dropped files are emitted once the cursor position is known
+                for dropped_file_path in mem::take(&mut self.dropped_file_paths) {
+                    self.send_event(
+                        window_id,
+                        PlatformEvent {
+                            name: EventName::FileDrop,
+                            data: PlatformEventData::File {
+                                file_path: Some(dropped_file_path),
+                                cursor: self.cursor_pos,
+                            },
                         },
-                    });
+                    );
                 }
             }
             WindowEvent::Touch(Touch {
@@ -465,15 +899,18 @@ impl<State: Clone> ApplicationHandler<EventLoopMessage> for WinitRenderer<'_, St
                     TouchPhase::Started => EventName::TouchStart,
                 };
 
-                self.send_event(PlatformEvent {
-                    name,
-                    data: PlatformEventData::Touch {
-                        location: self.cursor_pos,
-                        finger_id: id,
-                        phase,
-                        force,
+                self.send_event(
+                    window_id,
+                    PlatformEvent {
+                        name,
+                        data: PlatformEventData::Touch {
+                            location: self.cursor_pos,
+                            finger_id: id,
+                            phase,
+                            force,
+                        },
                     },
-                });
+                );
             }
             WindowEvent::Resized(size) => {
                 let (new_surface, new_dirty_surface) = graphics_driver.resize(size);
@@ -481,38 +918,81 @@ impl<State: Clone> ApplicationHandler<EventLoopMessage> for WinitRenderer<'_, St
                 *surface = new_surface;
                 *dirty_surface = new_dirty_surface;
 
-                window.request_redraw();
+                app.frame_scheduler.request_frame();
 
                 app.resize(window);
             }
             WindowEvent::DroppedFile(file_path) => {
-                self.dropped_file_path = Some(file_path);
+                self.dropped_file_paths.push(file_path);
             }
             WindowEvent::HoveredFile(file_path) => {
-                self.send_event(PlatformEvent {
-                    name: EventName::GlobalFileHover,
-                    data: PlatformEventData::File {
-                        file_path: Some(file_path),
-                        cursor: self.cursor_pos,
+                self.send_event(
+                    window_id,
+                    PlatformEvent {
+                        name: EventName::GlobalFileHover,
+                        data: PlatformEventData::File {
+                            file_path: Some(file_path),
+                            cursor: self.cursor_pos,
+                        },
                     },
-                });
+                );
             }
             WindowEvent::HoveredFileCancelled => {
-                self.send_event(PlatformEvent {
-                    name: EventName::GlobalFileHoverCancelled,
-                    data: PlatformEventData::File {
-                        file_path: None,
-                        cursor: self.cursor_pos,
+                self.send_event(
+                    window_id,
+                    PlatformEvent {
+                        name: EventName::GlobalFileHoverCancelled,
+                        data: PlatformEventData::File {
+                            file_path: None,
+                            cursor: self.cursor_pos,
+                        },
                     },
-                });
+                );
             }
             WindowEvent::Focused(is_focused) => {
                 *is_window_focused = is_focused;
             }
+            WindowEvent::Occluded(occluded) => {
+                let event = if occluded {
+                    PowerEvent::DisplayOff
+                } else {
+                    PowerEvent::DisplayOn
+                };
+                app.power_sender.send(event).ok();
+            }
             _ => {}
         }
     }
 
+    fn about_to_wait(&mut self, event_loop: &winit::event_loop::ActiveEventLoop) {
+        if !self.state.has_been_created() {
+            return;
+        }
+
+        let now = Instant::now();
+        let mut control_flow = ControlFlow::Wait;
+        let windows = std::iter::once(self.state.created_state()).chain(self.windows.values_mut());
+        for CreatedState { app, window, .. } in windows {
+            match app.frame_scheduler.next_frame(now) {
+                NextFrame::Idle => {}
+                NextFrame::Now => {
+                    app.frame_scheduler.frame_scheduled();
+                    window.request_redraw();
+                }
+                // Wake up once the FPS cap allows to render the next frame
+                NextFrame::At(instant) => {
+                    control_flow = match control_flow {
+                        ControlFlow::WaitUntil(next_instant) => {
+                            ControlFlow::WaitUntil(next_instant.min(instant))
+                        }
+                        _ => ControlFlow::WaitUntil(instant),
+                    }
+                }
+            }
+        }
+        event_loop.set_control_flow(control_flow);
+    }
+
     fn exiting(&mut self, _event_loop: &winit::event_loop::ActiveEventLoop) {
         self.run_on_exit();
     }
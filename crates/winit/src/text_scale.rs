@@ -0,0 +1,70 @@
+use std::{
+    process::Command,
+    thread,
+};
+
+use freya_core::event_loop_messages::EventLoopMessage;
+use winit::{
+    event_loop::EventLoopProxy,
+    window::WindowId,
+};
+
+/// Keep the text scale factor in a range where text is still usable.
+pub(crate) fn sanitize_text_scale_factor(text_scale_factor: f32) -> f32 {
+    if text_scale_factor.is_finite() {
+        text_scale_factor.clamp(0.5, 4.0)
+    } else {
+        1.0
+    }
+}
+
+/// Apply the text size preference of the OS to the given window once it's known.
+///
+/// Reading it might take a while, so it happens in a background thread
+/// and the window starts with a text scale factor of `1.0` meanwhile.
+pub(crate) fn load_os_text_scale_factor(
+    event_loop_proxy: EventLoopProxy<EventLoopMessage>,
+    window_id: WindowId,
+) {
+    thread::spawn(move || {
+        if let Some(text_scale_factor) = os_text_scale_factor() {
+            event_loop_proxy
+                .send_event(EventLoopMessage::ForWindow(
+                    window_id,
+                    Box::new(EventLoopMessage::SetTextScaleFactor(text_scale_factor)),
+                ))
+                .ok();
+        }
+    });
+}
+
+/// Read the text size preference from the accessibility settings of the OS.
+/// It runs a command, so it must not be called from the event loop.
+///
+/// Supported on Windows ("Text size") and on Linux desktops exposing the GNOME `text-scaling-factor` setting.
+fn os_text_scale_factor() -> Option<f32> {
+    if cfg!(target_os = "windows") {
+        let output = Command::new("reg")
+            .args([
+                "query",
+                r"HKCU\Software\Microsoft\Accessibility",
+                "/v",
+                "TextScaleFactor",
+            ])
+            .output()
+            .ok()?;
+        let output = String::from_utf8(output.stdout).ok()?;
+        // e.g `TextScaleFactor    REG_DWORD    0x7d`, as a percentage
+        let value = output.split_whitespace().last()?.strip_prefix("0x")?;
+        let percentage = u32::from_str_radix(value, 16).ok()?;
+        Some(percentage as f32 / 100.0)
+    } else if cfg!(target_os = "linux") {
+        let output = Command::new("gsettings")
+            .args(["get", "org.gnome.desktop.interface", "text-scaling-factor"])
+            .output()
+            .ok()?;
+        String::from_utf8(output.stdout).ok()?.trim().parse().ok()
+    } else {
+        None
+    }
+}
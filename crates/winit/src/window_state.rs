@@ -3,11 +3,17 @@ use std::mem;
 use dioxus_core::VirtualDom;
 use freya_core::{
     dom::SafeDOM,
-    event_loop_messages::EventLoopMessage,
+    event_loop_messages::{
+        EventLoopMessage,
+        SizeToContent,
+    },
 };
 use freya_engine::prelude::*;
 use winit::{
-    dpi::LogicalSize,
+    dpi::{
+        LogicalSize,
+        PhysicalSize,
+    },
     event_loop::{
         ActiveEventLoop,
         EventLoopProxy,
@@ -22,6 +28,7 @@ use crate::{
     devtools::Devtools,
     drivers::GraphicsDriver,
     size::WinitSize,
+    text_scale::load_os_text_scale_factor,
     LaunchConfig,
 };
 
@@ -65,21 +72,41 @@ impl<'a, State: Clone + 'a> WindowState<'a, State> {
         event_loop: &ActiveEventLoop,
         event_loop_proxy: &EventLoopProxy<EventLoopMessage>,
     ) {
-        let Self::NotCreated(NotCreatedState {
+        let Self::NotCreated(not_created_state) = mem::replace(self, WindowState::Creating) else {
+            unreachable!("Infallible, window should not be created at this point.")
+        };
+
+        *self = WindowState::Created(CreatedState::new(
+            event_loop,
+            event_loop_proxy,
+            not_created_state,
+            None,
+        ));
+    }
+}
+
+impl CreatedState {
+    /// Create a window and its app, sharing the fonts of the main app in the case of the windows opened from it.
+    pub fn new<State: Clone + 'static>(
+        event_loop: &ActiveEventLoop,
+        event_loop_proxy: &EventLoopProxy<EventLoopMessage>,
+        NotCreatedState {
             sdom,
             vdom,
             devtools,
             mut config,
-        }) = mem::replace(self, WindowState::Creating)
-        else {
-            unreachable!("Infallible, window should not be created at this point.")
-        };
-
+        }: NotCreatedState<State>,
+        main_app: Option<&Application>,
+    ) -> Self {
         let mut window_attributes = Window::default_attributes()
             .with_visible(false)
             .with_title(config.window_config.title)
             .with_decorations(config.window_config.decorations)
-            .with_transparent(config.window_config.transparent)
+            .with_transparent(
+                config.window_config.transparent
+                    || config.window_config.drawn_corner_radius().is_some(),
+            )
+            .with_blur(config.window_config.blur)
             .with_window_icon(config.window_config.icon.take())
             .with_inner_size(LogicalSize::<f64>::from(config.window_config.size));
 
@@ -92,20 +119,49 @@ impl<'a, State: Clone + 'a> WindowState<'a, State> {
                 window_attributes.with_max_inner_size(LogicalSize::<f64>::from(max_size));
         }
 
+        if !config.window_config.decorations {
+            #[cfg(target_os = "windows")]
+            {
+                use winit::platform::windows::{
+                    CornerPreference,
+                    WindowAttributesExtWindows,
+                };
+
+                window_attributes =
+                    window_attributes.with_undecorated_shadow(config.window_config.shadow);
+                if config.window_config.corner_radius.is_some() {
+                    window_attributes =
+                        window_attributes.with_corner_preference(CornerPreference::Round);
+                }
+            }
+            #[cfg(target_os = "macos")]
+            {
+                use winit::platform::macos::WindowAttributesExtMacOS;
+
+                window_attributes = window_attributes.with_has_shadow(config.window_config.shadow);
+            }
+        }
+
         if let Some(with_window_attributes) = config.window_config.window_attributes_hook.take() {
             window_attributes = (with_window_attributes)(window_attributes);
         }
 
         let (graphics_driver, window, mut surface) =
-            GraphicsDriver::new(event_loop, window_attributes, &config);
+            GraphicsDriver::new(event_loop, window_attributes, &config.window_config);
+
+        // Fallback to an opaque background if the platform can't make the window transparent
+        if config.window_config.transparent && !graphics_driver.supports_transparency() {
+            tracing::warn!("Transparent windows are not supported, using an opaque background.");
+            config.window_config.background = config.window_config.background.with_a(255);
+            if config.window_config.drawn_corner_radius().is_some() {
+                tracing::warn!("Rounded corners are not supported, using square corners.");
+                config.window_config.corner_radius = None;
+            }
+        }
 
         let accessibility =
             WinitAcessibilityTree::new(event_loop, &window, event_loop_proxy.clone());
 
-        if config.window_config.visible {
-            window.set_visible(true);
-        }
-
         // Allow IME
         window.set_ime_allowed(true);
 
@@ -134,15 +190,36 @@ impl<'a, State: Clone + 'a> WindowState<'a, State> {
             devtools,
             &window,
             config.embedded_fonts,
+            config.embedded_font_bytes,
             config.plugins,
             config.default_fonts,
+            config.text_scale_factor.unwrap_or(1.0),
+            config.layout_direction,
             accessibility,
+            config.window_config.max_fps,
         );
 
+        if let Some(main_app) = main_app {
+            app.share_fonts(main_app);
+        }
+
+        if config.text_scale_factor.is_none() {
+            load_os_text_scale_factor(event_loop_proxy.clone(), window.id());
+        }
+
         app.init_doms(scale_factor as f32, config.state);
         app.process_layout(window.inner_size(), scale_factor);
 
-        *self = WindowState::Created(CreatedState {
+        if config.window_config.size_to_content != SizeToContent::Never {
+            size_window_to_content(&window, &app, &config.window_config);
+        }
+
+        // Shown once it has its final size
+        if config.window_config.visible {
+            window.set_visible(true);
+        }
+
+        CreatedState {
             surface,
             dirty_surface,
             graphics_driver,
@@ -150,6 +227,52 @@ impl<'a, State: Clone + 'a> WindowState<'a, State> {
             app,
             window_config: config.window_config,
             is_window_focused: false,
-        });
+        }
+    }
+}
+
+/// Resize the window so it fits the content of its app, within its minimum and maximum sizes.
+pub(crate) fn size_window_to_content(
+    window: &Window,
+    app: &Application,
+    window_config: &WindowConfig,
+) {
+    let scale_factor = window.scale_factor();
+    let content_size = app.content_size();
+    let mut width = content_size.width.ceil() as f64;
+    let mut height = content_size.height.ceil() as f64;
+
+    if let Some((min_width, min_height)) = window_config.min_size {
+        width = width.max(min_width * scale_factor);
+        height = height.max(min_height * scale_factor);
+    }
+    if let Some((max_width, max_height)) = window_config.max_size {
+        width = width.min(max_width * scale_factor);
+        height = height.min(max_height * scale_factor);
     }
+
+    let size = PhysicalSize::new(width.max(1.) as u32, height.max(1.) as u32);
+    if size != window.inner_size() {
+        let _ = window.request_inner_size(size);
+    }
+}
+
+/// Clear the corners of the surface outside of a rounded rectangle, for windows whose corners
+/// can't be rounded by the platform.
+pub(crate) fn clip_window_corners(
+    surface: &mut Surface,
+    window_size: PhysicalSize<u32>,
+    corner_radius: f32,
+) {
+    let rrect = RRect::new_rect_radii(
+        Rect::new(0., 0., window_size.width as f32, window_size.height as f32),
+        &[Point::new(corner_radius, corner_radius); 4],
+    );
+
+    let canvas = surface.canvas();
+    canvas.save();
+    canvas.reset_matrix();
+    canvas.clip_rrect(rrect, ClipOp::Difference, true);
+    canvas.clear(Color::TRANSPARENT);
+    canvas.restore();
 }
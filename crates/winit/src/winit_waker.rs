@@ -5,17 +5,26 @@ use futures_task::{
     waker,
     ArcWake,
 };
-use winit::event_loop::EventLoopProxy;
+use winit::{
+    event_loop::EventLoopProxy,
+    window::WindowId,
+};
 
-/// Used to enqueue a new polling for the VirtualDOM once the current one has finished
-pub fn winit_waker(proxy: &EventLoopProxy<EventLoopMessage>) -> std::task::Waker {
-    struct DomHandle(EventLoopProxy<EventLoopMessage>);
+/// Used to enqueue a new polling for the VirtualDOM of the given window once the current one has finished
+pub fn winit_waker(
+    proxy: &EventLoopProxy<EventLoopMessage>,
+    window_id: WindowId,
+) -> std::task::Waker {
+    struct DomHandle(EventLoopProxy<EventLoopMessage>, WindowId);
 
     impl ArcWake for DomHandle {
         fn wake_by_ref(arc_self: &Arc<Self>) {
-            _ = arc_self.0.send_event(EventLoopMessage::PollVDOM);
+            _ = arc_self.0.send_event(EventLoopMessage::ForWindow(
+                arc_self.1,
+                Box::new(EventLoopMessage::PollVDOM),
+            ));
         }
     }
 
-    waker(Arc::new(DomHandle(proxy.clone())))
+    waker(Arc::new(DomHandle(proxy.clone(), window_id)))
 }
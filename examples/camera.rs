@@ -17,25 +17,61 @@ fn main() {
 }
 #[cfg(feature = "use_camera")]
 fn app() -> Element {
-    let image = use_camera(CameraSettings::default());
+    let cameras = use_hook(|| available_cameras().unwrap_or_default());
+    let mut selected = use_signal(|| 0);
+    let camera = use_camera(
+        CameraSettings::default().with_camera_index(
+            cameras
+                .get(selected())
+                .map(|camera| camera.index().clone())
+                .unwrap_or(CameraIndex::Index(0)),
+        ),
+    );
+    let mut snapshot = use_signal(|| None);
 
     rsx!(
         rect {
             width: "100%",
             height: "100%",
             padding: "50",
-            if let Some(err) = &*image.error().read() {
-                label {
-                    color: "black",
-                    "{err}"
+            spacing: "10",
+            rect {
+                direction: "horizontal",
+                spacing: "10",
+                for (i, info) in cameras.iter().enumerate() {
+                    Button {
+                        key: "{i}",
+                        onpress: move |_| selected.set(i),
+                        label { "{info.human_name()}" }
+                    }
                 }
-            } else {
-                image {
-                    width: "100%",
-                    height: "100%",
+                Button {
+                    onpress: {
+                        let camera = camera.clone();
+                        move |_| snapshot.set(camera.snapshot())
+                    },
+                    label { "Snapshot" }
+                }
+            }
+            rect {
+                direction: "horizontal",
+                spacing: "10",
+                CameraView {
+                    camera,
+                    width: "70%",
                     aspect_ratio: "none",
-                    reference: image.attribute(),
-                    image_reference: image.image_attribute()
+                    fallback: rsx!(
+                        label {
+                            color: "black",
+                            "Camera not available"
+                        }
+                    )
+                }
+                if let Some(snapshot) = snapshot() {
+                    image {
+                        width: "fill",
+                        image_data: dynamic_bytes(snapshot)
+                    }
                 }
             }
         }
@@ -0,0 +1,74 @@
+#![cfg_attr(
+    all(not(debug_assertions), target_os = "windows"),
+    windows_subsystem = "windows"
+)]
+
+#[cfg(feature = "dialogs")]
+use freya::prelude::*;
+
+#[cfg(not(feature = "dialogs"))]
+fn main() {
+    panic!("Run with the 'dialogs' feature");
+}
+
+#[cfg(feature = "dialogs")]
+fn main() {
+    launch(app);
+}
+
+#[cfg(feature = "dialogs")]
+fn app() -> Element {
+    let platform = use_platform();
+    let mut count = use_signal(|| 0);
+
+    let on_warn = move |_| {
+        spawn(async move {
+            platform
+                .alert(
+                    "Careful",
+                    "The counter keeps going while this is open.",
+                    MessageLevel::Warning,
+                )
+                .await;
+        });
+    };
+
+    let on_exit = move |_| {
+        spawn(async move {
+            let res = platform
+                .confirm("Exit", "Do you really want to exit?", MessageButtons::YesNo)
+                .await;
+            if res == MessageDialogResult::Yes {
+                platform.exit();
+            }
+        });
+    };
+
+    use_future(move || async move {
+        loop {
+            tokio::time::sleep(std::time::Duration::from_millis(100)).await;
+            count += 1;
+        }
+    });
+
+    rsx!(
+        rect {
+            width: "100%",
+            height: "100%",
+            main_align: "center",
+            cross_align: "center",
+            spacing: "10",
+            label {
+                "{count}"
+            }
+            Button {
+                onpress: on_warn,
+                label { "Warn" }
+            }
+            Button {
+                onpress: on_exit,
+                label { "Exit" }
+            }
+        }
+    )
+}
@@ -0,0 +1,43 @@
+#![cfg_attr(
+    all(not(debug_assertions), target_os = "windows"),
+    windows_subsystem = "windows"
+)]
+
+use freya::prelude::*;
+
+fn main() {
+    launch_cfg(
+        app,
+        LaunchConfig::<()>::new()
+            .with_size(400.0, 200.)
+            // Only needed if your system fonts don't include emojis
+            .with_default_font("Noto Color Emoji"),
+    );
+}
+
+fn app() -> Element {
+    rsx!(
+        rect {
+            main_align: "center",
+            cross_align: "center",
+            height: "100%",
+            width: "100%",
+            spacing: "8",
+            label {
+                font_size: "24",
+                "Label with emojis 🦀🎉🔥"
+            }
+            paragraph {
+                text {
+                    font_size: "24",
+                    "Paragraph with "
+                }
+                text {
+                    font_size: "24",
+                    font_family: "Inter",
+                    "emojis 🦀🎉🔥"
+                }
+            }
+        }
+    )
+}
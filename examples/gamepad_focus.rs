@@ -67,6 +67,8 @@ impl GamePadPlugin {
                                         key: Key::Enter,
                                         code: Code::Enter,
                                         modifiers: Modifiers::default(),
+                                        repeat: false,
+                                        location: Location::Standard,
                                     },
                                 });
                             }
@@ -0,0 +1,57 @@
+#![cfg_attr(
+    all(not(debug_assertions), target_os = "windows"),
+    windows_subsystem = "windows"
+)]
+
+use freya::prelude::*;
+
+fn main() {
+    launch(app);
+}
+
+fn app() -> Element {
+    rsx!(
+        rect {
+            width: "100%",
+            height: "100%",
+            padding: "10",
+            spacing: "10",
+            content: "grid",
+            grid_template_columns: "150 1fr 2fr",
+            grid_template_rows: "auto 1fr 1fr",
+
+            rect {
+                grid_column: "1 / span 3",
+                width: "100%",
+                height: "50",
+                background: "rgb(60, 60, 60)",
+            }
+
+            rect {
+                grid_row: "2 / span 2",
+                width: "100%",
+                height: "100%",
+                background: "red",
+            }
+
+            rect {
+                width: "100%",
+                height: "100%",
+                background: "orange",
+            }
+
+            rect {
+                width: "100%",
+                height: "100%",
+                background: "yellow",
+            }
+
+            rect {
+                grid_column: "2 / span 2",
+                width: "100%",
+                height: "100%",
+                background: "green",
+            }
+        }
+    )
+}
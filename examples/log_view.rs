@@ -0,0 +1,55 @@
+#![cfg_attr(
+    all(not(debug_assertions), target_os = "windows"),
+    windows_subsystem = "windows"
+)]
+
+use std::time::Duration;
+
+use freya::prelude::*;
+use tokio::time::sleep;
+
+fn main() {
+    launch_with_props(app, "Log View", (500.0, 400.0));
+}
+
+fn app() -> Element {
+    let mut log = use_text_log(Some(50_000));
+    let mut follow = use_signal(|| true);
+
+    use_hook(move || {
+        spawn(async move {
+            let mut i = 0;
+            loop {
+                sleep(Duration::from_millis(50)).await;
+                log.push_line(format!("[{i:06}] Received request from client #{}", i % 7));
+                i += 1;
+            }
+        })
+    });
+
+    rsx!(
+        rect {
+            width: "fill",
+            height: "fill",
+            rect {
+                direction: "horizontal",
+                padding: "8",
+                spacing: "8",
+                cross_align: "center",
+                Button {
+                    onpress: move |_| follow.toggle(),
+                    label { if follow() { "Stop following" } else { "Follow" } }
+                }
+                Button {
+                    onpress: move |_| log.clear(),
+                    label { "Clear" }
+                }
+                label { "{log.len()} lines" }
+            }
+            LogView {
+                log,
+                follow: follow(),
+            }
+        }
+    )
+}
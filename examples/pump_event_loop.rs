@@ -0,0 +1,60 @@
+#![cfg_attr(
+    all(not(debug_assertions), target_os = "windows"),
+    windows_subsystem = "windows"
+)]
+
+use std::{
+    sync::atomic::{
+        AtomicU64,
+        Ordering,
+    },
+    time::Duration,
+};
+
+use freya::prelude::*;
+
+/// Ticks of the main loop owned by this program, not Freya.
+static TICKS: AtomicU64 = AtomicU64::new(0);
+
+fn main() {
+    let mut event_loop = launch_pumped(
+        app,
+        LaunchConfig::<()>::new()
+            .with_title("Pumped event loop")
+            .with_size(400.0, 300.0),
+    );
+
+    loop {
+        // Here is where an audio host or a game engine would do its own work
+        TICKS.fetch_add(1, Ordering::Relaxed);
+
+        if let PumpStatus::Exit(_) = event_loop.pump(Some(Duration::from_millis(16))) {
+            break;
+        }
+    }
+}
+
+fn app() -> Element {
+    let mut ticks = use_signal(|| 0);
+
+    use_hook(move || {
+        spawn(async move {
+            loop {
+                tokio::time::sleep(Duration::from_millis(100)).await;
+                ticks.set(TICKS.load(Ordering::Relaxed));
+            }
+        })
+    });
+
+    rsx!(
+        rect {
+            width: "fill",
+            height: "fill",
+            main_align: "center",
+            cross_align: "center",
+            label {
+                "Main loop ticks: {ticks}"
+            }
+        }
+    )
+}
@@ -0,0 +1,66 @@
+#![cfg_attr(
+    all(not(debug_assertions), target_os = "windows"),
+    windows_subsystem = "windows"
+)]
+
+use freya::prelude::*;
+
+fn main() {
+    launch_cfg(
+        app,
+        LaunchConfig::<()>::new()
+            .with_title("Right-to-left")
+            .with_size(450.0, 350.0)
+            .with_layout_direction(LayoutDirection::Rtl),
+    );
+}
+
+fn app() -> Element {
+    let platform = use_platform();
+    let layout_direction = use_layout_direction();
+    let mut value = use_signal(|| 25.0);
+
+    let toggle = move |_| {
+        if layout_direction().is_rtl() {
+            platform.set_layout_direction(LayoutDirection::Ltr);
+        } else {
+            platform.set_layout_direction(LayoutDirection::Rtl);
+        }
+    };
+
+    rsx!(
+        rect {
+            width: "fill",
+            height: "fill",
+            padding: "12",
+            spacing: "12",
+            rect {
+                width: "fill",
+                direction: "horizontal",
+                cross_align: "center",
+                spacing: "8",
+                Button {
+                    onpress: toggle,
+                    label { "Toggle direction" }
+                }
+                label {
+                    margin: "0 0 0 auto",
+                    "{layout_direction():?}"
+                }
+            }
+            Slider {
+                size: "fill",
+                value: value(),
+                onmoved: move |v| value.set(v),
+            }
+            ScrollView {
+                for i in 0..20 {
+                    label {
+                        key: "{i}",
+                        "Item {i}"
+                    }
+                }
+            }
+        }
+    )
+}
@@ -0,0 +1,45 @@
+#![cfg_attr(
+    all(not(debug_assertions), target_os = "windows"),
+    windows_subsystem = "windows"
+)]
+
+use freya::prelude::*;
+
+static SANSITA_SWASHED: &[u8] = include_bytes!("./SansitaSwashed-Regular.ttf");
+
+fn main() {
+    launch_with_props(app, "Runtime font", (400.0, 200.0));
+}
+
+fn app() -> Element {
+    let mut load_font = use_signal(|| false);
+
+    rsx!(
+        rect {
+            main_align: "center",
+            cross_align: "center",
+            height: "100%",
+            width: "100%",
+            spacing: "12",
+            if load_font() {
+                FontLoader { }
+            }
+            label {
+                font_size: "20",
+                font_family: "Sansita Swashed, Inter",
+                "Falls back to Inter until loaded"
+            }
+            Button {
+                onpress: move |_| load_font.set(true),
+                label { "Load font" }
+            }
+        }
+    )
+}
+
+#[component]
+fn FontLoader() -> Element {
+    use_font("Sansita Swashed", || SANSITA_SWASHED.into());
+
+    Ok(VNode::placeholder())
+}
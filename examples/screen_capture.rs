@@ -0,0 +1,85 @@
+#![cfg_attr(
+    all(not(debug_assertions), target_os = "windows"),
+    windows_subsystem = "windows"
+)]
+
+#[cfg(feature = "use_screen_capture")]
+use freya::prelude::*;
+
+#[cfg(not(feature = "use_screen_capture"))]
+fn main() {
+    panic!("Run with the 'use_screen_capture' feature");
+}
+
+#[cfg(feature = "use_screen_capture")]
+fn main() {
+    launch(app);
+}
+
+#[cfg(feature = "use_screen_capture")]
+fn app() -> Element {
+    let sources = use_hook(|| capture_sources().unwrap_or_default());
+    let mut selected = use_signal(|| sources.first().cloned());
+
+    rsx!(
+        rect {
+            width: "100%",
+            height: "100%",
+            padding: "20",
+            spacing: "10",
+            if let Some(selected_source) = selected() {
+                Dropdown {
+                    value: selected_source,
+                    for source in sources.iter() {
+                        DropdownItem {
+                            key: "{source:?}",
+                            value: source.clone(),
+                            onpress: {
+                                let source = source.clone();
+                                move |_| selected.set(Some(source.clone()))
+                            },
+                            label { "{source} ({source.width}x{source.height})" }
+                        }
+                    }
+                }
+                Preview {
+                    source: selected_source.source
+                }
+            } else {
+                label {
+                    "Nothing to capture"
+                }
+            }
+        }
+    )
+}
+
+#[cfg(feature = "use_screen_capture")]
+#[component]
+fn Preview(source: CaptureSource) -> Element {
+    let capture = use_screen_capture(ScreenCaptureSettings::new(source).with_frame_rate(30));
+    let mut marks = use_signal(Vec::<CursorPoint>::new);
+
+    rsx!(
+        ScreenCaptureView {
+            capture,
+            rect {
+                width: "fill",
+                height: "fill",
+                onclick: move |e: MouseEvent| marks.push(e.get_element_coordinates()),
+                for (i, mark) in marks.read().iter().enumerate() {
+                    rect {
+                        key: "{i}",
+                        position: "absolute",
+                        position_top: "{mark.y - 10.}",
+                        position_left: "{mark.x - 10.}",
+                        width: "20",
+                        height: "20",
+                        corner_radius: "10",
+                        border: "3 inner red",
+                    }
+                }
+            }
+        }
+    )
+}
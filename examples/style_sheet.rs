@@ -0,0 +1,67 @@
+#![cfg_attr(
+    all(not(debug_assertions), target_os = "windows"),
+    windows_subsystem = "windows"
+)]
+
+use freya::prelude::*;
+
+fn main() {
+    launch(app);
+}
+
+fn app() -> Element {
+    use_init_theme(|| DARK_THEME);
+    use_init_style_sheet(|| {
+        StyleSheet::new()
+            .with(
+                "card",
+                Style::new()
+                    .set("width", "200")
+                    .set("padding", "12")
+                    .set("corner_radius", "8")
+                    .set("background", "key(neutral_surface)")
+                    .set("color", "key(color)")
+                    .hover(Style::new().set("background", "key(focused_surface)"))
+                    .disabled(Style::new().set("color", "key(secondary_accent)")),
+            )
+            .with(
+                "container",
+                Style::new()
+                    .set("width", "fill")
+                    .set("height", "fill")
+                    .set("spacing", "10")
+                    .set("main_align", "center")
+                    .set("cross_align", "center")
+                    .set("background", "key(background)"),
+            )
+    });
+
+    let container = use_style("container");
+
+    rsx!(
+        rect {
+            ..container.read().attributes(InteractionState::default()),
+            Card { title: "Hover me" }
+            Card { title: "Disabled", disabled: true }
+        }
+    )
+}
+
+#[component]
+fn Card(title: &'static str, #[props(default)] disabled: bool) -> Element {
+    let card = use_style("card");
+    let mut hovered = use_signal(|| false);
+
+    rsx!(
+        rect {
+            onmouseenter: move |_| hovered.set(true),
+            onmouseleave: move |_| hovered.set(false),
+            ..card.read().attributes(InteractionState {
+                hovered: hovered(),
+                disabled,
+                ..Default::default()
+            }),
+            label { "{title}" }
+        }
+    )
+}
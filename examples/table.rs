@@ -35,6 +35,7 @@ impl Display for OrderBy {
 fn app() -> Element {
     let mut order_direction = use_signal(|| OrderDirection::Down);
     let mut order = use_signal(|| OrderBy::Name);
+    let mut selection = use_selection::<String>(SelectionMode::Multiple);
     let data = use_signal(|| {
         vec![
             vec!["Zeus".to_owned(), "Sky".to_owned(), "01".to_owned()],
@@ -72,7 +73,12 @@ fn app() -> Element {
         } else {
             Either::Right(filtered_data.rev())
         }
-    };
+    }
+    .collect::<Vec<_>>();
+    let names = filtered_data
+        .iter()
+        .map(|items| items[0].clone())
+        .collect::<Vec<_>>();
 
     let mut on_column_head_click = move |column_order: &OrderBy| {
         // Change order diection
@@ -90,6 +96,14 @@ fn app() -> Element {
     };
 
     rsx!(
+        rect {
+            onglobalkeydown: {
+                let names = names.clone();
+                move |e: KeyboardEvent| {
+                    selection.keydown(&e.data, &names);
+                }
+            },
+        }
         Body {
             padding: "10",
             spacing: "10",
@@ -100,6 +114,7 @@ fn app() -> Element {
                         for (n, (text, order_by)) in columns.into_iter().enumerate() {
                             TableCell {
                                 key: "{n}",
+                                column_index: n,
                                 order_direction: if *order.read() == order_by { Some(*order_direction.read()) } else { None },
                                 onpress: move |_| on_column_head_click(&order_by),
                                 label {
@@ -111,12 +126,19 @@ fn app() -> Element {
                 }
                 TableBody {
                     ScrollView {
-                        for (i, items) in filtered_data.enumerate() {
+                        for (i, items) in filtered_data.into_iter().enumerate() {
                             TableRow {
                                 key: "{i}",
+                                selected: selection.is_selected(&items[0]),
+                                onpress: {
+                                    let name = items[0].clone();
+                                    let names = names.clone();
+                                    move |_| selection.click(name.clone(), &names)
+                                },
                                 for (n, item) in items.iter().enumerate() {
                                     TableCell {
                                         key: "{n}",
+                                        column_index: n,
                                         label {
                                             width: "100%",
                                             text_align: "right",
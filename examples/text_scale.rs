@@ -0,0 +1,51 @@
+#![cfg_attr(
+    all(not(debug_assertions), target_os = "windows"),
+    windows_subsystem = "windows"
+)]
+
+use freya::prelude::*;
+
+fn main() {
+    launch_with_props(app, "Text scale", (400.0, 350.0));
+}
+
+fn app() -> Element {
+    let platform = use_platform();
+    let text_scale_factor = use_text_scale_factor();
+
+    let set_scale = move |delta: f32| {
+        platform.set_text_scale_factor(text_scale_factor().0 + delta);
+    };
+
+    rsx!(
+        rect {
+            width: "fill",
+            height: "fill",
+            padding: "12",
+            spacing: "8",
+            rect {
+                direction: "horizontal",
+                spacing: "8",
+                cross_align: "center",
+                Button {
+                    onpress: move |_| set_scale(-0.25),
+                    label { "A-" }
+                }
+                Button {
+                    onpress: move |_| set_scale(0.25),
+                    label { "A+" }
+                }
+                label { "Text scale: {text_scale_factor().0}" }
+            }
+            paragraph {
+                width: "fill",
+                text { "Only the text gets bigger, the rest of the layout keeps its size and reflows around it." }
+            }
+            rect {
+                width: "100",
+                height: "40",
+                background: "rgb(0, 119, 182)",
+            }
+        }
+    )
+}
@@ -0,0 +1,62 @@
+#![cfg_attr(
+    all(not(debug_assertions), target_os = "windows"),
+    windows_subsystem = "windows"
+)]
+
+use freya::prelude::*;
+
+fn main() {
+    launch(app);
+}
+
+const TAGS: [&str; 16] = [
+    "rust",
+    "gui",
+    "skia",
+    "dioxus",
+    "layout",
+    "torin",
+    "desktop",
+    "cross-platform",
+    "widgets",
+    "themes",
+    "animations",
+    "accessibility",
+    "text",
+    "images",
+    "svg",
+    "shaders",
+];
+
+fn app() -> Element {
+    let mut width = use_signal(|| 50.);
+
+    rsx!(
+        rect {
+            width: "100%",
+            height: "100%",
+            padding: "20",
+            spacing: "20",
+            Slider {
+                size: "200",
+                value: width(),
+                onmoved: move |w| width.set(w),
+            }
+            rect {
+                width: "{width}%",
+                direction: "horizontal",
+                wrap_content: "wrap",
+                spacing: "8",
+                for tag in TAGS {
+                    rect {
+                        key: "{tag}",
+                        padding: "6 10",
+                        corner_radius: "12",
+                        background: "rgb(225, 225, 235)",
+                        label { "{tag}" }
+                    }
+                }
+            }
+        }
+    )
+}